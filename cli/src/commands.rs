@@ -149,6 +149,44 @@ pub(crate) enum Command {
     },
     /// Rescan onchain swaps
     RescanOnchainSwaps,
+    /// Claim a stuck Receive or incoming Chain swap, regardless of its last swapper status
+    ForceClaim {
+        /// The id of the swap
+        swap_id: String,
+    },
+    /// Refund the lockup of a stuck Send swap, regardless of its last swapper status
+    ForceRefund {
+        /// The id of the swap
+        swap_id: String,
+
+        /// Whether to refund without the cooperation of the swapper, once the swap expired
+        #[arg(long)]
+        non_cooperative: bool,
+    },
+    /// Fetch the current status of a swap from the swapper and handle it
+    RefetchSwapStatus {
+        /// The id of the swap
+        swap_id: String,
+    },
+    /// Mark a stuck swap as failed
+    MarkSwapFailed {
+        /// The id of the swap
+        swap_id: String,
+    },
+    /// Import a swap created outside the SDK from its Boltz rescue file
+    ImportSwap {
+        /// The path of the rescue file
+        file_path: String,
+    },
+    /// Export the Boltz rescue file of a swap, containing its claim and refund keys
+    ExportRescueFile {
+        /// The id of the swap
+        swap_id: String,
+
+        /// The file to write the rescue file to. Printed if not set
+        #[arg(short, long)]
+        output_path: Option<String>,
+    },
     /// Get the balance and general info of the current instance
     GetInfo,
     /// Sign a message using the wallet private key
@@ -178,6 +216,23 @@ pub(crate) enum Command {
         #[arg(short, long)]
         backup_path: Option<String>,
     },
+    /// Runs the claims, refunds and history sync once within a time budget, as done by mobile
+    /// background tasks
+    SyncOnce {
+        /// The time budget in seconds
+        timeout_secs: u64,
+    },
+    /// Get the recorded state transitions of a swap
+    GetSwapHistory {
+        /// The id of the swap
+        swap_id: String,
+    },
+    /// Generates a redacted report of the SDK state to attach to support requests
+    GenerateDiagnostics {
+        /// The file to write the report to. Printed if not set
+        #[arg(short, long)]
+        output_path: Option<String>,
+    },
     /// Shuts down all background threads of this SDK instance
     Disconnect,
     /// Parse a generic string to get its type and relevant metadata
@@ -190,6 +245,10 @@ pub(crate) enum Command {
         /// LN Address or LNURL-pay endpoint
         lnurl: String,
 
+        /// Amount to pay in millisatoshi. Prompted for if not set in interactive mode
+        #[arg(short, long)]
+        amount_msat: Option<u64>,
+
         /// Validates the success action URL
         #[clap(name = "validate_success_url", short = 'v', long = "validate")]
         validate_success_url: Option<bool>,
@@ -197,6 +256,10 @@ pub(crate) enum Command {
     LnurlWithdraw {
         /// LNURL-withdraw endpoint
         lnurl: String,
+
+        /// Amount to withdraw in millisatoshi. Prompted for if not set in interactive mode
+        #[arg(short, long)]
+        amount_msat: Option<u64>,
     },
     LnurlAuth {
         /// LNURL-auth endpoint
@@ -224,6 +287,25 @@ impl Highlighter for CliHelper {
     }
 }
 
+/// How commands are run
+pub(crate) struct CommandMode {
+    /// Whether the user can be prompted for confirmations and input
+    pub(crate) interactive: bool,
+    /// Whether fees and other confirmations are accepted without prompting. In non-interactive
+    /// mode, commands requiring a confirmation fail unless this is set.
+    pub(crate) accept_fees: bool,
+    /// If set, payment commands block until the payment reaches a final state
+    pub(crate) wait_timeout: Option<Duration>,
+}
+
+/// The states after which a payment no longer changes without user action
+const FINAL_PAYMENT_STATES: [PaymentState; 4] = [
+    PaymentState::Complete,
+    PaymentState::Failed,
+    PaymentState::TimedOut,
+    PaymentState::Refundable,
+];
+
 #[derive(Serialize)]
 pub(crate) struct CommandResult<T: Serialize> {
     pub success: bool,
@@ -240,22 +322,88 @@ macro_rules! command_result {
 }
 
 macro_rules! wait_confirmation {
-    ($prompt:expr,$result:expr) => {
-        print!("{}", $prompt);
-        std::io::stdout().flush()?;
+    ($mode:expr,$prompt:expr,$result:expr) => {
+        match ($mode.accept_fees, $mode.interactive) {
+            (true, _) => {}
+            (false, true) => {
+                print!("{}", $prompt);
+                std::io::stdout().flush()?;
+
+                let mut buf = String::new();
+                std::io::stdin().read_line(&mut buf)?;
+                if !['y', 'Y'].contains(&(buf.as_bytes()[0] as char)) {
+                    return Ok(command_result!($result));
+                }
+            }
+            (false, false) => return Err(confirmation_required(&$prompt)),
+        }
+    };
+}
+
+/// The error returned in non-interactive mode when a confirmation wasn't given upfront
+#[derive(Debug)]
+pub(crate) struct ConfirmationRequired(String);
+
+impl std::fmt::Display for ConfirmationRequired {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Confirmation required: {} Run again with `--accept-fees` to confirm",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ConfirmationRequired {}
+
+fn confirmation_required(prompt: &str) -> anyhow::Error {
+    let question = prompt.trim_end().trim_end_matches("(y/N)").trim_end();
+    ConfirmationRequired(question.to_string()).into()
+}
+
+fn read_amount_msat(
+    rl: &mut Editor<CliHelper, DefaultHistory>,
+    mode: &CommandMode,
+    amount_msat: Option<u64>,
+    prompt: String,
+) -> Result<u64> {
+    match (amount_msat, mode.interactive) {
+        (Some(amount_msat), _) => Ok(amount_msat),
+        (None, true) => Ok(rl.readline(&prompt)?.parse::<u64>()?),
+        (None, false) => Err(anyhow!(
+            "Must specify `amount_msat` in non-interactive mode"
+        )),
+    }
+}
 
-        let mut buf = String::new();
-        std::io::stdin().read_line(&mut buf)?;
-        if !['y', 'Y'].contains(&(buf.as_bytes()[0] as char)) {
-            return Ok(command_result!($result));
+/// Waits for the payment to reach a final state if [CommandMode::wait_timeout] is set
+async fn wait_for_final_state(
+    sdk: &Arc<LiquidSdk>,
+    mode: &CommandMode,
+    payment: Payment,
+) -> Result<Payment> {
+    let Some(wait_timeout) = mode.wait_timeout else {
+        return Ok(payment);
+    };
+    let payment_id = match &payment.details {
+        PaymentDetails::Lightning { swap_id, .. } | PaymentDetails::Bitcoin { swap_id, .. } => {
+            swap_id.clone()
         }
+        PaymentDetails::Liquid { .. } => payment
+            .tx_id
+            .clone()
+            .ok_or(anyhow!("Payment has no tx id to wait for"))?,
     };
+    Ok(sdk
+        .wait_for_payment(&payment_id, &FINAL_PAYMENT_STATES, wait_timeout)
+        .await?)
 }
 
 pub(crate) async fn handle_command(
     rl: &mut Editor<CliHelper, DefaultHistory>,
     sdk: &Arc<LiquidSdk>,
     command: Command,
+    mode: &CommandMode,
 ) -> Result<String> {
     Ok(match command {
         Command::ReceivePayment {
@@ -267,11 +415,13 @@ pub(crate) async fn handle_command(
             let prepare_response = sdk
                 .prepare_receive_payment(&PrepareReceiveRequest {
                     payer_amount_sat,
+                    payer_amount_fiat: None,
                     payment_method: payment_method.unwrap_or(PaymentMethod::Lightning),
                 })
                 .await?;
 
             wait_confirmation!(
+                mode,
                 format!(
                     "Fees: {} sat. Are the fees acceptable? (y/N) ",
                     prepare_response.fees_sat
@@ -288,6 +438,9 @@ pub(crate) async fn handle_command(
                 .await?;
 
             let mut result = command_result!(&response);
+            if !mode.interactive {
+                return Ok(result);
+            }
             result.push('\n');
 
             match parse(&response.destination).await? {
@@ -354,6 +507,7 @@ pub(crate) async fn handle_command(
                 .await?;
 
             wait_confirmation!(
+                mode,
                 format!(
                     "Fees: {} sat. Are the fees acceptable? (y/N) ",
                     prepare_response.fees_sat
@@ -375,7 +529,8 @@ pub(crate) async fn handle_command(
                 command_result!(prepare_response)
             } else {
                 let response = sdk.send_payment(&send_payment_req).await?;
-                command_result!(response)
+                let payment = wait_for_final_state(sdk, mode, response.payment).await?;
+                command_result!(SendPaymentResponse { payment })
             }
         }
         Command::SendOnchainPayment {
@@ -400,6 +555,7 @@ pub(crate) async fn handle_command(
                 .await?;
 
             wait_confirmation!(
+                mode,
                 format!(
                     "Fees: {} sat (incl claim fee: {} sat). Receiver amount: {} sat. Are the fees acceptable? (y/N) ",
                     prepare_response.total_fees_sat, prepare_response.claim_fees_sat, prepare_response.receiver_amount_sat
@@ -413,7 +569,8 @@ pub(crate) async fn handle_command(
                     prepare_response,
                 })
                 .await?;
-            command_result!(response)
+            let payment = wait_for_final_state(sdk, mode, response.payment).await?;
+            command_result!(SendPaymentResponse { payment })
         }
         Command::BuyBitcoin {
             provider,
@@ -427,6 +584,7 @@ pub(crate) async fn handle_command(
                 .await?;
 
             wait_confirmation!(
+                mode,
                 format!(
                     "Fees: {} sat. Are the fees acceptable? (y/N) ",
                     prepare_response.fees_sat
@@ -442,6 +600,9 @@ pub(crate) async fn handle_command(
                 .await?;
 
             let mut result = command_result!(url.clone());
+            if !mode.interactive {
+                return Ok(result);
+            }
             result.push('\n');
             result.push_str(&build_qr_text(&url));
             result
@@ -541,6 +702,60 @@ pub(crate) async fn handle_command(
             sdk.rescan_onchain_swaps().await?;
             command_result!("Rescanned successfully")
         }
+        Command::ForceClaim { swap_id } => {
+            wait_confirmation!(
+                mode,
+                format!("Claim swap {swap_id} regardless of its status? (y/N) "),
+                "Claim halted"
+            );
+            sdk.force_claim_swap(&swap_id).await?;
+            command_result!("Claimed successfully")
+        }
+        Command::ForceRefund {
+            swap_id,
+            non_cooperative,
+        } => {
+            wait_confirmation!(
+                mode,
+                format!("Refund swap {swap_id} regardless of its status? (y/N) "),
+                "Refund halted"
+            );
+            let refund_tx_id = sdk
+                .force_refund_send_swap(&swap_id, !non_cooperative)
+                .await?;
+            command_result!(refund_tx_id)
+        }
+        Command::RefetchSwapStatus { swap_id } => {
+            sdk.refetch_swap_status(&swap_id).await?;
+            command_result!("Swap status refetched successfully")
+        }
+        Command::MarkSwapFailed { swap_id } => {
+            wait_confirmation!(
+                mode,
+                format!("Mark swap {swap_id} as failed? (y/N) "),
+                "Swap update halted"
+            );
+            sdk.mark_swap_failed(&swap_id).await?;
+            command_result!("Swap marked as failed")
+        }
+        Command::ImportSwap { file_path } => {
+            let rescue_json = std::fs::read_to_string(&file_path)?;
+            sdk.import_swap(&rescue_json).await?;
+            command_result!("Swap imported successfully")
+        }
+        Command::ExportRescueFile {
+            swap_id,
+            output_path,
+        } => {
+            let rescue_json = sdk.export_rescue_file(&swap_id)?;
+            match output_path {
+                Some(output_path) => {
+                    std::fs::write(&output_path, rescue_json)?;
+                    command_result!(format!("Rescue file written to {output_path}"))
+                }
+                None => command_result!(serde_json::from_str::<serde_json::Value>(&rescue_json)?),
+            }
+        }
         Command::Sync => {
             sdk.sync().await?;
             command_result!("Synced successfully")
@@ -561,6 +776,24 @@ pub(crate) async fn handle_command(
             sdk.restore(RestoreRequest { backup_path })?;
             command_result!("Backup restored successfully!")
         }
+        Command::SyncOnce { timeout_secs } => {
+            let res = sdk.sync_once(&SyncOnceRequest { timeout_secs }).await?;
+            command_result!(res)
+        }
+        Command::GetSwapHistory { swap_id } => {
+            let history = sdk.get_swap_history(&swap_id)?;
+            command_result!(history)
+        }
+        Command::GenerateDiagnostics { output_path } => {
+            let diagnostics = sdk.generate_diagnostics().await?;
+            match output_path {
+                Some(output_path) => {
+                    std::fs::write(&output_path, diagnostics)?;
+                    command_result!(format!("Diagnostics written to {output_path}"))
+                }
+                None => command_result!(serde_json::from_str::<serde_json::Value>(&diagnostics)?),
+            }
+        }
         Command::Disconnect => {
             sdk.disconnect().await?;
             command_result!("Liquid SDK instance disconnected")
@@ -571,6 +804,7 @@ pub(crate) async fn handle_command(
         }
         Command::LnurlPay {
             lnurl,
+            amount_msat,
             validate_success_url,
         } => {
             let input = LiquidSdk::parse(&lnurl).await?;
@@ -581,17 +815,18 @@ pub(crate) async fn handle_command(
                         pd.min_sendable, pd.max_sendable
                     );
 
-                    let amount_msat = rl.readline(&prompt)?;
+                    let amount_msat = read_amount_msat(rl, mode, amount_msat, prompt)?;
                     let prepare_response = sdk
                         .prepare_lnurl_pay(PrepareLnUrlPayRequest {
                             data: pd,
-                            amount_msat: amount_msat.parse::<u64>()?,
+                            amount_msat,
                             comment: None,
                             validate_success_action_url: validate_success_url,
                         })
                         .await?;
 
                     wait_confirmation!(
+                        mode,
                         format!(
                             "Fees: {} sat. Are the fees acceptable? (y/N) ",
                             prepare_response.fees_sat
@@ -602,14 +837,24 @@ pub(crate) async fn handle_command(
                     let pay_res = sdk
                         .lnurl_pay(model::LnUrlPayRequest { prepare_response })
                         .await?;
-                    Ok(pay_res)
+                    match pay_res {
+                        LnUrlPayResult::EndpointSuccess { data } => {
+                            Ok(LnUrlPayResult::EndpointSuccess {
+                                data: LnUrlPaySuccessData {
+                                    payment: wait_for_final_state(sdk, mode, data.payment).await?,
+                                    success_action: data.success_action,
+                                },
+                            })
+                        }
+                        pay_res => Ok(pay_res),
+                    }
                 }
                 _ => Err(anyhow!("Invalid input")),
             }?;
 
             command_result!(res)
         }
-        Command::LnurlWithdraw { lnurl } => {
+        Command::LnurlWithdraw { lnurl, amount_msat } => {
             let input = LiquidSdk::parse(&lnurl).await?;
             let res = match input {
                 InputType::LnUrlWithdraw { data: pd } => {
@@ -618,11 +863,11 @@ pub(crate) async fn handle_command(
                         pd.min_withdrawable, pd.max_withdrawable
                     );
 
-                    let amount_msat = rl.readline(&prompt)?;
+                    let amount_msat = read_amount_msat(rl, mode, amount_msat, prompt)?;
                     let withdraw_res = sdk
                         .lnurl_withdraw(LnUrlWithdrawRequest {
                             data: pd,
-                            amount_msat: amount_msat.parse()?,
+                            amount_msat,
                             description: Some("LNURL-withdraw".to_string()),
                         })
                         .await?;
@@ -673,3 +918,18 @@ fn build_qr_text(text: &str) -> String {
         .light_color(unicode::Dense1x2::Dark)
         .build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::confirmation_required;
+
+    #[test]
+    fn test_confirmation_required() {
+        let err = confirmation_required("Fees: 21 sat. Are the fees acceptable? (y/N) ");
+        assert_eq!(
+            err.to_string(),
+            "Confirmation required: Fees: 21 sat. Are the fees acceptable? Run again with `--accept-fees` to confirm"
+        );
+        assert_eq!(crate::exit_code(&err), 5);
+    }
+}
@@ -1,12 +1,16 @@
 mod commands;
 mod persist;
+mod serve;
 
-use std::{fs, path::PathBuf};
+use std::{fs, net::SocketAddr, path::PathBuf, process, time::Duration};
 
 use anyhow::{anyhow, Result};
+use breez_sdk_liquid::error::{PaymentError, SdkError};
 use breez_sdk_liquid::prelude::*;
 use clap::Parser;
-use commands::{handle_command, CliHelper, Command, CommandResult};
+use commands::{
+    handle_command, CliHelper, Command, CommandMode, CommandResult, ConfirmationRequired,
+};
 use log::{error, info};
 use persist::CliPersistence;
 use rustyline::{error::ReadlineError, hint::HistoryHinter, Editor};
@@ -24,6 +28,28 @@ pub(crate) struct Args {
 
     #[clap(short, long, value_parser = parse_network_arg)]
     pub(crate) network: Option<LiquidNetwork>,
+
+    /// Serve the SDK over JSON-RPC on the given address instead of starting the shell.
+    /// Requests are authenticated with the bearer token stored in `<data_dir>/api_token`
+    #[clap(long)]
+    pub(crate) serve: Option<SocketAddr>,
+
+    /// Block payment commands until the payment reaches a final state
+    #[clap(long)]
+    pub(crate) wait: bool,
+
+    /// The maximum time to wait for a payment when using `--wait`, in seconds
+    #[clap(long, default_value_t = 300)]
+    pub(crate) wait_timeout_secs: u64,
+
+    /// Accept the fees and other confirmations without prompting. Commands requiring a
+    /// confirmation fail without it when run non-interactively.
+    #[clap(long, visible_alias = "yes")]
+    pub(crate) accept_fees: bool,
+
+    /// Run a single command without prompting, print its JSON result and exit
+    #[command(subcommand)]
+    pub(crate) command: Option<Command>,
 }
 
 fn parse_network_arg(s: &str) -> Result<LiquidNetwork, String> {
@@ -42,6 +68,42 @@ fn show_results(result: Result<String>) -> Result<()> {
     Ok(println!("{result_str}"))
 }
 
+/// Maps a command error to the process exit code used in non-interactive mode
+fn exit_code(err: &anyhow::Error) -> i32 {
+    if err.is::<ConfirmationRequired>() {
+        return 5;
+    }
+    if let Some(err) = err.downcast_ref::<PaymentError>() {
+        return match err {
+            PaymentError::InsufficientFunds => 10,
+            PaymentError::AmountOutOfRange | PaymentError::AmountMissing { .. } => 11,
+            PaymentError::InvalidInvoice { .. }
+            | PaymentError::InvalidNetwork { .. }
+            | PaymentError::InvalidDescription { .. } => 12,
+            PaymentError::InvalidOrExpiredFees => 13,
+            PaymentError::AlreadyClaimed
+            | PaymentError::AlreadyPaid
+            | PaymentError::PaymentInProgress => 14,
+            PaymentError::PaymentTimeout => 15,
+            PaymentError::Refunded { .. } => 16,
+            PaymentError::SelfTransferNotSupported => 17,
+            PaymentError::Swapper { .. } | PaymentError::ChainService { .. } => 4,
+            _ => 1,
+        };
+    }
+    if let Some(err) = err.downcast_ref::<SdkError>() {
+        return match err {
+            SdkError::AlreadyStarted | SdkError::NotStarted => 3,
+            SdkError::ServiceConnectivity { .. }
+            | SdkError::Swapper { .. }
+            | SdkError::ChainService { .. } => 4,
+            SdkError::Validation { .. } => 12,
+            _ => 1,
+        };
+    }
+    1
+}
+
 struct CliEventListener {}
 
 impl EventListener for CliEventListener {
@@ -88,9 +150,44 @@ async fn main() -> Result<()> {
         .add_event_listener(Box::new(CliEventListener {}))
         .await?;
 
+    if let Some(addr) = args.serve {
+        let api_token = persistence.get_or_create_api_token()?;
+        serve::serve(sdk.clone(), addr, api_token).await?;
+        sdk.disconnect().await?;
+        return sdk
+            .remove_event_listener(listener_id)
+            .await
+            .map_err(Into::into);
+    }
+
+    if let Some(command) = args.command {
+        let mode = CommandMode {
+            interactive: false,
+            accept_fees: args.accept_fees,
+            wait_timeout: args
+                .wait
+                .then(|| Duration::from_secs(args.wait_timeout_secs)),
+        };
+        let res = handle_command(rl, &sdk, command, &mode).await;
+        let code = res.as_ref().err().map(exit_code).unwrap_or(0);
+        show_results(res)?;
+        sdk.remove_event_listener(listener_id).await?;
+        // The command may have already disconnected the instance
+        let _ = sdk.disconnect().await;
+        process::exit(code);
+    }
+
+    let mode = CommandMode {
+        interactive: true,
+        accept_fees: args.accept_fees,
+        wait_timeout: args
+            .wait
+            .then(|| Duration::from_secs(args.wait_timeout_secs)),
+    };
     let cli_prompt = match network {
         LiquidNetwork::Mainnet => "breez-liquid-cli [mainnet]> ",
         LiquidNetwork::Testnet => "breez-liquid-cli [testnet]> ",
+        LiquidNetwork::Regtest => "breez-liquid-cli [regtest]> ",
     };
 
     loop {
@@ -105,7 +202,7 @@ async fn main() -> Result<()> {
                     println!("{}", cli_res.unwrap_err());
                     continue;
                 }
-                let res = handle_command(rl, &sdk, cli_res.unwrap()).await;
+                let res = handle_command(rl, &sdk, cli_res.unwrap(), &mode).await;
                 show_results(res)?;
             }
             Err(ReadlineError::Interrupted) => {
@@ -1,13 +1,18 @@
 use std::{
-    fs, io,
+    fs,
+    io::{self, Write},
     path::{Path, PathBuf},
     str::FromStr,
 };
 
 use anyhow::Result;
-use bip39::{Language, Mnemonic};
+use bip39::{
+    rand::{thread_rng, RngCore},
+    Language, Mnemonic,
+};
 
 const PHRASE_FILE_NAME: &str = "phrase";
+const API_TOKEN_FILE_NAME: &str = "api_token";
 const HISTORY_FILE_NAME: &str = "history.txt";
 
 pub(crate) struct CliPersistence {
@@ -32,8 +37,98 @@ impl CliPersistence {
         Ok(mnemonic)
     }
 
+    pub(crate) fn get_or_create_api_token(&self) -> Result<String> {
+        let filename = Path::new(&self.data_dir).join(API_TOKEN_FILE_NAME);
+
+        let api_token = match fs::read_to_string(filename.clone()) {
+            Ok(api_token) => {
+                restrict_permissions(&filename)?;
+                api_token.trim().to_string()
+            }
+            Err(e) => {
+                if e.kind() != io::ErrorKind::NotFound {
+                    panic!("Can't read from file: {}, err {e}", filename.display());
+                }
+                let mut bytes = [0u8; 32];
+                thread_rng().fill_bytes(&mut bytes);
+                let api_token = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+                write_private_file(&filename, &api_token)?;
+                api_token
+            }
+        };
+        Ok(api_token)
+    }
+
     pub(crate) fn history_file(&self) -> String {
         let path = Path::new(&self.data_dir).join(HISTORY_FILE_NAME);
         path.to_str().unwrap().to_string()
     }
 }
+
+/// Creates a file which only the current user can read and write
+#[cfg(unix)]
+fn write_private_file(path: &Path, contents: &str) -> io::Result<()> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, contents: &str) -> io::Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+/// Tightens the permissions of a file created by an earlier version, readable by other users
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::{CliPersistence, API_TOKEN_FILE_NAME};
+
+    #[test]
+    fn test_api_token() -> Result<()> {
+        let data_dir = std::env::temp_dir().join(format!("breez-cli-test-{}", std::process::id()));
+        fs::create_dir_all(&data_dir)?;
+        let persistence = CliPersistence {
+            data_dir: data_dir.clone(),
+        };
+
+        let api_token = persistence.get_or_create_api_token()?;
+        assert_eq!(api_token.len(), 64);
+        assert_eq!(persistence.get_or_create_api_token()?, api_token);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let metadata = fs::metadata(data_dir.join(API_TOKEN_FILE_NAME))?;
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        }
+
+        fs::remove_dir_all(data_dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,451 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::{
+    extract::{Query, Request, State},
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post},
+    Json, Router,
+};
+use breez_sdk_liquid::prelude::*;
+use futures_util::{Stream, StreamExt};
+use log::info;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use tokio::net::TcpListener;
+
+const JSONRPC_VERSION: &str = "2.0";
+const PARSE_ERROR: i32 = -32700;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+const SDK_ERROR: i32 = -32000;
+
+#[derive(Clone)]
+struct ServerState {
+    sdk: Arc<LiquidSdk>,
+    api_token: Arc<String>,
+}
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+#[derive(Serialize)]
+struct RpcError {
+    code: i32,
+    message: String,
+}
+
+impl RpcError {
+    fn sdk(err: impl Display) -> Self {
+        RpcError {
+            code: SDK_ERROR,
+            message: err.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct InputParams {
+    input: String,
+}
+
+#[derive(Deserialize)]
+struct LnUrlWithdrawParams {
+    lnurl: String,
+    amount_msat: u64,
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LnUrlAuthParams {
+    lnurl: String,
+}
+
+#[derive(Deserialize)]
+struct RegisterWebhookParams {
+    webhook_url: String,
+}
+
+#[derive(Deserialize)]
+struct EventsSinceParams {
+    seq: u64,
+}
+
+#[derive(Deserialize)]
+struct AckEventsParams {
+    listener_id: String,
+    seq: u64,
+}
+
+#[derive(Deserialize)]
+struct EventCursorParams {
+    listener_id: String,
+}
+
+#[derive(Deserialize)]
+struct WebhookDeliveryParams {
+    id: u64,
+}
+
+#[derive(Deserialize)]
+struct WaitForPaymentParams {
+    payment_id: String,
+    states: Vec<PaymentState>,
+    timeout_secs: u64,
+}
+
+#[derive(Deserialize)]
+struct SwapIdParams {
+    swap_id: String,
+}
+
+#[derive(Deserialize)]
+struct ForceRefundParams {
+    swap_id: String,
+    #[serde(default = "default_cooperative")]
+    is_cooperative: bool,
+}
+
+fn default_cooperative() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+struct ImportSwapParams {
+    rescue_json: String,
+}
+
+#[derive(Deserialize)]
+struct BackupPathParams {
+    backup_path: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SyncOnceParams {
+    timeout_secs: u64,
+}
+
+#[derive(Deserialize)]
+struct HistoricalFiatRateParams {
+    currency: String,
+    timestamp: u32,
+}
+
+#[derive(Deserialize)]
+struct EventsQuery {
+    payment_id: Option<String>,
+}
+
+/// Serves the SDK over an authenticated JSON-RPC 2.0 endpoint at `POST /rpc`, and streams the
+/// emitted [SdkEvent]s as server-sent events at `GET /events`.
+///
+/// All requests have to set the `Authorization: Bearer <api_token>` header.
+pub(crate) async fn serve(sdk: Arc<LiquidSdk>, addr: SocketAddr, api_token: String) -> Result<()> {
+    let state = ServerState {
+        sdk,
+        api_token: Arc::new(api_token),
+    };
+    let app = Router::new()
+        .route("/rpc", post(handle_rpc))
+        .route("/events", get(handle_events))
+        .layer(middleware::from_fn_with_state(state.clone(), authenticate))
+        .with_state(state);
+
+    let listener = TcpListener::bind(addr).await?;
+    info!("Serving the Liquid SDK on {addr}");
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+            info!("CTRL-C");
+        })
+        .await?;
+    Ok(())
+}
+
+async fn authenticate(State(state): State<ServerState>, request: Request, next: Next) -> Response {
+    let is_authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| constant_time_eq(token.as_bytes(), state.api_token.as_bytes()));
+    match is_authorized {
+        true => next.run(request).await,
+        false => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+async fn handle_rpc(State(state): State<ServerState>, body: String) -> Json<RpcResponse> {
+    let (id, res) = match serde_json::from_str::<RpcRequest>(&body) {
+        Ok(req) => (req.id, dispatch(&state.sdk, &req.method, req.params).await),
+        Err(e) => (
+            Value::Null,
+            Err(RpcError {
+                code: PARSE_ERROR,
+                message: e.to_string(),
+            }),
+        ),
+    };
+    let (result, error) = match res {
+        Ok(result) => (Some(result), None),
+        Err(error) => (None, Some(error)),
+    };
+    Json(RpcResponse {
+        jsonrpc: JSONRPC_VERSION,
+        id,
+        result,
+        error,
+    })
+}
+
+async fn handle_events(
+    State(state): State<ServerState>,
+    Query(query): Query<EventsQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let filter = SdkEventFilter {
+        payment_id: query.payment_id,
+        kinds: None,
+    };
+    let stream = state.sdk.subscribe_events(filter).map(|e| {
+        let event = Event::default().event(format!("{:?}", SdkEventKind::from(&e)));
+        Ok(event
+            .json_data(&e)
+            .unwrap_or_else(|_| Event::default().comment("unserializable event")))
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Compares the tokens without returning early on the first differing byte, so that the time
+/// taken doesn't reveal how much of the token was guessed right
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Remote clients can't choose where the backup is written to or restored from, as that would
+/// let them read and overwrite arbitrary files. The default backup path is used instead.
+fn default_backup_path(p: Value) -> Result<Option<String>, RpcError> {
+    let BackupPathParams { backup_path } = params(p)?;
+    match backup_path {
+        Some(_) => Err(RpcError {
+            code: INVALID_PARAMS,
+            message: "Custom backup paths are not supported over RPC".to_string(),
+        }),
+        None => Ok(None),
+    }
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    // Allow omitting the params of requests without required fields
+    let params = match params {
+        Value::Null => Value::Object(Default::default()),
+        params => params,
+    };
+    serde_json::from_value(params).map_err(|e| RpcError {
+        code: INVALID_PARAMS,
+        message: e.to_string(),
+    })
+}
+
+fn to_value<T: Serialize, E: Display>(res: Result<T, E>) -> Result<Value, RpcError> {
+    serde_json::to_value(res.map_err(RpcError::sdk)?).map_err(RpcError::sdk)
+}
+
+async fn dispatch(sdk: &LiquidSdk, method: &str, p: Value) -> Result<Value, RpcError> {
+    match method {
+        "get_info" => to_value(sdk.get_info().await),
+        "sign_message" => to_value(sdk.sign_message(&params(p)?)),
+        "check_message" => to_value(sdk.check_message(&params(p)?)),
+        "parse" => {
+            let InputParams { input } = params(p)?;
+            to_value(LiquidSdk::parse(&input).await)
+        }
+        "prepare_send_payment" => to_value(sdk.prepare_send_payment(&params(p)?).await),
+        "send_payment" => to_value(sdk.send_payment(&params(p)?).await),
+        "fetch_lightning_limits" => to_value(sdk.fetch_lightning_limits().await),
+        "fetch_onchain_limits" => to_value(sdk.fetch_onchain_limits().await),
+        "prepare_pay_onchain" => to_value(sdk.prepare_pay_onchain(&params(p)?).await),
+        "pay_onchain" => to_value(sdk.pay_onchain(&params(p)?).await),
+        "prepare_receive_payment" => to_value(sdk.prepare_receive_payment(&params(p)?).await),
+        "receive_payment" => to_value(sdk.receive_payment(&params(p)?).await),
+        "prepare_buy_bitcoin" => to_value(sdk.prepare_buy_bitcoin(&params(p)?).await),
+        "buy_bitcoin" => to_value(sdk.buy_bitcoin(&params(p)?).await),
+        "list_payments" => to_value(sdk.list_payments(&params(p)?).await),
+        "get_payment" => to_value(sdk.get_payment(&params(p)?).await),
+        "wait_for_payment" => {
+            let WaitForPaymentParams {
+                payment_id,
+                states,
+                timeout_secs,
+            } = params(p)?;
+            to_value(
+                sdk.wait_for_payment(&payment_id, &states, Duration::from_secs(timeout_secs))
+                    .await,
+            )
+        }
+        "list_refundables" => to_value(sdk.list_refundables().await),
+        "prepare_refund" => to_value(sdk.prepare_refund(&params(p)?).await),
+        "refund" => to_value(sdk.refund(&params(p)?).await),
+        "rescan_onchain_swaps" => to_value(sdk.rescan_onchain_swaps().await),
+        "sync" => to_value(sdk.sync().await),
+        "sync_once" => {
+            let SyncOnceParams { timeout_secs } = params(p)?;
+            to_value(sdk.sync_once(&SyncOnceRequest { timeout_secs }).await)
+        }
+        "get_last_sync_metrics" => to_value(sdk.get_last_sync_metrics()),
+        "force_claim_swap" => {
+            let SwapIdParams { swap_id } = params(p)?;
+            to_value(sdk.force_claim_swap(&swap_id).await)
+        }
+        "force_refund_send_swap" => {
+            let ForceRefundParams {
+                swap_id,
+                is_cooperative,
+            } = params(p)?;
+            to_value(sdk.force_refund_send_swap(&swap_id, is_cooperative).await)
+        }
+        "refetch_swap_status" => {
+            let SwapIdParams { swap_id } = params(p)?;
+            to_value(sdk.refetch_swap_status(&swap_id).await)
+        }
+        "mark_swap_failed" => {
+            let SwapIdParams { swap_id } = params(p)?;
+            to_value(sdk.mark_swap_failed(&swap_id).await)
+        }
+        "import_swap" => {
+            let ImportSwapParams { rescue_json } = params(p)?;
+            to_value(sdk.import_swap(&rescue_json).await)
+        }
+        "export_rescue_file" => {
+            let SwapIdParams { swap_id } = params(p)?;
+            to_value(sdk.export_rescue_file(&swap_id))
+        }
+        "get_swap_history" => {
+            let SwapIdParams { swap_id } = params(p)?;
+            to_value(sdk.get_swap_history(&swap_id))
+        }
+        "generate_diagnostics" => to_value(sdk.generate_diagnostics().await),
+        "recommended_fees" => to_value(sdk.recommended_fees().await),
+        "empty_wallet_cache" => to_value(sdk.empty_wallet_cache()),
+        "backup" => to_value(sdk.backup(BackupRequest {
+            backup_path: default_backup_path(p)?,
+        })),
+        "restore" => to_value(sdk.restore(RestoreRequest {
+            backup_path: default_backup_path(p)?,
+        })),
+        "prepare_lnurl_pay" => to_value(sdk.prepare_lnurl_pay(params(p)?).await),
+        "lnurl_pay" => to_value(sdk.lnurl_pay(params(p)?).await),
+        "lnurl_withdraw" => {
+            let LnUrlWithdrawParams {
+                lnurl,
+                amount_msat,
+                description,
+            } = params(p)?;
+            match LiquidSdk::parse(&lnurl).await.map_err(RpcError::sdk)? {
+                InputType::LnUrlWithdraw { data } => to_value(
+                    sdk.lnurl_withdraw(LnUrlWithdrawRequest {
+                        data,
+                        amount_msat,
+                        description,
+                    })
+                    .await,
+                ),
+                _ => Err(RpcError::sdk("Invalid LNURL-withdraw input")),
+            }
+        }
+        "lnurl_auth" => {
+            let LnUrlAuthParams { lnurl } = params(p)?;
+            match LiquidSdk::parse(&lnurl).await.map_err(RpcError::sdk)? {
+                InputType::LnUrlAuth { data } => to_value(sdk.lnurl_auth(data).await),
+                _ => Err(RpcError::sdk("Invalid LNURL-auth input")),
+            }
+        }
+        "register_webhook" => {
+            let RegisterWebhookParams { webhook_url } = params(p)?;
+            to_value(sdk.register_webhook(webhook_url).await)
+        }
+        "unregister_webhook" => to_value(sdk.unregister_webhook().await),
+        "fetch_fiat_rates" => to_value(sdk.fetch_fiat_rates().await),
+        "get_historical_fiat_rate" => {
+            let HistoricalFiatRateParams {
+                currency,
+                timestamp,
+            } = params(p)?;
+            to_value(sdk.get_historical_fiat_rate(&currency, timestamp).await)
+        }
+        "list_fiat_currencies" => to_value(sdk.list_fiat_currencies().await),
+        "events_since" => {
+            let EventsSinceParams { seq } = params(p)?;
+            to_value(sdk.events_since(seq))
+        }
+        "ack_events" => {
+            let AckEventsParams { listener_id, seq } = params(p)?;
+            to_value(sdk.ack_events(&listener_id, seq))
+        }
+        "get_event_cursor" => {
+            let EventCursorParams { listener_id } = params(p)?;
+            to_value(sdk.get_event_cursor(&listener_id))
+        }
+        "get_webhook_signing_secret" => to_value(sdk.get_webhook_signing_secret()),
+        "list_failed_webhook_deliveries" => to_value(sdk.list_failed_webhook_deliveries()),
+        "retry_webhook_delivery" => {
+            let WebhookDeliveryParams { id } = params(p)?;
+            to_value(sdk.retry_webhook_delivery(id))
+        }
+        _ => Err(RpcError {
+            code: METHOD_NOT_FOUND,
+            message: format!("Unknown method {method}"),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{constant_time_eq, default_backup_path, INVALID_PARAMS};
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"token", b"token"));
+        assert!(!constant_time_eq(b"token", b"tokem"));
+        assert!(!constant_time_eq(b"token", b"token2"));
+        assert!(!constant_time_eq(b"", b"token"));
+    }
+
+    #[test]
+    fn test_default_backup_path() {
+        assert!(matches!(default_backup_path(Value::Null), Ok(None)));
+        assert!(matches!(default_backup_path(json!({})), Ok(None)));
+
+        let err = default_backup_path(json!({ "backup_path": "/etc/passwd" })).unwrap_err();
+        assert_eq!(err.code, INVALID_PARAMS);
+    }
+}
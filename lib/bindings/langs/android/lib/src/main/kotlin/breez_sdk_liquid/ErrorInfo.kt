@@ -0,0 +1,9 @@
+package breez_sdk_liquid
+
+/** The stable code and retryable flag of this error */
+val SdkException.info: ErrorInfo
+    get() = sdkErrorInfo(this)
+
+/** The stable code and retryable flag of this error */
+val PaymentException.info: ErrorInfo
+    get() = paymentErrorInfo(this)
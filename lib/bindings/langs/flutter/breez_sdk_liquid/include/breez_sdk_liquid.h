@@ -20,6 +20,11 @@ typedef struct _Dart_Handle* Dart_Handle;
 
 #define LOWBALL_FEE_RATE_SAT_PER_VBYTE 0.01
 
+/**
+ * The average time between two Bitcoin blocks, in seconds
+ */
+#define BITCOIN_BLOCK_INTERVAL_SECS 600
+
 /**
  * The minimum acceptable fee rate when claiming using zero-conf
  */
@@ -37,6 +42,39 @@ typedef struct _Dart_Handle* Dart_Handle;
  */
 #define CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS 4320
 
+/**
+ * Number of blocks before the timeout of a Bitcoin swap lockup from which
+ * [SdkEvent::SwapNearingTimeout] is emitted (~1 hour)
+ */
+#define SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS 6
+
+/**
+ * Number of blocks before the timeout of a Liquid swap lockup from which
+ * [SdkEvent::SwapNearingTimeout] is emitted (~1 hour)
+ */
+#define SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS 60
+
+/**
+ * Number of seconds the persisted [SdkEvent]s are kept in the event log (30 days)
+ */
+#define EVENT_LOG_RETENTION_SECS (((30 * 24) * 60) * 60)
+
+/**
+ * Maximum number of events returned by a single [LiquidSdk::events_since] call
+ */
+#define EVENT_LOG_PAGE_SIZE 500
+
+/**
+ * Number of seconds the persisted fiat rates are kept (1 year)
+ */
+#define FIAT_RATE_HISTORY_RETENTION_SECS (((365 * 24) * 60) * 60)
+
+/**
+ * Maximum time between a persisted fiat rate and the timestamp it's looked up for.
+ * See [LiquidSdk::get_historical_fiat_rate]
+ */
+#define FIAT_RATE_HISTORY_MAX_DISTANCE_SECS ((24 * 60) * 60)
+
 typedef struct wire_cst_list_prim_u_8_strict {
   uint8_t *ptr;
   int32_t len;
@@ -294,10 +332,16 @@ typedef struct wire_cst_ln_url_withdraw_request {
   struct wire_cst_list_prim_u_8_strict *description;
 } wire_cst_ln_url_withdraw_request;
 
+typedef struct wire_cst_payment_fiat_rate {
+  struct wire_cst_list_prim_u_8_strict *currency;
+  double rate;
+} wire_cst_payment_fiat_rate;
+
 typedef struct wire_cst_prepare_pay_onchain_response {
   uint64_t receiver_amount_sat;
   uint64_t claim_fees_sat;
   uint64_t total_fees_sat;
+  struct wire_cst_payment_fiat_rate *fiat_rate;
 } wire_cst_prepare_pay_onchain_response;
 
 typedef struct wire_cst_pay_onchain_request {
@@ -333,8 +377,14 @@ typedef struct wire_cst_PayAmount_Receiver {
   uint64_t amount_sat;
 } wire_cst_PayAmount_Receiver;
 
+typedef struct wire_cst_PayAmount_Fiat {
+  struct wire_cst_list_prim_u_8_strict *currency;
+  double amount;
+} wire_cst_PayAmount_Fiat;
+
 typedef union PayAmountKind {
   struct wire_cst_PayAmount_Receiver Receiver;
+  struct wire_cst_PayAmount_Fiat Fiat;
 } PayAmountKind;
 
 typedef struct wire_cst_pay_amount {
@@ -347,8 +397,14 @@ typedef struct wire_cst_prepare_pay_onchain_request {
   uint32_t *fee_rate_sat_per_vbyte;
 } wire_cst_prepare_pay_onchain_request;
 
+typedef struct wire_cst_fiat_amount {
+  struct wire_cst_list_prim_u_8_strict *currency;
+  double amount;
+} wire_cst_fiat_amount;
+
 typedef struct wire_cst_prepare_receive_request {
   uint64_t *payer_amount_sat;
+  struct wire_cst_fiat_amount *payer_amount_fiat;
   int32_t payment_method;
 } wire_cst_prepare_receive_request;
 
@@ -367,6 +423,7 @@ typedef struct wire_cst_prepare_receive_response {
   int32_t payment_method;
   uint64_t *payer_amount_sat;
   uint64_t fees_sat;
+  struct wire_cst_payment_fiat_rate *fiat_rate;
 } wire_cst_prepare_receive_response;
 
 typedef struct wire_cst_receive_payment_request {
@@ -388,6 +445,7 @@ typedef struct wire_cst_restore_request {
 typedef struct wire_cst_prepare_send_response {
   struct wire_cst_send_destination destination;
   uint64_t fees_sat;
+  struct wire_cst_payment_fiat_rate *fiat_rate;
 } wire_cst_prepare_send_response;
 
 typedef struct wire_cst_send_payment_request {
@@ -398,6 +456,10 @@ typedef struct wire_cst_sign_message_request {
   struct wire_cst_list_prim_u_8_strict *message;
 } wire_cst_sign_message_request;
 
+typedef struct wire_cst_sync_once_request {
+  uint64_t timeout_secs;
+} wire_cst_sync_once_request;
+
 typedef struct wire_cst_binding_event_listener {
   struct wire_cst_list_prim_u_8_strict *stream;
 } wire_cst_binding_event_listener;
@@ -445,6 +507,7 @@ typedef struct wire_cst_payment {
   int32_t payment_type;
   int32_t status;
   struct wire_cst_payment_details details;
+  struct wire_cst_payment_fiat_rate *fiat_rate;
 } wire_cst_payment;
 
 typedef struct wire_cst_SdkEvent_PaymentFailed {
@@ -455,6 +518,10 @@ typedef struct wire_cst_SdkEvent_PaymentPending {
   struct wire_cst_payment *details;
 } wire_cst_SdkEvent_PaymentPending;
 
+typedef struct wire_cst_SdkEvent_PaymentQueued {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentQueued;
+
 typedef struct wire_cst_SdkEvent_PaymentRefunded {
   struct wire_cst_payment *details;
 } wire_cst_SdkEvent_PaymentRefunded;
@@ -471,13 +538,55 @@ typedef struct wire_cst_SdkEvent_PaymentWaitingConfirmation {
   struct wire_cst_payment *details;
 } wire_cst_SdkEvent_PaymentWaitingConfirmation;
 
+typedef struct wire_cst_SdkEvent_SwapNearingTimeout {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  uint32_t blocks_left;
+} wire_cst_SdkEvent_SwapNearingTimeout;
+
+typedef struct wire_cst_SdkEvent_SwapRefundable {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+} wire_cst_SdkEvent_SwapRefundable;
+
+typedef struct wire_cst_SdkEvent_SwapStateChanged {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  int32_t from_state;
+  int32_t to_state;
+} wire_cst_SdkEvent_SwapStateChanged;
+
+typedef struct wire_cst_SdkEvent_BalanceChanged {
+  uint64_t old_balance_sat;
+  uint64_t new_balance_sat;
+} wire_cst_SdkEvent_BalanceChanged;
+
+typedef struct wire_cst_SdkEvent_SyncProgress {
+  uint32_t step;
+  uint32_t total;
+} wire_cst_SdkEvent_SyncProgress;
+
+typedef struct wire_cst_SdkEvent_ConnectivityChanged {
+  bool is_connected;
+} wire_cst_SdkEvent_ConnectivityChanged;
+
+typedef struct wire_cst_SdkEvent_NewBlock {
+  int32_t chain;
+  uint32_t height;
+} wire_cst_SdkEvent_NewBlock;
+
 typedef union SdkEventKind {
   struct wire_cst_SdkEvent_PaymentFailed PaymentFailed;
   struct wire_cst_SdkEvent_PaymentPending PaymentPending;
+  struct wire_cst_SdkEvent_PaymentQueued PaymentQueued;
   struct wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
   struct wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
   struct wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
   struct wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+  struct wire_cst_SdkEvent_SwapNearingTimeout SwapNearingTimeout;
+  struct wire_cst_SdkEvent_SwapRefundable SwapRefundable;
+  struct wire_cst_SdkEvent_SwapStateChanged SwapStateChanged;
+  struct wire_cst_SdkEvent_BalanceChanged BalanceChanged;
+  struct wire_cst_SdkEvent_SyncProgress SyncProgress;
+  struct wire_cst_SdkEvent_ConnectivityChanged ConnectivityChanged;
+  struct wire_cst_SdkEvent_NewBlock NewBlock;
 } SdkEventKind;
 
 typedef struct wire_cst_sdk_event {
@@ -485,9 +594,61 @@ typedef struct wire_cst_sdk_event {
   union SdkEventKind kind;
 } wire_cst_sdk_event;
 
+typedef struct wire_cst_ChainBackend_Electrum {
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_ChainBackend_Electrum;
+
+typedef struct wire_cst_ChainBackend_Esplora {
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_ChainBackend_Esplora;
+
+typedef struct wire_cst_ChainBackend_Hybrid {
+  struct wire_cst_list_prim_u_8_strict *electrum_url;
+  struct wire_cst_list_prim_u_8_strict *esplora_url;
+} wire_cst_ChainBackend_Hybrid;
+
+typedef struct wire_cst_ChainBackend_Rpc {
+  struct wire_cst_list_prim_u_8_strict *url;
+  struct wire_cst_list_prim_u_8_strict *username;
+  struct wire_cst_list_prim_u_8_strict *password;
+} wire_cst_ChainBackend_Rpc;
+
+typedef union ChainBackendKind {
+  struct wire_cst_ChainBackend_Electrum Electrum;
+  struct wire_cst_ChainBackend_Esplora Esplora;
+  struct wire_cst_ChainBackend_Hybrid Hybrid;
+  struct wire_cst_ChainBackend_Rpc Rpc;
+} ChainBackendKind;
+
+typedef struct wire_cst_chain_backend {
+  int32_t tag;
+  union ChainBackendKind kind;
+} wire_cst_chain_backend;
+
+typedef struct wire_cst_list_chain_backend {
+  struct wire_cst_chain_backend *ptr;
+  int32_t len;
+} wire_cst_list_chain_backend;
+
+typedef struct wire_cst_scheduler_intervals {
+  uint64_t sync_secs;
+  uint64_t refunds_secs;
+  uint64_t liquid_rescan_secs;
+  uint64_t bitcoin_rescan_secs;
+  uint64_t new_blocks_secs;
+} wire_cst_scheduler_intervals;
+
 typedef struct wire_cst_config {
-  struct wire_cst_list_prim_u_8_strict *liquid_electrum_url;
-  struct wire_cst_list_prim_u_8_strict *bitcoin_electrum_url;
+  struct wire_cst_chain_backend liquid_chain_backend;
+  struct wire_cst_chain_backend bitcoin_chain_backend;
+  struct wire_cst_list_chain_backend *liquid_fallback_chain_backends;
+  struct wire_cst_list_chain_backend *bitcoin_fallback_chain_backends;
+  uint64_t *chain_quorum_min_amount_sat;
+  struct wire_cst_list_prim_u_8_strict *swapper_url;
+  struct wire_cst_list_prim_u_8_strict *swapper_ws_url;
+  struct wire_cst_list_prim_u_8_strict *swapper_referral_id;
+  struct wire_cst_list_prim_u_8_strict *lbtc_asset_id;
+  struct wire_cst_list_prim_u_8_strict *liquid_genesis_hash;
   struct wire_cst_list_prim_u_8_strict *mempoolspace_url;
   struct wire_cst_list_prim_u_8_strict *working_dir;
   struct wire_cst_list_prim_u_8_strict *cache_dir;
@@ -496,6 +657,9 @@ typedef struct wire_cst_config {
   uint32_t zero_conf_min_fee_rate_msat;
   uint64_t *zero_conf_max_amount_sat;
   struct wire_cst_list_prim_u_8_strict *breez_api_key;
+  struct wire_cst_list_String *fiat_rate_history_currencies;
+  struct wire_cst_list_String *event_webhook_urls;
+  struct wire_cst_scheduler_intervals *scheduler_intervals;
 } wire_cst_config;
 
 typedef struct wire_cst_connect_request {
@@ -503,6 +667,118 @@ typedef struct wire_cst_connect_request {
   struct wire_cst_list_prim_u_8_strict *mnemonic;
 } wire_cst_connect_request;
 
+typedef struct wire_cst_PaymentError_AmountMissing {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_AmountMissing;
+
+typedef struct wire_cst_PaymentError_InvalidNetwork {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_InvalidNetwork;
+
+typedef struct wire_cst_PaymentError_Generic {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_Generic;
+
+typedef struct wire_cst_PaymentError_InvalidDescription {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_InvalidDescription;
+
+typedef struct wire_cst_PaymentError_InvalidInvoice {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_InvalidInvoice;
+
+typedef struct wire_cst_PaymentError_LwkError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_LwkError;
+
+typedef struct wire_cst_PaymentError_ReceiveError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_ReceiveError;
+
+typedef struct wire_cst_PaymentError_Refunded {
+  struct wire_cst_list_prim_u_8_strict *err;
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+} wire_cst_PaymentError_Refunded;
+
+typedef struct wire_cst_PaymentError_SendError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_SendError;
+
+typedef struct wire_cst_PaymentError_SignerError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_SignerError;
+
+typedef struct wire_cst_PaymentError_Swapper {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_Swapper;
+
+typedef struct wire_cst_PaymentError_ChainService {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_ChainService;
+
+typedef union PaymentErrorKind {
+  struct wire_cst_PaymentError_AmountMissing AmountMissing;
+  struct wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
+  struct wire_cst_PaymentError_Generic Generic;
+  struct wire_cst_PaymentError_InvalidDescription InvalidDescription;
+  struct wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
+  struct wire_cst_PaymentError_LwkError LwkError;
+  struct wire_cst_PaymentError_ReceiveError ReceiveError;
+  struct wire_cst_PaymentError_Refunded Refunded;
+  struct wire_cst_PaymentError_SendError SendError;
+  struct wire_cst_PaymentError_SignerError SignerError;
+  struct wire_cst_PaymentError_Swapper Swapper;
+  struct wire_cst_PaymentError_ChainService ChainService;
+} PaymentErrorKind;
+
+typedef struct wire_cst_payment_error {
+  int32_t tag;
+  union PaymentErrorKind kind;
+} wire_cst_payment_error;
+
+typedef struct wire_cst_SdkError_Generic {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_Generic;
+
+typedef struct wire_cst_SdkError_ServiceConnectivity {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_ServiceConnectivity;
+
+typedef struct wire_cst_SdkError_Swapper {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_Swapper;
+
+typedef struct wire_cst_SdkError_ChainService {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_ChainService;
+
+typedef struct wire_cst_SdkError_Persistence {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_Persistence;
+
+typedef struct wire_cst_SdkError_Signer {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_Signer;
+
+typedef struct wire_cst_SdkError_Validation {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_Validation;
+
+typedef union SdkErrorKind {
+  struct wire_cst_SdkError_Generic Generic;
+  struct wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
+  struct wire_cst_SdkError_Swapper Swapper;
+  struct wire_cst_SdkError_ChainService ChainService;
+  struct wire_cst_SdkError_Persistence Persistence;
+  struct wire_cst_SdkError_Signer Signer;
+  struct wire_cst_SdkError_Validation Validation;
+} SdkErrorKind;
+
+typedef struct wire_cst_sdk_error {
+  int32_t tag;
+  union SdkErrorKind kind;
+} wire_cst_sdk_error;
+
 typedef struct wire_cst_aes_success_action_data_decrypted {
   struct wire_cst_list_prim_u_8_strict *description;
   struct wire_cst_list_prim_u_8_strict *plaintext;
@@ -575,6 +851,11 @@ typedef struct wire_cst_ln_url_withdraw_success_data {
   struct wire_cst_ln_invoice invoice;
 } wire_cst_ln_url_withdraw_success_data;
 
+typedef struct wire_cst_rate {
+  struct wire_cst_list_prim_u_8_strict *coin;
+  double value;
+} wire_cst_rate;
+
 typedef struct wire_cst_symbol {
   struct wire_cst_list_prim_u_8_strict *grapheme;
   struct wire_cst_list_prim_u_8_strict *template_;
@@ -582,6 +863,26 @@ typedef struct wire_cst_symbol {
   uint32_t *position;
 } wire_cst_symbol;
 
+typedef struct wire_cst_sync_metrics {
+  uint32_t started_at;
+  uint64_t duration_ms;
+  uint64_t scan_duration_ms;
+  uint32_t *liquid_tip;
+  uint32_t total_tx_count;
+  uint32_t processed_tx_count;
+} wire_cst_sync_metrics;
+
+typedef struct wire_cst_event_log_entry {
+  uint64_t seq;
+  uint32_t created_at;
+  struct wire_cst_sdk_event event;
+} wire_cst_event_log_entry;
+
+typedef struct wire_cst_list_event_log_entry {
+  struct wire_cst_event_log_entry *ptr;
+  int32_t len;
+} wire_cst_list_event_log_entry;
+
 typedef struct wire_cst_localized_name {
   struct wire_cst_list_prim_u_8_strict *locale;
   struct wire_cst_list_prim_u_8_strict *name;
@@ -623,16 +924,21 @@ typedef struct wire_cst_list_fiat_currency {
   int32_t len;
 } wire_cst_list_fiat_currency;
 
+typedef struct wire_cst_log_field {
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_log_field;
+
+typedef struct wire_cst_list_log_field {
+  struct wire_cst_log_field *ptr;
+  int32_t len;
+} wire_cst_list_log_field;
+
 typedef struct wire_cst_list_payment {
   struct wire_cst_payment *ptr;
   int32_t len;
 } wire_cst_list_payment;
 
-typedef struct wire_cst_rate {
-  struct wire_cst_list_prim_u_8_strict *coin;
-  double value;
-} wire_cst_rate;
-
 typedef struct wire_cst_list_rate {
   struct wire_cst_rate *ptr;
   int32_t len;
@@ -642,6 +948,7 @@ typedef struct wire_cst_refundable_swap {
   struct wire_cst_list_prim_u_8_strict *swap_address;
   uint32_t timestamp;
   uint64_t amount_sat;
+  uint64_t *time_to_refundable;
 } wire_cst_refundable_swap;
 
 typedef struct wire_cst_list_refundable_swap {
@@ -649,10 +956,51 @@ typedef struct wire_cst_list_refundable_swap {
   int32_t len;
 } wire_cst_list_refundable_swap;
 
+typedef struct wire_cst_swap_state_transition {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  int32_t from_state;
+  int32_t to_state;
+  struct wire_cst_list_prim_u_8_strict *swapper_status;
+  struct wire_cst_list_String *tx_ids;
+  uint32_t created_at;
+} wire_cst_swap_state_transition;
+
+typedef struct wire_cst_list_swap_state_transition {
+  struct wire_cst_swap_state_transition *ptr;
+  int32_t len;
+} wire_cst_list_swap_state_transition;
+
+typedef struct wire_cst_list_sync_task {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_sync_task;
+
+typedef struct wire_cst_webhook_delivery {
+  uint64_t id;
+  struct wire_cst_list_prim_u_8_strict *url;
+  uint64_t event_seq;
+  struct wire_cst_list_prim_u_8_strict *payload;
+  int32_t state;
+  uint32_t attempts;
+  uint32_t next_attempt_at;
+  struct wire_cst_list_prim_u_8_strict *last_error;
+  uint32_t created_at;
+} wire_cst_webhook_delivery;
+
+typedef struct wire_cst_list_webhook_delivery {
+  struct wire_cst_webhook_delivery *ptr;
+  int32_t len;
+} wire_cst_list_webhook_delivery;
+
 typedef struct wire_cst_check_message_response {
   bool is_valid;
 } wire_cst_check_message_response;
 
+typedef struct wire_cst_error_info {
+  uint32_t code;
+  bool retryable;
+} wire_cst_error_info;
+
 typedef struct wire_cst_get_info_response {
   uint64_t balance_sat;
   uint64_t pending_send_sat;
@@ -728,6 +1076,7 @@ typedef struct wire_cst_limits {
 typedef struct wire_cst_lightning_payment_limits_response {
   struct wire_cst_limits send;
   struct wire_cst_limits receive;
+  uint64_t *cache_age_secs;
 } wire_cst_lightning_payment_limits_response;
 
 typedef struct wire_cst_LnUrlAuthError_Generic {
@@ -916,76 +1265,26 @@ typedef struct wire_cst_ln_url_withdraw_result {
 typedef struct wire_cst_log_entry {
   struct wire_cst_list_prim_u_8_strict *line;
   struct wire_cst_list_prim_u_8_strict *level;
+  struct wire_cst_list_prim_u_8_strict *target;
+  struct wire_cst_list_prim_u_8_strict *module;
+  uint64_t timestamp;
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *payment_id;
+  struct wire_cst_list_log_field *fields;
 } wire_cst_log_entry;
 
 typedef struct wire_cst_onchain_payment_limits_response {
   struct wire_cst_limits send;
   struct wire_cst_limits receive;
+  uint64_t *cache_age_secs;
 } wire_cst_onchain_payment_limits_response;
 
-typedef struct wire_cst_PaymentError_AmountMissing {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_AmountMissing;
-
-typedef struct wire_cst_PaymentError_InvalidNetwork {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_InvalidNetwork;
-
-typedef struct wire_cst_PaymentError_Generic {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_Generic;
-
-typedef struct wire_cst_PaymentError_InvalidDescription {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_InvalidDescription;
-
-typedef struct wire_cst_PaymentError_InvalidInvoice {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_InvalidInvoice;
-
-typedef struct wire_cst_PaymentError_LwkError {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_LwkError;
-
-typedef struct wire_cst_PaymentError_ReceiveError {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_ReceiveError;
-
-typedef struct wire_cst_PaymentError_Refunded {
-  struct wire_cst_list_prim_u_8_strict *err;
-  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
-} wire_cst_PaymentError_Refunded;
-
-typedef struct wire_cst_PaymentError_SendError {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_SendError;
-
-typedef struct wire_cst_PaymentError_SignerError {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_PaymentError_SignerError;
-
-typedef union PaymentErrorKind {
-  struct wire_cst_PaymentError_AmountMissing AmountMissing;
-  struct wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
-  struct wire_cst_PaymentError_Generic Generic;
-  struct wire_cst_PaymentError_InvalidDescription InvalidDescription;
-  struct wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
-  struct wire_cst_PaymentError_LwkError LwkError;
-  struct wire_cst_PaymentError_ReceiveError ReceiveError;
-  struct wire_cst_PaymentError_Refunded Refunded;
-  struct wire_cst_PaymentError_SendError SendError;
-  struct wire_cst_PaymentError_SignerError SignerError;
-} PaymentErrorKind;
-
-typedef struct wire_cst_payment_error {
-  int32_t tag;
-  union PaymentErrorKind kind;
-} wire_cst_payment_error;
-
 typedef struct wire_cst_prepare_refund_response {
   uint32_t tx_vsize;
   uint64_t tx_fee_sat;
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+  bool is_cooperative;
+  uint64_t fee_saved_sat;
 } wire_cst_prepare_refund_response;
 
 typedef struct wire_cst_receive_payment_response {
@@ -1004,24 +1303,6 @@ typedef struct wire_cst_refund_response {
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
 } wire_cst_refund_response;
 
-typedef struct wire_cst_SdkError_Generic {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_SdkError_Generic;
-
-typedef struct wire_cst_SdkError_ServiceConnectivity {
-  struct wire_cst_list_prim_u_8_strict *err;
-} wire_cst_SdkError_ServiceConnectivity;
-
-typedef union SdkErrorKind {
-  struct wire_cst_SdkError_Generic Generic;
-  struct wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
-} SdkErrorKind;
-
-typedef struct wire_cst_sdk_error {
-  int32_t tag;
-  union SdkErrorKind kind;
-} wire_cst_sdk_error;
-
 typedef struct wire_cst_send_payment_response {
   struct wire_cst_payment payment;
 } wire_cst_send_payment_response;
@@ -1030,6 +1311,15 @@ typedef struct wire_cst_sign_message_response {
   struct wire_cst_list_prim_u_8_strict *signature;
 } wire_cst_sign_message_response;
 
+typedef struct wire_cst_sync_once_response {
+  struct wire_cst_list_sync_task *pending_tasks;
+  uint32_t pending_swap_count;
+} wire_cst_sync_once_response;
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events(uintptr_t that,
+                                                                                            struct wire_cst_list_prim_u_8_strict *listener_id,
+                                                                                            uint64_t seq);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(int64_t port_,
                                                                                     uintptr_t that,
                                                                                     struct wire_cst_list_prim_u_8_strict *listener);
@@ -1049,6 +1339,12 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(int6
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(uintptr_t that);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since(uintptr_t that,
+                                                                                              uint64_t seq);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file(uintptr_t that,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *swap_id);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(int64_t port_,
                                                                                   uintptr_t that);
 
@@ -1058,13 +1354,46 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits(int64_t port_,
                                                                                       uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap(int64_t port_,
+                                                                                  uintptr_t that,
+                                                                                  struct wire_cst_list_prim_u_8_strict *swap_id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(int64_t port_,
+                                                                                        uintptr_t that,
+                                                                                        struct wire_cst_list_prim_u_8_strict *swap_id,
+                                                                                        bool is_cooperative);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(int64_t port_,
+                                                                                      uintptr_t that);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor(uintptr_t that,
+                                                                                                  struct wire_cst_list_prim_u_8_strict *listener_id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(int64_t port_,
+                                                                                          uintptr_t that,
+                                                                                          struct wire_cst_list_prim_u_8_strict *currency,
+                                                                                          uint32_t timestamp);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(int64_t port_,
                                                                           uintptr_t that);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(int64_t port_,
                                                                              uintptr_t that,
                                                                              struct wire_cst_get_payment_request *req);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history(uintptr_t that,
+                                                                                                  struct wire_cst_list_prim_u_8_strict *swap_id);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap(int64_t port_,
+                                                                             uintptr_t that,
+                                                                             struct wire_cst_list_prim_u_8_strict *rescue_json);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(int64_t port_,
                                                                                       uintptr_t that);
 
@@ -1087,6 +1416,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
                                                                                 uintptr_t that,
                                                                                 struct wire_cst_ln_url_withdraw_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(int64_t port_,
+                                                                                  uintptr_t that,
+                                                                                  struct wire_cst_list_prim_u_8_strict *swap_id);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(int64_t port_,
                                                                              uintptr_t that,
                                                                              struct wire_cst_pay_onchain_request *req);
@@ -1122,6 +1455,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_fees(int64_t port_,
                                                                                   uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(int64_t port_,
+                                                                                     uintptr_t that,
+                                                                                     struct wire_cst_list_prim_u_8_strict *swap_id);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(int64_t port_,
                                                                         uintptr_t that,
                                                                         struct wire_cst_refund_request *req);
@@ -1136,6 +1473,9 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(uintptr_t that,
                                                                                          struct wire_cst_restore_request *req);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(uintptr_t that,
+                                                                                                        uint64_t id);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(int64_t port_,
                                                                               uintptr_t that,
                                                                               struct wire_cst_send_payment_request *req);
@@ -1146,6 +1486,10 @@ WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(int64_t port_,
                                                                       uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once(int64_t port_,
+                                                                           uintptr_t that,
+                                                                           struct wire_cst_sync_once_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook(int64_t port_,
                                                                                     uintptr_t that);
 
@@ -1167,6 +1511,10 @@ void frbgen_breez_liquid_wire__crate__bindings__parse(int64_t port_,
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__parse_invoice(struct wire_cst_list_prim_u_8_strict *input);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__payment_error_info(struct wire_cst_payment_error *err);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__sdk_error_info(struct wire_cst_sdk_error *err);
+
 void frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
 void frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
@@ -1193,6 +1541,8 @@ struct wire_cst_check_message_request *frbgen_breez_liquid_cst_new_box_autoadd_c
 
 struct wire_cst_connect_request *frbgen_breez_liquid_cst_new_box_autoadd_connect_request(void);
 
+struct wire_cst_fiat_amount *frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount(void);
+
 struct wire_cst_get_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request(void);
 
 int64_t *frbgen_breez_liquid_cst_new_box_autoadd_i_64(int64_t value);
@@ -1233,6 +1583,10 @@ struct wire_cst_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_pay
 
 struct wire_cst_payment *frbgen_breez_liquid_cst_new_box_autoadd_payment(void);
 
+struct wire_cst_payment_error *frbgen_breez_liquid_cst_new_box_autoadd_payment_error(void);
+
+struct wire_cst_payment_fiat_rate *frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate(void);
+
 struct wire_cst_prepare_buy_bitcoin_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request(void);
 
 struct wire_cst_prepare_ln_url_pay_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request(void);
@@ -1245,12 +1599,18 @@ struct wire_cst_prepare_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_
 
 struct wire_cst_prepare_send_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(void);
 
+struct wire_cst_rate *frbgen_breez_liquid_cst_new_box_autoadd_rate(void);
+
 struct wire_cst_receive_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request(void);
 
 struct wire_cst_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_refund_request(void);
 
 struct wire_cst_restore_request *frbgen_breez_liquid_cst_new_box_autoadd_restore_request(void);
 
+struct wire_cst_scheduler_intervals *frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals(void);
+
+struct wire_cst_sdk_error *frbgen_breez_liquid_cst_new_box_autoadd_sdk_error(void);
+
 struct wire_cst_sdk_event *frbgen_breez_liquid_cst_new_box_autoadd_sdk_event(void);
 
 struct wire_cst_send_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_send_payment_request(void);
@@ -1263,6 +1623,10 @@ struct wire_cst_success_action_processed *frbgen_breez_liquid_cst_new_box_autoad
 
 struct wire_cst_symbol *frbgen_breez_liquid_cst_new_box_autoadd_symbol(void);
 
+struct wire_cst_sync_metrics *frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics(void);
+
+struct wire_cst_sync_once_request *frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request(void);
+
 uint32_t *frbgen_breez_liquid_cst_new_box_autoadd_u_32(uint32_t value);
 
 uint64_t *frbgen_breez_liquid_cst_new_box_autoadd_u_64(uint64_t value);
@@ -1271,6 +1635,10 @@ struct wire_cst_url_success_action_data *frbgen_breez_liquid_cst_new_box_autoadd
 
 struct wire_cst_list_String *frbgen_breez_liquid_cst_new_list_String(int32_t len);
 
+struct wire_cst_list_chain_backend *frbgen_breez_liquid_cst_new_list_chain_backend(int32_t len);
+
+struct wire_cst_list_event_log_entry *frbgen_breez_liquid_cst_new_list_event_log_entry(int32_t len);
+
 struct wire_cst_list_fiat_currency *frbgen_breez_liquid_cst_new_list_fiat_currency(int32_t len);
 
 struct wire_cst_list_ln_offer_blinded_path *frbgen_breez_liquid_cst_new_list_ln_offer_blinded_path(int32_t len);
@@ -1279,6 +1647,8 @@ struct wire_cst_list_locale_overrides *frbgen_breez_liquid_cst_new_list_locale_o
 
 struct wire_cst_list_localized_name *frbgen_breez_liquid_cst_new_list_localized_name(int32_t len);
 
+struct wire_cst_list_log_field *frbgen_breez_liquid_cst_new_list_log_field(int32_t len);
+
 struct wire_cst_list_payment *frbgen_breez_liquid_cst_new_list_payment(int32_t len);
 
 struct wire_cst_list_payment_type *frbgen_breez_liquid_cst_new_list_payment_type(int32_t len);
@@ -1292,6 +1662,12 @@ struct wire_cst_list_refundable_swap *frbgen_breez_liquid_cst_new_list_refundabl
 struct wire_cst_list_route_hint *frbgen_breez_liquid_cst_new_list_route_hint(int32_t len);
 
 struct wire_cst_list_route_hint_hop *frbgen_breez_liquid_cst_new_list_route_hint_hop(int32_t len);
+
+struct wire_cst_list_swap_state_transition *frbgen_breez_liquid_cst_new_list_swap_state_transition(int32_t len);
+
+struct wire_cst_list_sync_task *frbgen_breez_liquid_cst_new_list_sync_task(int32_t len);
+
+struct wire_cst_list_webhook_delivery *frbgen_breez_liquid_cst_new_list_webhook_delivery(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data);
@@ -1305,6 +1681,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_check_message_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_connect_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_i_64);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data);
@@ -1325,29 +1702,39 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_amount);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment_error);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_rate);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_refund_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_restore_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sdk_error);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sdk_event);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_send_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sign_message_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_symbol);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_64);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_chain_backend);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_event_log_entry);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_fiat_currency);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_ln_offer_blinded_path);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_locale_overrides);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_localized_name);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_log_field);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_type);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_prim_u_8_strict);
@@ -1355,25 +1742,42 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_refundable_swap);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint_hop);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_swap_state_transition);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_sync_task);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_webhook_delivery);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_message);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_auth);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay);
@@ -1383,13 +1787,16 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_fees);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_webhook);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_message);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__breez_log_stream);
@@ -1397,6 +1804,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__default_config);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse_invoice);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__payment_error_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__sdk_error_info);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
 }
@@ -15,6 +15,8 @@ static IGNORED_FUNCTIONS: Lazy<HashSet<String>> = Lazy::new(|| {
         "add_event_listener",
         "set_logger",
         "connect_with_signer",
+        "sdk_error_info",
+        "payment_error_info",
     ];
     HashSet::from_iter(list.into_iter().map(|s| s.to_string()))
 });
@@ -52,7 +52,7 @@ fun as{{ type_name }}List(arr: ReadableArray): List<{{ type_name }}> {
 {%- else %}
             is ReadableMap -> list.add(as{{ type_name }}(value)!!)            
 {%- endif %}
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
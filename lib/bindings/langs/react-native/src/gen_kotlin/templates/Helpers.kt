@@ -26,7 +26,7 @@ fun pushToArray(array: WritableArray, value: Any?) {
 	    {%- endfor %}
         is Array<*> -> array.pushArray(readableArrayOf(value.asIterable()))
         is List<*> -> array.pushArray(readableArrayOf(value))
-        else -> throw SdkException.Generic(errUnexpectedType(value))
+        else -> throw SdkException.Validation(errUnexpectedType(value))
     }
 }
 
@@ -48,7 +48,7 @@ fun pushToMap(map: WritableMap, key: String, value: Any?) {
         is ULong -> map.putDouble(key, value.toDouble())
         is Array<*> -> map.putArray(key, readableArrayOf(value.asIterable()))
         is List<*> -> map.putArray(key, readableArrayOf(value))
-        else -> throw SdkException.Generic("Unexpected type ${value::class.java.name} for key [$key]")
+        else -> throw SdkException.Validation("Unexpected type ${value::class.java.name} for key [$key]")
     }
 }
 
@@ -70,7 +70,7 @@ fun asUByteList(arr: ReadableArray): List<UByte> {
             is Double -> list.add(value.toInt().toUByte())
             is Int -> list.add(value.toUByte())
             is UByte -> list.add(value)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -112,7 +112,7 @@ internal fun ReadableArray.toList(): List<*> {
             ReadableType.String -> arrayList.add(getString(i))
             ReadableType.Map -> arrayList.add(getMap(i))
             ReadableType.Array -> arrayList.add(getArray(i))
-            else -> throw SdkException.Generic("Could not convert object at index: $i")
+            else -> throw SdkException.Validation("Could not convert object at index: $i")
         }
     }
     return arrayList
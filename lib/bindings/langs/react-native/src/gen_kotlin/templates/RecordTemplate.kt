@@ -43,7 +43,7 @@ fun as{{ type_name }}List(arr: ReadableArray): List<{{ type_name }}> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(as{{ type_name }}(value)!!)            
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
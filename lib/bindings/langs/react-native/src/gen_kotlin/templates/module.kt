@@ -33,7 +33,7 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
             return bindingLiquidSdk!!
         }
 
-        throw SdkException.Generic("Not initialized")
+        throw SdkException.NotStarted()
     }
 
     @Throws(SdkException::class)
@@ -42,13 +42,31 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
             val workingDirFile = File(workingDir)
 
             if (!workingDirFile.exists() && !workingDirFile.mkdirs()) {
-                throw SdkException.Generic("Mandatory field workingDir must contain a writable directory")
+                throw SdkException.Validation("Mandatory field workingDir must contain a writable directory")
             }
         } catch (e: SecurityException) {
-            throw SdkException.Generic("Mandatory field workingDir must contain a writable directory")
+            throw SdkException.Validation("Mandatory field workingDir must contain a writable directory")
         }
     }
 
+    private fun rejectErr(
+        e: Exception,
+        promise: Promise,
+    ) {
+        val info =
+            when (e) {
+                is SdkException -> sdkErrorInfo(e)
+                is PaymentException -> paymentErrorInfo(e)
+                else -> null
+            }
+        val userInfo = Arguments.createMap()
+        info?.let {
+            userInfo.putInt("code", it.code.toInt())
+            userInfo.putBoolean("retryable", it.retryable)
+        }
+        promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e, userInfo)
+    }
+
     @ReactMethod
     fun addListener(eventName: String) {}
 
@@ -71,7 +89,7 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 e.printStackTrace()
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -79,20 +97,20 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
     @ReactMethod
     fun connect(req: ReadableMap, promise: Promise) {
         if (bindingLiquidSdk != null) {
-            promise.reject("Generic", "Already initialized")
+            rejectErr(SdkException.AlreadyStarted(), promise)
             return
         }
 
         executor.execute {
             try {
-                var connectRequest = asConnectRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ConnectRequest")) }
+                var connectRequest = asConnectRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "ConnectRequest")) }
 
                 ensureWorkingDir(connectRequest.config.workingDir)
 
                 bindingLiquidSdk = connect(connectRequest)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -108,7 +126,7 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
                 eventListener.setId(res)
                 promise.resolve(res)
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
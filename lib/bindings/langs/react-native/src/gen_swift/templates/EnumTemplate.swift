@@ -10,7 +10,7 @@ static func as{{ type_name }}({{ type_name|var_name|unquote }}: String) throws -
 
     {%- endfor %}
     
-    default: throw SdkError.Generic(message: "Invalid variant \({{ type_name|var_name|unquote }}) for enum {{ type_name }}")
+    default: throw SdkError.Validation(err: "Invalid variant \({{ type_name|var_name|unquote }}) for enum {{ type_name }}")
     }
 }
 
@@ -52,11 +52,11 @@ static func as{{ type_name }}({{ type_name|var_name|unquote }}: [String: Any?])
                 {%- else %}
                 {% if field.type_()|inline_optional_field(ci) -%}
                 guard let _{{field.name()|var_name|unquote}} = {{ type_name|var_name|unquote }}["{{field.name()|var_name|unquote}}"] as? {{field.type_()|rn_type_name(ci, true)}} else {
-                    throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "{{field.name()|var_name|unquote}}", typeName: "{{ type_name }}"))
+                    throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "{{field.name()|var_name|unquote}}", typeName: "{{ type_name }}"))
                 }
                 {%- else -%}
                 guard let {{field.name()|var_name|unquote|temporary}} = {{ type_name|var_name|unquote }}["{{field.name()|var_name|unquote}}"] as? {{field.type_()|rn_type_name(ci, true)}} else {
-                    throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "{{field.name()|var_name|unquote}}", typeName: "{{ type_name }}"))
+                    throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "{{field.name()|var_name|unquote}}", typeName: "{{ type_name }}"))
                 }
             let _{{field.name()|var_name|unquote}} = {{field.type_()|render_from_map(ci, field.name()|var_name|unquote|temporary)}}
             {% endif -%}        
@@ -69,7 +69,7 @@ static func as{{ type_name }}({{ type_name|var_name|unquote }}: [String: Any?])
         }        
     {%- endfor %}    
 
-    throw SdkError.Generic(message: "Unexpected type \(type) for enum {{ type_name }}")
+    throw SdkError.Validation(err: "Unexpected type \(type) for enum {{ type_name }}")
 }
 
 static func dictionaryOf({{ type_name|var_name|unquote }}: {{ type_name }}) -> [String: Any?] {    
@@ -109,7 +109,7 @@ static func as{{ type_name }}List(arr: [Any]) throws -> [{{ type_name }}] {
             var {{ type_name|var_name|unquote }} = try as{{ type_name }}({{ type_name|var_name|unquote }}: val)
             list.append({{ type_name|var_name|unquote }})
         } else { 
-            throw SdkError.Generic(message: errUnexpectedType(typeName: "{{ type_name }}"))
+            throw SdkError.Validation(err: errUnexpectedType(typeName: "{{ type_name }}"))
         }
     }
     return list
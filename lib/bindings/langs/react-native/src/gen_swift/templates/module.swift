@@ -59,7 +59,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
             return bindingLiquidSdk
         }
         
-        throw SdkError.Generic(message: "Not initialized")
+        throw SdkError.NotStarted
     }
         
     private func ensureWorkingDir(workingDir: String) throws {
@@ -68,7 +68,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
                 try FileManager.default.createDirectory(atPath: workingDir, withIntermediateDirectories: true)
             }
         } catch {
-            throw SdkError.Generic(message: "Mandatory field workingDir must contain a writable directory")
+            throw SdkError.Validation(err: "Mandatory field workingDir must contain a writable directory")
         }
     }
 
@@ -91,7 +91,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
     @objc(connect:resolve:reject:)
     func connect(_ req:[String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) -> Void {
         if bindingLiquidSdk != nil {
-            reject("Generic", "Already initialized", nil)
+            rejectErr(err: SdkError.AlreadyStarted, reject: reject)
             return
         }
 
@@ -129,7 +129,18 @@ class RNBreezSDKLiquid: RCTEventEmitter {
                 message = associatedMessage.value as! String
             }
         }
-        reject(errorName, message, err)
+        var info: ErrorInfo?
+        if let sdkErr = err as? SdkError {
+            info = sdkErrorInfo(err: sdkErr)
+        } else if let paymentErr = err as? PaymentError {
+            info = paymentErrorInfo(err: paymentErr)
+        }
+        var userInfo: [String: Any] = [NSLocalizedDescriptionKey: message]
+        if let info = info {
+            userInfo["code"] = info.code
+            userInfo["retryable"] = info.retryable
+        }
+        reject(errorName, message, NSError(domain: RNBreezSDKLiquid.TAG, code: Int(info?.code ?? 0), userInfo: userInfo))
     }
 }
 
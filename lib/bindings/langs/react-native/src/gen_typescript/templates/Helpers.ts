@@ -23,4 +23,16 @@ export const setLogger = async (logger: Logger): Promise<EmitterSubscription> =>
     } catch {}
 
     return subscription
+}
+
+/**
+ * Returns the stable code and retryable flag of an error thrown by the SDK, which are
+ * attached to the rejected promise's `userInfo`.
+ */
+export const errorInfo = (err: unknown): ErrorInfo | undefined => {
+    const userInfo = (err as { userInfo?: Partial<ErrorInfo> } | undefined)?.userInfo
+    if (typeof userInfo?.code !== "number" || typeof userInfo?.retryable !== "boolean") {
+        return undefined
+    }
+    return { code: userInfo.code, retryable: userInfo.retryable }
 }
\ No newline at end of file
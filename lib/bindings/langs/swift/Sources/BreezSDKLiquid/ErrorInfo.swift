@@ -0,0 +1,15 @@
+import Foundation
+
+extension SdkError {
+    /// The stable code and retryable flag of this error
+    public var info: ErrorInfo {
+        return sdkErrorInfo(err: self)
+    }
+}
+
+extension PaymentError {
+    /// The stable code and retryable flag of this error
+    public var info: ErrorInfo {
+        return paymentErrorInfo(err: self)
+    }
+}
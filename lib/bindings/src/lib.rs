@@ -34,10 +34,7 @@ impl log::Log for UniffiBindingLogger {
     }
 
     fn log(&self, record: &Record) {
-        self.logger.log(LogEntry {
-            line: record.args().to_string(),
-            level: record.level().as_str().to_string(),
-        });
+        self.logger.log(LogEntry::from(record));
     }
     fn flush(&self) {}
 }
@@ -78,6 +75,14 @@ pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
 
+pub fn sdk_error_info(err: SdkError) -> ErrorInfo {
+    err.info()
+}
+
+pub fn payment_error_info(err: PaymentError) -> ErrorInfo {
+    err.info()
+}
+
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
 }
@@ -91,6 +96,30 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.remove_event_listener(id))
     }
 
+    pub fn events_since(&self, seq: u64) -> SdkResult<Vec<EventLogEntry>> {
+        self.sdk.events_since(seq)
+    }
+
+    pub fn ack_events(&self, listener_id: String, seq: u64) -> SdkResult<()> {
+        self.sdk.ack_events(&listener_id, seq)
+    }
+
+    pub fn get_event_cursor(&self, listener_id: String) -> SdkResult<Option<u64>> {
+        self.sdk.get_event_cursor(&listener_id)
+    }
+
+    pub fn get_webhook_signing_secret(&self) -> SdkResult<String> {
+        self.sdk.get_webhook_signing_secret()
+    }
+
+    pub fn list_failed_webhook_deliveries(&self) -> SdkResult<Vec<WebhookDelivery>> {
+        self.sdk.list_failed_webhook_deliveries()
+    }
+
+    pub fn retry_webhook_delivery(&self, id: u64) -> SdkResult<()> {
+        self.sdk.retry_webhook_delivery(id)
+    }
+
     pub fn get_info(&self) -> Result<GetInfoResponse, SdkError> {
         rt().block_on(self.sdk.get_info()).map_err(Into::into)
     }
@@ -208,6 +237,14 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.fetch_fiat_rates())
     }
 
+    pub fn get_historical_fiat_rate(
+        &self,
+        currency: String,
+        timestamp: u32,
+    ) -> Result<Option<Rate>, SdkError> {
+        rt().block_on(self.sdk.get_historical_fiat_rate(&currency, timestamp))
+    }
+
     pub fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
         rt().block_on(self.sdk.list_fiat_currencies())
     }
@@ -228,10 +265,54 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.rescan_onchain_swaps())
     }
 
+    pub fn force_claim_swap(&self, swap_id: String) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.force_claim_swap(&swap_id))
+    }
+
+    pub fn force_refund_send_swap(
+        &self,
+        swap_id: String,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        rt().block_on(self.sdk.force_refund_send_swap(&swap_id, is_cooperative))
+    }
+
+    pub fn refetch_swap_status(&self, swap_id: String) -> SdkResult<()> {
+        rt().block_on(self.sdk.refetch_swap_status(&swap_id))
+    }
+
+    pub fn mark_swap_failed(&self, swap_id: String) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.mark_swap_failed(&swap_id))
+    }
+
+    pub fn import_swap(&self, rescue_json: String) -> SdkResult<()> {
+        rt().block_on(self.sdk.import_swap(&rescue_json))
+    }
+
+    pub fn export_rescue_file(&self, swap_id: String) -> SdkResult<String> {
+        self.sdk.export_rescue_file(&swap_id)
+    }
+
     pub fn sync(&self) -> SdkResult<()> {
         rt().block_on(self.sdk.sync()).map_err(Into::into)
     }
 
+    pub fn sync_once(&self, req: SyncOnceRequest) -> SdkResult<SyncOnceResponse> {
+        rt().block_on(self.sdk.sync_once(&req))
+    }
+
+    pub fn get_last_sync_metrics(&self) -> SdkResult<Option<SyncMetrics>> {
+        self.sdk.get_last_sync_metrics()
+    }
+
+    pub fn get_swap_history(&self, swap_id: String) -> SdkResult<Vec<SwapStateTransition>> {
+        self.sdk.get_swap_history(&swap_id)
+    }
+
+    pub fn generate_diagnostics(&self) -> SdkResult<String> {
+        rt().block_on(self.sdk.generate_diagnostics())
+    }
+
     pub fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
         rt().block_on(self.sdk.recommended_fees())
     }
@@ -38,10 +38,7 @@ impl log::Log for DartBindingLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            let _ = self.log_stream.add(LogEntry {
-                line: record.args().to_string(),
-                level: record.level().as_str().to_string(),
-            });
+            let _ = self.log_stream.add(LogEntry::from(record));
         }
     }
     fn flush(&self) {}
@@ -75,6 +72,16 @@ pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
 
+#[frb(sync)]
+pub fn sdk_error_info(err: SdkError) -> ErrorInfo {
+    err.info()
+}
+
+#[frb(sync)]
+pub fn payment_error_info(err: PaymentError) -> ErrorInfo {
+    err.info()
+}
+
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
 }
@@ -84,6 +91,36 @@ impl BindingLiquidSdk {
         self.sdk.get_info().await.map_err(Into::into)
     }
 
+    #[frb(sync)]
+    pub fn events_since(&self, seq: u64) -> Result<Vec<EventLogEntry>, SdkError> {
+        self.sdk.events_since(seq)
+    }
+
+    #[frb(sync)]
+    pub fn ack_events(&self, listener_id: String, seq: u64) -> Result<(), SdkError> {
+        self.sdk.ack_events(&listener_id, seq)
+    }
+
+    #[frb(sync)]
+    pub fn get_event_cursor(&self, listener_id: String) -> Result<Option<u64>, SdkError> {
+        self.sdk.get_event_cursor(&listener_id)
+    }
+
+    #[frb(sync)]
+    pub fn get_webhook_signing_secret(&self) -> Result<String, SdkError> {
+        self.sdk.get_webhook_signing_secret()
+    }
+
+    #[frb(sync)]
+    pub fn list_failed_webhook_deliveries(&self) -> Result<Vec<WebhookDelivery>, SdkError> {
+        self.sdk.list_failed_webhook_deliveries()
+    }
+
+    #[frb(sync)]
+    pub fn retry_webhook_delivery(&self, id: u64) -> Result<(), SdkError> {
+        self.sdk.retry_webhook_delivery(id)
+    }
+
     #[frb(sync)]
     pub fn sign_message(&self, req: SignMessageRequest) -> Result<SignMessageResponse, SdkError> {
         self.sdk.sign_message(&req)
@@ -231,6 +268,16 @@ impl BindingLiquidSdk {
         self.sdk.fetch_fiat_rates().await
     }
 
+    pub async fn get_historical_fiat_rate(
+        &self,
+        currency: String,
+        timestamp: u32,
+    ) -> Result<Option<Rate>, SdkError> {
+        self.sdk
+            .get_historical_fiat_rate(&currency, timestamp)
+            .await
+    }
+
     pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
         self.sdk.list_fiat_currencies().await
     }
@@ -254,11 +301,60 @@ impl BindingLiquidSdk {
         self.sdk.rescan_onchain_swaps().await
     }
 
+    pub async fn force_claim_swap(&self, swap_id: String) -> Result<(), PaymentError> {
+        self.sdk.force_claim_swap(&swap_id).await
+    }
+
+    pub async fn force_refund_send_swap(
+        &self,
+        swap_id: String,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        self.sdk
+            .force_refund_send_swap(&swap_id, is_cooperative)
+            .await
+    }
+
+    pub async fn refetch_swap_status(&self, swap_id: String) -> Result<(), SdkError> {
+        self.sdk.refetch_swap_status(&swap_id).await
+    }
+
+    pub async fn mark_swap_failed(&self, swap_id: String) -> Result<(), PaymentError> {
+        self.sdk.mark_swap_failed(&swap_id).await
+    }
+
+    pub async fn import_swap(&self, rescue_json: String) -> Result<(), SdkError> {
+        self.sdk.import_swap(&rescue_json).await
+    }
+
+    #[frb(sync)]
+    pub fn export_rescue_file(&self, swap_id: String) -> Result<String, SdkError> {
+        self.sdk.export_rescue_file(&swap_id)
+    }
+
     #[frb(name = "sync")]
     pub async fn sync(&self) -> Result<(), SdkError> {
         self.sdk.sync().await.map_err(Into::into)
     }
 
+    pub async fn sync_once(&self, req: SyncOnceRequest) -> Result<SyncOnceResponse, SdkError> {
+        self.sdk.sync_once(&req).await
+    }
+
+    #[frb(sync)]
+    pub fn get_last_sync_metrics(&self) -> Result<Option<SyncMetrics>, SdkError> {
+        self.sdk.get_last_sync_metrics()
+    }
+
+    #[frb(sync)]
+    pub fn get_swap_history(&self, swap_id: String) -> Result<Vec<SwapStateTransition>, SdkError> {
+        self.sdk.get_swap_history(&swap_id)
+    }
+
+    pub async fn generate_diagnostics(&self) -> Result<String, SdkError> {
+        self.sdk.generate_diagnostics().await
+    }
+
     pub async fn recommended_fees(&self) -> Result<RecommendedFees, SdkError> {
         self.sdk.recommended_fees().await.map_err(Into::into)
     }
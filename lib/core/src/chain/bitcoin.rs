@@ -1,3 +1,8 @@
+use std::str::FromStr;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use std::thread;
 use std::time::Duration;
 
@@ -6,18 +11,27 @@ use async_trait::async_trait;
 use boltz_client::Amount;
 use electrum_client::{
     bitcoin::{
+        block::Header,
         consensus::{deserialize, serialize},
         hashes::{sha256, Hash},
         Address, OutPoint, Script, Transaction, TxOut, Txid,
     },
     Client, ElectrumApi, GetBalanceRes, HeaderNotification, ListUnspentRes,
 };
-use log::info;
+use log::{info, warn};
 use lwk_wollet::{ElectrumOptions, ElectrumUrl, Error, History};
 use sdk_common::{bitcoin::hashes::hex::ToHex, prelude::get_parse_and_log_response};
+use tokio::sync::{Mutex, OnceCell};
 
+use super::{
+    esplora::EsploraClient,
+    history_agreed_by_all, is_transport_error, parse_electrum_url,
+    rpc::{history_from_utxos, RpcClient, RpcUtxo, FEE_ESTIMATE_TARGETS},
+    CHAIN_QUORUM,
+};
 use crate::{
-    model::{Config, RecommendedFees},
+    error::ChainServiceError,
+    model::{ChainBackend, Config, RecommendedFees},
     prelude::Utxo,
 };
 
@@ -25,63 +39,155 @@ use crate::{
 #[allow(dead_code)]
 #[async_trait]
 pub trait BitcoinChainService: Send + Sync {
+    /// Whether the backend sees the full history of a script, including its spent outputs.
+    /// Only such backends are used to fetch script histories.
+    fn has_full_history(&self) -> bool {
+        true
+    }
+
     /// Get the blockchain latest block
-    fn tip(&mut self) -> Result<HeaderNotification>;
+    async fn tip(&mut self) -> Result<HeaderNotification>;
 
     /// Broadcast a transaction
-    fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
 
     /// Get a list of transactions
-    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>>;
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>>;
 
     /// Get the transactions involved for a script
-    fn get_script_history(&self, script: &Script) -> Result<Vec<History>>;
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>>;
 
     /// Get the transactions involved in a list of scripts.
-    fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>>;
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>>;
 
     /// Get the transactions involved for a script
     async fn get_script_history_with_retry(
         &self,
         script: &Script,
         retries: u64,
-    ) -> Result<Vec<History>>;
+    ) -> Result<Vec<History>> {
+        let script_hash = sha256::Hash::hash(script.as_bytes()).to_hex();
+        info!("Fetching script history for {}", script_hash);
+        let mut script_history = vec![];
+
+        let mut retry = 0;
+        while retry <= retries {
+            script_history = self.get_script_history(script).await?;
+            match script_history.is_empty() {
+                true => {
+                    retry += 1;
+                    info!(
+                        "Script history for {} got zero transactions, retrying in {} seconds...",
+                        script_hash, retry
+                    );
+                    thread::sleep(Duration::from_secs(retry));
+                }
+                false => break,
+            }
+        }
+        Ok(script_history)
+    }
 
     /// Get the utxos associated with a script pubkey
     async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>>;
 
     /// Return the confirmed and unconfirmed balances of a script hash
-    fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes>;
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes>;
 
     /// Return the confirmed and unconfirmed balances of a list of script hashes
-    fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>>;
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>>;
 
-    /// Verify that a transaction appears in the address script history
+    /// Verify that a transaction appears in the address script history. The history is fetched
+    /// once, retries are up to the caller, see [super::verify_tx_with_retry]
     async fn verify_tx(
         &self,
         address: &Address,
         tx_id: &str,
         tx_hex: &str,
         verify_confirmation: bool,
-    ) -> Result<Transaction>;
+    ) -> Result<Transaction> {
+        let script = address.script_pubkey();
+        let script_history = self.get_script_history(&script).await?;
+        let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
+
+        match lockup_tx_history {
+            Some(history) => {
+                info!("Bitcoin transaction found, verifying transaction content...");
+                let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
+                if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
+                    return Err(anyhow!(
+                        "Bitcoin transaction id and hex do not match: {} vs {}",
+                        tx_id,
+                        tx.txid().to_hex()
+                    ));
+                }
+
+                if verify_confirmation && history.height <= 0 {
+                    return Err(anyhow!(
+                        "Bitcoin transaction was not confirmed, txid={} waiting for confirmation",
+                        tx_id,
+                    ));
+                }
+                Ok(tx)
+            }
+            None => Err(anyhow!(
+                "Bitcoin transaction was not found, txid={} waiting for broadcast",
+                tx_id,
+            )),
+        }
+    }
+
+    /// Verify that a transaction appears in the address script history of
+    /// [CHAIN_QUORUM] different chain backends
+    async fn verify_tx_with_quorum(
+        &self,
+        _address: &Address,
+        tx_id: &str,
+        _tx_hex: &str,
+        _verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        Err(anyhow!(
+            "Cannot verify Bitcoin transaction {tx_id} with a single chain backend"
+        ))
+    }
+
+    /// Get the transactions involved in a script which [CHAIN_QUORUM] different chain backends
+    /// agree on
+    async fn get_script_history_with_quorum(&self, _script: &Script) -> Result<Vec<History>> {
+        Err(anyhow!(
+            "Cannot fetch Bitcoin script history with a single chain backend"
+        ))
+    }
+
+    /// Get the lowest of the latest blocks of [CHAIN_QUORUM] different chain backends
+    async fn tip_with_quorum(&mut self) -> Result<HeaderNotification> {
+        Err(anyhow!(
+            "Cannot fetch Bitcoin tip with a single chain backend"
+        ))
+    }
 
     /// Get the recommended fees, in sat/vbyte
     async fn recommended_fees(&self) -> Result<RecommendedFees>;
 }
 
-pub(crate) struct HybridBitcoinChainService {
+pub(crate) struct ElectrumBitcoinChainService {
     client: Client,
     tip: HeaderNotification,
-    config: Config,
+    mempoolspace_url: String,
 }
-impl HybridBitcoinChainService {
-    pub fn new(config: Config) -> Result<Self, Error> {
-        Self::with_options(config, ElectrumOptions::default())
+impl ElectrumBitcoinChainService {
+    pub fn new(url: &str, mempoolspace_url: &str) -> Result<Self, Error> {
+        Self::with_options(url, mempoolspace_url, ElectrumOptions::default())
     }
 
     /// Creates an Electrum client specifying non default options like timeout
-    pub fn with_options(config: Config, options: ElectrumOptions) -> Result<Self, Error> {
-        let url = ElectrumUrl::new(&config.bitcoin_electrum_url, true, true);
+    pub fn with_options(
+        url: &str,
+        mempoolspace_url: &str,
+        options: ElectrumOptions,
+    ) -> Result<Self, Error> {
+        let (url, tls) = parse_electrum_url(url);
+        let url = ElectrumUrl::new(url, tls, tls);
         let client = url.build_client(&options)?;
         let header = client.block_headers_subscribe_raw()?;
         let tip: HeaderNotification = header.try_into()?;
@@ -89,14 +195,14 @@ impl HybridBitcoinChainService {
         Ok(Self {
             client,
             tip,
-            config,
+            mempoolspace_url: mempoolspace_url.to_string(),
         })
     }
 }
 
 #[async_trait]
-impl BitcoinChainService for HybridBitcoinChainService {
-    fn tip(&mut self) -> Result<HeaderNotification> {
+impl BitcoinChainService for ElectrumBitcoinChainService {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
         let mut maybe_popped_header = None;
         while let Some(header) = self.client.block_headers_pop_raw()? {
             maybe_popped_header = Some(header)
@@ -122,12 +228,12 @@ impl BitcoinChainService for HybridBitcoinChainService {
         Ok(self.tip.clone())
     }
 
-    fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         let txid = self.client.transaction_broadcast_raw(&serialize(&tx))?;
         Ok(Txid::from_raw_hash(txid.to_raw_hash()))
     }
 
-    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
         let mut result = vec![];
         for tx in self.client.batch_transaction_get_raw(txids)? {
             let tx: Transaction = deserialize(&tx)?;
@@ -136,7 +242,7 @@ impl BitcoinChainService for HybridBitcoinChainService {
         Ok(result)
     }
 
-    fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
         Ok(self
             .client
             .script_get_history(script)?
@@ -145,7 +251,7 @@ impl BitcoinChainService for HybridBitcoinChainService {
             .collect())
     }
 
-    fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
         Ok(self
             .client
             .batch_script_get_history(scripts)?
@@ -154,33 +260,6 @@ impl BitcoinChainService for HybridBitcoinChainService {
             .collect())
     }
 
-    async fn get_script_history_with_retry(
-        &self,
-        script: &Script,
-        retries: u64,
-    ) -> Result<Vec<History>> {
-        let script_hash = sha256::Hash::hash(script.as_bytes()).to_hex();
-        info!("Fetching script history for {}", script_hash);
-        let mut script_history = vec![];
-
-        let mut retry = 0;
-        while retry <= retries {
-            script_history = self.get_script_history(script)?;
-            match script_history.is_empty() {
-                true => {
-                    retry += 1;
-                    info!(
-                        "Script history for {} got zero transactions, retrying in {} seconds...",
-                        script_hash, retry
-                    );
-                    thread::sleep(Duration::from_secs(retry));
-                }
-                false => break,
-            }
-        }
-        Ok(script_history)
-    }
-
     async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
         let utxos = self
             .client
@@ -206,14 +285,391 @@ impl BitcoinChainService for HybridBitcoinChainService {
         Ok(utxos)
     }
 
-    fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
         Ok(self.client.script_get_balance(script)?)
     }
 
-    fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
         Ok(self.client.batch_script_get_balance(scripts)?)
     }
 
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        fetch_recommended_fees(&self.mempoolspace_url).await
+    }
+}
+
+pub(crate) struct EsploraBitcoinChainService {
+    client: EsploraClient,
+    mempoolspace_url: String,
+}
+
+impl EsploraBitcoinChainService {
+    pub(crate) fn new(url: &str, mempoolspace_url: &str) -> Self {
+        Self {
+            client: EsploraClient::new(url, None),
+            mempoolspace_url: mempoolspace_url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for EsploraBitcoinChainService {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        let tip_hash = self.client.tip_hash().await?;
+        let header: Header = deserialize(&hex::decode(
+            self.client.get_block_header_hex(&tip_hash).await?,
+        )?)?;
+        let height = self.client.tip_height().await? as usize;
+        Ok(HeaderNotification { height, header })
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        let txid = self
+            .client
+            .broadcast(hex::encode(serialize(tx)), None)
+            .await?;
+        Ok(Txid::from_str(&txid)?)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut result = vec![];
+        for txid in txids {
+            if let Some(tx_hex) = self.client.get_tx_hex(&txid.to_string()).await? {
+                let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
+                result.push(tx);
+            }
+        }
+        Ok(result)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.client.get_script_history(script.as_bytes()).await
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        let mut histories = vec![];
+        for script in scripts {
+            histories.push(self.get_script_history(script).await?);
+        }
+        Ok(histories)
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        let mut utxos = vec![];
+        for utxo in self.client.get_script_utxos(script.as_bytes()).await? {
+            utxos.push(Utxo::Bitcoin((
+                OutPoint::new(Txid::from_str(&utxo.txid)?, utxo.vout),
+                TxOut {
+                    value: Amount::from_sat(utxo.value.unwrap_or_default()),
+                    script_pubkey: script.into(),
+                },
+            )));
+        }
+        Ok(utxos)
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        let info = self.client.get_script_info(script.as_bytes()).await?;
+        Ok(GetBalanceRes {
+            confirmed: info
+                .chain_stats
+                .funded_txo_sum
+                .saturating_sub(info.chain_stats.spent_txo_sum),
+            unconfirmed: info.mempool_stats.funded_txo_sum as i64
+                - info.mempool_stats.spent_txo_sum as i64,
+        })
+    }
+
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+        let mut balances = vec![];
+        for script in scripts {
+            balances.push(self.script_get_balance(script).await?);
+        }
+        Ok(balances)
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        fetch_recommended_fees(&self.mempoolspace_url).await
+    }
+}
+
+pub(crate) struct HybridBitcoinChainService {
+    electrum: ElectrumBitcoinChainService,
+    esplora: EsploraBitcoinChainService,
+}
+
+impl HybridBitcoinChainService {
+    pub(crate) fn new(
+        electrum_url: &str,
+        esplora_url: &str,
+        mempoolspace_url: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            electrum: ElectrumBitcoinChainService::new(electrum_url, mempoolspace_url)?,
+            esplora: EsploraBitcoinChainService::new(esplora_url, mempoolspace_url),
+        })
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for HybridBitcoinChainService {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        self.electrum.tip().await
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        self.esplora.broadcast(tx).await
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        self.electrum.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.esplora.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        self.electrum.get_scripts_history(scripts).await
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        self.electrum.get_script_utxos(script).await
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        self.electrum.script_get_balance(script).await
+    }
+
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+        self.electrum.scripts_get_balance(scripts).await
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        self.electrum.recommended_fees().await
+    }
+}
+
+pub(crate) struct RpcBitcoinChainService {
+    client: RpcClient,
+}
+
+impl RpcBitcoinChainService {
+    pub(crate) fn new(url: &str, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            client: RpcClient::new(url, username, password),
+        }
+    }
+
+    async fn scan_utxos(&self, scripts: &[&Script]) -> Result<Vec<Vec<RpcUtxo>>> {
+        let scripts_hex: Vec<String> = scripts
+            .iter()
+            .map(|script| hex::encode(script.as_bytes()))
+            .collect();
+        let mut utxos_by_script = self.client.scan_utxos(&scripts_hex).await?;
+        Ok(scripts_hex
+            .iter()
+            .map(|script_hex| utxos_by_script.remove(script_hex).unwrap_or_default())
+            .collect())
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for RpcBitcoinChainService {
+    fn has_full_history(&self) -> bool {
+        // The history is built out of the unspent outputs of the script
+        false
+    }
+
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        let header: Header =
+            deserialize(&hex::decode(self.client.best_block_header_hex().await?)?)?;
+        let height = self.client.block_count().await? as usize;
+        Ok(HeaderNotification { height, header })
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        let txid = self
+            .client
+            .send_raw_transaction(&hex::encode(serialize(tx)))
+            .await?;
+        Ok(Txid::from_str(&txid)?)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut result = vec![];
+        for txid in txids {
+            if let Some(tx_hex) = self.client.get_raw_transaction(&txid.to_string()).await? {
+                let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
+                result.push(tx);
+            }
+        }
+        Ok(result)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        let mut histories = self.get_scripts_history(&[script]).await?;
+        Ok(histories.pop().unwrap_or_default())
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        Ok(self
+            .scan_utxos(scripts)
+            .await?
+            .iter()
+            .map(|utxos| history_from_utxos(Some(utxos)))
+            .collect())
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        let mut utxos = vec![];
+        for utxo in self.scan_utxos(&[script]).await?.into_iter().flatten() {
+            utxos.push(Utxo::Bitcoin((
+                OutPoint::new(Txid::from_str(&utxo.txid.to_string())?, utxo.vout),
+                TxOut {
+                    value: Amount::from_sat(utxo.amount_sat()),
+                    script_pubkey: script.into(),
+                },
+            )));
+        }
+        Ok(utxos)
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        let mut balances = self.scripts_get_balance(&[script]).await?;
+        balances
+            .pop()
+            .ok_or(anyhow!("Could not get the balance of script {script}"))
+    }
+
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+        // The UTXO set only contains confirmed outputs
+        Ok(self
+            .scan_utxos(scripts)
+            .await?
+            .iter()
+            .map(|utxos| GetBalanceRes {
+                confirmed: utxos.iter().map(RpcUtxo::amount_sat).sum(),
+                unconfirmed: 0,
+            })
+            .collect())
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        let mut fee_rates = vec![];
+        for target_blocks in FEE_ESTIMATE_TARGETS {
+            fee_rates.push(self.client.estimate_fee_rate(target_blocks).await?);
+        }
+        Ok(RecommendedFees {
+            fastest_fee: fee_rates[0],
+            half_hour_fee: fee_rates[1],
+            hour_fee: fee_rates[2],
+            economy_fee: fee_rates[3],
+            minimum_fee: fee_rates[4],
+        })
+    }
+}
+
+/// Wraps the Bitcoin chain services of the configured backends, failing over to the next one
+/// when a request to the active backend fails.
+pub(crate) struct FailoverBitcoinChainService {
+    services: Vec<Box<dyn BitcoinChainService>>,
+    active: AtomicUsize,
+}
+
+impl FailoverBitcoinChainService {
+    pub(crate) fn new(services: Vec<Box<dyn BitcoinChainService>>) -> Self {
+        Self {
+            services,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// The service indices, starting from the active one
+    fn failover_order(&self) -> Vec<usize> {
+        let active = self.active.load(Ordering::Relaxed);
+        (0..self.services.len())
+            .map(|i| (active + i) % self.services.len())
+            .collect()
+    }
+
+    /// The indices of the services which see full script histories, starting from the active one
+    fn history_order(&self) -> Vec<usize> {
+        self.failover_order()
+            .into_iter()
+            .filter(|index| self.services[*index].has_full_history())
+            .collect()
+    }
+
+    fn set_active(&self, index: usize) {
+        let previous = self.active.swap(index, Ordering::Relaxed);
+        if previous != index {
+            info!("Switched Bitcoin chain backend from {previous} to {index}");
+        }
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for FailoverBitcoinChainService {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        // The tip is polled periodically, so it doubles as the health check of the backends
+        let mut last_err = None;
+        for index in self.failover_order() {
+            match self.services[index].tip().await {
+                Ok(tip) => {
+                    self.set_active(index);
+                    return Ok(tip);
+                }
+                Err(e) if !is_transport_error(&e) => {
+                    self.set_active(index);
+                    return Err(ChainServiceError::from(e).into());
+                }
+                Err(e) => {
+                    warn!("Bitcoin chain backend {index} failed to fetch the tip: {e:?}");
+                    last_err = Some(e);
+                }
+            }
+        }
+        let err = last_err.unwrap_or_else(|| anyhow!("No Bitcoin chain backend available"));
+        Err(ChainServiceError::from(err).into())
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        with_failover!(self, service => service.broadcast(tx).await)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        with_failover!(self, service => service.get_transactions(txids).await)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        with_failover!(self, self.history_order(), service => service.get_script_history(script).await)
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        with_failover!(self, self.history_order(), service => service.get_scripts_history(scripts).await)
+    }
+
+    async fn get_script_history_with_retry(
+        &self,
+        script: &Script,
+        retries: u64,
+    ) -> Result<Vec<History>> {
+        with_failover!(self, self.history_order(), service => service.get_script_history_with_retry(script, retries).await)
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        with_failover!(self, service => service.get_script_utxos(script).await)
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        with_failover!(self, service => service.script_get_balance(script).await)
+    }
+
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+        with_failover!(self, service => service.scripts_get_balance(scripts).await)
+    }
+
     async fn verify_tx(
         &self,
         address: &Address,
@@ -221,43 +677,215 @@ impl BitcoinChainService for HybridBitcoinChainService {
         tx_hex: &str,
         verify_confirmation: bool,
     ) -> Result<Transaction> {
-        let script = address.script_pubkey();
-        let script_history = self.get_script_history_with_retry(&script, 5).await?;
-        let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
+        with_failover!(self, self.history_order(), service => service.verify_tx(address, tx_id, tx_hex, verify_confirmation).await)
+    }
 
-        match lockup_tx_history {
-            Some(history) => {
-                info!("Bitcoin transaction found, verifying transaction content...");
-                let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
-                if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
-                    return Err(anyhow!(
-                        "Bitcoin transaction id and hex do not match: {} vs {}",
-                        tx_id,
-                        tx.txid().to_hex()
-                    ));
-                }
+    async fn verify_tx_with_quorum(
+        &self,
+        address: &Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        let mut verified = vec![];
+        for index in self.history_order() {
+            match self.services[index]
+                .verify_tx(address, tx_id, tx_hex, verify_confirmation)
+                .await
+            {
+                Ok(tx) => verified.push(tx),
+                Err(e) => warn!("Bitcoin chain backend {index} could not verify tx {tx_id}: {e:?}"),
+            }
+            if verified.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        match verified.len() {
+            CHAIN_QUORUM => Ok(verified.remove(0)),
+            n => Err(ChainServiceError::from(anyhow!(
+                "Bitcoin transaction {tx_id} was verified by {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
+        }
+    }
 
-                if verify_confirmation && history.height <= 0 {
-                    return Err(anyhow!(
-                        "Bitcoin transaction was not confirmed, txid={} waiting for confirmation",
-                        tx_id,
-                    ));
+    async fn get_script_history_with_quorum(&self, script: &Script) -> Result<Vec<History>> {
+        let mut histories = vec![];
+        for index in self.history_order() {
+            match self.services[index].get_script_history(script).await {
+                Ok(history) => histories.push(history),
+                Err(e) => {
+                    warn!("Bitcoin chain backend {index} failed to fetch the script history: {e:?}")
                 }
-                Ok(tx)
             }
-            None => Err(anyhow!(
-                "Bitcoin transaction was not found, txid={} waiting for broadcast",
-                tx_id,
-            )),
+            if histories.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        match histories.len() {
+            CHAIN_QUORUM => Ok(history_agreed_by_all(histories)),
+            n => Err(ChainServiceError::from(anyhow!(
+                "Bitcoin script history was fetched from {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
+        }
+    }
+
+    async fn tip_with_quorum(&mut self) -> Result<HeaderNotification> {
+        let mut tips = vec![];
+        for index in self.failover_order() {
+            match self.services[index].tip().await {
+                Ok(tip) => tips.push(tip),
+                Err(e) => warn!("Bitcoin chain backend {index} failed to fetch the tip: {e:?}"),
+            }
+            if tips.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        // Taking the lowest tip, a single backend can't make a swap look expired
+        let n = tips.len();
+        match tips.into_iter().min_by_key(|tip| tip.height) {
+            Some(tip) if n == CHAIN_QUORUM => Ok(tip),
+            _ => Err(ChainServiceError::from(anyhow!(
+                "Bitcoin tip was fetched from {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
         }
     }
 
     async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        get_parse_and_log_response(
-            &format!("{}/v1/fees/recommended", self.config.mempoolspace_url),
-            true,
-        )
+        with_failover!(self, service => service.recommended_fees().await)
+    }
+}
+
+/// A backend which couldn't be reached at startup. It's connected to on first use, so that the
+/// SDK can start while offline.
+struct LazyBitcoinChainService {
+    config: Config,
+    backend: ChainBackend,
+    service: OnceCell<Box<dyn BitcoinChainService>>,
+}
+
+impl LazyBitcoinChainService {
+    fn new(config: &Config, backend: &ChainBackend) -> Self {
+        Self {
+            config: config.clone(),
+            backend: backend.clone(),
+            service: OnceCell::new(),
+        }
+    }
+
+    async fn service(&self) -> Result<&dyn BitcoinChainService> {
+        let service = self
+            .service
+            .get_or_try_init(|| async { new_backend_service(&self.config, &self.backend) })
+            .await?;
+        Ok(service.as_ref())
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for LazyBitcoinChainService {
+    fn has_full_history(&self) -> bool {
+        !matches!(self.backend, ChainBackend::Rpc { .. })
+    }
+
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        self.service().await?;
+        match self.service.get_mut() {
+            Some(service) => service.tip().await,
+            None => Err(anyhow!("Bitcoin chain backend is not connected")),
+        }
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        self.service().await?.broadcast(tx).await
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        self.service().await?.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.service().await?.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        self.service().await?.get_scripts_history(scripts).await
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        self.service().await?.get_script_utxos(script).await
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        self.service().await?.script_get_balance(script).await
+    }
+
+    async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+        self.service().await?.scripts_get_balance(scripts).await
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        self.service().await?.recommended_fees().await
+    }
+}
+
+/// Creates the [BitcoinChainService] for the configured [ChainBackend] and its fallbacks.
+///
+/// Backends that can't be reached at startup are connected to on first use.
+pub(crate) fn new_bitcoin_chain_service(
+    config: &Config,
+) -> Result<Arc<Mutex<dyn BitcoinChainService>>> {
+    let mut services: Vec<Box<dyn BitcoinChainService>> = vec![];
+    for backend in config.bitcoin_chain_backends() {
+        match new_backend_service(config, backend) {
+            Ok(service) => services.push(service),
+            Err(e) => {
+                warn!("Could not connect to Bitcoin chain backend {backend:?}, retrying on first use: {e:?}");
+                services.push(Box::new(LazyBitcoinChainService::new(config, backend)));
+            }
+        }
+    }
+    Ok(Arc::new(Mutex::new(FailoverBitcoinChainService::new(
+        services,
+    ))))
+}
+
+fn new_backend_service(
+    config: &Config,
+    backend: &ChainBackend,
+) -> Result<Box<dyn BitcoinChainService>> {
+    let mempoolspace_url = &config.mempoolspace_url;
+    Ok(match backend {
+        ChainBackend::Electrum { url } => {
+            Box::new(ElectrumBitcoinChainService::new(url, mempoolspace_url)?)
+        }
+        ChainBackend::Esplora { url } => {
+            Box::new(EsploraBitcoinChainService::new(url, mempoolspace_url))
+        }
+        ChainBackend::Hybrid {
+            electrum_url,
+            esplora_url,
+        } => Box::new(HybridBitcoinChainService::new(
+            electrum_url,
+            esplora_url,
+            mempoolspace_url,
+        )?),
+        ChainBackend::Rpc {
+            url,
+            username,
+            password,
+        } => Box::new(RpcBitcoinChainService::new(
+            url,
+            username.clone(),
+            password.clone(),
+        )),
+    })
+}
+
+async fn fetch_recommended_fees(mempoolspace_url: &str) -> Result<RecommendedFees> {
+    get_parse_and_log_response(&format!("{mempoolspace_url}/v1/fees/recommended"), true)
         .await
         .map_err(Into::into)
-    }
 }
@@ -0,0 +1,311 @@
+use std::{future::Future, time::Duration};
+
+use anyhow::{anyhow, Result};
+use boltz_client::ToHex;
+use log::info;
+use lwk_wollet::{
+    elements::{BlockHash, Txid},
+    hashes::{sha256, Hash},
+    History,
+};
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+
+/// Maximum number of confirmed transactions returned by a single Esplora script history page
+const CHAIN_TXS_PER_PAGE: usize = 25;
+
+#[derive(Deserialize)]
+pub(crate) struct EsploraTx {
+    pub(crate) txid: Txid,
+    pub(crate) status: Status,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Status {
+    pub(crate) block_height: Option<i32>,
+    pub(crate) block_hash: Option<BlockHash>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EsploraUtxo {
+    pub(crate) txid: String,
+    pub(crate) vout: u32,
+    pub(crate) value: Option<u64>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ScriptStats {
+    pub(crate) funded_txo_sum: u64,
+    pub(crate) spent_txo_sum: u64,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ScriptInfo {
+    pub(crate) chain_stats: ScriptStats,
+    pub(crate) mempool_stats: ScriptStats,
+}
+
+impl From<EsploraTx> for History {
+    fn from(value: EsploraTx) -> Self {
+        let status = value.status;
+        History {
+            txid: value.txid,
+            height: status.block_height.unwrap_or_default(),
+            block_hash: status.block_hash,
+            block_timestamp: None,
+        }
+    }
+}
+
+/// A client for the subset of the [Esplora REST API](https://github.com/blockstream/esplora/blob/master/API.md)
+/// used by the chain services. Shared by the Liquid and Bitcoin implementations.
+pub(crate) struct EsploraClient {
+    base_url: String,
+    api_key: Option<String>,
+    client: reqwest::Client,
+}
+
+impl EsploraClient {
+    pub(crate) fn new(base_url: &str, api_key: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub(crate) async fn tip_height(&self) -> Result<u32> {
+        let response = self.get("/blocks/tip/height").await?;
+        Ok(response.text().await?.trim().parse()?)
+    }
+
+    pub(crate) async fn tip_hash(&self) -> Result<String> {
+        let response = self.get("/blocks/tip/hash").await?;
+        Ok(response.text().await?.trim().to_string())
+    }
+
+    pub(crate) async fn get_block_header_hex(&self, block_hash: &str) -> Result<String> {
+        let response = self.get(&format!("/block/{block_hash}/header")).await?;
+        Ok(response.text().await?.trim().to_string())
+    }
+
+    /// Broadcasts the hex encoded transaction, returning its txid
+    pub(crate) async fn broadcast(&self, tx_hex: String, swap_id: Option<&str>) -> Result<String> {
+        info!("Broadcasting tx via Esplora: {tx_hex}");
+        let mut req = self
+            .client
+            .post(format!("{}/tx", self.base_url))
+            .header("Swap-ID", swap_id.unwrap_or_default())
+            .body(tx_hex);
+        if let Some(api_key) = &self.api_key {
+            req = req.header("Authorization", format!("Bearer {}", api_key));
+        };
+
+        let response = req.send().await?;
+        let status = response.status();
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            response.error_for_status_ref()?;
+        }
+        let text = response.text().await?;
+        match status {
+            StatusCode::OK => Ok(text.trim().to_string()),
+            _ => Err(anyhow!("Failed to broadcast tx: {status} {text}")),
+        }
+    }
+
+    /// Returns the hex encoded transaction, or `None` if it's unknown to the server
+    pub(crate) async fn get_tx_hex(&self, txid: &str) -> Result<Option<String>> {
+        let response = self.get_with_retry(&format!("/tx/{txid}/hex"), 3).await?;
+        Ok(match response.status() {
+            StatusCode::OK => Some(response.text().await?.trim().to_string()),
+            StatusCode::NOT_FOUND => None,
+            status => {
+                response.error_for_status_ref()?;
+                return Err(anyhow!("Failed to fetch tx {txid}: {status}"));
+            }
+        })
+    }
+
+    /// Returns the full history of a script, following the pagination of confirmed transactions
+    pub(crate) async fn get_script_history(&self, script: &[u8]) -> Result<Vec<History>> {
+        let script_hash = Self::script_hash(script);
+        let txs = fetch_all_pages(|last_seen_txid| {
+            let path = match last_seen_txid {
+                None => format!("/scripthash/{script_hash}/txs"),
+                Some(txid) => format!("/scripthash/{script_hash}/txs/chain/{txid}"),
+            };
+            async move { Ok(self.get(&path).await?.json().await?) }
+        })
+        .await?;
+        Ok(txs.into_iter().map(Into::into).collect())
+    }
+
+    pub(crate) async fn get_script_utxos(&self, script: &[u8]) -> Result<Vec<EsploraUtxo>> {
+        let script_hash = Self::script_hash(script);
+        Ok(self
+            .get(&format!("/scripthash/{script_hash}/utxo"))
+            .await?
+            .json()
+            .await?)
+    }
+
+    pub(crate) async fn get_script_info(&self, script: &[u8]) -> Result<ScriptInfo> {
+        let script_hash = Self::script_hash(script);
+        Ok(self
+            .get(&format!("/scripthash/{script_hash}"))
+            .await?
+            .json()
+            .await?)
+    }
+
+    fn script_hash(script: &[u8]) -> String {
+        sha256::Hash::hash(script).to_byte_array().to_hex()
+    }
+
+    /// Fetches the path, failing on any unsuccessful response status
+    async fn get(&self, path: &str) -> Result<Response> {
+        Ok(self.get_with_retry(path, 3).await?.error_for_status()?)
+    }
+
+    async fn get_with_retry(&self, path: &str, retries: usize) -> Result<Response> {
+        let url = format!("{}{path}", self.base_url);
+        let mut attempt = 0;
+        loop {
+            info!("Esplora get_with_retry for url {url}");
+            let response = self.client.get(&url).send().await?;
+            attempt += 1;
+            // 429 Too many requests
+            // 503 Service Temporarily Unavailable
+            if response.status() == 429 || response.status() == 503 {
+                if attempt >= retries {
+                    // Keep the status in the error, so that it's classified as a transport error
+                    response.error_for_status_ref()?;
+                }
+                let secs = 1 << attempt;
+
+                tokio::time::sleep(Duration::from_secs(secs)).await;
+            } else {
+                return Ok(response);
+            }
+        }
+    }
+}
+
+/// Fetches all the pages of a script history. The first page holds the mempool txs and the
+/// first confirmed txs, the following ones the confirmed txs after the last one seen.
+async fn fetch_all_pages<F, Fut>(mut fetch_page: F) -> Result<Vec<EsploraTx>>
+where
+    F: FnMut(Option<Txid>) -> Fut,
+    Fut: Future<Output = Result<Vec<EsploraTx>>>,
+{
+    let mut txs = vec![];
+    let mut last_seen_txid = None;
+    loop {
+        let page = fetch_page(last_seen_txid).await?;
+        let confirmed_txids: Vec<Txid> = page
+            .iter()
+            .filter(|tx| tx.status.block_height.is_some())
+            .map(|tx| tx.txid)
+            .collect();
+        txs.extend(page);
+        if confirmed_txids.len() < CHAIN_TXS_PER_PAGE {
+            return Ok(txs);
+        }
+        last_seen_txid = confirmed_txids.last().copied();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use lwk_wollet::{elements::Txid, History};
+
+    use super::{fetch_all_pages, EsploraTx, EsploraUtxo, Status, CHAIN_TXS_PER_PAGE};
+
+    fn new_tx(i: usize, block_height: Option<i32>) -> EsploraTx {
+        EsploraTx {
+            txid: Txid::from_str(&format!("{i:064x}")).unwrap(),
+            status: Status {
+                block_height,
+                block_hash: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_esplora_tx_conversion() -> Result<()> {
+        let txs: Vec<EsploraTx> = serde_json::from_str(
+            r#"[
+                {"txid": "6f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "status": {"confirmed": false}},
+                {"txid": "1f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "status": {"confirmed": true, "block_height": 101, "block_hash": "2f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "block_time": 1700000000}}
+            ]"#,
+        )?;
+        let history: Vec<History> = txs.into_iter().map(Into::into).collect();
+        assert_eq!(history[0].height, 0);
+        assert!(history[0].block_hash.is_none());
+        assert_eq!(history[1].height, 101);
+        assert_eq!(
+            history[1]
+                .block_hash
+                .map(|hash| hash.to_string())
+                .as_deref(),
+            Some("2f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d")
+        );
+
+        // Confidential outputs have no explicit value
+        let utxos: Vec<EsploraUtxo> = serde_json::from_str(
+            r#"[
+                {"txid": "6f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "vout": 1, "status": {"confirmed": false}, "value": 10000},
+                {"txid": "1f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "vout": 0, "status": {"confirmed": false}, "valuecommitment": "08aa"}
+            ]"#,
+        )?;
+        assert_eq!(utxos[0].value, Some(10_000));
+        assert_eq!(utxos[1].vout, 0);
+        assert!(utxos[1].value.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_script_history_pagination() -> Result<()> {
+        // The first page holds 2 mempool txs and a full page of confirmed txs
+        let first_page: Vec<EsploraTx> = (0..2)
+            .map(|i| new_tx(i, None))
+            .chain((2..CHAIN_TXS_PER_PAGE + 2).map(|i| new_tx(i, Some(100))))
+            .collect();
+        let second_page: Vec<EsploraTx> = (100..CHAIN_TXS_PER_PAGE + 100)
+            .map(|i| new_tx(i, Some(90)))
+            .collect();
+        let last_page: Vec<EsploraTx> = (200..203).map(|i| new_tx(i, Some(80))).collect();
+        let expected_cursors = vec![
+            None,
+            first_page.last().map(|tx| tx.txid),
+            second_page.last().map(|tx| tx.txid),
+        ];
+
+        let mut pages = vec![first_page, second_page, last_page].into_iter();
+        let mut cursors = vec![];
+        let txs = fetch_all_pages(|last_seen_txid| {
+            cursors.push(last_seen_txid);
+            let page = pages.next().unwrap_or_default();
+            async move { Ok(page) }
+        })
+        .await?;
+        assert_eq!(txs.len(), 2 * CHAIN_TXS_PER_PAGE + 5);
+        assert_eq!(cursors, expected_cursors);
+
+        // A history with fewer confirmed txs than a page is fetched at once
+        let mut requests = 0;
+        let txs = fetch_all_pages(|_| {
+            requests += 1;
+            async { Ok(vec![new_tx(0, None), new_tx(1, Some(100))]) }
+        })
+        .await?;
+        assert_eq!(txs.len(), 2);
+        assert_eq!(requests, 1);
+
+        Ok(())
+    }
+}
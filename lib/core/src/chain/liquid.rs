@@ -1,30 +1,47 @@
-use std::{str::FromStr, thread, time::Duration};
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::ToHex;
-use log::info;
+use log::{info, warn};
 use lwk_wollet::elements::hex::FromHex;
 use lwk_wollet::{
-    elements::{
-        pset::serialize::Serialize, Address, BlockHash, OutPoint, Script, Transaction, Txid,
-    },
+    elements::{pset::serialize::Serialize, Address, OutPoint, Script, Transaction, Txid},
     hashes::{sha256, Hash},
     BlockchainBackend, ElectrumClient, ElectrumUrl, History,
 };
-use reqwest::{Response, StatusCode};
-use serde::Deserialize;
-
+use tokio::sync::{Mutex, OnceCell};
+use url::Url;
+
+use super::{
+    esplora::EsploraClient,
+    history_agreed_by_all, is_transport_error, parse_electrum_url,
+    rpc::{history_from_utxos, RpcClient},
+    CHAIN_QUORUM,
+};
 use crate::prelude::Utxo;
 use crate::{
-    model::{Config, LiquidNetwork},
+    error::ChainServiceError,
+    model::{ChainBackend, Config},
     utils,
 };
 
-const LIQUID_ESPLORA_URL: &str = "https://lq1.breez.technology/liquid/api";
-
 #[async_trait]
 pub trait LiquidChainService: Send + Sync {
+    /// Whether the backend sees the full history of a script, including its spent outputs.
+    /// Only such backends are used to fetch script histories.
+    fn has_full_history(&self) -> bool {
+        true
+    }
+
     /// Get the blockchain latest block
     async fn tip(&mut self) -> Result<u32>;
 
@@ -37,142 +54,16 @@ pub trait LiquidChainService: Send + Sync {
     /// Get a list of transactions
     async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>>;
 
-    /// Get the transactions involved in a script, including lowball transactions.
-    ///
-    /// On mainnet, the data is fetched from Esplora. On testnet, it's fetched from Electrum.
+    /// Get the transactions involved in a script, including lowball transactions if the
+    /// backend relays them.
     async fn get_script_history(&self, scripts: &Script) -> Result<Vec<History>>;
 
-    /// Get the transactions involved in a list of scripts, including lowball transactions.
+    /// Get the transactions involved in a list of scripts.
     ///
-    /// The data is fetched in a single call from the Electrum endpoint.
+    /// Electrum backends fetch the data in a single batched call.
     async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>>;
 
     /// Get the transactions involved in a list of scripts including lowball
-    async fn get_script_history_with_retry(
-        &self,
-        script: &Script,
-        retries: u64,
-    ) -> Result<Vec<History>>;
-
-    /// Get the utxos associated with a script pubkey
-    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>>;
-
-    /// Verify that a transaction appears in the address script history
-    async fn verify_tx(
-        &self,
-        address: &Address,
-        tx_id: &str,
-        tx_hex: &str,
-        verify_confirmation: bool,
-    ) -> Result<Transaction>;
-}
-
-#[derive(Deserialize)]
-struct EsploraTx {
-    txid: Txid,
-    status: Status,
-}
-
-#[derive(Deserialize)]
-struct Status {
-    block_height: Option<i32>,
-    block_hash: Option<BlockHash>,
-}
-
-pub(crate) struct HybridLiquidChainService {
-    network: LiquidNetwork,
-    api_key: Option<String>,
-    electrum_client: ElectrumClient,
-}
-
-impl HybridLiquidChainService {
-    pub(crate) fn new(config: Config) -> Result<Self> {
-        let electrum_client =
-            ElectrumClient::new(&ElectrumUrl::new(&config.liquid_electrum_url, true, true))?;
-        Ok(Self {
-            electrum_client,
-            network: config.network,
-            api_key: config.breez_api_key,
-        })
-    }
-}
-
-#[async_trait]
-impl LiquidChainService for HybridLiquidChainService {
-    async fn tip(&mut self) -> Result<u32> {
-        Ok(self.electrum_client.tip()?.height)
-    }
-
-    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
-        match self.network {
-            LiquidNetwork::Mainnet => {
-                let tx_bytes = tx.serialize();
-                info!("Broadcasting Liquid tx: {}", tx_bytes.to_hex());
-                let client = reqwest::Client::new();
-                let mut req = client
-                    .post(format!("{LIQUID_ESPLORA_URL}/tx"))
-                    .header("Swap-ID", swap_id.unwrap_or_default())
-                    .body(tx_bytes.to_hex());
-
-                if let Some(api_key) = &self.api_key {
-                    req = req.header("Authorization", format!("Bearer {}", api_key));
-                };
-
-                let response = req.send().await?;
-                let txid = Txid::from_str(&response.text().await?)?;
-                Ok(txid)
-            }
-            LiquidNetwork::Testnet => Ok(self.electrum_client.broadcast(tx)?),
-        }
-    }
-
-    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
-        match self.network {
-            LiquidNetwork::Mainnet => {
-                let url = format!("{}/tx/{}/hex", LIQUID_ESPLORA_URL, txid.to_hex());
-                let response = get_with_retry(&url, 3).await?;
-                Ok(match response.status() {
-                    StatusCode::OK => Some(utils::deserialize_tx_hex(&response.text().await?)?),
-                    _ => None,
-                })
-            }
-            LiquidNetwork::Testnet => Ok(self.get_transactions(&[*txid]).await?.first().cloned()),
-        }
-    }
-
-    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
-        Ok(self.electrum_client.get_transactions(txids)?)
-    }
-
-    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
-        match self.network {
-            LiquidNetwork::Mainnet => {
-                let script = lwk_wollet::elements::bitcoin::Script::from_bytes(script.as_bytes());
-                let script_hash = sha256::Hash::hash(script.as_bytes())
-                    .to_byte_array()
-                    .to_hex();
-                let url = format!("{}/scripthash/{}/txs", LIQUID_ESPLORA_URL, script_hash);
-                // TODO must handle paging -> https://github.com/blockstream/esplora/blob/master/API.md#addresses
-                let response = get_with_retry(&url, 3).await?;
-                let json: Vec<EsploraTx> = response.json().await?;
-
-                let history: Vec<History> = json.into_iter().map(Into::into).collect();
-                Ok(history)
-            }
-            LiquidNetwork::Testnet => {
-                let mut history_vec = self.electrum_client.get_scripts_history(&[script])?;
-                let h = history_vec.pop();
-                Ok(h.unwrap_or(vec![]))
-            }
-        }
-    }
-
-    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
-        self.electrum_client
-            .get_scripts_history(scripts)
-            .map_err(Into::into)
-    }
-
     async fn get_script_history_with_retry(
         &self,
         script: &Script,
@@ -200,6 +91,7 @@ impl LiquidChainService for HybridLiquidChainService {
         Ok(script_history)
     }
 
+    /// Get the utxos associated with a script pubkey
     async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
         let history = self.get_script_history_with_retry(script, 3).await?;
 
@@ -230,6 +122,8 @@ impl LiquidChainService for HybridLiquidChainService {
         return Ok(utxos);
     }
 
+    /// Verify that a transaction appears in the address script history. The history is fetched
+    /// once, retries are up to the caller, see [super::verify_tx_with_retry]
     async fn verify_tx(
         &self,
         address: &Address,
@@ -242,7 +136,7 @@ impl LiquidChainService for HybridLiquidChainService {
         )
         .map_err(|e| anyhow!("Failed to get script from address {e:?}"))?;
 
-        let script_history = self.get_script_history_with_retry(&script, 30).await?;
+        let script_history = self.get_script_history(&script).await?;
         let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
 
         match lockup_tx_history {
@@ -271,37 +165,822 @@ impl LiquidChainService for HybridLiquidChainService {
             )),
         }
     }
+
+    /// Verify that a transaction appears in the address script history of
+    /// [CHAIN_QUORUM] different chain backends
+    async fn verify_tx_with_quorum(
+        &self,
+        _address: &Address,
+        tx_id: &str,
+        _tx_hex: &str,
+        _verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        Err(anyhow!(
+            "Cannot verify Liquid transaction {tx_id} with a single chain backend"
+        ))
+    }
+
+    /// Get the transactions involved in a script which [CHAIN_QUORUM] different chain backends
+    /// agree on
+    async fn get_script_history_with_quorum(&self, _script: &Script) -> Result<Vec<History>> {
+        Err(anyhow!(
+            "Cannot fetch Liquid script history with a single chain backend"
+        ))
+    }
+
+    /// Get the lowest of the latest blocks of [CHAIN_QUORUM] different chain backends
+    async fn tip_with_quorum(&mut self) -> Result<u32> {
+        Err(anyhow!(
+            "Cannot fetch Liquid tip with a single chain backend"
+        ))
+    }
+}
+
+pub(crate) struct ElectrumLiquidChainService {
+    electrum_client: ElectrumClient,
+}
+
+impl ElectrumLiquidChainService {
+    pub(crate) fn new(url: &str) -> Result<Self> {
+        let (url, tls) = parse_electrum_url(url);
+        let electrum_client = ElectrumClient::new(&ElectrumUrl::new(url, tls, tls))?;
+        Ok(Self { electrum_client })
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for ElectrumLiquidChainService {
+    async fn tip(&mut self) -> Result<u32> {
+        Ok(self.electrum_client.tip()?.height)
+    }
+
+    async fn broadcast(&self, tx: &Transaction, _swap_id: Option<&str>) -> Result<Txid> {
+        Ok(self.electrum_client.broadcast(tx)?)
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        Ok(self.get_transactions(&[*txid]).await?.first().cloned())
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        Ok(self.electrum_client.get_transactions(txids)?)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        let mut history_vec = self.electrum_client.get_scripts_history(&[script])?;
+        let h = history_vec.pop();
+        Ok(h.unwrap_or(vec![]))
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        self.electrum_client
+            .get_scripts_history(scripts)
+            .map_err(Into::into)
+    }
+}
+
+pub(crate) struct EsploraLiquidChainService {
+    client: EsploraClient,
+}
+
+impl EsploraLiquidChainService {
+    pub(crate) fn new(url: &str, api_key: Option<String>) -> Self {
+        Self {
+            client: EsploraClient::new(url, api_key),
+        }
+    }
 }
 
-async fn get_with_retry(url: &str, retries: usize) -> Result<Response> {
-    let mut attempt = 0;
-    loop {
-        info!("liquid chain service get_with_retry for url {url}");
-        let response = reqwest::get(url).await?;
-        attempt += 1;
-        // 429 Too many requests
-        // 503 Service Temporarily Unavailable
-        if response.status() == 429 || response.status() == 503 {
-            if attempt >= retries {
-                return Err(anyhow!("Too many retry".to_string()));
+#[async_trait]
+impl LiquidChainService for EsploraLiquidChainService {
+    async fn tip(&mut self) -> Result<u32> {
+        self.client.tip_height().await
+    }
+
+    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
+        let txid = self
+            .client
+            .broadcast(tx.serialize().to_hex(), swap_id)
+            .await?;
+        Ok(Txid::from_str(&txid)?)
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        self.client
+            .get_tx_hex(&txid.to_hex())
+            .await?
+            .map(|tx_hex| utils::deserialize_tx_hex(&tx_hex))
+            .transpose()
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut txs = vec![];
+        for txid in txids {
+            if let Some(tx) = self.get_transaction_hex(txid).await? {
+                txs.push(tx);
             }
-            let secs = 1 << attempt;
+        }
+        Ok(txs)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.client.get_script_history(script.as_bytes()).await
+    }
 
-            thread::sleep(Duration::from_secs(secs));
-        } else {
-            return Ok(response);
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        let mut histories = vec![];
+        for script in scripts {
+            histories.push(self.get_script_history(script).await?);
         }
+        Ok(histories)
     }
 }
 
-impl From<EsploraTx> for History {
-    fn from(value: EsploraTx) -> Self {
-        let status = value.status;
+pub(crate) struct HybridLiquidChainService {
+    electrum: ElectrumLiquidChainService,
+    esplora: EsploraLiquidChainService,
+}
+
+impl HybridLiquidChainService {
+    pub(crate) fn new(
+        electrum_url: &str,
+        esplora_url: &str,
+        api_key: Option<String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            electrum: ElectrumLiquidChainService::new(electrum_url)?,
+            esplora: EsploraLiquidChainService::new(esplora_url, api_key),
+        })
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for HybridLiquidChainService {
+    async fn tip(&mut self) -> Result<u32> {
+        self.electrum.tip().await
+    }
+
+    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
+        self.esplora.broadcast(tx, swap_id).await
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        self.esplora.get_transaction_hex(txid).await
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        self.electrum.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.esplora.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        self.electrum.get_scripts_history(scripts).await
+    }
+}
+
+pub(crate) struct RpcLiquidChainService {
+    client: RpcClient,
+}
+
+impl RpcLiquidChainService {
+    pub(crate) fn new(url: &str, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            client: RpcClient::new(url, username, password),
+        }
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for RpcLiquidChainService {
+    fn has_full_history(&self) -> bool {
+        // The history is built out of the unspent outputs of the script
+        false
+    }
+
+    async fn tip(&mut self) -> Result<u32> {
+        self.client.block_count().await
+    }
+
+    async fn broadcast(&self, tx: &Transaction, _swap_id: Option<&str>) -> Result<Txid> {
+        let txid = self
+            .client
+            .send_raw_transaction(&tx.serialize().to_hex())
+            .await?;
+        Ok(Txid::from_str(&txid)?)
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        self.client
+            .get_raw_transaction(&txid.to_hex())
+            .await?
+            .map(|tx_hex| utils::deserialize_tx_hex(&tx_hex))
+            .transpose()
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut txs = vec![];
+        for txid in txids {
+            if let Some(tx) = self.get_transaction_hex(txid).await? {
+                txs.push(tx);
+            }
+        }
+        Ok(txs)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        let mut histories = self.get_scripts_history(&[script]).await?;
+        Ok(histories.pop().unwrap_or_default())
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        let scripts_hex: Vec<String> = scripts
+            .iter()
+            .map(|script| hex::encode(script.as_bytes()))
+            .collect();
+        let utxos_by_script = self.client.scan_utxos(&scripts_hex).await?;
+        Ok(scripts_hex
+            .iter()
+            .map(|script_hex| history_from_utxos(utxos_by_script.get(script_hex)))
+            .collect())
+    }
+}
+
+/// Wraps the Liquid chain services of the configured backends, failing over to the next one
+/// when a request to the active backend fails.
+pub(crate) struct FailoverLiquidChainService {
+    services: Vec<Box<dyn LiquidChainService>>,
+    active: AtomicUsize,
+}
+
+impl FailoverLiquidChainService {
+    pub(crate) fn new(services: Vec<Box<dyn LiquidChainService>>) -> Self {
+        Self {
+            services,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// The service indices, starting from the active one
+    fn failover_order(&self) -> Vec<usize> {
+        let active = self.active.load(Ordering::Relaxed);
+        (0..self.services.len())
+            .map(|i| (active + i) % self.services.len())
+            .collect()
+    }
+
+    /// The indices of the services which see full script histories, starting from the active one
+    fn history_order(&self) -> Vec<usize> {
+        self.failover_order()
+            .into_iter()
+            .filter(|index| self.services[*index].has_full_history())
+            .collect()
+    }
+
+    fn set_active(&self, index: usize) {
+        let previous = self.active.swap(index, Ordering::Relaxed);
+        if previous != index {
+            info!("Switched Liquid chain backend from {previous} to {index}");
+        }
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for FailoverLiquidChainService {
+    async fn tip(&mut self) -> Result<u32> {
+        // The tip is polled periodically, so it doubles as the health check of the backends
+        let mut last_err = None;
+        for index in self.failover_order() {
+            match self.services[index].tip().await {
+                Ok(tip) => {
+                    self.set_active(index);
+                    return Ok(tip);
+                }
+                Err(e) if !is_transport_error(&e) => {
+                    self.set_active(index);
+                    return Err(ChainServiceError::from(e).into());
+                }
+                Err(e) => {
+                    warn!("Liquid chain backend {index} failed to fetch the tip: {e:?}");
+                    last_err = Some(e);
+                }
+            }
+        }
+        let err = last_err.unwrap_or_else(|| anyhow!("No Liquid chain backend available"));
+        Err(ChainServiceError::from(err).into())
+    }
+
+    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
+        with_failover!(self, service => service.broadcast(tx, swap_id).await)
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        with_failover!(self, service => service.get_transaction_hex(txid).await)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        with_failover!(self, service => service.get_transactions(txids).await)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        with_failover!(self, self.history_order(), service => service.get_script_history(script).await)
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        with_failover!(self, self.history_order(), service => service.get_scripts_history(scripts).await)
+    }
+
+    async fn get_script_history_with_retry(
+        &self,
+        script: &Script,
+        retries: u64,
+    ) -> Result<Vec<History>> {
+        with_failover!(self, self.history_order(), service => service.get_script_history_with_retry(script, retries).await)
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        with_failover!(self, self.history_order(), service => service.get_script_utxos(script).await)
+    }
+
+    async fn verify_tx(
+        &self,
+        address: &Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        with_failover!(self, self.history_order(), service => service.verify_tx(address, tx_id, tx_hex, verify_confirmation).await)
+    }
+
+    async fn verify_tx_with_quorum(
+        &self,
+        address: &Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        let mut verified = vec![];
+        for index in self.history_order() {
+            match self.services[index]
+                .verify_tx(address, tx_id, tx_hex, verify_confirmation)
+                .await
+            {
+                Ok(tx) => verified.push(tx),
+                Err(e) => warn!("Liquid chain backend {index} could not verify tx {tx_id}: {e:?}"),
+            }
+            if verified.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        match verified.len() {
+            CHAIN_QUORUM => Ok(verified.remove(0)),
+            n => Err(ChainServiceError::from(anyhow!(
+                "Liquid transaction {tx_id} was verified by {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
+        }
+    }
+
+    async fn get_script_history_with_quorum(&self, script: &Script) -> Result<Vec<History>> {
+        let mut histories = vec![];
+        for index in self.history_order() {
+            match self.services[index].get_script_history(script).await {
+                Ok(history) => histories.push(history),
+                Err(e) => {
+                    warn!("Liquid chain backend {index} failed to fetch the script history: {e:?}")
+                }
+            }
+            if histories.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        match histories.len() {
+            CHAIN_QUORUM => Ok(history_agreed_by_all(histories)),
+            n => Err(ChainServiceError::from(anyhow!(
+                "Liquid script history was fetched from {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
+        }
+    }
+
+    async fn tip_with_quorum(&mut self) -> Result<u32> {
+        let mut tips = vec![];
+        for index in self.failover_order() {
+            match self.services[index].tip().await {
+                Ok(tip) => tips.push(tip),
+                Err(e) => warn!("Liquid chain backend {index} failed to fetch the tip: {e:?}"),
+            }
+            if tips.len() == CHAIN_QUORUM {
+                break;
+            }
+        }
+        // Taking the lowest tip, a single backend can't make a swap look expired
+        let n = tips.len();
+        match tips.into_iter().min() {
+            Some(tip) if n == CHAIN_QUORUM => Ok(tip),
+            _ => Err(ChainServiceError::from(anyhow!(
+                "Liquid tip was fetched from {n} of the {CHAIN_QUORUM} required chain backends"
+            ))
+            .into()),
+        }
+    }
+}
+
+/// A backend which couldn't be reached at startup. It's connected to on first use, so that the
+/// SDK can start while offline.
+struct LazyLiquidChainService {
+    config: Config,
+    backend: ChainBackend,
+    service: OnceCell<Box<dyn LiquidChainService>>,
+}
+
+impl LazyLiquidChainService {
+    fn new(config: &Config, backend: &ChainBackend) -> Self {
+        Self {
+            config: config.clone(),
+            backend: backend.clone(),
+            service: OnceCell::new(),
+        }
+    }
+
+    async fn service(&self) -> Result<&dyn LiquidChainService> {
+        let service = self
+            .service
+            .get_or_try_init(|| async { new_backend_service(&self.config, &self.backend) })
+            .await?;
+        Ok(service.as_ref())
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for LazyLiquidChainService {
+    fn has_full_history(&self) -> bool {
+        !matches!(self.backend, ChainBackend::Rpc { .. })
+    }
+
+    async fn tip(&mut self) -> Result<u32> {
+        self.service().await?;
+        match self.service.get_mut() {
+            Some(service) => service.tip().await,
+            None => Err(anyhow!("Liquid chain backend is not connected")),
+        }
+    }
+
+    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
+        self.service().await?.broadcast(tx, swap_id).await
+    }
+
+    async fn get_transaction_hex(&self, txid: &Txid) -> Result<Option<Transaction>> {
+        self.service().await?.get_transaction_hex(txid).await
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        self.service().await?.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        self.service().await?.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+        self.service().await?.get_scripts_history(scripts).await
+    }
+
+    async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
+        self.service().await?.get_script_utxos(script).await
+    }
+}
+
+/// Creates the [LiquidChainService] for the configured [ChainBackend] and its fallbacks.
+///
+/// Backends that can't be reached at startup are connected to on first use.
+pub(crate) fn new_liquid_chain_service(
+    config: &Config,
+) -> Result<Arc<Mutex<dyn LiquidChainService>>> {
+    let mut services: Vec<Box<dyn LiquidChainService>> = vec![];
+    for backend in config.liquid_chain_backends() {
+        match new_backend_service(config, backend) {
+            Ok(service) => services.push(service),
+            Err(e) => {
+                warn!("Could not connect to Liquid chain backend {backend:?}, retrying on first use: {e:?}");
+                services.push(Box::new(LazyLiquidChainService::new(config, backend)));
+            }
+        }
+    }
+    Ok(Arc::new(Mutex::new(FailoverLiquidChainService::new(
+        services,
+    ))))
+}
+
+fn new_backend_service(
+    config: &Config,
+    backend: &ChainBackend,
+) -> Result<Box<dyn LiquidChainService>> {
+    Ok(match backend {
+        ChainBackend::Electrum { url } => Box::new(ElectrumLiquidChainService::new(url)?),
+        ChainBackend::Esplora { url } => Box::new(EsploraLiquidChainService::new(
+            url,
+            breez_api_key(config, url),
+        )),
+        ChainBackend::Hybrid {
+            electrum_url,
+            esplora_url,
+        } => Box::new(HybridLiquidChainService::new(
+            electrum_url,
+            esplora_url,
+            breez_api_key(config, esplora_url),
+        )?),
+        ChainBackend::Rpc {
+            url,
+            username,
+            password,
+        } => Box::new(RpcLiquidChainService::new(
+            url,
+            username.clone(),
+            password.clone(),
+        )),
+    })
+}
+
+/// The Breez API key is only sent over TLS to Esplora instances hosted by Breez
+fn breez_api_key(config: &Config, esplora_url: &str) -> Option<String> {
+    let url = Url::parse(esplora_url).ok()?;
+    let is_breez_host = url
+        .host_str()
+        .is_some_and(|host| host == "breez.technology" || host.ends_with(".breez.technology"));
+    match url.scheme() == "https" && is_breez_host {
+        true => config.breez_api_key.clone(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        str::FromStr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use anyhow::{anyhow, Result};
+    use async_trait::async_trait;
+    use lwk_wollet::{
+        elements::{Script, Transaction, Txid},
+        History,
+    };
+
+    use crate::model::Config;
+
+    use super::{breez_api_key, FailoverLiquidChainService, LiquidChainService};
+
+    /// A backend answering with a fixed tip and history, or failing with the given error
+    struct TestLiquidChainService {
+        tip: u32,
+        history: Vec<History>,
+        error: Option<fn() -> anyhow::Error>,
+        full_history: bool,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl TestLiquidChainService {
+        fn new(
+            tip: u32,
+            history: Vec<History>,
+            calls: &Arc<AtomicUsize>,
+        ) -> Box<dyn LiquidChainService> {
+            Box::new(Self {
+                tip,
+                history,
+                error: None,
+                full_history: true,
+                calls: calls.clone(),
+            })
+        }
+
+        /// A backend which, like an RPC one, doesn't see the full history of a script
+        fn utxos_only(
+            tip: u32,
+            history: Vec<History>,
+            calls: &Arc<AtomicUsize>,
+        ) -> Box<dyn LiquidChainService> {
+            Box::new(Self {
+                tip,
+                history,
+                error: None,
+                full_history: false,
+                calls: calls.clone(),
+            })
+        }
+
+        fn failing(
+            error: fn() -> anyhow::Error,
+            calls: &Arc<AtomicUsize>,
+        ) -> Box<dyn LiquidChainService> {
+            Box::new(Self {
+                tip: 0,
+                history: vec![],
+                error: Some(error),
+                full_history: true,
+                calls: calls.clone(),
+            })
+        }
+
+        fn answer<T>(&self, res: T) -> Result<T> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            match self.error {
+                Some(error) => Err(error()),
+                None => Ok(res),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LiquidChainService for TestLiquidChainService {
+        fn has_full_history(&self) -> bool {
+            self.full_history
+        }
+
+        async fn tip(&mut self) -> Result<u32> {
+            self.answer(self.tip)
+        }
+
+        async fn broadcast(&self, tx: &Transaction, _swap_id: Option<&str>) -> Result<Txid> {
+            self.answer(tx.txid())
+        }
+
+        async fn get_transaction_hex(&self, _txid: &Txid) -> Result<Option<Transaction>> {
+            self.answer(None)
+        }
+
+        async fn get_transactions(&self, _txids: &[Txid]) -> Result<Vec<Transaction>> {
+            self.answer(vec![])
+        }
+
+        async fn get_script_history(&self, _script: &Script) -> Result<Vec<History>> {
+            self.answer(self.history.clone())
+        }
+
+        async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+            self.answer(vec![self.history.clone(); scripts.len()])
+        }
+    }
+
+    fn transport_error() -> anyhow::Error {
+        std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused").into()
+    }
+
+    fn backend_error() -> anyhow::Error {
+        anyhow!("bad-txns-inputs-missingorspent")
+    }
+
+    fn txs(history: &[History]) -> Vec<(Txid, i32)> {
+        history.iter().map(|h| (h.txid, h.height)).collect()
+    }
+
+    fn history(txid_byte: &str, height: i32) -> History {
         History {
-            txid: value.txid,
-            height: status.block_height.unwrap_or_default(),
-            block_hash: status.block_hash,
+            txid: Txid::from_str(&txid_byte.repeat(32)).unwrap(),
+            height,
+            block_hash: None,
             block_timestamp: None,
         }
     }
+
+    #[tokio::test]
+    async fn test_failover_on_transport_errors_only() -> Result<()> {
+        let first_calls = Arc::new(AtomicUsize::new(0));
+        let second_calls = Arc::new(AtomicUsize::new(0));
+        let service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::failing(transport_error, &first_calls),
+            TestLiquidChainService::new(100, vec![history("11", 10)], &second_calls),
+        ]);
+        let res = service.get_script_history(&Script::new()).await?;
+        assert_eq!(txs(&res), txs(&[history("11", 10)]));
+        assert_eq!(first_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(second_calls.load(Ordering::Relaxed), 1);
+
+        // The backend which answered is now the active one
+        service.get_script_history(&Script::new()).await?;
+        assert_eq!(first_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(second_calls.load(Ordering::Relaxed), 2);
+
+        let first_calls = Arc::new(AtomicUsize::new(0));
+        let second_calls = Arc::new(AtomicUsize::new(0));
+        let service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::failing(backend_error, &first_calls),
+            TestLiquidChainService::new(100, vec![history("11", 10)], &second_calls),
+        ]);
+        let err = service
+            .get_script_history(&Script::new())
+            .await
+            .unwrap_err();
+        assert!(format!("{err:?}").contains("bad-txns-inputs-missingorspent"));
+        assert_eq!(first_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(second_calls.load(Ordering::Relaxed), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_history_skips_utxo_only_backends() -> Result<()> {
+        let rpc_calls = Arc::new(AtomicUsize::new(0));
+        let electrum_calls = Arc::new(AtomicUsize::new(0));
+        let mut service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::utxos_only(100, vec![history("11", 10)], &rpc_calls),
+            TestLiquidChainService::new(
+                100,
+                vec![history("22", 5), history("11", 10)],
+                &electrum_calls,
+            ),
+        ]);
+        assert_eq!(service.tip().await?, 100);
+        assert_eq!(rpc_calls.load(Ordering::Relaxed), 1);
+
+        let res = service.get_script_history(&Script::new()).await?;
+        assert_eq!(txs(&res), txs(&[history("22", 5), history("11", 10)]));
+        assert_eq!(rpc_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(electrum_calls.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tip_failover() -> Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::failing(transport_error, &calls),
+            TestLiquidChainService::new(100, vec![], &calls),
+        ]);
+        assert_eq!(service.tip().await?, 100);
+
+        let mut service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::failing(backend_error, &calls),
+            TestLiquidChainService::new(100, vec![], &calls),
+        ]);
+        assert!(service.tip().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_script_history_with_quorum() -> Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::new(
+                100,
+                vec![history("11", 10), history("22", 20), history("33", 30)],
+                &calls,
+            ),
+            TestLiquidChainService::failing(transport_error, &calls),
+            TestLiquidChainService::new(100, vec![history("11", 10), history("22", 0)], &calls),
+        ]);
+        // Only the txs seen by both backends are kept, and only confirmed if both agree
+        let res = service
+            .get_script_history_with_quorum(&Script::new())
+            .await?;
+        assert_eq!(txs(&res), txs(&[history("11", 10), history("22", 0)]));
+
+        let service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::new(100, vec![history("11", 10)], &calls),
+            TestLiquidChainService::failing(transport_error, &calls),
+        ]);
+        assert!(service
+            .get_script_history_with_quorum(&Script::new())
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tip_with_quorum() -> Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut service = FailoverLiquidChainService::new(vec![
+            TestLiquidChainService::new(1_000_000, vec![], &calls),
+            TestLiquidChainService::failing(transport_error, &calls),
+            TestLiquidChainService::new(100, vec![], &calls),
+        ]);
+        assert_eq!(service.tip_with_quorum().await?, 100);
+
+        let mut service =
+            FailoverLiquidChainService::new(vec![TestLiquidChainService::new(100, vec![], &calls)]);
+        assert!(service.tip_with_quorum().await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_breez_api_key() {
+        let config = Config::mainnet("api_key".to_string());
+        let api_key = |url| breez_api_key(&config, url);
+
+        assert_eq!(
+            api_key("https://lq1.breez.technology/liquid/api").as_deref(),
+            Some("api_key")
+        );
+        assert!(api_key("http://lq1.breez.technology/liquid/api").is_none());
+        assert!(api_key("https://breez.technology.example.com/api").is_none());
+        assert!(api_key("https://example.com/.breez.technology/api").is_none());
+        assert!(api_key("https://example.com/api?host=lq1.breez.technology").is_none());
+        assert!(api_key("lq1.breez.technology").is_none());
+    }
 }
@@ -1,2 +1,348 @@
+/// Runs `$call` against the services of a failover chain service, starting from the active one,
+/// until one of them answers. The first service to answer becomes the active one.
+///
+/// Only transport errors fail over to the next service. Any other error is an answer of the
+/// backend, e.g. a rejected broadcast, and is returned as is.
+///
+/// The services can be restricted to an `$order` of indices, e.g. to those which see full
+/// script histories.
+macro_rules! with_failover {
+    ($self:ident, $service:ident => $call:expr) => {
+        with_failover!($self, $self.failover_order(), $service => $call)
+    };
+    ($self:ident, $order:expr, $service:ident => $call:expr) => {{
+        let mut last_err = None;
+        for index in $order {
+            let $service = &$self.services[index];
+            match $call {
+                Ok(res) => {
+                    $self.set_active(index);
+                    return Ok(res);
+                }
+                Err(e) if !$crate::chain::is_transport_error(&e) => {
+                    $self.set_active(index);
+                    return Err($crate::error::ChainServiceError::from(e).into());
+                }
+                Err(e) => {
+                    log::warn!("Chain backend {index} failed: {e:?}");
+                    last_err = Some(e);
+                }
+            }
+        }
+        let err = last_err.unwrap_or_else(|| anyhow::anyhow!("No chain backend available"));
+        Err($crate::error::ChainServiceError::from(err).into())
+    }};
+}
+
 pub(crate) mod bitcoin;
+pub(crate) mod esplora;
 pub(crate) mod liquid;
+pub(crate) mod rpc;
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use log::info;
+use lwk_wollet::{elements::Txid, History};
+
+/// Splits an Electrum URL into the `host:port` to connect to and whether to use TLS,
+/// which is the case unless the URL is prefixed with `tcp://`
+pub(crate) fn parse_electrum_url(url: &str) -> (&str, bool) {
+    match url.strip_prefix("tcp://") {
+        Some(host) => (host, false),
+        None => (url.strip_prefix("ssl://").unwrap_or(url), true),
+    }
+}
+
+/// The number of chain backends that have to agree on a transaction for a quorum check to pass
+pub(crate) const CHAIN_QUORUM: usize = 2;
+
+/// Whether the error is caused by the connection to the chain backend rather than by its answer,
+/// in which case the request can be retried against another backend
+pub(crate) fn is_transport_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        if let Some(e) = cause.downcast_ref::<electrum_client::Error>() {
+            return is_electrum_transport_error(e);
+        }
+        if let Some(lwk_wollet::Error::ElectrumClient(e)) =
+            cause.downcast_ref::<lwk_wollet::Error>()
+        {
+            return is_electrum_transport_error(e);
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            return e.is_connect()
+                || e.is_timeout()
+                || e.is_request()
+                || e.status().is_some_and(|status| {
+                    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                });
+        }
+        cause.is::<std::io::Error>()
+    })
+}
+
+fn is_electrum_transport_error(err: &electrum_client::Error) -> bool {
+    match err {
+        electrum_client::Error::IOError(_)
+        | electrum_client::Error::SharedIOError(_)
+        | electrum_client::Error::CouldntLockReader
+        | electrum_client::Error::Mpsc => true,
+        electrum_client::Error::AllAttemptsErrored(errors) => {
+            errors.iter().any(is_electrum_transport_error)
+        }
+        _ => false,
+    }
+}
+
+/// How long a Liquid tx reported by the swapper is looked up before giving up, as the chain
+/// backend may not have seen it yet
+pub(crate) const LIQUID_VERIFY_TX_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a Bitcoin tx reported by the swapper is looked up before giving up
+pub(crate) const BITCOIN_VERIFY_TX_TIMEOUT: Duration = Duration::from_secs(15);
+
+const VERIFY_TX_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Retries `verify` every second until it succeeds, the `timeout` elapses or the `deadline`, if
+/// any, is reached.
+///
+/// `verify` has to lock the chain service itself, so that the chain service isn't locked while
+/// waiting for the next attempt.
+pub(crate) async fn verify_tx_with_retry<T, F, Fut>(
+    timeout: Duration,
+    deadline: Option<Instant>,
+    mut verify: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let timeout_at = Instant::now() + timeout;
+    let deadline = deadline.map_or(timeout_at, |deadline| deadline.min(timeout_at));
+    loop {
+        match verify().await {
+            Ok(res) => return Ok(res),
+            Err(e) if Instant::now() + VERIFY_TX_RETRY_INTERVAL < deadline => {
+                info!("Could not verify tx, retrying in 1 second: {e}");
+                tokio::time::sleep(VERIFY_TX_RETRY_INTERVAL).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// How a chain backend answered a tx broadcast which did not succeed
+#[derive(Debug, PartialEq)]
+pub(crate) enum BroadcastRejection {
+    /// The tx is already in the mempool or in a block
+    AlreadyKnown,
+    /// The backend could not be reached or can't accept the tx right now, e.g. because it's
+    /// rate limited or its mempool is full, so the broadcast can be retried later
+    Temporary,
+    /// The tx was rejected by consensus or policy rules and will never be accepted as is
+    Rejected,
+}
+
+const ALREADY_KNOWN_REASONS: [&str; 3] = [
+    "txn-already-known",
+    "txn-already-in-mempool",
+    "transaction already in block chain",
+];
+
+// The static fee policies, such as "min relay fee not met" or "insufficient fee", are left out,
+// as a tx rejected by them will never be accepted as is
+const TEMPORARY_REJECTION_REASONS: [&str; 5] = [
+    "mempool full",
+    "mempool min fee not met",
+    "too-long-mempool-chain",
+    "non-final",
+    "non-bip68-final",
+];
+
+pub(crate) fn classify_broadcast_error(err: &anyhow::Error) -> BroadcastRejection {
+    if is_transport_error(err) {
+        return BroadcastRejection::Temporary;
+    }
+    let reason = format!("{err:#}").to_lowercase();
+    if ALREADY_KNOWN_REASONS.iter().any(|r| reason.contains(r)) {
+        return BroadcastRejection::AlreadyKnown;
+    }
+    if TEMPORARY_REJECTION_REASONS
+        .iter()
+        .any(|r| reason.contains(r))
+    {
+        return BroadcastRejection::Temporary;
+    }
+    BroadcastRejection::Rejected
+}
+
+/// Keeps the history entries which all the given histories agree on. An entry is only
+/// considered confirmed if it's confirmed in all of them.
+pub(crate) fn history_agreed_by_all(histories: Vec<Vec<History>>) -> Vec<History> {
+    let mut histories = histories.into_iter();
+    let Some(first) = histories.next() else {
+        return vec![];
+    };
+    let others: Vec<HashMap<Txid, i32>> = histories
+        .map(|history| history.into_iter().map(|h| (h.txid, h.height)).collect())
+        .collect();
+    first
+        .into_iter()
+        .filter_map(|mut entry| {
+            for other in &others {
+                let height = other.get(&entry.txid)?;
+                if *height < entry.height {
+                    entry.height = *height;
+                    entry.block_hash = None;
+                    entry.block_timestamp = None;
+                }
+            }
+            Some(entry)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{Duration, Instant},
+    };
+
+    use anyhow::{anyhow, Result};
+
+    use super::{
+        classify_broadcast_error, is_transport_error, parse_electrum_url, verify_tx_with_retry,
+        BroadcastRejection,
+    };
+
+    #[test]
+    fn test_parse_electrum_url() {
+        assert_eq!(
+            parse_electrum_url("elements-mainnet.breez.technology:50002"),
+            ("elements-mainnet.breez.technology:50002", true)
+        );
+        assert_eq!(
+            parse_electrum_url("ssl://blockstream.info:995"),
+            ("blockstream.info:995", true)
+        );
+        assert_eq!(
+            parse_electrum_url("tcp://localhost:19002"),
+            ("localhost:19002", false)
+        );
+    }
+
+    #[test]
+    fn test_is_transport_error() {
+        let io_error =
+            || std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset");
+
+        assert!(is_transport_error(&io_error().into()));
+        assert!(is_transport_error(
+            &electrum_client::Error::IOError(io_error()).into()
+        ));
+        assert!(is_transport_error(
+            &lwk_wollet::Error::ElectrumClient(electrum_client::Error::IOError(io_error())).into()
+        ));
+        assert!(is_transport_error(
+            &anyhow::Error::from(io_error()).context("Failed to fetch the tip")
+        ));
+
+        assert!(!is_transport_error(&anyhow!(
+            "bad-txns-inputs-missingorspent"
+        )));
+        assert!(!is_transport_error(
+            &electrum_client::Error::Protocol("missing transaction".into()).into()
+        ));
+    }
+
+    #[test]
+    fn test_classify_broadcast_error() {
+        let io_error =
+            || std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+
+        assert_eq!(
+            classify_broadcast_error(&io_error().into()),
+            BroadcastRejection::Temporary
+        );
+        for reason in [
+            "sendrawtransaction RPC error: {\"code\":-26,\"message\":\"mempool full\"}",
+            "Mempool min fee not met",
+            "too-long-mempool-chain",
+            "non-BIP68-final",
+        ] {
+            assert_eq!(
+                classify_broadcast_error(&anyhow!(reason.to_string())),
+                BroadcastRejection::Temporary,
+                "{reason}"
+            );
+        }
+
+        for reason in ["txn-already-known", "Transaction already in block chain"] {
+            assert_eq!(
+                classify_broadcast_error(&anyhow!(reason.to_string())),
+                BroadcastRejection::AlreadyKnown,
+                "{reason}"
+            );
+        }
+
+        for reason in [
+            "bad-txns-inputs-missingorspent",
+            "mandatory-script-verify-flag-failed",
+            "txn-mempool-conflict",
+            "dust",
+            "Failed to broadcast tx: 400 Bad Request min relay fee not met, 100 < 251",
+            "insufficient fee, rejecting replacement",
+        ] {
+            assert_eq!(
+                classify_broadcast_error(&anyhow!(reason.to_string())),
+                BroadcastRejection::Rejected,
+                "{reason}"
+            );
+        }
+        assert_eq!(
+            classify_broadcast_error(
+                &electrum_client::Error::Protocol("bad-txns-in-belowout".into()).into()
+            ),
+            BroadcastRejection::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_tx_with_retry() -> Result<()> {
+        let calls = &AtomicUsize::new(0);
+        let verify = move || async move {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(anyhow!("Liquid transaction was not found")),
+                _ => Ok(()),
+            }
+        };
+
+        // The tx is looked up again until it's found
+        verify_tx_with_retry(Duration::from_secs(30), None, verify).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // It's looked up once if the deadline leaves no time for a retry
+        calls.store(0, Ordering::SeqCst);
+        let started_at = Instant::now();
+        assert!(
+            verify_tx_with_retry(Duration::from_secs(30), Some(Instant::now()), verify)
+                .await
+                .is_err()
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+
+        calls.store(0, Ordering::SeqCst);
+        assert!(verify_tx_with_retry(Duration::ZERO, None, verify)
+            .await
+            .is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use lwk_wollet::{elements::Txid, History};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+/// Confirmation targets, in blocks, of the fee estimates in [crate::model::RecommendedFees]
+pub(crate) const FEE_ESTIMATE_TARGETS: [u16; 5] = [1, 3, 6, 144, 1008];
+
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct ScanTxOutSetResult {
+    unspents: Vec<RpcUtxo>,
+}
+
+#[derive(Clone, Deserialize)]
+pub(crate) struct RpcUtxo {
+    pub(crate) txid: Txid,
+    pub(crate) vout: u32,
+    #[serde(rename = "scriptPubKey")]
+    pub(crate) script_pubkey: String,
+    /// The amount in BTC. Only meaningful for Bitcoin and explicit Liquid outputs.
+    #[serde(default)]
+    pub(crate) amount: f64,
+    pub(crate) height: i32,
+}
+
+impl RpcUtxo {
+    pub(crate) fn amount_sat(&self) -> u64 {
+        (self.amount * 100_000_000.0).round() as u64
+    }
+}
+
+/// Builds a script history out of its unspent outputs, one entry per transaction
+pub(crate) fn history_from_utxos(utxos: Option<&Vec<RpcUtxo>>) -> Vec<History> {
+    let mut history: Vec<History> = vec![];
+    for utxo in utxos.into_iter().flatten() {
+        if history.iter().all(|h| h.txid != utxo.txid) {
+            history.push(History {
+                txid: utxo.txid,
+                height: utxo.height,
+                block_hash: None,
+                block_timestamp: None,
+            });
+        }
+    }
+    history
+}
+
+#[derive(Deserialize)]
+struct EstimateSmartFeeResult {
+    feerate: Option<f64>,
+}
+
+/// A JSON-RPC client for a bitcoind or elementsd node.
+///
+/// The node needs `txindex=1` to look up transactions which aren't in its wallet or mempool.
+pub(crate) struct RpcClient {
+    url: String,
+    username: Option<String>,
+    password: Option<String>,
+    client: reqwest::Client,
+}
+
+impl RpcClient {
+    pub(crate) fn new(url: &str, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            url: url.to_string(),
+            username,
+            password,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let mut req = self.client.post(&self.url).json(&json!({
+            "jsonrpc": "1.0",
+            "id": "breez-sdk-liquid",
+            "method": method,
+            "params": params,
+        }));
+        if let Some(username) = &self.username {
+            req = req.basic_auth(username, self.password.as_ref());
+        }
+
+        let response: RpcResponse<T> = req.send().await?.json().await?;
+        match (response.result, response.error) {
+            (_, Some(RpcError { code, message })) => Err(anyhow!(
+                "RPC call {method} failed with code {code}: {message}"
+            )),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(anyhow!("RPC call {method} returned no result")),
+        }
+    }
+
+    pub(crate) async fn block_count(&self) -> Result<u32> {
+        self.call("getblockcount", json!([])).await
+    }
+
+    pub(crate) async fn best_block_header_hex(&self) -> Result<String> {
+        let hash: String = self.call("getbestblockhash", json!([])).await?;
+        self.call("getblockheader", json!([hash, false])).await
+    }
+
+    /// Broadcasts the hex encoded transaction, returning its txid
+    pub(crate) async fn send_raw_transaction(&self, tx_hex: &str) -> Result<String> {
+        info!("Broadcasting tx via RPC: {tx_hex}");
+        self.call("sendrawtransaction", json!([tx_hex])).await
+    }
+
+    /// Returns the hex encoded transaction, or `None` if the node doesn't know it
+    pub(crate) async fn get_raw_transaction(&self, txid: &str) -> Result<Option<String>> {
+        match self
+            .call::<String>("getrawtransaction", json!([txid, false]))
+            .await
+        {
+            Ok(tx_hex) => Ok(Some(tx_hex)),
+            // -5: No such mempool or blockchain transaction
+            Err(e) if e.to_string().contains("code -5") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Scans the UTXO set for outputs locked to the given scripts, grouped by script.
+    ///
+    /// Only confirmed and unspent outputs are returned, so spent outputs and mempool
+    /// transactions don't show up in the script history.
+    pub(crate) async fn scan_utxos(
+        &self,
+        scripts_hex: &[String],
+    ) -> Result<HashMap<String, Vec<RpcUtxo>>> {
+        let descriptors: Vec<String> = scripts_hex
+            .iter()
+            .map(|script_hex| format!("raw({script_hex})"))
+            .collect();
+        let result: ScanTxOutSetResult = self
+            .call("scantxoutset", json!(["start", descriptors]))
+            .await?;
+
+        let mut utxos_by_script: HashMap<String, Vec<RpcUtxo>> = HashMap::new();
+        for utxo in result.unspents {
+            utxos_by_script
+                .entry(utxo.script_pubkey.clone())
+                .or_default()
+                .push(utxo);
+        }
+        Ok(utxos_by_script)
+    }
+
+    /// Estimates the fee rate, in sat/vbyte, for a confirmation within `target_blocks`
+    pub(crate) async fn estimate_fee_rate(&self, target_blocks: u16) -> Result<u64> {
+        let result: EstimateSmartFeeResult = self
+            .call("estimatesmartfee", json!([target_blocks]))
+            .await?;
+        let fee_rate_btc_per_kvb = result
+            .feerate
+            .ok_or(anyhow!("Not enough data to estimate the fee rate"))?;
+        // BTC/kvB to sat/vB
+        Ok((fee_rate_btc_per_kvb * 100_000.0).ceil() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{history_from_utxos, RpcUtxo};
+
+    #[test]
+    fn test_history_from_utxos() -> Result<()> {
+        let utxos: Vec<RpcUtxo> = serde_json::from_str(
+            r#"[
+                {"txid": "6f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "vout": 0, "scriptPubKey": "0014aa", "amount": 0.0001, "height": 101},
+                {"txid": "6f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "vout": 1, "scriptPubKey": "0014aa", "amount": 0.00025, "height": 101},
+                {"txid": "1f3a0a8fd18a0a3b2d1fb4b4b2e7e9c1b36f8b9a1a8f3b7c8e0d7e6f5a4b3c2d", "vout": 0, "scriptPubKey": "0014aa", "height": 102}
+            ]"#,
+        )?;
+        assert_eq!(utxos[0].amount_sat(), 10_000);
+        assert_eq!(utxos[1].amount_sat(), 25_000);
+        assert_eq!(utxos[2].amount_sat(), 0);
+
+        let history = history_from_utxos(Some(&utxos));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].height, 101);
+        assert_eq!(history[1].height, 102);
+        assert!(history_from_utxos(None).is_empty());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::{str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Result};
@@ -18,17 +18,23 @@ use tokio::sync::{broadcast, watch, Mutex};
 use tokio::time::MissedTickBehavior;
 
 use crate::{
-    chain::{bitcoin::BitcoinChainService, liquid::LiquidChainService},
+    chain::{
+        bitcoin::BitcoinChainService, liquid::LiquidChainService, verify_tx_with_retry,
+        BITCOIN_VERIFY_TX_TIMEOUT, LIQUID_VERIFY_TX_TIMEOUT,
+    },
     ensure_sdk,
     error::{PaymentError, SdkError, SdkResult},
+    event::EventManager,
+    logger::LogSpan,
     model::{
         ChainSwap, Config, Direction,
         PaymentState::{self, *},
-        PaymentTxData, PaymentType, Swap, SwapScriptV2, Transaction as SdkTransaction,
+        PaymentTxData, PaymentType, PrepareRefundResponse, SdkEvent, Swap, SwapScriptV2,
+        Transaction as SdkTransaction,
     },
     persist::Persister,
     sdk::CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS,
-    swapper::Swapper,
+    swapper::{self, Swapper},
     utils,
     wallet::OnchainWallet,
 };
@@ -43,6 +49,7 @@ pub(crate) struct ChainSwapHandler {
     swapper: Arc<dyn Swapper>,
     liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
     bitcoin_chain_service: Arc<Mutex<dyn BitcoinChainService>>,
+    event_manager: Arc<EventManager>,
     subscription_notifier: broadcast::Sender<String>,
 }
 
@@ -54,6 +61,7 @@ impl ChainSwapHandler {
         swapper: Arc<dyn Swapper>,
         liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
         bitcoin_chain_service: Arc<Mutex<dyn BitcoinChainService>>,
+        event_manager: Arc<EventManager>,
     ) -> Result<Self> {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Ok(Self {
@@ -63,15 +71,19 @@ impl ChainSwapHandler {
             swapper,
             liquid_chain_service,
             bitcoin_chain_service,
+            event_manager,
             subscription_notifier,
         })
     }
 
     pub(crate) async fn start(self: Arc<Self>, mut shutdown: watch::Receiver<()>) {
         let cloned = self.clone();
+        let intervals = self.config.scheduler_intervals();
         tokio::spawn(async move {
-            let mut bitcoin_rescan_interval = tokio::time::interval(Duration::from_secs(60 * 10));
-            let mut liquid_rescan_interval = tokio::time::interval(Duration::from_secs(60));
+            let mut bitcoin_rescan_interval =
+                tokio::time::interval(Duration::from_secs(intervals.bitcoin_rescan_secs));
+            let mut liquid_rescan_interval =
+                tokio::time::interval(Duration::from_secs(intervals.liquid_rescan_secs));
             bitcoin_rescan_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
             liquid_rescan_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
@@ -103,25 +115,52 @@ impl ChainSwapHandler {
         self.subscription_notifier.subscribe()
     }
 
-    /// Handles status updates from Boltz for Chain swaps
-    pub(crate) async fn on_new_status(&self, update: &boltz::Update) -> Result<()> {
+    /// Handles status updates from Boltz for Chain swaps.
+    ///
+    /// The lockup txs are looked up until the `verify_deadline`, if any, see
+    /// [verify_tx_with_retry].
+    pub(crate) async fn on_new_status(
+        &self,
+        update: &boltz::Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
         let id = &update.id;
         let swap = self
             .persister
             .fetch_chain_swap_by_id(id)?
             .ok_or(anyhow!("No ongoing Chain Swap found for ID {id}"))?;
 
-        match swap.direction {
-            Direction::Incoming => self.on_new_incoming_status(&swap, update).await,
-            Direction::Outgoing => self.on_new_outgoing_status(&swap, update).await,
-        }
+        let handle_status = async {
+            match swap.direction {
+                Direction::Incoming => {
+                    self.on_new_incoming_status(&swap, update, verify_deadline)
+                        .await
+                }
+                Direction::Outgoing => {
+                    self.on_new_outgoing_status(&swap, update, verify_deadline)
+                        .await
+                }
+            }
+        };
+        Self::log_span(&swap)
+            .scope(swapper::with_swapper_status(&update.status, handle_status))
+            .await
+    }
+
+    /// The log span of a swap, with the tx id of its payment if already known
+    fn log_span(swap: &ChainSwap) -> LogSpan {
+        let payment_id = match swap.direction {
+            Direction::Incoming => swap.claim_tx_id.clone(),
+            Direction::Outgoing => swap.user_lockup_tx_id.clone(),
+        };
+        LogSpan::swap(&swap.id, payment_id)
     }
 
     pub(crate) async fn rescan_incoming_user_lockup_txs(
         &self,
         ignore_monitoring_block_height: bool,
     ) -> Result<()> {
-        let current_height = self.bitcoin_chain_service.lock().await.tip()?.height as u32;
+        let current_height = self.bitcoin_chain_service.lock().await.tip().await?.height as u32;
         let chain_swaps: Vec<ChainSwap> = self
             .persister
             .list_chain_swaps()?
@@ -176,7 +215,8 @@ impl ChainSwapHandler {
                 .bitcoin_chain_service
                 .lock()
                 .await
-                .script_get_balance(script_pubkey.as_script())?;
+                .script_get_balance(script_pubkey.as_script())
+                .await?;
             info!(
                 "Incoming Chain Swap {} has {} confirmed and {} unconfirmed sats",
                 swap.id, script_balance.confirmed, script_balance.unconfirmed
@@ -268,7 +308,7 @@ impl ChainSwapHandler {
     }
 
     pub(crate) async fn rescan_outgoing_claim_txs(&self) -> Result<()> {
-        let current_height = self.bitcoin_chain_service.lock().await.tip()?.height as u32;
+        let current_height = self.bitcoin_chain_service.lock().await.tip().await?.height as u32;
         let chain_swaps: Vec<ChainSwap> = self
             .persister
             .list_chain_swaps()?
@@ -295,11 +335,20 @@ impl ChainSwapHandler {
             let address = Address::from_str(claim_address)?;
             let claim_tx_id = swap.claim_tx_id.clone().ok_or(anyhow!("No claim tx id"))?;
             let script_pubkey = address.assume_checked().script_pubkey();
-            let script_history = self
-                .bitcoin_chain_service
-                .lock()
-                .await
-                .get_script_history(script_pubkey.as_script())?;
+            let bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
+            let script_history = match self.config.requires_chain_quorum(swap.receiver_amount_sat) {
+                true => {
+                    bitcoin_chain_service
+                        .get_script_history_with_quorum(script_pubkey.as_script())
+                        .await?
+                }
+                false => {
+                    bitcoin_chain_service
+                        .get_script_history(script_pubkey.as_script())
+                        .await?
+                }
+            };
+            drop(bitcoin_chain_service);
             let claim_tx_history = script_history
                 .iter()
                 .find(|h| h.txid.to_hex().eq(&claim_tx_id) && h.height > 0);
@@ -315,13 +364,18 @@ impl ChainSwapHandler {
         Ok(())
     }
 
-    async fn on_new_incoming_status(&self, swap: &ChainSwap, update: &boltz::Update) -> Result<()> {
+    async fn on_new_incoming_status(
+        &self,
+        swap: &ChainSwap,
+        update: &boltz::Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
         let id = &update.id;
         let status = &update.status;
         let swap_state = ChainSwapStates::from_str(status)
             .map_err(|_| anyhow!("Invalid ChainSwapState for Chain Swap {id}: {status}"))?;
 
-        info!("Handling incoming Chain Swap transition to {status:?} for swap {id}");
+        info!(status:% = status; "Handling incoming Chain Swap transition to {status:?} for swap {id}");
         // See https://docs.boltz.exchange/v/api/lifecycle#chain-swaps
         match swap_state {
             // Boltz announced the user lockup tx is in the mempool or has been confirmed.
@@ -348,7 +402,7 @@ impl ChainSwapHandler {
                         };
 
                         if let Err(e) = self
-                            .verify_server_lockup_tx(swap, &transaction, false)
+                            .verify_server_lockup_tx(swap, &transaction, false, verify_deadline)
                             .await
                         {
                             warn!("Server lockup mempool transaction for incoming Chain Swap {} could not be verified. txid: {}, err: {}",
@@ -388,13 +442,14 @@ impl ChainSwapHandler {
                             return Err(anyhow!("Unexpected payload from Boltz status stream"));
                         };
 
-                        if let Err(e) = self.verify_user_lockup_tx(swap).await {
+                        if let Err(e) = self.verify_user_lockup_tx(swap, verify_deadline).await {
                             warn!("User lockup transaction for incoming Chain Swap {} could not be verified. err: {}", swap.id, e);
                             return Err(anyhow!("Could not verify user lockup transaction: {e}",));
                         }
 
-                        let verify_res =
-                            self.verify_server_lockup_tx(swap, &transaction, true).await;
+                        let verify_res = self
+                            .verify_server_lockup_tx(swap, &transaction, true, verify_deadline)
+                            .await;
 
                         // Set the server_lockup_tx_id if it is verified or not.
                         // If it is not yet confirmed, then it will be claimed after confirmation
@@ -439,7 +494,7 @@ impl ChainSwapHandler {
                 match swap.refund_tx_id.clone() {
                     None => {
                         warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}");
-                        match self.verify_user_lockup_tx(swap).await {
+                        match self.verify_user_lockup_tx(swap, verify_deadline).await {
                             Ok(_) => {
                                 info!("Chain Swap {id} user lockup tx was broadcast. Setting the swap to refundable.");
                                 self.update_swap_info(id, Refundable, None, None, None, None)
@@ -466,13 +521,18 @@ impl ChainSwapHandler {
         }
     }
 
-    async fn on_new_outgoing_status(&self, swap: &ChainSwap, update: &boltz::Update) -> Result<()> {
+    async fn on_new_outgoing_status(
+        &self,
+        swap: &ChainSwap,
+        update: &boltz::Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
         let id = &update.id;
         let status = &update.status;
         let swap_state = ChainSwapStates::from_str(status)
             .map_err(|_| anyhow!("Invalid ChainSwapState for Chain Swap {id}: {status}"))?;
 
-        info!("Handling outgoing Chain Swap transition to {status:?} for swap {id}");
+        info!(status:% = status; "Handling outgoing Chain Swap transition to {status:?} for swap {id}");
         // See https://docs.boltz.exchange/v/api/lifecycle#chain-swaps
         match swap_state {
             // The swap is created
@@ -534,7 +594,7 @@ impl ChainSwapHandler {
                         };
 
                         if let Err(e) = self
-                            .verify_server_lockup_tx(swap, &transaction, false)
+                            .verify_server_lockup_tx(swap, &transaction, false, verify_deadline)
                             .await
                         {
                             warn!("Server lockup mempool transaction for outgoing Chain Swap {} could not be verified. txid: {}, err: {}",
@@ -574,12 +634,14 @@ impl ChainSwapHandler {
                             return Err(anyhow!("Unexpected payload from Boltz status stream"));
                         };
 
-                        if let Err(e) = self.verify_user_lockup_tx(swap).await {
+                        if let Err(e) = self.verify_user_lockup_tx(swap, verify_deadline).await {
                             warn!("User lockup transaction for outgoing Chain Swap {} could not be verified. err: {}", swap.id, e);
                             return Err(anyhow!("Could not verify user lockup transaction: {e}",));
                         }
 
-                        if let Err(e) = self.verify_server_lockup_tx(swap, &transaction, true).await
+                        if let Err(e) = self
+                            .verify_server_lockup_tx(swap, &transaction, true, verify_deadline)
+                            .await
                         {
                             warn!("Server lockup transaction for outgoing Chain Swap {} could not be verified. txid: {}, err: {}",
                                 swap.id,
@@ -738,13 +800,35 @@ impl ChainSwapHandler {
             user_lockup_tx_id,
             claim_tx_id,
             refund_tx_id,
+            swapper::current_swapper_status().as_deref(),
         )?;
+        if swap.state != to_state {
+            self.event_manager
+                .notify(SdkEvent::SwapStateChanged {
+                    swap_id: swap_id.to_string(),
+                    from_state: swap.state,
+                    to_state,
+                })
+                .await;
+        }
         if let Some(payment_id) = payment_id {
             let _ = self.subscription_notifier.send(payment_id);
         }
         Ok(())
     }
 
+    /// Claims an incoming swap, regardless of the last status received from the swapper
+    pub(crate) async fn force_claim(&self, swap: &ChainSwap) -> Result<(), PaymentError> {
+        ensure_sdk!(
+            swap.direction == Direction::Incoming,
+            PaymentError::Generic {
+                err: format!("Chain Swap {} is not an incoming swap", swap.id)
+            }
+        );
+        Self::validate_state_transition(swap.state, Pending)?;
+        self.claim(&swap.id).await
+    }
+
     async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
         let swap = self
             .persister
@@ -793,6 +877,7 @@ impl ChainSwapHandler {
                         let bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
                         bitcoin_chain_service
                             .broadcast(&tx)
+                            .await
                             .map(|tx_id| tx_id.to_hex())
                             .map_err(|err| PaymentError::Generic {
                                 err: err.to_string(),
@@ -855,7 +940,7 @@ impl ChainSwapHandler {
         lockup_address: &str,
         refund_address: &str,
         fee_rate_sat_per_vb: u32,
-    ) -> SdkResult<(u32, u64, Option<String>)> {
+    ) -> SdkResult<PrepareRefundResponse> {
         let swap = self
             .persister
             .fetch_chain_swap_by_lockup_address(lockup_address)?
@@ -871,13 +956,40 @@ impl ChainSwapHandler {
             );
         }
 
-        let (refund_tx_size, refund_tx_fees_sat) = self.swapper.estimate_refund_broadcast(
-            Swap::Chain(swap),
-            refund_address,
-            Some(fee_rate_sat_per_vb as f64),
-        )?;
+        let (script_path_tx_size, script_path_tx_fees_sat) =
+            self.swapper.estimate_refund_broadcast(
+                Swap::Chain(swap.clone()),
+                refund_address,
+                Some(fee_rate_sat_per_vb as f64),
+                false,
+            )?;
+
+        let is_cooperative = self.is_cooperative_refund(&swap).await?;
+        let (tx_vsize, tx_fee_sat) = match is_cooperative {
+            true => self.swapper.estimate_refund_broadcast(
+                Swap::Chain(swap),
+                refund_address,
+                Some(fee_rate_sat_per_vb as f64),
+                true,
+            )?,
+            false => (script_path_tx_size, script_path_tx_fees_sat),
+        };
 
-        Ok((refund_tx_size, refund_tx_fees_sat, refund_tx_id))
+        Ok(PrepareRefundResponse {
+            tx_vsize,
+            tx_fee_sat,
+            refund_tx_id,
+            is_cooperative,
+            fee_saved_sat: script_path_tx_fees_sat.saturating_sub(tx_fee_sat),
+        })
+    }
+
+    /// Selects the refund path for an incoming Chain Swap. Until the swap timeout, the lockup
+    /// can only be spent with the swapper's partial signature, so the refund is cooperative.
+    /// Once the timeout has elapsed, the refund uses the script path, which no longer depends on
+    /// the swapper. A failed cooperative refund also falls back to the script path.
+    async fn is_cooperative_refund(&self, swap: &ChainSwap) -> Result<bool> {
+        Ok(!self.check_swap_expiry(swap).await?)
     }
 
     pub(crate) async fn refund_incoming_swap(
@@ -885,7 +997,6 @@ impl ChainSwapHandler {
         lockup_address: &str,
         refund_address: &str,
         broadcast_fee_rate_sat_per_vb: u32,
-        is_cooperative: bool,
     ) -> Result<String, PaymentError> {
         let swap = self
             .persister
@@ -893,6 +1004,18 @@ impl ChainSwapHandler {
             .ok_or(PaymentError::Generic {
                 err: format!("Swap for lockup address {} not found", lockup_address),
             })?;
+
+        Self::log_span(&swap)
+            .scope(self.refund_incoming(swap, refund_address, broadcast_fee_rate_sat_per_vb))
+            .await
+    }
+
+    async fn refund_incoming(
+        &self,
+        swap: ChainSwap,
+        refund_address: &str,
+        broadcast_fee_rate_sat_per_vb: u32,
+    ) -> Result<String, PaymentError> {
         let id = &swap.id;
 
         ensure_sdk!(
@@ -909,6 +1032,53 @@ impl ChainSwapHandler {
             }
         );
 
+        let is_cooperative = self.is_cooperative_refund(&swap).await?;
+        let refund_tx_id = match self
+            .broadcast_incoming_refund(
+                &swap,
+                refund_address,
+                broadcast_fee_rate_sat_per_vb,
+                is_cooperative,
+            )
+            .await
+        {
+            Err(e) if is_cooperative => {
+                warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
+                self.broadcast_incoming_refund(
+                    &swap,
+                    refund_address,
+                    broadcast_fee_rate_sat_per_vb,
+                    false,
+                )
+                .await?
+            }
+            res => res?,
+        };
+
+        // After refund tx is broadcasted, set the payment state to `RefundPending`. This ensures:
+        // - the swap is not shown in `list-refundables` anymore
+        // - the background thread will move it to Failed once the refund tx confirms
+        self.update_swap_info(
+            &swap.id,
+            RefundPending,
+            None,
+            None,
+            None,
+            Some(&refund_tx_id),
+        )
+        .await?;
+
+        Ok(refund_tx_id)
+    }
+
+    async fn broadcast_incoming_refund(
+        &self,
+        swap: &ChainSwap,
+        refund_address: &str,
+        broadcast_fee_rate_sat_per_vb: u32,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        let id = &swap.id;
         info!("Initiating refund for incoming Chain Swap {id}, is_cooperative: {is_cooperative}",);
 
         let SwapScriptV2::Bitcoin(swap_script) = swap.get_lockup_swap_script()? else {
@@ -936,23 +1106,12 @@ impl ChainSwapHandler {
                 err: format!("Unexpected refund tx type returned for incoming Chain swap {id}",),
             });
         };
-        let refund_tx_id = bitcoin_chain_service.broadcast(&refund_tx)?.to_string();
+        let refund_tx_id = bitcoin_chain_service
+            .broadcast(&refund_tx)
+            .await?
+            .to_string();
 
         info!("Successfully broadcast refund for incoming Chain Swap {id}, is_cooperative: {is_cooperative}");
-
-        // After refund tx is broadcasted, set the payment state to `RefundPending`. This ensures:
-        // - the swap is not shown in `list-refundables` anymore
-        // - the background thread will move it to Failed once the refund tx confirms
-        self.update_swap_info(
-            &swap.id,
-            RefundPending,
-            None,
-            None,
-            None,
-            Some(&refund_tx_id),
-        )
-        .await?;
-
         Ok(refund_tx_id)
     }
 
@@ -960,6 +1119,16 @@ impl ChainSwapHandler {
         &self,
         swap: &ChainSwap,
         is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        Self::log_span(swap)
+            .scope(self.refund_outgoing(swap, is_cooperative))
+            .await
+    }
+
+    async fn refund_outgoing(
+        &self,
+        swap: &ChainSwap,
+        is_cooperative: bool,
     ) -> Result<String, PaymentError> {
         ensure_sdk!(
             swap.refund_tx_id.is_none(),
@@ -1026,10 +1195,16 @@ impl ChainSwapHandler {
             return Ok(false);
         }
 
+        let requires_quorum = self.config.requires_chain_quorum(swap.payer_amount_sat);
         match swap.direction {
             Direction::Incoming => {
                 let swap_script = swap.get_lockup_swap_script()?.as_bitcoin_script()?;
-                let current_height = self.bitcoin_chain_service.lock().await.tip()?.height as u32;
+                let mut bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
+                let tip = match requires_quorum {
+                    true => bitcoin_chain_service.tip_with_quorum().await?,
+                    false => bitcoin_chain_service.tip().await?,
+                };
+                let current_height = tip.height as u32;
                 let locktime_from_height =
                     LockTime::from_height(current_height).map_err(|e| PaymentError::Generic {
                         err: format!("Error getting locktime from height {current_height:?}: {e}",),
@@ -1040,7 +1215,11 @@ impl ChainSwapHandler {
             }
             Direction::Outgoing => {
                 let swap_script = swap.get_lockup_swap_script()?.as_liquid_script()?;
-                let current_height = self.liquid_chain_service.lock().await.tip().await?;
+                let mut liquid_chain_service = self.liquid_chain_service.lock().await;
+                let current_height = match requires_quorum {
+                    true => liquid_chain_service.tip_with_quorum().await?,
+                    false => liquid_chain_service.tip().await?,
+                };
                 let locktime_from_height = ElementsLockTime::from_height(current_height)?;
 
                 info!("Checking Chain Swap {} expiration: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}", swap.id, swap_script.locktime);
@@ -1174,6 +1353,7 @@ impl ChainSwapHandler {
         chain_swap: &ChainSwap,
         swap_update_tx: &SwapUpdateTxDetails,
         verify_confirmation: bool,
+        verify_deadline: Option<Instant>,
     ) -> Result<()> {
         match chain_swap.direction {
             Direction::Incoming => {
@@ -1181,6 +1361,7 @@ impl ChainSwapHandler {
                     chain_swap,
                     swap_update_tx,
                     verify_confirmation,
+                    verify_deadline,
                 )
                 .await
             }
@@ -1189,6 +1370,7 @@ impl ChainSwapHandler {
                     chain_swap,
                     swap_update_tx,
                     verify_confirmation,
+                    verify_deadline,
                 )
                 .await
             }
@@ -1200,6 +1382,7 @@ impl ChainSwapHandler {
         chain_swap: &ChainSwap,
         swap_update_tx: &SwapUpdateTxDetails,
         verify_confirmation: bool,
+        verify_deadline: Option<Instant>,
     ) -> Result<()> {
         let swap_script = chain_swap.get_claim_swap_script()?;
         let claim_details = chain_swap.get_boltz_create_response()?.claim_details;
@@ -1208,17 +1391,36 @@ impl ChainSwapHandler {
         let address = liquid_swap_script
             .to_address(self.config.network.into())
             .map_err(|e| anyhow!("Failed to get swap script address {e:?}"))?;
-        let tx = self
-            .liquid_chain_service
-            .lock()
-            .await
-            .verify_tx(
-                &address,
-                &swap_update_tx.id,
-                &swap_update_tx.hex,
-                verify_confirmation,
-            )
-            .await?;
+        let requires_chain_quorum = self.config.requires_chain_quorum(claim_details.amount);
+        let tx = verify_tx_with_retry(LIQUID_VERIFY_TX_TIMEOUT, verify_deadline, || {
+            let address = &address;
+            async move {
+                let liquid_chain_service = self.liquid_chain_service.lock().await;
+                match requires_chain_quorum {
+                    true => {
+                        liquid_chain_service
+                            .verify_tx_with_quorum(
+                                address,
+                                &swap_update_tx.id,
+                                &swap_update_tx.hex,
+                                verify_confirmation,
+                            )
+                            .await
+                    }
+                    false => {
+                        liquid_chain_service
+                            .verify_tx(
+                                address,
+                                &swap_update_tx.id,
+                                &swap_update_tx.hex,
+                                verify_confirmation,
+                            )
+                            .await
+                    }
+                }
+            }
+        })
+        .await?;
         // Verify RBF
         let rbf_explicit = tx.input.iter().any(|tx_in| tx_in.sequence.is_rbf());
         if !verify_confirmation && rbf_explicit {
@@ -1250,6 +1452,7 @@ impl ChainSwapHandler {
         chain_swap: &ChainSwap,
         swap_update_tx: &SwapUpdateTxDetails,
         verify_confirmation: bool,
+        verify_deadline: Option<Instant>,
     ) -> Result<()> {
         let swap_script = chain_swap.get_claim_swap_script()?;
         let claim_details = chain_swap.get_boltz_create_response()?.claim_details;
@@ -1258,17 +1461,36 @@ impl ChainSwapHandler {
             .as_bitcoin_script()?
             .to_address(self.config.network.as_bitcoin_chain())
             .map_err(|e| anyhow!("Failed to get swap script address {e:?}"))?;
-        let tx = self
-            .bitcoin_chain_service
-            .lock()
-            .await
-            .verify_tx(
-                &address,
-                &swap_update_tx.id,
-                &swap_update_tx.hex,
-                verify_confirmation,
-            )
-            .await?;
+        let requires_chain_quorum = self.config.requires_chain_quorum(claim_details.amount);
+        let tx = verify_tx_with_retry(BITCOIN_VERIFY_TX_TIMEOUT, verify_deadline, || {
+            let address = &address;
+            async move {
+                let bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
+                match requires_chain_quorum {
+                    true => {
+                        bitcoin_chain_service
+                            .verify_tx_with_quorum(
+                                address,
+                                &swap_update_tx.id,
+                                &swap_update_tx.hex,
+                                verify_confirmation,
+                            )
+                            .await
+                    }
+                    false => {
+                        bitcoin_chain_service
+                            .verify_tx(
+                                address,
+                                &swap_update_tx.id,
+                                &swap_update_tx.hex,
+                                verify_confirmation,
+                            )
+                            .await
+                    }
+                }
+            }
+        })
+        .await?;
         // Verify RBF
         let rbf_explicit = tx.input.iter().any(|input| input.sequence.is_rbf());
         if !verify_confirmation && rbf_explicit {
@@ -1290,12 +1512,28 @@ impl ChainSwapHandler {
         Ok(())
     }
 
-    async fn verify_user_lockup_tx(&self, chain_swap: &ChainSwap) -> Result<String> {
-        let swap_script = chain_swap.get_lockup_swap_script()?;
-        let script_history = match chain_swap.direction {
-            Direction::Incoming => self.fetch_bitcoin_script_history(&swap_script).await,
-            Direction::Outgoing => self.fetch_liquid_script_history(&swap_script).await,
-        }?;
+    async fn verify_user_lockup_tx(
+        &self,
+        chain_swap: &ChainSwap,
+        verify_deadline: Option<Instant>,
+    ) -> Result<String> {
+        let swap_script = &chain_swap.get_lockup_swap_script()?;
+        let timeout = match chain_swap.direction {
+            Direction::Incoming => BITCOIN_VERIFY_TX_TIMEOUT,
+            Direction::Outgoing => LIQUID_VERIFY_TX_TIMEOUT,
+        };
+        let script_history = verify_tx_with_retry(timeout, verify_deadline, || async move {
+            let script_history = match chain_swap.direction {
+                Direction::Incoming => self.fetch_bitcoin_script_history(swap_script).await,
+                Direction::Outgoing => self.fetch_liquid_script_history(swap_script).await,
+            }?;
+            ensure_sdk!(
+                !script_history.is_empty(),
+                anyhow!("Script history has no transactions")
+            );
+            Ok(script_history)
+        })
+        .await?;
 
         match chain_swap.user_lockup_tx_id.clone() {
             Some(user_lockup_tx_id) => {
@@ -1331,7 +1569,7 @@ impl ChainSwapHandler {
         self.bitcoin_chain_service
             .lock()
             .await
-            .get_script_history_with_retry(script, 5)
+            .get_script_history(script)
             .await
     }
 
@@ -1349,7 +1587,7 @@ impl ChainSwapHandler {
         self.liquid_chain_service
             .lock()
             .await
-            .get_script_history_with_retry(&script, 5)
+            .get_script_history(&script)
             .await
     }
 }
@@ -1438,4 +1676,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_prepare_refund() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+        let chain_swap_handler = new_chain_swap_handler(storage.clone())?;
+
+        let chain_swap = new_chain_swap(Direction::Incoming, Some(Refundable), false, None);
+        storage.insert_chain_swap(&chain_swap)?;
+
+        // A recently created swap has not expired, so the swapper co-signs the refund
+        let response = chain_swap_handler
+            .prepare_refund(&chain_swap.lockup_address, "refund_address", 2)
+            .await?;
+        assert!(response.is_cooperative);
+        assert_eq!(response.tx_vsize, 100);
+        assert_eq!(response.tx_fee_sat, 200);
+        assert_eq!(response.fee_saved_sat, 100);
+        assert!(response.refund_tx_id.is_none());
+
+        assert!(chain_swap_handler
+            .prepare_refund("unknown_address", "refund_address", 2)
+            .await
+            .is_err());
+
+        Ok(())
+    }
 }
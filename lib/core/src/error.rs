@@ -13,6 +13,54 @@ macro_rules! ensure_sdk {
     };
 }
 
+/// A stable numeric code and retryable flag describing an [SdkError] or [PaymentError].
+///
+/// Codes never change meaning between releases: `1xx` codes are used by [SdkError] and `2xx`
+/// codes by [PaymentError].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorInfo {
+    pub code: u32,
+    /// Whether the same call may succeed if retried later, without changing its arguments
+    pub retryable: bool,
+}
+
+/// Marks an error as returned by the chain backends, for errors whose type is shared with other
+/// components, such as HTTP errors. It's reported as [SdkError::ChainService].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct ChainServiceError(#[from] anyhow::Error);
+
+/// Marks an error as returned by the swapper, for errors whose type is shared with other
+/// components, such as HTTP errors. It's reported as [SdkError::Swapper].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct SwapperError(#[from] anyhow::Error);
+
+/// The component a failure wrapped in an [anyhow::Error] originates from
+enum ErrorSource {
+    Swapper,
+    ChainService,
+    Persistence,
+    Other,
+}
+
+impl ErrorSource {
+    fn of(err: &Error) -> Self {
+        for cause in err.chain() {
+            if cause.is::<SwapperError>() || cause.is::<boltz_client::error::Error>() {
+                return Self::Swapper;
+            }
+            if cause.is::<ChainServiceError>() || cause.is::<electrum_client::Error>() {
+                return Self::ChainService;
+            }
+            if cause.is::<rusqlite::Error>() {
+                return Self::Persistence;
+            }
+        }
+        Self::Other
+    }
+}
+
 // TODO Unify error enum
 #[derive(Debug, thiserror::Error)]
 pub enum SdkError {
@@ -27,6 +75,21 @@ pub enum SdkError {
 
     #[error("Service connectivity: {err}")]
     ServiceConnectivity { err: String },
+
+    #[error("Swapper error: {err}")]
+    Swapper { err: String },
+
+    #[error("Chain service error: {err}")]
+    ChainService { err: String },
+
+    #[error("Persistence error: {err}")]
+    Persistence { err: String },
+
+    #[error("Signer error: {err}")]
+    Signer { err: String },
+
+    #[error("Invalid argument: {err}")]
+    Validation { err: String },
 }
 impl SdkError {
     pub fn generic<T: AsRef<str>>(err: T) -> Self {
@@ -34,28 +97,79 @@ impl SdkError {
             err: err.as_ref().to_string(),
         }
     }
+
+    pub fn validation<T: AsRef<str>>(err: T) -> Self {
+        Self::Validation {
+            err: err.as_ref().to_string(),
+        }
+    }
+
+    pub fn info(&self) -> ErrorInfo {
+        let (code, retryable) = match self {
+            Self::Generic { .. } => (100, false),
+            Self::AlreadyStarted => (101, false),
+            Self::NotStarted => (102, false),
+            Self::ServiceConnectivity { .. } => (103, true),
+            Self::Swapper { .. } => (104, true),
+            Self::ChainService { .. } => (105, true),
+            Self::Persistence { .. } => (106, false),
+            Self::Signer { .. } => (107, false),
+            Self::Validation { .. } => (108, false),
+        };
+        ErrorInfo { code, retryable }
+    }
 }
 
 impl From<anyhow::Error> for SdkError {
     fn from(e: Error) -> Self {
-        SdkError::generic(e.to_string())
+        let e = match e.downcast::<SdkError>() {
+            Ok(e) => return e,
+            Err(e) => e,
+        };
+        let err = e.to_string();
+        match ErrorSource::of(&e) {
+            ErrorSource::Swapper => SdkError::Swapper { err },
+            ErrorSource::ChainService => SdkError::ChainService { err },
+            ErrorSource::Persistence => SdkError::Persistence { err },
+            ErrorSource::Other => SdkError::Generic { err },
+        }
     }
 }
 
 impl From<boltz_client::error::Error> for SdkError {
     fn from(err: boltz_client::error::Error) -> Self {
         match err {
-            boltz_client::error::Error::HTTP(e) => {
-                SdkError::generic(format!("Could not contact servers: {e:?}"))
-            }
-            _ => SdkError::generic(format!("{err:?}")),
+            boltz_client::error::Error::HTTP(e) => SdkError::Swapper {
+                err: format!("Could not contact servers: {e:?}"),
+            },
+            _ => SdkError::Swapper {
+                err: format!("{err:?}"),
+            },
         }
     }
 }
 
 impl From<secp256k1::Error> for SdkError {
     fn from(err: secp256k1::Error) -> Self {
-        SdkError::generic(format!("{err:?}"))
+        SdkError::Signer {
+            err: format!("{err:?}"),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for SdkError {
+    fn from(err: rusqlite::Error) -> Self {
+        SdkError::Persistence {
+            err: err.to_string(),
+        }
+    }
+}
+
+impl From<electrum_client::Error> for SdkError {
+    fn from(err: electrum_client::Error) -> Self {
+        SdkError::ChainService {
+            err: format!("{err:?}"),
+        }
     }
 }
 
@@ -123,6 +237,12 @@ pub enum PaymentError {
 
     #[error("Could not sign the transaction: {err}")]
     SignerError { err: String },
+
+    #[error("Swapper error: {err}")]
+    Swapper { err: String },
+
+    #[error("Chain service error: {err}")]
+    ChainService { err: String },
 }
 impl PaymentError {
     pub(crate) fn generic(err: &str) -> Self {
@@ -154,15 +274,44 @@ impl PaymentError {
             err: err.to_string(),
         }
     }
+
+    pub fn info(&self) -> ErrorInfo {
+        let (code, retryable) = match self {
+            Self::Generic { .. } => (200, false),
+            Self::AlreadyClaimed => (201, false),
+            Self::AlreadyPaid => (202, false),
+            Self::PaymentInProgress => (203, false),
+            Self::AmountOutOfRange => (204, false),
+            Self::AmountMissing { .. } => (205, false),
+            Self::InvalidNetwork { .. } => (206, false),
+            Self::InvalidOrExpiredFees => (207, false),
+            Self::InsufficientFunds => (208, false),
+            Self::InvalidDescription { .. } => (209, false),
+            Self::InvalidInvoice { .. } => (210, false),
+            Self::InvalidPreimage => (211, false),
+            Self::LwkError { .. } => (212, false),
+            Self::PairsNotFound => (213, true),
+            Self::PaymentTimeout => (214, true),
+            Self::PersistError => (215, false),
+            Self::ReceiveError { .. } => (216, false),
+            Self::Refunded { .. } => (217, false),
+            Self::SelfTransferNotSupported => (218, false),
+            Self::SendError { .. } => (219, false),
+            Self::SignerError { .. } => (220, false),
+            Self::Swapper { .. } => (221, true),
+            Self::ChainService { .. } => (222, true),
+        };
+        ErrorInfo { code, retryable }
+    }
 }
 
 impl From<boltz_client::error::Error> for PaymentError {
     fn from(err: boltz_client::error::Error) -> Self {
         match err {
-            boltz_client::error::Error::HTTP(e) => PaymentError::Generic {
+            boltz_client::error::Error::HTTP(e) => PaymentError::Swapper {
                 err: format!("Could not contact servers: {e:?}"),
             },
-            _ => PaymentError::Generic {
+            _ => PaymentError::Swapper {
                 err: format!("{err:?}"),
             },
         }
@@ -198,8 +347,29 @@ impl From<lwk_signer::SignerError> for PaymentError {
 
 impl From<anyhow::Error> for PaymentError {
     fn from(err: anyhow::Error) -> Self {
-        Self::Generic {
-            err: err.to_string(),
+        let err = match err.downcast::<PaymentError>() {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+        match ErrorSource::of(&err) {
+            ErrorSource::Swapper => Self::Swapper {
+                err: err.to_string(),
+            },
+            ErrorSource::ChainService => Self::ChainService {
+                err: err.to_string(),
+            },
+            ErrorSource::Persistence => Self::PersistError,
+            ErrorSource::Other => Self::Generic {
+                err: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<electrum_client::Error> for PaymentError {
+    fn from(err: electrum_client::Error) -> Self {
+        Self::ChainService {
+            err: format!("{err:?}"),
         }
     }
 }
@@ -212,8 +382,14 @@ impl From<rusqlite::Error> for PaymentError {
 
 impl From<SdkError> for PaymentError {
     fn from(err: SdkError) -> Self {
-        Self::Generic {
-            err: err.to_string(),
+        match err {
+            SdkError::Swapper { err } => Self::Swapper { err },
+            SdkError::ChainService { err } => Self::ChainService { err },
+            SdkError::Persistence { .. } => Self::PersistError,
+            SdkError::Signer { err } => Self::SignerError { err },
+            _ => Self::Generic {
+                err: err.to_string(),
+            },
         }
     }
 }
@@ -233,3 +409,54 @@ impl From<PaymentError> for LnUrlAuthError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Context};
+
+    use super::*;
+
+    #[test]
+    fn test_error_mapping() {
+        let err: SdkError = Err::<(), _>(rusqlite::Error::InvalidQuery)
+            .context("Could not read payments")
+            .unwrap_err()
+            .into();
+        assert!(matches!(err, SdkError::Persistence { .. }));
+        assert_eq!(err.info().code, 106);
+
+        let err: SdkError = anyhow!(SdkError::NotStarted).into();
+        assert!(matches!(err, SdkError::NotStarted));
+
+        let err: PaymentError = anyhow!(PaymentError::InsufficientFunds).into();
+        assert!(matches!(err, PaymentError::InsufficientFunds));
+
+        let err: PaymentError = SdkError::Swapper {
+            err: "Could not contact servers".to_string(),
+        }
+        .into();
+        assert_eq!(
+            err.info(),
+            ErrorInfo {
+                code: 221,
+                retryable: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_source_by_call_site() {
+        // The same underlying error is classified by the component which returned it
+        let err: SdkError = anyhow!(ChainServiceError::from(anyhow!("Connection refused"))).into();
+        assert!(matches!(err, SdkError::ChainService { .. }));
+        assert_eq!(err.to_string(), "Chain service error: Connection refused");
+
+        let err: SdkError = anyhow!(SwapperError::from(anyhow!("Connection refused")))
+            .context("Could not fetch swap status")
+            .into();
+        assert!(matches!(err, SdkError::Swapper { .. }));
+
+        let err: PaymentError = anyhow!("Connection refused").into();
+        assert!(matches!(err, PaymentError::Generic { .. }));
+    }
+}
@@ -1,27 +1,31 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::Result;
-use log::info;
+use log::{info, warn};
 use tokio::sync::{broadcast, RwLock};
 
 use crate::model::{EventListener, SdkEvent};
+use crate::persist::Persister;
 
 pub(crate) struct EventManager {
     listeners: RwLock<HashMap<String, Box<dyn EventListener>>>,
     notifier: broadcast::Sender<SdkEvent>,
     is_paused: AtomicBool,
+    persister: Arc<Persister>,
 }
 
 impl EventManager {
-    pub fn new() -> Self {
+    pub fn new(persister: Arc<Persister>) -> Self {
         let (notifier, _) = broadcast::channel::<SdkEvent>(100);
 
         Self {
             listeners: Default::default(),
             notifier,
             is_paused: AtomicBool::new(false),
+            persister,
         }
     }
 
@@ -38,7 +42,16 @@ impl EventManager {
         (*self.listeners.write().await).remove(&id);
     }
 
+    /// Appends the event to the event log and emits it to the live listeners.
+    ///
+    /// While notifications are paused, the event is still logged so that it can be replayed.
     pub async fn notify(&self, e: SdkEvent) {
+        if Self::is_persisted(&e) {
+            if let Err(err) = self.persister.insert_event(&e) {
+                warn!("Failed to persist event {e:?}: {err:?}");
+            }
+        }
+
         match self.is_paused.load(Ordering::SeqCst) {
             true => info!("Event notifications are paused, not emitting event {e:?}"),
             false => {
@@ -51,17 +64,82 @@ impl EventManager {
         }
     }
 
+    /// Progress and block events are only relevant while they are emitted, so they are
+    /// not appended to the event log
+    fn is_persisted(e: &SdkEvent) -> bool {
+        !matches!(e, SdkEvent::SyncProgress { .. } | SdkEvent::NewBlock { .. })
+    }
+
     pub(crate) fn subscribe(&self) -> broadcast::Receiver<SdkEvent> {
         self.notifier.subscribe()
     }
 
-    pub(crate) fn pause_notifications(&self) {
+    /// Pauses the event notifications until the returned guard is dropped, so that they're
+    /// resumed even if the paused work fails or is cancelled
+    pub(crate) fn pause_notifications(&self) -> PausedNotifications<'_> {
         info!("Pausing event notifications");
         self.is_paused.store(true, Ordering::SeqCst);
+        PausedNotifications(self)
     }
+}
 
-    pub(crate) fn resume_notifications(&self) {
+#[must_use = "Event notifications are resumed as soon as the guard is dropped"]
+pub(crate) struct PausedNotifications<'a>(&'a EventManager);
+
+impl Drop for PausedNotifications<'_> {
+    fn drop(&mut self) {
         info!("Resuming event notifications");
-        self.is_paused.store(false, Ordering::SeqCst);
+        self.0.is_paused.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use anyhow::Result;
+
+    use crate::{model::SdkEvent, test_utils::persist::new_persister};
+
+    use super::EventManager;
+
+    #[tokio::test]
+    async fn test_paused_events_are_persisted() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let event_manager = EventManager::new(persister.clone());
+        let mut events = event_manager.subscribe();
+
+        let paused_notifications = event_manager.pause_notifications();
+        event_manager.notify(SdkEvent::Synced).await;
+        assert!(events.try_recv().is_err());
+        assert_eq!(persister.list_events_since(0, 10)?.len(), 1);
+
+        drop(paused_notifications);
+        event_manager.notify(SdkEvent::Synced).await;
+        assert_eq!(events.try_recv()?, SdkEvent::Synced);
+        assert_eq!(persister.list_events_since(0, 10)?.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_notifications_resume_when_paused_work_is_cancelled() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+        let event_manager = EventManager::new(Arc::new(persister));
+        let mut events = event_manager.subscribe();
+
+        let paused_work = async {
+            let _paused_notifications = event_manager.pause_notifications();
+            std::future::pending::<()>().await
+        };
+        assert!(tokio::time::timeout(Duration::from_millis(10), paused_work)
+            .await
+            .is_err());
+
+        event_manager.notify(SdkEvent::Synced).await;
+        assert_eq!(events.try_recv()?, SdkEvent::Synced);
+
+        Ok(())
     }
 }
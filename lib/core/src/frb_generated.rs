@@ -39,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.4.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 425220482;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1701918067;
 
 // Section: executor
 
@@ -47,6 +47,48 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__bindings__BindingLiquidSdk_ack_events_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    listener_id: impl CstDecode<String>,
+    seq: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_ack_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_listener_id = listener_id.cst_decode();
+            let api_seq = seq.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::ack_events(
+                    &*api_that_guard,
+                    api_listener_id,
+                    api_seq,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_add_event_listener_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -300,6 +342,82 @@ fn wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_events_since_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    seq: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_events_since",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_seq = seq.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::bindings::BindingLiquidSdk::events_since(&*api_that_guard, api_seq)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_export_rescue_file_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_export_rescue_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::export_rescue_file(
+                    &*api_that_guard,
+                    api_swap_id,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -437,6 +555,245 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_force_claim_swap_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_force_claim_swap",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::force_claim_swap(
+                            &*api_that_guard,
+                            api_swap_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+    is_cooperative: impl CstDecode<bool>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_force_refund_send_swap",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            let api_is_cooperative = is_cooperative.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::force_refund_send_swap(
+                            &*api_that_guard,
+                            api_swap_id,
+                            api_is_cooperative,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_generate_diagnostics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_generate_diagnostics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::generate_diagnostics(
+                            &*api_that_guard,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_get_event_cursor_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    listener_id: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_event_cursor",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_listener_id = listener_id.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::get_event_cursor(
+                    &*api_that_guard,
+                    api_listener_id,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    currency: impl CstDecode<String>,
+    timestamp: impl CstDecode<u32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_historical_fiat_rate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_currency = currency.cst_decode();
+            let api_timestamp = timestamp.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::get_historical_fiat_rate(
+                                &*api_that_guard,
+                                api_currency,
+                                api_timestamp,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -481,24 +838,183 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_last_sync_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::bindings::BindingLiquidSdk::get_last_sync_metrics(&*api_that_guard)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::GetPaymentRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::get_payment(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_get_swap_history_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_swap_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::get_swap_history(
+                    &*api_that_guard,
+                    api_swap_id,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_get_webhook_signing_secret",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::get_webhook_signing_secret(
+                    &*api_that_guard,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_import_swap_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::GetPaymentRequest>,
+    rescue_json: impl CstDecode<String>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_get_payment",
+            debug_name: "BindingLiquidSdk_import_swap",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
+            let api_rescue_json = rescue_json.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::error::SdkError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -517,9 +1033,9 @@ fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::get_payment(
+                        let output_ok = crate::bindings::BindingLiquidSdk::import_swap(
                             &*api_that_guard,
-                            api_req,
+                            api_rescue_json,
                         )
                         .await?;
                         Ok(output_ok)
@@ -530,6 +1046,42 @@ fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_list_failed_webhook_deliveries",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::list_failed_webhook_deliveries(
+                    &*api_that_guard,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -815,6 +1367,55 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_mark_swap_failed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_mark_swap_failed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::mark_swap_failed(
+                            &*api_that_guard,
+                            api_swap_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -1252,6 +1853,55 @@ fn wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_refetch_swap_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    swap_id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_refetch_swap_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_swap_id = swap_id.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::refetch_swap_status(
+                            &*api_that_guard,
+                            api_swap_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -1431,6 +2081,45 @@ fn wire__crate__bindings__BindingLiquidSdk_restore_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_retry_webhook_delivery",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_id = id.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = crate::bindings::BindingLiquidSdk::retry_webhook_delivery(
+                    &*api_that_guard,
+                    api_id,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -1561,6 +2250,53 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
         },
     )
 }
+fn wire__crate__bindings__BindingLiquidSdk_sync_once_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::SyncOnceRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_sync_once",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::sync_once(&*api_that_guard, api_req)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_unregister_webhook_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -1740,6 +2476,42 @@ fn wire__crate__bindings__parse_invoice_impl(
         },
     )
 }
+fn wire__crate__bindings__payment_error_info_impl(
+    err: impl CstDecode<crate::error::PaymentError>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "payment_error_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_err = err.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::bindings::payment_error_info(api_err))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__sdk_error_info_impl(
+    err: impl CstDecode<crate::error::SdkError>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sdk_error_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_err = err.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::bindings::sdk_error_info(api_err))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 
 // Section: static_checks
 
@@ -1986,6 +2758,16 @@ const _: fn() = || {
 
 // Section: dart2rust
 
+impl CstDecode<crate::model::Blockchain> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::Blockchain {
+        match self {
+            0 => crate::model::Blockchain::Liquid,
+            1 => crate::model::Blockchain::Bitcoin,
+            _ => unreachable!("Invalid variant for Blockchain: {}", self),
+        }
+    }
+}
 impl CstDecode<bool> for bool {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> bool {
@@ -2025,6 +2807,7 @@ impl CstDecode<crate::model::LiquidNetwork> for i32 {
         match self {
             0 => crate::model::LiquidNetwork::Mainnet,
             1 => crate::model::LiquidNetwork::Testnet,
+            2 => crate::model::LiquidNetwork::Regtest,
             _ => unreachable!("Invalid variant for LiquidNetwork: {}", self),
         }
     }
@@ -2077,6 +2860,17 @@ impl CstDecode<crate::model::PaymentType> for i32 {
         }
     }
 }
+impl CstDecode<crate::model::SyncTask> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SyncTask {
+        match self {
+            0 => crate::model::SyncTask::Claims,
+            1 => crate::model::SyncTask::Refunds,
+            2 => crate::model::SyncTask::History,
+            _ => unreachable!("Invalid variant for SyncTask: {}", self),
+        }
+    }
+}
 impl CstDecode<u16> for u16 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> u16 {
@@ -2107,6 +2901,16 @@ impl CstDecode<usize> for usize {
         self
     }
 }
+impl CstDecode<crate::model::WebhookDeliveryState> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::WebhookDeliveryState {
+        match self {
+            0 => crate::model::WebhookDeliveryState::Pending,
+            1 => crate::model::WebhookDeliveryState::Failed,
+            _ => unreachable!("Invalid variant for WebhookDeliveryState: {}", self),
+        }
+    }
+}
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2277,6 +3081,18 @@ impl SseDecode for crate::bindings::BitcoinAddressData {
     }
 }
 
+impl SseDecode for crate::model::Blockchain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::Blockchain::Liquid,
+            1 => crate::model::Blockchain::Bitcoin,
+            _ => unreachable!("Invalid variant for Blockchain: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2308,6 +3124,44 @@ impl SseDecode for crate::model::BuyBitcoinRequest {
     }
 }
 
+impl SseDecode for crate::model::ChainBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::ChainBackend::Electrum { url: var_url };
+            }
+            1 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::ChainBackend::Esplora { url: var_url };
+            }
+            2 => {
+                let mut var_electrumUrl = <String>::sse_decode(deserializer);
+                let mut var_esploraUrl = <String>::sse_decode(deserializer);
+                return crate::model::ChainBackend::Hybrid {
+                    electrum_url: var_electrumUrl,
+                    esplora_url: var_esploraUrl,
+                };
+            }
+            3 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                let mut var_username = <Option<String>>::sse_decode(deserializer);
+                let mut var_password = <Option<String>>::sse_decode(deserializer);
+                return crate::model::ChainBackend::Rpc {
+                    url: var_url,
+                    username: var_username,
+                    password: var_password,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::model::CheckMessageRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2335,8 +3189,18 @@ impl SseDecode for crate::model::CheckMessageResponse {
 impl SseDecode for crate::model::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_liquidElectrumUrl = <String>::sse_decode(deserializer);
-        let mut var_bitcoinElectrumUrl = <String>::sse_decode(deserializer);
+        let mut var_liquidChainBackend = <crate::model::ChainBackend>::sse_decode(deserializer);
+        let mut var_bitcoinChainBackend = <crate::model::ChainBackend>::sse_decode(deserializer);
+        let mut var_liquidFallbackChainBackends =
+            <Option<Vec<crate::model::ChainBackend>>>::sse_decode(deserializer);
+        let mut var_bitcoinFallbackChainBackends =
+            <Option<Vec<crate::model::ChainBackend>>>::sse_decode(deserializer);
+        let mut var_chainQuorumMinAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_swapperUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_swapperWsUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_swapperReferralId = <Option<String>>::sse_decode(deserializer);
+        let mut var_lbtcAssetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_liquidGenesisHash = <Option<String>>::sse_decode(deserializer);
         let mut var_mempoolspaceUrl = <String>::sse_decode(deserializer);
         let mut var_workingDir = <String>::sse_decode(deserializer);
         let mut var_cacheDir = <Option<String>>::sse_decode(deserializer);
@@ -2345,9 +3209,21 @@ impl SseDecode for crate::model::Config {
         let mut var_zeroConfMinFeeRateMsat = <u32>::sse_decode(deserializer);
         let mut var_zeroConfMaxAmountSat = <Option<u64>>::sse_decode(deserializer);
         let mut var_breezApiKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_fiatRateHistoryCurrencies = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_eventWebhookUrls = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_schedulerIntervals =
+            <Option<crate::model::SchedulerIntervals>>::sse_decode(deserializer);
         return crate::model::Config {
-            liquid_electrum_url: var_liquidElectrumUrl,
-            bitcoin_electrum_url: var_bitcoinElectrumUrl,
+            liquid_chain_backend: var_liquidChainBackend,
+            bitcoin_chain_backend: var_bitcoinChainBackend,
+            liquid_fallback_chain_backends: var_liquidFallbackChainBackends,
+            bitcoin_fallback_chain_backends: var_bitcoinFallbackChainBackends,
+            chain_quorum_min_amount_sat: var_chainQuorumMinAmountSat,
+            swapper_url: var_swapperUrl,
+            swapper_ws_url: var_swapperWsUrl,
+            swapper_referral_id: var_swapperReferralId,
+            lbtc_asset_id: var_lbtcAssetId,
+            liquid_genesis_hash: var_liquidGenesisHash,
             mempoolspace_url: var_mempoolspaceUrl,
             working_dir: var_workingDir,
             cache_dir: var_cacheDir,
@@ -2356,6 +3232,9 @@ impl SseDecode for crate::model::Config {
             zero_conf_min_fee_rate_msat: var_zeroConfMinFeeRateMsat,
             zero_conf_max_amount_sat: var_zeroConfMaxAmountSat,
             breez_api_key: var_breezApiKey,
+            fiat_rate_history_currencies: var_fiatRateHistoryCurrencies,
+            event_webhook_urls: var_eventWebhookUrls,
+            scheduler_intervals: var_schedulerIntervals,
         };
     }
 }
@@ -2395,6 +3274,32 @@ impl SseDecode for crate::bindings::CurrencyInfo {
     }
 }
 
+impl SseDecode for crate::error::ErrorInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <u32>::sse_decode(deserializer);
+        let mut var_retryable = <bool>::sse_decode(deserializer);
+        return crate::error::ErrorInfo {
+            code: var_code,
+            retryable: var_retryable,
+        };
+    }
+}
+
+impl SseDecode for crate::model::EventLogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        let mut var_createdAt = <u32>::sse_decode(deserializer);
+        let mut var_event = <crate::model::SdkEvent>::sse_decode(deserializer);
+        return crate::model::EventLogEntry {
+            seq: var_seq,
+            created_at: var_createdAt,
+            event: var_event,
+        };
+    }
+}
+
 impl SseDecode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2402,6 +3307,18 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::model::FiatAmount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_currency = <String>::sse_decode(deserializer);
+        let mut var_amount = <f64>::sse_decode(deserializer);
+        return crate::model::FiatAmount {
+            currency: var_currency,
+            amount: var_amount,
+        };
+    }
+}
+
 impl SseDecode for crate::bindings::FiatCurrency {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2533,9 +3450,11 @@ impl SseDecode for crate::model::LightningPaymentLimitsResponse {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_send = <crate::model::Limits>::sse_decode(deserializer);
         let mut var_receive = <crate::model::Limits>::sse_decode(deserializer);
+        let mut var_cacheAgeSecs = <Option<u64>>::sse_decode(deserializer);
         return crate::model::LightningPaymentLimitsResponse {
             send: var_send,
             receive: var_receive,
+            cache_age_secs: var_cacheAgeSecs,
         };
     }
 }
@@ -2581,6 +3500,7 @@ impl SseDecode for crate::model::LiquidNetwork {
         return match inner {
             0 => crate::model::LiquidNetwork::Mainnet,
             1 => crate::model::LiquidNetwork::Testnet,
+            2 => crate::model::LiquidNetwork::Regtest,
             _ => unreachable!("Invalid variant for LiquidNetwork: {}", inner),
         };
     }
@@ -2598,6 +3518,30 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::model::ChainBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::ChainBackend>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::EventLogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::EventLogEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::bindings::FiatCurrency> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2648,6 +3592,18 @@ impl SseDecode for Vec<crate::bindings::LocalizedName> {
     }
 }
 
+impl SseDecode for Vec<crate::model::LogField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::LogField>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::model::Payment> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2776,6 +3732,44 @@ impl SseDecode for Vec<crate::bindings::RouteHintHop> {
     }
 }
 
+impl SseDecode for Vec<crate::model::SwapStateTransition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::SwapStateTransition>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::SyncTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::SyncTask>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::WebhookDelivery> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::WebhookDelivery>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for crate::bindings::LNInvoice {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3215,9 +4209,33 @@ impl SseDecode for crate::model::LogEntry {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_line = <String>::sse_decode(deserializer);
         let mut var_level = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_module = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_swapId = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_fields = <Vec<crate::model::LogField>>::sse_decode(deserializer);
         return crate::model::LogEntry {
             line: var_line,
             level: var_level,
+            target: var_target,
+            module: var_module,
+            timestamp: var_timestamp,
+            swap_id: var_swapId,
+            payment_id: var_paymentId,
+            fields: var_fields,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LogField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::model::LogField {
+            key: var_key,
+            value: var_value,
         };
     }
 }
@@ -3251,9 +4269,11 @@ impl SseDecode for crate::model::OnchainPaymentLimitsResponse {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_send = <crate::model::Limits>::sse_decode(deserializer);
         let mut var_receive = <crate::model::Limits>::sse_decode(deserializer);
+        let mut var_cacheAgeSecs = <Option<u64>>::sse_decode(deserializer);
         return crate::model::OnchainPaymentLimitsResponse {
             send: var_send,
             receive: var_receive,
+            cache_age_secs: var_cacheAgeSecs,
         };
     }
 }
@@ -3291,6 +4311,17 @@ impl SseDecode for Option<bool> {
     }
 }
 
+impl SseDecode for Option<crate::model::FiatAmount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::FiatAmount>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3335,6 +4366,39 @@ impl SseDecode for Option<crate::model::Payment> {
     }
 }
 
+impl SseDecode for Option<crate::model::PaymentFiatRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::PaymentFiatRate>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::bindings::Rate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::bindings::Rate>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::SchedulerIntervals> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::SchedulerIntervals>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::bindings::SuccessAction> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3370,6 +4434,17 @@ impl SseDecode for Option<crate::bindings::Symbol> {
     }
 }
 
+impl SseDecode for Option<crate::model::SyncMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::SyncMetrics>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3392,6 +4467,28 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::model::ChainBackend>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::model::ChainBackend>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<Vec<crate::model::PaymentType>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3417,6 +4514,14 @@ impl SseDecode for crate::model::PayAmount {
             1 => {
                 return crate::model::PayAmount::Drain;
             }
+            2 => {
+                let mut var_currency = <String>::sse_decode(deserializer);
+                let mut var_amount = <f64>::sse_decode(deserializer);
+                return crate::model::PayAmount::Fiat {
+                    currency: var_currency,
+                    amount: var_amount,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3448,6 +4553,7 @@ impl SseDecode for crate::model::Payment {
         let mut var_paymentType = <crate::model::PaymentType>::sse_decode(deserializer);
         let mut var_status = <crate::model::PaymentState>::sse_decode(deserializer);
         let mut var_details = <crate::model::PaymentDetails>::sse_decode(deserializer);
+        let mut var_fiatRate = <Option<crate::model::PaymentFiatRate>>::sse_decode(deserializer);
         return crate::model::Payment {
             destination: var_destination,
             tx_id: var_txId,
@@ -3457,6 +4563,7 @@ impl SseDecode for crate::model::Payment {
             payment_type: var_paymentType,
             status: var_status,
             details: var_details,
+            fiat_rate: var_fiatRate,
         };
     }
 }
@@ -3595,6 +4702,14 @@ impl SseDecode for crate::error::PaymentError {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::SignerError { err: var_err };
             }
+            21 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::Swapper { err: var_err };
+            }
+            22 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::ChainService { err: var_err };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3602,6 +4717,18 @@ impl SseDecode for crate::error::PaymentError {
     }
 }
 
+impl SseDecode for crate::model::PaymentFiatRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_currency = <String>::sse_decode(deserializer);
+        let mut var_rate = <f64>::sse_decode(deserializer);
+        return crate::model::PaymentFiatRate {
+            currency: var_currency,
+            rate: var_rate,
+        };
+    }
+}
+
 impl SseDecode for crate::model::PaymentMethod {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3719,10 +4846,12 @@ impl SseDecode for crate::model::PreparePayOnchainResponse {
         let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
         let mut var_claimFeesSat = <u64>::sse_decode(deserializer);
         let mut var_totalFeesSat = <u64>::sse_decode(deserializer);
+        let mut var_fiatRate = <Option<crate::model::PaymentFiatRate>>::sse_decode(deserializer);
         return crate::model::PreparePayOnchainResponse {
             receiver_amount_sat: var_receiverAmountSat,
             claim_fees_sat: var_claimFeesSat,
             total_fees_sat: var_totalFeesSat,
+            fiat_rate: var_fiatRate,
         };
     }
 }
@@ -3731,9 +4860,11 @@ impl SseDecode for crate::model::PrepareReceiveRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_payerAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_payerAmountFiat = <Option<crate::model::FiatAmount>>::sse_decode(deserializer);
         let mut var_paymentMethod = <crate::model::PaymentMethod>::sse_decode(deserializer);
         return crate::model::PrepareReceiveRequest {
             payer_amount_sat: var_payerAmountSat,
+            payer_amount_fiat: var_payerAmountFiat,
             payment_method: var_paymentMethod,
         };
     }
@@ -3745,10 +4876,12 @@ impl SseDecode for crate::model::PrepareReceiveResponse {
         let mut var_paymentMethod = <crate::model::PaymentMethod>::sse_decode(deserializer);
         let mut var_payerAmountSat = <Option<u64>>::sse_decode(deserializer);
         let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_fiatRate = <Option<crate::model::PaymentFiatRate>>::sse_decode(deserializer);
         return crate::model::PrepareReceiveResponse {
             payment_method: var_paymentMethod,
             payer_amount_sat: var_payerAmountSat,
             fees_sat: var_feesSat,
+            fiat_rate: var_fiatRate,
         };
     }
 }
@@ -3773,10 +4906,14 @@ impl SseDecode for crate::model::PrepareRefundResponse {
         let mut var_txVsize = <u32>::sse_decode(deserializer);
         let mut var_txFeeSat = <u64>::sse_decode(deserializer);
         let mut var_refundTxId = <Option<String>>::sse_decode(deserializer);
+        let mut var_isCooperative = <bool>::sse_decode(deserializer);
+        let mut var_feeSavedSat = <u64>::sse_decode(deserializer);
         return crate::model::PrepareRefundResponse {
             tx_vsize: var_txVsize,
             tx_fee_sat: var_txFeeSat,
             refund_tx_id: var_refundTxId,
+            is_cooperative: var_isCooperative,
+            fee_saved_sat: var_feeSavedSat,
         };
     }
 }
@@ -3798,9 +4935,11 @@ impl SseDecode for crate::model::PrepareSendResponse {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_destination = <crate::model::SendDestination>::sse_decode(deserializer);
         let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_fiatRate = <Option<crate::model::PaymentFiatRate>>::sse_decode(deserializer);
         return crate::model::PrepareSendResponse {
             destination: var_destination,
             fees_sat: var_feesSat,
+            fiat_rate: var_fiatRate,
         };
     }
 }
@@ -3890,10 +5029,12 @@ impl SseDecode for crate::model::RefundableSwap {
         let mut var_swapAddress = <String>::sse_decode(deserializer);
         let mut var_timestamp = <u32>::sse_decode(deserializer);
         let mut var_amountSat = <u64>::sse_decode(deserializer);
+        let mut var_timeToRefundable = <Option<u64>>::sse_decode(deserializer);
         return crate::model::RefundableSwap {
             swap_address: var_swapAddress,
             timestamp: var_timestamp,
             amount_sat: var_amountSat,
+            time_to_refundable: var_timeToRefundable,
         };
     }
 }
@@ -3938,6 +5079,24 @@ impl SseDecode for crate::bindings::RouteHintHop {
     }
 }
 
+impl SseDecode for crate::model::SchedulerIntervals {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_syncSecs = <u64>::sse_decode(deserializer);
+        let mut var_refundsSecs = <u64>::sse_decode(deserializer);
+        let mut var_liquidRescanSecs = <u64>::sse_decode(deserializer);
+        let mut var_bitcoinRescanSecs = <u64>::sse_decode(deserializer);
+        let mut var_newBlocksSecs = <u64>::sse_decode(deserializer);
+        return crate::model::SchedulerIntervals {
+            sync_secs: var_syncSecs,
+            refunds_secs: var_refundsSecs,
+            liquid_rescan_secs: var_liquidRescanSecs,
+            bitcoin_rescan_secs: var_bitcoinRescanSecs,
+            new_blocks_secs: var_newBlocksSecs,
+        };
+    }
+}
+
 impl SseDecode for crate::error::SdkError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3957,6 +5116,26 @@ impl SseDecode for crate::error::SdkError {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::SdkError::ServiceConnectivity { err: var_err };
             }
+            4 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::SdkError::Swapper { err: var_err };
+            }
+            5 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::SdkError::ChainService { err: var_err };
+            }
+            6 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::SdkError::Persistence { err: var_err };
+            }
+            7 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::SdkError::Signer { err: var_err };
+            }
+            8 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::SdkError::Validation { err: var_err };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3983,29 +5162,89 @@ impl SseDecode for crate::model::SdkEvent {
             }
             2 => {
                 let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::SdkEvent::PaymentRefunded {
+                return crate::model::SdkEvent::PaymentQueued {
                     details: var_details,
                 };
             }
             3 => {
                 let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::SdkEvent::PaymentRefundPending {
+                return crate::model::SdkEvent::PaymentRefunded {
                     details: var_details,
                 };
             }
             4 => {
                 let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::SdkEvent::PaymentSucceeded {
+                return crate::model::SdkEvent::PaymentRefundPending {
                     details: var_details,
                 };
             }
             5 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::SdkEvent::PaymentSucceeded {
+                    details: var_details,
+                };
+            }
+            6 => {
                 let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
                 return crate::model::SdkEvent::PaymentWaitingConfirmation {
                     details: var_details,
                 };
             }
-            6 => {
+            7 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                let mut var_blocksLeft = <u32>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SwapNearingTimeout {
+                    swap_id: var_swapId,
+                    blocks_left: var_blocksLeft,
+                };
+            }
+            8 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SwapRefundable {
+                    swap_id: var_swapId,
+                };
+            }
+            9 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                let mut var_fromState = <crate::model::PaymentState>::sse_decode(deserializer);
+                let mut var_toState = <crate::model::PaymentState>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SwapStateChanged {
+                    swap_id: var_swapId,
+                    from_state: var_fromState,
+                    to_state: var_toState,
+                };
+            }
+            10 => {
+                let mut var_oldBalanceSat = <u64>::sse_decode(deserializer);
+                let mut var_newBalanceSat = <u64>::sse_decode(deserializer);
+                return crate::model::SdkEvent::BalanceChanged {
+                    old_balance_sat: var_oldBalanceSat,
+                    new_balance_sat: var_newBalanceSat,
+                };
+            }
+            11 => {
+                let mut var_step = <u32>::sse_decode(deserializer);
+                let mut var_total = <u32>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SyncProgress {
+                    step: var_step,
+                    total: var_total,
+                };
+            }
+            12 => {
+                let mut var_isConnected = <bool>::sse_decode(deserializer);
+                return crate::model::SdkEvent::ConnectivityChanged {
+                    is_connected: var_isConnected,
+                };
+            }
+            13 => {
+                let mut var_chain = <crate::model::Blockchain>::sse_decode(deserializer);
+                let mut var_height = <u32>::sse_decode(deserializer);
+                return crate::model::SdkEvent::NewBlock {
+                    chain: var_chain,
+                    height: var_height,
+                };
+            }
+            14 => {
                 return crate::model::SdkEvent::Synced;
             }
             _ => {
@@ -4142,6 +5381,26 @@ impl SseDecode for crate::bindings::SuccessActionProcessed {
     }
 }
 
+impl SseDecode for crate::model::SwapStateTransition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        let mut var_fromState = <crate::model::PaymentState>::sse_decode(deserializer);
+        let mut var_toState = <crate::model::PaymentState>::sse_decode(deserializer);
+        let mut var_swapperStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_txIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <u32>::sse_decode(deserializer);
+        return crate::model::SwapStateTransition {
+            swap_id: var_swapId,
+            from_state: var_fromState,
+            to_state: var_toState,
+            swapper_status: var_swapperStatus,
+            tx_ids: var_txIds,
+            created_at: var_createdAt,
+        };
+    }
+}
+
 impl SseDecode for crate::bindings::Symbol {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4158,6 +5417,61 @@ impl SseDecode for crate::bindings::Symbol {
     }
 }
 
+impl SseDecode for crate::model::SyncMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startedAt = <u32>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_scanDurationMs = <u64>::sse_decode(deserializer);
+        let mut var_liquidTip = <Option<u32>>::sse_decode(deserializer);
+        let mut var_totalTxCount = <u32>::sse_decode(deserializer);
+        let mut var_processedTxCount = <u32>::sse_decode(deserializer);
+        return crate::model::SyncMetrics {
+            started_at: var_startedAt,
+            duration_ms: var_durationMs,
+            scan_duration_ms: var_scanDurationMs,
+            liquid_tip: var_liquidTip,
+            total_tx_count: var_totalTxCount,
+            processed_tx_count: var_processedTxCount,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SyncOnceRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeoutSecs = <u64>::sse_decode(deserializer);
+        return crate::model::SyncOnceRequest {
+            timeout_secs: var_timeoutSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SyncOnceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pendingTasks = <Vec<crate::model::SyncTask>>::sse_decode(deserializer);
+        let mut var_pendingSwapCount = <u32>::sse_decode(deserializer);
+        return crate::model::SyncOnceResponse {
+            pending_tasks: var_pendingTasks,
+            pending_swap_count: var_pendingSwapCount,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SyncTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::SyncTask::Claims,
+            1 => crate::model::SyncTask::Refunds,
+            2 => crate::model::SyncTask::History,
+            _ => unreachable!("Invalid variant for SyncTask: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4212,6 +5526,44 @@ impl SseDecode for usize {
     }
 }
 
+impl SseDecode for crate::model::WebhookDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_eventSeq = <u64>::sse_decode(deserializer);
+        let mut var_payload = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::model::WebhookDeliveryState>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        let mut var_nextAttemptAt = <u32>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <u32>::sse_decode(deserializer);
+        return crate::model::WebhookDelivery {
+            id: var_id,
+            url: var_url,
+            event_seq: var_eventSeq,
+            payload: var_payload,
+            state: var_state,
+            attempts: var_attempts,
+            next_attempt_at: var_nextAttemptAt,
+            last_error: var_lastError,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::model::WebhookDeliveryState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::WebhookDeliveryState::Pending,
+            1 => crate::model::WebhookDeliveryState::Failed,
+            _ => unreachable!("Invalid variant for WebhookDeliveryState: {}", inner),
+        };
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -4413,6 +5765,22 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::BitcoinAddres
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Blockchain {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Liquid => 0.into_dart(),
+            Self::Bitcoin => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Blockchain {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Blockchain> for crate::model::Blockchain {
+    fn into_into_dart(self) -> crate::model::Blockchain {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::BuyBitcoinProvider {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -4454,6 +5822,48 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::BuyBitcoinRequest>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ChainBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::ChainBackend::Electrum { url } => {
+                [0.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::ChainBackend::Esplora { url } => {
+                [1.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::ChainBackend::Hybrid {
+                electrum_url,
+                esplora_url,
+            } => [
+                2.into_dart(),
+                electrum_url.into_into_dart().into_dart(),
+                esplora_url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::ChainBackend::Rpc {
+                url,
+                username,
+                password,
+            } => [
+                3.into_dart(),
+                url.into_into_dart().into_dart(),
+                username.into_into_dart().into_dart(),
+                password.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ChainBackend {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ChainBackend> for crate::model::ChainBackend {
+    fn into_into_dart(self) -> crate::model::ChainBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::CheckMessageRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4496,8 +5906,22 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::CheckMessageResponse>
 impl flutter_rust_bridge::IntoDart for crate::model::Config {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.liquid_electrum_url.into_into_dart().into_dart(),
-            self.bitcoin_electrum_url.into_into_dart().into_dart(),
+            self.liquid_chain_backend.into_into_dart().into_dart(),
+            self.bitcoin_chain_backend.into_into_dart().into_dart(),
+            self.liquid_fallback_chain_backends
+                .into_into_dart()
+                .into_dart(),
+            self.bitcoin_fallback_chain_backends
+                .into_into_dart()
+                .into_dart(),
+            self.chain_quorum_min_amount_sat
+                .into_into_dart()
+                .into_dart(),
+            self.swapper_url.into_into_dart().into_dart(),
+            self.swapper_ws_url.into_into_dart().into_dart(),
+            self.swapper_referral_id.into_into_dart().into_dart(),
+            self.lbtc_asset_id.into_into_dart().into_dart(),
+            self.liquid_genesis_hash.into_into_dart().into_dart(),
             self.mempoolspace_url.into_into_dart().into_dart(),
             self.working_dir.into_into_dart().into_dart(),
             self.cache_dir.into_into_dart().into_dart(),
@@ -4508,6 +5932,11 @@ impl flutter_rust_bridge::IntoDart for crate::model::Config {
                 .into_dart(),
             self.zero_conf_max_amount_sat.into_into_dart().into_dart(),
             self.breez_api_key.into_into_dart().into_dart(),
+            self.fiat_rate_history_currencies
+                .into_into_dart()
+                .into_dart(),
+            self.event_webhook_urls.into_into_dart().into_dart(),
+            self.scheduler_intervals.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4563,6 +5992,57 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::CurrencyInfo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::ErrorInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.retryable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::ErrorInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::ErrorInfo> for crate::error::ErrorInfo {
+    fn into_into_dart(self) -> crate::error::ErrorInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::EventLogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.seq.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.event.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::EventLogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::EventLogEntry>
+    for crate::model::EventLogEntry
+{
+    fn into_into_dart(self) -> crate::model::EventLogEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::FiatAmount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.currency.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::FiatAmount {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::FiatAmount> for crate::model::FiatAmount {
+    fn into_into_dart(self) -> crate::model::FiatAmount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::FiatCurrency> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4685,6 +6165,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::LightningPaymentLimitsRespo
         [
             self.send.into_into_dart().into_dart(),
             self.receive.into_into_dart().into_dart(),
+            self.cache_age_secs.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4748,6 +6229,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::LiquidNetwork {
         match self {
             Self::Mainnet => 0.into_dart(),
             Self::Testnet => 1.into_dart(),
+            Self::Regtest => 2.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -5332,6 +6814,12 @@ impl flutter_rust_bridge::IntoDart for crate::model::LogEntry {
         [
             self.line.into_into_dart().into_dart(),
             self.level.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.module.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.swap_id.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5343,6 +6831,22 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::LogEntry> for crate::model:
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LogField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LogField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LogField> for crate::model::LogField {
+    fn into_into_dart(self) -> crate::model::LogField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::MessageSuccessActionData> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.0.message.into_into_dart().into_dart()].into_dart()
@@ -5388,6 +6892,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::OnchainPaymentLimitsRespons
         [
             self.send.into_into_dart().into_dart(),
             self.receive.into_into_dart().into_dart(),
+            self.cache_age_secs.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5411,6 +6916,12 @@ impl flutter_rust_bridge::IntoDart for crate::model::PayAmount {
                 [0.into_dart(), amount_sat.into_into_dart().into_dart()].into_dart()
             }
             crate::model::PayAmount::Drain => [1.into_dart()].into_dart(),
+            crate::model::PayAmount::Fiat { currency, amount } => [
+                2.into_dart(),
+                currency.into_into_dart().into_dart(),
+                amount.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -5456,6 +6967,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::Payment {
             self.payment_type.into_into_dart().into_dart(),
             self.status.into_into_dart().into_dart(),
             self.details.into_into_dart().into_dart(),
+            self.fiat_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5575,6 +7087,12 @@ impl flutter_rust_bridge::IntoDart for crate::error::PaymentError {
             crate::error::PaymentError::SignerError { err } => {
                 [20.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
+            crate::error::PaymentError::Swapper { err } => {
+                [21.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::ChainService { err } => {
+                [22.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -5588,6 +7106,24 @@ impl flutter_rust_bridge::IntoIntoDart<crate::error::PaymentError> for crate::er
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentFiatRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.currency.into_into_dart().into_dart(),
+            self.rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentFiatRate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentFiatRate>
+    for crate::model::PaymentFiatRate
+{
+    fn into_into_dart(self) -> crate::model::PaymentFiatRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::PaymentMethod {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -5761,6 +7297,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PreparePayOnchainResponse {
             self.receiver_amount_sat.into_into_dart().into_dart(),
             self.claim_fees_sat.into_into_dart().into_dart(),
             self.total_fees_sat.into_into_dart().into_dart(),
+            self.fiat_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5781,6 +7318,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.payer_amount_sat.into_into_dart().into_dart(),
+            self.payer_amount_fiat.into_into_dart().into_dart(),
             self.payment_method.into_into_dart().into_dart(),
         ]
         .into_dart()
@@ -5804,6 +7342,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveResponse {
             self.payment_method.into_into_dart().into_dart(),
             self.payer_amount_sat.into_into_dart().into_dart(),
             self.fees_sat.into_into_dart().into_dart(),
+            self.fiat_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5848,6 +7387,8 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareRefundResponse {
             self.tx_vsize.into_into_dart().into_dart(),
             self.tx_fee_sat.into_into_dart().into_dart(),
             self.refund_tx_id.into_into_dart().into_dart(),
+            self.is_cooperative.into_into_dart().into_dart(),
+            self.fee_saved_sat.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5890,6 +7431,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendResponse {
         [
             self.destination.into_into_dart().into_dart(),
             self.fees_sat.into_into_dart().into_dart(),
+            self.fiat_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -6026,6 +7568,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::RefundableSwap {
             self.swap_address.into_into_dart().into_dart(),
             self.timestamp.into_into_dart().into_dart(),
             self.amount_sat.into_into_dart().into_dart(),
+            self.time_to_refundable.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -6099,6 +7642,30 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::RouteHintHop>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SchedulerIntervals {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sync_secs.into_into_dart().into_dart(),
+            self.refunds_secs.into_into_dart().into_dart(),
+            self.liquid_rescan_secs.into_into_dart().into_dart(),
+            self.bitcoin_rescan_secs.into_into_dart().into_dart(),
+            self.new_blocks_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SchedulerIntervals
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SchedulerIntervals>
+    for crate::model::SchedulerIntervals
+{
+    fn into_into_dart(self) -> crate::model::SchedulerIntervals {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::error::SdkError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -6110,6 +7677,21 @@ impl flutter_rust_bridge::IntoDart for crate::error::SdkError {
             crate::error::SdkError::ServiceConnectivity { err } => {
                 [3.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
+            crate::error::SdkError::Swapper { err } => {
+                [4.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::SdkError::ChainService { err } => {
+                [5.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::SdkError::Persistence { err } => {
+                [6.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::SdkError::Signer { err } => {
+                [7.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::SdkError::Validation { err } => {
+                [8.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -6132,19 +7714,69 @@ impl flutter_rust_bridge::IntoDart for crate::model::SdkEvent {
             crate::model::SdkEvent::PaymentPending { details } => {
                 [1.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::PaymentRefunded { details } => {
+            crate::model::SdkEvent::PaymentQueued { details } => {
                 [2.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::PaymentRefundPending { details } => {
+            crate::model::SdkEvent::PaymentRefunded { details } => {
                 [3.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::PaymentSucceeded { details } => {
+            crate::model::SdkEvent::PaymentRefundPending { details } => {
                 [4.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::PaymentWaitingConfirmation { details } => {
+            crate::model::SdkEvent::PaymentSucceeded { details } => {
                 [5.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::Synced => [6.into_dart()].into_dart(),
+            crate::model::SdkEvent::PaymentWaitingConfirmation { details } => {
+                [6.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SdkEvent::SwapNearingTimeout {
+                swap_id,
+                blocks_left,
+            } => [
+                7.into_dart(),
+                swap_id.into_into_dart().into_dart(),
+                blocks_left.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SdkEvent::SwapRefundable { swap_id } => {
+                [8.into_dart(), swap_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SdkEvent::SwapStateChanged {
+                swap_id,
+                from_state,
+                to_state,
+            } => [
+                9.into_dart(),
+                swap_id.into_into_dart().into_dart(),
+                from_state.into_into_dart().into_dart(),
+                to_state.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SdkEvent::BalanceChanged {
+                old_balance_sat,
+                new_balance_sat,
+            } => [
+                10.into_dart(),
+                old_balance_sat.into_into_dart().into_dart(),
+                new_balance_sat.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SdkEvent::SyncProgress { step, total } => [
+                11.into_dart(),
+                step.into_into_dart().into_dart(),
+                total.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SdkEvent::ConnectivityChanged { is_connected } => {
+                [12.into_dart(), is_connected.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SdkEvent::NewBlock { chain, height } => [
+                13.into_dart(),
+                chain.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SdkEvent::Synced => [14.into_dart()].into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -6319,6 +7951,31 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::SuccessAction
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SwapStateTransition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_id.into_into_dart().into_dart(),
+            self.from_state.into_into_dart().into_dart(),
+            self.to_state.into_into_dart().into_dart(),
+            self.swapper_status.into_into_dart().into_dart(),
+            self.tx_ids.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SwapStateTransition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SwapStateTransition>
+    for crate::model::SwapStateTransition
+{
+    fn into_into_dart(self) -> crate::model::SwapStateTransition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::Symbol> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6342,6 +7999,78 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::Symbol>>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SyncMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.started_at.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.scan_duration_ms.into_into_dart().into_dart(),
+            self.liquid_tip.into_into_dart().into_dart(),
+            self.total_tx_count.into_into_dart().into_dart(),
+            self.processed_tx_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SyncMetrics {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SyncMetrics> for crate::model::SyncMetrics {
+    fn into_into_dart(self) -> crate::model::SyncMetrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SyncOnceRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.timeout_secs.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SyncOnceRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SyncOnceRequest>
+    for crate::model::SyncOnceRequest
+{
+    fn into_into_dart(self) -> crate::model::SyncOnceRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SyncOnceResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pending_tasks.into_into_dart().into_dart(),
+            self.pending_swap_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SyncOnceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SyncOnceResponse>
+    for crate::model::SyncOnceResponse
+{
+    fn into_into_dart(self) -> crate::model::SyncOnceResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SyncTask {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Claims => 0.into_dart(),
+            Self::Refunds => 1.into_dart(),
+            Self::History => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SyncTask {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SyncTask> for crate::model::SyncTask {
+    fn into_into_dart(self) -> crate::model::SyncTask {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::UrlSuccessActionData> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6363,6 +8092,52 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::UrlSuccessAct
         self.into()
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::WebhookDelivery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.event_seq.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.next_attempt_at.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::WebhookDelivery {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::WebhookDelivery>
+    for crate::model::WebhookDelivery
+{
+    fn into_into_dart(self) -> crate::model::WebhookDelivery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::WebhookDeliveryState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pending => 0.into_dart(),
+            Self::Failed => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::WebhookDeliveryState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::WebhookDeliveryState>
+    for crate::model::WebhookDeliveryState
+{
+    fn into_into_dart(self) -> crate::model::WebhookDeliveryState {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6498,6 +8273,22 @@ impl SseEncode for crate::bindings::BitcoinAddressData {
     }
 }
 
+impl SseEncode for crate::model::Blockchain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::Blockchain::Liquid => 0,
+                crate::model::Blockchain::Bitcoin => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6528,6 +8319,43 @@ impl SseEncode for crate::model::BuyBitcoinRequest {
     }
 }
 
+impl SseEncode for crate::model::ChainBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::ChainBackend::Electrum { url } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::model::ChainBackend::Esplora { url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::model::ChainBackend::Hybrid {
+                electrum_url,
+                esplora_url,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(electrum_url, serializer);
+                <String>::sse_encode(esplora_url, serializer);
+            }
+            crate::model::ChainBackend::Rpc {
+                url,
+                username,
+                password,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(url, serializer);
+                <Option<String>>::sse_encode(username, serializer);
+                <Option<String>>::sse_encode(password, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::model::CheckMessageRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6547,8 +8375,22 @@ impl SseEncode for crate::model::CheckMessageResponse {
 impl SseEncode for crate::model::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.liquid_electrum_url, serializer);
-        <String>::sse_encode(self.bitcoin_electrum_url, serializer);
+        <crate::model::ChainBackend>::sse_encode(self.liquid_chain_backend, serializer);
+        <crate::model::ChainBackend>::sse_encode(self.bitcoin_chain_backend, serializer);
+        <Option<Vec<crate::model::ChainBackend>>>::sse_encode(
+            self.liquid_fallback_chain_backends,
+            serializer,
+        );
+        <Option<Vec<crate::model::ChainBackend>>>::sse_encode(
+            self.bitcoin_fallback_chain_backends,
+            serializer,
+        );
+        <Option<u64>>::sse_encode(self.chain_quorum_min_amount_sat, serializer);
+        <Option<String>>::sse_encode(self.swapper_url, serializer);
+        <Option<String>>::sse_encode(self.swapper_ws_url, serializer);
+        <Option<String>>::sse_encode(self.swapper_referral_id, serializer);
+        <Option<String>>::sse_encode(self.lbtc_asset_id, serializer);
+        <Option<String>>::sse_encode(self.liquid_genesis_hash, serializer);
         <String>::sse_encode(self.mempoolspace_url, serializer);
         <String>::sse_encode(self.working_dir, serializer);
         <Option<String>>::sse_encode(self.cache_dir, serializer);
@@ -6557,6 +8399,12 @@ impl SseEncode for crate::model::Config {
         <u32>::sse_encode(self.zero_conf_min_fee_rate_msat, serializer);
         <Option<u64>>::sse_encode(self.zero_conf_max_amount_sat, serializer);
         <Option<String>>::sse_encode(self.breez_api_key, serializer);
+        <Option<Vec<String>>>::sse_encode(self.fiat_rate_history_currencies, serializer);
+        <Option<Vec<String>>>::sse_encode(self.event_webhook_urls, serializer);
+        <Option<crate::model::SchedulerIntervals>>::sse_encode(
+            self.scheduler_intervals,
+            serializer,
+        );
     }
 }
 
@@ -6581,6 +8429,23 @@ impl SseEncode for crate::bindings::CurrencyInfo {
     }
 }
 
+impl SseEncode for crate::error::ErrorInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.code, serializer);
+        <bool>::sse_encode(self.retryable, serializer);
+    }
+}
+
+impl SseEncode for crate::model::EventLogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.seq, serializer);
+        <u32>::sse_encode(self.created_at, serializer);
+        <crate::model::SdkEvent>::sse_encode(self.event, serializer);
+    }
+}
+
 impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6588,6 +8453,14 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::model::FiatAmount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.currency, serializer);
+        <f64>::sse_encode(self.amount, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::FiatCurrency {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6692,6 +8565,7 @@ impl SseEncode for crate::model::LightningPaymentLimitsResponse {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <crate::model::Limits>::sse_encode(self.send, serializer);
         <crate::model::Limits>::sse_encode(self.receive, serializer);
+        <Option<u64>>::sse_encode(self.cache_age_secs, serializer);
     }
 }
 
@@ -6723,6 +8597,7 @@ impl SseEncode for crate::model::LiquidNetwork {
             match self {
                 crate::model::LiquidNetwork::Mainnet => 0,
                 crate::model::LiquidNetwork::Testnet => 1,
+                crate::model::LiquidNetwork::Regtest => 2,
                 _ => {
                     unimplemented!("");
                 }
@@ -6742,6 +8617,26 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::model::ChainBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::ChainBackend>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::EventLogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::EventLogEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::bindings::FiatCurrency> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6782,6 +8677,16 @@ impl SseEncode for Vec<crate::bindings::LocalizedName> {
     }
 }
 
+impl SseEncode for Vec<crate::model::LogField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::LogField>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::model::Payment> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6883,6 +8788,36 @@ impl SseEncode for Vec<crate::bindings::RouteHintHop> {
     }
 }
 
+impl SseEncode for Vec<crate::model::SwapStateTransition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::SwapStateTransition>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::SyncTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::SyncTask>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::WebhookDelivery> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::WebhookDelivery>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::bindings::LNInvoice {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7213,6 +9148,20 @@ impl SseEncode for crate::model::LogEntry {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.line, serializer);
         <String>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <Option<String>>::sse_encode(self.module, serializer);
+        <u64>::sse_encode(self.timestamp, serializer);
+        <Option<String>>::sse_encode(self.swap_id, serializer);
+        <Option<String>>::sse_encode(self.payment_id, serializer);
+        <Vec<crate::model::LogField>>::sse_encode(self.fields, serializer);
+    }
+}
+
+impl SseEncode for crate::model::LogField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
 
@@ -7246,6 +9195,7 @@ impl SseEncode for crate::model::OnchainPaymentLimitsResponse {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <crate::model::Limits>::sse_encode(self.send, serializer);
         <crate::model::Limits>::sse_encode(self.receive, serializer);
+        <Option<u64>>::sse_encode(self.cache_age_secs, serializer);
     }
 }
 
@@ -7279,6 +9229,16 @@ impl SseEncode for Option<bool> {
     }
 }
 
+impl SseEncode for Option<crate::model::FiatAmount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::FiatAmount>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7319,6 +9279,36 @@ impl SseEncode for Option<crate::model::Payment> {
     }
 }
 
+impl SseEncode for Option<crate::model::PaymentFiatRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::PaymentFiatRate>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::bindings::Rate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::bindings::Rate>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::SchedulerIntervals> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::SchedulerIntervals>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::bindings::SuccessAction> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7349,6 +9339,16 @@ impl SseEncode for Option<crate::bindings::Symbol> {
     }
 }
 
+impl SseEncode for Option<crate::model::SyncMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::SyncMetrics>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7369,6 +9369,26 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::model::ChainBackend>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::model::ChainBackend>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<Vec<crate::model::PaymentType>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7390,6 +9410,11 @@ impl SseEncode for crate::model::PayAmount {
             crate::model::PayAmount::Drain => {
                 <i32>::sse_encode(1, serializer);
             }
+            crate::model::PayAmount::Fiat { currency, amount } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(currency, serializer);
+                <f64>::sse_encode(amount, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -7416,6 +9441,7 @@ impl SseEncode for crate::model::Payment {
         <crate::model::PaymentType>::sse_encode(self.payment_type, serializer);
         <crate::model::PaymentState>::sse_encode(self.status, serializer);
         <crate::model::PaymentDetails>::sse_encode(self.details, serializer);
+        <Option<crate::model::PaymentFiatRate>>::sse_encode(self.fiat_rate, serializer);
     }
 }
 
@@ -7548,6 +9574,14 @@ impl SseEncode for crate::error::PaymentError {
                 <i32>::sse_encode(20, serializer);
                 <String>::sse_encode(err, serializer);
             }
+            crate::error::PaymentError::Swapper { err } => {
+                <i32>::sse_encode(21, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::PaymentError::ChainService { err } => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(err, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -7555,6 +9589,14 @@ impl SseEncode for crate::error::PaymentError {
     }
 }
 
+impl SseEncode for crate::model::PaymentFiatRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.currency, serializer);
+        <f64>::sse_encode(self.rate, serializer);
+    }
+}
+
 impl SseEncode for crate::model::PaymentMethod {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7659,6 +9701,7 @@ impl SseEncode for crate::model::PreparePayOnchainResponse {
         <u64>::sse_encode(self.receiver_amount_sat, serializer);
         <u64>::sse_encode(self.claim_fees_sat, serializer);
         <u64>::sse_encode(self.total_fees_sat, serializer);
+        <Option<crate::model::PaymentFiatRate>>::sse_encode(self.fiat_rate, serializer);
     }
 }
 
@@ -7666,6 +9709,7 @@ impl SseEncode for crate::model::PrepareReceiveRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<u64>>::sse_encode(self.payer_amount_sat, serializer);
+        <Option<crate::model::FiatAmount>>::sse_encode(self.payer_amount_fiat, serializer);
         <crate::model::PaymentMethod>::sse_encode(self.payment_method, serializer);
     }
 }
@@ -7676,6 +9720,7 @@ impl SseEncode for crate::model::PrepareReceiveResponse {
         <crate::model::PaymentMethod>::sse_encode(self.payment_method, serializer);
         <Option<u64>>::sse_encode(self.payer_amount_sat, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<crate::model::PaymentFiatRate>>::sse_encode(self.fiat_rate, serializer);
     }
 }
 
@@ -7694,6 +9739,8 @@ impl SseEncode for crate::model::PrepareRefundResponse {
         <u32>::sse_encode(self.tx_vsize, serializer);
         <u64>::sse_encode(self.tx_fee_sat, serializer);
         <Option<String>>::sse_encode(self.refund_tx_id, serializer);
+        <bool>::sse_encode(self.is_cooperative, serializer);
+        <u64>::sse_encode(self.fee_saved_sat, serializer);
     }
 }
 
@@ -7710,6 +9757,7 @@ impl SseEncode for crate::model::PrepareSendResponse {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <crate::model::SendDestination>::sse_encode(self.destination, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<crate::model::PaymentFiatRate>>::sse_encode(self.fiat_rate, serializer);
     }
 }
 
@@ -7770,6 +9818,7 @@ impl SseEncode for crate::model::RefundableSwap {
         <String>::sse_encode(self.swap_address, serializer);
         <u32>::sse_encode(self.timestamp, serializer);
         <u64>::sse_encode(self.amount_sat, serializer);
+        <Option<u64>>::sse_encode(self.time_to_refundable, serializer);
     }
 }
 
@@ -7800,6 +9849,17 @@ impl SseEncode for crate::bindings::RouteHintHop {
     }
 }
 
+impl SseEncode for crate::model::SchedulerIntervals {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.sync_secs, serializer);
+        <u64>::sse_encode(self.refunds_secs, serializer);
+        <u64>::sse_encode(self.liquid_rescan_secs, serializer);
+        <u64>::sse_encode(self.bitcoin_rescan_secs, serializer);
+        <u64>::sse_encode(self.new_blocks_secs, serializer);
+    }
+}
+
 impl SseEncode for crate::error::SdkError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7818,6 +9878,26 @@ impl SseEncode for crate::error::SdkError {
                 <i32>::sse_encode(3, serializer);
                 <String>::sse_encode(err, serializer);
             }
+            crate::error::SdkError::Swapper { err } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::SdkError::ChainService { err } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::SdkError::Persistence { err } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::SdkError::Signer { err } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::SdkError::Validation { err } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(err, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -7837,24 +9917,72 @@ impl SseEncode for crate::model::SdkEvent {
                 <i32>::sse_encode(1, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::SdkEvent::PaymentRefunded { details } => {
+            crate::model::SdkEvent::PaymentQueued { details } => {
                 <i32>::sse_encode(2, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::SdkEvent::PaymentRefundPending { details } => {
+            crate::model::SdkEvent::PaymentRefunded { details } => {
                 <i32>::sse_encode(3, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::SdkEvent::PaymentSucceeded { details } => {
+            crate::model::SdkEvent::PaymentRefundPending { details } => {
                 <i32>::sse_encode(4, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::SdkEvent::PaymentWaitingConfirmation { details } => {
-                <i32>::sse_encode(5, serializer);
-                <crate::model::Payment>::sse_encode(details, serializer);
+            crate::model::SdkEvent::PaymentSucceeded { details } => {
+                <i32>::sse_encode(5, serializer);
+                <crate::model::Payment>::sse_encode(details, serializer);
+            }
+            crate::model::SdkEvent::PaymentWaitingConfirmation { details } => {
+                <i32>::sse_encode(6, serializer);
+                <crate::model::Payment>::sse_encode(details, serializer);
+            }
+            crate::model::SdkEvent::SwapNearingTimeout {
+                swap_id,
+                blocks_left,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(swap_id, serializer);
+                <u32>::sse_encode(blocks_left, serializer);
+            }
+            crate::model::SdkEvent::SwapRefundable { swap_id } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(swap_id, serializer);
+            }
+            crate::model::SdkEvent::SwapStateChanged {
+                swap_id,
+                from_state,
+                to_state,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(swap_id, serializer);
+                <crate::model::PaymentState>::sse_encode(from_state, serializer);
+                <crate::model::PaymentState>::sse_encode(to_state, serializer);
+            }
+            crate::model::SdkEvent::BalanceChanged {
+                old_balance_sat,
+                new_balance_sat,
+            } => {
+                <i32>::sse_encode(10, serializer);
+                <u64>::sse_encode(old_balance_sat, serializer);
+                <u64>::sse_encode(new_balance_sat, serializer);
+            }
+            crate::model::SdkEvent::SyncProgress { step, total } => {
+                <i32>::sse_encode(11, serializer);
+                <u32>::sse_encode(step, serializer);
+                <u32>::sse_encode(total, serializer);
+            }
+            crate::model::SdkEvent::ConnectivityChanged { is_connected } => {
+                <i32>::sse_encode(12, serializer);
+                <bool>::sse_encode(is_connected, serializer);
+            }
+            crate::model::SdkEvent::NewBlock { chain, height } => {
+                <i32>::sse_encode(13, serializer);
+                <crate::model::Blockchain>::sse_encode(chain, serializer);
+                <u32>::sse_encode(height, serializer);
             }
             crate::model::SdkEvent::Synced => {
-                <i32>::sse_encode(6, serializer);
+                <i32>::sse_encode(14, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -7964,6 +10092,18 @@ impl SseEncode for crate::bindings::SuccessActionProcessed {
     }
 }
 
+impl SseEncode for crate::model::SwapStateTransition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+        <crate::model::PaymentState>::sse_encode(self.from_state, serializer);
+        <crate::model::PaymentState>::sse_encode(self.to_state, serializer);
+        <Option<String>>::sse_encode(self.swapper_status, serializer);
+        <Vec<String>>::sse_encode(self.tx_ids, serializer);
+        <u32>::sse_encode(self.created_at, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::Symbol {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7974,6 +10114,50 @@ impl SseEncode for crate::bindings::Symbol {
     }
 }
 
+impl SseEncode for crate::model::SyncMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <u64>::sse_encode(self.scan_duration_ms, serializer);
+        <Option<u32>>::sse_encode(self.liquid_tip, serializer);
+        <u32>::sse_encode(self.total_tx_count, serializer);
+        <u32>::sse_encode(self.processed_tx_count, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SyncOnceRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.timeout_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SyncOnceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::model::SyncTask>>::sse_encode(self.pending_tasks, serializer);
+        <u32>::sse_encode(self.pending_swap_count, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SyncTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::SyncTask::Claims => 0,
+                crate::model::SyncTask::Refunds => 1,
+                crate::model::SyncTask::History => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8026,6 +10210,37 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::model::WebhookDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <u64>::sse_encode(self.event_seq, serializer);
+        <String>::sse_encode(self.payload, serializer);
+        <crate::model::WebhookDeliveryState>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+        <u32>::sse_encode(self.next_attempt_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <u32>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::model::WebhookDeliveryState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::WebhookDeliveryState::Pending => 0,
+                crate::model::WebhookDeliveryState::Failed => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -8286,6 +10501,13 @@ mod io {
             CstDecode::<crate::model::ConnectRequest>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::model::FiatAmount> for *mut wire_cst_fiat_amount {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::FiatAmount {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::model::FiatAmount>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::model::GetPaymentRequest> for *mut wire_cst_get_payment_request {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::GetPaymentRequest {
@@ -8432,6 +10654,20 @@ mod io {
             CstDecode::<crate::model::Payment>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::error::PaymentError> for *mut wire_cst_payment_error {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::error::PaymentError {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::error::PaymentError>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::model::PaymentFiatRate> for *mut wire_cst_payment_fiat_rate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::PaymentFiatRate {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::model::PaymentFiatRate>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::model::PrepareBuyBitcoinRequest>
         for *mut wire_cst_prepare_buy_bitcoin_request
     {
@@ -8478,6 +10714,13 @@ mod io {
             CstDecode::<crate::model::PrepareSendRequest>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::bindings::Rate> for *mut wire_cst_rate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::bindings::Rate {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::bindings::Rate>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::model::ReceivePaymentRequest> for *mut wire_cst_receive_payment_request {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::ReceivePaymentRequest {
@@ -8499,6 +10742,20 @@ mod io {
             CstDecode::<crate::model::RestoreRequest>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::model::SchedulerIntervals> for *mut wire_cst_scheduler_intervals {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SchedulerIntervals {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::model::SchedulerIntervals>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::error::SdkError> for *mut wire_cst_sdk_error {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::error::SdkError {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::error::SdkError>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<crate::model::SdkEvent> for *mut wire_cst_sdk_event {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::SdkEvent {
@@ -8541,6 +10798,20 @@ mod io {
             CstDecode::<crate::bindings::Symbol>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::model::SyncMetrics> for *mut wire_cst_sync_metrics {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SyncMetrics {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::model::SyncMetrics>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::model::SyncOnceRequest> for *mut wire_cst_sync_once_request {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SyncOnceRequest {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::model::SyncOnceRequest>::cst_decode(*wrap).into()
+        }
+    }
     impl CstDecode<u32> for *mut u32 {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> u32 {
@@ -8569,6 +10840,41 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::model::ChainBackend> for wire_cst_chain_backend {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::ChainBackend {
+            match self.tag {
+                0 => {
+                    let ans = unsafe { self.kind.Electrum };
+                    crate::model::ChainBackend::Electrum {
+                        url: ans.url.cst_decode(),
+                    }
+                }
+                1 => {
+                    let ans = unsafe { self.kind.Esplora };
+                    crate::model::ChainBackend::Esplora {
+                        url: ans.url.cst_decode(),
+                    }
+                }
+                2 => {
+                    let ans = unsafe { self.kind.Hybrid };
+                    crate::model::ChainBackend::Hybrid {
+                        electrum_url: ans.electrum_url.cst_decode(),
+                        esplora_url: ans.esplora_url.cst_decode(),
+                    }
+                }
+                3 => {
+                    let ans = unsafe { self.kind.Rpc };
+                    crate::model::ChainBackend::Rpc {
+                        url: ans.url.cst_decode(),
+                        username: ans.username.cst_decode(),
+                        password: ans.password.cst_decode(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
     impl CstDecode<crate::model::CheckMessageRequest> for wire_cst_check_message_request {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::CheckMessageRequest {
@@ -8591,8 +10897,16 @@ mod io {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::Config {
             crate::model::Config {
-                liquid_electrum_url: self.liquid_electrum_url.cst_decode(),
-                bitcoin_electrum_url: self.bitcoin_electrum_url.cst_decode(),
+                liquid_chain_backend: self.liquid_chain_backend.cst_decode(),
+                bitcoin_chain_backend: self.bitcoin_chain_backend.cst_decode(),
+                liquid_fallback_chain_backends: self.liquid_fallback_chain_backends.cst_decode(),
+                bitcoin_fallback_chain_backends: self.bitcoin_fallback_chain_backends.cst_decode(),
+                chain_quorum_min_amount_sat: self.chain_quorum_min_amount_sat.cst_decode(),
+                swapper_url: self.swapper_url.cst_decode(),
+                swapper_ws_url: self.swapper_ws_url.cst_decode(),
+                swapper_referral_id: self.swapper_referral_id.cst_decode(),
+                lbtc_asset_id: self.lbtc_asset_id.cst_decode(),
+                liquid_genesis_hash: self.liquid_genesis_hash.cst_decode(),
                 mempoolspace_url: self.mempoolspace_url.cst_decode(),
                 working_dir: self.working_dir.cst_decode(),
                 cache_dir: self.cache_dir.cst_decode(),
@@ -8601,6 +10915,9 @@ mod io {
                 zero_conf_min_fee_rate_msat: self.zero_conf_min_fee_rate_msat.cst_decode(),
                 zero_conf_max_amount_sat: self.zero_conf_max_amount_sat.cst_decode(),
                 breez_api_key: self.breez_api_key.cst_decode(),
+                fiat_rate_history_currencies: self.fiat_rate_history_currencies.cst_decode(),
+                event_webhook_urls: self.event_webhook_urls.cst_decode(),
+                scheduler_intervals: self.scheduler_intervals.cst_decode(),
             }
         }
     }
@@ -8627,6 +10944,34 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::error::ErrorInfo> for wire_cst_error_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::error::ErrorInfo {
+            crate::error::ErrorInfo {
+                code: self.code.cst_decode(),
+                retryable: self.retryable.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::model::EventLogEntry> for wire_cst_event_log_entry {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::EventLogEntry {
+            crate::model::EventLogEntry {
+                seq: self.seq.cst_decode(),
+                created_at: self.created_at.cst_decode(),
+                event: self.event.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::model::FiatAmount> for wire_cst_fiat_amount {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::FiatAmount {
+            crate::model::FiatAmount {
+                currency: self.currency.cst_decode(),
+                amount: self.amount.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::bindings::FiatCurrency> for wire_cst_fiat_currency {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::bindings::FiatCurrency {
@@ -8738,6 +11083,7 @@ mod io {
             crate::model::LightningPaymentLimitsResponse {
                 send: self.send.cst_decode(),
                 receive: self.receive.cst_decode(),
+                cache_age_secs: self.cache_age_secs.cst_decode(),
             }
         }
     }
@@ -8774,6 +11120,26 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::model::ChainBackend>> for *mut wire_cst_list_chain_backend {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::ChainBackend> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::model::EventLogEntry>> for *mut wire_cst_list_event_log_entry {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::EventLogEntry> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::bindings::FiatCurrency>> for *mut wire_cst_list_fiat_currency {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::bindings::FiatCurrency> {
@@ -8816,6 +11182,16 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::model::LogField>> for *mut wire_cst_list_log_field {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::LogField> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::model::Payment>> for *mut wire_cst_list_payment {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::model::Payment> {
@@ -8918,6 +11294,38 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::model::SwapStateTransition>>
+        for *mut wire_cst_list_swap_state_transition
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::SwapStateTransition> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::model::SyncTask>> for *mut wire_cst_list_sync_task {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::SyncTask> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::model::WebhookDelivery>> for *mut wire_cst_list_webhook_delivery {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::model::WebhookDelivery> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<crate::bindings::LNInvoice> for wire_cst_ln_invoice {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::bindings::LNInvoice {
@@ -9296,6 +11704,21 @@ mod io {
             crate::model::LogEntry {
                 line: self.line.cst_decode(),
                 level: self.level.cst_decode(),
+                target: self.target.cst_decode(),
+                module: self.module.cst_decode(),
+                timestamp: self.timestamp.cst_decode(),
+                swap_id: self.swap_id.cst_decode(),
+                payment_id: self.payment_id.cst_decode(),
+                fields: self.fields.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::model::LogField> for wire_cst_log_field {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::LogField {
+            crate::model::LogField {
+                key: self.key.cst_decode(),
+                value: self.value.cst_decode(),
             }
         }
     }
@@ -9315,6 +11738,7 @@ mod io {
             crate::model::OnchainPaymentLimitsResponse {
                 send: self.send.cst_decode(),
                 receive: self.receive.cst_decode(),
+                cache_age_secs: self.cache_age_secs.cst_decode(),
             }
         }
     }
@@ -9329,6 +11753,13 @@ mod io {
                     }
                 }
                 1 => crate::model::PayAmount::Drain,
+                2 => {
+                    let ans = unsafe { self.kind.Fiat };
+                    crate::model::PayAmount::Fiat {
+                        currency: ans.currency.cst_decode(),
+                        amount: ans.amount.cst_decode(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -9354,6 +11785,7 @@ mod io {
                 payment_type: self.payment_type.cst_decode(),
                 status: self.status.cst_decode(),
                 details: self.details.cst_decode(),
+                fiat_rate: self.fiat_rate.cst_decode(),
             }
         }
     }
@@ -9470,10 +11902,31 @@ mod io {
                         err: ans.err.cst_decode(),
                     }
                 }
+                21 => {
+                    let ans = unsafe { self.kind.Swapper };
+                    crate::error::PaymentError::Swapper {
+                        err: ans.err.cst_decode(),
+                    }
+                }
+                22 => {
+                    let ans = unsafe { self.kind.ChainService };
+                    crate::error::PaymentError::ChainService {
+                        err: ans.err.cst_decode(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
     }
+    impl CstDecode<crate::model::PaymentFiatRate> for wire_cst_payment_fiat_rate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::PaymentFiatRate {
+            crate::model::PaymentFiatRate {
+                currency: self.currency.cst_decode(),
+                rate: self.rate.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::model::PrepareBuyBitcoinRequest> for wire_cst_prepare_buy_bitcoin_request {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::model::PrepareBuyBitcoinRequest {
@@ -9530,6 +11983,7 @@ mod io {
                 receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
                 claim_fees_sat: self.claim_fees_sat.cst_decode(),
                 total_fees_sat: self.total_fees_sat.cst_decode(),
+                fiat_rate: self.fiat_rate.cst_decode(),
             }
         }
     }
@@ -9538,6 +11992,7 @@ mod io {
         fn cst_decode(self) -> crate::model::PrepareReceiveRequest {
             crate::model::PrepareReceiveRequest {
                 payer_amount_sat: self.payer_amount_sat.cst_decode(),
+                payer_amount_fiat: self.payer_amount_fiat.cst_decode(),
                 payment_method: self.payment_method.cst_decode(),
             }
         }
@@ -9549,6 +12004,7 @@ mod io {
                 payment_method: self.payment_method.cst_decode(),
                 payer_amount_sat: self.payer_amount_sat.cst_decode(),
                 fees_sat: self.fees_sat.cst_decode(),
+                fiat_rate: self.fiat_rate.cst_decode(),
             }
         }
     }
@@ -9569,6 +12025,8 @@ mod io {
                 tx_vsize: self.tx_vsize.cst_decode(),
                 tx_fee_sat: self.tx_fee_sat.cst_decode(),
                 refund_tx_id: self.refund_tx_id.cst_decode(),
+                is_cooperative: self.is_cooperative.cst_decode(),
+                fee_saved_sat: self.fee_saved_sat.cst_decode(),
             }
         }
     }
@@ -9587,6 +12045,7 @@ mod io {
             crate::model::PrepareSendResponse {
                 destination: self.destination.cst_decode(),
                 fees_sat: self.fees_sat.cst_decode(),
+                fiat_rate: self.fiat_rate.cst_decode(),
             }
         }
     }
@@ -9654,6 +12113,7 @@ mod io {
                 swap_address: self.swap_address.cst_decode(),
                 timestamp: self.timestamp.cst_decode(),
                 amount_sat: self.amount_sat.cst_decode(),
+                time_to_refundable: self.time_to_refundable.cst_decode(),
             }
         }
     }
@@ -9687,6 +12147,18 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::model::SchedulerIntervals> for wire_cst_scheduler_intervals {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SchedulerIntervals {
+            crate::model::SchedulerIntervals {
+                sync_secs: self.sync_secs.cst_decode(),
+                refunds_secs: self.refunds_secs.cst_decode(),
+                liquid_rescan_secs: self.liquid_rescan_secs.cst_decode(),
+                bitcoin_rescan_secs: self.bitcoin_rescan_secs.cst_decode(),
+                new_blocks_secs: self.new_blocks_secs.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::error::SdkError> for wire_cst_sdk_error {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::error::SdkError {
@@ -9705,6 +12177,36 @@ mod io {
                         err: ans.err.cst_decode(),
                     }
                 }
+                4 => {
+                    let ans = unsafe { self.kind.Swapper };
+                    crate::error::SdkError::Swapper {
+                        err: ans.err.cst_decode(),
+                    }
+                }
+                5 => {
+                    let ans = unsafe { self.kind.ChainService };
+                    crate::error::SdkError::ChainService {
+                        err: ans.err.cst_decode(),
+                    }
+                }
+                6 => {
+                    let ans = unsafe { self.kind.Persistence };
+                    crate::error::SdkError::Persistence {
+                        err: ans.err.cst_decode(),
+                    }
+                }
+                7 => {
+                    let ans = unsafe { self.kind.Signer };
+                    crate::error::SdkError::Signer {
+                        err: ans.err.cst_decode(),
+                    }
+                }
+                8 => {
+                    let ans = unsafe { self.kind.Validation };
+                    crate::error::SdkError::Validation {
+                        err: ans.err.cst_decode(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -9725,31 +12227,85 @@ mod io {
                         details: ans.details.cst_decode(),
                     }
                 }
-                2 => {
-                    let ans = unsafe { self.kind.PaymentRefunded };
-                    crate::model::SdkEvent::PaymentRefunded {
-                        details: ans.details.cst_decode(),
+                2 => {
+                    let ans = unsafe { self.kind.PaymentQueued };
+                    crate::model::SdkEvent::PaymentQueued {
+                        details: ans.details.cst_decode(),
+                    }
+                }
+                3 => {
+                    let ans = unsafe { self.kind.PaymentRefunded };
+                    crate::model::SdkEvent::PaymentRefunded {
+                        details: ans.details.cst_decode(),
+                    }
+                }
+                4 => {
+                    let ans = unsafe { self.kind.PaymentRefundPending };
+                    crate::model::SdkEvent::PaymentRefundPending {
+                        details: ans.details.cst_decode(),
+                    }
+                }
+                5 => {
+                    let ans = unsafe { self.kind.PaymentSucceeded };
+                    crate::model::SdkEvent::PaymentSucceeded {
+                        details: ans.details.cst_decode(),
+                    }
+                }
+                6 => {
+                    let ans = unsafe { self.kind.PaymentWaitingConfirmation };
+                    crate::model::SdkEvent::PaymentWaitingConfirmation {
+                        details: ans.details.cst_decode(),
+                    }
+                }
+                7 => {
+                    let ans = unsafe { self.kind.SwapNearingTimeout };
+                    crate::model::SdkEvent::SwapNearingTimeout {
+                        swap_id: ans.swap_id.cst_decode(),
+                        blocks_left: ans.blocks_left.cst_decode(),
+                    }
+                }
+                8 => {
+                    let ans = unsafe { self.kind.SwapRefundable };
+                    crate::model::SdkEvent::SwapRefundable {
+                        swap_id: ans.swap_id.cst_decode(),
+                    }
+                }
+                9 => {
+                    let ans = unsafe { self.kind.SwapStateChanged };
+                    crate::model::SdkEvent::SwapStateChanged {
+                        swap_id: ans.swap_id.cst_decode(),
+                        from_state: ans.from_state.cst_decode(),
+                        to_state: ans.to_state.cst_decode(),
                     }
                 }
-                3 => {
-                    let ans = unsafe { self.kind.PaymentRefundPending };
-                    crate::model::SdkEvent::PaymentRefundPending {
-                        details: ans.details.cst_decode(),
+                10 => {
+                    let ans = unsafe { self.kind.BalanceChanged };
+                    crate::model::SdkEvent::BalanceChanged {
+                        old_balance_sat: ans.old_balance_sat.cst_decode(),
+                        new_balance_sat: ans.new_balance_sat.cst_decode(),
                     }
                 }
-                4 => {
-                    let ans = unsafe { self.kind.PaymentSucceeded };
-                    crate::model::SdkEvent::PaymentSucceeded {
-                        details: ans.details.cst_decode(),
+                11 => {
+                    let ans = unsafe { self.kind.SyncProgress };
+                    crate::model::SdkEvent::SyncProgress {
+                        step: ans.step.cst_decode(),
+                        total: ans.total.cst_decode(),
                     }
                 }
-                5 => {
-                    let ans = unsafe { self.kind.PaymentWaitingConfirmation };
-                    crate::model::SdkEvent::PaymentWaitingConfirmation {
-                        details: ans.details.cst_decode(),
+                12 => {
+                    let ans = unsafe { self.kind.ConnectivityChanged };
+                    crate::model::SdkEvent::ConnectivityChanged {
+                        is_connected: ans.is_connected.cst_decode(),
+                    }
+                }
+                13 => {
+                    let ans = unsafe { self.kind.NewBlock };
+                    crate::model::SdkEvent::NewBlock {
+                        chain: ans.chain.cst_decode(),
+                        height: ans.height.cst_decode(),
                     }
                 }
-                6 => crate::model::SdkEvent::Synced,
+                14 => crate::model::SdkEvent::Synced,
                 _ => unreachable!(),
             }
         }
@@ -9865,6 +12421,19 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::model::SwapStateTransition> for wire_cst_swap_state_transition {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SwapStateTransition {
+            crate::model::SwapStateTransition {
+                swap_id: self.swap_id.cst_decode(),
+                from_state: self.from_state.cst_decode(),
+                to_state: self.to_state.cst_decode(),
+                swapper_status: self.swapper_status.cst_decode(),
+                tx_ids: self.tx_ids.cst_decode(),
+                created_at: self.created_at.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::bindings::Symbol> for wire_cst_symbol {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::bindings::Symbol {
@@ -9876,6 +12445,36 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::model::SyncMetrics> for wire_cst_sync_metrics {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SyncMetrics {
+            crate::model::SyncMetrics {
+                started_at: self.started_at.cst_decode(),
+                duration_ms: self.duration_ms.cst_decode(),
+                scan_duration_ms: self.scan_duration_ms.cst_decode(),
+                liquid_tip: self.liquid_tip.cst_decode(),
+                total_tx_count: self.total_tx_count.cst_decode(),
+                processed_tx_count: self.processed_tx_count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::model::SyncOnceRequest> for wire_cst_sync_once_request {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SyncOnceRequest {
+            crate::model::SyncOnceRequest {
+                timeout_secs: self.timeout_secs.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::model::SyncOnceResponse> for wire_cst_sync_once_response {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::SyncOnceResponse {
+            crate::model::SyncOnceResponse {
+                pending_tasks: self.pending_tasks.cst_decode(),
+                pending_swap_count: self.pending_swap_count.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::bindings::UrlSuccessActionData> for wire_cst_url_success_action_data {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::bindings::UrlSuccessActionData {
@@ -9886,6 +12485,22 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::model::WebhookDelivery> for wire_cst_webhook_delivery {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::model::WebhookDelivery {
+            crate::model::WebhookDelivery {
+                id: self.id.cst_decode(),
+                url: self.url.cst_decode(),
+                event_seq: self.event_seq.cst_decode(),
+                payload: self.payload.cst_decode(),
+                state: self.state.cst_decode(),
+                attempts: self.attempts.cst_decode(),
+                next_attempt_at: self.next_attempt_at.cst_decode(),
+                last_error: self.last_error.cst_decode(),
+                created_at: self.created_at.cst_decode(),
+            }
+        }
+    }
     impl NewWithNullPtr for wire_cst_aes_success_action_data {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -9992,6 +12607,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_chain_backend {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: ChainBackendKind { nil__: () },
+            }
+        }
+    }
+    impl Default for wire_cst_chain_backend {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_check_message_request {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -10021,8 +12649,16 @@ mod io {
     impl NewWithNullPtr for wire_cst_config {
         fn new_with_null_ptr() -> Self {
             Self {
-                liquid_electrum_url: core::ptr::null_mut(),
-                bitcoin_electrum_url: core::ptr::null_mut(),
+                liquid_chain_backend: Default::default(),
+                bitcoin_chain_backend: Default::default(),
+                liquid_fallback_chain_backends: core::ptr::null_mut(),
+                bitcoin_fallback_chain_backends: core::ptr::null_mut(),
+                chain_quorum_min_amount_sat: core::ptr::null_mut(),
+                swapper_url: core::ptr::null_mut(),
+                swapper_ws_url: core::ptr::null_mut(),
+                swapper_referral_id: core::ptr::null_mut(),
+                lbtc_asset_id: core::ptr::null_mut(),
+                liquid_genesis_hash: core::ptr::null_mut(),
                 mempoolspace_url: core::ptr::null_mut(),
                 working_dir: core::ptr::null_mut(),
                 cache_dir: core::ptr::null_mut(),
@@ -10031,6 +12667,9 @@ mod io {
                 zero_conf_min_fee_rate_msat: Default::default(),
                 zero_conf_max_amount_sat: core::ptr::null_mut(),
                 breez_api_key: core::ptr::null_mut(),
+                fiat_rate_history_currencies: core::ptr::null_mut(),
+                event_webhook_urls: core::ptr::null_mut(),
+                scheduler_intervals: core::ptr::null_mut(),
             }
         }
     }
@@ -10070,6 +12709,46 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_error_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                code: Default::default(),
+                retryable: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_error_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_event_log_entry {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                seq: Default::default(),
+                created_at: Default::default(),
+                event: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_event_log_entry {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_fiat_amount {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                currency: core::ptr::null_mut(),
+                amount: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_fiat_amount {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_fiat_currency {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -10130,6 +12809,7 @@ mod io {
             Self {
                 send: Default::default(),
                 receive: Default::default(),
+                cache_age_secs: core::ptr::null_mut(),
             }
         }
     }
@@ -10490,6 +13170,12 @@ mod io {
             Self {
                 line: core::ptr::null_mut(),
                 level: core::ptr::null_mut(),
+                target: core::ptr::null_mut(),
+                module: core::ptr::null_mut(),
+                timestamp: Default::default(),
+                swap_id: core::ptr::null_mut(),
+                payment_id: core::ptr::null_mut(),
+                fields: core::ptr::null_mut(),
             }
         }
     }
@@ -10498,6 +13184,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_log_field {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                key: core::ptr::null_mut(),
+                value: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_log_field {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_message_success_action_data {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -10515,6 +13214,7 @@ mod io {
             Self {
                 send: Default::default(),
                 receive: Default::default(),
+                cache_age_secs: core::ptr::null_mut(),
             }
         }
     }
@@ -10560,6 +13260,7 @@ mod io {
                 payment_type: Default::default(),
                 status: Default::default(),
                 details: Default::default(),
+                fiat_rate: core::ptr::null_mut(),
             }
         }
     }
@@ -10594,6 +13295,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_payment_fiat_rate {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                currency: core::ptr::null_mut(),
+                rate: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_payment_fiat_rate {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_prepare_buy_bitcoin_request {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -10669,6 +13383,7 @@ mod io {
                 receiver_amount_sat: Default::default(),
                 claim_fees_sat: Default::default(),
                 total_fees_sat: Default::default(),
+                fiat_rate: core::ptr::null_mut(),
             }
         }
     }
@@ -10681,6 +13396,7 @@ mod io {
         fn new_with_null_ptr() -> Self {
             Self {
                 payer_amount_sat: core::ptr::null_mut(),
+                payer_amount_fiat: core::ptr::null_mut(),
                 payment_method: Default::default(),
             }
         }
@@ -10696,6 +13412,7 @@ mod io {
                 payment_method: Default::default(),
                 payer_amount_sat: core::ptr::null_mut(),
                 fees_sat: Default::default(),
+                fiat_rate: core::ptr::null_mut(),
             }
         }
     }
@@ -10724,6 +13441,8 @@ mod io {
                 tx_vsize: Default::default(),
                 tx_fee_sat: Default::default(),
                 refund_tx_id: core::ptr::null_mut(),
+                is_cooperative: Default::default(),
+                fee_saved_sat: Default::default(),
             }
         }
     }
@@ -10750,6 +13469,7 @@ mod io {
             Self {
                 destination: Default::default(),
                 fees_sat: Default::default(),
+                fiat_rate: core::ptr::null_mut(),
             }
         }
     }
@@ -10845,6 +13565,7 @@ mod io {
                 swap_address: core::ptr::null_mut(),
                 timestamp: Default::default(),
                 amount_sat: Default::default(),
+                time_to_refundable: core::ptr::null_mut(),
             }
         }
     }
@@ -10895,6 +13616,22 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_scheduler_intervals {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                sync_secs: Default::default(),
+                refunds_secs: Default::default(),
+                liquid_rescan_secs: Default::default(),
+                bitcoin_rescan_secs: Default::default(),
+                new_blocks_secs: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_scheduler_intervals {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_sdk_error {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -11008,6 +13745,23 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_swap_state_transition {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                swap_id: core::ptr::null_mut(),
+                from_state: Default::default(),
+                to_state: Default::default(),
+                swapper_status: core::ptr::null_mut(),
+                tx_ids: core::ptr::null_mut(),
+                created_at: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_swap_state_transition {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_symbol {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -11023,6 +13777,48 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_sync_metrics {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                started_at: Default::default(),
+                duration_ms: Default::default(),
+                scan_duration_ms: Default::default(),
+                liquid_tip: core::ptr::null_mut(),
+                total_tx_count: Default::default(),
+                processed_tx_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_sync_metrics {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_sync_once_request {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                timeout_secs: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_sync_once_request {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_sync_once_response {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                pending_tasks: core::ptr::null_mut(),
+                pending_swap_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_sync_once_response {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_url_success_action_data {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -11037,6 +13833,35 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_webhook_delivery {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: Default::default(),
+                url: core::ptr::null_mut(),
+                event_seq: Default::default(),
+                payload: core::ptr::null_mut(),
+                state: Default::default(),
+                attempts: Default::default(),
+                next_attempt_at: Default::default(),
+                last_error: core::ptr::null_mut(),
+                created_at: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_webhook_delivery {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events(
+        that: usize,
+        listener_id: *mut wire_cst_list_prim_u_8_strict,
+        seq: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_ack_events_impl(that, listener_id, seq)
+    }
 
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(
@@ -11087,6 +13912,22 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since(
+        that: usize,
+        seq: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_events_since_impl(that, seq)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file(
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_export_rescue_file_impl(that, swap_id)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
         port_: i64,
@@ -11111,6 +13952,58 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(port_, that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap(
+        port_: i64,
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_force_claim_swap_impl(port_, that, swap_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(
+        port_: i64,
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+        is_cooperative: bool,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap_impl(
+            port_,
+            that,
+            swap_id,
+            is_cooperative,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(
+        port_: i64,
+        that: usize,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_generate_diagnostics_impl(port_, that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor(
+        that: usize,
+        listener_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_get_event_cursor_impl(that, listener_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(
+        port_: i64,
+        that: usize,
+        currency: *mut wire_cst_list_prim_u_8_strict,
+        timestamp: u32,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate_impl(
+            port_, that, currency, timestamp,
+        )
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
         port_: i64,
@@ -11119,6 +14012,13 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_get_info_impl(port_, that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(
+        that: usize,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics_impl(that)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
         port_: i64,
@@ -11128,6 +14028,37 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_get_payment_impl(port_, that, req)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history(
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_get_swap_history_impl(that, swap_id)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(
+        that: usize,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap(
+        port_: i64,
+        that: usize,
+        rescue_json: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_import_swap_impl(port_, that, rescue_json)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(
+        that: usize,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries_impl(that)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
         port_: i64,
@@ -11180,6 +14111,15 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(port_, that, req)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(
+        port_: i64,
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_mark_swap_failed_impl(port_, that, swap_id)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(
         port_: i64,
@@ -11260,6 +14200,15 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(port_, that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(
+        port_: i64,
+        that: usize,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_refetch_swap_status_impl(port_, that, swap_id)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(
         port_: i64,
@@ -11287,11 +14236,19 @@ mod io {
     }
 
     #[no_mangle]
-    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(
+        that: usize,
+        req: *mut wire_cst_restore_request,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__BindingLiquidSdk_restore_impl(that, req)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(
         that: usize,
-        req: *mut wire_cst_restore_request,
+        id: u64,
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__bindings__BindingLiquidSdk_restore_impl(that, req)
+        wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery_impl(that, id)
     }
 
     #[no_mangle]
@@ -11319,6 +14276,15 @@ mod io {
         wire__crate__bindings__BindingLiquidSdk_sync_impl(port_, that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once(
+        port_: i64,
+        that: usize,
+        req: *mut wire_cst_sync_once_request,
+    ) {
+        wire__crate__bindings__BindingLiquidSdk_sync_once_impl(port_, that, req)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook(
         port_: i64,
@@ -11375,6 +14341,20 @@ mod io {
         wire__crate__bindings__parse_invoice_impl(input)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__payment_error_info(
+        err: *mut wire_cst_payment_error,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__payment_error_info_impl(err)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__sdk_error_info(
+        err: *mut wire_cst_sdk_error,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__bindings__sdk_error_info_impl(err)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
         ptr: *const std::ffi::c_void,
@@ -11475,6 +14455,14 @@ mod io {
         )
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount(
+    ) -> *mut wire_cst_fiat_amount {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_fiat_amount::new_with_null_ptr(),
+        )
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request(
     ) -> *mut wire_cst_get_payment_request {
@@ -11626,6 +14614,22 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_payment::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment_error(
+    ) -> *mut wire_cst_payment_error {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_payment_error::new_with_null_ptr(),
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate(
+    ) -> *mut wire_cst_payment_fiat_rate {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_payment_fiat_rate::new_with_null_ptr(),
+        )
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request(
     ) -> *mut wire_cst_prepare_buy_bitcoin_request {
@@ -11674,6 +14678,11 @@ mod io {
         )
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_rate() -> *mut wire_cst_rate {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_rate::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request(
     ) -> *mut wire_cst_receive_payment_request {
@@ -11698,6 +14707,20 @@ mod io {
         )
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals(
+    ) -> *mut wire_cst_scheduler_intervals {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_scheduler_intervals::new_with_null_ptr(),
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_sdk_error() -> *mut wire_cst_sdk_error
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_sdk_error::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_sdk_event() -> *mut wire_cst_sdk_event
     {
@@ -11741,6 +14764,22 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_symbol::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics(
+    ) -> *mut wire_cst_sync_metrics {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_sync_metrics::new_with_null_ptr(),
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request(
+    ) -> *mut wire_cst_sync_once_request {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_sync_once_request::new_with_null_ptr(),
+        )
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
@@ -11773,6 +14812,34 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_chain_backend(
+        len: i32,
+    ) -> *mut wire_cst_list_chain_backend {
+        let wrap = wire_cst_list_chain_backend {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_chain_backend>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_event_log_entry(
+        len: i32,
+    ) -> *mut wire_cst_list_event_log_entry {
+        let wrap = wire_cst_list_event_log_entry {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_event_log_entry>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_list_fiat_currency(
         len: i32,
@@ -11829,6 +14896,20 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_log_field(
+        len: i32,
+    ) -> *mut wire_cst_list_log_field {
+        let wrap = wire_cst_list_log_field {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_log_field>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment(
         len: i32,
@@ -11919,6 +15000,45 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_swap_state_transition(
+        len: i32,
+    ) -> *mut wire_cst_list_swap_state_transition {
+        let wrap = wire_cst_list_swap_state_transition {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_swap_state_transition>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_sync_task(
+        len: i32,
+    ) -> *mut wire_cst_list_sync_task {
+        let wrap = wire_cst_list_sync_task {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn frbgen_breez_liquid_cst_new_list_webhook_delivery(
+        len: i32,
+    ) -> *mut wire_cst_list_webhook_delivery {
+        let wrap = wire_cst_list_webhook_delivery {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_webhook_delivery>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[repr(C)]
     #[derive(Clone, Copy)]
     pub struct wire_cst_aes_success_action_data {
@@ -12006,6 +15126,44 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_chain_backend {
+        tag: i32,
+        kind: ChainBackendKind,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union ChainBackendKind {
+        Electrum: wire_cst_ChainBackend_Electrum,
+        Esplora: wire_cst_ChainBackend_Esplora,
+        Hybrid: wire_cst_ChainBackend_Hybrid,
+        Rpc: wire_cst_ChainBackend_Rpc,
+        nil__: (),
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ChainBackend_Electrum {
+        url: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ChainBackend_Esplora {
+        url: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ChainBackend_Hybrid {
+        electrum_url: *mut wire_cst_list_prim_u_8_strict,
+        esplora_url: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ChainBackend_Rpc {
+        url: *mut wire_cst_list_prim_u_8_strict,
+        username: *mut wire_cst_list_prim_u_8_strict,
+        password: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_check_message_request {
         message: *mut wire_cst_list_prim_u_8_strict,
         pubkey: *mut wire_cst_list_prim_u_8_strict,
@@ -12019,8 +15177,16 @@ mod io {
     #[repr(C)]
     #[derive(Clone, Copy)]
     pub struct wire_cst_config {
-        liquid_electrum_url: *mut wire_cst_list_prim_u_8_strict,
-        bitcoin_electrum_url: *mut wire_cst_list_prim_u_8_strict,
+        liquid_chain_backend: wire_cst_chain_backend,
+        bitcoin_chain_backend: wire_cst_chain_backend,
+        liquid_fallback_chain_backends: *mut wire_cst_list_chain_backend,
+        bitcoin_fallback_chain_backends: *mut wire_cst_list_chain_backend,
+        chain_quorum_min_amount_sat: *mut u64,
+        swapper_url: *mut wire_cst_list_prim_u_8_strict,
+        swapper_ws_url: *mut wire_cst_list_prim_u_8_strict,
+        swapper_referral_id: *mut wire_cst_list_prim_u_8_strict,
+        lbtc_asset_id: *mut wire_cst_list_prim_u_8_strict,
+        liquid_genesis_hash: *mut wire_cst_list_prim_u_8_strict,
         mempoolspace_url: *mut wire_cst_list_prim_u_8_strict,
         working_dir: *mut wire_cst_list_prim_u_8_strict,
         cache_dir: *mut wire_cst_list_prim_u_8_strict,
@@ -12029,6 +15195,9 @@ mod io {
         zero_conf_min_fee_rate_msat: u32,
         zero_conf_max_amount_sat: *mut u64,
         breez_api_key: *mut wire_cst_list_prim_u_8_strict,
+        fiat_rate_history_currencies: *mut wire_cst_list_String,
+        event_webhook_urls: *mut wire_cst_list_String,
+        scheduler_intervals: *mut wire_cst_scheduler_intervals,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12049,6 +15218,25 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_error_info {
+        code: u32,
+        retryable: bool,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_event_log_entry {
+        seq: u64,
+        created_at: u32,
+        event: wire_cst_sdk_event,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_fiat_amount {
+        currency: *mut wire_cst_list_prim_u_8_strict,
+        amount: f64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_fiat_currency {
         id: *mut wire_cst_list_prim_u_8_strict,
         info: wire_cst_currency_info,
@@ -12155,6 +15343,7 @@ mod io {
     pub struct wire_cst_lightning_payment_limits_response {
         send: wire_cst_limits,
         receive: wire_cst_limits,
+        cache_age_secs: *mut u64,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12181,6 +15370,18 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_chain_backend {
+        ptr: *mut wire_cst_chain_backend,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_event_log_entry {
+        ptr: *mut wire_cst_event_log_entry,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_fiat_currency {
         ptr: *mut wire_cst_fiat_currency,
         len: i32,
@@ -12205,6 +15406,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_log_field {
+        ptr: *mut wire_cst_log_field,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_payment {
         ptr: *mut wire_cst_payment,
         len: i32,
@@ -12280,6 +15487,24 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_swap_state_transition {
+        ptr: *mut wire_cst_swap_state_transition,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_sync_task {
+        ptr: *mut i32,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_webhook_delivery {
+        ptr: *mut wire_cst_webhook_delivery,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_ln_invoice {
         bolt11: *mut wire_cst_list_prim_u_8_strict,
         network: i32,
@@ -12621,6 +15846,18 @@ mod io {
     pub struct wire_cst_log_entry {
         line: *mut wire_cst_list_prim_u_8_strict,
         level: *mut wire_cst_list_prim_u_8_strict,
+        target: *mut wire_cst_list_prim_u_8_strict,
+        module: *mut wire_cst_list_prim_u_8_strict,
+        timestamp: u64,
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+        payment_id: *mut wire_cst_list_prim_u_8_strict,
+        fields: *mut wire_cst_list_log_field,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_log_field {
+        key: *mut wire_cst_list_prim_u_8_strict,
+        value: *mut wire_cst_list_prim_u_8_strict,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12632,6 +15869,7 @@ mod io {
     pub struct wire_cst_onchain_payment_limits_response {
         send: wire_cst_limits,
         receive: wire_cst_limits,
+        cache_age_secs: *mut u64,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12643,6 +15881,7 @@ mod io {
     #[derive(Clone, Copy)]
     pub union PayAmountKind {
         Receiver: wire_cst_PayAmount_Receiver,
+        Fiat: wire_cst_PayAmount_Fiat,
         nil__: (),
     }
     #[repr(C)]
@@ -12652,6 +15891,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_PayAmount_Fiat {
+        currency: *mut wire_cst_list_prim_u_8_strict,
+        amount: f64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_pay_onchain_request {
         address: *mut wire_cst_list_prim_u_8_strict,
         prepare_response: wire_cst_prepare_pay_onchain_response,
@@ -12667,6 +15912,7 @@ mod io {
         payment_type: i32,
         status: i32,
         details: wire_cst_payment_details,
+        fiat_rate: *mut wire_cst_payment_fiat_rate,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12727,6 +15973,8 @@ mod io {
         Refunded: wire_cst_PaymentError_Refunded,
         SendError: wire_cst_PaymentError_SendError,
         SignerError: wire_cst_PaymentError_SignerError,
+        Swapper: wire_cst_PaymentError_Swapper,
+        ChainService: wire_cst_PaymentError_ChainService,
         nil__: (),
     }
     #[repr(C)]
@@ -12782,6 +16030,22 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_PaymentError_Swapper {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_PaymentError_ChainService {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_payment_fiat_rate {
+        currency: *mut wire_cst_list_prim_u_8_strict,
+        rate: f64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_prepare_buy_bitcoin_request {
         provider: i32,
         amount_sat: u64,
@@ -12820,11 +16084,13 @@ mod io {
         receiver_amount_sat: u64,
         claim_fees_sat: u64,
         total_fees_sat: u64,
+        fiat_rate: *mut wire_cst_payment_fiat_rate,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
     pub struct wire_cst_prepare_receive_request {
         payer_amount_sat: *mut u64,
+        payer_amount_fiat: *mut wire_cst_fiat_amount,
         payment_method: i32,
     }
     #[repr(C)]
@@ -12833,6 +16099,7 @@ mod io {
         payment_method: i32,
         payer_amount_sat: *mut u64,
         fees_sat: u64,
+        fiat_rate: *mut wire_cst_payment_fiat_rate,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12847,6 +16114,8 @@ mod io {
         tx_vsize: u32,
         tx_fee_sat: u64,
         refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
+        is_cooperative: bool,
+        fee_saved_sat: u64,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12859,6 +16128,7 @@ mod io {
     pub struct wire_cst_prepare_send_response {
         destination: wire_cst_send_destination,
         fees_sat: u64,
+        fiat_rate: *mut wire_cst_payment_fiat_rate,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12905,6 +16175,7 @@ mod io {
         swap_address: *mut wire_cst_list_prim_u_8_strict,
         timestamp: u32,
         amount_sat: u64,
+        time_to_refundable: *mut u64,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -12929,6 +16200,15 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_scheduler_intervals {
+        sync_secs: u64,
+        refunds_secs: u64,
+        liquid_rescan_secs: u64,
+        bitcoin_rescan_secs: u64,
+        new_blocks_secs: u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_sdk_error {
         tag: i32,
         kind: SdkErrorKind,
@@ -12938,6 +16218,11 @@ mod io {
     pub union SdkErrorKind {
         Generic: wire_cst_SdkError_Generic,
         ServiceConnectivity: wire_cst_SdkError_ServiceConnectivity,
+        Swapper: wire_cst_SdkError_Swapper,
+        ChainService: wire_cst_SdkError_ChainService,
+        Persistence: wire_cst_SdkError_Persistence,
+        Signer: wire_cst_SdkError_Signer,
+        Validation: wire_cst_SdkError_Validation,
         nil__: (),
     }
     #[repr(C)]
@@ -12952,6 +16237,31 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkError_Swapper {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkError_ChainService {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkError_Persistence {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkError_Signer {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkError_Validation {
+        err: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_sdk_event {
         tag: i32,
         kind: SdkEventKind,
@@ -12961,10 +16271,18 @@ mod io {
     pub union SdkEventKind {
         PaymentFailed: wire_cst_SdkEvent_PaymentFailed,
         PaymentPending: wire_cst_SdkEvent_PaymentPending,
+        PaymentQueued: wire_cst_SdkEvent_PaymentQueued,
         PaymentRefunded: wire_cst_SdkEvent_PaymentRefunded,
         PaymentRefundPending: wire_cst_SdkEvent_PaymentRefundPending,
         PaymentSucceeded: wire_cst_SdkEvent_PaymentSucceeded,
         PaymentWaitingConfirmation: wire_cst_SdkEvent_PaymentWaitingConfirmation,
+        SwapNearingTimeout: wire_cst_SdkEvent_SwapNearingTimeout,
+        SwapRefundable: wire_cst_SdkEvent_SwapRefundable,
+        SwapStateChanged: wire_cst_SdkEvent_SwapStateChanged,
+        BalanceChanged: wire_cst_SdkEvent_BalanceChanged,
+        SyncProgress: wire_cst_SdkEvent_SyncProgress,
+        ConnectivityChanged: wire_cst_SdkEvent_ConnectivityChanged,
+        NewBlock: wire_cst_SdkEvent_NewBlock,
         nil__: (),
     }
     #[repr(C)]
@@ -12979,6 +16297,11 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_PaymentQueued {
+        details: *mut wire_cst_payment,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_SdkEvent_PaymentRefunded {
         details: *mut wire_cst_payment,
     }
@@ -12999,6 +16322,47 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_SwapNearingTimeout {
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+        blocks_left: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_SwapRefundable {
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_SwapStateChanged {
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+        from_state: i32,
+        to_state: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_BalanceChanged {
+        old_balance_sat: u64,
+        new_balance_sat: u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_SyncProgress {
+        step: u32,
+        total: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_ConnectivityChanged {
+        is_connected: bool,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SdkEvent_NewBlock {
+        chain: i32,
+        height: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_send_destination {
         tag: i32,
         kind: SendDestinationKind,
@@ -13107,6 +16471,16 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_swap_state_transition {
+        swap_id: *mut wire_cst_list_prim_u_8_strict,
+        from_state: i32,
+        to_state: i32,
+        swapper_status: *mut wire_cst_list_prim_u_8_strict,
+        tx_ids: *mut wire_cst_list_String,
+        created_at: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_symbol {
         grapheme: *mut wire_cst_list_prim_u_8_strict,
         template: *mut wire_cst_list_prim_u_8_strict,
@@ -13115,11 +16489,45 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_sync_metrics {
+        started_at: u32,
+        duration_ms: u64,
+        scan_duration_ms: u64,
+        liquid_tip: *mut u32,
+        total_tx_count: u32,
+        processed_tx_count: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_sync_once_request {
+        timeout_secs: u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_sync_once_response {
+        pending_tasks: *mut wire_cst_list_sync_task,
+        pending_swap_count: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_url_success_action_data {
         description: *mut wire_cst_list_prim_u_8_strict,
         url: *mut wire_cst_list_prim_u_8_strict,
         matches_callback_domain: bool,
     }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_webhook_delivery {
+        id: u64,
+        url: *mut wire_cst_list_prim_u_8_strict,
+        event_seq: u64,
+        payload: *mut wire_cst_list_prim_u_8_strict,
+        state: i32,
+        attempts: u32,
+        next_attempt_at: u32,
+        last_error: *mut wire_cst_list_prim_u_8_strict,
+        created_at: u32,
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
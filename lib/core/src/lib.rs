@@ -48,6 +48,7 @@
 //!     .prepare_receive_payment(&PrepareReceiveRequest {
 //!         payment_method: PaymentMethod::Lightning,
 //!         payer_amount_sat: Some(5_000),
+//!         payer_amount_fiat: None,
 //!     })
 //!     .await?;
 //!
@@ -167,6 +168,7 @@ pub(crate) mod bindings;
 pub(crate) mod buy;
 pub(crate) mod chain;
 pub(crate) mod chain_swap;
+pub(crate) mod diagnostics;
 pub mod error;
 pub(crate) mod event;
 #[cfg(feature = "frb")]
@@ -176,6 +178,7 @@ pub mod logger;
 pub mod model;
 pub mod persist;
 pub(crate) mod receive_swap;
+pub(crate) mod rescue;
 #[allow(dead_code)]
 mod restore;
 pub mod sdk;
@@ -185,6 +188,7 @@ pub(crate) mod swapper;
 pub(crate) mod test_utils;
 pub(crate) mod utils;
 pub(crate) mod wallet;
+pub(crate) mod webhook;
 
 pub use sdk_common::prelude::*;
 
@@ -1,11 +1,201 @@
 use std::fs::OpenOptions;
+use std::future::Future;
 use std::io::Write;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Result};
-use chrono::Local;
+use chrono::{Local, TimeZone};
+use log::kv::{Key, Value, VisitSource};
 use log::{LevelFilter, Metadata, Record};
 
-use crate::model::LogEntry;
+use crate::model::{LogEntry, LogField};
+
+/// Keywords after which the following value is considered a secret and redacted
+const SENSITIVE_KEYWORDS: [&str; 8] = [
+    "preimage",
+    "private_key",
+    "privatekey",
+    "secret",
+    "mnemonic",
+    "seed",
+    "xprv",
+    "tprv",
+];
+/// The [SENSITIVE_KEYWORDS] which can be followed by a space separated phrase
+const PHRASE_KEYWORDS: [&str; 2] = ["mnemonic", "seed"];
+/// The minimum length of a value following a [SENSITIVE_KEYWORDS] match for it to be redacted
+const MIN_SECRET_LEN: usize = 32;
+/// The minimum number of words of a phrase following a [PHRASE_KEYWORDS] match for it to be
+/// redacted, which is the length of the shortest BIP39 mnemonic
+const MIN_PHRASE_WORDS: usize = 12;
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// The path of the SDK log file, set by [init_logging]
+static LOG_FILE_PATH: OnceLock<String> = OnceLock::new();
+
+tokio::task_local! {
+    static LOG_SPAN: LogSpan;
+}
+
+/// The swap context attached to the records logged while a future runs in [LogSpan::scope]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LogSpan {
+    swap_id: Option<String>,
+    payment_id: Option<String>,
+}
+
+impl LogSpan {
+    pub(crate) fn swap(swap_id: &str, payment_id: Option<String>) -> Self {
+        Self {
+            swap_id: Some(swap_id.to_string()),
+            payment_id,
+        }
+    }
+
+    /// Runs the future with this span as the context of all records it logs
+    pub(crate) async fn scope<F: Future>(self, f: F) -> F::Output {
+        LOG_SPAN.scope(self, f).await
+    }
+
+    fn current() -> Self {
+        LOG_SPAN.try_with(Clone::clone).unwrap_or_default()
+    }
+}
+
+struct FieldCollector(Vec<LogField>);
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), log::kv::Error> {
+        let key = key.as_str().to_string();
+        let value = match is_sensitive(&key) {
+            true => REDACTED.to_string(),
+            false => redact(&value.to_string()),
+        };
+        self.0.push(LogField { key, value });
+        Ok(())
+    }
+}
+
+impl From<&Record<'_>> for LogEntry {
+    fn from(record: &Record<'_>) -> Self {
+        let span = LogSpan::current();
+        let mut fields = FieldCollector(vec![]);
+        let _ = record.key_values().visit(&mut fields);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        LogEntry {
+            line: redact(&record.args().to_string()),
+            level: record.level().as_str().to_string(),
+            target: record.target().to_string(),
+            module: record.module_path().map(Into::into),
+            timestamp,
+            swap_id: span.swap_id,
+            payment_id: span.payment_id,
+            fields: fields.0,
+        }
+    }
+}
+
+fn is_sensitive(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEYWORDS.iter().any(|k| key.contains(k))
+}
+
+/// Replaces the values following any of the [SENSITIVE_KEYWORDS] with [REDACTED]
+pub(crate) fn redact(line: &str) -> String {
+    let is_separator = |c: char| " :=\"'([".contains(c);
+    // ASCII lowercasing keeps the byte offsets of both strings aligned
+    let lowercase = line.to_ascii_lowercase();
+    let mut redacted = String::with_capacity(line.len());
+    let mut pos = 0;
+    while let Some((start, keyword)) = SENSITIVE_KEYWORDS
+        .iter()
+        .filter_map(|k| lowercase[pos..].find(k).map(|i| (pos + i, k)))
+        .min_by_key(|(i, _)| *i)
+    {
+        // Skip separators and wrappers like `: `, `=`, `"` or `Some(` before the value
+        let mut value = line[start + keyword.len()..].trim_start_matches(is_separator);
+        if let Some(inner) = value.strip_prefix("Some(") {
+            value = inner.trim_start_matches(is_separator);
+        }
+        let value_start = line.len() - value.len();
+        let phrase_len = match PHRASE_KEYWORDS.contains(keyword) {
+            true => phrase_len(value),
+            false => None,
+        };
+        let (value_len, is_secret) = match phrase_len {
+            Some(phrase_len) => (phrase_len, true),
+            None => {
+                let value_len = value
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(value.len());
+                (value_len, value_len >= MIN_SECRET_LEN)
+            }
+        };
+
+        redacted.push_str(&line[pos..value_start]);
+        match is_secret {
+            true => redacted.push_str(REDACTED),
+            false => redacted.push_str(&value[..value_len]),
+        }
+        pos = value_start + value_len;
+    }
+    redacted.push_str(&line[pos..]);
+    redacted
+}
+
+/// The length of the mnemonic-like phrase the value starts with, if any. The phrase runs until
+/// the end of the line or the next delimiter, and has to consist of at least
+/// [MIN_PHRASE_WORDS] lowercase words.
+fn phrase_len(value: &str) -> Option<usize> {
+    let end = value
+        .find(|c: char| "\"',;)}]\n".contains(c))
+        .unwrap_or(value.len());
+    let phrase = value[..end].trim_end();
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let is_phrase = words.len() >= MIN_PHRASE_WORDS
+        && words
+            .iter()
+            .all(|word| word.chars().all(|c| c.is_ascii_lowercase()));
+    is_phrase.then_some(phrase.len())
+}
+
+/// Formats a [LogEntry] as a single line of the SDK log file
+fn format_line(entry: &LogEntry, line_no: Option<u32>) -> String {
+    let time = Local
+        .timestamp_millis_opt(entry.timestamp as i64)
+        .single()
+        .unwrap_or_else(Local::now);
+    let mut context = vec![];
+    if let Some(swap_id) = &entry.swap_id {
+        context.push(format!("swap_id={swap_id}"));
+    }
+    if let Some(payment_id) = &entry.payment_id {
+        context.push(format!("payment_id={payment_id}"));
+    }
+    context.extend(
+        entry
+            .fields
+            .iter()
+            .map(|f| format!("{}={}", f.key, f.value)),
+    );
+    let context = match context.is_empty() {
+        true => String::new(),
+        false => format!(" {{{}}}", context.join(" ")),
+    };
+    format!(
+        "[{} {} {}:{}]{context} {}",
+        time.format("%Y-%m-%d %H:%M:%S%.3f"),
+        entry.level,
+        entry.module.as_deref().unwrap_or("unknown"),
+        line_no.unwrap_or(0),
+        entry.line
+    )
+}
 
 pub(crate) struct GlobalSdkLogger {
     /// SDK internal logger, which logs to file
@@ -33,12 +223,18 @@ impl log::Log for GlobalSdkLogger {
     fn flush(&self) {}
 }
 
+/// The path of the SDK log file, if logging to file was initialized
+pub(crate) fn log_file_path() -> Option<String> {
+    LOG_FILE_PATH.get().cloned()
+}
+
 pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
+    let log_file_path = format!("{log_dir}/sdk.log");
     let target_log_file = Box::new(
         OpenOptions::new()
             .create(true)
             .append(true)
-            .open(format!("{log_dir}/sdk.log"))
+            .open(&log_file_path)
             .map_err(|e| anyhow!("Can't create log file: {e}"))?,
     );
     let logger = env_logger::Builder::new()
@@ -59,12 +255,8 @@ pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>)
         .format(|buf, record| {
             writeln!(
                 buf,
-                "[{} {} {}:{}] {}",
-                Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
-                record.level(),
-                record.module_path().unwrap_or("unknown"),
-                record.line().unwrap_or(0),
-                record.args()
+                "{}",
+                format_line(&LogEntry::from(record), record.line())
             )
         })
         .build();
@@ -77,6 +269,7 @@ pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>)
     log::set_boxed_logger(Box::new(global_logger))
         .map_err(|e| anyhow!("Failed to set global logger: {e}"))?;
     log::set_max_level(LevelFilter::Trace);
+    let _ = LOG_FILE_PATH.set(log_file_path);
 
     Ok(())
 }
@@ -84,3 +277,90 @@ pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>)
 pub trait Logger: Send + Sync {
     fn log(&self, l: LogEntry);
 }
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let preimage = "a".repeat(64);
+        assert_eq!(
+            redact(&format!("Found claim tx preimage: {preimage}")),
+            "Found claim tx preimage: [REDACTED]"
+        );
+        assert_eq!(
+            redact(&format!(
+                "SendSwap {{ preimage: Some(\"{preimage}\"), id: \"abc\" }}"
+            )),
+            "SendSwap { preimage: Some(\"[REDACTED]\"), id: \"abc\" }"
+        );
+        // Short values and unrelated words are kept
+        assert_eq!(
+            redact("Preimage is valid for Send Swap abc"),
+            "Preimage is valid for Send Swap abc"
+        );
+
+        // Space separated mnemonics are redacted up to the end of the line or next delimiter
+        let mnemonic =
+            "abandon ability able about above absent absorb abstract absurd abuse access accident";
+        assert_eq!(
+            redact(&format!("Using mnemonic: {mnemonic}")),
+            "Using mnemonic: [REDACTED]"
+        );
+        assert_eq!(
+            redact(&format!(
+                "ConnectRequest {{ mnemonic: \"{mnemonic}\", config: Config }}"
+            )),
+            "ConnectRequest { mnemonic: \"[REDACTED]\", config: Config }"
+        );
+        assert_eq!(
+            redact(&format!("Restored seed {mnemonic}\nNext line")),
+            "Restored seed [REDACTED]\nNext line"
+        );
+        assert_eq!(
+            redact("Seed phrase was not found in the working dir"),
+            "Seed phrase was not found in the working dir"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_log_entry_span() {
+        let entry = LogSpan::swap("swap-id", Some("tx-id".to_string()))
+            .scope(async {
+                let preimage = "b".repeat(64);
+                let key_values = [
+                    ("status", "transaction.claimed"),
+                    ("preimage", preimage.as_str()),
+                ];
+                // The formatted args only live until the end of the statement
+                LogEntry::from(
+                    &Record::builder()
+                        .args(format_args!("Handling swap"))
+                        .level(Level::Info)
+                        .target("breez_sdk_liquid::send_swap")
+                        .key_values(&key_values)
+                        .build(),
+                )
+            })
+            .await;
+
+        assert_eq!(entry.swap_id.as_deref(), Some("swap-id"));
+        assert_eq!(entry.payment_id.as_deref(), Some("tx-id"));
+        assert_eq!(
+            entry.fields,
+            vec![
+                LogField {
+                    key: "status".to_string(),
+                    value: "transaction.claimed".to_string()
+                },
+                LogField {
+                    key: "preimage".to_string(),
+                    value: REDACTED.to_string()
+                }
+            ]
+        );
+    }
+}
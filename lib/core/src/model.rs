@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 use anyhow::{anyhow, Result};
 
@@ -11,13 +11,19 @@ use boltz_client::{
     ToHex,
 };
 use boltz_client::{BtcSwapScript, Keypair, LBtcSwapScript};
-use lwk_wollet::{bitcoin::bip32, ElementsNetwork};
+use lwk_wollet::{
+    bitcoin::bip32,
+    elements::{AssetId, BlockHash},
+    ElementsNetwork,
+};
 use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
 use rusqlite::ToSql;
 use sdk_common::prelude::*;
 use serde::{Deserialize, Serialize};
-use strum_macros::{Display, EnumString};
+use strum_macros::{Display, EnumDiscriminants, EnumString};
 
+use crate::chain::CHAIN_QUORUM;
+use crate::ensure_sdk;
 use crate::error::{PaymentError, SdkError, SdkResult};
 use crate::receive_swap::{
     DEFAULT_ZERO_CONF_MAX_SAT, DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
@@ -28,12 +34,115 @@ use crate::utils;
 // Both use f64 for the maximum precision when converting between units
 pub const STANDARD_FEE_RATE_SAT_PER_VBYTE: f64 = 0.1;
 pub const LOWBALL_FEE_RATE_SAT_PER_VBYTE: f64 = 0.01;
+/// The average time between two Bitcoin blocks, in seconds
+pub const BITCOIN_BLOCK_INTERVAL_SECS: u64 = 600;
+/// Currencies whose rates are persisted by default. See [Config::fiat_rate_history_currencies]
+pub const DEFAULT_FIAT_RATE_HISTORY_CURRENCIES: [&str; 2] = ["USD", "EUR"];
+/// The policy asset of an elementsd regtest chain started with the default chain parameters
+const DEFAULT_REGTEST_LBTC_ASSET_ID: &str =
+    "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+
+/// The intervals, in seconds, at which the SDK background tasks run while it's connected.
+/// See [Config::scheduler_intervals]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SchedulerIntervals {
+    /// How often the wallet and payments are synced, see [crate::sdk::LiquidSdk::sync]
+    pub sync_secs: u64,
+    /// How often expired swaps are checked and refunded
+    pub refunds_secs: u64,
+    /// How often the Liquid lockups of incoming chain swaps are rescanned
+    pub liquid_rescan_secs: u64,
+    /// How often the Bitcoin lockups and claims of chain swaps are rescanned
+    pub bitcoin_rescan_secs: u64,
+    /// How often the chain tips are polled for new blocks, see [SdkEvent::NewBlock]
+    pub new_blocks_secs: u64,
+}
+
+impl Default for SchedulerIntervals {
+    fn default() -> Self {
+        Self {
+            sync_secs: 30,
+            refunds_secs: 60,
+            liquid_rescan_secs: 60,
+            bitcoin_rescan_secs: 10 * 60,
+            new_blocks_secs: 10,
+        }
+    }
+}
+
+/// The data source used to query a chain and broadcast transactions to it
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum ChainBackend {
+    /// An Electrum server, in the format `host:port`. TLS is used unless the URL is
+    /// prefixed with `tcp://`, as is common for local regtest servers.
+    Electrum { url: String },
+    /// An Esplora REST API, in the format `https://blockstream.info/liquid/api`
+    ///
+    /// Boltz swap claim and refund transactions are built with Electrum data. If no backend of
+    /// the chain has an Electrum server, a public Electrum server of the network is used for them.
+    Esplora { url: String },
+    /// An Electrum server for the tip and batched queries, with single script histories,
+    /// transaction lookups and broadcasts going through an Esplora REST API.
+    /// This lets the SDK see lowball transactions which Electrum servers may not relay.
+    Hybrid {
+        electrum_url: String,
+        esplora_url: String,
+    },
+    /// The JSON-RPC interface of a bitcoind or elementsd node, e.g. `http://127.0.0.1:18884`.
+    ///
+    /// The node needs `txindex=1`. Script histories can't be fetched over RPC, as scanning the
+    /// UTXO set doesn't show spent outputs, and neither can the Liquid wallet and Boltz swap
+    /// transactions be synced. These go through the other backends of the chain, of which at
+    /// least one has to be an Electrum, Esplora or Hybrid backend. RPC-only configurations
+    /// aren't supported, so a regtest setup needs a local Electrum or Esplora server, such as
+    /// electrs, next to the daemons.
+    Rpc {
+        url: String,
+        username: Option<String>,
+        password: Option<String>,
+    },
+}
+
+impl ChainBackend {
+    /// The Electrum URL of this backend, if any
+    pub(crate) fn electrum_url(&self) -> Option<&str> {
+        match self {
+            ChainBackend::Electrum { url } => Some(url),
+            ChainBackend::Hybrid { electrum_url, .. } => Some(electrum_url),
+            ChainBackend::Esplora { .. } | ChainBackend::Rpc { .. } => None,
+        }
+    }
+}
 
 /// Configuration for the Liquid SDK
 #[derive(Clone, Debug, Serialize)]
 pub struct Config {
-    pub liquid_electrum_url: String,
-    pub bitcoin_electrum_url: String,
+    pub liquid_chain_backend: ChainBackend,
+    pub bitcoin_chain_backend: ChainBackend,
+    /// Backends the SDK fails over to, in order, when the Liquid chain backend is unreachable
+    pub liquid_fallback_chain_backends: Option<Vec<ChainBackend>>,
+    /// Backends the SDK fails over to, in order, when the Bitcoin chain backend is unreachable
+    pub bitcoin_fallback_chain_backends: Option<Vec<ChainBackend>>,
+    /// Swaps of at least this amount have the server lockup transaction verified by two
+    /// different chain backends before being claimed. Requires fallback backends to be set, so
+    /// that each chain has at least two Electrum, Esplora or Hybrid backends.
+    pub chain_quorum_min_amount_sat: Option<u64>,
+    /// The Boltz API URL, in the format `https://api.boltz.exchange/v2`. Overrides the default
+    /// Boltz instance of the network. Required on [LiquidNetwork::Regtest], where there is
+    /// no public Boltz instance.
+    pub swapper_url: Option<String>,
+    /// The Boltz websocket URL, in the format `wss://api.boltz.exchange/v2/ws`.
+    /// Defaults to the `/ws` endpoint of the swapper API URL.
+    pub swapper_ws_url: Option<String>,
+    /// The referral id sent to Boltz when creating swaps. If not set, the Breez referral id is
+    /// used when swapping through the default mainnet Boltz instance.
+    pub swapper_referral_id: Option<String>,
+    /// The hex encoded L-BTC asset id. Only used on [LiquidNetwork::Regtest], where it defaults
+    /// to the policy asset of a default elementsd regtest chain.
+    pub lbtc_asset_id: Option<String>,
+    /// The hex encoded genesis block hash of the Liquid chain. Only used on
+    /// [LiquidNetwork::Regtest], where it's fetched from the Liquid chain backend if not set.
+    pub liquid_genesis_hash: Option<String>,
     /// The mempool.space API URL, has to be in the format: `https://mempool.space/api`
     pub mempoolspace_url: String,
     /// Directory in which the DB and log files are stored.
@@ -52,13 +161,27 @@ pub struct Config {
     pub zero_conf_max_amount_sat: Option<u64>,
     /// The Breez API key used for making requests to their mempool service
     pub breez_api_key: Option<String>,
+    /// Currencies whose rates are persisted hourly, so that the fiat value of past payments is
+    /// available offline. Defaults to [DEFAULT_FIAT_RATE_HISTORY_CURRENCIES]
+    pub fiat_rate_history_currencies: Option<Vec<String>>,
+    /// Endpoints to which every persisted [SdkEvent] is POSTed as a signed JSON payload,
+    /// starting with the events emitted after they are first configured.
+    /// See [crate::sdk::LiquidSdk::get_webhook_signing_secret]
+    pub event_webhook_urls: Option<Vec<String>>,
+    /// The intervals of the background tasks. Defaults to [SchedulerIntervals::default]
+    pub scheduler_intervals: Option<SchedulerIntervals>,
 }
 
 impl Config {
     pub fn mainnet(breez_api_key: String) -> Self {
         Config {
-            liquid_electrum_url: "elements-mainnet.breez.technology:50002".to_string(),
-            bitcoin_electrum_url: "bitcoin-mainnet.blockstream.info:50002".to_string(),
+            liquid_chain_backend: ChainBackend::Hybrid {
+                electrum_url: "elements-mainnet.breez.technology:50002".to_string(),
+                esplora_url: "https://lq1.breez.technology/liquid/api".to_string(),
+            },
+            bitcoin_chain_backend: ChainBackend::Electrum {
+                url: "bitcoin-mainnet.blockstream.info:50002".to_string(),
+            },
             mempoolspace_url: "https://mempool.space/api".to_string(),
             working_dir: ".".to_string(),
             cache_dir: None,
@@ -67,13 +190,28 @@ impl Config {
             zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
             zero_conf_max_amount_sat: None,
             breez_api_key: Some(breez_api_key),
+            fiat_rate_history_currencies: None,
+            event_webhook_urls: None,
+            liquid_fallback_chain_backends: None,
+            bitcoin_fallback_chain_backends: None,
+            chain_quorum_min_amount_sat: None,
+            swapper_url: None,
+            swapper_ws_url: None,
+            swapper_referral_id: None,
+            lbtc_asset_id: None,
+            liquid_genesis_hash: None,
+            scheduler_intervals: None,
         }
     }
 
     pub fn testnet(breez_api_key: Option<String>) -> Self {
         Config {
-            liquid_electrum_url: "elements-testnet.blockstream.info:50002".to_string(),
-            bitcoin_electrum_url: "bitcoin-testnet.blockstream.info:50002".to_string(),
+            liquid_chain_backend: ChainBackend::Electrum {
+                url: "elements-testnet.blockstream.info:50002".to_string(),
+            },
+            bitcoin_chain_backend: ChainBackend::Electrum {
+                url: "bitcoin-testnet.blockstream.info:50002".to_string(),
+            },
             mempoolspace_url: "https://mempool.space/testnet/api".to_string(),
             working_dir: ".".to_string(),
             cache_dir: None,
@@ -82,9 +220,163 @@ impl Config {
             zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
             zero_conf_max_amount_sat: None,
             breez_api_key,
+            fiat_rate_history_currencies: None,
+            event_webhook_urls: None,
+            liquid_fallback_chain_backends: None,
+            bitcoin_fallback_chain_backends: None,
+            chain_quorum_min_amount_sat: None,
+            swapper_url: None,
+            swapper_ws_url: None,
+            swapper_referral_id: None,
+            lbtc_asset_id: None,
+            liquid_genesis_hash: None,
+            scheduler_intervals: None,
         }
     }
 
+    /// The Liquid chain backend followed by its fallbacks
+    pub(crate) fn liquid_chain_backends(&self) -> impl Iterator<Item = &ChainBackend> {
+        std::iter::once(&self.liquid_chain_backend)
+            .chain(self.liquid_fallback_chain_backends.iter().flatten())
+    }
+
+    /// The Bitcoin chain backend followed by its fallbacks
+    pub(crate) fn bitcoin_chain_backends(&self) -> impl Iterator<Item = &ChainBackend> {
+        std::iter::once(&self.bitcoin_chain_backend)
+            .chain(self.bitcoin_fallback_chain_backends.iter().flatten())
+    }
+
+    /// Whether a swap of the given amount needs its lockup verified by a quorum of chain backends
+    pub(crate) fn requires_chain_quorum(&self, amount_sat: u64) -> bool {
+        self.chain_quorum_min_amount_sat
+            .is_some_and(|min_amount_sat| amount_sat >= min_amount_sat)
+    }
+
+    /// A local regtest stack, with the ports used by the Boltz regtest environment
+    pub fn regtest() -> Self {
+        Config {
+            liquid_chain_backend: ChainBackend::Electrum {
+                url: "tcp://localhost:19002".to_string(),
+            },
+            bitcoin_chain_backend: ChainBackend::Electrum {
+                url: "tcp://localhost:19001".to_string(),
+            },
+            mempoolspace_url: "http://localhost:8999/api/v1".to_string(),
+            working_dir: ".".to_string(),
+            cache_dir: None,
+            network: LiquidNetwork::Regtest,
+            payment_timeout_sec: 15,
+            zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
+            zero_conf_max_amount_sat: None,
+            breez_api_key: None,
+            fiat_rate_history_currencies: None,
+            event_webhook_urls: None,
+            liquid_fallback_chain_backends: None,
+            bitcoin_fallback_chain_backends: None,
+            chain_quorum_min_amount_sat: None,
+            swapper_url: Some("http://localhost:9001/v2".to_string()),
+            swapper_ws_url: None,
+            swapper_referral_id: None,
+            lbtc_asset_id: None,
+            liquid_genesis_hash: None,
+            scheduler_intervals: None,
+        }
+    }
+
+    /// Checks that the network specific options are set and well formed
+    pub(crate) fn validate(&self) -> Result<(), SdkError> {
+        if self.network == LiquidNetwork::Regtest {
+            ensure_sdk!(
+                self.swapper_url.is_some(),
+                SdkError::validation("A swapper URL must be provided on regtest")
+            );
+        }
+        if let Some(swapper_url) = &self.swapper_url {
+            Self::validate_url(swapper_url, &["http", "https"])?;
+        }
+        if let Some(swapper_ws_url) = &self.swapper_ws_url {
+            Self::validate_url(swapper_ws_url, &["ws", "wss"])?;
+        }
+        if let Some(swapper_referral_id) = &self.swapper_referral_id {
+            ensure_sdk!(
+                !swapper_referral_id.trim().is_empty(),
+                SdkError::validation("The swapper referral id cannot be empty")
+            );
+        }
+        if let Some(lbtc_asset_id) = &self.lbtc_asset_id {
+            AssetId::from_str(lbtc_asset_id).map_err(|e| {
+                SdkError::validation(format!("Invalid L-BTC asset id {lbtc_asset_id}: {e:?}"))
+            })?;
+        }
+        if let Some(liquid_genesis_hash) = &self.liquid_genesis_hash {
+            BlockHash::from_str(liquid_genesis_hash).map_err(|e| {
+                SdkError::validation(format!("Invalid genesis hash {liquid_genesis_hash}: {e:?}"))
+            })?;
+        }
+        if let Some(intervals) = &self.scheduler_intervals {
+            ensure_sdk!(
+                intervals.sync_secs > 0
+                    && intervals.refunds_secs > 0
+                    && intervals.liquid_rescan_secs > 0
+                    && intervals.bitcoin_rescan_secs > 0
+                    && intervals.new_blocks_secs > 0,
+                SdkError::validation("The scheduler intervals must be greater than zero")
+            );
+        }
+        let is_history_backend =
+            |backend: &ChainBackend| !matches!(backend, ChainBackend::Rpc { .. });
+        ensure_sdk!(
+            self.liquid_chain_backends().any(is_history_backend)
+                && self.bitcoin_chain_backends().any(is_history_backend),
+            SdkError::validation("An RPC chain backend needs an Electrum, Esplora or Hybrid backend of the same chain to fetch script histories")
+        );
+        if self.chain_quorum_min_amount_sat.is_some() {
+            // Quorum reads fetch script histories, which RPC backends can't provide
+            let liquid_history_backends = self
+                .liquid_chain_backends()
+                .filter(|backend| is_history_backend(backend))
+                .count();
+            let bitcoin_history_backends = self
+                .bitcoin_chain_backends()
+                .filter(|backend| is_history_backend(backend))
+                .count();
+            ensure_sdk!(
+                liquid_history_backends >= CHAIN_QUORUM && bitcoin_history_backends >= CHAIN_QUORUM,
+                SdkError::validation(format!("A chain quorum needs at least {CHAIN_QUORUM} Liquid and {CHAIN_QUORUM} Bitcoin Electrum, Esplora or Hybrid chain backends"))
+            );
+        }
+        Ok(())
+    }
+
+    fn validate_url(url: &str, schemes: &[&str]) -> Result<(), SdkError> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| SdkError::validation(format!("Invalid URL {url}: {e:?}")))?;
+        ensure_sdk!(
+            schemes.contains(&parsed.scheme()),
+            SdkError::validation(format!(
+                "Invalid URL {url}: scheme must be one of {schemes:?}"
+            ))
+        );
+        Ok(())
+    }
+
+    /// The Elements network, with the configured L-BTC asset id on regtest
+    pub(crate) fn elements_network(&self) -> ElementsNetwork {
+        match (self.network, &self.lbtc_asset_id) {
+            (LiquidNetwork::Regtest, Some(lbtc_asset_id)) => match AssetId::from_str(lbtc_asset_id)
+            {
+                Ok(policy_asset) => ElementsNetwork::ElementsRegtest { policy_asset },
+                Err(_) => self.network.into(),
+            },
+            _ => self.network.into(),
+        }
+    }
+
+    /// The L-BTC asset id of the configured network
+    pub(crate) fn lbtc_asset_id(&self) -> AssetId {
+        self.elements_network().policy_asset()
+    }
+
     pub(crate) fn get_wallet_dir(
         &self,
         base_dir: &str,
@@ -94,6 +386,7 @@ impl Config {
             .join(match self.network {
                 LiquidNetwork::Mainnet => "mainnet",
                 LiquidNetwork::Testnet => "testnet",
+                LiquidNetwork::Regtest => "regtest",
             })
             .join(fingerprint_hex)
             .to_str()
@@ -108,10 +401,25 @@ impl Config {
             .unwrap_or(DEFAULT_ZERO_CONF_MAX_SAT)
     }
 
+    pub(crate) fn scheduler_intervals(&self) -> SchedulerIntervals {
+        self.scheduler_intervals.clone().unwrap_or_default()
+    }
+
+    pub(crate) fn fiat_rate_history_currencies(&self) -> Vec<String> {
+        self.fiat_rate_history_currencies
+            .clone()
+            .unwrap_or_else(|| {
+                DEFAULT_FIAT_RATE_HISTORY_CURRENCIES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            })
+    }
+
     pub(crate) fn lowball_fee_rate_msat_per_vbyte(&self) -> Option<f32> {
         match self.network {
             LiquidNetwork::Mainnet => Some((LOWBALL_FEE_RATE_SAT_PER_VBYTE * 1000.0) as f32),
-            LiquidNetwork::Testnet => None,
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => None,
         }
     }
 }
@@ -124,12 +432,15 @@ pub enum LiquidNetwork {
     Mainnet,
     /// Testnet Bitcoin and Liquid chains
     Testnet,
+    /// Local regtest Bitcoin and Liquid chains, e.g. for integration tests
+    Regtest,
 }
 impl LiquidNetwork {
     pub fn as_bitcoin_chain(&self) -> Chain {
         match self {
             LiquidNetwork::Mainnet => Chain::Bitcoin,
             LiquidNetwork::Testnet => Chain::BitcoinTestnet,
+            LiquidNetwork::Regtest => Chain::BitcoinRegtest,
         }
     }
 }
@@ -139,6 +450,10 @@ impl From<LiquidNetwork> for ElementsNetwork {
         match value {
             LiquidNetwork::Mainnet => ElementsNetwork::Liquid,
             LiquidNetwork::Testnet => ElementsNetwork::LiquidTestnet,
+            LiquidNetwork::Regtest => ElementsNetwork::ElementsRegtest {
+                policy_asset: AssetId::from_str(DEFAULT_REGTEST_LBTC_ASSET_ID)
+                    .expect("Default regtest asset id is valid"),
+            },
         }
     }
 }
@@ -148,6 +463,7 @@ impl From<LiquidNetwork> for Chain {
         match value {
             LiquidNetwork::Mainnet => Chain::Liquid,
             LiquidNetwork::Testnet => Chain::LiquidTestnet,
+            LiquidNetwork::Regtest => Chain::LiquidRegtest,
         }
     }
 }
@@ -159,6 +475,7 @@ impl TryFrom<&str> for LiquidNetwork {
         match value.to_lowercase().as_str() {
             "mainnet" => Ok(LiquidNetwork::Mainnet),
             "testnet" => Ok(LiquidNetwork::Testnet),
+            "regtest" => Ok(LiquidNetwork::Regtest),
             _ => Err(anyhow!("Invalid network")),
         }
     }
@@ -169,6 +486,7 @@ impl From<LiquidNetwork> for sdk_common::prelude::Network {
         match value {
             LiquidNetwork::Mainnet => Self::Bitcoin,
             LiquidNetwork::Testnet => Self::Testnet,
+            LiquidNetwork::Regtest => Self::Regtest,
         }
     }
 }
@@ -178,6 +496,7 @@ impl From<LiquidNetwork> for sdk_common::bitcoin::Network {
         match value {
             LiquidNetwork::Mainnet => Self::Bitcoin,
             LiquidNetwork::Testnet => Self::Testnet,
+            LiquidNetwork::Regtest => Self::Regtest,
         }
     }
 }
@@ -187,6 +506,7 @@ impl From<LiquidNetwork> for boltz_client::bitcoin::Network {
         match value {
             LiquidNetwork::Mainnet => Self::Bitcoin,
             LiquidNetwork::Testnet => Self::Testnet,
+            LiquidNetwork::Regtest => Self::Regtest,
         }
     }
 }
@@ -198,17 +518,263 @@ pub trait EventListener: Send + Sync {
 
 /// Event emitted by the SDK. Add an [EventListener] by calling [crate::sdk::LiquidSdk::add_event_listener]
 /// to listen for emitted events.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, EnumDiscriminants, Serialize, Deserialize)]
+#[strum_discriminants(name(SdkEventKind), derive(Hash))]
 pub enum SdkEvent {
-    PaymentFailed { details: Payment },
-    PaymentPending { details: Payment },
-    PaymentRefunded { details: Payment },
-    PaymentRefundPending { details: Payment },
-    PaymentSucceeded { details: Payment },
-    PaymentWaitingConfirmation { details: Payment },
+    PaymentFailed {
+        details: Payment,
+    },
+    PaymentPending {
+        details: Payment,
+    },
+    /// A direct Liquid payment was signed while offline and is queued until it can be broadcast.
+    /// A [SdkEvent::PaymentWaitingConfirmation] follows once it's broadcast, or a
+    /// [SdkEvent::PaymentFailed] if it's rejected.
+    PaymentQueued {
+        details: Payment,
+    },
+    PaymentRefunded {
+        details: Payment,
+    },
+    PaymentRefundPending {
+        details: Payment,
+    },
+    PaymentSucceeded {
+        details: Payment,
+    },
+    PaymentWaitingConfirmation {
+        details: Payment,
+    },
+    /// An ongoing swap is getting close to its timeout block height
+    SwapNearingTimeout {
+        swap_id: String,
+        blocks_left: u32,
+    },
+    /// An ongoing swap has reached its timeout block height and its funds can be refunded
+    SwapRefundable {
+        swap_id: String,
+    },
+    /// A swap transitioned to a new [PaymentState]
+    SwapStateChanged {
+        swap_id: String,
+        from_state: PaymentState,
+        to_state: PaymentState,
+    },
+    /// The usable balance changed. See [GetInfoResponse::balance_sat].
+    BalanceChanged {
+        old_balance_sat: u64,
+        new_balance_sat: u64,
+    },
+    /// Emitted while synchronizing the wallet transactions with the local state
+    SyncProgress {
+        step: u32,
+        total: u32,
+    },
+    /// The connection to the swapper status stream was lost or (re)established
+    ConnectivityChanged {
+        is_connected: bool,
+    },
+    /// A new block was found on the given chain
+    NewBlock {
+        chain: Blockchain,
+        height: u32,
+    },
     Synced,
 }
 
+impl SdkEvent {
+    /// The payment this event relates to, if any
+    pub fn payment(&self) -> Option<&Payment> {
+        match self {
+            SdkEvent::PaymentFailed { details }
+            | SdkEvent::PaymentPending { details }
+            | SdkEvent::PaymentQueued { details }
+            | SdkEvent::PaymentRefunded { details }
+            | SdkEvent::PaymentRefundPending { details }
+            | SdkEvent::PaymentSucceeded { details }
+            | SdkEvent::PaymentWaitingConfirmation { details } => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Whether this event relates to the payment with the given tx id or swap id
+    pub fn is_related_to(&self, id: &str) -> bool {
+        match self {
+            SdkEvent::SwapNearingTimeout { swap_id, .. }
+            | SdkEvent::SwapRefundable { swap_id }
+            | SdkEvent::SwapStateChanged { swap_id, .. } => swap_id == id,
+            _ => self.payment().is_some_and(|payment| {
+                payment.tx_id.as_deref() == Some(id)
+                    || payment.details.get_swap_id().as_deref() == Some(id)
+            }),
+        }
+    }
+}
+
+/// A filter used when calling [crate::sdk::LiquidSdk::subscribe_events].
+/// Events have to match all the set fields.
+#[derive(Clone, Debug, Default)]
+pub struct SdkEventFilter {
+    /// Only emit events related to the payment with this tx id or swap id
+    pub payment_id: Option<String>,
+    /// Only emit events of these kinds
+    pub kinds: Option<Vec<SdkEventKind>>,
+}
+
+impl SdkEventFilter {
+    pub(crate) fn matches(&self, event: &SdkEvent) -> bool {
+        let matches_payment_id = match &self.payment_id {
+            Some(payment_id) => event.is_related_to(payment_id),
+            None => true,
+        };
+        let matches_kind = match &self.kinds {
+            Some(kinds) => kinds.contains(&event.into()),
+            None => true,
+        };
+        matches_payment_id && matches_kind
+    }
+}
+
+/// An [SdkEvent] stored in the persisted event log, see [crate::sdk::LiquidSdk::events_since]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EventLogEntry {
+    /// Monotonically increasing sequence number of the event
+    pub seq: u64,
+    /// The time the event was emitted
+    pub created_at: u32,
+    pub event: SdkEvent,
+}
+
+/// The delivery state of a [WebhookDelivery]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum WebhookDeliveryState {
+    /// The delivery is waiting for its next attempt
+    Pending = 0,
+    /// All delivery attempts failed. See [crate::sdk::LiquidSdk::retry_webhook_delivery]
+    Failed = 1,
+}
+impl ToSql for WebhookDeliveryState {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(*self as i8))
+    }
+}
+impl FromSql for WebhookDeliveryState {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(i) => match i as u8 {
+                0 => Ok(WebhookDeliveryState::Pending),
+                1 => Ok(WebhookDeliveryState::Failed),
+                _ => Err(FromSqlError::OutOfRange(i)),
+            },
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// An [EventLogEntry] queued for delivery to one of the [Config::event_webhook_urls].
+/// Successfully delivered entries are removed from the outbox.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct WebhookDelivery {
+    pub id: u64,
+    pub url: String,
+    /// The sequence number of the delivered [EventLogEntry]
+    pub event_seq: u64,
+    /// The JSON body that is POSTed to the url
+    pub payload: String,
+    pub state: WebhookDeliveryState,
+    /// The number of failed delivery attempts
+    pub attempts: u32,
+    /// The time after which the next delivery attempt is made
+    pub next_attempt_at: u32,
+    /// The error of the last failed delivery attempt
+    pub last_error: Option<String>,
+    pub created_at: u32,
+}
+
+/// The wallet state processed by the last sync, so that the next sync only processes the wallet
+/// txs which are new or changed since then
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct SyncCheckpoint {
+    /// The block height of each processed wallet tx, or `None` if it was unconfirmed
+    pub(crate) tx_heights: HashMap<String, Option<u32>>,
+}
+
+/// Metrics of the last [crate::sdk::LiquidSdk::sync]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncMetrics {
+    pub started_at: u32,
+    /// The total duration of the sync
+    pub duration_ms: u64,
+    /// The time spent scanning the wallet with the Liquid chain backend
+    pub scan_duration_ms: u64,
+    /// The Liquid tip height when the sync completed, if it could be fetched
+    pub liquid_tip: Option<u32>,
+    /// The number of wallet txs
+    pub total_tx_count: u32,
+    /// The number of new or changed wallet txs which were processed
+    pub processed_tx_count: u32,
+}
+
+/// The work done by [crate::sdk::LiquidSdk::sync_once], from highest to lowest priority
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SyncTask {
+    /// Checking the ongoing swaps with the swapper and claiming their funds
+    Claims,
+    /// Refunding the expired swaps
+    Refunds,
+    /// Syncing the wallet txs and payments, see [crate::sdk::LiquidSdk::sync]
+    History,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::sync_once]
+#[derive(Clone, Debug, Serialize)]
+pub struct SyncOnceRequest {
+    /// The time budget. No new work is started once it has elapsed.
+    pub timeout_secs: u64,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sync_once]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SyncOnceResponse {
+    /// The tasks which didn't complete within the time budget
+    pub pending_tasks: Vec<SyncTask>,
+    /// The number of ongoing swaps which weren't checked within the time budget
+    pub pending_swap_count: u32,
+}
+
+/// A signed direct Liquid payment tx which couldn't be broadcast because the SDK was offline.
+/// It's broadcast once the Liquid chain backends can be reached again.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OutboxTx {
+    pub(crate) tx_id: String,
+    pub(crate) tx_hex: String,
+    /// The number of failed broadcast attempts
+    pub(crate) attempts: u32,
+    /// The error of the last failed broadcast attempt
+    pub(crate) last_error: Option<String>,
+    pub(crate) created_at: u32,
+}
+
+/// A recorded transition of a swap, see [crate::sdk::LiquidSdk::get_swap_history]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SwapStateTransition {
+    pub swap_id: String,
+    pub from_state: PaymentState,
+    pub to_state: PaymentState,
+    /// The swapper status update which triggered the transition, if any
+    pub swapper_status: Option<String>,
+    /// The ids of the txs involved in the transition
+    pub tx_ids: Vec<String>,
+    pub created_at: u32,
+}
+
+/// The chains the SDK interacts with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Blockchain {
+    Liquid,
+    Bitcoin,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum SignerError {
     #[error("Signer error: {err}")]
@@ -280,7 +846,7 @@ pub(crate) struct ReservedAddress {
 }
 
 /// The send/receive methods supported by the SDK
-#[derive(Clone, Debug, EnumString, Serialize, Eq, PartialEq)]
+#[derive(Clone, Debug, EnumString, Serialize, Deserialize, Eq, PartialEq)]
 pub enum PaymentMethod {
     #[strum(serialize = "lightning")]
     Lightning,
@@ -291,22 +857,27 @@ pub enum PaymentMethod {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareReceiveRequest {
     pub payer_amount_sat: Option<u64>,
+    /// The amount to be paid by the payer in a fiat currency, converted at the current rate.
+    /// Cannot be set together with `payer_amount_sat`.
+    pub payer_amount_fiat: Option<FiatAmount>,
     pub payment_method: PaymentMethod,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareReceiveResponse {
     pub payment_method: PaymentMethod,
     pub payer_amount_sat: Option<u64>,
     pub fees_sat: u64,
+    /// The rate used to convert the fiat amount, if one was requested
+    pub fiat_rate: Option<PaymentFiatRate>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::receive_payment].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ReceivePaymentRequest {
     pub prepare_response: PrepareReceiveResponse,
     /// The description for this payment request.
@@ -338,6 +909,10 @@ pub struct LightningPaymentLimitsResponse {
     pub send: Limits,
     /// Amount limits for a Receive Payment to be valid
     pub receive: Limits,
+    /// Seconds since the limits were fetched from the swapper. They are cached for a short
+    /// while, and served from the cache while the swapper is unreachable.
+    /// `None` if they haven't been cached yet.
+    pub cache_age_secs: Option<u64>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::fetch_onchain_limits].
@@ -347,10 +922,14 @@ pub struct OnchainPaymentLimitsResponse {
     pub send: Limits,
     /// Amount limits for a Receive Onchain Payment to be valid
     pub receive: Limits,
+    /// Seconds since the limits were fetched from the swapper. They are cached for a short
+    /// while, and served from the cache while the swapper is unreachable.
+    /// `None` if they haven't been cached yet.
+    pub cache_age_secs: Option<u64>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PrepareSendRequest {
     /// The destination we intend to pay to.
     /// Supports BIP21 URIs, BOLT11 invoices, BOLT12 offers and Liquid addresses
@@ -362,7 +941,7 @@ pub struct PrepareSendRequest {
 }
 
 /// Specifies the supported destinations which can be payed by the SDK
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SendDestination {
     LiquidAddress {
         address_data: liquid::LiquidAddressData,
@@ -377,14 +956,16 @@ pub enum SendDestination {
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PrepareSendResponse {
     pub destination: SendDestination,
     pub fees_sat: u64,
+    /// The rate used to convert the fiat amount, if one was requested
+    pub fiat_rate: Option<PaymentFiatRate>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::send_payment].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SendPaymentRequest {
     pub prepare_response: PrepareSendResponse,
 }
@@ -395,16 +976,42 @@ pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum PayAmount {
     /// The amount in satoshi that will be received
     Receiver { amount_sat: u64 },
     /// Indicates that all available funds should be sent
     Drain,
+    /// The amount in a fiat currency that will be received, converted to satoshi at the
+    /// current rate when preparing the payment
+    Fiat { currency: String, amount: f64 },
+}
+
+/// An amount denominated in a fiat currency
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FiatAmount {
+    /// The currency id, e.g. `USD`
+    pub currency: String,
+    pub amount: f64,
+}
+
+/// The fiat rate used to convert a fiat amount when preparing a payment
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PaymentFiatRate {
+    /// The currency id, e.g. `USD`
+    pub currency: String,
+    /// The value of one bitcoin in the currency
+    pub rate: f64,
+}
+
+impl PaymentFiatRate {
+    pub(crate) fn to_sat(&self, amount: f64) -> u64 {
+        (amount / self.rate * 100_000_000.0).round() as u64
+    }
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PreparePayOnchainRequest {
     pub amount: PayAmount,
     /// The optional fee rate of the Bitcoin claim transaction in sat/vB. Defaults to the swapper estimated claim fee.
@@ -412,22 +1019,24 @@ pub struct PreparePayOnchainRequest {
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PreparePayOnchainResponse {
     pub receiver_amount_sat: u64,
     pub claim_fees_sat: u64,
     pub total_fees_sat: u64,
+    /// The rate used to convert the fiat amount, if one was requested
+    pub fiat_rate: Option<PaymentFiatRate>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PayOnchainRequest {
     pub address: String,
     pub prepare_response: PreparePayOnchainResponse,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_refund].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareRefundRequest {
     /// The address where the swap funds are locked up
     pub swap_address: String,
@@ -443,10 +1052,16 @@ pub struct PrepareRefundResponse {
     pub tx_vsize: u32,
     pub tx_fee_sat: u64,
     pub refund_tx_id: Option<String>,
+    /// Whether the refund will be co-signed by the swapper (key path) instead of using the
+    /// refund script path. The cooperative path is used until the swap timeout, after which
+    /// the refund falls back to the script path.
+    pub is_cooperative: bool,
+    /// The fee saved in sats by refunding cooperatively, compared to the script path
+    pub fee_saved_sat: u64,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::refund].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     /// The address where the swap funds are locked up
     pub swap_address: String,
@@ -478,19 +1093,19 @@ pub struct GetInfoResponse {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::sign_message].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SignMessageRequest {
     pub message: String,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::sign_message].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct SignMessageResponse {
     pub signature: String,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::check_message].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckMessageRequest {
     /// The message that was signed.
     pub message: String,
@@ -501,7 +1116,7 @@ pub struct CheckMessageRequest {
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::check_message].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct CheckMessageResponse {
     /// Boolean value indicating whether the signature covers the message and
     /// was signed by the given pubkey.
@@ -509,7 +1124,7 @@ pub struct CheckMessageResponse {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::backup].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct BackupRequest {
     /// Path to the backup.
     ///
@@ -519,13 +1134,13 @@ pub struct BackupRequest {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::restore].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RestoreRequest {
     pub backup_path: Option<String>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::list_payments].
-#[derive(Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ListPaymentsRequest {
     pub filters: Option<Vec<PaymentType>>,
     /// Epoch time, in seconds
@@ -538,7 +1153,7 @@ pub struct ListPaymentsRequest {
 }
 
 /// An argument of [ListPaymentsRequest] when calling [crate::sdk::LiquidSdk::list_payments].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ListPaymentDetails {
     /// The Liquid BIP21 URI or address of the payment
     Liquid { destination: String },
@@ -548,7 +1163,7 @@ pub enum ListPaymentDetails {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::get_payment].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum GetPaymentRequest {
     /// The Lightning payment hash of the payment
     Lightning { payment_hash: String },
@@ -718,11 +1333,23 @@ impl ChainSwap {
         Ok(script_pubkey)
     }
 
-    pub(crate) fn to_refundable(&self, refundable_amount_sat: u64) -> RefundableSwap {
+    pub(crate) fn to_refundable(
+        &self,
+        refundable_amount_sat: u64,
+        bitcoin_tip: Option<u32>,
+    ) -> RefundableSwap {
+        let blocks_left = bitcoin_tip.and_then(|tip| self.timeout_block_height.checked_sub(tip));
+        let time_to_refundable = match blocks_left {
+            Some(blocks_left) if blocks_left > 0 => {
+                Some(blocks_left as u64 * BITCOIN_BLOCK_INTERVAL_SECS)
+            }
+            _ => None,
+        };
         RefundableSwap {
             swap_address: self.lockup_address.clone(),
             timestamp: self.created_at,
             amount_sat: refundable_amount_sat,
+            time_to_refundable,
         }
     }
 
@@ -851,10 +1478,10 @@ pub(crate) struct ReceiveSwap {
     pub(crate) claim_tx_id: Option<String>,
     /// Persisted only when the lockup tx is broadcast
     pub(crate) lockup_tx_id: Option<String>,
-    /// The address reserved for a magic routing hint payment
-    pub(crate) mrh_address: String,
-    /// The script pubkey for a magic routing hint payment
-    pub(crate) mrh_script_pubkey: String,
+    /// The address reserved for a magic routing hint payment, if any
+    pub(crate) mrh_address: Option<String>,
+    /// The script pubkey for a magic routing hint payment, if any
+    pub(crate) mrh_script_pubkey: Option<String>,
     /// Persisted only if a transaction is sent to the `mrh_address`
     pub(crate) mrh_tx_id: Option<String>,
     /// Until the lockup tx is seen in the mempool, it contains the swap creation time.
@@ -939,10 +1566,14 @@ pub struct RefundableSwap {
     pub timestamp: u32,
     /// Amount that is refundable, from all UTXOs
     pub amount_sat: u64,
+    /// Estimated time in seconds until the swap timeout, after which the funds can be refunded
+    /// without the swapper's cooperation. `None` if the timeout has already elapsed, or if the
+    /// Bitcoin tip could not be fetched.
+    pub time_to_refundable: Option<u64>,
 }
 
 /// The payment state of an individual payment.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub enum PaymentState {
     Created = 0,
 
@@ -1031,7 +1662,7 @@ impl FromSql for PaymentState {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, EnumString, Display, Hash, PartialEq, Serialize)]
+#[derive(Debug, Copy, Clone, Eq, EnumString, Display, Hash, PartialEq, Serialize, Deserialize)]
 #[strum(serialize_all = "lowercase")]
 pub enum PaymentType {
     Receive = 0,
@@ -1149,7 +1780,7 @@ pub struct PaymentSwapData {
 }
 
 /// The specific details of a payment, depending on its type
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PaymentDetails {
     /// Swapping to or from Lightning
     Lightning {
@@ -1228,7 +1859,7 @@ impl PaymentDetails {
 /// Represents an SDK payment.
 ///
 /// By default, this is an onchain tx. It may represent a swap, if swap metadata is available.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Payment {
     /// The destination associated with the payment, if it was created via our SDK.
     /// Can be either a Liquid/Bitcoin address, a Liquid BIP21 URI or an invoice
@@ -1276,6 +1907,12 @@ pub struct Payment {
     /// The details of a payment, depending on its [destination](Payment::destination) and
     /// [type](Payment::payment_type)
     pub details: PaymentDetails,
+
+    /// The fiat rate used when the payment was prepared with a fiat amount.
+    ///
+    /// Not recorded for direct Liquid receives, whose tx isn't known in advance.
+    /// See [crate::sdk::LiquidSdk::get_historical_fiat_rate] for the rate at any point in time.
+    pub fiat_rate: Option<PaymentFiatRate>,
 }
 impl Payment {
     pub(crate) fn from_pending_swap(swap: PaymentSwapData, payment_type: PaymentType) -> Payment {
@@ -1302,6 +1939,7 @@ impl Payment {
                 refund_tx_id: swap.refund_tx_id,
                 refund_tx_amount_sat: swap.refund_tx_amount_sat,
             },
+            fiat_rate: None,
         }
     }
 
@@ -1358,6 +1996,7 @@ impl Payment {
                 },
             },
             details,
+            fiat_rate: None,
         }
     }
 
@@ -1383,21 +2022,21 @@ pub struct RecommendedFees {
 }
 
 /// An argument of [PrepareBuyBitcoinRequest] when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-#[derive(Debug, Clone, Copy, EnumString, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, EnumString, PartialEq, Serialize, Deserialize)]
 pub enum BuyBitcoinProvider {
     #[strum(serialize = "moonpay")]
     Moonpay,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareBuyBitcoinRequest {
     pub provider: BuyBitcoinProvider,
     pub amount_sat: u64,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PrepareBuyBitcoinResponse {
     pub provider: BuyBitcoinProvider,
     pub amount_sat: u64,
@@ -1405,7 +2044,7 @@ pub struct PrepareBuyBitcoinResponse {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::buy_bitcoin].
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BuyBitcoinRequest {
     pub prepare_response: PrepareBuyBitcoinResponse,
 
@@ -1415,11 +2054,31 @@ pub struct BuyBitcoinRequest {
     pub redirect_url: Option<String>,
 }
 
-/// Internal SDK log entry used in the Uniffi and Dart bindings
+/// Internal SDK log entry used in the Uniffi and Dart bindings.
+///
+/// Preimages, private keys and other secrets are redacted from the line and the fields.
 #[derive(Clone, Debug)]
 pub struct LogEntry {
     pub line: String,
     pub level: String,
+    /// The target of the log record, by default the module path of the caller
+    pub target: String,
+    pub module: Option<String>,
+    /// The time of the log record, in milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// The id of the swap being handled, when logged within a swap span
+    pub swap_id: Option<String>,
+    /// The tx id of the swap payment, when logged within a swap span and already known
+    pub payment_id: Option<String>,
+    /// The key/value pairs attached to the log record
+    pub fields: Vec<LogField>,
+}
+
+/// A key/value pair of a [LogEntry]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogField {
+    pub key: String,
+    pub value: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1467,7 +2126,7 @@ impl From<SwapTree> for InternalSwapTree {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareLnUrlPayRequest {
     /// The [LnUrlPayRequestData] returned by [crate::input_parser::parse]
     pub data: LnUrlPayRequestData,
@@ -1481,7 +2140,7 @@ pub struct PrepareLnUrlPayRequest {
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PrepareLnUrlPayResponse {
     /// The destination of the payment
     pub destination: SendDestination,
@@ -1493,7 +2152,7 @@ pub struct PrepareLnUrlPayResponse {
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::lnurl_pay].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LnUrlPayRequest {
     /// The response from calling [crate::sdk::LiquidSdk::prepare_lnurl_pay]
     pub prepare_response: PrepareLnUrlPayResponse,
@@ -1609,3 +2268,151 @@ macro_rules! get_invoice_description {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use boltz_client::network::Chain;
+    use lwk_wollet::{elements::AssetId, ElementsNetwork};
+
+    use super::{ChainBackend, Config, LiquidNetwork, SchedulerIntervals};
+
+    #[test]
+    fn test_validate() -> Result<()> {
+        Config::mainnet("api_key".to_string()).validate()?;
+        Config::testnet(None).validate()?;
+
+        let config = Config {
+            swapper_url: None,
+            ..Config::regtest()
+        };
+        assert!(config.validate().is_err());
+
+        let invalid_configs = [
+            Config {
+                swapper_url: Some("ws://localhost:9001/v2".to_string()),
+                ..Config::regtest()
+            },
+            Config {
+                swapper_ws_url: Some("http://localhost:9001/v2/ws".to_string()),
+                ..Config::regtest()
+            },
+            Config {
+                swapper_referral_id: Some(" ".to_string()),
+                ..Config::regtest()
+            },
+            Config {
+                lbtc_asset_id: Some("lbtc".to_string()),
+                ..Config::regtest()
+            },
+            Config {
+                liquid_genesis_hash: Some("genesis".to_string()),
+                ..Config::regtest()
+            },
+            Config {
+                scheduler_intervals: Some(SchedulerIntervals {
+                    sync_secs: 0,
+                    ..Default::default()
+                }),
+                ..Config::regtest()
+            },
+        ];
+        for config in invalid_configs {
+            assert!(
+                matches!(config.validate(), Err(SdkError::Validation { .. })),
+                "{config:?} should be invalid"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_regtest_config() -> Result<()> {
+        let config = Config::regtest();
+        config.validate()?;
+        assert_eq!(config.network, LiquidNetwork::Regtest);
+        assert!(matches!(
+            config.network.as_bitcoin_chain(),
+            Chain::BitcoinRegtest
+        ));
+        assert!(matches!(Chain::from(config.network), Chain::LiquidRegtest));
+        assert_eq!(
+            config.get_wallet_dir("base", "fingerprint")?,
+            "base/regtest/fingerprint"
+        );
+
+        let default_asset_id = AssetId::from_str(super::DEFAULT_REGTEST_LBTC_ASSET_ID)?;
+        assert!(matches!(
+            config.elements_network(),
+            ElementsNetwork::ElementsRegtest { policy_asset } if policy_asset == default_asset_id
+        ));
+
+        let asset_id = "b2e15d0d7a0c94e4e2ce0fe6e8691b9e451377f6e46e8045a86f7c4b5d4f0f23";
+        let config = Config {
+            lbtc_asset_id: Some(asset_id.to_string()),
+            ..Config::regtest()
+        };
+        config.validate()?;
+        let custom_asset_id = AssetId::from_str(asset_id)?;
+        assert!(matches!(
+            config.elements_network(),
+            ElementsNetwork::ElementsRegtest { policy_asset } if policy_asset == custom_asset_id
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_chain_quorum() -> Result<()> {
+        let mut config = Config::mainnet("api_key".to_string());
+        config.chain_quorum_min_amount_sat = Some(1_000_000);
+        assert!(config.validate().is_err());
+
+        config.liquid_fallback_chain_backends = Some(vec![ChainBackend::Electrum {
+            url: "elements-mainnet.blockstream.info:50002".to_string(),
+        }]);
+        assert!(config.validate().is_err());
+
+        config.bitcoin_fallback_chain_backends = Some(vec![ChainBackend::Electrum {
+            url: "bitcoin-mainnet.blockstream.info:50002".to_string(),
+        }]);
+        config.validate()?;
+
+        // RPC backends can't fetch script histories, so they don't count towards the quorum
+        config.liquid_chain_backend = ChainBackend::Electrum {
+            url: "elements-mainnet.blockstream.info:50002".to_string(),
+        };
+        config.liquid_fallback_chain_backends = Some(vec![ChainBackend::Rpc {
+            url: "http://localhost:7041".to_string(),
+            username: None,
+            password: None,
+        }]);
+        assert!(config.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_rpc_chain_backend() -> Result<()> {
+        let mut config = Config::regtest();
+        config.liquid_chain_backend = ChainBackend::Rpc {
+            url: "http://localhost:18884".to_string(),
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+        };
+        assert!(config.validate().is_err());
+
+        config.liquid_fallback_chain_backends = Some(vec![ChainBackend::Electrum {
+            url: "tcp://localhost:19002".to_string(),
+        }]);
+        config.validate()?;
+
+        config.bitcoin_chain_backend = ChainBackend::Rpc {
+            url: "http://localhost:18443".to_string(),
+            username: None,
+            password: None,
+        };
+        assert!(config.validate().is_err());
+        Ok(())
+    }
+}
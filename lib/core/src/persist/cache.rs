@@ -20,7 +20,11 @@ impl Persister {
         Ok(res.ok())
     }
 
-    fn update_cached_item_inner(tx: &Transaction, key: &str, value: String) -> Result<()> {
+    pub(crate) fn update_cached_item_inner(
+        tx: &Transaction,
+        key: &str,
+        value: String,
+    ) -> Result<()> {
         tx.execute(
             "INSERT OR REPLACE INTO cached_items (key, value) VALUES (?1,?2)",
             (key, value),
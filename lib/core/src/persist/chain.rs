@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::{ChainSwapDetails, CreateChainResponse};
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use sdk_common::bitcoin::hashes::{hex::ToHex, sha256, Hash};
 use serde::{Deserialize, Serialize};
 
@@ -317,10 +317,19 @@ impl Persister {
         user_lockup_tx_id: Option<&str>,
         claim_tx_id: Option<&str>,
         refund_tx_id: Option<&str>,
+        swapper_status: Option<&str>,
     ) -> Result<(), PaymentError> {
         // Do not overwrite server_lockup_tx_id, user_lockup_tx_id, claim_tx_id, refund_tx_id
-        let con: Connection = self.get_connection()?;
-        con.execute(
+        let mut con: Connection = self.get_connection()?;
+        let tx = con.transaction()?;
+        let from_state: Option<PaymentState> = tx
+            .query_row(
+                "SELECT state FROM chain_swaps WHERE id = ?1",
+                [swap_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        tx.execute(
             "UPDATE chain_swaps
             SET
                 server_lockup_tx_id =
@@ -359,6 +368,22 @@ impl Persister {
                 ":state": to_state,
             },
         )?;
+        if let Some(from_state) = from_state {
+            Self::insert_swap_state_transition_inner(
+                &tx,
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                &[
+                    user_lockup_tx_id,
+                    server_lockup_tx_id,
+                    claim_tx_id,
+                    refund_tx_id,
+                ],
+            )?;
+        }
+        tx.commit()?;
 
         Ok(())
     }
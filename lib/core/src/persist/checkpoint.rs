@@ -0,0 +1,85 @@
+use anyhow::{anyhow, Result};
+use rusqlite::params;
+
+use super::Persister;
+use crate::model::{SyncCheckpoint, SyncMetrics};
+
+const KEY_LAST_SYNC_METRICS: &str = "last_sync_metrics";
+
+impl Persister {
+    pub(crate) fn get_sync_checkpoint(&self) -> Result<SyncCheckpoint> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT tx_id, height FROM sync_checkpoint_txs")?;
+        let tx_heights = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+
+        Ok(SyncCheckpoint { tx_heights })
+    }
+
+    /// Records the wallet txs processed by a sync and drops the ones no longer in the wallet
+    pub(crate) fn update_sync_checkpoint(
+        &self,
+        processed_txs: &[(String, Option<u32>)],
+        removed_tx_ids: &[String],
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for (tx_id, tx_height) in processed_txs {
+            tx.execute(
+                "INSERT OR REPLACE INTO sync_checkpoint_txs (tx_id, height) VALUES (?, ?)",
+                params![tx_id, tx_height],
+            )?;
+        }
+        for tx_id in removed_tx_ids {
+            tx.execute(
+                "DELETE FROM sync_checkpoint_txs WHERE tx_id = ?1",
+                params![tx_id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn set_last_sync_metrics(&self, metrics: &SyncMetrics) -> Result<()> {
+        let metrics_json = serde_json::to_string(metrics)
+            .map_err(|e| anyhow!("Failed to serialize sync metrics: {e:?}"))?;
+        self.update_cached_item(KEY_LAST_SYNC_METRICS, metrics_json)
+    }
+
+    pub(crate) fn get_last_sync_metrics(&self) -> Result<Option<SyncMetrics>> {
+        Ok(self
+            .get_cached_item(KEY_LAST_SYNC_METRICS)?
+            .and_then(|metrics_json| serde_json::from_str(&metrics_json).ok()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::test_utils::persist::new_persister;
+
+    #[test]
+    fn test_sync_checkpoint() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let checkpoint = storage.get_sync_checkpoint()?;
+        assert!(checkpoint.tx_heights.is_empty());
+
+        storage.update_sync_checkpoint(
+            &[("tx1".to_string(), Some(99)), ("tx2".to_string(), None)],
+            &[],
+        )?;
+        let checkpoint = storage.get_sync_checkpoint()?;
+        assert_eq!(checkpoint.tx_heights.get("tx1"), Some(&Some(99)));
+        assert_eq!(checkpoint.tx_heights.get("tx2"), Some(&None));
+
+        // Txs no longer in the wallet are dropped
+        storage.update_sync_checkpoint(&[("tx2".to_string(), Some(101))], &["tx1".to_string()])?;
+        let checkpoint = storage.get_sync_checkpoint()?;
+        assert_eq!(checkpoint.tx_heights.len(), 1);
+        assert_eq!(checkpoint.tx_heights.get("tx2"), Some(&Some(101)));
+
+        Ok(())
+    }
+}
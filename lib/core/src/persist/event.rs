@@ -0,0 +1,170 @@
+use anyhow::{anyhow, Result};
+use log::warn;
+use rusqlite::{params, OptionalExtension};
+
+use super::Persister;
+use crate::{
+    model::{EventLogEntry, SdkEvent},
+    utils,
+};
+
+impl Persister {
+    /// Appends an event to the event log and returns its sequence number
+    pub(crate) fn insert_event(&self, event: &SdkEvent) -> Result<u64> {
+        let event_json = serde_json::to_string(event)
+            .map_err(|e| anyhow!("Failed to serialize event {event:?}: {e:?}"))?;
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO event_log (event_json, created_at) VALUES (?, ?)",
+            (event_json, utils::now()),
+        )?;
+        Ok(con.last_insert_rowid() as u64)
+    }
+
+    /// Lists up to `limit` logged events with a sequence number strictly greater than `seq`,
+    /// ordered by sequence number.
+    ///
+    /// Events which can no longer be decoded, e.g. after an [SdkEvent] variant was changed,
+    /// are skipped so they don't block the replay of the following ones.
+    pub(crate) fn list_events_since(&self, seq: u64, limit: u32) -> Result<Vec<EventLogEntry>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                seq,
+                event_json,
+                created_at
+            FROM event_log
+            WHERE seq > ?1
+            ORDER BY seq ASC
+            LIMIT ?2
+        ",
+        )?;
+
+        let mut events = vec![];
+        let mut last_seq = seq;
+        loop {
+            let rows = stmt
+                .query_map(params![last_seq, limit], |row| {
+                    Ok((row.get::<_, u64>(0)?, row.get::<_, String>(1)?, row.get(2)?))
+                })?
+                .collect::<Result<Vec<(u64, String, u32)>, _>>()?;
+            let is_last_page = rows.len() < limit as usize;
+            for (seq, event_json, created_at) in rows {
+                last_seq = seq;
+                match serde_json::from_str(&event_json) {
+                    Ok(event) => events.push(EventLogEntry {
+                        seq,
+                        event,
+                        created_at,
+                    }),
+                    Err(e) => warn!("Skipping logged event {seq}, which can't be decoded: {e:?}"),
+                }
+                if events.len() == limit as usize {
+                    return Ok(events);
+                }
+            }
+            if is_last_page {
+                return Ok(events);
+            }
+        }
+    }
+
+    /// Returns the sequence number of the last logged event, if any
+    pub(crate) fn get_last_event_seq(&self) -> Result<Option<u64>> {
+        let seq =
+            self.get_connection()?
+                .query_row("SELECT MAX(seq) FROM event_log", [], |row| row.get(0))?;
+        Ok(seq)
+    }
+
+    /// Deletes the logged events created before the given timestamp
+    pub(crate) fn prune_event_log(&self, before_timestamp: u32) -> Result<()> {
+        self.get_connection()?.execute(
+            "DELETE FROM event_log WHERE created_at < ?1",
+            [before_timestamp],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn set_event_cursor(&self, listener_id: &str, seq: u64) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO event_cursors (listener_id, acked_seq) VALUES (?1, ?2)",
+            params![listener_id, seq],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn get_event_cursor(&self, listener_id: &str) -> Result<Option<u64>> {
+        let res = self
+            .get_connection()?
+            .query_row(
+                "SELECT acked_seq FROM event_cursors WHERE listener_id = ?1",
+                [listener_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{model::SdkEvent, test_utils::persist::new_persister};
+
+    #[test]
+    fn test_event_log() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+
+        let first_seq = persister.insert_event(&SdkEvent::Synced)?;
+        let second_seq = persister.insert_event(&SdkEvent::SwapRefundable {
+            swap_id: "swap-id".to_string(),
+        })?;
+        assert!(second_seq > first_seq);
+
+        let events = persister.list_events_since(0, 10)?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event, SdkEvent::Synced);
+
+        let events = persister.list_events_since(first_seq, 10)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, second_seq);
+
+        // Events are listed in pages
+        let events = persister.list_events_since(0, 1)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, first_seq);
+
+        assert_eq!(persister.get_event_cursor("listener")?, None);
+        persister.set_event_cursor("listener", first_seq)?;
+        assert_eq!(persister.get_event_cursor("listener")?, Some(first_seq));
+
+        Ok(())
+    }
+    #[test]
+    fn test_event_log_skips_undecodable_events() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+
+        let first_seq = persister.insert_event(&SdkEvent::Synced)?;
+        persister.get_connection()?.execute(
+            "INSERT INTO event_log (event_json, created_at) VALUES (?, ?)",
+            ("{\"RemovedVariant\":{}}", 0),
+        )?;
+        let third_seq = persister.insert_event(&SdkEvent::Synced)?;
+
+        let events = persister.list_events_since(0, 10)?;
+        assert_eq!(
+            events.iter().map(|e| e.seq).collect::<Vec<_>>(),
+            vec![first_seq, third_seq]
+        );
+
+        // A page is filled with the events following the undecodable one
+        let events = persister.list_events_since(first_seq, 1)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, third_seq);
+
+        Ok(())
+    }
+}
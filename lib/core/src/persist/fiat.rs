@@ -0,0 +1,146 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension};
+use sdk_common::prelude::Rate;
+
+use super::Persister;
+use crate::model::PaymentFiatRate;
+
+impl Persister {
+    /// Stores a snapshot of fiat rates, taken at `created_at`
+    pub(crate) fn insert_fiat_rates(&self, rates: &[Rate], created_at: u32) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for rate in rates {
+            tx.execute(
+                "INSERT OR REPLACE INTO fiat_rates (currency, value, created_at) VALUES (?, ?, ?)",
+                params![rate.coin, rate.value, created_at],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Returns the most recently stored rate of a currency, along with the time it was stored
+    pub(crate) fn get_latest_fiat_rate(&self, currency: &str) -> Result<Option<(Rate, u32)>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                "
+                SELECT currency, value, created_at
+                FROM fiat_rates
+                WHERE currency = ?1
+                ORDER BY created_at DESC
+                LIMIT 1",
+                params![currency],
+                |row| {
+                    Ok((
+                        Rate {
+                            coin: row.get(0)?,
+                            value: row.get(1)?,
+                        },
+                        row.get(2)?,
+                    ))
+                },
+            )
+            .optional()?)
+    }
+
+    /// Returns the stored rate of a currency which is closest in time to `timestamp`, if it was
+    /// stored at most `max_distance_secs` away from it
+    pub(crate) fn get_closest_fiat_rate(
+        &self,
+        currency: &str,
+        timestamp: u32,
+        max_distance_secs: u32,
+    ) -> Result<Option<Rate>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                "
+                SELECT currency, value
+                FROM fiat_rates
+                WHERE currency = ?1 AND ABS(created_at - ?2) <= ?3
+                ORDER BY ABS(created_at - ?2) ASC
+                LIMIT 1",
+                params![currency, timestamp, max_distance_secs],
+                |row| {
+                    Ok(Rate {
+                        coin: row.get(0)?,
+                        value: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    /// Deletes the stored rates created before the given timestamp
+    pub(crate) fn prune_fiat_rates(&self, before_timestamp: u32) -> Result<()> {
+        self.get_connection()?.execute(
+            "DELETE FROM fiat_rates WHERE created_at < ?1",
+            [before_timestamp],
+        )?;
+        Ok(())
+    }
+
+    /// Records the fiat rate used to prepare a payment, by swap id or, for direct Liquid
+    /// payments, by tx id
+    pub(crate) fn insert_payment_fiat_rate(
+        &self,
+        payment_id: &str,
+        fiat_rate: &PaymentFiatRate,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO payment_fiat_rates (id, currency, rate) VALUES (?, ?, ?)",
+            params![payment_id, fiat_rate.currency, fiat_rate.rate],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::Rate;
+
+    use crate::test_utils::persist::new_persister;
+
+    fn rate(coin: &str, value: f64) -> Rate {
+        Rate {
+            coin: coin.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_fiat_rates() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        assert!(storage.get_latest_fiat_rate("USD")?.is_none());
+
+        storage.insert_fiat_rates(&[rate("USD", 60_000.0), rate("EUR", 55_000.0)], 1_000)?;
+        storage.insert_fiat_rates(&[rate("USD", 62_000.0)], 5_000)?;
+
+        let (latest, created_at) = storage.get_latest_fiat_rate("USD")?.unwrap();
+        assert_eq!(latest.value, 62_000.0);
+        assert_eq!(created_at, 5_000);
+
+        let closest = |currency, timestamp| -> Result<Option<f64>> {
+            Ok(storage
+                .get_closest_fiat_rate(currency, timestamp, 10_000)?
+                .map(|rate| rate.value))
+        };
+        assert_eq!(closest("USD", 0)?, Some(60_000.0));
+        assert_eq!(closest("USD", 2_000)?, Some(60_000.0));
+        assert_eq!(closest("USD", 4_000)?, Some(62_000.0));
+        assert_eq!(closest("EUR", 10_000)?, Some(55_000.0));
+        assert_eq!(closest("GBP", 1_000)?, None);
+        // Rates further away than the max distance aren't returned
+        assert_eq!(closest("EUR", 11_001)?, None);
+        assert_eq!(closest("USD", 15_001)?, None);
+
+        storage.prune_fiat_rates(5_000)?;
+        assert_eq!(closest("USD", 0)?, Some(62_000.0));
+        assert_eq!(closest("EUR", 1_000)?, None);
+
+        Ok(())
+    }
+}
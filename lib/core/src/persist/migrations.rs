@@ -184,5 +184,139 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
         DROP TABLE old_chain_swaps;
         ",
         "ALTER TABLE send_swaps ADD COLUMN bolt12_offer TEXT;",
+        "
+        CREATE TABLE IF NOT EXISTS event_log (
+            seq INTEGER PRIMARY KEY AUTOINCREMENT,
+            event_json TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+
+        CREATE TABLE IF NOT EXISTS event_cursors (
+            listener_id TEXT NOT NULL PRIMARY KEY,
+            acked_seq INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS webhook_deliveries (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            url TEXT NOT NULL,
+            event_seq INTEGER NOT NULL,
+            payload TEXT NOT NULL,
+            state INTEGER NOT NULL,
+            attempts INTEGER NOT NULL,
+            next_attempt_at INTEGER NOT NULL,
+            last_error TEXT,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS swap_state_transitions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            swap_id TEXT NOT NULL,
+            from_state INTEGER NOT NULL,
+            to_state INTEGER NOT NULL,
+            swapper_status TEXT,
+            tx_ids TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+
+        CREATE INDEX IF NOT EXISTS swap_state_transitions_swap_id_idx
+            ON swap_state_transitions(swap_id);
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS fiat_rates (
+            currency TEXT NOT NULL,
+            value REAL NOT NULL,
+            created_at INTEGER NOT NULL,
+            PRIMARY KEY (currency, created_at)
+        ) STRICT;
+
+        CREATE TABLE IF NOT EXISTS payment_fiat_rates (
+            id TEXT NOT NULL PRIMARY KEY,
+            currency TEXT NOT NULL,
+            rate REAL NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS outbox_txs (
+            tx_id TEXT NOT NULL PRIMARY KEY,
+            tx_hex TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            last_error TEXT,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS sync_checkpoint_txs (
+            tx_id TEXT NOT NULL PRIMARY KEY,
+            height INTEGER
+        ) STRICT;
+        ",
+        "
+        ALTER TABLE receive_swaps RENAME TO old_receive_swaps;
+
+        CREATE TABLE IF NOT EXISTS receive_swaps (
+            id TEXT NOT NULL PRIMARY KEY,
+            id_hash TEXT,
+            preimage TEXT NOT NULL,
+            create_response_json TEXT NOT NULL,
+            claim_private_key TEXT NOT NULL,
+            invoice TEXT NOT NULL,
+            payment_hash TEXT,
+            description TEXT,
+            payer_amount_sat INTEGER NOT NULL,
+            receiver_amount_sat INTEGER NOT NULL,
+            created_at INTEGER NOT NULL,
+            claim_fees_sat INTEGER NOT NULL,
+            claim_tx_id TEXT,
+            lockup_tx_id TEXT,
+            mrh_address TEXT,
+            mrh_script_pubkey TEXT,
+            mrh_tx_id TEXT,
+            state INTEGER NOT NULL
+        ) STRICT;
+
+        INSERT INTO receive_swaps (
+            id,
+            id_hash,
+            preimage,
+            create_response_json,
+            claim_private_key,
+            invoice,
+            payment_hash,
+            description,
+            payer_amount_sat,
+            receiver_amount_sat,
+            created_at,
+            claim_fees_sat,
+            claim_tx_id,
+            lockup_tx_id,
+            mrh_address,
+            mrh_script_pubkey,
+            mrh_tx_id,
+            state
+        ) SELECT
+            id,
+            id_hash,
+            preimage,
+            create_response_json,
+            claim_private_key,
+            invoice,
+            payment_hash,
+            description,
+            payer_amount_sat,
+            receiver_amount_sat,
+            created_at,
+            claim_fees_sat,
+            claim_tx_id,
+            lockup_tx_id,
+            NULLIF(mrh_address, ''),
+            NULLIF(mrh_script_pubkey, ''),
+            mrh_tx_id,
+            state
+        FROM old_receive_swaps;
+
+        DROP TABLE old_receive_swaps;
+        ",
     ]
 }
@@ -2,9 +2,15 @@ mod address;
 mod backup;
 mod cache;
 pub(crate) mod chain;
+mod checkpoint;
+mod event;
+mod fiat;
 mod migrations;
+mod outbox;
 pub(crate) mod receive;
 pub(crate) mod send;
+mod swap_history;
+mod webhook;
 
 use std::collections::HashSet;
 use std::{fs::create_dir_all, path::PathBuf, str::FromStr};
@@ -73,16 +79,18 @@ impl Persister {
     }
 
     pub(crate) fn fetch_swap_by_id(&self, id: &str) -> Result<Swap> {
-        match self.fetch_send_swap_by_id(id) {
-            Ok(Some(send_swap)) => Ok(Swap::Send(send_swap)),
-            _ => match self.fetch_receive_swap_by_id(id) {
-                Ok(Some(receive_swap)) => Ok(Swap::Receive(receive_swap)),
-                _ => match self.fetch_chain_swap_by_id(id) {
-                    Ok(Some(chain_swap)) => Ok(Swap::Chain(chain_swap)),
-                    _ => Err(anyhow!("Could not find Swap {id}")),
-                },
-            },
+        self.try_fetch_swap_by_id(id)?
+            .ok_or(anyhow!("Could not find Swap {id}"))
+    }
+
+    pub(crate) fn try_fetch_swap_by_id(&self, id: &str) -> Result<Option<Swap>> {
+        if let Some(send_swap) = self.fetch_send_swap_by_id(id)? {
+            return Ok(Some(Swap::Send(send_swap)));
+        }
+        if let Some(receive_swap) = self.fetch_receive_swap_by_id(id)? {
+            return Ok(Some(Swap::Receive(receive_swap)));
         }
+        Ok(self.fetch_chain_swap_by_id(id)?.map(Swap::Chain))
     }
 
     pub(crate) fn insert_or_update_payment(
@@ -201,7 +209,9 @@ impl Persister {
                 cs.state,
                 rtx.amount_sat,
                 pd.destination,
-                pd.description
+                pd.description,
+                pfr.currency,
+                pfr.rate
             FROM payment_tx_data AS ptx          -- Payment tx (each tx results in a Payment)
             FULL JOIN (
                 SELECT * FROM receive_swaps
@@ -216,6 +226,8 @@ impl Persister {
                 ON rtx.tx_id in (ss.refund_tx_id, cs.refund_tx_id)
             LEFT JOIN payment_details AS pd      -- Payment details
                 ON pd.tx_id = ptx.tx_id
+            LEFT JOIN payment_fiat_rates AS pfr  -- Fiat rate used to prepare the payment
+                ON pfr.id = COALESCE(rs.id, ss.id, cs.id, ptx.tx_id)
             WHERE                                -- Filter out refund txs from Send Swaps
                 ptx.tx_id NOT IN (SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL)
             AND                                  -- Filter out refund txs from Chain Swaps
@@ -284,6 +296,13 @@ impl Persister {
         let maybe_payment_details_destination: Option<String> = row.get(37)?;
         let maybe_payment_details_description: Option<String> = row.get(38)?;
 
+        let maybe_fiat_rate_currency: Option<String> = row.get(39)?;
+        let maybe_fiat_rate: Option<f64> = row.get(40)?;
+        let fiat_rate = match (maybe_fiat_rate_currency, maybe_fiat_rate) {
+            (Some(currency), Some(rate)) => Some(PaymentFiatRate { currency, rate }),
+            _ => None,
+        };
+
         let (swap, payment_type) = match maybe_receive_swap_id {
             Some(receive_swap_id) => (
                 Some(PaymentSwapData {
@@ -415,12 +434,16 @@ impl Persister {
             },
         };
 
-        match (tx, swap.clone()) {
-            (None, None) => Err(maybe_tx_tx_id.err().unwrap()),
-            (None, Some(swap)) => Ok(Payment::from_pending_swap(swap, payment_type)),
-            (Some(tx), None) => Ok(Payment::from_tx_data(tx, None, payment_details)),
-            (Some(tx), Some(swap)) => Ok(Payment::from_tx_data(tx, Some(swap), payment_details)),
-        }
+        let payment = match (tx, swap.clone()) {
+            (None, None) => return Err(maybe_tx_tx_id.err().unwrap()),
+            (None, Some(swap)) => Payment::from_pending_swap(swap, payment_type),
+            (Some(tx), None) => Payment::from_tx_data(tx, None, payment_details),
+            (Some(tx), Some(swap)) => Payment::from_tx_data(tx, Some(swap), payment_details),
+        };
+        Ok(Payment {
+            fiat_rate,
+            ..payment
+        })
     }
 
     pub fn get_payment(&self, id: &str) -> Result<Option<Payment>> {
@@ -434,6 +457,21 @@ impl Persister {
             .optional()?)
     }
 
+    pub fn get_payment_by_swap_id(&self, swap_id: &str) -> Result<Option<Payment>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &self.select_payment_query(
+                    Some("(rs.id = ?1 OR ss.id = ?1 OR cs.id = ?1)"),
+                    None,
+                    None,
+                ),
+                params![swap_id],
+                |row| self.sql_row_to_payment(row),
+            )
+            .optional()?)
+    }
+
     pub fn get_payment_by_request(&self, req: &GetPaymentRequest) -> Result<Option<Payment>> {
         let (where_clause, param) = match req {
             GetPaymentRequest::Lightning { payment_hash } => (
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use rusqlite::{params, Row};
+
+use super::Persister;
+use crate::{model::OutboxTx, utils};
+
+impl Persister {
+    /// Queues a signed tx to be broadcast once the Liquid chain backends can be reached
+    pub(crate) fn insert_outbox_tx(&self, tx_id: &str, tx_hex: &str) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO outbox_txs (tx_id, tx_hex, created_at) VALUES (?, ?, ?)",
+            params![tx_id, tx_hex, utils::now()],
+        )?;
+        Ok(())
+    }
+
+    /// Lists the queued txs, oldest first
+    pub(crate) fn list_outbox_txs(&self) -> Result<Vec<OutboxTx>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                tx_id,
+                tx_hex,
+                attempts,
+                last_error,
+                created_at
+            FROM outbox_txs
+            ORDER BY created_at ASC
+        ",
+        )?;
+        let txs = stmt
+            .query_map([], Self::sql_row_to_outbox_tx)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(txs)
+    }
+
+    pub(crate) fn update_outbox_tx_attempt(
+        &self,
+        tx_id: &str,
+        attempts: u32,
+        last_error: Option<String>,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "UPDATE outbox_txs SET attempts = ?2, last_error = ?3 WHERE tx_id = ?1",
+            params![tx_id, attempts, last_error],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a queued tx once it has been broadcast
+    pub(crate) fn delete_outbox_tx(&self, tx_id: &str) -> Result<()> {
+        self.get_connection()?
+            .execute("DELETE FROM outbox_txs WHERE tx_id = ?1", params![tx_id])?;
+        Ok(())
+    }
+
+    /// Removes a queued tx which was rejected, along with the payment recorded for it
+    pub(crate) fn delete_queued_payment(&self, tx_id: &str) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute("DELETE FROM outbox_txs WHERE tx_id = ?1", params![tx_id])?;
+        tx.execute(
+            "DELETE FROM payment_tx_data WHERE tx_id = ?1",
+            params![tx_id],
+        )?;
+        tx.execute(
+            "DELETE FROM payment_details WHERE tx_id = ?1",
+            params![tx_id],
+        )?;
+        tx.execute(
+            "DELETE FROM payment_fiat_rates WHERE id = ?1",
+            params![tx_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn sql_row_to_outbox_tx(row: &Row) -> rusqlite::Result<OutboxTx> {
+        Ok(OutboxTx {
+            tx_id: row.get(0)?,
+            tx_hex: row.get(1)?,
+            attempts: row.get(2)?,
+            last_error: row.get(3)?,
+            created_at: row.get(4)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        model::{PaymentTxData, PaymentType},
+        test_utils::persist::new_persister,
+    };
+
+    #[test]
+    fn test_outbox_txs() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        assert!(storage.list_outbox_txs()?.is_empty());
+
+        storage.insert_outbox_tx("tx1", "00")?;
+        storage.insert_outbox_tx("tx2", "01")?;
+        storage.update_outbox_tx_attempt("tx1", 1, Some("offline".to_string()))?;
+
+        let txs = storage.list_outbox_txs()?;
+        assert_eq!(txs.len(), 2);
+        let tx1 = txs.iter().find(|tx| tx.tx_id == "tx1").unwrap();
+        assert_eq!(tx1.attempts, 1);
+        assert_eq!(tx1.last_error.as_deref(), Some("offline"));
+
+        storage.delete_outbox_tx("tx1")?;
+        assert_eq!(storage.list_outbox_txs()?.len(), 1);
+
+        storage.insert_or_update_payment(
+            PaymentTxData {
+                tx_id: "tx2".to_string(),
+                timestamp: None,
+                amount_sat: 1_000,
+                fees_sat: 100,
+                payment_type: PaymentType::Send,
+                is_confirmed: false,
+            },
+            Some("destination".to_string()),
+            None,
+        )?;
+        assert!(storage.get_payment("tx2")?.is_some());
+
+        storage.delete_queued_payment("tx2")?;
+        assert!(storage.list_outbox_txs()?.is_empty());
+        assert!(storage.get_payment("tx2")?.is_none());
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::CreateReverseResponse;
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use sdk_common::bitcoin::hashes::{hex::ToHex, sha256, Hash};
 use serde::{Deserialize, Serialize};
 
@@ -58,6 +58,7 @@ impl Persister {
             SET
                 description = :description,
                 claim_tx_id = :claim_tx_id,
+                lockup_tx_id = :lockup_tx_id,
                 mrh_tx_id = :mrh_tx_id
             WHERE
                 id = :id",
@@ -65,6 +66,7 @@ impl Persister {
                 ":id": &receive_swap.id,
                 ":description": &receive_swap.description,
                 ":claim_tx_id": &receive_swap.claim_tx_id,
+                ":lockup_tx_id": &receive_swap.lockup_tx_id,
                 ":mrh_tx_id": &receive_swap.mrh_tx_id,
             },
         )?;
@@ -199,11 +201,13 @@ impl Persister {
             .iter()
             .filter_map(|swap| {
                 match (
-                    swap.lockup_tx_id.clone(),
-                    swap.claim_tx_id.clone(),
-                    swap.mrh_script_pubkey.is_empty(),
+                    &swap.lockup_tx_id,
+                    &swap.claim_tx_id,
+                    &swap.mrh_script_pubkey,
                 ) {
-                    (None, None, false) => Some((swap.mrh_script_pubkey.clone(), swap.clone())),
+                    (None, None, Some(mrh_script_pubkey)) => {
+                        Some((mrh_script_pubkey.clone(), swap.clone()))
+                    }
                     _ => None,
                 }
             })
@@ -280,10 +284,19 @@ impl Persister {
         lockup_tx_id: Option<&str>,
         mrh_tx_id: Option<&str>,
         mrh_amount_sat: Option<u64>,
+        swapper_status: Option<&str>,
     ) -> Result<(), PaymentError> {
         // Do not overwrite claim_tx_id or lockup_tx_id
-        let con: Connection = self.get_connection()?;
-        con.execute(
+        let mut con: Connection = self.get_connection()?;
+        let tx = con.transaction()?;
+        let from_state: Option<PaymentState> = tx
+            .query_row(
+                "SELECT state FROM receive_swaps WHERE id = ?1",
+                [swap_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        tx.execute(
             "UPDATE receive_swaps
             SET
                 claim_tx_id =
@@ -315,6 +328,17 @@ impl Persister {
                 ":state": to_state,
             },
         )?;
+        if let Some(from_state) = from_state {
+            Self::insert_swap_state_transition_inner(
+                &tx,
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                &[lockup_tx_id, claim_tx_id, mrh_tx_id],
+            )?;
+        }
+        tx.commit()?;
 
         Ok(())
     }
@@ -427,6 +451,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )?;
 
         let updated_receive_swap = storage
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::CreateSubmarineResponse;
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use sdk_common::bitcoin::hashes::{hex::ToHex, sha256, Hash};
 use serde::{Deserialize, Serialize};
 
@@ -205,10 +205,19 @@ impl Persister {
         preimage: Option<&str>,
         lockup_tx_id: Option<&str>,
         refund_tx_id: Option<&str>,
+        swapper_status: Option<&str>,
     ) -> Result<(), PaymentError> {
         // Do not overwrite preimage, lockup_tx_id, refund_tx_id
-        let con: Connection = self.get_connection()?;
-        con.execute(
+        let mut con: Connection = self.get_connection()?;
+        let tx = con.transaction()?;
+        let from_state: Option<PaymentState> = tx
+            .query_row(
+                "SELECT state FROM send_swaps WHERE id = ?1",
+                [swap_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        tx.execute(
             "UPDATE send_swaps
             SET
                 preimage =
@@ -240,6 +249,17 @@ impl Persister {
                 ":state": to_state,
             },
         )?;
+        if let Some(from_state) = from_state {
+            Self::insert_swap_state_transition_inner(
+                &tx,
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                &[lockup_tx_id, refund_tx_id],
+            )?;
+        }
+        tx.commit()?;
 
         Ok(())
     }
@@ -397,6 +417,7 @@ mod tests {
             preimage,
             lockup_tx_id,
             refund_tx_id,
+            None,
         )?;
 
         let updated_send_swap = storage
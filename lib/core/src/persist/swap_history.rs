@@ -0,0 +1,161 @@
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Row, Transaction};
+
+use super::Persister;
+use crate::{
+    model::{PaymentState, SwapStateTransition},
+    utils,
+};
+
+impl Persister {
+    /// Appends a transition to the history of a swap, as part of the transaction that
+    /// updates the swap state.
+    ///
+    /// Nothing is recorded if the transition does not change the state.
+    pub(crate) fn insert_swap_state_transition_inner(
+        tx: &Transaction,
+        swap_id: &str,
+        from_state: PaymentState,
+        to_state: PaymentState,
+        swapper_status: Option<&str>,
+        tx_ids: &[Option<&str>],
+    ) -> Result<()> {
+        if from_state == to_state {
+            return Ok(());
+        }
+
+        let tx_ids: Vec<&str> = tx_ids.iter().flatten().copied().collect();
+        let tx_ids_json = serde_json::to_string(&tx_ids)
+            .map_err(|e| anyhow!("Failed to serialize tx ids {tx_ids:?}: {e:?}"))?;
+        tx.execute(
+            "
+            INSERT INTO swap_state_transitions (
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                tx_ids,
+                created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                tx_ids_json,
+                utils::now()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Lists the recorded transitions of a swap, oldest first
+    pub(crate) fn list_swap_state_transitions(
+        &self,
+        swap_id: &str,
+    ) -> Result<Vec<SwapStateTransition>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                swap_id,
+                from_state,
+                to_state,
+                swapper_status,
+                tx_ids,
+                created_at
+            FROM swap_state_transitions
+            WHERE swap_id = ?1
+            ORDER BY id ASC
+        ",
+        )?;
+        let transitions = stmt
+            .query_map(params![swap_id], Self::sql_row_to_swap_state_transition)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(transitions)
+    }
+
+    fn sql_row_to_swap_state_transition(row: &Row) -> rusqlite::Result<SwapStateTransition> {
+        let tx_ids_json: String = row.get(4)?;
+        let tx_ids = serde_json::from_str(&tx_ids_json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(4, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+        Ok(SwapStateTransition {
+            swap_id: row.get(0)?,
+            from_state: row.get(1)?,
+            to_state: row.get(2)?,
+            swapper_status: row.get(3)?,
+            tx_ids,
+            created_at: row.get(5)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::PaymentState::*;
+    use crate::test_utils::persist::{new_persister, new_receive_swap, new_send_swap};
+
+    #[test]
+    fn test_swap_state_transitions() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let send_swap = new_send_swap(Some(Created));
+        storage.insert_send_swap(&send_swap)?;
+        let other_swap = new_receive_swap(Some(Created));
+        storage.insert_receive_swap(&other_swap)?;
+
+        storage.try_handle_send_swap_update(
+            &send_swap.id,
+            Pending,
+            None,
+            Some("lockup"),
+            None,
+            None,
+        )?;
+        // Updates which do not change the state are not recorded
+        storage.try_handle_send_swap_update(
+            &send_swap.id,
+            Pending,
+            Some("preimage"),
+            None,
+            None,
+            None,
+        )?;
+        storage.try_handle_send_swap_update(
+            &send_swap.id,
+            Refundable,
+            None,
+            None,
+            None,
+            Some("transaction.lockupFailed"),
+        )?;
+        storage.try_handle_receive_swap_update(
+            &other_swap.id,
+            Failed,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let history = storage.list_swap_state_transitions(&send_swap.id)?;
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            (history[0].from_state, history[0].to_state),
+            (Created, Pending)
+        );
+        assert_eq!(history[0].tx_ids, vec!["lockup".to_string()]);
+        assert_eq!(history[1].to_state, Refundable);
+        assert_eq!(
+            history[1].swapper_status.as_deref(),
+            Some("transaction.lockupFailed")
+        );
+
+        Ok(())
+    }
+}
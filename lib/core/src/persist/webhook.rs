@@ -0,0 +1,168 @@
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Row};
+
+use super::Persister;
+use crate::{
+    model::{WebhookDelivery, WebhookDeliveryState},
+    utils,
+};
+
+/// The sequence number of the last logged event queued for webhook delivery. It's kept apart
+/// from the `event_cursors` of [Persister::set_event_cursor], which consumers can move.
+const KEY_WEBHOOK_EVENT_CURSOR: &str = "webhook_event_cursor";
+
+impl Persister {
+    pub(crate) fn get_webhook_event_cursor(&self) -> Result<Option<u64>> {
+        self.get_cached_item(KEY_WEBHOOK_EVENT_CURSOR)
+            .map(|maybe_str| maybe_str.and_then(|str| str.as_str().parse::<u64>().ok()))
+    }
+
+    pub(crate) fn set_webhook_event_cursor(&self, seq: u64) -> Result<()> {
+        self.update_cached_item(KEY_WEBHOOK_EVENT_CURSOR, seq.to_string())
+    }
+
+    /// Queues the delivery of an event to each of the urls and advances the webhook event cursor
+    /// to `event_seq`, so that an event is queued at most once.
+    pub(crate) fn enqueue_webhook_deliveries(
+        &self,
+        event_seq: u64,
+        urls: &[String],
+        payload: &str,
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        let now = utils::now();
+        for url in urls {
+            tx.execute(
+                "
+                INSERT INTO webhook_deliveries (
+                    url,
+                    event_seq,
+                    payload,
+                    state,
+                    attempts,
+                    next_attempt_at,
+                    created_at
+                )
+                VALUES (?, ?, ?, ?, 0, ?, ?)",
+                params![
+                    url,
+                    event_seq,
+                    payload,
+                    WebhookDeliveryState::Pending,
+                    now,
+                    now
+                ],
+            )?;
+        }
+        Self::update_cached_item_inner(&tx, KEY_WEBHOOK_EVENT_CURSOR, event_seq.to_string())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn list_webhook_deliveries_where(
+        &self,
+        where_clause: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<WebhookDelivery>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(&format!(
+            "
+            SELECT
+                id,
+                url,
+                event_seq,
+                payload,
+                state,
+                attempts,
+                next_attempt_at,
+                last_error,
+                created_at
+            FROM webhook_deliveries
+            WHERE {where_clause}
+            ORDER BY id ASC
+        "
+        ))?;
+        let deliveries = stmt
+            .query_map(params, Self::sql_row_to_webhook_delivery)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(deliveries)
+    }
+
+    /// Lists the pending deliveries whose next attempt is due at the given timestamp
+    pub(crate) fn list_due_webhook_deliveries(&self, now: u32) -> Result<Vec<WebhookDelivery>> {
+        self.list_webhook_deliveries_where(
+            "state = ?1 AND next_attempt_at <= ?2",
+            params![WebhookDeliveryState::Pending, now],
+        )
+    }
+
+    pub(crate) fn list_failed_webhook_deliveries(&self) -> Result<Vec<WebhookDelivery>> {
+        self.list_webhook_deliveries_where("state = ?1", params![WebhookDeliveryState::Failed])
+    }
+
+    pub(crate) fn delete_webhook_delivery(&self, id: u64) -> Result<()> {
+        self.get_connection()?
+            .execute("DELETE FROM webhook_deliveries WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub(crate) fn update_webhook_delivery_attempt(
+        &self,
+        id: u64,
+        state: WebhookDeliveryState,
+        attempts: u32,
+        next_attempt_at: u32,
+        last_error: Option<String>,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "
+            UPDATE webhook_deliveries
+            SET
+                state = ?2,
+                attempts = ?3,
+                next_attempt_at = ?4,
+                last_error = ?5
+            WHERE id = ?1",
+            params![id, state, attempts, next_attempt_at, last_error],
+        )?;
+        Ok(())
+    }
+
+    /// Moves a failed delivery back to the pending state, to be attempted again right away
+    pub(crate) fn reset_failed_webhook_delivery(&self, id: u64) -> Result<()> {
+        let updated = self.get_connection()?.execute(
+            "
+            UPDATE webhook_deliveries
+            SET
+                state = ?2,
+                attempts = 0,
+                next_attempt_at = ?3
+            WHERE id = ?1 AND state = ?4",
+            params![
+                id,
+                WebhookDeliveryState::Pending,
+                utils::now(),
+                WebhookDeliveryState::Failed
+            ],
+        )?;
+        match updated {
+            0 => Err(anyhow!("No failed webhook delivery found with id {id}")),
+            _ => Ok(()),
+        }
+    }
+
+    fn sql_row_to_webhook_delivery(row: &Row) -> rusqlite::Result<WebhookDelivery> {
+        Ok(WebhookDelivery {
+            id: row.get(0)?,
+            url: row.get(1)?,
+            event_seq: row.get(2)?,
+            payload: row.get(3)?,
+            state: row.get(4)?,
+            attempts: row.get(5)?,
+            next_attempt_at: row.get(6)?,
+            last_error: row.get(7)?,
+            created_at: row.get(8)?,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Instant};
 
 use anyhow::{anyhow, Result};
 use boltz_client::swaps::boltz::RevSwapStates;
@@ -8,7 +8,8 @@ use log::{debug, error, info, warn};
 use lwk_wollet::hashes::hex::DisplayHex;
 use tokio::sync::{broadcast, Mutex};
 
-use crate::chain::liquid::LiquidChainService;
+use crate::chain::{liquid::LiquidChainService, verify_tx_with_retry, LIQUID_VERIFY_TX_TIMEOUT};
+use crate::logger::LogSpan;
 use crate::model::PaymentState::{
     Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut,
 };
@@ -16,7 +17,10 @@ use crate::model::{Config, PaymentTxData, PaymentType, ReceiveSwap};
 use crate::prelude::{Swap, Transaction};
 use crate::{ensure_sdk, utils};
 use crate::{
-    error::PaymentError, model::PaymentState, persist::Persister, swapper::Swapper,
+    error::PaymentError,
+    model::PaymentState,
+    persist::Persister,
+    swapper::{self, Swapper},
     wallet::OnchainWallet,
 };
 
@@ -33,6 +37,7 @@ pub(crate) struct ReceiveSwapHandler {
     swapper: Arc<dyn Swapper>,
     subscription_notifier: broadcast::Sender<String>,
     liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
+    event_manager: Arc<EventManager>,
 }
 
 impl ReceiveSwapHandler {
@@ -42,6 +47,7 @@ impl ReceiveSwapHandler {
         persister: Arc<Persister>,
         swapper: Arc<dyn Swapper>,
         liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
+        event_manager: Arc<EventManager>,
     ) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Self {
@@ -51,6 +57,7 @@ impl ReceiveSwapHandler {
             swapper,
             subscription_notifier,
             liquid_chain_service,
+            event_manager,
         }
     }
 
@@ -58,16 +65,38 @@ impl ReceiveSwapHandler {
         self.subscription_notifier.subscribe()
     }
 
-    /// Handles status updates from Boltz for Receive swaps
-    pub(crate) async fn on_new_status(&self, update: &boltz::Update) -> Result<()> {
+    /// Handles status updates from Boltz for Receive swaps.
+    ///
+    /// The lockup tx is looked up until the `verify_deadline`, if any, see [verify_tx_with_retry].
+    pub(crate) async fn on_new_status(
+        &self,
+        update: &boltz::Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
         let id = &update.id;
-        let swap_state = &update.status;
         let receive_swap = self
             .persister
             .fetch_receive_swap_by_id(id)?
             .ok_or(anyhow!("No ongoing Receive Swap found for ID {id}"))?;
 
-        info!("Handling Receive Swap transition to {swap_state:?} for swap {id}");
+        LogSpan::swap(id, receive_swap.claim_tx_id.clone())
+            .scope(swapper::with_swapper_status(
+                &update.status,
+                self.handle_new_status(receive_swap, update, verify_deadline),
+            ))
+            .await
+    }
+
+    async fn handle_new_status(
+        &self,
+        receive_swap: ReceiveSwap,
+        update: &boltz::Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
+        let id = &update.id;
+        let swap_state = &update.status;
+
+        info!(status:% = swap_state; "Handling Receive Swap transition to {swap_state:?} for swap {id}");
 
         match RevSwapStates::from_str(swap_state) {
             Ok(
@@ -110,7 +139,7 @@ impl ReceiveSwapHandler {
 
                 // looking for lockup script history to verify lockup was broadcasted
                 if let Err(e) = self
-                    .verify_lockup_tx(&receive_swap, &transaction, false)
+                    .verify_lockup_tx(&receive_swap, &transaction, false, verify_deadline)
                     .await
                 {
                     return Err(anyhow!(
@@ -187,7 +216,7 @@ impl ReceiveSwapHandler {
 
                 // looking for lockup script history to verify lockup was broadcasted and confirmed
                 if let Err(e) = self
-                    .verify_lockup_tx(&receive_swap, &transaction, true)
+                    .verify_lockup_tx(&receive_swap, &transaction, true, verify_deadline)
                     .await
                 {
                     return Err(anyhow!(
@@ -266,7 +295,17 @@ impl ReceiveSwapHandler {
             lockup_tx_id,
             mrh_tx_id,
             mrh_amount_sat,
+            swapper::current_swapper_status().as_deref(),
         )?;
+        if swap.state != to_state {
+            self.event_manager
+                .notify(SdkEvent::SwapStateChanged {
+                    swap_id: swap_id.to_string(),
+                    from_state: swap.state,
+                    to_state,
+                })
+                .await;
+        }
 
         if let Some(payment_id) = payment_id {
             let _ = self.subscription_notifier.send(payment_id);
@@ -274,6 +313,12 @@ impl ReceiveSwapHandler {
         Ok(())
     }
 
+    /// Claims the swap, regardless of the last status received from the swapper
+    pub(crate) async fn force_claim(&self, swap: &ReceiveSwap) -> Result<(), PaymentError> {
+        Self::validate_state_transition(swap.state, Pending)?;
+        self.claim(&swap.id).await
+    }
+
     async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
         let swap = self
             .persister
@@ -403,6 +448,7 @@ impl ReceiveSwapHandler {
         receive_swap: &ReceiveSwap,
         swap_update_tx: &SwapUpdateTxDetails,
         verify_confirmation: bool,
+        verify_deadline: Option<Instant>,
     ) -> Result<()> {
         // Looking for lockup script history to verify lockup was broadcasted
         let script = receive_swap.get_swap_script()?;
@@ -412,16 +458,36 @@ impl ReceiveSwapHandler {
                 .map_err(|e| PaymentError::Generic {
                     err: format!("Failed to get swap script address {e:?}"),
                 })?;
-        self.liquid_chain_service
-            .lock()
-            .await
-            .verify_tx(
-                &address,
-                &swap_update_tx.id,
-                &swap_update_tx.hex,
-                verify_confirmation,
-            )
-            .await?;
+        let address = &address;
+        let requires_chain_quorum = self
+            .config
+            .requires_chain_quorum(receive_swap.receiver_amount_sat);
+        verify_tx_with_retry(LIQUID_VERIFY_TX_TIMEOUT, verify_deadline, || async move {
+            let liquid_chain_service = self.liquid_chain_service.lock().await;
+            match requires_chain_quorum {
+                true => {
+                    liquid_chain_service
+                        .verify_tx_with_quorum(
+                            address,
+                            &swap_update_tx.id,
+                            &swap_update_tx.hex,
+                            verify_confirmation,
+                        )
+                        .await
+                }
+                false => {
+                    liquid_chain_service
+                        .verify_tx(
+                            address,
+                            &swap_update_tx.id,
+                            &swap_update_tx.hex,
+                            verify_confirmation,
+                        )
+                        .await
+                }
+            }
+        })
+        .await?;
         Ok(())
     }
 }
@@ -0,0 +1,547 @@
+//! Swaps in the rescue format of the Boltz web app, which contains the swapper create response
+//! along with the key material needed to claim or refund the swap
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use boltz_client::swaps::boltz::{
+    ChainSwapDetails, CreateChainResponse, CreateReverseResponse, CreateSubmarineResponse, Leaf,
+    SwapTree,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
+use crate::model::{
+    ChainSwap, Direction, LiquidNetwork, PaymentState, ReceiveSwap, SendSwap, Swap, SwapScriptV2,
+};
+use crate::utils;
+
+const BTC_ASSET: &str = "BTC";
+const LBTC_ASSET: &str = "L-BTC";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RescueLeaf {
+    output: String,
+    version: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RescueSwapTree {
+    claim_leaf: RescueLeaf,
+    refund_leaf: RescueLeaf,
+}
+impl From<RescueSwapTree> for SwapTree {
+    fn from(value: RescueSwapTree) -> Self {
+        SwapTree {
+            claim_leaf: Leaf {
+                output: value.claim_leaf.output,
+                version: value.claim_leaf.version,
+            },
+            refund_leaf: Leaf {
+                output: value.refund_leaf.output,
+                version: value.refund_leaf.version,
+            },
+        }
+    }
+}
+
+impl From<SwapTree> for RescueSwapTree {
+    fn from(value: SwapTree) -> Self {
+        RescueSwapTree {
+            claim_leaf: RescueLeaf {
+                output: value.claim_leaf.output,
+                version: value.claim_leaf.version,
+            },
+            refund_leaf: RescueLeaf {
+                output: value.refund_leaf.output,
+                version: value.refund_leaf.version,
+            },
+        }
+    }
+}
+
+/// A submarine swap, used for Send
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubmarineRescue {
+    id: String,
+    asset_send: String,
+    asset_receive: String,
+    send_amount: u64,
+    receive_amount: u64,
+    /// Creation time in milliseconds
+    date: u64,
+    invoice: String,
+    /// Older rescue files name the refund key `privateKey`
+    #[serde(alias = "privateKey")]
+    refund_private_key: String,
+    address: String,
+    bip21: String,
+    swap_tree: RescueSwapTree,
+    accept_zero_conf: bool,
+    expected_amount: u64,
+    claim_public_key: String,
+    timeout_block_height: u64,
+    #[serde(default)]
+    blinding_key: Option<String>,
+    #[serde(default)]
+    referral_id: Option<String>,
+}
+
+/// A reverse swap, used for Receive
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReverseRescue {
+    id: String,
+    asset_send: String,
+    asset_receive: String,
+    send_amount: u64,
+    receive_amount: u64,
+    /// Creation time in milliseconds
+    date: u64,
+    invoice: String,
+    preimage: String,
+    /// Older rescue files name the claim key `privateKey`
+    #[serde(alias = "privateKey")]
+    claim_private_key: String,
+    swap_tree: RescueSwapTree,
+    lockup_address: String,
+    refund_public_key: String,
+    timeout_block_height: u32,
+    onchain_amount: u64,
+    #[serde(default)]
+    blinding_key: Option<String>,
+}
+
+/// A chain swap, used for both directions between BTC and L-BTC
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChainRescue {
+    id: String,
+    asset_send: String,
+    asset_receive: String,
+    send_amount: u64,
+    receive_amount: u64,
+    /// Creation time in milliseconds
+    date: u64,
+    preimage: String,
+    claim_private_key: String,
+    refund_private_key: String,
+    /// The address the claimed funds are sent to
+    #[serde(default)]
+    claim_address: Option<String>,
+    claim_details: ChainSwapDetails,
+    lockup_details: ChainSwapDetails,
+}
+
+/// A swap rescue file, as exported by the Boltz web app
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub(crate) enum RescueFile {
+    Submarine(SubmarineRescue),
+    Reverse(ReverseRescue),
+    Chain(ChainRescue),
+}
+
+impl RescueFile {
+    pub(crate) fn id(&self) -> &str {
+        match self {
+            RescueFile::Submarine(SubmarineRescue { id, .. })
+            | RescueFile::Reverse(ReverseRescue { id, .. })
+            | RescueFile::Chain(ChainRescue { id, .. }) => id,
+        }
+    }
+
+    /// Converts the rescued swap into a [Swap] in the [PaymentState::Created] state.
+    ///
+    /// Fails if the swap is not between L-BTC and Lightning or BTC, or if the keys in the file
+    /// do not match the swap addresses.
+    pub(crate) fn into_swap(self, network: LiquidNetwork) -> Result<Swap> {
+        let swap = match self {
+            RescueFile::Submarine(rescue) => Swap::Send(rescue.into_send_swap()?),
+            RescueFile::Reverse(rescue) => Swap::Receive(rescue.into_receive_swap()?),
+            RescueFile::Chain(rescue) => Swap::Chain(rescue.into_chain_swap()?),
+        };
+        verify_swap_addresses(&swap, network)?;
+        Ok(swap)
+    }
+
+    /// Builds the rescue file of the swap, containing the keys needed to claim or refund it
+    pub(crate) fn from_swap(swap: &Swap) -> Result<Self> {
+        Ok(match swap {
+            Swap::Send(swap) => RescueFile::Submarine(SubmarineRescue::from_send_swap(swap)?),
+            Swap::Receive(swap) => RescueFile::Reverse(ReverseRescue::from_receive_swap(swap)?),
+            Swap::Chain(swap) => RescueFile::Chain(ChainRescue::from_chain_swap(swap)?),
+        })
+    }
+}
+
+impl SubmarineRescue {
+    fn from_send_swap(swap: &SendSwap) -> Result<Self> {
+        let create_response = swap.get_boltz_create_response()?;
+        Ok(SubmarineRescue {
+            id: swap.id.clone(),
+            asset_send: LBTC_ASSET.to_string(),
+            asset_receive: BTC_ASSET.to_string(),
+            send_amount: swap.payer_amount_sat,
+            receive_amount: swap.receiver_amount_sat,
+            date: swap.created_at as u64 * 1000,
+            invoice: swap.invoice.clone(),
+            refund_private_key: swap.refund_private_key.clone(),
+            address: create_response.address,
+            bip21: create_response.bip21,
+            swap_tree: create_response.swap_tree.into(),
+            accept_zero_conf: create_response.accept_zero_conf,
+            expected_amount: create_response.expected_amount,
+            claim_public_key: create_response.claim_public_key.to_string(),
+            timeout_block_height: create_response.timeout_block_height,
+            blinding_key: create_response.blinding_key,
+            referral_id: create_response.referral_id,
+        })
+    }
+
+    fn into_send_swap(self) -> Result<SendSwap> {
+        ensure!(
+            self.asset_send == LBTC_ASSET,
+            "Unsupported asset {} for Send Swap {}",
+            self.asset_send,
+            self.id
+        );
+        let create_response = CreateSubmarineResponse {
+            id: self.id.clone(),
+            accept_zero_conf: self.accept_zero_conf,
+            address: self.address,
+            bip21: self.bip21,
+            claim_public_key: utils::json_to_pubkey(&self.claim_public_key)?,
+            expected_amount: self.expected_amount,
+            referral_id: self.referral_id,
+            swap_tree: self.swap_tree.into(),
+            timeout_block_height: self.timeout_block_height,
+            blinding_key: self.blinding_key,
+        };
+        let (payment_hash, description) = parse_invoice(&self.invoice);
+
+        Ok(SendSwap {
+            create_response_json: SendSwap::from_boltz_struct_to_json(&create_response, &self.id)?,
+            id: self.id,
+            invoice: self.invoice,
+            bolt12_offer: None,
+            payment_hash,
+            description,
+            preimage: None,
+            payer_amount_sat: self.expected_amount,
+            receiver_amount_sat: self.receive_amount,
+            lockup_tx_id: None,
+            refund_tx_id: None,
+            created_at: (self.date / 1000) as u32,
+            state: PaymentState::Created,
+            refund_private_key: self.refund_private_key,
+        })
+    }
+}
+
+impl ReverseRescue {
+    fn from_receive_swap(swap: &ReceiveSwap) -> Result<Self> {
+        let create_response = swap.get_boltz_create_response()?;
+        Ok(ReverseRescue {
+            id: swap.id.clone(),
+            asset_send: BTC_ASSET.to_string(),
+            asset_receive: LBTC_ASSET.to_string(),
+            send_amount: swap.payer_amount_sat,
+            receive_amount: swap.receiver_amount_sat,
+            date: swap.created_at as u64 * 1000,
+            invoice: swap.invoice.clone(),
+            preimage: swap.preimage.clone(),
+            claim_private_key: swap.claim_private_key.clone(),
+            swap_tree: create_response.swap_tree.into(),
+            lockup_address: create_response.lockup_address,
+            refund_public_key: create_response.refund_public_key.to_string(),
+            timeout_block_height: create_response.timeout_block_height,
+            onchain_amount: create_response.onchain_amount,
+            blinding_key: create_response.blinding_key,
+        })
+    }
+
+    fn into_receive_swap(self) -> Result<ReceiveSwap> {
+        ensure!(
+            self.asset_receive == LBTC_ASSET,
+            "Unsupported asset {} for Receive Swap {}",
+            self.asset_receive,
+            self.id
+        );
+        let create_response = CreateReverseResponse {
+            id: self.id.clone(),
+            invoice: self.invoice.clone(),
+            swap_tree: self.swap_tree.into(),
+            lockup_address: self.lockup_address,
+            refund_public_key: utils::json_to_pubkey(&self.refund_public_key)?,
+            timeout_block_height: self.timeout_block_height,
+            onchain_amount: self.onchain_amount,
+            blinding_key: self.blinding_key,
+        };
+        let (payment_hash, description) = parse_invoice(&self.invoice);
+
+        Ok(ReceiveSwap {
+            create_response_json: ReceiveSwap::from_boltz_struct_to_json(
+                &create_response,
+                &self.id,
+                &self.invoice,
+            )?,
+            id: self.id,
+            preimage: self.preimage,
+            claim_private_key: self.claim_private_key,
+            invoice: self.invoice,
+            payment_hash,
+            description,
+            payer_amount_sat: self.send_amount,
+            // The claim tx fees are only known once the claim is broadcast
+            receiver_amount_sat: self.onchain_amount,
+            claim_fees_sat: 0,
+            claim_tx_id: None,
+            lockup_tx_id: None,
+            // Swaps created outside the SDK have no magic routing hint
+            mrh_address: None,
+            mrh_script_pubkey: None,
+            mrh_tx_id: None,
+            created_at: (self.date / 1000) as u32,
+            state: PaymentState::Created,
+        })
+    }
+}
+
+impl ChainRescue {
+    fn from_chain_swap(swap: &ChainSwap) -> Result<Self> {
+        let create_response = swap.get_boltz_create_response()?;
+        let (asset_send, asset_receive) = match swap.direction {
+            Direction::Incoming => (BTC_ASSET, LBTC_ASSET),
+            Direction::Outgoing => (LBTC_ASSET, BTC_ASSET),
+        };
+        Ok(ChainRescue {
+            id: swap.id.clone(),
+            asset_send: asset_send.to_string(),
+            asset_receive: asset_receive.to_string(),
+            send_amount: swap.payer_amount_sat,
+            receive_amount: swap.receiver_amount_sat,
+            date: swap.created_at as u64 * 1000,
+            preimage: swap.preimage.clone(),
+            claim_private_key: swap.claim_private_key.clone(),
+            refund_private_key: swap.refund_private_key.clone(),
+            claim_address: swap.claim_address.clone(),
+            claim_details: create_response.claim_details,
+            lockup_details: create_response.lockup_details,
+        })
+    }
+
+    fn into_chain_swap(self) -> Result<ChainSwap> {
+        let direction = match (self.asset_send.as_str(), self.asset_receive.as_str()) {
+            (BTC_ASSET, LBTC_ASSET) => Direction::Incoming,
+            (LBTC_ASSET, BTC_ASSET) => Direction::Outgoing,
+            (from, to) => bail!("Unsupported pair {from}/{to} for Chain Swap {}", self.id),
+        };
+        let claim_address = match direction {
+            Direction::Incoming => None,
+            Direction::Outgoing => Some(self.claim_address.ok_or_else(|| {
+                anyhow!("No claim address found for outgoing Chain Swap {}", self.id)
+            })?),
+        };
+        let create_response = CreateChainResponse {
+            id: self.id.clone(),
+            claim_details: self.claim_details,
+            lockup_details: self.lockup_details,
+        };
+
+        Ok(ChainSwap {
+            create_response_json: ChainSwap::from_boltz_struct_to_json(&create_response, &self.id)?,
+            id: self.id,
+            direction,
+            claim_address,
+            lockup_address: create_response.lockup_details.lockup_address,
+            timeout_block_height: create_response.lockup_details.timeout_block_height,
+            preimage: self.preimage,
+            description: Some("Bitcoin transfer".to_string()),
+            payer_amount_sat: self.send_amount,
+            receiver_amount_sat: self.receive_amount,
+            // The claim tx fees are only known once the claim is broadcast
+            claim_fees_sat: 0,
+            accept_zero_conf: false,
+            server_lockup_tx_id: None,
+            user_lockup_tx_id: None,
+            claim_tx_id: None,
+            refund_tx_id: None,
+            created_at: (self.date / 1000) as u32,
+            state: PaymentState::Created,
+            claim_private_key: self.claim_private_key,
+            refund_private_key: self.refund_private_key,
+        })
+    }
+}
+
+/// Returns the payment hash and description of a Bolt11 invoice
+fn parse_invoice(invoice: &str) -> (Option<String>, Option<String>) {
+    match Bolt11Invoice::from_str(invoice) {
+        Ok(invoice) => (
+            Some(invoice.payment_hash().to_string()),
+            match invoice.description() {
+                Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
+                Bolt11InvoiceDescription::Hash(_) => None,
+            },
+        ),
+        Err(_) => (None, None),
+    }
+}
+
+/// Ensures the swap scripts built from our keys and the swapper response pay to the swap addresses
+fn verify_swap_addresses(swap: &Swap, network: LiquidNetwork) -> Result<()> {
+    match swap {
+        Swap::Send(swap) => {
+            let create_response = swap.get_boltz_create_response()?;
+            verify_swap_address(
+                SwapScriptV2::Liquid(swap.get_swap_script()?),
+                &create_response.address,
+                network,
+            )
+        }
+        Swap::Receive(swap) => {
+            let create_response = swap.get_boltz_create_response()?;
+            verify_swap_address(
+                SwapScriptV2::Liquid(swap.get_swap_script()?),
+                &create_response.lockup_address,
+                network,
+            )
+        }
+        Swap::Chain(swap) => {
+            let create_response = swap.get_boltz_create_response()?;
+            verify_swap_address(
+                swap.get_lockup_swap_script()?,
+                &create_response.lockup_details.lockup_address,
+                network,
+            )?;
+            verify_swap_address(
+                swap.get_claim_swap_script()?,
+                &create_response.claim_details.lockup_address,
+                network,
+            )
+        }
+    }
+}
+
+fn verify_swap_address(
+    swap_script: SwapScriptV2,
+    address: &str,
+    network: LiquidNetwork,
+) -> Result<()> {
+    let is_match = match swap_script {
+        SwapScriptV2::Liquid(swap_script) => {
+            let expected = lwk_wollet::elements::Address::from_str(address)?.script_pubkey();
+            swap_script
+                .to_address(network.into())
+                .map_err(|e| anyhow!("Could not retrieve address from swap script: {e:?}"))?
+                .script_pubkey()
+                == expected
+        }
+        SwapScriptV2::Bitcoin(swap_script) => {
+            swap_script
+                .to_address(network.as_bitcoin_chain())
+                .map_err(|e| anyhow!("Could not retrieve address from swap script: {e:?}"))?
+                .to_string()
+                == address
+        }
+    };
+    ensure!(
+        is_match,
+        "The swap keys do not match the swap address {address}"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_utils::chain_swap::new_chain_swap;
+    use crate::test_utils::persist::new_send_swap;
+
+    fn submarine_rescue_json(refund_private_key: &str, asset_send: &str) -> Result<String> {
+        let swap = new_send_swap(None);
+        let create_response = swap.get_boltz_create_response()?;
+        Ok(json!({
+            "type": "submarine",
+            "id": swap.id,
+            "assetSend": asset_send,
+            "assetReceive": "BTC",
+            "sendAmount": swap.payer_amount_sat,
+            "receiveAmount": swap.receiver_amount_sat,
+            "date": swap.created_at as u64 * 1000,
+            "invoice": swap.invoice,
+            "privateKey": refund_private_key,
+            "address": create_response.address,
+            "bip21": create_response.bip21,
+            "swapTree": {
+                "claimLeaf": {
+                    "output": create_response.swap_tree.claim_leaf.output,
+                    "version": create_response.swap_tree.claim_leaf.version,
+                },
+                "refundLeaf": {
+                    "output": create_response.swap_tree.refund_leaf.output,
+                    "version": create_response.swap_tree.refund_leaf.version,
+                },
+            },
+            "acceptZeroConf": create_response.accept_zero_conf,
+            "expectedAmount": create_response.expected_amount,
+            "claimPublicKey": create_response.claim_public_key.to_string(),
+            "timeoutBlockHeight": create_response.timeout_block_height,
+            "blindingKey": create_response.blinding_key,
+        })
+        .to_string())
+    }
+
+    #[test]
+    fn test_rescue_file_validation() -> Result<()> {
+        let other_key = utils::generate_keypair().display_secret().to_string();
+        let rescue_file: RescueFile =
+            serde_json::from_str(&submarine_rescue_json(&other_key, LBTC_ASSET)?)?;
+        assert!(matches!(rescue_file, RescueFile::Submarine(_)));
+        // The refund key is not part of the swap script
+        assert!(rescue_file.into_swap(LiquidNetwork::Testnet).is_err());
+
+        let refund_key = new_send_swap(None).refund_private_key;
+        let rescue_file: RescueFile =
+            serde_json::from_str(&submarine_rescue_json(&refund_key, BTC_ASSET)?)?;
+        assert!(rescue_file.into_swap(LiquidNetwork::Testnet).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_rescue_file() -> Result<()> {
+        let send_swap = new_send_swap(None);
+        let rescue_json =
+            serde_json::to_string(&RescueFile::from_swap(&Swap::Send(send_swap.clone()))?)?;
+        let RescueFile::Submarine(rescue) = serde_json::from_str(&rescue_json)? else {
+            panic!("Expected a submarine swap rescue file");
+        };
+        let imported = rescue.into_send_swap()?;
+        assert_eq!(imported.id, send_swap.id);
+        assert_eq!(imported.refund_private_key, send_swap.refund_private_key);
+        assert_eq!(imported.created_at, send_swap.created_at);
+        assert_eq!(
+            imported.get_boltz_create_response()?.address,
+            send_swap.get_boltz_create_response()?.address
+        );
+
+        let chain_swap = new_chain_swap(Direction::Outgoing, None, false, None);
+        let rescue_json =
+            serde_json::to_value(RescueFile::from_swap(&Swap::Chain(chain_swap.clone()))?)?;
+        assert_eq!(rescue_json["type"], "chain");
+        assert_eq!(rescue_json["assetSend"], LBTC_ASSET);
+        assert_eq!(
+            rescue_json["refundPrivateKey"],
+            chain_swap.refund_private_key
+        );
+
+        Ok(())
+    }
+}
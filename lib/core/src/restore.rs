@@ -14,6 +14,8 @@ use crate::restore::immutable::*;
 pub(crate) struct TxMap {
     outgoing_tx_map: HashMap<Txid, WalletTx>,
     incoming_tx_map: HashMap<Txid, WalletTx>,
+    /// The lockup tx of an imported swap, which may have been funded by another wallet
+    external_lockup_tx_id: Option<Txid>,
 }
 impl TxMap {
     pub(crate) fn from_raw_tx_map(raw_tx_map: HashMap<Txid, WalletTx>) -> Self {
@@ -25,8 +27,25 @@ impl TxMap {
         Self {
             outgoing_tx_map,
             incoming_tx_map,
+            external_lockup_tx_id: None,
         }
     }
+
+    pub(crate) fn with_external_lockup_tx(mut self, lockup_tx_id: Txid) -> Self {
+        self.external_lockup_tx_id = Some(lockup_tx_id);
+        self
+    }
+
+    /// Finds the lockup tx in the history of a swap lockup script funded by us
+    fn find_lockup_tx(&self, history: &[HistoryTxId]) -> Option<HistoryTxId> {
+        history
+            .iter()
+            .find(|&tx| {
+                self.outgoing_tx_map.contains_key::<Txid>(&tx.txid)
+                    || self.external_lockup_tx_id == Some(tx.txid)
+            })
+            .cloned()
+    }
 }
 
 trait PartialSwapState {
@@ -156,6 +175,48 @@ pub(crate) struct RecoveredOnchainData {
     chain_send: HashMap<String, RecoveredOnchainDataChainSend>,
     chain_receive: HashMap<String, RecoveredOnchainDataChainReceive>,
 }
+impl RecoveredOnchainData {
+    /// Sets the recovered tx IDs and partial state on the swap, if any data was recovered for it
+    pub(crate) fn update_swap(&self, swap: &mut Swap) {
+        let tx_id = |tx: &Option<HistoryTxId>| tx.as_ref().map(|tx| tx.txid.to_string());
+        match swap {
+            Swap::Send(swap) => {
+                if let Some(data) = self.send.get(&swap.id) {
+                    swap.lockup_tx_id = tx_id(&data.lockup_tx_id);
+                    swap.refund_tx_id = tx_id(&data.refund_tx_id);
+                    swap.state = data.derive_partial_state();
+                }
+            }
+            Swap::Receive(swap) => {
+                if let Some(data) = self.receive.get(&swap.id) {
+                    swap.lockup_tx_id = tx_id(&data.lockup_tx_id);
+                    swap.claim_tx_id = tx_id(&data.claim_tx_id);
+                    swap.state = data.derive_partial_state();
+                }
+            }
+            Swap::Chain(swap) => match swap.direction {
+                Direction::Outgoing => {
+                    if let Some(data) = self.chain_send.get(&swap.id) {
+                        swap.user_lockup_tx_id = tx_id(&data.lbtc_user_lockup_tx_id);
+                        swap.refund_tx_id = tx_id(&data.lbtc_refund_tx_id);
+                        swap.server_lockup_tx_id = tx_id(&data.btc_server_lockup_tx_id);
+                        swap.claim_tx_id = tx_id(&data.btc_claim_tx_id);
+                        swap.state = data.derive_partial_state();
+                    }
+                }
+                Direction::Incoming => {
+                    if let Some(data) = self.chain_receive.get(&swap.id) {
+                        swap.server_lockup_tx_id = tx_id(&data.lbtc_server_lockup_tx_id);
+                        swap.claim_tx_id = tx_id(&data.lbtc_server_claim_tx_id);
+                        swap.user_lockup_tx_id = tx_id(&data.btc_user_lockup_tx_id);
+                        swap.refund_tx_id = tx_id(&data.btc_refund_tx_id);
+                        swap.state = data.derive_partial_state();
+                    }
+                }
+            },
+        }
+    }
+}
 
 impl LiquidSdk {
     /// For each swap, recovers data from chain services.
@@ -205,6 +266,95 @@ impl LiquidSdk {
         })
     }
 
+    /// Recovers the tx IDs and the partial state of a swap that was created outside the SDK, and
+    /// whose lockup may have been funded by another wallet.
+    pub(crate) async fn recover_imported_swap(&self, mut swap: Swap) -> Result<Swap> {
+        let raw_tx_map = self
+            .onchain_wallet
+            .transactions()
+            .await?
+            .into_iter()
+            .map(|tx| (tx.txid, tx))
+            .collect();
+        let mut tx_map = TxMap::from_raw_tx_map(raw_tx_map);
+        let swaps = match &swap {
+            Swap::Send(send_swap) => {
+                SwapsList::init(vec![send_swap.clone()], vec![], vec![], vec![])
+            }
+            Swap::Receive(receive_swap) => {
+                SwapsList::init(vec![], vec![receive_swap.clone()], vec![], vec![])
+            }
+            Swap::Chain(chain_swap) => match chain_swap.direction {
+                Direction::Outgoing => {
+                    SwapsList::init(vec![], vec![], vec![chain_swap.clone()], vec![])
+                }
+                Direction::Incoming => {
+                    SwapsList::init(vec![], vec![], vec![], vec![chain_swap.clone()])
+                }
+            },
+        }?;
+
+        // Only the Send and outgoing Chain swaps are funded by the user on Liquid
+        let lockup_script = swaps
+            .send_swap_immutable_db_by_swap_id
+            .values()
+            .map(|imm| imm.script.clone())
+            .chain(
+                swaps
+                    .send_chain_swap_immutable_db_by_swap_id
+                    .values()
+                    .map(|imm| imm.lockup_script.clone()),
+            )
+            .next();
+        if let Some(lockup_script) = lockup_script {
+            let lockup_amount_sat = match &swap {
+                Swap::Send(send_swap) => send_swap.payer_amount_sat,
+                Swap::Receive(receive_swap) => receive_swap.payer_amount_sat,
+                Swap::Chain(chain_swap) => chain_swap.payer_amount_sat,
+            };
+            if let Some(lockup_tx_id) = self
+                .find_external_lockup_tx(&lockup_script, lockup_amount_sat)
+                .await?
+            {
+                tx_map = tx_map.with_external_lockup_tx(lockup_tx_id);
+            }
+        }
+
+        self.recover_from_onchain(tx_map, swaps)
+            .await?
+            .update_swap(&mut swap);
+        Ok(swap)
+    }
+
+    /// Finds the lockup tx of an imported swap in the lockup script history. Unlike the claim
+    /// or refund txs, which spend from the lockup script, it has an output paying to the
+    /// lockup script. If the output amount is not blinded, it must match the swap amount.
+    async fn find_external_lockup_tx(
+        &self,
+        lockup_script: &lwk_wollet::elements::Script,
+        lockup_amount_sat: u64,
+    ) -> Result<Option<Txid>> {
+        let liquid_chain_service = self.liquid_chain_service.lock().await;
+        let history = liquid_chain_service
+            .get_script_history(lockup_script)
+            .await?;
+        let txids: Vec<Txid> = history.iter().map(|tx| tx.txid).collect();
+        let lockup_tx = liquid_chain_service
+            .get_transactions(&txids)
+            .await?
+            .into_iter()
+            .find(|tx| {
+                tx.output.iter().any(|out| {
+                    &out.script_pubkey == lockup_script
+                        && out
+                            .value
+                            .explicit()
+                            .map_or(true, |value| value == lockup_amount_sat)
+                })
+            });
+        Ok(lockup_tx.map(|tx| tx.txid()))
+    }
+
     /// Reconstruct Send Swap tx IDs from the onchain data and the immutable DB data
     async fn recover_send_swap_tx_ids(
         &self,
@@ -214,10 +364,7 @@ impl LiquidSdk {
         let mut res: HashMap<String, RecoveredOnchainDataSend> = HashMap::new();
         for (swap_id, history) in send_histories_by_swap_id {
             // If a history tx is one of our outgoing txs, it's a lockup tx
-            let lockup_tx_id = history
-                .iter()
-                .find(|&tx| tx_map.outgoing_tx_map.contains_key::<Txid>(&tx.txid))
-                .cloned();
+            let lockup_tx_id = tx_map.find_lockup_tx(&history);
             if lockup_tx_id.is_none() {
                 error!("No lockup tx found when recovering data for Send Swap {swap_id}");
             }
@@ -232,6 +379,7 @@ impl LiquidSdk {
             let claim_tx_id = history
                 .iter()
                 .filter(|&tx| !tx_map.incoming_tx_map.contains_key::<Txid>(&tx.txid))
+                .filter(|&tx| lockup_tx_id.as_ref().map(|l| l.txid) != Some(tx.txid))
                 .find(|&tx| !tx_map.outgoing_tx_map.contains_key::<Txid>(&tx.txid))
                 .cloned();
 
@@ -324,11 +472,7 @@ impl LiquidSdk {
             info!("[Recover Chain Send] Checking swap {swap_id}");
 
             // If a history tx is one of our outgoing txs, it's a lockup tx
-            let lbtc_user_lockup_tx_id = history
-                .lbtc_lockup_script_history
-                .iter()
-                .find(|&tx| tx_map.outgoing_tx_map.contains_key::<Txid>(&tx.txid))
-                .cloned();
+            let lbtc_user_lockup_tx_id = tx_map.find_lockup_tx(&history.lbtc_lockup_script_history);
             if lbtc_user_lockup_tx_id.is_none() {
                 error!("No lockup tx found when recovering data for Chain Send Swap {swap_id}");
             }
@@ -559,7 +703,7 @@ pub(crate) mod immutable {
     pub(crate) struct SendChainSwapImmutableData {
         swap_id: String,
         lockup_swap_script: LBtcSwapScript,
-        lockup_script: LBtcScript,
+        pub(crate) lockup_script: LBtcScript,
         claim_swap_script: BtcSwapScript,
         pub(crate) claim_script: BtcScript,
     }
@@ -596,7 +740,7 @@ pub(crate) mod immutable {
     }
 
     impl SwapsList {
-        fn init(
+        pub(crate) fn init(
             send_swaps: Vec<SendSwap>,
             receive_swaps: Vec<ReceiveSwap>,
             send_chain_swaps: Vec<ChainSwap>,
@@ -995,7 +1139,8 @@ pub(crate) mod immutable {
                         .iter()
                         .map(|x| x.as_script())
                         .collect::<Vec<&lwk_wollet::bitcoin::Script>>(),
-                )?;
+                )
+                .await?;
             let btx_script_tx_ids: Vec<lwk_wollet::bitcoin::Txid> = btc_script_histories
                 .iter()
                 .flatten()
@@ -1020,7 +1165,8 @@ pub(crate) mod immutable {
                 .bitcoin_chain_service
                 .lock()
                 .await
-                .get_transactions(&btx_script_tx_ids)?;
+                .get_transactions(&btx_script_tx_ids)
+                .await?;
             let btc_script_to_txs_map: HashMap<BtcScript, Vec<boltz_client::bitcoin::Transaction>> =
                 swap_btc_scripts
                     .into_iter()
@@ -1,19 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Instant;
-use std::{fs, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    fs,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
 use boltz_client::{swaps::boltz::*, util::secrets::Preimage};
 use buy::{BuyBitcoinApi, BuyBitcoinService};
-use chain::bitcoin::HybridBitcoinChainService;
-use chain::liquid::{HybridLiquidChainService, LiquidChainService};
+use chain::bitcoin::new_bitcoin_chain_service;
+use chain::liquid::{new_liquid_chain_service, LiquidChainService};
 use chain_swap::ESTIMATED_BTC_CLAIM_TX_VSIZE;
 use futures_util::stream::select_all;
-use futures_util::{StreamExt, TryFutureExt};
+use futures_util::{Stream, StreamExt, TryFutureExt};
 use lnurl::auth::SdkLnurlAuthSigner;
 use log::{debug, error, info, warn};
 use lwk_wollet::bitcoin::base64::Engine as _;
-use lwk_wollet::elements::{AssetId, Txid};
+use lwk_wollet::elements::{encode::serialize_hex, Txid};
 use lwk_wollet::elements_miniscript::elements::bitcoin::bip32::Xpub;
 use lwk_wollet::hashes::{sha256, Hash};
 use lwk_wollet::secp256k1::ThirtyTwoByteHash;
@@ -29,16 +35,20 @@ use tokio_stream::wrappers::BroadcastStream;
 use x509_parser::parse_x509_certificate;
 
 use crate::chain::bitcoin::BitcoinChainService;
+use crate::chain::{classify_broadcast_error, BroadcastRejection};
 use crate::chain_swap::ChainSwapHandler;
+use crate::diagnostics::{Diagnostics, SwapStatusUpdate, DIAGNOSTICS_SWAP_UPDATES};
 use crate::ensure_sdk;
 use crate::error::SdkError;
 use crate::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
 use crate::model::PaymentState::*;
 use crate::model::Signer;
 use crate::receive_swap::ReceiveSwapHandler;
+use crate::rescue::RescueFile;
 use crate::send_swap::SendSwapHandler;
 use crate::swapper::{boltz::BoltzSwapper, Swapper, SwapperReconnectHandler, SwapperStatusStream};
 use crate::wallet::{LiquidOnchainWallet, OnchainWallet};
+use crate::webhook::WebhookDispatcher;
 use crate::{
     error::{PaymentError, SdkResult},
     event::EventManager,
@@ -52,6 +62,36 @@ use ::lightning::offers::offer::Offer;
 pub const DEFAULT_DATA_DIR: &str = ".data";
 /// Number of blocks to monitor a swap after its timeout block height
 pub const CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS: u32 = 4320;
+/// Number of blocks before the timeout of a Bitcoin swap lockup from which
+/// [SdkEvent::SwapNearingTimeout] is emitted (~1 hour)
+pub const SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS: u32 = 6;
+/// Number of blocks before the timeout of a Liquid swap lockup from which
+/// [SdkEvent::SwapNearingTimeout] is emitted (~1 hour)
+pub const SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS: u32 = 60;
+/// Number of seconds the persisted [SdkEvent]s are kept in the event log (30 days)
+pub const EVENT_LOG_RETENTION_SECS: u32 = 30 * 24 * 60 * 60;
+/// Maximum number of events returned by a single [LiquidSdk::events_since] call
+pub const EVENT_LOG_PAGE_SIZE: u32 = 500;
+/// How often the rates of [Config::fiat_rate_history_currencies] are persisted
+const FIAT_RATE_HISTORY_INTERVAL_SECS: u64 = 60 * 60;
+/// Number of seconds the persisted fiat rates are kept (1 year)
+pub const FIAT_RATE_HISTORY_RETENTION_SECS: u32 = 365 * 24 * 60 * 60;
+/// Maximum time between a persisted fiat rate and the timestamp it's looked up for.
+/// See [LiquidSdk::get_historical_fiat_rate]
+pub const FIAT_RATE_HISTORY_MAX_DISTANCE_SECS: u32 = 24 * 60 * 60;
+/// Maximum age of a persisted fiat rate used to convert a fiat amount when rates can't be fetched
+const FIAT_RATE_MAX_AGE_SECS: u32 = 60 * 60;
+/// Maximum time a queued direct Liquid payment is retried before it's dropped (1 day)
+const OUTBOX_TX_MAX_AGE_SECS: u32 = 24 * 60 * 60;
+/// Maximum number of [SdkEvent::SyncProgress] events emitted by a single sync
+const SYNC_PROGRESS_MAX_EVENTS: u32 = 20;
+
+/// The last known block heights of the Liquid and Bitcoin chains
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct ChainTips {
+    liquid: Option<u32>,
+    bitcoin: Option<u32>,
+}
 
 pub struct LiquidSdk {
     pub(crate) config: Config,
@@ -61,8 +101,6 @@ pub struct LiquidSdk {
     pub(crate) event_manager: Arc<EventManager>,
     pub(crate) status_stream: Arc<dyn SwapperStatusStream>,
     pub(crate) swapper: Arc<dyn Swapper>,
-    // TODO: Remove field if unnecessary
-    #[allow(dead_code)]
     pub(crate) liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
     pub(crate) bitcoin_chain_service: Arc<Mutex<dyn BitcoinChainService>>,
     pub(crate) fiat_api: Arc<dyn FiatAPI>,
@@ -73,6 +111,11 @@ pub struct LiquidSdk {
     pub(crate) receive_swap_handler: ReceiveSwapHandler,
     pub(crate) chain_swap_handler: Arc<ChainSwapHandler>,
     pub(crate) buy_bitcoin_service: Arc<dyn BuyBitcoinApi>,
+    pub(crate) webhook_dispatcher: Arc<WebhookDispatcher>,
+    /// The last known usable balance, used to emit [SdkEvent::BalanceChanged]
+    pub(crate) last_balance_sat: RwLock<Option<u64>>,
+    /// The most recent swapper status updates, included in [LiquidSdk::generate_diagnostics]
+    pub(crate) recent_swap_updates: RwLock<VecDeque<SwapStatusUpdate>>,
 }
 
 impl LiquidSdk {
@@ -99,15 +142,19 @@ impl LiquidSdk {
         req: ConnectWithSignerRequest,
         signer: Box<dyn Signer>,
     ) -> Result<Arc<LiquidSdk>> {
-        let maybe_swapper_proxy_url =
-            match BreezServer::new("https://bs1.breez.technology:443".into(), None) {
-                Ok(breez_server) => breez_server
-                    .fetch_boltz_swapper_urls()
-                    .await
-                    .ok()
-                    .and_then(|swapper_urls| swapper_urls.first().cloned()),
-                Err(_) => None,
-            };
+        let maybe_swapper_proxy_url = match (&req.config.network, &req.config.swapper_url) {
+            (LiquidNetwork::Mainnet, None) => {
+                match BreezServer::new("https://bs1.breez.technology:443".into(), None) {
+                    Ok(breez_server) => breez_server
+                        .fetch_boltz_swapper_urls()
+                        .await
+                        .ok()
+                        .and_then(|swapper_urls| swapper_urls.first().cloned()),
+                    Err(_) => None,
+                }
+            }
+            _ => None,
+        };
         let sdk = LiquidSdk::new(req.config, maybe_swapper_proxy_url, Arc::new(signer))?;
         sdk.start()
             .inspect_err(|e| error!("Failed to start an SDK instance: {:?}", e))
@@ -115,12 +162,17 @@ impl LiquidSdk {
         Ok(sdk)
     }
 
-    fn validate_api_key(api_key: &str) -> Result<()> {
+    fn validate_api_key(api_key: &str) -> Result<(), SdkError> {
         let api_key_decoded = lwk_wollet::bitcoin::base64::engine::general_purpose::STANDARD
             .decode(api_key.as_bytes())
-            .map_err(|err| anyhow!("Could not base64 decode the Breez API key: {err:?}"))?;
-        let (_rem, cert) = parse_x509_certificate(&api_key_decoded)
-            .map_err(|err| anyhow!("Invaid certificate for Breez API key: {err:?}"))?;
+            .map_err(|err| {
+                SdkError::validation(format!(
+                    "Could not base64 decode the Breez API key: {err:?}"
+                ))
+            })?;
+        let (_rem, cert) = parse_x509_certificate(&api_key_decoded).map_err(|err| {
+            SdkError::validation(format!("Invaid certificate for Breez API key: {err:?}"))
+        })?;
 
         let issuer = cert
             .issuer()
@@ -130,10 +182,10 @@ impl LiquidSdk {
         match issuer {
             Some(common_name) => ensure_sdk!(
                 common_name.starts_with("Breez"),
-                anyhow!("Invalid certificate found for Breez API key: issuer mismatch. Please confirm that the certificate's origin is trusted")
+                SdkError::validation("Invalid certificate found for Breez API key: issuer mismatch. Please confirm that the certificate's origin is trusted")
             ),
             _ => {
-                return Err(anyhow!("Could not parse Breez API key certificate: issuer is invalid or not found."))
+                return Err(SdkError::validation("Could not parse Breez API key certificate: issuer is invalid or not found."))
             }
         }
 
@@ -148,10 +200,13 @@ impl LiquidSdk {
         match (config.network, &config.breez_api_key) {
             (_, Some(api_key)) => Self::validate_api_key(api_key)?,
             (LiquidNetwork::Mainnet, None) => {
-                return Err(anyhow!("Breez API key must be provided on mainnet."));
+                return Err(
+                    SdkError::validation("Breez API key must be provided on mainnet.").into(),
+                );
             }
-            (LiquidNetwork::Testnet, None) => {}
+            (LiquidNetwork::Testnet | LiquidNetwork::Regtest, None) => {}
         };
+        config.validate()?;
 
         fs::create_dir_all(&config.working_dir)?;
         let fingerprint_hex: String =
@@ -172,7 +227,7 @@ impl LiquidSdk {
             signer.clone(),
         )?);
 
-        let event_manager = Arc::new(EventManager::new());
+        let event_manager = Arc::new(EventManager::new(persister.clone()));
         let (shutdown_sender, shutdown_receiver) = watch::channel::<()>(());
 
         if let Some(swapper_proxy_url) = swapper_proxy_url {
@@ -182,10 +237,8 @@ impl LiquidSdk {
         let swapper = Arc::new(BoltzSwapper::new(config.clone(), cached_swapper_proxy_url));
         let status_stream = Arc::<dyn SwapperStatusStream>::from(swapper.create_status_stream());
 
-        let liquid_chain_service =
-            Arc::new(Mutex::new(HybridLiquidChainService::new(config.clone())?));
-        let bitcoin_chain_service =
-            Arc::new(Mutex::new(HybridBitcoinChainService::new(config.clone())?));
+        let liquid_chain_service = new_liquid_chain_service(&config)?;
+        let bitcoin_chain_service = new_bitcoin_chain_service(&config)?;
 
         let send_swap_handler = SendSwapHandler::new(
             config.clone(),
@@ -193,6 +246,7 @@ impl LiquidSdk {
             persister.clone(),
             swapper.clone(),
             liquid_chain_service.clone(),
+            event_manager.clone(),
         );
 
         let receive_swap_handler = ReceiveSwapHandler::new(
@@ -201,6 +255,7 @@ impl LiquidSdk {
             persister.clone(),
             swapper.clone(),
             liquid_chain_service.clone(),
+            event_manager.clone(),
         );
 
         let chain_swap_handler = Arc::new(ChainSwapHandler::new(
@@ -210,6 +265,7 @@ impl LiquidSdk {
             swapper.clone(),
             liquid_chain_service.clone(),
             bitcoin_chain_service.clone(),
+            event_manager.clone(),
         )?);
 
         let breez_server = Arc::new(BreezServer::new(PRODUCTION_BREEZSERVER_URL.into(), None)?);
@@ -217,6 +273,13 @@ impl LiquidSdk {
         let buy_bitcoin_service =
             Arc::new(BuyBitcoinService::new(config.clone(), breez_server.clone()));
 
+        let webhook_dispatcher = Arc::new(WebhookDispatcher::new(
+            config.event_webhook_urls.clone().unwrap_or_default(),
+            persister.clone(),
+            signer.clone(),
+            event_manager.clone(),
+        ));
+
         let sdk = Arc::new(LiquidSdk {
             config: config.clone(),
             onchain_wallet,
@@ -235,6 +298,9 @@ impl LiquidSdk {
             receive_swap_handler,
             chain_swap_handler,
             buy_bitcoin_service,
+            webhook_dispatcher,
+            last_balance_sat: RwLock::new(None),
+            recent_swap_updates: RwLock::new(VecDeque::new()),
         });
         Ok(sdk)
     }
@@ -250,6 +316,12 @@ impl LiquidSdk {
         self.persister
             .update_send_swaps_by_state(Created, TimedOut)
             .inspect_err(|e| error!("Failed to update send swaps by state: {:?}", e))?;
+        self.persister
+            .prune_event_log(utils::now().saturating_sub(EVENT_LOG_RETENTION_SECS))
+            .inspect_err(|e| error!("Failed to prune event log: {:?}", e))?;
+        self.persister
+            .prune_fiat_rates(utils::now().saturating_sub(FIAT_RATE_HISTORY_RETENTION_SECS))
+            .inspect_err(|e| error!("Failed to prune fiat rates: {:?}", e))?;
 
         self.start_background_tasks()
             .inspect_err(|e| error!("Failed to start background tasks: {:?}", e))
@@ -268,12 +340,16 @@ impl LiquidSdk {
         // Periodically run sync() in the background
         let sdk_clone = self.clone();
         let mut shutdown_rx_sync_loop = self.shutdown_receiver.clone();
+        let sync_interval = Duration::from_secs(self.config.scheduler_intervals().sync_secs);
         tokio::spawn(async move {
             loop {
+                if let Err(e) = sdk_clone.broadcast_outbox_txs().await {
+                    warn!("Could not broadcast queued txs: {e:?}");
+                }
                 _ = sdk_clone.sync().await;
 
                 tokio::select! {
-                    _ = tokio::time::sleep(Duration::from_secs(30)) => {}
+                    _ = tokio::time::sleep(sync_interval) => {}
                     _ = shutdown_rx_sync_loop.changed() => {
                         info!("Received shutdown signal, exiting periodic sync loop");
                         return;
@@ -285,6 +361,7 @@ impl LiquidSdk {
         let reconnect_handler = Box::new(SwapperReconnectHandler::new(
             self.persister.clone(),
             self.status_stream.clone(),
+            self.event_manager.clone(),
         ));
         self.status_stream
             .clone()
@@ -296,6 +373,12 @@ impl LiquidSdk {
             .await;
         self.track_swap_updates().await;
         self.track_pending_swaps().await;
+        self.track_new_blocks().await;
+        self.track_fiat_rates().await;
+        self.webhook_dispatcher
+            .clone()
+            .start(self.shutdown_receiver.clone())
+            .await;
 
         Ok(())
     }
@@ -347,22 +430,10 @@ impl LiquidSdk {
                     update = updates_stream.recv() => match update {
                         Ok(update) => {
                             let id = &update.id;
-                            match cloned.persister.fetch_swap_by_id(id) {
-                                Ok(Swap::Send(_)) => match cloned.send_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Send Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Send Swap {id} update: {e}")
-                                },
-                                Ok(Swap::Receive(_)) => match cloned.receive_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Receive Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Receive Swap {id} update: {e}")
-                                },
-                                Ok(Swap::Chain(_)) => match cloned.chain_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Chain Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Chain Swap {id} update: {e}")
-                                },
-                                _ => {
-                                    error!("Could not find Swap {id}");
-                                }
+                            cloned.record_swap_update(&update).await;
+                            match cloned.handle_swap_update(&update, None).await {
+                                Ok(_) => info!("Successfully handled Swap {id} update"),
+                                Err(e) => error!("Failed to handle Swap {id} update: {e}"),
                             }
                         }
                         Err(e) => error!("Received stream error: {e:?}"),
@@ -376,11 +447,47 @@ impl LiquidSdk {
         });
     }
 
+    async fn record_swap_update(&self, update: &boltz_client::swaps::boltz::Update) {
+        let mut recent_swap_updates = self.recent_swap_updates.write().await;
+        if recent_swap_updates.len() >= DIAGNOSTICS_SWAP_UPDATES {
+            recent_swap_updates.pop_front();
+        }
+        recent_swap_updates.push_back(SwapStatusUpdate {
+            swap_id: update.id.clone(),
+            status: update.status.clone(),
+            received_at: utils::now(),
+        });
+    }
+
+    /// Handles a swap status update. The lockup txs it reports are looked up until the
+    /// `verify_deadline`, if any.
+    async fn handle_swap_update(
+        &self,
+        update: &Update,
+        verify_deadline: Option<Instant>,
+    ) -> Result<()> {
+        match self.persister.fetch_swap_by_id(&update.id)? {
+            Swap::Send(_) => self.send_swap_handler.on_new_status(update).await,
+            Swap::Receive(_) => {
+                self.receive_swap_handler
+                    .on_new_status(update, verify_deadline)
+                    .await
+            }
+            Swap::Chain(_) => {
+                self.chain_swap_handler
+                    .on_new_status(update, verify_deadline)
+                    .await
+            }
+        }
+    }
+
     async fn track_pending_swaps(self: &Arc<LiquidSdk>) {
         let cloned = self.clone();
         tokio::spawn(async move {
             let mut shutdown_receiver = cloned.shutdown_receiver.clone();
-            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            let mut interval = tokio::time::interval(Duration::from_secs(
+                cloned.config.scheduler_intervals().refunds_secs,
+            ));
             interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
             loop {
                 tokio::select! {
@@ -401,6 +508,206 @@ impl LiquidSdk {
         });
     }
 
+    /// Periodically persists the rates of [Config::fiat_rate_history_currencies], so that the
+    /// fiat value of past payments is available offline. See [LiquidSdk::get_historical_fiat_rate]
+    async fn track_fiat_rates(self: &Arc<LiquidSdk>) {
+        let cloned = self.clone();
+        tokio::spawn(async move {
+            let mut shutdown_receiver = cloned.shutdown_receiver.clone();
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(FIAT_RATE_HISTORY_INTERVAL_SECS));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(err) = cloned.persist_fiat_rates().await {
+                            warn!("Could not persist fiat rates: {err:?}");
+                        }
+                    },
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting fiat rates loop");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn persist_fiat_rates(&self) -> Result<()> {
+        let currencies = self.config.fiat_rate_history_currencies();
+        let rates: Vec<Rate> = self
+            .fiat_api
+            .fetch_fiat_rates()
+            .await?
+            .into_iter()
+            .filter(|rate| currencies.contains(&rate.coin))
+            .collect();
+        self.persister.insert_fiat_rates(&rates, utils::now())
+    }
+
+    /// Polls the chain tips and, whenever a new block is found, emits [SdkEvent::NewBlock] and
+    /// checks the ongoing swaps against their timeout block height. See [LiquidSdk::check_swap_timeouts].
+    async fn track_new_blocks(self: &Arc<LiquidSdk>) {
+        let cloned = self.clone();
+        tokio::spawn(async move {
+            let mut shutdown_receiver = cloned.shutdown_receiver.clone();
+            let mut interval = tokio::time::interval(Duration::from_secs(
+                cloned.config.scheduler_intervals().new_blocks_secs,
+            ));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let mut last_tips = ChainTips::default();
+            let mut last_checked_tips = ChainTips::default();
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        // Keep the last known tip of a chain if it can't be fetched
+                        let tips = ChainTips {
+                            liquid: cloned
+                                .liquid_chain_service
+                                .lock()
+                                .await
+                                .tip()
+                                .await
+                                .ok()
+                                .or(last_tips.liquid),
+                            bitcoin: cloned
+                                .bitcoin_chain_service
+                                .lock()
+                                .await
+                                .tip()
+                                .await
+                                .ok()
+                                .map(|header| header.height as u32)
+                                .or(last_tips.bitcoin),
+                        };
+                        if tips != last_tips {
+                            cloned.emit_new_blocks(&last_tips, &tips).await;
+                            last_tips = tips;
+                        }
+                        if tips == last_checked_tips {
+                            continue;
+                        }
+                        match cloned.check_swap_timeouts(&last_checked_tips, &tips).await {
+                            Ok(_) => last_checked_tips = tips,
+                            Err(err) => warn!("Could not check swap timeouts, error: {err:?}"),
+                        }
+                    },
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting new blocks loop");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn emit_new_blocks(&self, last_tips: &ChainTips, tips: &ChainTips) {
+        for (chain, last_tip, tip) in [
+            (Blockchain::Liquid, last_tips.liquid, tips.liquid),
+            (Blockchain::Bitcoin, last_tips.bitcoin, tips.bitcoin),
+        ] {
+            if let Some(height) = tip {
+                if last_tip != tip {
+                    self.event_manager
+                        .notify(SdkEvent::NewBlock { chain, height })
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Checks the ongoing swaps with a lockup against the new chain tips:
+    /// - emits [SdkEvent::SwapNearingTimeout] on each new block within the last
+    ///   [SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS] or [SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS] blocks
+    /// - emits [SdkEvent::SwapRefundable] once the timeout block height is reached, and
+    ///   triggers the refund tracking without waiting for the next periodic run
+    async fn check_swap_timeouts(&self, last_tips: &ChainTips, tips: &ChainTips) -> Result<()> {
+        let mut has_timed_out_swaps = false;
+        for swap in self.persister.list_ongoing_swaps()? {
+            let (timeout_block_height, last_tip, tip, nearing_timeout_blocks) = match &swap {
+                Swap::Send(send_swap) if send_swap.lockup_tx_id.is_some() => {
+                    let swap_script = match send_swap.get_swap_script() {
+                        Ok(swap_script) => swap_script,
+                        Err(e) => {
+                            warn!(
+                                "Failed to get swap script of Send Swap {}, skipping timeout check: {e:?}",
+                                send_swap.id
+                            );
+                            continue;
+                        }
+                    };
+                    (
+                        swap_script.locktime.to_consensus_u32(),
+                        last_tips.liquid,
+                        tips.liquid,
+                        SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS,
+                    )
+                }
+                Swap::Chain(chain_swap) if chain_swap.user_lockup_tx_id.is_some() => {
+                    match chain_swap.direction {
+                        Direction::Incoming => (
+                            chain_swap.timeout_block_height,
+                            last_tips.bitcoin,
+                            tips.bitcoin,
+                            SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS,
+                        ),
+                        Direction::Outgoing => (
+                            chain_swap.timeout_block_height,
+                            last_tips.liquid,
+                            tips.liquid,
+                            SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS,
+                        ),
+                    }
+                }
+                _ => continue,
+            };
+            // The first known tip of a chain only sets the starting point. Swaps which timed out
+            // or were nearing their timeout before then were already reported by a previous run,
+            // or are picked up by the periodic refund tracking.
+            let (Some(last_tip), Some(tip)) = (last_tip, tip) else {
+                continue;
+            };
+            if last_tip == tip {
+                continue;
+            }
+
+            let swap_id = swap.id();
+            match timeout_block_height.saturating_sub(tip) {
+                0 => {
+                    if last_tip < timeout_block_height {
+                        info!("Swap {swap_id} reached its timeout block height {timeout_block_height}");
+                        has_timed_out_swaps = true;
+                        self.notify_event_listeners(SdkEvent::SwapRefundable { swap_id })
+                            .await?;
+                    }
+                }
+                blocks_left if blocks_left <= nearing_timeout_blocks => {
+                    debug!("Swap {swap_id} is {blocks_left} blocks away from its timeout");
+                    self.notify_event_listeners(SdkEvent::SwapNearingTimeout {
+                        swap_id,
+                        blocks_left,
+                    })
+                    .await?;
+                }
+                _ => {}
+            }
+        }
+
+        if has_timed_out_swaps {
+            if let Err(err) = self.send_swap_handler.track_refunds().await {
+                warn!("Could not refund expired swaps, error: {err:?}");
+            }
+            if let Err(err) = self
+                .chain_swap_handler
+                .track_refunds_and_refundables()
+                .await
+            {
+                warn!("Could not refund expired swaps, error: {err:?}");
+            }
+        }
+        Ok(())
+    }
+
     async fn notify_event_listeners(&self, e: SdkEvent) -> Result<()> {
         self.event_manager.notify(e).await;
         Ok(())
@@ -426,6 +733,140 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Returns the persisted [SdkEvent]s emitted after the given sequence number, ordered by
+    /// sequence number. Together with [LiquidSdk::ack_events] this allows a consumer to process
+    /// every event at least once, including the ones emitted while it was not running.
+    ///
+    /// At most [EVENT_LOG_PAGE_SIZE] events are returned. To get the next page, call it again
+    /// with the sequence number of the last returned event, until an empty list is returned.
+    ///
+    /// Events are kept for [EVENT_LOG_RETENTION_SECS]. Transient [SdkEvent::SyncProgress] and
+    /// [SdkEvent::NewBlock] events are not persisted.
+    ///
+    /// # Arguments
+    ///
+    /// * `seq` - the sequence number of the last processed event, or 0 to list all events
+    pub fn events_since(&self, seq: u64) -> SdkResult<Vec<EventLogEntry>> {
+        Ok(self.persister.list_events_since(seq, EVENT_LOG_PAGE_SIZE)?)
+    }
+
+    /// Stores the sequence number of the last event processed by a listener.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener_id` - a consumer chosen id, stable across restarts
+    /// * `seq` - the sequence number of the last processed [EventLogEntry]
+    pub fn ack_events(&self, listener_id: &str, seq: u64) -> SdkResult<()> {
+        Ok(self.persister.set_event_cursor(listener_id, seq)?)
+    }
+
+    /// Returns the sequence number last acknowledged by a listener via [LiquidSdk::ack_events],
+    /// to be passed to [LiquidSdk::events_since] when resuming.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener_id` - the id used when calling [LiquidSdk::ack_events]
+    pub fn get_event_cursor(&self, listener_id: &str) -> SdkResult<Option<u64>> {
+        Ok(self.persister.get_event_cursor(listener_id)?)
+    }
+
+    /// Returns the hex encoded secret used to sign the payloads sent to the
+    /// [Config::event_webhook_urls]. The secret is derived from the [Signer].
+    ///
+    /// Each payload is a JSON serialized [EventLogEntry], POSTed with the following headers:
+    /// * `X-Breez-Signature` - the hex encoded HMAC-SHA256 of the request body, keyed with the secret
+    /// * `X-Breez-Delivery-Id` - the [WebhookDelivery::id], which is the same for all retries
+    pub fn get_webhook_signing_secret(&self) -> SdkResult<String> {
+        Ok(hex::encode(self.webhook_dispatcher.signing_secret()?))
+    }
+
+    /// Lists the webhook deliveries which failed after all their retries
+    pub fn list_failed_webhook_deliveries(&self) -> SdkResult<Vec<WebhookDelivery>> {
+        Ok(self.persister.list_failed_webhook_deliveries()?)
+    }
+
+    /// Retries a failed webhook delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the [WebhookDelivery::id] returned by [LiquidSdk::list_failed_webhook_deliveries]
+    pub fn retry_webhook_delivery(&self, id: u64) -> SdkResult<()> {
+        Ok(self.persister.reset_failed_webhook_delivery(id)?)
+    }
+
+    /// Returns a stream of the [SdkEvent]s emitted from now on, as an alternative to
+    /// [LiquidSdk::add_event_listener]. Events are dropped when the stream falls too far behind.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - the [SdkEventFilter] containing:
+    ///     * `payment_id` - only emit events related to this payment tx id or swap id
+    ///     * `kinds` - only emit events of these [SdkEventKind]s
+    pub fn subscribe_events(&self, filter: SdkEventFilter) -> impl Stream<Item = SdkEvent> {
+        BroadcastStream::new(self.event_manager.subscribe()).filter_map(move |res| {
+            let event = match res {
+                Ok(event) => filter.matches(&event).then_some(event),
+                Err(e) => {
+                    warn!("Event stream lagged behind: {e:?}");
+                    None
+                }
+            };
+            async move { event }
+        })
+    }
+
+    /// Waits until a payment reaches one of the given states.
+    ///
+    /// Returns immediately if the payment is already in one of the states.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_id` - the payment tx id or swap id
+    /// * `states` - the [PaymentState]s to wait for
+    /// * `timeout` - the maximum time to wait before failing with [PaymentError::PaymentTimeout]
+    pub async fn wait_for_payment(
+        &self,
+        payment_id: &str,
+        states: &[PaymentState],
+        timeout: Duration,
+    ) -> Result<Payment, PaymentError> {
+        self.ensure_is_started().await?;
+
+        // Subscribe before looking up the payment, so no update is missed in between
+        let events_stream = self.subscribe_events(SdkEventFilter {
+            payment_id: Some(payment_id.to_string()),
+            kinds: None,
+        });
+        tokio::pin!(events_stream);
+
+        let maybe_payment = match self.persister.get_payment(payment_id)? {
+            Some(payment) => Some(payment),
+            None => self.persister.get_payment_by_swap_id(payment_id)?,
+        };
+        if let Some(payment) = maybe_payment {
+            if states.contains(&payment.status) {
+                return Ok(payment);
+            }
+        }
+
+        let timeout_fut = tokio::time::sleep(timeout);
+        tokio::pin!(timeout_fut);
+        loop {
+            tokio::select! {
+                _ = &mut timeout_fut => return Err(PaymentError::PaymentTimeout),
+                event = events_stream.next() => match event {
+                    Some(event) => match event.payment() {
+                        Some(payment) if states.contains(&payment.status) => {
+                            return Ok(payment.clone())
+                        }
+                        _ => debug!("Unhandled event while waiting for payment {payment_id}: {event:?}"),
+                    },
+                    None => return Err(PaymentError::generic("Event stream closed")),
+                }
+            }
+        }
+    }
+
     async fn emit_payment_updated(&self, payment_id: Option<String>) -> Result<()> {
         if let Some(id) = payment_id {
             match self.persister.get_payment(&id)? {
@@ -505,13 +946,55 @@ impl LiquidSdk {
                 }
                 None => debug!("Payment not found: {id}"),
             }
+            self.emit_balance_changed().await;
         }
         Ok(())
     }
 
+    /// Emits [SdkEvent::BalanceChanged] if the usable balance differs from the last known one.
+    ///
+    /// The event is a notification only, so failing to compute the balance is logged and doesn't
+    /// fail the caller.
+    async fn emit_balance_changed(&self) {
+        let new_balance_sat = match self.get_balances().await {
+            Ok((balance_sat, _, _)) => balance_sat,
+            Err(e) => {
+                warn!("Could not compute the balance to check for changes: {e:?}");
+                return;
+            }
+        };
+        let mut last_balance_sat = self.last_balance_sat.write().await;
+        match *last_balance_sat {
+            Some(old_balance_sat) if old_balance_sat != new_balance_sat => {
+                self.event_manager
+                    .notify(SdkEvent::BalanceChanged {
+                        old_balance_sat,
+                        new_balance_sat,
+                    })
+                    .await;
+            }
+            _ => {}
+        }
+        *last_balance_sat = Some(new_balance_sat);
+    }
+
     /// Get the wallet info, calculating the current pending and confirmed balances.
     pub async fn get_info(&self) -> Result<GetInfoResponse> {
         self.ensure_is_started().await?;
+        let (balance_sat, pending_send_sat, pending_receive_sat) = self.get_balances().await?;
+
+        Ok(GetInfoResponse {
+            balance_sat,
+            pending_send_sat,
+            pending_receive_sat,
+            fingerprint: self.onchain_wallet.fingerprint()?,
+            pubkey: self.onchain_wallet.pubkey()?,
+        })
+    }
+
+    /// Calculates the usable balance, the pending send and the pending receive amounts from the
+    /// persisted payments
+    async fn get_balances(&self) -> Result<(u64, u64, u64)> {
         let mut pending_send_sat = 0;
         let mut pending_receive_sat = 0;
         let mut confirmed_sent_sat = 0;
@@ -549,13 +1032,11 @@ impl LiquidSdk {
             }
         }
 
-        Ok(GetInfoResponse {
-            balance_sat: confirmed_received_sat - confirmed_sent_sat - pending_send_sat,
+        Ok((
+            confirmed_received_sat - confirmed_sent_sat - pending_send_sat,
             pending_send_sat,
             pending_receive_sat,
-            fingerprint: self.onchain_wallet.fingerprint()?,
-            pubkey: self.onchain_wallet.pubkey()?,
-        })
+        ))
     }
 
     /// Sign given message with the private key. Returns a zbase encoded signature.
@@ -602,6 +1083,7 @@ impl LiquidSdk {
         match (invoice.network().to_string().as_str(), self.config.network) {
             ("bitcoin", LiquidNetwork::Mainnet) => {}
             ("testnet", LiquidNetwork::Testnet) => {}
+            ("regtest", LiquidNetwork::Regtest) => {}
             _ => {
                 return Err(PaymentError::InvalidNetwork {
                     err: "Invoice cannot be paid on the current network".to_string(),
@@ -734,7 +1216,25 @@ impl LiquidSdk {
         //  https://github.com/Blockstream/lwk/issues/31
         match self.config.network {
             LiquidNetwork::Mainnet => "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj",
-            LiquidNetwork::Testnet => "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5"
+            LiquidNetwork::Testnet => "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5",
+            LiquidNetwork::Regtest => {
+                static REGTEST_TEMP_P2TR_ADDR: OnceLock<String> = OnceLock::new();
+                REGTEST_TEMP_P2TR_ADDR.get_or_init(|| {
+                    // The address is only used to estimate fees, so any confidential P2TR address works
+                    let pubkey = lwk_wollet::elements::secp256k1_zkp::PublicKey::from_str(
+                        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                    )
+                    .expect("Hardcoded pubkey is valid");
+                    lwk_wollet::elements::Address::p2tr(
+                        &lwk_wollet::elements::secp256k1_zkp::Secp256k1::verification_only(),
+                        pubkey.x_only_public_key().0,
+                        None,
+                        Some(pubkey),
+                        &lwk_wollet::elements::AddressParams::ELEMENTS,
+                    )
+                    .to_string()
+                })
+            }
         }
     }
 
@@ -805,11 +1305,13 @@ impl LiquidSdk {
     ///        when paying directly onchain or via amount-less BIP21.
     ///        - [PayAmount::Drain] which uses all funds
     ///        - [PayAmount::Receiver] which sets the amount the receiver should receive
+    ///        - [PayAmount::Fiat] which sets the amount the receiver should receive in a fiat currency
     ///
     /// # Returns
     /// Returns a [PrepareSendResponse] containing:
     ///     * `destination` - the parsed destination, of type [SendDestination]
     ///     * `fees_sat` - the additional fees which will be paid by the sender
+    ///     * `fiat_rate` - the rate used to convert a [PayAmount::Fiat] amount
     pub async fn prepare_send_payment(
         &self,
         req: &PrepareSendRequest,
@@ -817,6 +1319,13 @@ impl LiquidSdk {
         self.ensure_is_started().await?;
 
         let get_info_res = self.get_info().await?;
+        let (amount, fiat_rate) = match req.amount.clone() {
+            Some(amount) => {
+                let (amount, fiat_rate) = self.convert_fiat_pay_amount(amount).await?;
+                (Some(amount), fiat_rate)
+            }
+            None => (None, None),
+        };
         let fees_sat;
         let receiver_amount_sat;
         let payment_destination;
@@ -825,7 +1334,7 @@ impl LiquidSdk {
             Ok(InputType::LiquidAddress {
                 address: mut liquid_address_data,
             }) => {
-                let amount = match (liquid_address_data.amount_sat, req.amount.clone()) {
+                let amount = match (liquid_address_data.amount_sat, amount) {
                     (None, None) => {
                         return Err(PaymentError::AmountMissing {
                             err: "Amount must be set when paying to a Liquid address".to_string(),
@@ -873,6 +1382,9 @@ impl LiquidSdk {
                             .await?;
                         (amount_sat, fees_sat)
                     }
+                    PayAmount::Fiat { .. } => {
+                        return Err(PaymentError::generic("Fiat amount was not converted"));
+                    }
                 };
 
                 liquid_address_data.amount_sat = Some(receiver_amount_sat);
@@ -888,7 +1400,7 @@ impl LiquidSdk {
                     "Expected invoice with an amount",
                 ))? / 1000;
 
-                if let Some(PayAmount::Receiver { amount_sat }) = req.amount {
+                if let Some(PayAmount::Receiver { amount_sat }) = amount {
                     ensure_sdk!(
                         receiver_amount_sat == amount_sat,
                         PaymentError::Generic {
@@ -916,7 +1428,7 @@ impl LiquidSdk {
                 payment_destination = SendDestination::Bolt11 { invoice };
             }
             Ok(InputType::Bolt12Offer { offer }) => {
-                receiver_amount_sat = match req.amount {
+                receiver_amount_sat = match amount {
                     Some(PayAmount::Receiver { amount_sat }) => Ok(amount_sat),
                     _ => Err(PaymentError::amount_missing(
                         "Expected PayAmount of type Receiver when processing a Bolt12 offer",
@@ -958,9 +1470,86 @@ impl LiquidSdk {
         Ok(PrepareSendResponse {
             destination: payment_destination,
             fees_sat,
+            fiat_rate,
         })
     }
 
+    /// Converts a [PayAmount::Fiat] to a [PayAmount::Receiver] at the current rate, returning
+    /// the rate used. Other amounts are returned as is.
+    async fn convert_fiat_pay_amount(
+        &self,
+        amount: PayAmount,
+    ) -> Result<(PayAmount, Option<PaymentFiatRate>), PaymentError> {
+        match amount {
+            PayAmount::Fiat { currency, amount } => {
+                let fiat_rate = self.get_fiat_rate(&currency).await?;
+                let amount_sat = Self::convert_fiat_amount(&fiat_rate, amount)?;
+                Ok((PayAmount::Receiver { amount_sat }, Some(fiat_rate)))
+            }
+            amount => Ok((amount, None)),
+        }
+    }
+
+    fn convert_fiat_amount(fiat_rate: &PaymentFiatRate, amount: f64) -> Result<u64, PaymentError> {
+        ensure_sdk!(
+            amount.is_finite() && amount > 0.0,
+            PaymentError::AmountOutOfRange
+        );
+        Ok(fiat_rate.to_sat(amount))
+    }
+
+    /// Fetches the current rate of a fiat currency. If rates can't be fetched, falls back to the
+    /// last persisted rate, as long as it's recent enough.
+    async fn get_fiat_rate(&self, currency: &str) -> Result<PaymentFiatRate, PaymentError> {
+        let rate = match self.fiat_api.fetch_fiat_rates().await {
+            Ok(rates) => rates.into_iter().find(|rate| rate.coin == currency),
+            Err(e) => {
+                warn!("Could not fetch fiat rates, using the last persisted one: {e:?}");
+                self.persister
+                    .get_latest_fiat_rate(currency)?
+                    .filter(|(_, created_at)| {
+                        utils::now().saturating_sub(*created_at) <= FIAT_RATE_MAX_AGE_SECS
+                    })
+                    .map(|(rate, _)| rate)
+            }
+        };
+        match rate {
+            Some(rate) if rate.value > 0.0 => Ok(PaymentFiatRate {
+                currency: rate.coin,
+                rate: rate.value,
+            }),
+            _ => Err(PaymentError::generic(&format!(
+                "No rate available for fiat currency {currency}"
+            ))),
+        }
+    }
+
+    /// Records the fiat rate used to prepare a payment, if any
+    fn insert_payment_fiat_rate(
+        &self,
+        payment_id: &str,
+        fiat_rate: Option<&PaymentFiatRate>,
+    ) -> Result<(), PaymentError> {
+        if let Some(fiat_rate) = fiat_rate {
+            self.persister
+                .insert_payment_fiat_rate(payment_id, fiat_rate)?;
+        }
+        Ok(())
+    }
+
+    /// Records the fiat rate used to prepare a sent payment, by swap id or else by tx id
+    fn with_payment_fiat_rate(
+        &self,
+        mut payment: Payment,
+        fiat_rate: Option<&PaymentFiatRate>,
+    ) -> Result<Payment, PaymentError> {
+        if let Some(payment_id) = payment.details.get_swap_id().or(payment.tx_id.clone()) {
+            self.insert_payment_fiat_rate(&payment_id, fiat_rate)?;
+            payment.fiat_rate = fiat_rate.cloned();
+        }
+        Ok(payment)
+    }
+
     fn ensure_send_is_not_self_transfer(&self, invoice: &str) -> Result<(), PaymentError> {
         match self.persister.fetch_receive_swap_by_invoice(invoice)? {
             None => Ok(()),
@@ -975,6 +1564,10 @@ impl LiquidSdk {
     ///   complete in this time
     /// * [PaymentState::Complete] payment - if the payment was successfully completed in this time
     ///
+    /// If no Liquid chain backend can be reached, a direct Liquid payment is signed and queued
+    /// instead, and broadcast once the SDK is back online (see [SdkEvent::PaymentQueued]). New
+    /// payments fail while a queued payment is waiting to be broadcast.
+    ///
     /// # Arguments
     ///
     /// * `req` - A [SendPaymentRequest], containing:
@@ -992,9 +1585,13 @@ impl LiquidSdk {
         let PrepareSendResponse {
             fees_sat,
             destination: payment_destination,
+            fiat_rate,
         } = &req.prepare_response;
 
-        match payment_destination {
+        // Queued payments go first. New txs which spend the same funds are rejected by the wallet.
+        self.broadcast_outbox_txs().await?;
+
+        let res = match payment_destination {
             SendDestination::LiquidAddress {
                 address_data: liquid_address_data,
             } => {
@@ -1035,7 +1632,11 @@ impl LiquidSdk {
                 self.pay_bolt12_invoice(offer, *receiver_amount_sat, &bolt12_invoice, *fees_sat)
                     .await
             }
-        }
+        }?;
+
+        Ok(SendPaymentResponse {
+            payment: self.with_payment_fiat_rate(res.payment, fiat_rate.as_ref())?,
+        })
     }
 
     async fn pay_bolt11_invoice(
@@ -1145,7 +1746,22 @@ impl LiquidSdk {
         );
 
         let liquid_chain_service = self.liquid_chain_service.lock().await;
-        let tx_id = liquid_chain_service.broadcast(&tx, None).await?.to_string();
+        let is_queued = match liquid_chain_service.broadcast(&tx, None).await {
+            Ok(_) => false,
+            Err(e) => match classify_broadcast_error(&e) {
+                BroadcastRejection::AlreadyKnown => false,
+                // If no backend can be reached or accept the tx right now, the tx is queued and
+                // broadcast later
+                BroadcastRejection::Temporary => {
+                    warn!("Could not broadcast tx {tx_id}, queueing it: {e:?}");
+                    self.persister
+                        .insert_outbox_tx(&tx_id, &serialize_hex(&tx))?;
+                    true
+                }
+                BroadcastRejection::Rejected => return Err(e.into()),
+            },
+        };
+        drop(liquid_chain_service);
 
         // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
         // This makes the tx known to the SDK (get_info, list_payments) instantly
@@ -1166,31 +1782,111 @@ impl LiquidSdk {
             Some(destination.clone()),
             description.clone(),
         )?;
-        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
         let payment_details = PaymentDetails::Liquid {
             destination,
             description: description.unwrap_or("Liquid transfer".to_string()),
         };
+        let payment = Payment::from_tx_data(tx_data, None, payment_details);
 
-        Ok(SendPaymentResponse {
-            payment: Payment::from_tx_data(tx_data, None, payment_details),
-        })
+        match is_queued {
+            true => {
+                self.notify_event_listeners(SdkEvent::PaymentQueued {
+                    details: payment.clone(),
+                })
+                .await?
+            }
+            false => self.emit_payment_updated(Some(tx_id)).await?, // Emit Pending event
+        }
+
+        Ok(SendPaymentResponse { payment })
     }
 
-    /// Performs a Send Payment by doing a swap (create it, fund it, track it, etc).
+    /// Broadcasts the direct Liquid payments which were queued while offline.
     ///
-    /// If `bolt12_offer` is set, `invoice` refers to a Bolt12 invoice, otherwise it's a Bolt11 one.
-    async fn send_payment_via_swap(
-        &self,
-        invoice: &str,
-        bolt12_offer: Option<String>,
-        payment_hash: &str,
-        description: Option<String>,
-        receiver_amount_sat: u64,
-        fees_sat: u64,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
+    /// A queued tx is kept as long as the Liquid chain backends can't be reached or only
+    /// temporarily refuse it, e.g. when rate limited or when their mempool is full, for up to
+    /// [OUTBOX_TX_MAX_AGE_SECS]. It's dropped, along with its payment, once it's rejected by
+    /// consensus or policy rules or has been queued for longer, in which case a
+    /// [SdkEvent::PaymentFailed] is emitted for it.
+    async fn broadcast_outbox_txs(&self) -> Result<()> {
+        for outbox_tx in self.persister.list_outbox_txs()? {
+            let tx_id = outbox_tx.tx_id;
+            let tx = utils::deserialize_tx_hex(&outbox_tx.tx_hex)?;
+
+            let liquid_chain_service = self.liquid_chain_service.lock().await;
+            let rejection = match liquid_chain_service.broadcast(&tx, None).await {
+                Ok(_) => None,
+                Err(e) => match classify_broadcast_error(&e) {
+                    BroadcastRejection::AlreadyKnown => None,
+                    // The tx may have been broadcast before it could be removed from the outbox
+                    _ if matches!(
+                        liquid_chain_service.get_transaction_hex(&tx.txid()).await,
+                        Ok(Some(_))
+                    ) =>
+                    {
+                        None
+                    }
+                    rejection => Some((rejection, e)),
+                },
+            };
+            drop(liquid_chain_service);
+
+            let err = match rejection {
+                None => {
+                    info!("Broadcast queued tx {tx_id}");
+                    self.persister.delete_outbox_tx(&tx_id)?;
+                    self.emit_payment_updated(Some(tx_id)).await?;
+                    continue;
+                }
+                Some((BroadcastRejection::Rejected, err)) => err,
+                Some((_, err))
+                    if utils::now().saturating_sub(outbox_tx.created_at)
+                        >= OUTBOX_TX_MAX_AGE_SECS =>
+                {
+                    err
+                }
+                Some((_, err)) => {
+                    info!("Could not broadcast queued tx {tx_id} yet, keeping it: {err:?}");
+                    self.persister.update_outbox_tx_attempt(
+                        &tx_id,
+                        outbox_tx.attempts + 1,
+                        Some(err.to_string()),
+                    )?;
+                    return Ok(());
+                }
+            };
+
+            warn!("Queued tx {tx_id} was rejected or queued for too long, dropping it: {err:?}");
+            let payment = self.persister.get_payment(&tx_id)?;
+            self.persister.delete_queued_payment(&tx_id)?;
+            if let Some(payment) = payment {
+                self.notify_event_listeners(SdkEvent::PaymentFailed {
+                    details: Payment {
+                        status: Failed,
+                        ..payment
+                    },
+                })
+                .await?;
+            }
+            self.emit_balance_changed().await;
+        }
+        Ok(())
+    }
+
+    /// Performs a Send Payment by doing a swap (create it, fund it, track it, etc).
+    ///
+    /// If `bolt12_offer` is set, `invoice` refers to a Bolt12 invoice, otherwise it's a Bolt11 one.
+    async fn send_payment_via_swap(
+        &self,
+        invoice: &str,
+        bolt12_offer: Option<String>,
+        payment_hash: &str,
+        description: Option<String>,
+        receiver_amount_sat: u64,
+        fees_sat: u64,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
         let boltz_fees_total = lbtc_pair.fees.total(receiver_amount_sat);
         let user_lockup_amount_sat = receiver_amount_sat + boltz_fees_total;
         let lockup_tx_fees_sat = self
@@ -1276,7 +1972,7 @@ impl LiquidSdk {
             .try_lockup(&swap, &create_response)
             .await?;
 
-        self.wait_for_payment(Swap::Send(swap), create_response.accept_zero_conf)
+        self.wait_for_swap_payment(Swap::Send(swap), create_response.accept_zero_conf)
             .await
             .map(|payment| SendPaymentResponse { payment })
     }
@@ -1310,6 +2006,8 @@ impl LiquidSdk {
                 max_sat: receive_limits.maximal,
                 max_zero_conf_sat: self.config.zero_conf_max_amount_sat(),
             },
+            cache_age_secs: self
+                .get_pairs_cache_age_secs(&[PaymentSwapType::Send, PaymentSwapType::Receive]),
         })
     }
 
@@ -1336,16 +2034,27 @@ impl LiquidSdk {
                 max_sat: receive_limits.maximal,
                 max_zero_conf_sat: receive_limits.maximal_zero_conf,
             },
+            cache_age_secs: self.get_pairs_cache_age_secs(&[PaymentSwapType::Chain]),
         })
     }
 
+    /// The age of the oldest cached pair information among the given swap types
+    fn get_pairs_cache_age_secs(&self, swap_types: &[PaymentSwapType]) -> Option<u64> {
+        swap_types
+            .iter()
+            .filter_map(|swap_type| self.swapper.get_pairs_cache_age(swap_type.clone()))
+            .max()
+            .map(|age| age.as_secs())
+    }
+
     /// Prepares to pay to a Bitcoin address via a chain swap.
     ///
     /// # Arguments
     ///
     /// * `req` - the [PreparePayOnchainRequest] containing:
-    ///     * `amount` - which can be of two types: [PayAmount::Drain], which uses all funds,
-    ///        and [PayAmount::Receiver], which sets the amount the receiver should receive
+    ///     * `amount` - which can be of three types: [PayAmount::Drain], which uses all funds,
+    ///        [PayAmount::Receiver], which sets the amount the receiver should receive,
+    ///        and [PayAmount::Fiat], which sets it in a fiat currency
     ///     * `fee_rate_sat_per_vbyte` - the optional fee rate of the Bitcoin claim transaction. Defaults to the swapper estimated claim fee
     pub async fn prepare_pay_onchain(
         &self,
@@ -1362,7 +2071,8 @@ impl LiquidSdk {
         let server_fees_sat = pair.fees.server();
 
         info!("Preparing for onchain payment of kind: {:?}", req.amount);
-        let (payer_amount_sat, receiver_amount_sat, total_fees_sat) = match req.amount {
+        let (amount, fiat_rate) = self.convert_fiat_pay_amount(req.amount.clone()).await?;
+        let (payer_amount_sat, receiver_amount_sat, total_fees_sat) = match amount {
             PayAmount::Receiver { amount_sat } => {
                 let receiver_amount_sat = amount_sat;
 
@@ -1407,12 +2117,16 @@ impl LiquidSdk {
 
                 (payer_amount_sat, receiver_amount_sat, total_fees_sat)
             }
+            PayAmount::Fiat { .. } => {
+                return Err(PaymentError::generic("Fiat amount was not converted"));
+            }
         };
 
         let res = PreparePayOnchainResponse {
             receiver_amount_sat,
             claim_fees_sat,
             total_fees_sat,
+            fiat_rate,
         };
 
         ensure_sdk!(
@@ -1446,6 +2160,8 @@ impl LiquidSdk {
     ) -> Result<SendPaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
         info!("Paying onchain, request = {req:?}");
+        // Queued payments go first. New txs which spend the same funds are rejected by the wallet.
+        self.broadcast_outbox_txs().await?;
 
         let claim_address = self.validate_bitcoin_address(&req.address).await?;
         let balance_sat = self.get_info().await?.balance_sat;
@@ -1549,14 +2265,15 @@ impl LiquidSdk {
             state: PaymentState::Created,
         };
         self.persister.insert_chain_swap(&swap)?;
+        self.insert_payment_fiat_rate(&swap_id, req.prepare_response.fiat_rate.as_ref())?;
         self.status_stream.track_swap_id(&swap_id)?;
 
-        self.wait_for_payment(Swap::Chain(swap), accept_zero_conf)
+        self.wait_for_swap_payment(Swap::Chain(swap), accept_zero_conf)
             .await
             .map(|payment| SendPaymentResponse { payment })
     }
 
-    async fn wait_for_payment(
+    async fn wait_for_swap_payment(
         &self,
         swap: Swap,
         accept_zero_conf: bool,
@@ -1565,7 +2282,14 @@ impl LiquidSdk {
         tokio::pin!(timeout_fut);
 
         let expected_swap_id = swap.id();
-        let mut events_stream = self.event_manager.subscribe();
+        let events_stream = self.subscribe_events(SdkEventFilter {
+            payment_id: Some(expected_swap_id.clone()),
+            kinds: Some(vec![
+                SdkEventKind::PaymentPending,
+                SdkEventKind::PaymentSucceeded,
+            ]),
+        });
+        tokio::pin!(events_stream);
         let mut maybe_payment: Option<Payment> = None;
 
         loop {
@@ -1582,31 +2306,25 @@ impl LiquidSdk {
                         return Err(PaymentError::PaymentTimeout)
                     },
                 },
-                event = events_stream.recv() => match event {
-                    Ok(SdkEvent::PaymentPending { details: payment }) => {
-                        let maybe_payment_swap_id = payment.details.get_swap_id();
-                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
-                            match accept_zero_conf {
-                                true => {
-                                    debug!("Received Send Payment pending event with zero-conf accepted");
-                                    return Ok(payment)
-                                }
-                                false => {
-                                    debug!("Received Send Payment pending event, waiting for confirmation");
-                                    maybe_payment = Some(payment);
-                                }
+                event = events_stream.next() => match event {
+                    Some(SdkEvent::PaymentPending { details: payment }) => {
+                        match accept_zero_conf {
+                            true => {
+                                debug!("Received Send Payment pending event with zero-conf accepted");
+                                return Ok(payment)
+                            }
+                            false => {
+                                debug!("Received Send Payment pending event, waiting for confirmation");
+                                maybe_payment = Some(payment);
                             }
-                        };
-                    },
-                    Ok(SdkEvent::PaymentSucceeded { details: payment }) => {
-                        let maybe_payment_swap_id = payment.details.get_swap_id();
-                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
-                            debug!("Received Send Payment succeed event");
-                            return Ok(payment);
                         }
                     },
-                    Ok(event) => debug!("Unhandled event: {event:?}"),
-                    Err(e) => debug!("Received error waiting for event: {e:?}"),
+                    Some(SdkEvent::PaymentSucceeded { details: payment }) => {
+                        debug!("Received Send Payment succeed event");
+                        return Ok(payment);
+                    },
+                    Some(event) => debug!("Unhandled event: {event:?}"),
+                    None => debug!("Event stream closed while waiting for payment"),
                 }
             }
         }
@@ -1618,6 +2336,7 @@ impl LiquidSdk {
     ///
     /// * `req` - the [PrepareReceiveRequest] containing:
     ///     * `payer_amount_sat` - the amount in satoshis to be paid by the payer
+    ///     * `payer_amount_fiat` - alternatively, the amount in a fiat currency to be paid by the payer
     ///     * `payment_method` - the supported payment methods; either an invoice, a Liquid address or a Bitcoin address
     pub async fn prepare_receive_payment(
         &self,
@@ -1625,10 +2344,24 @@ impl LiquidSdk {
     ) -> Result<PrepareReceiveResponse, PaymentError> {
         self.ensure_is_started().await?;
 
+        let (payer_amount_sat, fiat_rate) = match (req.payer_amount_sat, &req.payer_amount_fiat) {
+            (Some(_), Some(_)) => {
+                return Err(PaymentError::generic(
+                    "Only one of `payer_amount_sat` and `payer_amount_fiat` can be specified",
+                ));
+            }
+            (None, Some(FiatAmount { currency, amount })) => {
+                let fiat_rate = self.get_fiat_rate(currency).await?;
+                let amount_sat = Self::convert_fiat_amount(&fiat_rate, *amount)?;
+                (Some(amount_sat), Some(fiat_rate))
+            }
+            (payer_amount_sat, None) => (payer_amount_sat, None),
+        };
+
         let fees_sat;
         match req.payment_method {
             PaymentMethod::Lightning => {
-                let Some(payer_amount_sat) = req.payer_amount_sat else {
+                let Some(payer_amount_sat) = payer_amount_sat else {
                     return Err(PaymentError::AmountMissing { err: "`payer_amount_sat` must be specified when `PaymentMethod::Lightning` is used.".to_string() });
                 };
                 let reverse_pair = self
@@ -1650,7 +2383,7 @@ impl LiquidSdk {
                 );
             }
             PaymentMethod::BitcoinAddress => {
-                let Some(payer_amount_sat) = req.payer_amount_sat else {
+                let Some(payer_amount_sat) = payer_amount_sat else {
                     return Err(PaymentError::AmountMissing { err: "`payer_amount_sat` must be specified when `PaymentMethod::BitcoinAddress` is used.".to_string() });
                 };
                 let pair =
@@ -1666,15 +2399,16 @@ impl LiquidSdk {
                 fees_sat = 0;
                 debug!(
                     "Preparing Liquid Receive Swap with: amount_sat {:?} sat, fees_sat {fees_sat} sat",
-                    req.payer_amount_sat
+                    payer_amount_sat
                 );
             }
         };
 
         Ok(PrepareReceiveResponse {
-            payer_amount_sat: req.payer_amount_sat,
+            payer_amount_sat,
             fees_sat,
             payment_method: req.payment_method.clone(),
+            fiat_rate,
         })
     }
 
@@ -1702,6 +2436,7 @@ impl LiquidSdk {
             payment_method,
             payer_amount_sat: amount_sat,
             fees_sat,
+            fiat_rate,
         } = &req.prepare_response;
 
         match payment_method {
@@ -1724,14 +2459,21 @@ impl LiquidSdk {
                         })
                     }
                 };
-                self.create_receive_swap(*amount_sat, *fees_sat, description, description_hash)
-                    .await
+                self.create_receive_swap(
+                    *amount_sat,
+                    *fees_sat,
+                    description,
+                    description_hash,
+                    fiat_rate.as_ref(),
+                )
+                .await
             }
             PaymentMethod::BitcoinAddress => {
                 let Some(amount_sat) = amount_sat else {
                     return Err(PaymentError::AmountMissing { err: "`amount_sat` must be specified when `PaymentMethod::BitcoinAddress` is used.".to_string() });
                 };
-                self.receive_onchain(*amount_sat, *fees_sat).await
+                self.receive_onchain(*amount_sat, *fees_sat, fiat_rate.as_ref())
+                    .await
             }
             PaymentMethod::LiquidAddress => {
                 let address = self.onchain_wallet.next_unused_address().await?.to_string();
@@ -1741,7 +2483,7 @@ impl LiquidSdk {
                         address: address.to_string(),
                         network: self.config.network.into(),
                         amount_sat: Some(*amount_sat),
-                        asset_id: Some(AssetId::LIQUID_BTC.to_hex()),
+                        asset_id: Some(self.config.lbtc_asset_id().to_hex()),
                         label: None,
                         message: req.description.clone(),
                     }
@@ -1765,6 +2507,7 @@ impl LiquidSdk {
         fees_sat: u64,
         description: Option<String>,
         description_hash: Option<String>,
+        fiat_rate: Option<&PaymentFiatRate>,
     ) -> Result<ReceivePaymentResponse, PaymentError> {
         let reverse_pair = self
             .swapper
@@ -1873,13 +2616,14 @@ impl LiquidSdk {
                 claim_fees_sat: reverse_pair.fees.claim_estimate(),
                 claim_tx_id: None,
                 lockup_tx_id: None,
-                mrh_address: mrh_addr_str,
-                mrh_script_pubkey: mrh_addr.to_unconfidential().script_pubkey().to_hex(),
+                mrh_address: Some(mrh_addr_str),
+                mrh_script_pubkey: Some(mrh_addr.to_unconfidential().script_pubkey().to_hex()),
                 mrh_tx_id: None,
                 created_at: utils::now(),
                 state: PaymentState::Created,
             })
             .map_err(|_| PaymentError::PersistError)?;
+        self.insert_payment_fiat_rate(&swap_id, fiat_rate)?;
         self.status_stream.track_swap_id(&swap_id)?;
 
         Ok(ReceivePaymentResponse {
@@ -1975,12 +2719,14 @@ impl LiquidSdk {
         &self,
         payer_amount_sat: u64,
         fees_sat: u64,
+        fiat_rate: Option<&PaymentFiatRate>,
     ) -> Result<ReceivePaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
 
         let swap = self
             .create_receive_chain_swap(payer_amount_sat, fees_sat)
             .await?;
+        self.insert_payment_fiat_rate(&swap.id, fiat_rate)?;
         let create_response = swap.get_boltz_create_response()?;
         let address = create_response.lockup_details.lockup_address;
 
@@ -2006,11 +2752,21 @@ impl LiquidSdk {
             .iter()
             .map(|s| s.as_script())
             .collect();
-        let scripts_balance = self
-            .bitcoin_chain_service
-            .lock()
-            .await
-            .scripts_get_balance(&lockup_scripts)?;
+        let (scripts_balance, bitcoin_tip) = {
+            let mut bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
+            let scripts_balance = bitcoin_chain_service
+                .scripts_get_balance(&lockup_scripts)
+                .await?;
+            // The expiry info is optional, so the refundables are still listed without it
+            let bitcoin_tip = match bitcoin_chain_service.tip().await {
+                Ok(tip) => Some(tip.height as u32),
+                Err(e) => {
+                    warn!("Failed to fetch Bitcoin tip, listing refundables without expiry info: {e:?}");
+                    None
+                }
+            };
+            (scripts_balance, bitcoin_tip)
+        };
 
         let mut refundables = vec![];
         for (chain_swap, script_balance) in chain_swaps.into_iter().zip(scripts_balance) {
@@ -2018,7 +2774,8 @@ impl LiquidSdk {
             let refundable_confirmed_sat = script_balance.confirmed;
             info!("Incoming Chain Swap {swap_id} is refundable with {refundable_confirmed_sat} confirmed sats");
 
-            let refundable: RefundableSwap = chain_swap.to_refundable(refundable_confirmed_sat);
+            let refundable: RefundableSwap =
+                chain_swap.to_refundable(refundable_confirmed_sat, bitcoin_tip);
             refundables.push(refundable);
         }
 
@@ -2027,6 +2784,13 @@ impl LiquidSdk {
 
     /// Prepares to refund a failed chain swap by calculating the refund transaction size and absolute fee.
     ///
+    /// Before the swap timeout, the refund is co-signed by the swapper, which results in a
+    /// smaller transaction. After the timeout, the refund is done via the script path.
+    /// See [PrepareRefundResponse::is_cooperative] and [PrepareRefundResponse::fee_saved_sat].
+    ///
+    /// The lockup address of a send swap can also be given, to estimate the refund the SDK
+    /// broadcasts for it automatically. Such refunds are always broadcast at the lowball fee rate.
+    ///
     /// # Arguments
     ///
     /// * `req` - the [PrepareRefundRequest] containing:
@@ -2037,19 +2801,24 @@ impl LiquidSdk {
         &self,
         req: &PrepareRefundRequest,
     ) -> SdkResult<PrepareRefundResponse> {
-        let (tx_vsize, tx_fee_sat, refund_tx_id) = self
-            .chain_swap_handler
+        if self
+            .persister
+            .fetch_chain_swap_by_lockup_address(&req.swap_address)?
+            .is_none()
+        {
+            return self
+                .send_swap_handler
+                .prepare_refund(&req.swap_address, &req.refund_address)
+                .await;
+        }
+
+        self.chain_swap_handler
             .prepare_refund(
                 &req.swap_address,
                 &req.refund_address,
                 req.fee_rate_sat_per_vbyte,
             )
-            .await?;
-        Ok(PrepareRefundResponse {
-            tx_vsize,
-            tx_fee_sat,
-            refund_tx_id,
-        })
+            .await
     }
 
     /// Refund a failed chain swap.
@@ -2067,17 +2836,7 @@ impl LiquidSdk {
                 &req.swap_address,
                 &req.refund_address,
                 req.fee_rate_sat_per_vbyte,
-                true,
             )
-            .or_else(|e| {
-                warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
-                self.chain_swap_handler.refund_incoming_swap(
-                    &req.swap_address,
-                    &req.refund_address,
-                    req.fee_rate_sat_per_vbyte,
-                    false,
-                )
-            })
             .await?;
 
         Ok(RefundResponse { refund_tx_id })
@@ -2097,6 +2856,131 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Imports a swap created outside the SDK, like in the Boltz web app, from its rescue file.
+    ///
+    /// The keys in the file are checked against the swap addresses, then the swap txs and state
+    /// are recovered from the chain data. Once imported, the swap is claimed or refunded like
+    /// any swap created by the SDK.
+    pub async fn import_swap(&self, rescue_json: &str) -> SdkResult<()> {
+        self.ensure_is_started().await?;
+
+        let rescue_file: RescueFile = serde_json::from_str(rescue_json)
+            .map_err(|e| SdkError::validation(format!("Invalid rescue file: {e}")))?;
+        let swap_id = rescue_file.id().to_string();
+        ensure_sdk!(
+            self.persister.try_fetch_swap_by_id(&swap_id)?.is_none(),
+            SdkError::validation(format!("Swap {swap_id} already exists"))
+        );
+        let swap = rescue_file
+            .into_swap(self.config.network)
+            .map_err(|e| SdkError::validation(format!("Invalid swap {swap_id}: {e}")))?;
+
+        let swap = self.recover_imported_swap(swap).await?;
+        match &swap {
+            Swap::Send(send_swap) => self.persister.insert_send_swap(send_swap)?,
+            Swap::Receive(receive_swap) => self.persister.insert_receive_swap(receive_swap)?,
+            Swap::Chain(chain_swap) => self.persister.insert_chain_swap(chain_swap)?,
+        }
+        self.status_stream.track_swap_id(&swap_id)?;
+        info!("Imported swap {swap_id}");
+        Ok(())
+    }
+
+    fn fetch_swap(&self, swap_id: &str) -> Result<Swap, PaymentError> {
+        self.persister
+            .try_fetch_swap_by_id(swap_id)?
+            .ok_or(PaymentError::Generic {
+                err: format!("Swap {swap_id} not found"),
+            })
+    }
+
+    /// Claims a Receive or incoming Chain swap, regardless of the last status received from
+    /// the swapper.
+    ///
+    /// This is meant to recover swaps stuck because a swapper status update was missed.
+    pub async fn force_claim_swap(&self, swap_id: &str) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+        match self.fetch_swap(swap_id)? {
+            Swap::Receive(swap) => self.receive_swap_handler.force_claim(&swap).await,
+            Swap::Chain(swap) => self.chain_swap_handler.force_claim(&swap).await,
+            Swap::Send(_) => Err(PaymentError::Generic {
+                err: format!("Send Swap {swap_id} cannot be claimed"),
+            }),
+        }
+    }
+
+    /// Refunds the lockup of a Send swap, regardless of the last status received from the
+    /// swapper. Returns the refund tx id.
+    ///
+    /// A non-cooperative refund is only possible once the swap timeout block height is reached.
+    pub async fn force_refund_send_swap(
+        &self,
+        swap_id: &str,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        self.ensure_is_started().await?;
+        match self.fetch_swap(swap_id)? {
+            Swap::Send(swap) => {
+                self.send_swap_handler
+                    .force_refund(&swap, is_cooperative)
+                    .await
+            }
+            _ => Err(PaymentError::Generic {
+                err: format!("Swap {swap_id} is not a Send Swap"),
+            }),
+        }
+    }
+
+    /// Fetches the current status of the swap from the swapper and handles it as if it was
+    /// received from the status stream.
+    pub async fn refetch_swap_status(&self, swap_id: &str) -> SdkResult<()> {
+        self.ensure_is_started().await?;
+        self.persister.fetch_swap_by_id(swap_id)?;
+        let update = self.status_stream.fetch_swap_status(swap_id).await?;
+        self.record_swap_update(&update).await;
+        self.handle_swap_update(&update, None).await?;
+        Ok(())
+    }
+
+    /// Marks the swap as failed, so that it is no longer tracked as ongoing.
+    ///
+    /// Completed swaps cannot be marked as failed. Neither can swaps whose lockup tx was
+    /// broadcast but not refunded, as their funds would no longer be recovered.
+    pub async fn mark_swap_failed(&self, swap_id: &str) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+        let swap = self.fetch_swap(swap_id)?;
+        // The Receive swap lockup is funded by the swapper, so there is nothing to refund
+        let (lockup_tx_id, refund_tx_id) = match &swap {
+            Swap::Send(swap) => (&swap.lockup_tx_id, &swap.refund_tx_id),
+            Swap::Chain(swap) => (&swap.user_lockup_tx_id, &swap.refund_tx_id),
+            Swap::Receive(_) => (&None, &None),
+        };
+        ensure_sdk!(
+            lockup_tx_id.is_none() || refund_tx_id.is_some(),
+            PaymentError::Generic {
+                err: format!("The lockup tx of Swap {swap_id} must be refunded first")
+            }
+        );
+
+        match swap {
+            Swap::Send(swap) => {
+                self.send_swap_handler
+                    .update_swap_info(&swap.id, PaymentState::Failed, None, None, None)
+                    .await
+            }
+            Swap::Receive(swap) => {
+                self.receive_swap_handler
+                    .update_swap_info(&swap.id, PaymentState::Failed, None, None, None, None)
+                    .await
+            }
+            Swap::Chain(swap) => {
+                self.chain_swap_handler
+                    .update_swap_info(&swap.id, PaymentState::Failed, None, None, None, None)
+                    .await
+            }
+        }
+    }
+
     fn validate_buy_bitcoin(&self, amount_sat: u64) -> Result<(), PaymentError> {
         ensure_sdk!(
             self.config.network == LiquidNetwork::Mainnet,
@@ -2127,6 +3011,7 @@ impl LiquidSdk {
             .prepare_receive_payment(&PrepareReceiveRequest {
                 payment_method: PaymentMethod::BitcoinAddress,
                 payer_amount_sat: Some(req.amount_sat),
+                payer_amount_fiat: None,
             })
             .await?;
 
@@ -2175,26 +3060,18 @@ impl LiquidSdk {
 
     /// This method fetches the chain tx data (onchain and mempool) using LWK. For every wallet tx,
     /// it inserts or updates a corresponding entry in our Payments table.
-    async fn sync_payments_with_chain_data(&self, with_scan: bool) -> Result<()> {
-        let payments_before_sync: HashMap<String, Payment> = self
-            .list_payments(&ListPaymentsRequest::default())
-            .await?
-            .into_iter()
-            .flat_map(|payment| {
-                // Index payments by both tx_id (lockup/claim) and refund_tx_id
-                let mut res = vec![];
-                if let Some(tx_id) = payment.tx_id.clone() {
-                    res.push((tx_id, payment.clone()));
-                }
-                if let Some(refund_tx_id) = payment.get_refund_tx_id() {
-                    res.push((refund_tx_id, payment));
-                }
-                res
-            })
-            .collect();
+    /// Updates the payments and swaps with the wallet txs.
+    ///
+    /// Only the wallet txs which are new or changed since the last [SyncCheckpoint], or which
+    /// belong to a pending swap, are processed.
+    async fn sync_payments_with_chain_data(&self, with_scan: bool) -> Result<SyncMetrics> {
+        let started_at = utils::now();
+        let t0 = Instant::now();
         if with_scan {
             self.onchain_wallet.full_scan().await?;
         }
+        let scan_duration_ms = t0.elapsed().as_millis() as u64;
+        let liquid_tip = self.liquid_chain_service.lock().await.tip().await.ok();
 
         let pending_receive_swaps_by_claim_tx_id =
             self.persister.list_pending_receive_swaps_by_claim_tx_id()?;
@@ -2216,7 +3093,60 @@ impl LiquidSdk {
             .map(|tx| (tx.txid, tx.clone()))
             .collect();
 
-        for tx in tx_map.values() {
+        let checkpoint = self.persister.get_sync_checkpoint()?;
+        let changed_txs: Vec<&WalletTx> = tx_map
+            .values()
+            .filter(|tx| {
+                let tx_id = tx.txid.to_string();
+                checkpoint.tx_heights.get(&tx_id) != Some(&tx.height)
+                    || pending_receive_swaps_by_claim_tx_id.contains_key(&tx_id)
+                    || pending_send_swaps_by_refund_tx_id.contains_key(&tx_id)
+                    || pending_chain_swaps_by_claim_tx_id.contains_key(&tx_id)
+                    || pending_chain_swaps_by_refund_tx_id.contains_key(&tx_id)
+            })
+            .collect();
+        // Txs which are no longer in the wallet, e.g. dropped from the mempool
+        let wallet_tx_ids: HashSet<String> = tx_map.keys().map(Txid::to_string).collect();
+        let removed_tx_ids: Vec<String> = checkpoint
+            .tx_heights
+            .keys()
+            .filter(|tx_id| !wallet_tx_ids.contains(*tx_id))
+            .cloned()
+            .collect();
+        info!(
+            "Processing {} new or changed of {} wallet txs",
+            changed_txs.len(),
+            tx_map.len()
+        );
+
+        let payments_before_sync: HashMap<String, Payment> = match changed_txs.is_empty() {
+            true => HashMap::new(),
+            false => self
+                .list_payments(&ListPaymentsRequest::default())
+                .await?
+                .into_iter()
+                .flat_map(|payment| {
+                    // Index payments by both tx_id (lockup/claim) and refund_tx_id
+                    let mut res = vec![];
+                    if let Some(tx_id) = payment.tx_id.clone() {
+                        res.push((tx_id, payment.clone()));
+                    }
+                    if let Some(refund_tx_id) = payment.get_refund_tx_id() {
+                        res.push((refund_tx_id, payment));
+                    }
+                    res
+                })
+                .collect(),
+        };
+
+        let total = changed_txs.len() as u32;
+        for (step, tx) in changed_txs.iter().enumerate() {
+            let step = step as u32 + 1;
+            if should_emit_sync_progress(step, total) {
+                self.notify_event_listeners(SdkEvent::SyncProgress { step, total })
+                    .await?;
+            }
+
             let tx_id = tx.txid.to_string();
             let is_tx_confirmed = tx.height.is_some();
             let amount_sat = tx.balance.values().sum::<i64>();
@@ -2268,7 +3198,9 @@ impl LiquidSdk {
                     )
                     .await?;
                 // Remove the used MRH address from the reserved addresses
-                self.persister.delete_reserved_address(&swap.mrh_address)?;
+                if let Some(mrh_address) = &swap.mrh_address {
+                    self.persister.delete_reserved_address(mrh_address)?;
+                }
             } else if let Some(swap) = pending_send_swaps_by_refund_tx_id.get(&tx_id) {
                 if is_tx_confirmed {
                     self.send_swap_handler
@@ -2308,7 +3240,21 @@ impl LiquidSdk {
             }
         }
 
-        Ok(())
+        let processed_txs: Vec<(String, Option<u32>)> = changed_txs
+            .iter()
+            .map(|tx| (tx.txid.to_string(), tx.height))
+            .collect();
+        self.persister
+            .update_sync_checkpoint(&processed_txs, &removed_tx_ids)?;
+
+        Ok(SyncMetrics {
+            started_at,
+            duration_ms: t0.elapsed().as_millis() as u64,
+            scan_duration_ms,
+            liquid_tip,
+            total_tx_count: tx_map.len() as u32,
+            processed_tx_count: processed_txs.len() as u32,
+        })
     }
 
     /// Lists the SDK payments in reverse chronological order, from newest to oldest.
@@ -2357,29 +3303,209 @@ impl LiquidSdk {
     pub async fn sync(&self) -> SdkResult<()> {
         self.ensure_is_started().await?;
 
-        let t0 = Instant::now();
         let is_first_sync = !self
             .persister
             .get_is_first_sync_complete()?
             .unwrap_or(false);
-        match is_first_sync {
+        let metrics = match is_first_sync {
             true => {
-                self.event_manager.pause_notifications();
-                self.sync_payments_with_chain_data(true).await?;
-                self.event_manager.resume_notifications();
+                // The notifications are resumed even if the sync fails or is cancelled, e.g. when
+                // it runs out of the time budget of sync_once
+                let paused_notifications = self.event_manager.pause_notifications();
+                let metrics = self.sync_payments_with_chain_data(true).await?;
+                drop(paused_notifications);
                 self.persister.set_is_first_sync_complete(true)?;
+                metrics
             }
-            false => {
-                self.sync_payments_with_chain_data(true).await?;
-            }
-        }
-        let duration_ms = Instant::now().duration_since(t0).as_millis();
-        info!("Synchronized with mempool and onchain data (t = {duration_ms} ms)");
+            false => self.sync_payments_with_chain_data(true).await?,
+        };
+        info!(
+            "Synchronized with mempool and onchain data (t = {} ms, scan = {} ms, processed {} of {} txs)",
+            metrics.duration_ms,
+            metrics.scan_duration_ms,
+            metrics.processed_tx_count,
+            metrics.total_tx_count
+        );
+        self.persister.set_last_sync_metrics(&metrics)?;
 
+        self.emit_balance_changed().await;
         self.notify_event_listeners(SdkEvent::Synced).await?;
         Ok(())
     }
 
+    /// Runs the background work once within a time budget, from the highest to the lowest
+    /// priority: claiming the funds of ongoing swaps, refunding expired swaps, and finally
+    /// syncing the wallet history with [LiquidSdk::sync].
+    ///
+    /// It's meant to be called from the short background windows granted by mobile OSes. Each
+    /// step is cut short once the time budget has elapsed, and no new work is started after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [SyncOnceRequest] containing:
+    ///     * `timeout_secs` - the time budget
+    ///
+    /// # Returns
+    ///
+    /// * A [SyncOnceResponse] with the work left, to be resumed in the next background window
+    pub async fn sync_once(&self, req: &SyncOnceRequest) -> SdkResult<SyncOnceResponse> {
+        self.ensure_is_started().await?;
+        let deadline = Instant::now() + Duration::from_secs(req.timeout_secs);
+        let mut pending_tasks = vec![];
+
+        let swaps = self.persister.list_ongoing_swaps()?;
+        let mut pending_swap_count = 0;
+        for (i, swap) in swaps.iter().enumerate() {
+            let swap_id = swap.id();
+            let check_swap = async {
+                match self.status_stream.fetch_swap_status(&swap_id).await {
+                    Ok(update) => {
+                        self.record_swap_update(&update).await;
+                        if let Err(e) = self.handle_swap_update(&update, Some(deadline)).await {
+                            warn!("Could not handle the status of swap {swap_id}: {e:?}");
+                        }
+                    }
+                    Err(e) => warn!("Could not fetch the status of swap {swap_id}: {e:?}"),
+                }
+            };
+            let is_swap_checked = Instant::now() < deadline
+                && tokio::time::timeout_at(deadline.into(), check_swap)
+                    .await
+                    .is_ok();
+            if !is_swap_checked {
+                pending_swap_count = (swaps.len() - i) as u32;
+                pending_tasks.push(SyncTask::Claims);
+                break;
+            }
+        }
+
+        let track_refunds = async {
+            if let Err(err) = self.send_swap_handler.track_refunds().await {
+                warn!("Could not refund expired swaps, error: {err:?}");
+            }
+            if let Err(err) = self
+                .chain_swap_handler
+                .track_refunds_and_refundables()
+                .await
+            {
+                warn!("Could not refund expired swaps, error: {err:?}");
+            }
+        };
+        let are_refunds_tracked = Instant::now() < deadline
+            && tokio::time::timeout_at(deadline.into(), track_refunds)
+                .await
+                .is_ok();
+        if !are_refunds_tracked {
+            pending_tasks.push(SyncTask::Refunds);
+        }
+
+        let sync_history = async {
+            if let Err(e) = self.broadcast_outbox_txs().await {
+                warn!("Could not broadcast queued txs: {e:?}");
+            }
+            self.sync()
+                .await
+                .map_err(|e| warn!("Could not sync the wallet history: {e:?}"))
+                .is_ok()
+        };
+        let is_history_synced = Instant::now() < deadline
+            && tokio::time::timeout_at(deadline.into(), sync_history)
+                .await
+                .unwrap_or(false);
+        if !is_history_synced {
+            pending_tasks.push(SyncTask::History);
+        }
+
+        info!(
+            "Ran sync once, pending tasks: {pending_tasks:?}, pending swaps: {pending_swap_count}"
+        );
+        Ok(SyncOnceResponse {
+            pending_tasks,
+            pending_swap_count,
+        })
+    }
+
+    /// Returns the metrics of the last completed [LiquidSdk::sync], if any.
+    pub fn get_last_sync_metrics(&self) -> SdkResult<Option<SyncMetrics>> {
+        Ok(self.persister.get_last_sync_metrics()?)
+    }
+
+    /// Returns the recorded state transitions of a swap, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_id` - the id of the swap, as found in [PaymentDetails]
+    pub fn get_swap_history(&self, swap_id: &str) -> SdkResult<Vec<SwapStateTransition>> {
+        Ok(self.persister.list_swap_state_transitions(swap_id)?)
+    }
+
+    /// Exports the swap in the rescue file format of the Boltz web app.
+    ///
+    /// The file contains the claim and refund keys of the swap, so it allows recovering the
+    /// swap funds with third-party tools. It must be kept private.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_id` - the id of the swap, as found in [PaymentDetails]
+    pub fn export_rescue_file(&self, swap_id: &str) -> SdkResult<String> {
+        let swap = self.persister.fetch_swap_by_id(swap_id)?;
+        let rescue_file = RescueFile::from_swap(&swap)?;
+        Ok(serde_json::to_string_pretty(&rescue_file).map_err(anyhow::Error::new)?)
+    }
+
+    /// Generates a JSON report of the SDK state, to be attached to support requests.
+    ///
+    /// It contains the SDK version, the [Config] without its credentials, the chain tips, the
+    /// ongoing swaps with their state history and tx ids, the most recent swapper status updates
+    /// and the last lines of the SDK log file. Keys and preimages are stripped.
+    pub async fn generate_diagnostics(&self) -> SdkResult<String> {
+        let liquid_tip = self
+            .liquid_chain_service
+            .lock()
+            .await
+            .tip()
+            .await
+            .map_err(|e| warn!("Could not fetch the Liquid tip for diagnostics: {e:?}"))
+            .ok();
+        let bitcoin_tip = self
+            .bitcoin_chain_service
+            .lock()
+            .await
+            .tip()
+            .await
+            .map(|header| header.height as u32)
+            .map_err(|e| warn!("Could not fetch the Bitcoin tip for diagnostics: {e:?}"))
+            .ok();
+        let recent_swap_updates = self
+            .recent_swap_updates
+            .read()
+            .await
+            .iter()
+            .cloned()
+            .collect();
+
+        let ongoing_swaps = self
+            .persister
+            .list_ongoing_swaps()?
+            .into_iter()
+            .map(|swap| {
+                let history = self.persister.list_swap_state_transitions(&swap.id())?;
+                Ok((swap, history))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let diagnostics = Diagnostics::new(
+            &self.config,
+            utils::now(),
+            liquid_tip,
+            bitcoin_tip,
+            self.persister.get_last_sync_metrics()?,
+            ongoing_swaps,
+            recent_swap_updates,
+        )?;
+        Ok(serde_json::to_string_pretty(&diagnostics).map_err(anyhow::Error::new)?)
+    }
+
     /// Backup the local state to the provided backup path.
     ///
     /// # Arguments
@@ -2407,7 +3533,7 @@ impl LiquidSdk {
             .unwrap_or(self.persister.get_default_backup_path());
         ensure_sdk!(
             backup_path.exists(),
-            SdkError::generic("Backup file does not exist").into()
+            SdkError::validation("Backup file does not exist").into()
         );
         self.persister.restore_from_backup(backup_path)
     }
@@ -2491,6 +3617,7 @@ impl LiquidSdk {
                 prepare_response: PrepareSendResponse {
                     destination: prepare_response.destination,
                     fees_sat: prepare_response.fees_sat,
+                    fiat_rate: None,
                 },
             })
             .await
@@ -2558,6 +3685,7 @@ impl LiquidSdk {
                 PrepareReceiveRequest {
                     payment_method: PaymentMethod::Lightning,
                     payer_amount_sat: Some(req.amount_msat / 1_000),
+                    payer_amount_fiat: None,
                 }
             })
             .await?;
@@ -2621,6 +3749,25 @@ impl LiquidSdk {
         self.fiat_api.fetch_fiat_rates().await.map_err(Into::into)
     }
 
+    /// Get the persisted rate of a fiat currency closest in time to `timestamp`, e.g. to show
+    /// the fiat value of a past payment while offline. Rates are persisted hourly for the
+    /// currencies in [Config::fiat_rate_history_currencies], and kept for
+    /// [FIAT_RATE_HISTORY_RETENTION_SECS].
+    ///
+    /// Returns `None` if no rate was persisted within [FIAT_RATE_HISTORY_MAX_DISTANCE_SECS] of
+    /// `timestamp`, e.g. while the SDK wasn't running.
+    pub async fn get_historical_fiat_rate(
+        &self,
+        currency: &str,
+        timestamp: u32,
+    ) -> Result<Option<Rate>, SdkError> {
+        Ok(self.persister.get_closest_fiat_rate(
+            currency,
+            timestamp,
+            FIAT_RATE_HISTORY_MAX_DISTANCE_SECS,
+        )?)
+    }
+
     /// List all supported fiat currencies for which there is a known exchange rate.
     /// List is sorted by the canonical name of the currency.
     pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
@@ -2648,13 +3795,14 @@ impl LiquidSdk {
         let config = match network {
             LiquidNetwork::Mainnet => {
                 let Some(breez_api_key) = breez_api_key else {
-                    return Err(SdkError::Generic {
-                        err: "Breez API key must be provided on mainnet.".to_string(),
-                    });
+                    return Err(SdkError::validation(
+                        "Breez API key must be provided on mainnet.",
+                    ));
                 };
                 Config::mainnet(breez_api_key)
             }
             LiquidNetwork::Testnet => Config::testnet(breez_api_key),
+            LiquidNetwork::Regtest => Config::regtest(),
         };
         Ok(config)
     }
@@ -2753,6 +3901,13 @@ impl LiquidSdk {
     }
 }
 
+/// Throttles the sync progress events to the first and last steps and at most
+/// [SYNC_PROGRESS_MAX_EVENTS] evenly spaced steps in between
+fn should_emit_sync_progress(step: u32, total: u32) -> bool {
+    let interval = (total / SYNC_PROGRESS_MAX_EVENTS).max(1);
+    step == 1 || step == total || step % interval == 0
+}
+
 #[cfg(test)]
 mod tests {
     use std::{str::FromStr, sync::Arc};
@@ -2762,12 +3917,19 @@ mod tests {
         boltz::{self, SwapUpdateTxDetails},
         swaps::boltz::{ChainSwapStates, RevSwapStates, SubSwapStates},
     };
+    use futures_util::StreamExt;
     use lwk_wollet::{elements::Txid, hashes::hex::DisplayHex};
+    use rusqlite::params;
+    use sdk_common::liquid::LiquidAddressData;
     use tokio::sync::Mutex;
 
     use crate::{
-        model::{Direction, PaymentState, Swap},
-        sdk::LiquidSdk,
+        error::SdkError,
+        model::{
+            Config, Direction, LiquidNetwork, PaymentState, PaymentTxData, PaymentType, SdkEvent,
+            SdkEventFilter, SdkEventKind, SendSwap, Signer, Swap, SyncOnceRequest, SyncTask,
+        },
+        sdk::{should_emit_sync_progress, ChainTips, LiquidSdk, OUTBOX_TX_MAX_AGE_SECS},
         test_utils::{
             chain::{MockBitcoinChainService, MockHistory, MockLiquidChainService},
             chain_swap::{new_chain_swap, TEST_BITCOIN_TX},
@@ -2775,8 +3937,9 @@ mod tests {
             sdk::{new_liquid_sdk, new_liquid_sdk_with_chain_services},
             status_stream::MockStatusStream,
             swapper::MockSwapper,
-            wallet::TEST_LIQUID_TX,
+            wallet::{MockSigner, TEST_LIQUID_TX},
         },
+        utils,
     };
     use paste::paste;
 
@@ -3202,4 +4365,474 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_swap_timeout_events() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+        let mut events = sdk.event_manager.subscribe();
+
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Pending),
+            false,
+            Some("user-lockup-tx-id".to_string()),
+        );
+        persister.insert_chain_swap(&swap)?;
+        let timeout = swap.timeout_block_height;
+
+        // A swap whose script can't be read doesn't prevent checking the other swaps
+        let broken_send_swap = SendSwap {
+            lockup_tx_id: Some("lockup-tx-id".to_string()),
+            create_response_json: "{}".to_string(),
+            ..new_send_swap(Some(PaymentState::Pending))
+        };
+        persister.insert_send_swap(&broken_send_swap)?;
+
+        // Without a previous tip, e.g. right after the SDK starts, no event is emitted for a swap
+        // that already timed out
+        let expired_tips = ChainTips {
+            liquid: None,
+            bitcoin: Some(timeout + 1),
+        };
+        sdk.check_swap_timeouts(&ChainTips::default(), &expired_tips)
+            .await?;
+        assert!(events.try_recv().is_err());
+
+        // Within the nearing timeout window, an event is emitted for each new block
+        let last_tips = ChainTips {
+            liquid: None,
+            bitcoin: Some(timeout - 10),
+        };
+        let tips = ChainTips {
+            liquid: None,
+            bitcoin: Some(timeout - 3),
+        };
+        sdk.check_swap_timeouts(&last_tips, &tips).await?;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::SwapNearingTimeout {
+                swap_id: swap.id.clone(),
+                blocks_left: 3
+            }
+        );
+
+        // No new Bitcoin block, no event
+        sdk.check_swap_timeouts(&tips, &tips).await?;
+        assert!(events.try_recv().is_err());
+
+        // Reaching the timeout block height emits a single refundable event
+        let timeout_tips = ChainTips {
+            liquid: None,
+            bitcoin: Some(timeout),
+        };
+        sdk.check_swap_timeouts(&tips, &timeout_tips).await?;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::SwapRefundable {
+                swap_id: swap.id.clone()
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_filter() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+        let events_stream = sdk.subscribe_events(SdkEventFilter {
+            payment_id: Some("swap-id".to_string()),
+            kinds: Some(vec![SdkEventKind::SwapRefundable]),
+        });
+        tokio::pin!(events_stream);
+
+        for event in [
+            SdkEvent::Synced,
+            SdkEvent::SwapNearingTimeout {
+                swap_id: "swap-id".to_string(),
+                blocks_left: 1,
+            },
+            SdkEvent::SwapRefundable {
+                swap_id: "other-swap-id".to_string(),
+            },
+            SdkEvent::SwapRefundable {
+                swap_id: "swap-id".to_string(),
+            },
+        ] {
+            sdk.event_manager.notify(event).await;
+        }
+
+        assert_eq!(
+            events_stream.next().await,
+            Some(SdkEvent::SwapRefundable {
+                swap_id: "swap-id".to_string()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_arguments_are_validation_errors() {
+        let err = LiquidSdk::default_config(LiquidNetwork::Mainnet, None).unwrap_err();
+        assert!(matches!(err, SdkError::Validation { .. }));
+        assert_eq!(err.info().code, 108);
+
+        let err = LiquidSdk::validate_api_key("not an api key!").unwrap_err();
+        assert!(matches!(err, SdkError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_invalid_config_is_a_validation_error() {
+        let config = Config {
+            swapper_url: None,
+            ..Config::regtest()
+        };
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()));
+        let Err(err) = LiquidSdk::new(config, None, signer) else {
+            panic!("An SDK instance was created with an invalid config");
+        };
+        assert!(matches!(SdkError::from(err), SdkError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_should_emit_sync_progress() {
+        // Small syncs report every step
+        assert!((1..=5).all(|step| should_emit_sync_progress(step, 5)));
+
+        // Large syncs are throttled, but always report the first and last steps
+        let emitted: Vec<u32> = (1..=1_000)
+            .filter(|step| should_emit_sync_progress(*step, 1_000))
+            .collect();
+        assert_eq!(emitted.len(), 21);
+        assert_eq!(emitted.first(), Some(&1));
+        assert_eq!(emitted.last(), Some(&1_000));
+    }
+
+    #[tokio::test]
+    async fn test_balance_changed_events() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+        let mut events = sdk.event_manager.subscribe();
+
+        let new_receive = |tx_id: &str, amount_sat| PaymentTxData {
+            tx_id: tx_id.to_string(),
+            timestamp: None,
+            amount_sat,
+            fees_sat: 0,
+            payment_type: PaymentType::Receive,
+            is_confirmed: true,
+        };
+
+        // The first balance is only recorded
+        persister.insert_or_update_payment(new_receive("tx1", 1_000), None, None)?;
+        sdk.emit_balance_changed().await;
+        assert!(events.try_recv().is_err());
+
+        persister.insert_or_update_payment(new_receive("tx2", 500), None, None)?;
+        sdk.emit_balance_changed().await;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::BalanceChanged {
+                old_balance_sat: 1_000,
+                new_balance_sat: 1_500
+            }
+        );
+
+        // An unchanged balance doesn't emit an event
+        sdk.emit_balance_changed().await;
+        assert!(events.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_force_claim_swap() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+
+        let receive_swap = new_receive_swap(None);
+        persister.insert_receive_swap(&receive_swap)?;
+        sdk.force_claim_swap(&receive_swap.id).await?;
+        let claimed_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .ok_or(anyhow!("Could not find Receive swap"))?;
+        assert_eq!(claimed_swap.state, PaymentState::Pending);
+        assert!(claimed_swap.claim_tx_id.is_some());
+
+        // A swap is claimed only once
+        assert!(sdk.force_claim_swap(&receive_swap.id).await.is_err());
+
+        // Send swaps cannot be claimed
+        let send_swap = new_send_swap(None);
+        persister.insert_send_swap(&send_swap)?;
+        assert!(sdk.force_claim_swap(&send_swap.id).await.is_err());
+
+        assert!(sdk.force_claim_swap("unknown").await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_force_refund_send_swap() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+
+        // There is nothing to refund before the lockup tx is broadcast
+        let send_swap = new_send_swap(Some(PaymentState::Pending));
+        persister.insert_send_swap(&send_swap)?;
+        assert!(sdk
+            .force_refund_send_swap(&send_swap.id, true)
+            .await
+            .is_err());
+
+        persister.set_send_swap_lockup_tx_id(&send_swap.id, "lockup_tx_id")?;
+        let refund_tx_id = sdk.force_refund_send_swap(&send_swap.id, true).await?;
+        let refunded_swap = persister
+            .fetch_send_swap_by_id(&send_swap.id)?
+            .ok_or(anyhow!("Could not find Send swap"))?;
+        assert_eq!(refunded_swap.state, PaymentState::RefundPending);
+        assert_eq!(refunded_swap.refund_tx_id, Some(refund_tx_id));
+
+        // A swap is refunded only once
+        assert!(sdk
+            .force_refund_send_swap(&send_swap.id, true)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refetch_swap_status() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+
+        let receive_swap = new_receive_swap(None);
+        persister.insert_receive_swap(&receive_swap)?;
+        let new_update = |status: RevSwapStates| boltz::Update {
+            id: receive_swap.id.clone(),
+            status: status.to_string(),
+            transaction: None,
+            zero_conf_rejected: None,
+        };
+
+        assert!(sdk.refetch_swap_status("unknown").await.is_err());
+        // The swapper has no status for the swap
+        assert!(sdk.refetch_swap_status(&receive_swap.id).await.is_err());
+
+        // The status could not be handled, as the lockup tx is missing
+        status_stream.set_swap_status(new_update(RevSwapStates::TransactionMempool));
+        assert!(sdk.refetch_swap_status(&receive_swap.id).await.is_err());
+
+        status_stream.set_swap_status(new_update(RevSwapStates::TransactionFailed));
+        sdk.refetch_swap_status(&receive_swap.id).await?;
+        let failed_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .ok_or(anyhow!("Could not find Receive swap"))?;
+        assert_eq!(failed_swap.state, PaymentState::Failed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_swap_failed() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+
+        let receive_swap = new_receive_swap(Some(PaymentState::Pending));
+        persister.insert_receive_swap(&receive_swap)?;
+        sdk.mark_swap_failed(&receive_swap.id).await?;
+        let failed_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .ok_or(anyhow!("Could not find Receive swap"))?;
+        assert_eq!(failed_swap.state, PaymentState::Failed);
+
+        // Swaps with funds locked up must be refunded first
+        let send_swap = new_send_swap(Some(PaymentState::Pending));
+        persister.insert_send_swap(&send_swap)?;
+        persister.set_send_swap_lockup_tx_id(&send_swap.id, "lockup_tx_id")?;
+        assert!(sdk.mark_swap_failed(&send_swap.id).await.is_err());
+
+        let chain_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Pending),
+            false,
+            Some("user_lockup_tx_id".to_string()),
+        );
+        persister.insert_chain_swap(&chain_swap)?;
+        assert!(sdk.mark_swap_failed(&chain_swap.id).await.is_err());
+        let chain_swap = persister
+            .fetch_chain_swap_by_id(&chain_swap.id)?
+            .ok_or(anyhow!("Could not find Chain swap"))?;
+        assert_eq!(chain_swap.state, PaymentState::Pending);
+
+        // Completed swaps cannot be marked as failed
+        let complete_swap = new_receive_swap(Some(PaymentState::Complete));
+        persister.insert_receive_swap(&complete_swap)?;
+        assert!(sdk.mark_swap_failed(&complete_swap.id).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_outbox_broadcast() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(Mutex::new(MockLiquidChainService::new()));
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            liquid_chain_service.clone(),
+            bitcoin_chain_service.clone(),
+        )?;
+        let mut events = sdk.event_manager.subscribe();
+        let address_data = LiquidAddressData {
+            address: "tlq1pqwq5ft2l0khw7fr2f0fzfz5c00lku06sy9sgqlzhuj8y5vgslfx6y2pffw53ksu76uv25zkss8vpam96y8n2ke826mfmklaeg057guneaf8hr0ckqh0z".to_string(),
+            network: sdk.config.network.into(),
+            asset_id: None,
+            amount_sat: None,
+            label: None,
+            message: None,
+        };
+        let tx_id = TEST_LIQUID_TX.txid().to_string();
+        let outbox_tx_attempts = || -> Result<Option<u32>> {
+            Ok(persister
+                .list_outbox_txs()?
+                .into_iter()
+                .find(|tx| tx.tx_id == tx_id)
+                .map(|tx| tx.attempts))
+        };
+
+        // A payment made while offline is queued
+        liquid_chain_service.lock().await.set_offline(true);
+        sdk.pay_liquid(address_data.clone(), 1_000, 10_000).await?;
+        assert_eq!(outbox_tx_attempts()?, Some(0));
+        assert!(matches!(events.try_recv()?, SdkEvent::PaymentQueued { .. }));
+
+        // It's kept while offline, rate limited or while the tx is temporarily refused
+        sdk.broadcast_outbox_txs().await?;
+        assert_eq!(outbox_tx_attempts()?, Some(1));
+
+        liquid_chain_service
+            .lock()
+            .await
+            .set_offline(false)
+            .set_broadcast_error(Some("mempool min fee not met"));
+        sdk.broadcast_outbox_txs().await?;
+        assert_eq!(outbox_tx_attempts()?, Some(2));
+
+        // It's broadcast once the backends accept it
+        liquid_chain_service.lock().await.set_broadcast_error(None);
+        sdk.broadcast_outbox_txs().await?;
+        assert_eq!(outbox_tx_attempts()?, None);
+        assert!(persister.get_payment(&tx_id)?.is_some());
+
+        // A tx rejected by consensus or policy rules is dropped along with its payment
+        liquid_chain_service.lock().await.set_offline(true);
+        sdk.pay_liquid(address_data.clone(), 1_000, 10_000).await?;
+        assert_eq!(outbox_tx_attempts()?, Some(0));
+
+        liquid_chain_service
+            .lock()
+            .await
+            .set_offline(false)
+            .set_broadcast_error(Some("bad-txns-inputs-missingorspent"));
+        while events.try_recv().is_ok() {}
+        sdk.broadcast_outbox_txs().await?;
+        assert_eq!(outbox_tx_attempts()?, None);
+        assert!(persister.get_payment(&tx_id)?.is_none());
+        let mut is_failed_emitted = false;
+        while let Ok(event) = events.try_recv() {
+            is_failed_emitted |= matches!(event, SdkEvent::PaymentFailed { .. });
+        }
+        assert!(is_failed_emitted);
+
+        // A tx which is temporarily refused for too long is dropped as well
+        liquid_chain_service.lock().await.set_offline(true);
+        sdk.pay_liquid(address_data, 1_000, 10_000).await?;
+        persister.get_connection()?.execute(
+            "UPDATE outbox_txs SET created_at = ?1 WHERE tx_id = ?2",
+            params![utils::now() - OUTBOX_TX_MAX_AGE_SECS, tx_id],
+        )?;
+        while events.try_recv().is_ok() {}
+        sdk.broadcast_outbox_txs().await?;
+        assert_eq!(outbox_tx_attempts()?, None);
+        assert!(persister.get_payment(&tx_id)?.is_none());
+        let mut is_failed_emitted = false;
+        while let Ok(event) = events.try_recv() {
+            is_failed_emitted |= matches!(event, SdkEvent::PaymentFailed { .. });
+        }
+        assert!(is_failed_emitted);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_once_budget() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone())?;
+        let mut events = sdk.event_manager.subscribe();
+        persister.insert_receive_swap(&new_receive_swap(Some(PaymentState::Pending)))?;
+
+        // No work is started without a time budget
+        let res = sdk.sync_once(&SyncOnceRequest { timeout_secs: 0 }).await?;
+        assert_eq!(
+            res.pending_tasks,
+            vec![SyncTask::Claims, SyncTask::Refunds, SyncTask::History]
+        );
+        assert_eq!(res.pending_swap_count, 1);
+        assert!(events.try_recv().is_err());
+
+        // All the work is done within a large enough budget, and the notifications paused
+        // during the first sync are resumed
+        let res = sdk.sync_once(&SyncOnceRequest { timeout_secs: 30 }).await?;
+        assert!(res.pending_tasks.is_empty());
+        assert_eq!(res.pending_swap_count, 0);
+        let mut is_synced_emitted = false;
+        while let Ok(event) = events.try_recv() {
+            is_synced_emitted |= event == SdkEvent::Synced;
+        }
+        assert!(is_synced_emitted);
+
+        Ok(())
+    }
 }
@@ -14,13 +14,15 @@ use lwk_wollet::hashes::{sha256, Hash};
 use tokio::sync::{broadcast, Mutex};
 
 use crate::chain::liquid::LiquidChainService;
-use crate::model::{Config, PaymentState::*, SendSwap};
+use crate::event::EventManager;
+use crate::logger::LogSpan;
+use crate::model::{Config, PaymentState::*, PrepareRefundResponse, SdkEvent, SendSwap};
 use crate::prelude::{PaymentTxData, PaymentType, Swap};
-use crate::swapper::Swapper;
+use crate::swapper::{self, Swapper};
 use crate::wallet::OnchainWallet;
 use crate::{ensure_sdk, utils};
 use crate::{
-    error::PaymentError,
+    error::{PaymentError, SdkError, SdkResult},
     model::{PaymentState, Transaction as SdkTransaction},
     persist::Persister,
 };
@@ -32,6 +34,7 @@ pub(crate) struct SendSwapHandler {
     persister: Arc<Persister>,
     swapper: Arc<dyn Swapper>,
     chain_service: Arc<Mutex<dyn LiquidChainService>>,
+    event_manager: Arc<EventManager>,
     subscription_notifier: broadcast::Sender<String>,
 }
 
@@ -42,6 +45,7 @@ impl SendSwapHandler {
         persister: Arc<Persister>,
         swapper: Arc<dyn Swapper>,
         chain_service: Arc<Mutex<dyn LiquidChainService>>,
+        event_manager: Arc<EventManager>,
     ) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Self {
@@ -50,6 +54,7 @@ impl SendSwapHandler {
             persister,
             swapper,
             chain_service,
+            event_manager,
             subscription_notifier,
         }
     }
@@ -61,13 +66,24 @@ impl SendSwapHandler {
     /// Handles status updates from Boltz for Send swaps
     pub(crate) async fn on_new_status(&self, update: &boltz::Update) -> Result<()> {
         let id = &update.id;
-        let swap_state = &update.status;
         let swap = self
             .persister
             .fetch_send_swap_by_id(id)?
             .ok_or(anyhow!("No ongoing Send Swap found for ID {id}"))?;
 
-        info!("Handling Send Swap transition to {swap_state:?} for swap {id}");
+        LogSpan::swap(id, swap.lockup_tx_id.clone())
+            .scope(swapper::with_swapper_status(
+                &update.status,
+                self.handle_new_status(swap, update),
+            ))
+            .await
+    }
+
+    async fn handle_new_status(&self, swap: SendSwap, update: &boltz::Update) -> Result<()> {
+        let id = &update.id;
+        let swap_state = &update.status;
+
+        info!(status:% = swap_state; "Handling Send Swap transition to {swap_state:?} for swap {id}");
 
         // See https://docs.boltz.exchange/v/api/lifecycle#normal-submarine-swaps
         match SubSwapStates::from_str(swap_state) {
@@ -176,6 +192,16 @@ impl SendSwapHandler {
         &self,
         swap: &SendSwap,
         create_response: &CreateSubmarineResponse,
+    ) -> Result<Transaction, PaymentError> {
+        LogSpan::swap(&swap.id, None)
+            .scope(self.lockup(swap, create_response))
+            .await
+    }
+
+    async fn lockup(
+        &self,
+        swap: &SendSwap,
+        create_response: &CreateSubmarineResponse,
     ) -> Result<Transaction, PaymentError> {
         if swap.lockup_tx_id.is_some() {
             debug!("Lockup tx was already broadcast for Send Swap {}", swap.id);
@@ -268,7 +294,17 @@ impl SendSwapHandler {
             preimage,
             lockup_tx_id,
             refund_tx_id,
+            swapper::current_swapper_status().as_deref(),
         )?;
+        if swap.state != to_state {
+            self.event_manager
+                .notify(SdkEvent::SwapStateChanged {
+                    swap_id: swap_id.to_string(),
+                    from_state: swap.state,
+                    to_state,
+                })
+                .await;
+        }
         if let Some(payment_id) = payment_id {
             let _ = self.subscription_notifier.send(payment_id);
         }
@@ -380,6 +416,16 @@ impl SendSwapHandler {
         &self,
         swap: &SendSwap,
         is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        LogSpan::swap(&swap.id, swap.lockup_tx_id.clone())
+            .scope(self.broadcast_refund(swap, is_cooperative))
+            .await
+    }
+
+    async fn broadcast_refund(
+        &self,
+        swap: &SendSwap,
+        is_cooperative: bool,
     ) -> Result<String, PaymentError> {
         info!(
             "Initiating refund for Send Swap {}, is_cooperative: {is_cooperative}",
@@ -424,6 +470,33 @@ impl SendSwapHandler {
         Ok(refund_tx_id)
     }
 
+    /// Refunds the swap, regardless of the last status received from the swapper
+    pub(crate) async fn force_refund(
+        &self,
+        swap: &SendSwap,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        let id = &swap.id;
+        ensure_sdk!(
+            swap.lockup_tx_id.is_some(),
+            PaymentError::Generic {
+                err: format!("The lockup tx of Send Swap {id} was never broadcast")
+            }
+        );
+        ensure_sdk!(
+            swap.refund_tx_id.is_none(),
+            PaymentError::Generic {
+                err: format!("A refund tx for Send Swap {id} was already broadcast")
+            }
+        );
+        Self::validate_state_transition(swap.state, RefundPending)?;
+
+        let refund_tx_id = self.refund(swap, is_cooperative).await?;
+        self.update_swap_info(id, RefundPending, None, None, Some(&refund_tx_id))
+            .await?;
+        Ok(refund_tx_id)
+    }
+
     async fn check_swap_expiry(&self, swap: &SendSwap) -> Result<bool> {
         let swap_creation_time = UNIX_EPOCH + Duration::from_secs(swap.created_at as u64);
         let duration_since_creation_time = SystemTime::now().duration_since(swap_creation_time)?;
@@ -442,6 +515,59 @@ impl SendSwapHandler {
         ))
     }
 
+    /// Prepares the refund of a Send Swap by estimating the size and fees of its refund tx.
+    ///
+    /// The refund is cooperative unless the swap has expired, in which case it is estimated via
+    /// the script path. Liquid refunds are always broadcast at the lowball fee rate.
+    pub(crate) async fn prepare_refund(
+        &self,
+        lockup_address: &str,
+        refund_address: &str,
+    ) -> SdkResult<PrepareRefundResponse> {
+        let swap = self
+            .persister
+            .list_pending_send_swaps()?
+            .into_iter()
+            .find(|swap| {
+                swap.get_boltz_create_response()
+                    .is_ok_and(|create_response| create_response.address == lockup_address)
+            })
+            .ok_or(SdkError::generic(format!(
+                "Swap with lockup address {lockup_address} not found"
+            )))?;
+
+        let refund_tx_id = swap.refund_tx_id.clone();
+        if let Some(refund_tx_id) = &refund_tx_id {
+            warn!(
+                "A refund tx for Send Swap {} was already broadcast: txid {refund_tx_id}",
+                swap.id
+            );
+        }
+
+        let (script_path_tx_size, script_path_tx_fees_sat) = self
+            .swapper
+            .estimate_refund_broadcast(Swap::Send(swap.clone()), refund_address, None, false)?;
+
+        let is_cooperative = !self.check_swap_expiry(&swap).await?;
+        let (tx_vsize, tx_fee_sat) = match is_cooperative {
+            true => self.swapper.estimate_refund_broadcast(
+                Swap::Send(swap),
+                refund_address,
+                None,
+                true,
+            )?,
+            false => (script_path_tx_size, script_path_tx_fees_sat),
+        };
+
+        Ok(PrepareRefundResponse {
+            tx_vsize,
+            tx_fee_sat,
+            refund_tx_id,
+            is_cooperative,
+            fee_saved_sat: script_path_tx_fees_sat.saturating_sub(tx_fee_sat),
+        })
+    }
+
     // Attempts both cooperative and non-cooperative refunds, and updates the swap info accordingly
     pub(crate) async fn try_refund_all(&self, swaps: &[SendSwap]) {
         for swap in swaps {
@@ -451,23 +577,18 @@ impl SendSwapHandler {
 
             let has_swap_expired = self.check_swap_expiry(swap).await.unwrap_or(false);
 
-            if !has_swap_expired && swap.state == Pending {
-                continue;
-            }
-
-            let refund_tx_id_result = match swap.state {
-                Pending => self.refund(swap, false).await,
-                RefundPending => match has_swap_expired {
-                    true => {
-                        self.refund(swap, true)
-                            .or_else(|e| {
-                                warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
-                                self.refund(swap, false)
-                            })
-                            .await
-                    }
-                    false => self.refund(swap, true).await,
-                },
+            // As with incoming Chain Swaps, the refund is cooperative until the swap timeout and
+            // uses the script path once it has elapsed
+            let refund_tx_id_result = match (swap.state, has_swap_expired) {
+                (Pending | RefundPending, true) => self.refund(swap, false).await,
+                (RefundPending, false) => {
+                    self.refund(swap, true)
+                        .or_else(|e| {
+                            warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
+                            self.refund(swap, false)
+                        })
+                        .await
+                }
                 _ => {
                     continue;
                 }
@@ -559,7 +680,10 @@ mod tests {
     use anyhow::Result;
 
     use crate::{
-        model::PaymentState::{self, *},
+        model::{
+            PaymentState::{self, *},
+            SdkEvent,
+        },
         test_utils::{
             persist::{new_persister, new_send_swap},
             send_swap::new_send_swap_handler,
@@ -626,4 +750,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_prepare_refund() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+        let send_swap_handler = new_send_swap_handler(storage.clone())?;
+
+        let send_swap = new_send_swap(Some(Pending));
+        storage.insert_send_swap(&send_swap)?;
+        let lockup_address = send_swap.get_boltz_create_response()?.address;
+
+        // A recently created swap has not expired, so the swapper co-signs the refund, which is
+        // broadcast at the lowball fee rate
+        let response = send_swap_handler
+            .prepare_refund(&lockup_address, "refund_address")
+            .await?;
+        assert!(response.is_cooperative);
+        assert_eq!(response.tx_vsize, 100);
+        assert_eq!(response.tx_fee_sat, 10);
+        assert_eq!(response.fee_saved_sat, 5);
+        assert!(response.refund_tx_id.is_none());
+
+        assert!(send_swap_handler
+            .prepare_refund("unknown_address", "refund_address")
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_swap_state_changed_events() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+        let send_swap_handler = new_send_swap_handler(storage.clone())?;
+        let mut events = send_swap_handler.event_manager.subscribe();
+
+        let send_swap = new_send_swap(Some(Created));
+        storage.insert_send_swap(&send_swap)?;
+
+        send_swap_handler
+            .update_swap_info(&send_swap.id, Pending, None, Some("lockup-tx-id"), None)
+            .await?;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::SwapStateChanged {
+                swap_id: send_swap.id.clone(),
+                from_state: Created,
+                to_state: Pending,
+            }
+        );
+
+        // Updating the swap without changing its state doesn't emit an event
+        send_swap_handler
+            .update_swap_info(&send_swap.id, Pending, None, None, None)
+            .await?;
+        assert!(events.try_recv().is_err());
+
+        Ok(())
+    }
 }
@@ -13,7 +13,7 @@ use crate::{
     prelude::{ChainSwap, Direction, Swap, Utxo},
 };
 
-use super::BoltzSwapper;
+use super::{key_path_refund_size, BoltzSwapper};
 
 impl BoltzSwapper {
     pub(crate) fn new_btc_refund_wrapper(
@@ -24,14 +24,16 @@ impl BoltzSwapper {
         let refund_wrapper = match swap {
             Swap::Chain(swap) => match swap.direction {
                 Direction::Incoming => {
-                    let swap_script = swap.get_lockup_swap_script()?;
-                    BtcSwapTx::new_refund(
-                        swap_script.as_bitcoin_script()?,
-                        refund_address,
-                        &self.bitcoin_electrum_config,
-                        self.boltz_url.clone(),
-                        swap.id.clone(),
-                    )
+                    let swap_script = swap.get_lockup_swap_script()?.as_bitcoin_script()?;
+                    Self::with_electrum_fallback(&self.bitcoin_electrum_configs, |config| {
+                        BtcSwapTx::new_refund(
+                            swap_script.clone(),
+                            refund_address,
+                            config,
+                            self.boltz_url.clone(),
+                            swap.id.clone(),
+                        )
+                    })
                 }
                 Direction::Outgoing => {
                     return Err(SdkError::generic(format!(
@@ -64,11 +66,11 @@ impl BoltzSwapper {
         );
 
         let address = Address::from_str(refund_address)
-            .map_err(|err| SdkError::generic(format!("Could not parse address: {err:?}")))?;
+            .map_err(|err| SdkError::validation(format!("Could not parse address: {err:?}")))?;
 
         ensure_sdk!(
             address.is_valid_for_network(self.config.network.into()),
-            SdkError::generic("Address network validation failed")
+            SdkError::validation("Address network validation failed")
         );
 
         let utxos = utxos
@@ -87,12 +89,19 @@ impl BoltzSwapper {
         let refund_keypair = swap.get_refund_keypair()?;
         let preimage = Preimage::from_str(&swap.preimage)?;
         let refund_tx_size = refund_tx.size(&refund_keypair, &preimage)?;
-        let broadcast_fees_sat = (refund_tx_size as f64 * broadcast_fee_rate_sat_per_vb) as u64;
 
-        let cooperative = match is_cooperative {
-            true => self.get_cooperative_details(swap.id.clone(), None, None),
-            false => None,
+        let (refund_tx_size, cooperative) = match is_cooperative {
+            true => (
+                key_path_refund_size(
+                    refund_tx_size,
+                    refund_tx.swap_script.refund_script().len(),
+                    refund_tx.utxos.len(),
+                ),
+                self.get_cooperative_details(swap.id.clone(), None, None),
+            ),
+            false => (refund_tx_size, None),
         };
+        let broadcast_fees_sat = (refund_tx_size as f64 * broadcast_fee_rate_sat_per_vb) as u64;
 
         let signed_tx = refund_tx.sign_refund(&refund_keypair, broadcast_fees_sat, cooperative)?;
         Ok(signed_tx)
@@ -105,13 +114,16 @@ impl BoltzSwapper {
     ) -> Result<Transaction, PaymentError> {
         let claim_keypair = swap.get_claim_keypair()?;
         let claim_swap_script = swap.get_claim_swap_script()?.as_bitcoin_script()?;
-        let claim_tx_wrapper = BtcSwapTx::new_claim(
-            claim_swap_script,
-            claim_address,
-            &self.bitcoin_electrum_config,
-            self.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
+        let claim_tx_wrapper =
+            Self::with_electrum_fallback(&self.bitcoin_electrum_configs, |config| {
+                BtcSwapTx::new_claim(
+                    claim_swap_script.clone(),
+                    claim_address.clone(),
+                    config,
+                    self.boltz_url.clone(),
+                    swap.id.clone(),
+                )
+            })?;
 
         let (partial_sig, pub_nonce) = self.get_claim_partial_sig(swap)?;
 
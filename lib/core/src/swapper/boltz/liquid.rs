@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use boltz_client::{
     boltz::SwapTxKind,
-    elements::Transaction,
+    elements::{BlockHash, Transaction},
     util::{liquid_genesis_hash, secrets::Preimage},
     Amount, Bolt11Invoice, ElementsAddress as Address, LBtcSwapTx,
 };
@@ -17,7 +17,7 @@ use crate::{
     },
 };
 
-use super::BoltzSwapper;
+use super::{key_path_refund_size, BoltzSwapper};
 
 impl BoltzSwapper {
     pub(crate) fn validate_send_swap_preimage(
@@ -60,13 +60,16 @@ impl BoltzSwapper {
     ) -> Result<Transaction, PaymentError> {
         let swap_script = swap.get_swap_script()?;
 
-        let claim_tx_wrapper = LBtcSwapTx::new_claim(
-            swap_script,
-            claim_address,
-            &self.liquid_electrum_config,
-            self.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
+        let claim_tx_wrapper =
+            Self::with_electrum_fallback(&self.liquid_electrum_configs, |config| {
+                LBtcSwapTx::new_claim(
+                    swap_script.clone(),
+                    claim_address.clone(),
+                    config,
+                    self.boltz_url.clone(),
+                    swap.id.clone(),
+                )
+            })?;
 
         let signed_tx = claim_tx_wrapper.sign_claim(
             &swap.get_claim_keypair()?,
@@ -85,13 +88,16 @@ impl BoltzSwapper {
     ) -> Result<Transaction, PaymentError> {
         let claim_keypair = swap.get_claim_keypair()?;
         let swap_script = swap.get_claim_swap_script()?.as_liquid_script()?;
-        let claim_tx_wrapper = LBtcSwapTx::new_claim(
-            swap_script,
-            claim_address,
-            &self.liquid_electrum_config,
-            self.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
+        let claim_tx_wrapper =
+            Self::with_electrum_fallback(&self.liquid_electrum_configs, |config| {
+                LBtcSwapTx::new_claim(
+                    swap_script.clone(),
+                    claim_address.clone(),
+                    config,
+                    self.boltz_url.clone(),
+                    swap.id.clone(),
+                )
+            })?;
 
         let (partial_sig, pub_nonce) = self.get_claim_partial_sig(swap)?;
 
@@ -108,7 +114,7 @@ impl BoltzSwapper {
     fn calculate_refund_fees(&self, refund_tx_size: usize) -> u64 {
         let fee_rate = match self.config.network {
             LiquidNetwork::Mainnet => LOWBALL_FEE_RATE_SAT_PER_VBYTE,
-            LiquidNetwork::Testnet => STANDARD_FEE_RATE_SAT_PER_VBYTE,
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => STANDARD_FEE_RATE_SAT_PER_VBYTE,
         };
         (refund_tx_size as f64 * fee_rate).ceil() as u64
     }
@@ -127,25 +133,29 @@ impl BoltzSwapper {
                     )));
                 }
                 Direction::Outgoing => {
-                    let swap_script = swap.get_lockup_swap_script()?;
+                    let swap_script = swap.get_lockup_swap_script()?.as_liquid_script()?;
+                    Self::with_electrum_fallback(&self.liquid_electrum_configs, |config| {
+                        LBtcSwapTx::new_refund(
+                            swap_script.clone(),
+                            refund_address,
+                            config,
+                            self.boltz_url.clone(),
+                            swap.id.clone(),
+                        )
+                    })
+                }
+            },
+            Swap::Send(swap) => {
+                let swap_script = swap.get_swap_script()?;
+                Self::with_electrum_fallback(&self.liquid_electrum_configs, |config| {
                     LBtcSwapTx::new_refund(
-                        swap_script.as_liquid_script()?,
+                        swap_script.clone(),
                         refund_address,
-                        &self.liquid_electrum_config,
+                        config,
                         self.boltz_url.clone(),
                         swap.id.clone(),
                     )
-                }
-            },
-            Swap::Send(swap) => {
-                let swap_script = swap.get_swap_script()?;
-                LBtcSwapTx::new_refund(
-                    swap_script,
-                    refund_address,
-                    &self.liquid_electrum_config,
-                    self.boltz_url.clone(),
-                    swap.id.clone(),
-                )
+                })
             }
             Swap::Receive(swap) => {
                 return Err(SdkError::generic(format!(
@@ -191,9 +201,15 @@ impl BoltzSwapper {
         let swap_id = swap.id();
 
         let address = Address::from_str(refund_address)
-            .map_err(|err| SdkError::generic(format!("Could not parse address: {err:?}")))?;
+            .map_err(|err| SdkError::validation(format!("Could not parse address: {err:?}")))?;
 
-        let genesis_hash = liquid_genesis_hash(&self.liquid_electrum_config)?;
+        let genesis_hash = match &self.config.liquid_genesis_hash {
+            Some(genesis_hash) => BlockHash::from_str(genesis_hash)
+                .map_err(|err| SdkError::validation(format!("Invalid genesis hash: {err:?}")))?,
+            None => {
+                Self::with_electrum_fallback(&self.liquid_electrum_configs, liquid_genesis_hash)?
+            }
+        };
 
         let (funding_outpoint, funding_tx_out) =
             *utxos
@@ -211,12 +227,19 @@ impl BoltzSwapper {
         };
 
         let refund_tx_size = refund_tx.size(&refund_keypair, &preimage)?;
-        let broadcast_fees_sat = self.calculate_refund_fees(refund_tx_size);
 
-        let cooperative = match is_cooperative {
-            true => self.get_cooperative_details(swap_id.clone(), None, None),
-            false => None,
+        let (refund_tx_size, cooperative) = match is_cooperative {
+            true => (
+                key_path_refund_size(
+                    refund_tx_size,
+                    refund_tx.swap_script.refund_script().len(),
+                    1,
+                ),
+                self.get_cooperative_details(swap_id.clone(), None, None),
+            ),
+            false => (refund_tx_size, None),
         };
+        let broadcast_fees_sat = self.calculate_refund_fees(refund_tx_size);
 
         let signed_tx = refund_tx.sign_refund(
             &refund_keypair,
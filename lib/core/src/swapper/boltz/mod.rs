@@ -1,3 +1,5 @@
+use std::{fmt::Debug, time::Duration};
+
 use boltz_client::{
     boltz::{
         BoltzApiClientV2, ChainPair, Cooperative, CreateChainRequest, CreateChainResponse,
@@ -9,84 +11,226 @@ use boltz_client::{
     network::{electrum::ElectrumConfig, Chain},
     util::secrets::Preimage,
 };
-use log::info;
+use log::{info, warn};
 use url::Url;
 
 use crate::{
     error::{PaymentError, SdkError},
     prelude::{
-        ChainSwap, Config, Direction, LiquidNetwork, SendSwap, Swap, Transaction, Utxo,
-        LOWBALL_FEE_RATE_SAT_PER_VBYTE,
+        ChainBackend, ChainSwap, Config, Direction, LiquidNetwork, PaymentSwapType, SendSwap, Swap,
+        Transaction, Utxo, LOWBALL_FEE_RATE_SAT_PER_VBYTE,
     },
 };
 
+use self::pair_cache::{is_pair_hash_outdated, PairCache, PAIR_CACHE_TTL};
 use self::status_stream::BoltzStatusStream;
 use super::{Swapper, SwapperStatusStream};
+use crate::chain::parse_electrum_url;
 
 pub(crate) mod bitcoin;
 pub(crate) mod liquid;
+pub(crate) mod pair_cache;
 pub mod status_stream;
 
+/// Size in bytes of the control block revealed by a script path spend of a swap output:
+/// leaf version and parity (1) + internal key (32) + merkle path to the refund leaf (32)
+const SCRIPT_PATH_CONTROL_BLOCK_SIZE: usize = 65;
+
+/// Estimates the size of a refund tx spending its `num_inputs` swap outputs via the key path,
+/// given the size of the same tx spending them via the refund script path.
+///
+/// Both paths are signed with a single Schnorr signature, but the key path does not reveal the
+/// refund script and the control block, which are witness data and therefore discounted by 4.
+pub(crate) fn key_path_refund_size(
+    script_path_size: usize,
+    refund_script_len: usize,
+    num_inputs: usize,
+) -> usize {
+    let saved_witness_bytes = 1 + refund_script_len + 1 + SCRIPT_PATH_CONTROL_BLOCK_SIZE;
+    script_path_size.saturating_sub(num_inputs * saved_witness_bytes / 4)
+}
+
 pub struct BoltzSwapper {
     client: BoltzApiClientV2,
     boltz_url: String,
+    boltz_ws_url: String,
     referral_id: Option<String>,
     config: Config,
-    liquid_electrum_config: ElectrumConfig,
-    bitcoin_electrum_config: ElectrumConfig,
+    liquid_electrum_configs: Vec<ElectrumConfig>,
+    bitcoin_electrum_configs: Vec<ElectrumConfig>,
+    submarine_pairs: PairCache<Option<SubmarinePair>>,
+    reverse_pairs: PairCache<Option<ReversePair>>,
+    /// The outgoing (L-BTC to BTC) and incoming (BTC to L-BTC) chain pairs
+    chain_pairs: PairCache<(Option<ChainPair>, Option<ChainPair>)>,
 }
 
 impl BoltzSwapper {
     pub fn new(config: Config, swapper_proxy_url: Option<String>) -> Self {
-        let (boltz_api_base_url, referral_id) = match &config.network {
-            LiquidNetwork::Testnet => (None, None),
-            LiquidNetwork::Mainnet => match &swapper_proxy_url {
-                Some(swapper_proxy_url) => Url::parse(swapper_proxy_url)
-                    .map(|url| match url.query() {
-                        None => (None, None),
-                        Some(query) => {
-                            let api_base_url =
-                                url.domain().map(|domain| format!("https://{domain}/v2"));
-                            let parts: Vec<String> = query.split('=').map(Into::into).collect();
-                            let referral_id = parts.get(1).cloned();
-                            (api_base_url, referral_id)
-                        }
-                    })
-                    .unwrap_or_default(),
-                None => (None, None),
-            },
+        // The Breez swapper proxy is only used on mainnet, when no swapper is configured
+        let (proxy_api_url, proxy_referral_id) = match (&config.network, &config.swapper_url) {
+            (LiquidNetwork::Mainnet, None) => swapper_proxy_url
+                .as_deref()
+                .map(Self::parse_swapper_proxy_url)
+                .unwrap_or_default(),
+            _ => (None, None),
         };
 
-        let boltz_url = boltz_api_base_url.unwrap_or(
-            match config.network {
-                LiquidNetwork::Mainnet => BOLTZ_MAINNET_URL_V2,
-                LiquidNetwork::Testnet => BOLTZ_TESTNET_URL_V2,
-            }
-            .to_string(),
-        );
+        let boltz_url = config
+            .swapper_url
+            .clone()
+            .or(proxy_api_url)
+            .unwrap_or_else(|| {
+                match config.network {
+                    LiquidNetwork::Mainnet => BOLTZ_MAINNET_URL_V2,
+                    LiquidNetwork::Testnet | LiquidNetwork::Regtest => BOLTZ_TESTNET_URL_V2,
+                }
+                .to_string()
+            });
+        let boltz_ws_url = config
+            .swapper_ws_url
+            .clone()
+            .unwrap_or_else(|| boltz_url.replacen("http", "ws", 1) + "/ws");
+        let referral_id = config.swapper_referral_id.clone().or(proxy_referral_id);
 
         Self {
             client: BoltzApiClientV2::new(&boltz_url),
             boltz_url,
+            boltz_ws_url,
             referral_id,
             config: config.clone(),
-            liquid_electrum_config: ElectrumConfig::new(
+            liquid_electrum_configs: Self::electrum_configs(
                 config.network.into(),
-                &config.liquid_electrum_url,
-                true,
-                true,
-                100,
+                config.liquid_chain_backends(),
             ),
-            bitcoin_electrum_config: ElectrumConfig::new(
+            bitcoin_electrum_configs: Self::electrum_configs(
                 config.network.as_bitcoin_chain(),
-                &config.bitcoin_electrum_url,
-                true,
-                true,
-                100,
+                config.bitcoin_chain_backends(),
+            ),
+            submarine_pairs: PairCache::new(PAIR_CACHE_TTL),
+            reverse_pairs: PairCache::new(PAIR_CACHE_TTL),
+            chain_pairs: PairCache::new(PAIR_CACHE_TTL),
+        }
+    }
+
+    /// Parses the Breez swapper proxy URL, in the format `https://<domain>?referralId=<id>`,
+    /// into the Boltz API URL and referral id
+    fn parse_swapper_proxy_url(swapper_proxy_url: &str) -> (Option<String>, Option<String>) {
+        let Ok(url) = Url::parse(swapper_proxy_url) else {
+            return (None, None);
+        };
+        match url.query_pairs().next() {
+            None => (None, None),
+            Some((_, referral_id)) => (
+                url.domain().map(|domain| format!("https://{domain}/v2")),
+                Some(referral_id.to_string()),
             ),
         }
     }
 
+    /// Refreshes the cached pairs of a swap type if the swapper rejected a swap, so that a swap
+    /// created with an outdated pair hash can be retried with the current pairs
+    fn refresh_pairs_on_rejection<T>(
+        &self,
+        swap_type: PaymentSwapType,
+        pair_hash: Option<&str>,
+        res: Result<T, PaymentError>,
+    ) -> Result<T, PaymentError> {
+        let (Err(PaymentError::Swapper { .. }), Some(pair_hash)) = (&res, pair_hash) else {
+            return res;
+        };
+        let current_hashes: Result<Vec<String>, PaymentError> = match swap_type {
+            PaymentSwapType::Send => self
+                .submarine_pairs
+                .refresh(|| self.fetch_submarine_pairs())
+                .map(|pair| pair.into_iter().map(|pair| pair.hash).collect()),
+            PaymentSwapType::Receive => self
+                .reverse_pairs
+                .refresh(|| self.fetch_reverse_pairs())
+                .map(|pair| pair.into_iter().map(|pair| pair.hash).collect()),
+            PaymentSwapType::Chain => {
+                self.chain_pairs
+                    .refresh(|| self.fetch_chain_pairs())
+                    .map(|(outgoing, incoming)| {
+                        outgoing
+                            .into_iter()
+                            .chain(incoming)
+                            .map(|pair| pair.hash)
+                            .collect()
+                    })
+            }
+        };
+        match current_hashes {
+            Ok(hashes) if is_pair_hash_outdated(pair_hash, &hashes) => {
+                warn!("Pair hash mismatch for {swap_type:?} swap, refreshed the cached pairs")
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Could not refresh the {swap_type:?} pairs: {e:?}"),
+        }
+        res
+    }
+
+    fn fetch_chain_pairs(&self) -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError> {
+        let pairs = self.client.get_chain_pairs()?;
+        Ok((pairs.get_lbtc_to_btc_pair(), pairs.get_btc_to_lbtc_pair()))
+    }
+
+    fn fetch_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
+        Ok(self.client.get_submarine_pairs()?.get_lbtc_to_btc_pair())
+    }
+
+    fn fetch_reverse_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
+        Ok(self.client.get_reverse_pairs()?.get_btc_to_lbtc_pair())
+    }
+
+    /// Boltz builds claim and refund transactions from Electrum data, so the backends with an
+    /// Electrum server are used, in order. Otherwise it falls back to a public Electrum server,
+    /// which is logged as it reveals the swap scripts to a server the user didn't configure.
+    fn electrum_configs<'a>(
+        chain: Chain,
+        backends: impl Iterator<Item = &'a ChainBackend>,
+    ) -> Vec<ElectrumConfig> {
+        let mut urls: Vec<&str> = backends.filter_map(ChainBackend::electrum_url).collect();
+        if urls.is_empty() {
+            let fallback_url = match chain {
+                Chain::Liquid => "elements-mainnet.breez.technology:50002",
+                Chain::LiquidTestnet => "elements-testnet.blockstream.info:50002",
+                Chain::LiquidRegtest => "tcp://localhost:19002",
+                Chain::Bitcoin => "bitcoin-mainnet.blockstream.info:50002",
+                Chain::BitcoinTestnet => "bitcoin-testnet.blockstream.info:50002",
+                Chain::BitcoinRegtest => "tcp://localhost:19001",
+            };
+            warn!(
+                "No chain backend has an Electrum server, swap claim and refund transactions \
+                are built with the public Electrum server {fallback_url}"
+            );
+            urls.push(fallback_url);
+        }
+        urls.into_iter()
+            .map(|url| {
+                let (url, tls) = parse_electrum_url(url);
+                ElectrumConfig::new(chain, url, tls, tls, 100)
+            })
+            .collect()
+    }
+
+    /// Builds a swap tx with the first Electrum server it succeeds with, returning the error of
+    /// the last one if none does
+    fn with_electrum_fallback<T, E: Debug>(
+        configs: &[ElectrumConfig],
+        build: impl Fn(&ElectrumConfig) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let (last, others) = configs
+            .split_last()
+            .expect("There is always at least one Electrum server");
+        for (index, config) in others.iter().enumerate() {
+            match build(config) {
+                Ok(res) => return Ok(res),
+                Err(e) => warn!("Electrum server {index} failed to build the swap tx: {e:?}"),
+            }
+        }
+        build(last)
+    }
+
     fn get_claim_partial_sig(
         &self,
         swap: &ChainSwap,
@@ -148,7 +292,8 @@ impl Swapper for BoltzSwapper {
             referral_id: self.referral_id.clone(),
             ..req.clone()
         };
-        Ok(self.client.post_chain_req(modified_req)?)
+        let res = self.client.post_chain_req(modified_req).map_err(Into::into);
+        self.refresh_pairs_on_rejection(PaymentSwapType::Chain, req.pair_hash.as_deref(), res)
     }
 
     /// Create a new send swap
@@ -160,28 +305,27 @@ impl Swapper for BoltzSwapper {
             referral_id: self.referral_id.clone(),
             ..req.clone()
         };
-        Ok(self.client.post_swap_req(&modified_req)?)
+        let res = self.client.post_swap_req(&modified_req).map_err(Into::into);
+        self.refresh_pairs_on_rejection(PaymentSwapType::Send, req.pair_hash.as_deref(), res)
     }
 
     fn get_chain_pair(&self, direction: Direction) -> Result<Option<ChainPair>, PaymentError> {
-        let pairs = self.client.get_chain_pairs()?;
+        let (pair_outgoing, pair_incoming) = self.get_chain_pairs()?;
         let pair = match direction {
-            Direction::Incoming => pairs.get_btc_to_lbtc_pair(),
-            Direction::Outgoing => pairs.get_lbtc_to_btc_pair(),
+            Direction::Incoming => pair_incoming,
+            Direction::Outgoing => pair_outgoing,
         };
         Ok(pair)
     }
 
     fn get_chain_pairs(&self) -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError> {
-        let pairs = self.client.get_chain_pairs()?;
-        let pair_outgoing = pairs.get_lbtc_to_btc_pair();
-        let pair_incoming = pairs.get_btc_to_lbtc_pair();
-        Ok((pair_outgoing, pair_incoming))
+        self.chain_pairs.get_or_fetch(|| self.fetch_chain_pairs())
     }
 
     /// Get a submarine pair information
     fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
-        Ok(self.client.get_submarine_pairs()?.get_lbtc_to_btc_pair())
+        self.submarine_pairs
+            .get_or_fetch(|| self.fetch_submarine_pairs())
     }
 
     /// Get claim tx details which includes the preimage as a proof of payment.
@@ -237,12 +381,25 @@ impl Swapper for BoltzSwapper {
             referral_id: self.referral_id.clone(),
             ..req.clone()
         };
-        Ok(self.client.post_reverse_req(modified_req)?)
+        let res = self
+            .client
+            .post_reverse_req(modified_req)
+            .map_err(Into::into);
+        self.refresh_pairs_on_rejection(PaymentSwapType::Receive, req.pair_hash.as_deref(), res)
     }
 
     // Get a reverse pair information
     fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
-        Ok(self.client.get_reverse_pairs()?.get_btc_to_lbtc_pair())
+        self.reverse_pairs
+            .get_or_fetch(|| self.fetch_reverse_pairs())
+    }
+
+    fn get_pairs_cache_age(&self, swap_type: PaymentSwapType) -> Option<Duration> {
+        match swap_type {
+            PaymentSwapType::Send => self.submarine_pairs.age(),
+            PaymentSwapType::Receive => self.reverse_pairs.age(),
+            PaymentSwapType::Chain => self.chain_pairs.age(),
+        }
     }
 
     /// Create a claim transaction for a receive or chain swap
@@ -300,6 +457,7 @@ impl Swapper for BoltzSwapper {
         swap: Swap,
         refund_address: &str,
         fee_rate_sat_per_vb: Option<f64>,
+        is_cooperative: bool,
     ) -> Result<(u32, u64), SdkError> {
         let refund_address = &refund_address.to_string();
         let (refund_keypair, preimage) = match &swap {
@@ -317,10 +475,28 @@ impl Swapper for BoltzSwapper {
         };
 
         let refund_tx_size = match self.new_lbtc_refund_wrapper(&swap, refund_address) {
-            Ok(refund_tx_wrapper) => refund_tx_wrapper.size(&refund_keypair, &preimage)?,
+            Ok(refund_tx_wrapper) => {
+                let size = refund_tx_wrapper.size(&refund_keypair, &preimage)?;
+                match is_cooperative {
+                    true => key_path_refund_size(
+                        size,
+                        refund_tx_wrapper.swap_script.refund_script().len(),
+                        1,
+                    ),
+                    false => size,
+                }
+            }
             Err(_) => {
                 let refund_tx_wrapper = self.new_btc_refund_wrapper(&swap, refund_address)?;
-                refund_tx_wrapper.size(&refund_keypair, &preimage)?
+                let size = refund_tx_wrapper.size(&refund_keypair, &preimage)?;
+                match is_cooperative {
+                    true => key_path_refund_size(
+                        size,
+                        refund_tx_wrapper.swap_script.refund_script().len(),
+                        refund_tx_wrapper.utxos.len(),
+                    ),
+                    false => size,
+                }
             }
         } as u32;
 
@@ -399,7 +575,7 @@ impl Swapper for BoltzSwapper {
     }
 
     fn create_status_stream(&self) -> Box<dyn SwapperStatusStream> {
-        Box::new(BoltzStatusStream::new(&self.boltz_url))
+        Box::new(BoltzStatusStream::new(&self.boltz_url, &self.boltz_ws_url))
     }
 
     fn check_for_mrh(
@@ -420,3 +596,68 @@ impl Swapper for BoltzSwapper {
         Ok(invoice_res.invoice)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Config;
+
+    use super::{key_path_refund_size, BoltzSwapper};
+
+    #[test]
+    fn test_swapper_endpoints() {
+        let proxy_url = Some("https://proxy.example.com?referralId=breez".to_string());
+
+        let swapper = BoltzSwapper::new(Config::mainnet("".to_string()), proxy_url.clone());
+        assert_eq!(swapper.boltz_url, "https://proxy.example.com/v2");
+        assert_eq!(swapper.boltz_ws_url, "wss://proxy.example.com/v2/ws");
+        assert_eq!(swapper.referral_id.as_deref(), Some("breez"));
+
+        let config = Config {
+            swapper_url: Some("https://boltz.example.com/v2".to_string()),
+            swapper_referral_id: Some("self-hosted".to_string()),
+            ..Config::mainnet("".to_string())
+        };
+        let swapper = BoltzSwapper::new(config, proxy_url);
+        assert_eq!(swapper.boltz_url, "https://boltz.example.com/v2");
+        assert_eq!(swapper.boltz_ws_url, "wss://boltz.example.com/v2/ws");
+        assert_eq!(swapper.referral_id.as_deref(), Some("self-hosted"));
+
+        let config = Config {
+            swapper_url: Some("https://boltz.example.com/v2".to_string()),
+            swapper_ws_url: Some("wss://ws.example.com/v2/ws".to_string()),
+            ..Config::testnet(None)
+        };
+        let swapper = BoltzSwapper::new(config, None);
+        assert_eq!(swapper.boltz_ws_url, "wss://ws.example.com/v2/ws");
+        assert!(swapper.referral_id.is_none());
+    }
+
+    #[test]
+    fn test_regtest_swapper_endpoints() {
+        // The proxy is ignored outside of mainnet, the local Boltz instance is used instead
+        let proxy_url = Some("https://proxy.example.com?referralId=breez".to_string());
+        let swapper = BoltzSwapper::new(Config::regtest(), proxy_url);
+        assert_eq!(swapper.boltz_url, "http://localhost:9001/v2");
+        assert_eq!(swapper.boltz_ws_url, "ws://localhost:9001/v2/ws");
+        assert!(swapper.referral_id.is_none());
+        assert_eq!(swapper.liquid_electrum_configs.len(), 1);
+        assert_eq!(swapper.bitcoin_electrum_configs.len(), 1);
+    }
+
+    #[test]
+    fn test_key_path_refund_size() {
+        // The refund script, its two length prefixes and the control block are no longer part
+        // of the witness: (1 + 38 + 1 + 65) / 4 = 26 vbytes saved
+        assert_eq!(key_path_refund_size(200, 38, 1), 174);
+
+        // The savings apply to every input
+        assert_eq!(key_path_refund_size(400, 38, 2), 348);
+        assert_eq!(key_path_refund_size(600, 38, 3), 522);
+
+        // A Bitcoin refund of one swap output to a P2TR address is 138 vbytes via the refund
+        // script (39 bytes), and within a vbyte of the 111 vbytes of the key path spend
+        let size = key_path_refund_size(138, 39, 1);
+        assert_eq!(size, 112);
+        assert!(size.abs_diff(111) <= 1);
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::warn;
+
+use crate::error::PaymentError;
+
+/// How long fetched pair information is served before it's fetched again from the swapper
+pub(crate) const PAIR_CACHE_TTL: Duration = Duration::from_secs(30);
+
+struct CachedPairs<T> {
+    pairs: T,
+    fetched_at: Instant,
+}
+
+/// A TTL cache of the pair information of one swap type.
+///
+/// The lock is held while fetching, so concurrent callers wait for a single request to the
+/// swapper instead of each sending their own.
+pub(crate) struct PairCache<T> {
+    ttl: Duration,
+    cached: Mutex<Option<CachedPairs<T>>>,
+}
+
+impl<T: Clone> PairCache<T> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached pairs if they were fetched within the TTL, otherwise fetches them.
+    ///
+    /// If fetching fails, the last fetched pairs are returned so that limits and fees can still
+    /// be shown while the swapper is unreachable.
+    pub(crate) fn get_or_fetch(
+        &self,
+        fetch: impl FnOnce() -> Result<T, PaymentError>,
+    ) -> Result<T, PaymentError> {
+        let mut cached = self.cached.lock().unwrap();
+        if let Some(cached) = cached.as_ref() {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.pairs.clone());
+            }
+        }
+
+        match fetch() {
+            Ok(pairs) => {
+                *cached = Some(CachedPairs {
+                    pairs: pairs.clone(),
+                    fetched_at: Instant::now(),
+                });
+                Ok(pairs)
+            }
+            Err(e) => match cached.as_ref() {
+                Some(cached) => {
+                    warn!(
+                        "Failed to fetch pairs, using the ones cached {:?} ago: {e:?}",
+                        cached.fetched_at.elapsed()
+                    );
+                    Ok(cached.pairs.clone())
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the pairs regardless of the TTL. The cached pairs are kept if fetching fails.
+    pub(crate) fn refresh(
+        &self,
+        fetch: impl FnOnce() -> Result<T, PaymentError>,
+    ) -> Result<T, PaymentError> {
+        let mut cached = self.cached.lock().unwrap();
+        let pairs = fetch()?;
+        *cached = Some(CachedPairs {
+            pairs: pairs.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(pairs)
+    }
+
+    /// Time since the cached pairs were fetched, if any
+    pub(crate) fn age(&self) -> Option<Duration> {
+        self.cached
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|cached| cached.fetched_at.elapsed())
+    }
+}
+
+/// Whether a swap was created with a pair hash which isn't among the current ones of the swapper
+pub(crate) fn is_pair_hash_outdated(pair_hash: &str, current_hashes: &[String]) -> bool {
+    !current_hashes.iter().any(|hash| hash == pair_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::error::PaymentError;
+
+    use super::{is_pair_hash_outdated, PairCache};
+
+    #[test]
+    fn test_pair_cache() {
+        let cache = PairCache::<u64>::new(Duration::from_secs(60));
+        assert!(cache.age().is_none());
+        assert!(cache
+            .get_or_fetch(|| Err(PaymentError::PairsNotFound))
+            .is_err());
+
+        assert_eq!(cache.get_or_fetch(|| Ok(1)).unwrap(), 1);
+        // Served from the cache while within the TTL
+        assert_eq!(cache.get_or_fetch(|| Ok(2)).unwrap(), 1);
+        assert!(cache.age().is_some());
+
+        // A refresh ignores the TTL, and keeps the cached pairs if fetching fails
+        assert_eq!(cache.refresh(|| Ok(3)).unwrap(), 3);
+        assert_eq!(cache.get_or_fetch(|| Ok(4)).unwrap(), 3);
+        assert!(cache.refresh(|| Err(PaymentError::PairsNotFound)).is_err());
+        assert_eq!(cache.get_or_fetch(|| Ok(4)).unwrap(), 3);
+
+        // Expired pairs are refetched, but still served if fetching fails
+        let cache = PairCache::<u64>::new(Duration::ZERO);
+        assert_eq!(cache.get_or_fetch(|| Ok(1)).unwrap(), 1);
+        assert_eq!(cache.get_or_fetch(|| Ok(2)).unwrap(), 2);
+        assert_eq!(
+            cache
+                .get_or_fetch(|| Err(PaymentError::PairsNotFound))
+                .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_is_pair_hash_outdated() {
+        let current_hashes = ["hash_outgoing".to_string(), "hash_incoming".to_string()];
+        assert!(!is_pair_hash_outdated("hash_incoming", &current_hashes));
+        assert!(is_pair_hash_outdated("hash_old", &current_hashes));
+        assert!(is_pair_hash_outdated("hash_old", &[]));
+    }
+}
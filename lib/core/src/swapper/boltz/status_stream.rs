@@ -13,21 +13,24 @@ use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
 use url::Url;
 
+use crate::error::SwapperError;
 use crate::swapper::{ReconnectHandler, SwapperStatusStream};
 
 pub(crate) struct BoltzStatusStream {
+    api_url: String,
     url: String,
     subscription_notifier: broadcast::Sender<String>,
     update_notifier: broadcast::Sender<boltz::Update>,
 }
 
 impl BoltzStatusStream {
-    pub(crate) fn new(url: &str) -> Self {
+    pub(crate) fn new(api_url: &str, ws_url: &str) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         let (update_notifier, _) = broadcast::channel::<boltz::Update>(30);
 
         Self {
-            url: url.replace("http", "ws") + "/ws",
+            api_url: api_url.to_string(),
+            url: ws_url.to_string(),
             subscription_notifier,
             update_notifier,
         }
@@ -69,6 +72,22 @@ impl SwapperStatusStream for BoltzStatusStream {
         self.update_notifier.subscribe()
     }
 
+    async fn fetch_swap_status(&self, swap_id: &str) -> Result<boltz::Update> {
+        let mut status = async {
+            reqwest::get(format!("{}/swap/{swap_id}", self.api_url))
+                .await?
+                .error_for_status()?
+                .json::<serde_json::Value>()
+                .await
+        }
+        .await
+        .map_err(|e| SwapperError::from(anyhow::Error::from(e)))?;
+        // The REST response has the same format as the status stream updates, without the id
+        status["id"] = swap_id.into();
+        serde_json::from_value(status)
+            .map_err(|e| anyhow!("Invalid status response for swap {swap_id}: {e:?}"))
+    }
+
     async fn start(
         self: Arc<Self>,
         callback: Box<dyn ReconnectHandler>,
@@ -158,6 +177,8 @@ impl SwapperStatusStream for BoltzStatusStream {
                                 }
                             }
                         }
+
+                        callback.on_stream_disconnect().await;
                     }
                     Err(e) => {
                         warn!("Error connecting to stream: {e}");
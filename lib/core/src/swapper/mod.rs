@@ -1,4 +1,6 @@
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use boltz_client::{
@@ -13,7 +15,7 @@ use tokio::sync::{broadcast, watch};
 
 use crate::{
     error::{PaymentError, SdkError},
-    prelude::{Direction, SendSwap, Swap, Utxo},
+    prelude::{Direction, PaymentSwapType, SendSwap, Swap, Utxo},
 };
 
 pub(crate) use reconnect_handler::*;
@@ -21,6 +23,21 @@ pub(crate) use reconnect_handler::*;
 pub(crate) mod boltz;
 pub(crate) mod reconnect_handler;
 
+tokio::task_local! {
+    static SWAPPER_STATUS: String;
+}
+
+/// Runs the future as the handling of a status update from the swapper, so that the swap state
+/// transitions it causes are recorded with the triggering status
+pub(crate) async fn with_swapper_status<F: Future>(status: &str, f: F) -> F::Output {
+    SWAPPER_STATUS.scope(status.to_string(), f).await
+}
+
+/// The status of the swapper update being handled, if any. See [with_swapper_status]
+pub(crate) fn current_swapper_status() -> Option<String> {
+    SWAPPER_STATUS.try_with(Clone::clone).ok()
+}
+
 pub trait Swapper: Send + Sync {
     /// Create a new chain swap
     fn create_chain_swap(
@@ -69,6 +86,12 @@ pub trait Swapper: Send + Sync {
     /// Get a reverse pair information
     fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError>;
 
+    /// Time since the pair information of a swap type was fetched from the swapper,
+    /// or `None` if it isn't cached
+    fn get_pairs_cache_age(&self, _swap_type: PaymentSwapType) -> Option<Duration> {
+        None
+    }
+
     /// Create a claim transaction for a receive or chain swap
     fn create_claim_tx(
         &self,
@@ -76,12 +99,15 @@ pub trait Swapper: Send + Sync {
         claim_address: Option<String>,
     ) -> Result<crate::prelude::Transaction, PaymentError>;
 
-    /// Estimate the refund broadcast transaction size and fees in sats for a send or chain swap
+    /// Estimate the refund broadcast transaction size and fees in sats for a send or chain swap.
+    ///
+    /// If `is_cooperative` is set, the estimate assumes the swap output is spent via the key path.
     fn estimate_refund_broadcast(
         &self,
         swap: Swap,
         refund_address: &str,
         fee_rate_sat_per_vb: Option<f64>,
+        is_cooperative: bool,
     ) -> Result<(u32, u64), SdkError>;
 
     /// Create a refund transaction for a send or chain swap
@@ -117,4 +143,7 @@ pub trait SwapperStatusStream: Send + Sync {
     );
     fn track_swap_id(&self, swap_id: &str) -> anyhow::Result<()>;
     fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz_client::boltz::Update>;
+    /// Fetches the current status of a swap from the swapper REST API
+    async fn fetch_swap_status(&self, swap_id: &str)
+        -> anyhow::Result<boltz_client::boltz::Update>;
 }
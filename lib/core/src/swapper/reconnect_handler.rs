@@ -1,30 +1,46 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use log::{error, info};
 
-use crate::persist::Persister;
+use crate::{event::EventManager, model::SdkEvent, persist::Persister};
 
 use super::SwapperStatusStream;
 
 #[async_trait]
 pub trait ReconnectHandler: Send + Sync {
     async fn on_stream_reconnect(&self);
+    async fn on_stream_disconnect(&self) {}
 }
 
 pub(crate) struct SwapperReconnectHandler {
     persister: Arc<Persister>,
     status_stream: Arc<dyn SwapperStatusStream>,
+    event_manager: Arc<EventManager>,
+    is_connected: AtomicBool,
 }
 
 impl SwapperReconnectHandler {
     pub(crate) fn new(
         persister: Arc<Persister>,
         status_stream: Arc<dyn SwapperStatusStream>,
+        event_manager: Arc<EventManager>,
     ) -> Self {
         Self {
             persister,
             status_stream,
+            event_manager,
+            is_connected: AtomicBool::new(false),
+        }
+    }
+
+    /// Emits [SdkEvent::ConnectivityChanged] if the connection state differs from the last one
+    async fn set_connected(&self, is_connected: bool) {
+        if self.is_connected.swap(is_connected, Ordering::SeqCst) != is_connected {
+            self.event_manager
+                .notify(SdkEvent::ConnectivityChanged { is_connected })
+                .await;
         }
     }
 }
@@ -32,6 +48,8 @@ impl SwapperReconnectHandler {
 #[async_trait]
 impl ReconnectHandler for SwapperReconnectHandler {
     async fn on_stream_reconnect(&self) {
+        self.set_connected(true).await;
+
         match self.persister.list_ongoing_swaps() {
             Ok(initial_ongoing_swaps) => {
                 info!(
@@ -48,4 +66,60 @@ impl ReconnectHandler for SwapperReconnectHandler {
             Err(e) => error!("Failed to list initial ongoing swaps: {e:?}"),
         }
     }
+
+    async fn on_stream_disconnect(&self) {
+        info!("Swapper status stream disconnected");
+        self.set_connected(false).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+
+    use crate::{
+        event::EventManager,
+        model::SdkEvent,
+        swapper::ReconnectHandler,
+        test_utils::{persist::new_persister, status_stream::MockStatusStream},
+    };
+
+    use super::SwapperReconnectHandler;
+
+    #[tokio::test]
+    async fn test_connectivity_changed_events() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let event_manager = Arc::new(EventManager::new(persister.clone()));
+        let mut events = event_manager.subscribe();
+        let reconnect_handler = SwapperReconnectHandler::new(
+            persister,
+            Arc::new(MockStatusStream::new()),
+            event_manager,
+        );
+
+        reconnect_handler.on_stream_reconnect().await;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::ConnectivityChanged { is_connected: true }
+        );
+
+        // Reconnecting while already connected doesn't emit a new event
+        reconnect_handler.on_stream_reconnect().await;
+        assert!(events.try_recv().is_err());
+
+        reconnect_handler.on_stream_disconnect().await;
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::ConnectivityChanged {
+                is_connected: false
+            }
+        );
+        reconnect_handler.on_stream_disconnect().await;
+        assert!(events.try_recv().is_err());
+
+        Ok(())
+    }
 }
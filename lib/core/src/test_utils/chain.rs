@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::{
     elements::{
@@ -44,6 +44,8 @@ impl From<MockHistory> for lwk_wollet::History {
 #[derive(Default)]
 pub(crate) struct MockLiquidChainService {
     history: Vec<MockHistory>,
+    is_offline: bool,
+    broadcast_error: Option<String>,
 }
 
 impl MockLiquidChainService {
@@ -55,12 +57,36 @@ impl MockLiquidChainService {
         self.history = history;
         self
     }
+
+    /// Makes all requests fail with a connection error
+    pub(crate) fn set_offline(&mut self, is_offline: bool) -> &mut Self {
+        self.is_offline = is_offline;
+        self
+    }
+
+    /// Makes broadcasts fail with the given reason, as returned by the backend
+    pub(crate) fn set_broadcast_error(&mut self, broadcast_error: Option<&str>) -> &mut Self {
+        self.broadcast_error = broadcast_error.map(ToString::to_string);
+        self
+    }
+
+    fn ensure_online(&self) -> Result<()> {
+        if self.is_offline {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                "connection refused",
+            )
+            .into());
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl LiquidChainService for MockLiquidChainService {
     async fn tip(&mut self) -> Result<u32> {
-        unimplemented!()
+        self.ensure_online()?;
+        Ok(0)
     }
 
     async fn broadcast(
@@ -68,6 +94,10 @@ impl LiquidChainService for MockLiquidChainService {
         tx: &lwk_wollet::elements::Transaction,
         _swap_id: Option<&str>,
     ) -> Result<lwk_wollet::elements::Txid> {
+        self.ensure_online()?;
+        if let Some(err) = &self.broadcast_error {
+            return Err(anyhow!("Failed to broadcast tx: {err}"));
+        }
         Ok(tx.txid())
     }
 
@@ -75,7 +105,8 @@ impl LiquidChainService for MockLiquidChainService {
         &self,
         _txid: &lwk_wollet::elements::Txid,
     ) -> Result<Option<lwk_wollet::elements::Transaction>> {
-        unimplemented!()
+        self.ensure_online()?;
+        Ok(None)
     }
 
     async fn get_transactions(
@@ -139,25 +170,25 @@ impl MockBitcoinChainService {
 
 #[async_trait]
 impl BitcoinChainService for MockBitcoinChainService {
-    fn tip(&mut self) -> Result<electrum_client::HeaderNotification> {
+    async fn tip(&mut self) -> Result<electrum_client::HeaderNotification> {
         unimplemented!()
     }
 
-    fn broadcast(
+    async fn broadcast(
         &self,
         tx: &boltz_client::bitcoin::Transaction,
     ) -> Result<boltz_client::bitcoin::Txid, anyhow::Error> {
         Ok(tx.txid())
     }
 
-    fn get_transactions(
+    async fn get_transactions(
         &self,
         _txids: &[boltz_client::bitcoin::Txid],
     ) -> Result<Vec<boltz_client::bitcoin::Transaction>> {
         unimplemented!()
     }
 
-    fn get_script_history(&self, _script: &Script) -> Result<Vec<lwk_wollet::History>> {
+    async fn get_script_history(&self, _script: &Script) -> Result<Vec<lwk_wollet::History>> {
         Ok(self.history.clone().into_iter().map(Into::into).collect())
     }
 
@@ -169,7 +200,7 @@ impl BitcoinChainService for MockBitcoinChainService {
         Ok(self.history.clone().into_iter().map(Into::into).collect())
     }
 
-    fn get_scripts_history(&self, _scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
+    async fn get_scripts_history(&self, _scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
         unimplemented!()
     }
 
@@ -182,14 +213,14 @@ impl BitcoinChainService for MockBitcoinChainService {
         Ok(vec![Utxo::Bitcoin((out_point, tx_out))])
     }
 
-    fn script_get_balance(
+    async fn script_get_balance(
         &self,
         _script: &boltz_client::bitcoin::Script,
     ) -> Result<electrum_client::GetBalanceRes> {
         unimplemented!()
     }
 
-    fn scripts_get_balance(&self, _scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
+    async fn scripts_get_balance(&self, _scripts: &[&Script]) -> Result<Vec<GetBalanceRes>> {
         unimplemented!()
     }
 
@@ -10,6 +10,7 @@ use tokio::sync::Mutex;
 
 use crate::{
     chain_swap::ChainSwapHandler,
+    event::EventManager,
     model::{ChainSwap, Config, Direction, PaymentState},
     persist::Persister,
     swapper::boltz::BoltzSwapper,
@@ -36,10 +37,11 @@ pub(crate) fn new_chain_swap_handler(persister: Arc<Persister>) -> Result<ChainS
     ChainSwapHandler::new(
         config,
         onchain_wallet,
-        persister,
+        persister.clone(),
         swapper,
         liquid_chain_service,
         bitcoin_chain_service,
+        Arc::new(EventManager::new(persister)),
     )
 }
 
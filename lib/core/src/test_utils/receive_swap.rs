@@ -5,7 +5,9 @@ use std::sync::Arc;
 
 use tokio::sync::Mutex;
 
-use crate::{model::Config, persist::Persister, receive_swap::ReceiveSwapHandler};
+use crate::{
+    event::EventManager, model::Config, persist::Persister, receive_swap::ReceiveSwapHandler,
+};
 
 use super::{chain::MockLiquidChainService, swapper::MockSwapper, wallet::MockWallet};
 
@@ -18,8 +20,9 @@ pub(crate) fn new_receive_swap_handler(persister: Arc<Persister>) -> Result<Rece
     Ok(ReceiveSwapHandler::new(
         config,
         onchain_wallet,
-        persister,
+        persister.clone(),
         swapper,
         liquid_chain_service,
+        Arc::new(EventManager::new(persister)),
     ))
 }
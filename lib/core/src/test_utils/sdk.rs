@@ -15,6 +15,7 @@ use crate::{
     receive_swap::ReceiveSwapHandler,
     sdk::LiquidSdk,
     send_swap::SendSwapHandler,
+    webhook::WebhookDispatcher,
 };
 
 use super::{
@@ -58,12 +59,15 @@ pub(crate) fn new_liquid_sdk_with_chain_services(
     let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()));
     let onchain_wallet = Arc::new(MockWallet::new());
 
+    let event_manager = Arc::new(EventManager::new(persister.clone()));
+
     let send_swap_handler = SendSwapHandler::new(
         config.clone(),
         onchain_wallet.clone(),
         persister.clone(),
         swapper.clone(),
         liquid_chain_service.clone(),
+        event_manager.clone(),
     );
 
     let receive_swap_handler = ReceiveSwapHandler::new(
@@ -72,6 +76,7 @@ pub(crate) fn new_liquid_sdk_with_chain_services(
         persister.clone(),
         swapper.clone(),
         liquid_chain_service.clone(),
+        event_manager.clone(),
     );
 
     let chain_swap_handler = Arc::new(ChainSwapHandler::new(
@@ -81,9 +86,9 @@ pub(crate) fn new_liquid_sdk_with_chain_services(
         swapper.clone(),
         liquid_chain_service.clone(),
         bitcoin_chain_service.clone(),
+        event_manager.clone(),
     )?);
 
-    let event_manager = Arc::new(EventManager::new());
     let (shutdown_sender, shutdown_receiver) = watch::channel::<()>(());
 
     let breez_server = Arc::new(BreezServer::new(STAGING_BREEZSERVER_URL.into(), None)?);
@@ -91,6 +96,13 @@ pub(crate) fn new_liquid_sdk_with_chain_services(
     let buy_bitcoin_service =
         Arc::new(BuyBitcoinService::new(config.clone(), breez_server.clone()));
 
+    let webhook_dispatcher = Arc::new(WebhookDispatcher::new(
+        vec![],
+        persister.clone(),
+        signer.clone(),
+        event_manager.clone(),
+    ));
+
     Ok(LiquidSdk {
         config,
         onchain_wallet,
@@ -109,5 +121,8 @@ pub(crate) fn new_liquid_sdk_with_chain_services(
         receive_swap_handler,
         chain_swap_handler,
         buy_bitcoin_service,
+        webhook_dispatcher,
+        last_balance_sat: RwLock::new(None),
+        recent_swap_updates: RwLock::new(Default::default()),
     })
 }
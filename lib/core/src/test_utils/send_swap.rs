@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use crate::{model::Config, persist::Persister, send_swap::SendSwapHandler};
+use crate::{event::EventManager, model::Config, persist::Persister, send_swap::SendSwapHandler};
 use anyhow::Result;
 use tokio::sync::Mutex;
 
@@ -17,8 +17,9 @@ pub(crate) fn new_send_swap_handler(persister: Arc<Persister>) -> Result<SendSwa
     Ok(SendSwapHandler::new(
         config,
         onchain_wallet,
-        persister,
+        persister.clone(),
         swapper,
         chain_service,
+        Arc::new(EventManager::new(persister)),
     ))
 }
@@ -1,9 +1,12 @@
 #![cfg(test)]
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::boltz;
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use tokio::sync::{broadcast, watch};
 
@@ -11,13 +14,25 @@ use crate::swapper::{ReconnectHandler, SwapperStatusStream};
 
 pub(crate) struct MockStatusStream {
     pub update_notifier: broadcast::Sender<boltz::Update>,
+    swap_statuses: Mutex<HashMap<String, boltz::Update>>,
 }
 
 impl MockStatusStream {
     pub(crate) fn new() -> Self {
         let (update_notifier, _) = broadcast::channel::<boltz::Update>(30);
 
-        Self { update_notifier }
+        Self {
+            update_notifier,
+            swap_statuses: Default::default(),
+        }
+    }
+
+    /// Sets the status returned by [SwapperStatusStream::fetch_swap_status] for the swap
+    pub(crate) fn set_swap_status(&self, update: boltz::Update) {
+        self.swap_statuses
+            .lock()
+            .unwrap()
+            .insert(update.id.clone(), update);
     }
 
     pub(crate) async fn send_mock_update(self: Arc<Self>, update: boltz::Update) -> Result<()> {
@@ -45,4 +60,13 @@ impl SwapperStatusStream for MockStatusStream {
     fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz::Update> {
         self.update_notifier.subscribe()
     }
+
+    async fn fetch_swap_status(&self, swap_id: &str) -> Result<boltz::Update> {
+        self.swap_statuses
+            .lock()
+            .unwrap()
+            .get(swap_id)
+            .cloned()
+            .ok_or(anyhow!("No status available for swap {swap_id}"))
+    }
 }
@@ -210,9 +210,18 @@ impl Swapper for MockSwapper {
         &self,
         _swap: Swap,
         _refund_address: &str,
-        _fee_rate_sat_per_vb: Option<f64>,
+        fee_rate_sat_per_vb: Option<f64>,
+        is_cooperative: bool,
     ) -> Result<(u32, u64), SdkError> {
-        Ok((0, 0))
+        let tx_vsize = match is_cooperative {
+            true => 100,
+            false => 150,
+        };
+        let fee_rate_sat_per_vb = fee_rate_sat_per_vb.unwrap_or(0.1);
+        Ok((
+            tx_vsize,
+            (tx_vsize as f64 * fee_rate_sat_per_vb).ceil() as u64,
+        ))
     }
 
     fn create_refund_tx(
@@ -13,6 +13,7 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 use lwk_wollet::{
     elements::{Address, Transaction},
+    hashes::{sha256, Hash, HashEngine, Hmac, HmacEngine},
     Tip, WalletTx,
 };
 
@@ -120,7 +121,11 @@ impl Signer for MockSigner {
         todo!()
     }
 
-    fn hmac_sha256(&self, _msg: Vec<u8>, _derivation_path: String) -> Result<Vec<u8>, SignerError> {
-        todo!()
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        let mut engine = HmacEngine::<sha256::Hash>::new(derivation_path.as_bytes());
+        engine.input(msg.as_slice());
+        Ok(Hmac::<sha256::Hash>::from_engine(engine)
+            .as_byte_array()
+            .to_vec())
     }
 }
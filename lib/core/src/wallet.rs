@@ -11,21 +11,23 @@ use lwk_common::Signer as LwkSigner;
 use lwk_common::{singlesig_desc, Singlesig};
 use lwk_wollet::{
     elements::{hex::ToHex, Address, Transaction},
-    ElectrumClient, ElectrumUrl, ElementsNetwork, FsPersister, Tip, WalletTx, Wollet,
-    WolletDescriptor,
+    BlockchainBackend, ElectrumClient, ElectrumUrl, ElementsNetwork, EsploraClient, FsPersister,
+    Tip, WalletTx, Wollet, WolletDescriptor,
 };
 use sdk_common::bitcoin::hashes::{sha256, Hash};
 use sdk_common::bitcoin::secp256k1::PublicKey;
 use sdk_common::lightning::util::message_signing::verify;
 use tokio::sync::Mutex;
 
+use crate::chain::parse_electrum_url;
 use crate::model::Signer;
 use crate::persist::Persister;
 use crate::signer::SdkLwkSigner;
 use crate::{
     ensure_sdk,
     error::PaymentError,
-    model::{Config, LiquidNetwork},
+    model::{ChainBackend, Config, LiquidNetwork},
+    utils,
 };
 use lwk_wollet::secp256k1::Message;
 
@@ -124,12 +126,89 @@ impl LiquidOnchainWallet {
         })
     }
 
+    /// Fails if the tx spends an output which a queued direct Liquid payment already spends.
+    /// LWK only learns about the inputs of a queued tx once it's broadcast, so it may select them
+    /// again, while the other wallet funds can still be spent.
+    fn ensure_no_conflict_with_queued_txs(&self, tx: &Transaction) -> Result<(), PaymentError> {
+        for outbox_tx in self.persister.list_outbox_txs()? {
+            let queued_tx = utils::deserialize_tx_hex(&outbox_tx.tx_hex)?;
+            let is_conflicting = tx.input.iter().any(|input| {
+                queued_tx
+                    .input
+                    .iter()
+                    .any(|queued_input| queued_input.previous_output == input.previous_output)
+            });
+            ensure_sdk!(
+                !is_conflicting,
+                PaymentError::generic(&format!(
+                    "The payment spends the same funds as queued payment {}, which is still waiting to be broadcast",
+                    outbox_tx.tx_id
+                ))
+            );
+        }
+        Ok(())
+    }
+
+    /// Scans the wallet up to the given derivation index, falling back to the next Liquid chain
+    /// backend which LWK can sync from when a scan fails
+    fn full_scan_to_index(&self, wallet: &mut Wollet, index: u32) -> Result<(), lwk_wollet::Error> {
+        let mut last_err = None;
+        for backend in self.config.liquid_chain_backends() {
+            match Self::full_scan_to_index_with_backend(wallet, index, backend) {
+                Ok(true) => return Ok(()),
+                Ok(false) => continue,
+                Err(e @ lwk_wollet::Error::UpdateHeightTooOld { .. }) => return Err(e),
+                Err(e) => {
+                    warn!("Full scan failed with Liquid chain backend {backend:?}: {e:?}");
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            lwk_wollet::Error::Generic(
+                "An Electrum or Esplora Liquid chain backend is needed to sync the wallet"
+                    .to_string(),
+            )
+        }))
+    }
+
+    /// Scans the wallet with the given backend, returning whether LWK can sync from it
+    fn full_scan_to_index_with_backend(
+        wallet: &mut Wollet,
+        index: u32,
+        backend: &ChainBackend,
+    ) -> Result<bool, lwk_wollet::Error> {
+        match backend {
+            ChainBackend::Esplora { url } => {
+                let mut esplora_client = EsploraClient::new(url);
+                if let Some(update) = esplora_client.full_scan_to_index(wallet, index)? {
+                    wallet.apply_update(update)?;
+                }
+                Ok(true)
+            }
+            ChainBackend::Electrum { url }
+            | ChainBackend::Hybrid {
+                electrum_url: url, ..
+            } => {
+                let (url, tls) = parse_electrum_url(url);
+                let mut electrum_client = ElectrumClient::new(&ElectrumUrl::new(url, tls, tls))?;
+                lwk_wollet::full_scan_to_index_with_electrum_client(
+                    wallet,
+                    index,
+                    &mut electrum_client,
+                )?;
+                Ok(true)
+            }
+            ChainBackend::Rpc { .. } => Ok(false),
+        }
+    }
+
     fn create_wallet<P: AsRef<Path>>(
         config: &Config,
         working_dir: P,
         signer: &SdkLwkSigner,
     ) -> Result<Wollet> {
-        let elements_network: ElementsNetwork = config.network.into();
+        let elements_network = config.elements_network();
         let descriptor = LiquidOnchainWallet::get_descriptor(signer, config.network)?;
         let mut lwk_persister =
             FsPersister::new(working_dir.as_ref(), elements_network, &descriptor)?;
@@ -187,7 +266,7 @@ impl OnchainWallet for LiquidOnchainWallet {
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError> {
         let lwk_wollet = self.wallet.lock().await;
-        let mut pset = lwk_wollet::TxBuilder::new(self.config.network.into())
+        let mut pset = lwk_wollet::TxBuilder::new(self.config.elements_network())
             .add_lbtc_recipient(
                 &ElementsAddress::from_str(recipient_address).map_err(|e| {
                     PaymentError::Generic {
@@ -205,7 +284,9 @@ impl OnchainWallet for LiquidOnchainWallet {
             .map_err(|e| PaymentError::Generic {
                 err: format!("Failed to sign transaction: {e:?}"),
             })?;
-        Ok(lwk_wollet.finalize(&mut pset)?)
+        let tx = lwk_wollet.finalize(&mut pset)?;
+        self.ensure_no_conflict_with_queued_txs(&tx)?;
+        Ok(tx)
     }
 
     async fn build_drain_tx(
@@ -251,7 +332,9 @@ impl OnchainWallet for LiquidOnchainWallet {
             .map_err(|e| PaymentError::Generic {
                 err: format!("Failed to sign transaction: {e:?}"),
             })?;
-        Ok(lwk_wollet.finalize(&mut pset)?)
+        let tx = lwk_wollet.finalize(&mut pset)?;
+        self.ensure_no_conflict_with_queued_txs(&tx)?;
+        Ok(tx)
     }
 
     async fn build_tx_or_drain_tx(
@@ -323,30 +406,17 @@ impl OnchainWallet for LiquidOnchainWallet {
     /// Perform a full scan of the wallet
     async fn full_scan(&self) -> Result<(), PaymentError> {
         let mut wallet = self.wallet.lock().await;
-        let mut electrum_client = ElectrumClient::new(&ElectrumUrl::new(
-            &self.config.liquid_electrum_url,
-            true,
-            true,
-        ))?;
         let index = self
             .persister
             .get_last_derivation_index()?
             .unwrap_or_default();
-        match lwk_wollet::full_scan_to_index_with_electrum_client(
-            &mut wallet,
-            index,
-            &mut electrum_client,
-        ) {
+        match self.full_scan_to_index(&mut wallet, index) {
             Ok(()) => Ok(()),
             Err(lwk_wollet::Error::UpdateHeightTooOld { .. }) => {
                 warn!("Full scan failed with update height too old, wiping storage and retrying");
                 let mut new_wallet =
                     Self::create_wallet(&self.config, &self.working_dir, &self.signer)?;
-                lwk_wollet::full_scan_to_index_with_electrum_client(
-                    &mut new_wallet,
-                    index,
-                    &mut electrum_client,
-                )?;
+                self.full_scan_to_index(&mut new_wallet, index)?;
                 *wallet = new_wallet;
                 Ok(())
             }
@@ -0,0 +1,339 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use lwk_wollet::hashes::{sha256, Hash, HashEngine, Hmac, HmacEngine};
+use reqwest::header::CONTENT_TYPE;
+use tokio::sync::{broadcast, watch};
+use tokio::time::MissedTickBehavior;
+
+use crate::event::EventManager;
+use crate::model::{Signer, WebhookDelivery, WebhookDeliveryState};
+use crate::persist::Persister;
+use crate::sdk::EVENT_LOG_PAGE_SIZE;
+use crate::utils;
+
+/// The derivation path of the key used to derive the webhook signing secret
+const WEBHOOK_SECRET_DERIVATION_PATH: &str = "m/1729'/0'";
+const WEBHOOK_SECRET_MESSAGE: &[u8] = b"breez-sdk-liquid-webhook-secret";
+/// The header containing the hex encoded HMAC-SHA256 of the request body
+pub(crate) const WEBHOOK_SIGNATURE_HEADER: &str = "X-Breez-Signature";
+/// The header containing the [WebhookDelivery::id], which stays the same across retries
+pub(crate) const WEBHOOK_DELIVERY_ID_HEADER: &str = "X-Breez-Delivery-Id";
+/// Number of failed attempts after which a delivery is marked as failed
+const WEBHOOK_MAX_ATTEMPTS: u32 = 10;
+const WEBHOOK_BASE_RETRY_DELAY_SECS: u32 = 5;
+const WEBHOOK_MAX_RETRY_DELAY_SECS: u32 = 60 * 60;
+const WEBHOOK_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Delivers the persisted events to the configured webhook urls.
+///
+/// Events are read from the event log and queued in a persisted outbox, one entry per url,
+/// from where they are POSTed until delivered or until [WEBHOOK_MAX_ATTEMPTS] is reached.
+pub(crate) struct WebhookDispatcher {
+    urls: Vec<String>,
+    persister: Arc<Persister>,
+    signer: Arc<Box<dyn Signer>>,
+    event_manager: Arc<EventManager>,
+    client: reqwest::Client,
+}
+
+impl WebhookDispatcher {
+    pub(crate) fn new(
+        urls: Vec<String>,
+        persister: Arc<Persister>,
+        signer: Arc<Box<dyn Signer>>,
+        event_manager: Arc<EventManager>,
+    ) -> Self {
+        Self {
+            urls,
+            persister,
+            signer,
+            event_manager,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub(crate) async fn start(self: Arc<Self>, mut shutdown: watch::Receiver<()>) {
+        if self.urls.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.event_cursor() {
+            warn!("Could not initialize the webhook event cursor: {e:?}");
+        }
+
+        let mut events_stream = self.event_manager.subscribe();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(WEBHOOK_BASE_RETRY_DELAY_SECS as u64));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    res = events_stream.recv() => {
+                        if let Err(broadcast::error::RecvError::Closed) = res {
+                            info!("Event stream closed, exiting webhook dispatcher loop");
+                            return;
+                        }
+                    }
+                    _ = interval.tick() => {}
+                    _ = shutdown.changed() => {
+                        info!("Received shutdown signal, exiting webhook dispatcher loop");
+                        return;
+                    }
+                }
+
+                if let Err(e) = self.enqueue_new_events() {
+                    warn!("Could not queue events for webhook delivery: {e:?}");
+                }
+                if let Err(e) = self.deliver_due().await {
+                    warn!("Could not deliver webhooks: {e:?}");
+                }
+            }
+        });
+    }
+
+    /// The secret used to sign the webhook payloads, derived from the [Signer]
+    pub(crate) fn signing_secret(&self) -> Result<Vec<u8>> {
+        Ok(self.signer.hmac_sha256(
+            WEBHOOK_SECRET_MESSAGE.to_vec(),
+            WEBHOOK_SECRET_DERIVATION_PATH.to_string(),
+        )?)
+    }
+
+    pub(crate) fn sign(secret: &[u8], payload: &str) -> String {
+        let mut engine = HmacEngine::<sha256::Hash>::new(secret);
+        engine.input(payload.as_bytes());
+        hex::encode(Hmac::<sha256::Hash>::from_engine(engine).as_byte_array())
+    }
+
+    /// The sequence number of the last event queued for delivery.
+    ///
+    /// Once webhooks are first configured, delivery starts with the events logged from then on,
+    /// rather than with all the events still in the event log.
+    fn event_cursor(&self) -> Result<u64> {
+        if let Some(seq) = self.persister.get_webhook_event_cursor()? {
+            return Ok(seq);
+        }
+        let seq = self.persister.get_last_event_seq()?.unwrap_or(0);
+        self.persister.set_webhook_event_cursor(seq)?;
+        Ok(seq)
+    }
+
+    /// Queues the events logged since the last call for delivery
+    pub(crate) fn enqueue_new_events(&self) -> Result<()> {
+        let mut last_seq = self.event_cursor()?;
+        loop {
+            let entries = self
+                .persister
+                .list_events_since(last_seq, EVENT_LOG_PAGE_SIZE)?;
+            if entries.is_empty() {
+                return Ok(());
+            }
+            for entry in entries {
+                let payload = serde_json::to_string(&entry)?;
+                self.persister
+                    .enqueue_webhook_deliveries(entry.seq, &self.urls, &payload)?;
+                last_seq = entry.seq;
+            }
+        }
+    }
+
+    /// Attempts the deliveries that are due, rescheduling the failed ones
+    pub(crate) async fn deliver_due(&self) -> Result<()> {
+        let deliveries = self.persister.list_due_webhook_deliveries(utils::now())?;
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let secret = self.signing_secret()?;
+        for delivery in deliveries {
+            match self.post(&secret, &delivery).await {
+                Ok(_) => {
+                    info!(
+                        "Delivered event {} to webhook {}",
+                        delivery.event_seq, delivery.url
+                    );
+                    self.persister.delete_webhook_delivery(delivery.id)?;
+                }
+                Err(e) => {
+                    let attempts = delivery.attempts + 1;
+                    warn!(
+                        "Failed to deliver event {} to webhook {} (attempt {attempts}): {e:?}",
+                        delivery.event_seq, delivery.url
+                    );
+                    let state = match attempts >= WEBHOOK_MAX_ATTEMPTS {
+                        true => WebhookDeliveryState::Failed,
+                        false => WebhookDeliveryState::Pending,
+                    };
+                    self.persister.update_webhook_delivery_attempt(
+                        delivery.id,
+                        state,
+                        attempts,
+                        utils::now() + Self::retry_delay_secs(attempts),
+                        Some(e.to_string()),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Exponential backoff, capped at [WEBHOOK_MAX_RETRY_DELAY_SECS]
+    fn retry_delay_secs(attempts: u32) -> u32 {
+        WEBHOOK_BASE_RETRY_DELAY_SECS
+            .saturating_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+            .min(WEBHOOK_MAX_RETRY_DELAY_SECS)
+    }
+
+    async fn post(&self, secret: &[u8], delivery: &WebhookDelivery) -> Result<()> {
+        let response = self
+            .client
+            .post(&delivery.url)
+            .timeout(Duration::from_secs(WEBHOOK_REQUEST_TIMEOUT_SECS))
+            .header(CONTENT_TYPE, "application/json")
+            .header(
+                WEBHOOK_SIGNATURE_HEADER,
+                Self::sign(secret, &delivery.payload),
+            )
+            .header(WEBHOOK_DELIVERY_ID_HEADER, delivery.id.to_string())
+            .body(delivery.payload.clone())
+            .send()
+            .await?;
+        match response.status().is_success() {
+            true => Ok(()),
+            false => Err(anyhow!("Unexpected response status {}", response.status())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::model::SdkEvent;
+    use crate::test_utils::{persist::new_persister, wallet::MockSigner};
+
+    /// Answers a single HTTP request with the given status and returns the received
+    /// headers and body
+    fn serve_once(status: u16) -> Result<(String, mpsc::Receiver<(Vec<String>, String)>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/", listener.local_addr()?);
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut headers = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                headers.push(line.trim().to_lowercase());
+            }
+            let content_length = headers
+                .iter()
+                .find_map(|h| h.strip_prefix("content-length: "))
+                .and_then(|len| len.parse::<usize>().ok())
+                .unwrap_or(0);
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            write!(stream, "HTTP/1.1 {status} OK\r\nContent-Length: 0\r\n\r\n").unwrap();
+            tx.send((headers, String::from_utf8(body).unwrap()))
+                .unwrap();
+        });
+        Ok((url, rx))
+    }
+
+    fn new_dispatcher(url: String) -> Result<(tempdir::TempDir, WebhookDispatcher)> {
+        let (temp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let event_manager = Arc::new(EventManager::new(persister.clone()));
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()));
+        Ok((
+            temp_dir,
+            WebhookDispatcher::new(vec![url], persister, signer, event_manager),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery() -> Result<()> {
+        let (url, rx) = serve_once(200)?;
+        let (_temp_dir, dispatcher) = new_dispatcher(url)?;
+
+        // Events logged before webhooks are first configured are not delivered
+        dispatcher.event_manager.notify(SdkEvent::Synced).await;
+        dispatcher.enqueue_new_events()?;
+        assert!(dispatcher
+            .persister
+            .list_due_webhook_deliveries(u32::MAX)?
+            .is_empty());
+
+        dispatcher
+            .event_manager
+            .notify(SdkEvent::SwapRefundable {
+                swap_id: "swap-id".to_string(),
+            })
+            .await;
+        // Moving a consumer event cursor does not affect the dispatcher
+        dispatcher
+            .persister
+            .set_event_cursor("webhook_dispatcher", 0)?;
+        dispatcher.enqueue_new_events()?;
+        // Queuing again does not duplicate the delivery
+        dispatcher.enqueue_new_events()?;
+        dispatcher.deliver_due().await?;
+
+        let (headers, body) = rx.recv()?;
+        assert!(body.contains("SwapRefundable"));
+        let signature = WebhookDispatcher::sign(&dispatcher.signing_secret()?, &body);
+        assert!(headers.contains(&format!(
+            "{}: {signature}",
+            WEBHOOK_SIGNATURE_HEADER.to_lowercase()
+        )));
+        assert!(dispatcher
+            .persister
+            .list_due_webhook_deliveries(u32::MAX)?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_retry() -> Result<()> {
+        let (url, _rx) = serve_once(500)?;
+        let (_temp_dir, dispatcher) = new_dispatcher(url)?;
+
+        dispatcher.enqueue_new_events()?;
+        dispatcher.event_manager.notify(SdkEvent::Synced).await;
+        dispatcher.enqueue_new_events()?;
+        dispatcher.deliver_due().await?;
+
+        let deliveries = dispatcher.persister.list_due_webhook_deliveries(u32::MAX)?;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].state, WebhookDeliveryState::Pending);
+        assert_eq!(deliveries[0].attempts, 1);
+        assert!(deliveries[0].next_attempt_at > utils::now());
+        assert!(deliveries[0].last_error.is_some());
+        assert!(dispatcher
+            .persister
+            .list_failed_webhook_deliveries()?
+            .is_empty());
+
+        assert_eq!(WebhookDispatcher::retry_delay_secs(1), 5);
+        assert_eq!(WebhookDispatcher::retry_delay_secs(3), 20);
+        assert_eq!(
+            WebhookDispatcher::retry_delay_secs(WEBHOOK_MAX_ATTEMPTS + 10),
+            WEBHOOK_MAX_RETRY_DELAY_SECS
+        );
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ library;
 export 'src/bindings.dart';
 export 'src/model.dart';
 export 'src/error.dart';
+export 'src/error_info.dart';
 export 'src/bindings/duplicates.dart';
 
 import 'dart:io';
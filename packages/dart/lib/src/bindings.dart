@@ -29,8 +29,15 @@ Future<InputType> parse({required String input}) => RustLib.instance.api.crateBi
 LNInvoice parseInvoice({required String input}) =>
     RustLib.instance.api.crateBindingsParseInvoice(input: input);
 
+ErrorInfo sdkErrorInfo({required SdkError err}) => RustLib.instance.api.crateBindingsSdkErrorInfo(err: err);
+
+ErrorInfo paymentErrorInfo({required PaymentError err}) =>
+    RustLib.instance.api.crateBindingsPaymentErrorInfo(err: err);
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>
 abstract class BindingLiquidSdk implements RustOpaqueInterface {
+  void ackEvents({required String listenerId, required BigInt seq});
+
   Stream<SdkEvent> addEventListener();
 
   void backup({required BackupRequest req});
@@ -43,16 +50,40 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   void emptyWalletCache();
 
+  List<EventLogEntry> eventsSince({required BigInt seq});
+
+  String exportRescueFile({required String swapId});
+
   Future<List<Rate>> fetchFiatRates();
 
   Future<LightningPaymentLimitsResponse> fetchLightningLimits();
 
   Future<OnchainPaymentLimitsResponse> fetchOnchainLimits();
 
+  Future<void> forceClaimSwap({required String swapId});
+
+  Future<String> forceRefundSendSwap({required String swapId, required bool isCooperative});
+
+  Future<String> generateDiagnostics();
+
+  BigInt? getEventCursor({required String listenerId});
+
+  Future<Rate?> getHistoricalFiatRate({required String currency, required int timestamp});
+
   Future<GetInfoResponse> getInfo();
 
+  SyncMetrics? getLastSyncMetrics();
+
   Future<Payment?> getPayment({required GetPaymentRequest req});
 
+  List<SwapStateTransition> getSwapHistory({required String swapId});
+
+  String getWebhookSigningSecret();
+
+  Future<void> importSwap({required String rescueJson});
+
+  List<WebhookDelivery> listFailedWebhookDeliveries();
+
   Future<List<FiatCurrency>> listFiatCurrencies();
 
   Future<List<Payment>> listPayments({required ListPaymentsRequest req});
@@ -65,6 +96,8 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req});
 
+  Future<void> markSwapFailed({required String swapId});
+
   Future<SendPaymentResponse> payOnchain({required PayOnchainRequest req});
 
   Future<PrepareBuyBitcoinResponse> prepareBuyBitcoin({required PrepareBuyBitcoinRequest req});
@@ -83,6 +116,8 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   Future<RecommendedFees> recommendedFees();
 
+  Future<void> refetchSwapStatus({required String swapId});
+
   Future<RefundResponse> refund({required RefundRequest req});
 
   Future<void> registerWebhook({required String webhookUrl});
@@ -91,12 +126,16 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   void restore({required RestoreRequest req});
 
+  void retryWebhookDelivery({required BigInt id});
+
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req});
 
   SignMessageResponse signMessage({required SignMessageRequest req});
 
   Future<void> sync();
 
+  Future<SyncOnceResponse> syncOnce({required SyncOnceRequest req});
+
   Future<void> unregisterWebhook();
 }
 
@@ -8,6 +8,33 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'error.freezed.dart';
 
+/// A stable numeric code and retryable flag describing an [SdkError] or [PaymentError].
+///
+/// Codes never change meaning between releases: `1xx` codes are used by [SdkError] and `2xx`
+/// codes by [PaymentError].
+class ErrorInfo {
+  final int code;
+
+  /// Whether the same call may succeed if retried later, without changing its arguments
+  final bool retryable;
+
+  const ErrorInfo({
+    required this.code,
+    required this.retryable,
+  });
+
+  @override
+  int get hashCode => code.hashCode ^ retryable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ErrorInfo &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          retryable == other.retryable;
+}
+
 @freezed
 sealed class PaymentError with _$PaymentError implements FrbException {
   const PaymentError._();
@@ -54,6 +81,12 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.signerError({
     required String err,
   }) = PaymentError_SignerError;
+  const factory PaymentError.swapper({
+    required String err,
+  }) = PaymentError_Swapper;
+  const factory PaymentError.chainService({
+    required String err,
+  }) = PaymentError_ChainService;
 }
 
 @freezed
@@ -68,4 +101,19 @@ sealed class SdkError with _$SdkError implements FrbException {
   const factory SdkError.serviceConnectivity({
     required String err,
   }) = SdkError_ServiceConnectivity;
+  const factory SdkError.swapper({
+    required String err,
+  }) = SdkError_Swapper;
+  const factory SdkError.chainService({
+    required String err,
+  }) = SdkError_ChainService;
+  const factory SdkError.persistence({
+    required String err,
+  }) = SdkError_Persistence;
+  const factory SdkError.signer({
+    required String err,
+  }) = SdkError_Signer;
+  const factory SdkError.validation({
+    required String err,
+  }) = SdkError_Validation;
 }
@@ -1324,6 +1324,164 @@ abstract class PaymentError_SignerError extends PaymentError {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$PaymentError_SwapperImplCopyWith<$Res> {
+  factory _$$PaymentError_SwapperImplCopyWith(
+          _$PaymentError_SwapperImpl value, $Res Function(_$PaymentError_SwapperImpl) then) =
+      __$$PaymentError_SwapperImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_SwapperImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_SwapperImpl>
+    implements _$$PaymentError_SwapperImplCopyWith<$Res> {
+  __$$PaymentError_SwapperImplCopyWithImpl(
+      _$PaymentError_SwapperImpl _value, $Res Function(_$PaymentError_SwapperImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_SwapperImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_SwapperImpl extends PaymentError_Swapper {
+  const _$PaymentError_SwapperImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.swapper(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_SwapperImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_SwapperImplCopyWith<_$PaymentError_SwapperImpl> get copyWith =>
+      __$$PaymentError_SwapperImplCopyWithImpl<_$PaymentError_SwapperImpl>(this, _$identity);
+}
+
+abstract class PaymentError_Swapper extends PaymentError {
+  const factory PaymentError_Swapper({required final String err}) = _$PaymentError_SwapperImpl;
+  const PaymentError_Swapper._() : super._();
+
+  String get err;
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PaymentError_SwapperImplCopyWith<_$PaymentError_SwapperImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentError_ChainServiceImplCopyWith<$Res> {
+  factory _$$PaymentError_ChainServiceImplCopyWith(
+          _$PaymentError_ChainServiceImpl value, $Res Function(_$PaymentError_ChainServiceImpl) then) =
+      __$$PaymentError_ChainServiceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_ChainServiceImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_ChainServiceImpl>
+    implements _$$PaymentError_ChainServiceImplCopyWith<$Res> {
+  __$$PaymentError_ChainServiceImplCopyWithImpl(
+      _$PaymentError_ChainServiceImpl _value, $Res Function(_$PaymentError_ChainServiceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_ChainServiceImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_ChainServiceImpl extends PaymentError_ChainService {
+  const _$PaymentError_ChainServiceImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.chainService(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_ChainServiceImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_ChainServiceImplCopyWith<_$PaymentError_ChainServiceImpl> get copyWith =>
+      __$$PaymentError_ChainServiceImplCopyWithImpl<_$PaymentError_ChainServiceImpl>(this, _$identity);
+}
+
+abstract class PaymentError_ChainService extends PaymentError {
+  const factory PaymentError_ChainService({required final String err}) = _$PaymentError_ChainServiceImpl;
+  const PaymentError_ChainService._() : super._();
+
+  String get err;
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PaymentError_ChainServiceImplCopyWith<_$PaymentError_ChainServiceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$SdkError {}
 
@@ -1589,3 +1747,394 @@ abstract class SdkError_ServiceConnectivity extends SdkError {
   _$$SdkError_ServiceConnectivityImplCopyWith<_$SdkError_ServiceConnectivityImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
+
+/// @nodoc
+abstract class _$$SdkError_SwapperImplCopyWith<$Res> {
+  factory _$$SdkError_SwapperImplCopyWith(
+          _$SdkError_SwapperImpl value, $Res Function(_$SdkError_SwapperImpl) then) =
+      __$$SdkError_SwapperImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SdkError_SwapperImplCopyWithImpl<$Res> extends _$SdkErrorCopyWithImpl<$Res, _$SdkError_SwapperImpl>
+    implements _$$SdkError_SwapperImplCopyWith<$Res> {
+  __$$SdkError_SwapperImplCopyWithImpl(
+      _$SdkError_SwapperImpl _value, $Res Function(_$SdkError_SwapperImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SdkError_SwapperImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkError_SwapperImpl extends SdkError_Swapper {
+  const _$SdkError_SwapperImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SdkError.swapper(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkError_SwapperImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkError_SwapperImplCopyWith<_$SdkError_SwapperImpl> get copyWith =>
+      __$$SdkError_SwapperImplCopyWithImpl<_$SdkError_SwapperImpl>(this, _$identity);
+}
+
+abstract class SdkError_Swapper extends SdkError {
+  const factory SdkError_Swapper({required final String err}) = _$SdkError_SwapperImpl;
+  const SdkError_Swapper._() : super._();
+
+  String get err;
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkError_SwapperImplCopyWith<_$SdkError_SwapperImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkError_ChainServiceImplCopyWith<$Res> {
+  factory _$$SdkError_ChainServiceImplCopyWith(
+          _$SdkError_ChainServiceImpl value, $Res Function(_$SdkError_ChainServiceImpl) then) =
+      __$$SdkError_ChainServiceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SdkError_ChainServiceImplCopyWithImpl<$Res>
+    extends _$SdkErrorCopyWithImpl<$Res, _$SdkError_ChainServiceImpl>
+    implements _$$SdkError_ChainServiceImplCopyWith<$Res> {
+  __$$SdkError_ChainServiceImplCopyWithImpl(
+      _$SdkError_ChainServiceImpl _value, $Res Function(_$SdkError_ChainServiceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SdkError_ChainServiceImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkError_ChainServiceImpl extends SdkError_ChainService {
+  const _$SdkError_ChainServiceImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SdkError.chainService(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkError_ChainServiceImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkError_ChainServiceImplCopyWith<_$SdkError_ChainServiceImpl> get copyWith =>
+      __$$SdkError_ChainServiceImplCopyWithImpl<_$SdkError_ChainServiceImpl>(this, _$identity);
+}
+
+abstract class SdkError_ChainService extends SdkError {
+  const factory SdkError_ChainService({required final String err}) = _$SdkError_ChainServiceImpl;
+  const SdkError_ChainService._() : super._();
+
+  String get err;
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkError_ChainServiceImplCopyWith<_$SdkError_ChainServiceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkError_PersistenceImplCopyWith<$Res> {
+  factory _$$SdkError_PersistenceImplCopyWith(
+          _$SdkError_PersistenceImpl value, $Res Function(_$SdkError_PersistenceImpl) then) =
+      __$$SdkError_PersistenceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SdkError_PersistenceImplCopyWithImpl<$Res>
+    extends _$SdkErrorCopyWithImpl<$Res, _$SdkError_PersistenceImpl>
+    implements _$$SdkError_PersistenceImplCopyWith<$Res> {
+  __$$SdkError_PersistenceImplCopyWithImpl(
+      _$SdkError_PersistenceImpl _value, $Res Function(_$SdkError_PersistenceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SdkError_PersistenceImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkError_PersistenceImpl extends SdkError_Persistence {
+  const _$SdkError_PersistenceImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SdkError.persistence(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkError_PersistenceImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkError_PersistenceImplCopyWith<_$SdkError_PersistenceImpl> get copyWith =>
+      __$$SdkError_PersistenceImplCopyWithImpl<_$SdkError_PersistenceImpl>(this, _$identity);
+}
+
+abstract class SdkError_Persistence extends SdkError {
+  const factory SdkError_Persistence({required final String err}) = _$SdkError_PersistenceImpl;
+  const SdkError_Persistence._() : super._();
+
+  String get err;
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkError_PersistenceImplCopyWith<_$SdkError_PersistenceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkError_SignerImplCopyWith<$Res> {
+  factory _$$SdkError_SignerImplCopyWith(
+          _$SdkError_SignerImpl value, $Res Function(_$SdkError_SignerImpl) then) =
+      __$$SdkError_SignerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SdkError_SignerImplCopyWithImpl<$Res> extends _$SdkErrorCopyWithImpl<$Res, _$SdkError_SignerImpl>
+    implements _$$SdkError_SignerImplCopyWith<$Res> {
+  __$$SdkError_SignerImplCopyWithImpl(
+      _$SdkError_SignerImpl _value, $Res Function(_$SdkError_SignerImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SdkError_SignerImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkError_SignerImpl extends SdkError_Signer {
+  const _$SdkError_SignerImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SdkError.signer(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkError_SignerImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkError_SignerImplCopyWith<_$SdkError_SignerImpl> get copyWith =>
+      __$$SdkError_SignerImplCopyWithImpl<_$SdkError_SignerImpl>(this, _$identity);
+}
+
+abstract class SdkError_Signer extends SdkError {
+  const factory SdkError_Signer({required final String err}) = _$SdkError_SignerImpl;
+  const SdkError_Signer._() : super._();
+
+  String get err;
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkError_SignerImplCopyWith<_$SdkError_SignerImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkError_ValidationImplCopyWith<$Res> {
+  factory _$$SdkError_ValidationImplCopyWith(
+          _$SdkError_ValidationImpl value, $Res Function(_$SdkError_ValidationImpl) then) =
+      __$$SdkError_ValidationImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SdkError_ValidationImplCopyWithImpl<$Res>
+    extends _$SdkErrorCopyWithImpl<$Res, _$SdkError_ValidationImpl>
+    implements _$$SdkError_ValidationImplCopyWith<$Res> {
+  __$$SdkError_ValidationImplCopyWithImpl(
+      _$SdkError_ValidationImpl _value, $Res Function(_$SdkError_ValidationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SdkError_ValidationImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkError_ValidationImpl extends SdkError_Validation {
+  const _$SdkError_ValidationImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SdkError.validation(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkError_ValidationImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkError_ValidationImplCopyWith<_$SdkError_ValidationImpl> get copyWith =>
+      __$$SdkError_ValidationImplCopyWithImpl<_$SdkError_ValidationImpl>(this, _$identity);
+}
+
+abstract class SdkError_Validation extends SdkError {
+  const factory SdkError_Validation({required final String err}) = _$SdkError_ValidationImpl;
+  const SdkError_Validation._() : super._();
+
+  String get err;
+
+  /// Create a copy of SdkError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkError_ValidationImplCopyWith<_$SdkError_ValidationImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,12 @@
+import 'bindings.dart';
+import 'error.dart';
+
+/// Exposes the stable code and retryable flag of an [SdkError].
+extension SdkErrorInfo on SdkError {
+  ErrorInfo get info => sdkErrorInfo(err: this);
+}
+
+/// Exposes the stable code and retryable flag of a [PaymentError].
+extension PaymentErrorInfo on PaymentError {
+  ErrorInfo get info => paymentErrorInfo(err: this);
+}
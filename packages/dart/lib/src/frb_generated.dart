@@ -65,7 +65,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.4.0';
 
   @override
-  int get rustContentHash => 425220482;
+  int get rustContentHash => 1701918067;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'breez_sdk_liquid',
@@ -75,6 +75,9 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  void crateBindingsBindingLiquidSdkAckEvents(
+      {required BindingLiquidSdk that, required String listenerId, required BigInt seq});
+
   Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that});
 
   void crateBindingsBindingLiquidSdkBackup({required BindingLiquidSdk that, required BackupRequest req});
@@ -89,6 +92,12 @@ abstract class RustLibApi extends BaseApi {
 
   void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that});
 
+  List<EventLogEntry> crateBindingsBindingLiquidSdkEventsSince(
+      {required BindingLiquidSdk that, required BigInt seq});
+
+  String crateBindingsBindingLiquidSdkExportRescueFile(
+      {required BindingLiquidSdk that, required String swapId});
+
   Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that});
 
   Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits(
@@ -97,11 +106,38 @@ abstract class RustLibApi extends BaseApi {
   Future<OnchainPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchOnchainLimits(
       {required BindingLiquidSdk that});
 
+  Future<void> crateBindingsBindingLiquidSdkForceClaimSwap(
+      {required BindingLiquidSdk that, required String swapId});
+
+  Future<String> crateBindingsBindingLiquidSdkForceRefundSendSwap(
+      {required BindingLiquidSdk that, required String swapId, required bool isCooperative});
+
+  Future<String> crateBindingsBindingLiquidSdkGenerateDiagnostics({required BindingLiquidSdk that});
+
+  BigInt? crateBindingsBindingLiquidSdkGetEventCursor(
+      {required BindingLiquidSdk that, required String listenerId});
+
+  Future<Rate?> crateBindingsBindingLiquidSdkGetHistoricalFiatRate(
+      {required BindingLiquidSdk that, required String currency, required int timestamp});
+
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that});
 
+  SyncMetrics? crateBindingsBindingLiquidSdkGetLastSyncMetrics({required BindingLiquidSdk that});
+
   Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
       {required BindingLiquidSdk that, required GetPaymentRequest req});
 
+  List<SwapStateTransition> crateBindingsBindingLiquidSdkGetSwapHistory(
+      {required BindingLiquidSdk that, required String swapId});
+
+  String crateBindingsBindingLiquidSdkGetWebhookSigningSecret({required BindingLiquidSdk that});
+
+  Future<void> crateBindingsBindingLiquidSdkImportSwap(
+      {required BindingLiquidSdk that, required String rescueJson});
+
+  List<WebhookDelivery> crateBindingsBindingLiquidSdkListFailedWebhookDeliveries(
+      {required BindingLiquidSdk that});
+
   Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies(
       {required BindingLiquidSdk that});
 
@@ -119,6 +155,9 @@ abstract class RustLibApi extends BaseApi {
   Future<LnUrlWithdrawResult> crateBindingsBindingLiquidSdkLnurlWithdraw(
       {required BindingLiquidSdk that, required LnUrlWithdrawRequest req});
 
+  Future<void> crateBindingsBindingLiquidSdkMarkSwapFailed(
+      {required BindingLiquidSdk that, required String swapId});
+
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain(
       {required BindingLiquidSdk that, required PayOnchainRequest req});
 
@@ -145,6 +184,9 @@ abstract class RustLibApi extends BaseApi {
 
   Future<RecommendedFees> crateBindingsBindingLiquidSdkRecommendedFees({required BindingLiquidSdk that});
 
+  Future<void> crateBindingsBindingLiquidSdkRefetchSwapStatus(
+      {required BindingLiquidSdk that, required String swapId});
+
   Future<RefundResponse> crateBindingsBindingLiquidSdkRefund(
       {required BindingLiquidSdk that, required RefundRequest req});
 
@@ -155,6 +197,9 @@ abstract class RustLibApi extends BaseApi {
 
   void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req});
 
+  void crateBindingsBindingLiquidSdkRetryWebhookDelivery(
+      {required BindingLiquidSdk that, required BigInt id});
+
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
       {required BindingLiquidSdk that, required SendPaymentRequest req});
 
@@ -163,6 +208,9 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that});
 
+  Future<SyncOnceResponse> crateBindingsBindingLiquidSdkSyncOnce(
+      {required BindingLiquidSdk that, required SyncOnceRequest req});
+
   Future<void> crateBindingsBindingLiquidSdkUnregisterWebhook({required BindingLiquidSdk that});
 
   Future<void> crateBindingsBindingEventListenerOnEvent(
@@ -178,6 +226,10 @@ abstract class RustLibApi extends BaseApi {
 
   LNInvoice crateBindingsParseInvoice({required String input});
 
+  ErrorInfo crateBindingsPaymentErrorInfo({required PaymentError err});
+
+  ErrorInfo crateBindingsSdkErrorInfo({required SdkError err});
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk;
@@ -193,6 +245,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  void crateBindingsBindingLiquidSdkAckEvents(
+      {required BindingLiquidSdk that, required String listenerId, required BigInt seq}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(listenerId);
+        var arg2 = cst_encode_u_64(seq);
+        return wire.wire__crate__bindings__BindingLiquidSdk_ack_events(arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkAckEventsConstMeta,
+      argValues: [that, listenerId, seq],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkAckEventsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_ack_events",
+        argNames: ["that", "listenerId", "seq"],
+      );
+
   @override
   Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that}) {
     final listener = RustStreamSink<SdkEvent>();
@@ -345,6 +424,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  List<EventLogEntry> crateBindingsBindingLiquidSdkEventsSince(
+      {required BindingLiquidSdk that, required BigInt seq}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_u_64(seq);
+        return wire.wire__crate__bindings__BindingLiquidSdk_events_since(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_event_log_entry,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkEventsSinceConstMeta,
+      argValues: [that, seq],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkEventsSinceConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_events_since",
+        argNames: ["that", "seq"],
+      );
+
+  @override
+  String crateBindingsBindingLiquidSdkExportRescueFile(
+      {required BindingLiquidSdk that, required String swapId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_export_rescue_file(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkExportRescueFileConstMeta,
+      argValues: [that, swapId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkExportRescueFileConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_export_rescue_file",
+        argNames: ["that", "swapId"],
+      );
+
   @override
   Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -419,6 +550,136 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkForceClaimSwap(
+      {required BindingLiquidSdk that, required String swapId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_force_claim_swap(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkForceClaimSwapConstMeta,
+      argValues: [that, swapId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkForceClaimSwapConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_force_claim_swap",
+        argNames: ["that", "swapId"],
+      );
+
+  @override
+  Future<String> crateBindingsBindingLiquidSdkForceRefundSendSwap(
+      {required BindingLiquidSdk that, required String swapId, required bool isCooperative}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        var arg2 = cst_encode_bool(isCooperative);
+        return wire.wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(port_, arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkForceRefundSendSwapConstMeta,
+      argValues: [that, swapId, isCooperative],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkForceRefundSendSwapConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_force_refund_send_swap",
+        argNames: ["that", "swapId", "isCooperative"],
+      );
+
+  @override
+  Future<String> crateBindingsBindingLiquidSdkGenerateDiagnostics({required BindingLiquidSdk that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        return wire.wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGenerateDiagnosticsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGenerateDiagnosticsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_generate_diagnostics",
+        argNames: ["that"],
+      );
+
+  @override
+  BigInt? crateBindingsBindingLiquidSdkGetEventCursor(
+      {required BindingLiquidSdk that, required String listenerId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(listenerId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_event_cursor(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_u_64,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetEventCursorConstMeta,
+      argValues: [that, listenerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetEventCursorConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_event_cursor",
+        argNames: ["that", "listenerId"],
+      );
+
+  @override
+  Future<Rate?> crateBindingsBindingLiquidSdkGetHistoricalFiatRate(
+      {required BindingLiquidSdk that, required String currency, required int timestamp}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(currency);
+        var arg2 = cst_encode_u_32(timestamp);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(port_, arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_rate,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetHistoricalFiatRateConstMeta,
+      argValues: [that, currency, timestamp],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetHistoricalFiatRateConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_historical_fiat_rate",
+        argNames: ["that", "currency", "timestamp"],
+      );
+
   @override
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -443,6 +704,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  SyncMetrics? crateBindingsBindingLiquidSdkGetLastSyncMetrics({required BindingLiquidSdk that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_sync_metrics,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetLastSyncMetricsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetLastSyncMetricsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_last_sync_metrics",
+        argNames: ["that"],
+      );
+
   @override
   Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
       {required BindingLiquidSdk that, required GetPaymentRequest req}) {
@@ -469,6 +754,107 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  List<SwapStateTransition> crateBindingsBindingLiquidSdkGetSwapHistory(
+      {required BindingLiquidSdk that, required String swapId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_swap_history(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_swap_state_transition,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetSwapHistoryConstMeta,
+      argValues: [that, swapId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetSwapHistoryConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_swap_history",
+        argNames: ["that", "swapId"],
+      );
+
+  @override
+  String crateBindingsBindingLiquidSdkGetWebhookSigningSecret({required BindingLiquidSdk that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetWebhookSigningSecretConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetWebhookSigningSecretConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_webhook_signing_secret",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateBindingsBindingLiquidSdkImportSwap(
+      {required BindingLiquidSdk that, required String rescueJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(rescueJson);
+        return wire.wire__crate__bindings__BindingLiquidSdk_import_swap(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkImportSwapConstMeta,
+      argValues: [that, rescueJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkImportSwapConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_import_swap",
+        argNames: ["that", "rescueJson"],
+      );
+
+  @override
+  List<WebhookDelivery> crateBindingsBindingLiquidSdkListFailedWebhookDeliveries(
+      {required BindingLiquidSdk that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        return wire.wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_webhook_delivery,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkListFailedWebhookDeliveriesConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListFailedWebhookDeliveriesConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_failed_webhook_deliveries",
+        argNames: ["that"],
+      );
+
   @override
   Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies(
       {required BindingLiquidSdk that}) {
@@ -623,6 +1009,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkMarkSwapFailed(
+      {required BindingLiquidSdk that, required String swapId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkMarkSwapFailedConstMeta,
+      argValues: [that, swapId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkMarkSwapFailedConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_mark_swap_failed",
+        argNames: ["that", "swapId"],
+      );
+
   @override
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain(
       {required BindingLiquidSdk that, required PayOnchainRequest req}) {
@@ -855,6 +1267,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkRefetchSwapStatus(
+      {required BindingLiquidSdk that, required String swapId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(swapId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkRefetchSwapStatusConstMeta,
+      argValues: [that, swapId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRefetchSwapStatusConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_refetch_swap_status",
+        argNames: ["that", "swapId"],
+      );
+
   @override
   Future<RefundResponse> crateBindingsBindingLiquidSdkRefund(
       {required BindingLiquidSdk that, required RefundRequest req}) {
@@ -956,6 +1394,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  void crateBindingsBindingLiquidSdkRetryWebhookDelivery(
+      {required BindingLiquidSdk that, required BigInt id}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_u_64(id);
+        return wire.wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkRetryWebhookDeliveryConstMeta,
+      argValues: [that, id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRetryWebhookDeliveryConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_retry_webhook_delivery",
+        argNames: ["that", "id"],
+      );
+
   @override
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
       {required BindingLiquidSdk that, required SendPaymentRequest req}) {
@@ -1032,6 +1496,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<SyncOnceResponse> crateBindingsBindingLiquidSdkSyncOnce(
+      {required BindingLiquidSdk that, required SyncOnceRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_sync_once_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_sync_once(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_sync_once_response,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSyncOnceConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSyncOnceConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_sync_once",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<void> crateBindingsBindingLiquidSdkUnregisterWebhook({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -1194,6 +1684,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["input"],
       );
 
+  @override
+  ErrorInfo crateBindingsPaymentErrorInfo({required PaymentError err}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_payment_error(err);
+        return wire.wire__crate__bindings__payment_error_info(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_error_info,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBindingsPaymentErrorInfoConstMeta,
+      argValues: [err],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsPaymentErrorInfoConstMeta => const TaskConstMeta(
+        debugName: "payment_error_info",
+        argNames: ["err"],
+      );
+
+  @override
+  ErrorInfo crateBindingsSdkErrorInfo({required SdkError err}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_sdk_error(err);
+        return wire.wire__crate__bindings__sdk_error_info(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_error_info,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBindingsSdkErrorInfoConstMeta,
+      argValues: [err],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsSdkErrorInfoConstMeta => const TaskConstMeta(
+        debugName: "sdk_error_info",
+        argNames: ["err"],
+      );
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
 
@@ -1339,6 +1873,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Blockchain dco_decode_blockchain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Blockchain.values[raw as int];
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1411,6 +1951,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_connect_request(raw);
   }
 
+  @protected
+  FiatAmount dco_decode_box_autoadd_fiat_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fiat_amount(raw);
+  }
+
   @protected
   GetPaymentRequest dco_decode_box_autoadd_get_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1531,6 +2077,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_payment(raw);
   }
 
+  @protected
+  PaymentError dco_decode_box_autoadd_payment_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_payment_error(raw);
+  }
+
+  @protected
+  PaymentFiatRate dco_decode_box_autoadd_payment_fiat_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_payment_fiat_rate(raw);
+  }
+
   @protected
   PrepareBuyBitcoinRequest dco_decode_box_autoadd_prepare_buy_bitcoin_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1567,6 +2125,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_send_request(raw);
   }
 
+  @protected
+  Rate dco_decode_box_autoadd_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rate(raw);
+  }
+
   @protected
   ReceivePaymentRequest dco_decode_box_autoadd_receive_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1585,6 +2149,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_restore_request(raw);
   }
 
+  @protected
+  SchedulerIntervals dco_decode_box_autoadd_scheduler_intervals(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scheduler_intervals(raw);
+  }
+
+  @protected
+  SdkError dco_decode_box_autoadd_sdk_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sdk_error(raw);
+  }
+
   @protected
   SdkEvent dco_decode_box_autoadd_sdk_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1621,6 +2197,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_symbol(raw);
   }
 
+  @protected
+  SyncMetrics dco_decode_box_autoadd_sync_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_metrics(raw);
+  }
+
+  @protected
+  SyncOnceRequest dco_decode_box_autoadd_sync_once_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_once_request(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1656,6 +2244,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ChainBackend dco_decode_chain_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ChainBackend_Electrum(
+          url: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return ChainBackend_Esplora(
+          url: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return ChainBackend_Hybrid(
+          electrumUrl: dco_decode_String(raw[1]),
+          esploraUrl: dco_decode_String(raw[2]),
+        );
+      case 3:
+        return ChainBackend_Rpc(
+          url: dco_decode_String(raw[1]),
+          username: dco_decode_opt_String(raw[2]),
+          password: dco_decode_opt_String(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   CheckMessageRequest dco_decode_check_message_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1682,18 +2298,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Config dco_decode_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 21) throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
     return Config(
-      liquidElectrumUrl: dco_decode_String(arr[0]),
-      bitcoinElectrumUrl: dco_decode_String(arr[1]),
-      mempoolspaceUrl: dco_decode_String(arr[2]),
-      workingDir: dco_decode_String(arr[3]),
-      cacheDir: dco_decode_opt_String(arr[4]),
-      network: dco_decode_liquid_network(arr[5]),
-      paymentTimeoutSec: dco_decode_u_64(arr[6]),
-      zeroConfMinFeeRateMsat: dco_decode_u_32(arr[7]),
-      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[8]),
-      breezApiKey: dco_decode_opt_String(arr[9]),
+      liquidChainBackend: dco_decode_chain_backend(arr[0]),
+      bitcoinChainBackend: dco_decode_chain_backend(arr[1]),
+      liquidFallbackChainBackends: dco_decode_opt_list_chain_backend(arr[2]),
+      bitcoinFallbackChainBackends: dco_decode_opt_list_chain_backend(arr[3]),
+      chainQuorumMinAmountSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      swapperUrl: dco_decode_opt_String(arr[5]),
+      swapperWsUrl: dco_decode_opt_String(arr[6]),
+      swapperReferralId: dco_decode_opt_String(arr[7]),
+      lbtcAssetId: dco_decode_opt_String(arr[8]),
+      liquidGenesisHash: dco_decode_opt_String(arr[9]),
+      mempoolspaceUrl: dco_decode_String(arr[10]),
+      workingDir: dco_decode_String(arr[11]),
+      cacheDir: dco_decode_opt_String(arr[12]),
+      network: dco_decode_liquid_network(arr[13]),
+      paymentTimeoutSec: dco_decode_u_64(arr[14]),
+      zeroConfMinFeeRateMsat: dco_decode_u_32(arr[15]),
+      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[16]),
+      breezApiKey: dco_decode_opt_String(arr[17]),
+      fiatRateHistoryCurrencies: dco_decode_opt_list_String(arr[18]),
+      eventWebhookUrls: dco_decode_opt_list_String(arr[19]),
+      schedulerIntervals: dco_decode_opt_box_autoadd_scheduler_intervals(arr[20]),
     );
   }
 
@@ -1724,12 +2351,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ErrorInfo dco_decode_error_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ErrorInfo(
+      code: dco_decode_u_32(arr[0]),
+      retryable: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  EventLogEntry dco_decode_event_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EventLogEntry(
+      seq: dco_decode_u_64(arr[0]),
+      createdAt: dco_decode_u_32(arr[1]),
+      event: dco_decode_sdk_event(arr[2]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as double;
   }
 
+  @protected
+  FiatAmount dco_decode_fiat_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FiatAmount(
+      currency: dco_decode_String(arr[0]),
+      amount: dco_decode_f_64(arr[1]),
+    );
+  }
+
   @protected
   FiatCurrency dco_decode_fiat_currency(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1833,10 +2494,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   LightningPaymentLimitsResponse dco_decode_lightning_payment_limits_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return LightningPaymentLimitsResponse(
       send: dco_decode_limits(arr[0]),
       receive: dco_decode_limits(arr[1]),
+      cacheAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[2]),
     );
   }
 
@@ -1879,6 +2541,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<ChainBackend> dco_decode_list_chain_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chain_backend).toList();
+  }
+
+  @protected
+  List<EventLogEntry> dco_decode_list_event_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_log_entry).toList();
+  }
+
   @protected
   List<FiatCurrency> dco_decode_list_fiat_currency(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1903,6 +2577,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_localized_name).toList();
   }
 
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_field).toList();
+  }
+
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1977,6 +2657,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_route_hint_hop).toList();
   }
 
+  @protected
+  List<SwapStateTransition> dco_decode_list_swap_state_transition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_swap_state_transition).toList();
+  }
+
+  @protected
+  List<SyncTask> dco_decode_list_sync_task(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sync_task).toList();
+  }
+
+  @protected
+  List<WebhookDelivery> dco_decode_list_webhook_delivery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_webhook_delivery).toList();
+  }
+
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2327,10 +3025,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   LogEntry dco_decode_log_entry(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return LogEntry(
       line: dco_decode_String(arr[0]),
       level: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      module: dco_decode_opt_String(arr[3]),
+      timestamp: dco_decode_u_64(arr[4]),
+      swapId: dco_decode_opt_String(arr[5]),
+      paymentId: dco_decode_opt_String(arr[6]),
+      fields: dco_decode_list_log_field(arr[7]),
+    );
+  }
+
+  @protected
+  LogField dco_decode_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LogField(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
     );
   }
 
@@ -2354,10 +3069,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   OnchainPaymentLimitsResponse dco_decode_onchain_payment_limits_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return OnchainPaymentLimitsResponse(
       send: dco_decode_limits(arr[0]),
       receive: dco_decode_limits(arr[1]),
+      cacheAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[2]),
     );
   }
 
@@ -2379,6 +3095,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_bool(raw);
   }
 
+  @protected
+  FiatAmount? dco_decode_opt_box_autoadd_fiat_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fiat_amount(raw);
+  }
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2403,6 +3125,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_payment(raw);
   }
 
+  @protected
+  PaymentFiatRate? dco_decode_opt_box_autoadd_payment_fiat_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_payment_fiat_rate(raw);
+  }
+
+  @protected
+  Rate? dco_decode_opt_box_autoadd_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rate(raw);
+  }
+
+  @protected
+  SchedulerIntervals? dco_decode_opt_box_autoadd_scheduler_intervals(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_scheduler_intervals(raw);
+  }
+
   @protected
   SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2421,6 +3161,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_symbol(raw);
   }
 
+  @protected
+  SyncMetrics? dco_decode_opt_box_autoadd_sync_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sync_metrics(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2433,6 +3179,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<ChainBackend>? dco_decode_opt_list_chain_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_chain_backend(raw);
+  }
+
   @protected
   List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2449,6 +3207,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         );
       case 1:
         return PayAmount_Drain();
+      case 2:
+        return PayAmount_Fiat(
+          currency: dco_decode_String(raw[1]),
+          amount: dco_decode_f_64(raw[2]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -2469,7 +3232,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Payment dco_decode_payment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return Payment(
       destination: dco_decode_opt_String(arr[0]),
       txId: dco_decode_opt_String(arr[1]),
@@ -2479,6 +3242,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       paymentType: dco_decode_payment_type(arr[5]),
       status: dco_decode_payment_state(arr[6]),
       details: dco_decode_payment_details(arr[7]),
+      fiatRate: dco_decode_opt_box_autoadd_payment_fiat_rate(arr[8]),
     );
   }
 
@@ -2581,11 +3345,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return PaymentError_SignerError(
           err: dco_decode_String(raw[1]),
         );
+      case 21:
+        return PaymentError_Swapper(
+          err: dco_decode_String(raw[1]),
+        );
+      case 22:
+        return PaymentError_ChainService(
+          err: dco_decode_String(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  PaymentFiatRate dco_decode_payment_fiat_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentFiatRate(
+      currency: dco_decode_String(arr[0]),
+      rate: dco_decode_f_64(arr[1]),
+    );
+  }
+
   @protected
   PaymentMethod dco_decode_payment_method(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2667,11 +3450,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PreparePayOnchainResponse(
       receiverAmountSat: dco_decode_u_64(arr[0]),
       claimFeesSat: dco_decode_u_64(arr[1]),
       totalFeesSat: dco_decode_u_64(arr[2]),
+      fiatRate: dco_decode_opt_box_autoadd_payment_fiat_rate(arr[3]),
     );
   }
 
@@ -2679,10 +3463,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PrepareReceiveRequest(
       payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
-      paymentMethod: dco_decode_payment_method(arr[1]),
+      payerAmountFiat: dco_decode_opt_box_autoadd_fiat_amount(arr[1]),
+      paymentMethod: dco_decode_payment_method(arr[2]),
     );
   }
 
@@ -2690,11 +3475,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareReceiveResponse dco_decode_prepare_receive_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PrepareReceiveResponse(
       paymentMethod: dco_decode_payment_method(arr[0]),
       payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
       feesSat: dco_decode_u_64(arr[2]),
+      fiatRate: dco_decode_opt_box_autoadd_payment_fiat_rate(arr[3]),
     );
   }
 
@@ -2714,11 +3500,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareRefundResponse dco_decode_prepare_refund_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return PrepareRefundResponse(
       txVsize: dco_decode_u_32(arr[0]),
       txFeeSat: dco_decode_u_64(arr[1]),
       refundTxId: dco_decode_opt_String(arr[2]),
+      isCooperative: dco_decode_bool(arr[3]),
+      feeSavedSat: dco_decode_u_64(arr[4]),
     );
   }
 
@@ -2737,10 +3525,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareSendResponse dco_decode_prepare_send_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PrepareSendResponse(
       destination: dco_decode_send_destination(arr[0]),
       feesSat: dco_decode_u_64(arr[1]),
+      fiatRate: dco_decode_opt_box_autoadd_payment_fiat_rate(arr[2]),
     );
   }
 
@@ -2817,11 +3606,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   RefundableSwap dco_decode_refundable_swap(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return RefundableSwap(
       swapAddress: dco_decode_String(arr[0]),
       timestamp: dco_decode_u_32(arr[1]),
       amountSat: dco_decode_u_64(arr[2]),
+      timeToRefundable: dco_decode_opt_box_autoadd_u_64(arr[3]),
     );
   }
 
@@ -2861,6 +3651,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SchedulerIntervals dco_decode_scheduler_intervals(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SchedulerIntervals(
+      syncSecs: dco_decode_u_64(arr[0]),
+      refundsSecs: dco_decode_u_64(arr[1]),
+      liquidRescanSecs: dco_decode_u_64(arr[2]),
+      bitcoinRescanSecs: dco_decode_u_64(arr[3]),
+      newBlocksSecs: dco_decode_u_64(arr[4]),
+    );
+  }
+
   @protected
   SdkError dco_decode_sdk_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2877,6 +3681,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return SdkError_ServiceConnectivity(
           err: dco_decode_String(raw[1]),
         );
+      case 4:
+        return SdkError_Swapper(
+          err: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return SdkError_ChainService(
+          err: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return SdkError_Persistence(
+          err: dco_decode_String(raw[1]),
+        );
+      case 7:
+        return SdkError_Signer(
+          err: dco_decode_String(raw[1]),
+        );
+      case 8:
+        return SdkError_Validation(
+          err: dco_decode_String(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -2895,22 +3719,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 2:
-        return SdkEvent_PaymentRefunded(
+        return SdkEvent_PaymentQueued(
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 3:
-        return SdkEvent_PaymentRefundPending(
+        return SdkEvent_PaymentRefunded(
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 4:
-        return SdkEvent_PaymentSucceeded(
+        return SdkEvent_PaymentRefundPending(
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 5:
-        return SdkEvent_PaymentWaitingConfirmation(
+        return SdkEvent_PaymentSucceeded(
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 6:
+        return SdkEvent_PaymentWaitingConfirmation(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 7:
+        return SdkEvent_SwapNearingTimeout(
+          swapId: dco_decode_String(raw[1]),
+          blocksLeft: dco_decode_u_32(raw[2]),
+        );
+      case 8:
+        return SdkEvent_SwapRefundable(
+          swapId: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return SdkEvent_SwapStateChanged(
+          swapId: dco_decode_String(raw[1]),
+          fromState: dco_decode_payment_state(raw[2]),
+          toState: dco_decode_payment_state(raw[3]),
+        );
+      case 10:
+        return SdkEvent_BalanceChanged(
+          oldBalanceSat: dco_decode_u_64(raw[1]),
+          newBalanceSat: dco_decode_u_64(raw[2]),
+        );
+      case 11:
+        return SdkEvent_SyncProgress(
+          step: dco_decode_u_32(raw[1]),
+          total: dco_decode_u_32(raw[2]),
+        );
+      case 12:
+        return SdkEvent_ConnectivityChanged(
+          isConnected: dco_decode_bool(raw[1]),
+        );
+      case 13:
+        return SdkEvent_NewBlock(
+          chain: dco_decode_blockchain(raw[1]),
+          height: dco_decode_u_32(raw[2]),
+        );
+      case 14:
         return SdkEvent_Synced();
       default:
         throw Exception("unreachable");
@@ -3021,6 +3883,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SwapStateTransition dco_decode_swap_state_transition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SwapStateTransition(
+      swapId: dco_decode_String(arr[0]),
+      fromState: dco_decode_payment_state(arr[1]),
+      toState: dco_decode_payment_state(arr[2]),
+      swapperStatus: dco_decode_opt_String(arr[3]),
+      txIds: dco_decode_list_String(arr[4]),
+      createdAt: dco_decode_u_32(arr[5]),
+    );
+  }
+
   @protected
   Symbol dco_decode_symbol(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3034,6 +3911,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SyncMetrics dco_decode_sync_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SyncMetrics(
+      startedAt: dco_decode_u_32(arr[0]),
+      durationMs: dco_decode_u_64(arr[1]),
+      scanDurationMs: dco_decode_u_64(arr[2]),
+      liquidTip: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      totalTxCount: dco_decode_u_32(arr[4]),
+      processedTxCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  SyncOnceRequest dco_decode_sync_once_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return SyncOnceRequest(
+      timeoutSecs: dco_decode_u_64(arr[0]),
+    );
+  }
+
+  @protected
+  SyncOnceResponse dco_decode_sync_once_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SyncOnceResponse(
+      pendingTasks: dco_decode_list_sync_task(arr[0]),
+      pendingSwapCount: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SyncTask dco_decode_sync_task(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncTask.values[raw as int];
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3082,6 +4001,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  WebhookDelivery dco_decode_webhook_delivery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return WebhookDelivery(
+      id: dco_decode_u_64(arr[0]),
+      url: dco_decode_String(arr[1]),
+      eventSeq: dco_decode_u_64(arr[2]),
+      payload: dco_decode_String(arr[3]),
+      state: dco_decode_webhook_delivery_state(arr[4]),
+      attempts: dco_decode_u_32(arr[5]),
+      nextAttemptAt: dco_decode_u_32(arr[6]),
+      lastError: dco_decode_opt_String(arr[7]),
+      createdAt: dco_decode_u_32(arr[8]),
+    );
+  }
+
+  @protected
+  WebhookDeliveryState dco_decode_webhook_delivery_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WebhookDeliveryState.values[raw as int];
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3216,6 +4159,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         message: var_message);
   }
 
+  @protected
+  Blockchain sse_decode_blockchain(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Blockchain.values[inner];
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3290,6 +4240,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_connect_request(deserializer));
   }
 
+  @protected
+  FiatAmount sse_decode_box_autoadd_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fiat_amount(deserializer));
+  }
+
   @protected
   GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3410,6 +4366,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_payment(deserializer));
   }
 
+  @protected
+  PaymentError sse_decode_box_autoadd_payment_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_payment_error(deserializer));
+  }
+
+  @protected
+  PaymentFiatRate sse_decode_box_autoadd_payment_fiat_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_payment_fiat_rate(deserializer));
+  }
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_box_autoadd_prepare_buy_bitcoin_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3446,6 +4414,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_send_request(deserializer));
   }
 
+  @protected
+  Rate sse_decode_box_autoadd_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rate(deserializer));
+  }
+
   @protected
   ReceivePaymentRequest sse_decode_box_autoadd_receive_payment_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3464,6 +4438,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_restore_request(deserializer));
   }
 
+  @protected
+  SchedulerIntervals sse_decode_box_autoadd_scheduler_intervals(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scheduler_intervals(deserializer));
+  }
+
+  @protected
+  SdkError sse_decode_box_autoadd_sdk_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sdk_error(deserializer));
+  }
+
   @protected
   SdkEvent sse_decode_box_autoadd_sdk_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3500,6 +4486,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_symbol(deserializer));
   }
 
+  @protected
+  SyncMetrics sse_decode_box_autoadd_sync_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_metrics(deserializer));
+  }
+
+  @protected
+  SyncOnceRequest sse_decode_box_autoadd_sync_once_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_once_request(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3533,6 +4531,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BuyBitcoinRequest(prepareResponse: var_prepareResponse, redirectUrl: var_redirectUrl);
   }
 
+  @protected
+  ChainBackend sse_decode_chain_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_url = sse_decode_String(deserializer);
+        return ChainBackend_Electrum(url: var_url);
+      case 1:
+        var var_url = sse_decode_String(deserializer);
+        return ChainBackend_Esplora(url: var_url);
+      case 2:
+        var var_electrumUrl = sse_decode_String(deserializer);
+        var var_esploraUrl = sse_decode_String(deserializer);
+        return ChainBackend_Hybrid(electrumUrl: var_electrumUrl, esploraUrl: var_esploraUrl);
+      case 3:
+        var var_url = sse_decode_String(deserializer);
+        var var_username = sse_decode_opt_String(deserializer);
+        var var_password = sse_decode_opt_String(deserializer);
+        return ChainBackend_Rpc(url: var_url, username: var_username, password: var_password);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   CheckMessageRequest sse_decode_check_message_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3552,8 +4576,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   Config sse_decode_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_liquidElectrumUrl = sse_decode_String(deserializer);
-    var var_bitcoinElectrumUrl = sse_decode_String(deserializer);
+    var var_liquidChainBackend = sse_decode_chain_backend(deserializer);
+    var var_bitcoinChainBackend = sse_decode_chain_backend(deserializer);
+    var var_liquidFallbackChainBackends = sse_decode_opt_list_chain_backend(deserializer);
+    var var_bitcoinFallbackChainBackends = sse_decode_opt_list_chain_backend(deserializer);
+    var var_chainQuorumMinAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_swapperUrl = sse_decode_opt_String(deserializer);
+    var var_swapperWsUrl = sse_decode_opt_String(deserializer);
+    var var_swapperReferralId = sse_decode_opt_String(deserializer);
+    var var_lbtcAssetId = sse_decode_opt_String(deserializer);
+    var var_liquidGenesisHash = sse_decode_opt_String(deserializer);
     var var_mempoolspaceUrl = sse_decode_String(deserializer);
     var var_workingDir = sse_decode_String(deserializer);
     var var_cacheDir = sse_decode_opt_String(deserializer);
@@ -3562,9 +4594,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_zeroConfMinFeeRateMsat = sse_decode_u_32(deserializer);
     var var_zeroConfMaxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_breezApiKey = sse_decode_opt_String(deserializer);
+    var var_fiatRateHistoryCurrencies = sse_decode_opt_list_String(deserializer);
+    var var_eventWebhookUrls = sse_decode_opt_list_String(deserializer);
+    var var_schedulerIntervals = sse_decode_opt_box_autoadd_scheduler_intervals(deserializer);
     return Config(
-        liquidElectrumUrl: var_liquidElectrumUrl,
-        bitcoinElectrumUrl: var_bitcoinElectrumUrl,
+        liquidChainBackend: var_liquidChainBackend,
+        bitcoinChainBackend: var_bitcoinChainBackend,
+        liquidFallbackChainBackends: var_liquidFallbackChainBackends,
+        bitcoinFallbackChainBackends: var_bitcoinFallbackChainBackends,
+        chainQuorumMinAmountSat: var_chainQuorumMinAmountSat,
+        swapperUrl: var_swapperUrl,
+        swapperWsUrl: var_swapperWsUrl,
+        swapperReferralId: var_swapperReferralId,
+        lbtcAssetId: var_lbtcAssetId,
+        liquidGenesisHash: var_liquidGenesisHash,
         mempoolspaceUrl: var_mempoolspaceUrl,
         workingDir: var_workingDir,
         cacheDir: var_cacheDir,
@@ -3572,7 +4615,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         paymentTimeoutSec: var_paymentTimeoutSec,
         zeroConfMinFeeRateMsat: var_zeroConfMinFeeRateMsat,
         zeroConfMaxAmountSat: var_zeroConfMaxAmountSat,
-        breezApiKey: var_breezApiKey);
+        breezApiKey: var_breezApiKey,
+        fiatRateHistoryCurrencies: var_fiatRateHistoryCurrencies,
+        eventWebhookUrls: var_eventWebhookUrls,
+        schedulerIntervals: var_schedulerIntervals);
   }
 
   @protected
@@ -3603,12 +4649,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         localeOverrides: var_localeOverrides);
   }
 
+  @protected
+  ErrorInfo sse_decode_error_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_u_32(deserializer);
+    var var_retryable = sse_decode_bool(deserializer);
+    return ErrorInfo(code: var_code, retryable: var_retryable);
+  }
+
+  @protected
+  EventLogEntry sse_decode_event_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_seq = sse_decode_u_64(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    var var_event = sse_decode_sdk_event(deserializer);
+    return EventLogEntry(seq: var_seq, createdAt: var_createdAt, event: var_event);
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FiatAmount sse_decode_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_currency = sse_decode_String(deserializer);
+    var var_amount = sse_decode_f_64(deserializer);
+    return FiatAmount(currency: var_currency, amount: var_amount);
+  }
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3705,7 +4776,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_send = sse_decode_limits(deserializer);
     var var_receive = sse_decode_limits(deserializer);
-    return LightningPaymentLimitsResponse(send: var_send, receive: var_receive);
+    var var_cacheAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return LightningPaymentLimitsResponse(
+        send: var_send, receive: var_receive, cacheAgeSecs: var_cacheAgeSecs);
   }
 
   @protected
@@ -3754,6 +4827,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ChainBackend> sse_decode_list_chain_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChainBackend>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chain_backend(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventLogEntry> sse_decode_list_event_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventLogEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_log_entry(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<FiatCurrency> sse_decode_list_fiat_currency(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3802,6 +4899,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_field(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Payment> sse_decode_list_payment(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3916,6 +5025,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<SwapStateTransition> sse_decode_list_swap_state_transition(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SwapStateTransition>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_swap_state_transition(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SyncTask> sse_decode_list_sync_task(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncTask>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_task(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WebhookDelivery> sse_decode_list_webhook_delivery(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WebhookDelivery>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_webhook_delivery(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4244,7 +5389,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_line = sse_decode_String(deserializer);
     var var_level = sse_decode_String(deserializer);
-    return LogEntry(line: var_line, level: var_level);
+    var var_target = sse_decode_String(deserializer);
+    var var_module = sse_decode_opt_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_swapId = sse_decode_opt_String(deserializer);
+    var var_paymentId = sse_decode_opt_String(deserializer);
+    var var_fields = sse_decode_list_log_field(deserializer);
+    return LogEntry(
+        line: var_line,
+        level: var_level,
+        target: var_target,
+        module: var_module,
+        timestamp: var_timestamp,
+        swapId: var_swapId,
+        paymentId: var_paymentId,
+        fields: var_fields);
+  }
+
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return LogField(key: var_key, value: var_value);
   }
 
   @protected
@@ -4266,7 +5433,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_send = sse_decode_limits(deserializer);
     var var_receive = sse_decode_limits(deserializer);
-    return OnchainPaymentLimitsResponse(send: var_send, receive: var_receive);
+    var var_cacheAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return OnchainPaymentLimitsResponse(send: var_send, receive: var_receive, cacheAgeSecs: var_cacheAgeSecs);
   }
 
   @protected
@@ -4302,6 +5470,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FiatAmount? sse_decode_opt_box_autoadd_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fiat_amount(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4346,6 +5525,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PaymentFiatRate? sse_decode_opt_box_autoadd_payment_fiat_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_payment_fiat_rate(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Rate? sse_decode_opt_box_autoadd_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rate(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SchedulerIntervals? sse_decode_opt_box_autoadd_scheduler_intervals(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_scheduler_intervals(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4379,6 +5591,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SyncMetrics? sse_decode_opt_box_autoadd_sync_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sync_metrics(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4401,6 +5624,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<ChainBackend>? sse_decode_opt_list_chain_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_chain_backend(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4423,6 +5668,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return PayAmount_Receiver(amountSat: var_amountSat);
       case 1:
         return PayAmount_Drain();
+      case 2:
+        var var_currency = sse_decode_String(deserializer);
+        var var_amount = sse_decode_f_64(deserializer);
+        return PayAmount_Fiat(currency: var_currency, amount: var_amount);
       default:
         throw UnimplementedError('');
     }
@@ -4447,6 +5696,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_paymentType = sse_decode_payment_type(deserializer);
     var var_status = sse_decode_payment_state(deserializer);
     var var_details = sse_decode_payment_details(deserializer);
+    var var_fiatRate = sse_decode_opt_box_autoadd_payment_fiat_rate(deserializer);
     return Payment(
         destination: var_destination,
         txId: var_txId,
@@ -4455,7 +5705,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         feesSat: var_feesSat,
         paymentType: var_paymentType,
         status: var_status,
-        details: var_details);
+        details: var_details,
+        fiatRate: var_fiatRate);
   }
 
   @protected
@@ -4560,11 +5811,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 20:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_SignerError(err: var_err);
+      case 21:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_Swapper(err: var_err);
+      case 22:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_ChainService(err: var_err);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  PaymentFiatRate sse_decode_payment_fiat_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_currency = sse_decode_String(deserializer);
+    var var_rate = sse_decode_f_64(deserializer);
+    return PaymentFiatRate(currency: var_currency, rate: var_rate);
+  }
+
   @protected
   PaymentMethod sse_decode_payment_method(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4641,18 +5906,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_receiverAmountSat = sse_decode_u_64(deserializer);
     var var_claimFeesSat = sse_decode_u_64(deserializer);
     var var_totalFeesSat = sse_decode_u_64(deserializer);
+    var var_fiatRate = sse_decode_opt_box_autoadd_payment_fiat_rate(deserializer);
     return PreparePayOnchainResponse(
         receiverAmountSat: var_receiverAmountSat,
         claimFeesSat: var_claimFeesSat,
-        totalFeesSat: var_totalFeesSat);
+        totalFeesSat: var_totalFeesSat,
+        fiatRate: var_fiatRate);
   }
 
   @protected
   PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_payerAmountFiat = sse_decode_opt_box_autoadd_fiat_amount(deserializer);
     var var_paymentMethod = sse_decode_payment_method(deserializer);
-    return PrepareReceiveRequest(payerAmountSat: var_payerAmountSat, paymentMethod: var_paymentMethod);
+    return PrepareReceiveRequest(
+        payerAmountSat: var_payerAmountSat,
+        payerAmountFiat: var_payerAmountFiat,
+        paymentMethod: var_paymentMethod);
   }
 
   @protected
@@ -4661,8 +5932,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_paymentMethod = sse_decode_payment_method(deserializer);
     var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
+    var var_fiatRate = sse_decode_opt_box_autoadd_payment_fiat_rate(deserializer);
     return PrepareReceiveResponse(
-        paymentMethod: var_paymentMethod, payerAmountSat: var_payerAmountSat, feesSat: var_feesSat);
+        paymentMethod: var_paymentMethod,
+        payerAmountSat: var_payerAmountSat,
+        feesSat: var_feesSat,
+        fiatRate: var_fiatRate);
   }
 
   @protected
@@ -4683,7 +5958,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_txVsize = sse_decode_u_32(deserializer);
     var var_txFeeSat = sse_decode_u_64(deserializer);
     var var_refundTxId = sse_decode_opt_String(deserializer);
-    return PrepareRefundResponse(txVsize: var_txVsize, txFeeSat: var_txFeeSat, refundTxId: var_refundTxId);
+    var var_isCooperative = sse_decode_bool(deserializer);
+    var var_feeSavedSat = sse_decode_u_64(deserializer);
+    return PrepareRefundResponse(
+        txVsize: var_txVsize,
+        txFeeSat: var_txFeeSat,
+        refundTxId: var_refundTxId,
+        isCooperative: var_isCooperative,
+        feeSavedSat: var_feeSavedSat);
   }
 
   @protected
@@ -4699,7 +5981,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_destination = sse_decode_send_destination(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
-    return PrepareSendResponse(destination: var_destination, feesSat: var_feesSat);
+    var var_fiatRate = sse_decode_opt_box_autoadd_payment_fiat_rate(deserializer);
+    return PrepareSendResponse(destination: var_destination, feesSat: var_feesSat, fiatRate: var_fiatRate);
   }
 
   @protected
@@ -4770,7 +6053,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_swapAddress = sse_decode_String(deserializer);
     var var_timestamp = sse_decode_u_32(deserializer);
     var var_amountSat = sse_decode_u_64(deserializer);
-    return RefundableSwap(swapAddress: var_swapAddress, timestamp: var_timestamp, amountSat: var_amountSat);
+    var var_timeToRefundable = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return RefundableSwap(
+        swapAddress: var_swapAddress,
+        timestamp: var_timestamp,
+        amountSat: var_amountSat,
+        timeToRefundable: var_timeToRefundable);
   }
 
   @protected
@@ -4807,6 +6095,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         htlcMaximumMsat: var_htlcMaximumMsat);
   }
 
+  @protected
+  SchedulerIntervals sse_decode_scheduler_intervals(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_syncSecs = sse_decode_u_64(deserializer);
+    var var_refundsSecs = sse_decode_u_64(deserializer);
+    var var_liquidRescanSecs = sse_decode_u_64(deserializer);
+    var var_bitcoinRescanSecs = sse_decode_u_64(deserializer);
+    var var_newBlocksSecs = sse_decode_u_64(deserializer);
+    return SchedulerIntervals(
+        syncSecs: var_syncSecs,
+        refundsSecs: var_refundsSecs,
+        liquidRescanSecs: var_liquidRescanSecs,
+        bitcoinRescanSecs: var_bitcoinRescanSecs,
+        newBlocksSecs: var_newBlocksSecs);
+  }
+
   @protected
   SdkError sse_decode_sdk_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4823,6 +6127,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 3:
         var var_err = sse_decode_String(deserializer);
         return SdkError_ServiceConnectivity(err: var_err);
+      case 4:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_Swapper(err: var_err);
+      case 5:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_ChainService(err: var_err);
+      case 6:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_Persistence(err: var_err);
+      case 7:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_Signer(err: var_err);
+      case 8:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_Validation(err: var_err);
       default:
         throw UnimplementedError('');
     }
@@ -4842,17 +6161,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return SdkEvent_PaymentPending(details: var_details);
       case 2:
         var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return SdkEvent_PaymentRefunded(details: var_details);
+        return SdkEvent_PaymentQueued(details: var_details);
       case 3:
         var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return SdkEvent_PaymentRefundPending(details: var_details);
+        return SdkEvent_PaymentRefunded(details: var_details);
       case 4:
         var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return SdkEvent_PaymentSucceeded(details: var_details);
+        return SdkEvent_PaymentRefundPending(details: var_details);
       case 5:
         var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return SdkEvent_PaymentWaitingConfirmation(details: var_details);
+        return SdkEvent_PaymentSucceeded(details: var_details);
       case 6:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return SdkEvent_PaymentWaitingConfirmation(details: var_details);
+      case 7:
+        var var_swapId = sse_decode_String(deserializer);
+        var var_blocksLeft = sse_decode_u_32(deserializer);
+        return SdkEvent_SwapNearingTimeout(swapId: var_swapId, blocksLeft: var_blocksLeft);
+      case 8:
+        var var_swapId = sse_decode_String(deserializer);
+        return SdkEvent_SwapRefundable(swapId: var_swapId);
+      case 9:
+        var var_swapId = sse_decode_String(deserializer);
+        var var_fromState = sse_decode_payment_state(deserializer);
+        var var_toState = sse_decode_payment_state(deserializer);
+        return SdkEvent_SwapStateChanged(swapId: var_swapId, fromState: var_fromState, toState: var_toState);
+      case 10:
+        var var_oldBalanceSat = sse_decode_u_64(deserializer);
+        var var_newBalanceSat = sse_decode_u_64(deserializer);
+        return SdkEvent_BalanceChanged(oldBalanceSat: var_oldBalanceSat, newBalanceSat: var_newBalanceSat);
+      case 11:
+        var var_step = sse_decode_u_32(deserializer);
+        var var_total = sse_decode_u_32(deserializer);
+        return SdkEvent_SyncProgress(step: var_step, total: var_total);
+      case 12:
+        var var_isConnected = sse_decode_bool(deserializer);
+        return SdkEvent_ConnectivityChanged(isConnected: var_isConnected);
+      case 13:
+        var var_chain = sse_decode_blockchain(deserializer);
+        var var_height = sse_decode_u_32(deserializer);
+        return SdkEvent_NewBlock(chain: var_chain, height: var_height);
+      case 14:
         return SdkEvent_Synced();
       default:
         throw UnimplementedError('');
@@ -4948,6 +6297,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SwapStateTransition sse_decode_swap_state_transition(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_fromState = sse_decode_payment_state(deserializer);
+    var var_toState = sse_decode_payment_state(deserializer);
+    var var_swapperStatus = sse_decode_opt_String(deserializer);
+    var var_txIds = sse_decode_list_String(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    return SwapStateTransition(
+        swapId: var_swapId,
+        fromState: var_fromState,
+        toState: var_toState,
+        swapperStatus: var_swapperStatus,
+        txIds: var_txIds,
+        createdAt: var_createdAt);
+  }
+
   @protected
   Symbol sse_decode_symbol(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4958,6 +6325,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Symbol(grapheme: var_grapheme, template: var_template, rtl: var_rtl, position: var_position);
   }
 
+  @protected
+  SyncMetrics sse_decode_sync_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startedAt = sse_decode_u_32(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_scanDurationMs = sse_decode_u_64(deserializer);
+    var var_liquidTip = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_totalTxCount = sse_decode_u_32(deserializer);
+    var var_processedTxCount = sse_decode_u_32(deserializer);
+    return SyncMetrics(
+        startedAt: var_startedAt,
+        durationMs: var_durationMs,
+        scanDurationMs: var_scanDurationMs,
+        liquidTip: var_liquidTip,
+        totalTxCount: var_totalTxCount,
+        processedTxCount: var_processedTxCount);
+  }
+
+  @protected
+  SyncOnceRequest sse_decode_sync_once_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeoutSecs = sse_decode_u_64(deserializer);
+    return SyncOnceRequest(timeoutSecs: var_timeoutSecs);
+  }
+
+  @protected
+  SyncOnceResponse sse_decode_sync_once_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pendingTasks = sse_decode_list_sync_task(deserializer);
+    var var_pendingSwapCount = sse_decode_u_32(deserializer);
+    return SyncOnceResponse(pendingTasks: var_pendingTasks, pendingSwapCount: var_pendingSwapCount);
+  }
+
+  @protected
+  SyncTask sse_decode_sync_task(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncTask.values[inner];
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5003,6 +6410,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  WebhookDelivery sse_decode_webhook_delivery(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_eventSeq = sse_decode_u_64(deserializer);
+    var var_payload = sse_decode_String(deserializer);
+    var var_state = sse_decode_webhook_delivery_state(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    var var_nextAttemptAt = sse_decode_u_32(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    return WebhookDelivery(
+        id: var_id,
+        url: var_url,
+        eventSeq: var_eventSeq,
+        payload: var_payload,
+        state: var_state,
+        attempts: var_attempts,
+        nextAttemptAt: var_nextAttemptAt,
+        lastError: var_lastError,
+        createdAt: var_createdAt);
+  }
+
+  @protected
+  WebhookDeliveryState sse_decode_webhook_delivery_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WebhookDeliveryState.values[inner];
+  }
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
       BindingLiquidSdk raw) {
@@ -5027,6 +6465,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as BindingLiquidSdkImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int cst_encode_blockchain(Blockchain raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   bool cst_encode_bool(bool raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -5081,6 +6525,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_sync_task(SyncTask raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_u_16(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -5105,6 +6555,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw;
   }
 
+  @protected
+  int cst_encode_webhook_delivery_state(WebhookDeliveryState raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5231,6 +6687,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_blockchain(Blockchain self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5305,6 +6767,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_connect_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_fiat_amount(FiatAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fiat_amount(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5417,15 +6885,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_box_autoadd_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pay_onchain_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_payment_error(PaymentError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_pay_onchain_request(self, serializer);
+    sse_encode_payment_error(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_payment(self, serializer);
+    sse_encode_payment_fiat_rate(self, serializer);
   }
 
   @protected
@@ -5467,6 +6947,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_send_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_rate(Rate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rate(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_receive_payment_request(ReceivePaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5485,6 +6971,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_restore_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_scheduler_intervals(SchedulerIntervals self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scheduler_intervals(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sdk_error(SdkError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sdk_error(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_sdk_event(SdkEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5522,6 +7020,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_symbol(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_sync_metrics(SyncMetrics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_metrics(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_once_request(SyncOnceRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_once_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5553,6 +7063,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.redirectUrl, serializer);
   }
 
+  @protected
+  void sse_encode_chain_backend(ChainBackend self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ChainBackend_Electrum(url: final url):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(url, serializer);
+      case ChainBackend_Esplora(url: final url):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(url, serializer);
+      case ChainBackend_Hybrid(electrumUrl: final electrumUrl, esploraUrl: final esploraUrl):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(electrumUrl, serializer);
+        sse_encode_String(esploraUrl, serializer);
+      case ChainBackend_Rpc(url: final url, username: final username, password: final password):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(url, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   void sse_encode_check_message_request(CheckMessageRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5570,8 +7104,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_config(Config self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.liquidElectrumUrl, serializer);
-    sse_encode_String(self.bitcoinElectrumUrl, serializer);
+    sse_encode_chain_backend(self.liquidChainBackend, serializer);
+    sse_encode_chain_backend(self.bitcoinChainBackend, serializer);
+    sse_encode_opt_list_chain_backend(self.liquidFallbackChainBackends, serializer);
+    sse_encode_opt_list_chain_backend(self.bitcoinFallbackChainBackends, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.chainQuorumMinAmountSat, serializer);
+    sse_encode_opt_String(self.swapperUrl, serializer);
+    sse_encode_opt_String(self.swapperWsUrl, serializer);
+    sse_encode_opt_String(self.swapperReferralId, serializer);
+    sse_encode_opt_String(self.lbtcAssetId, serializer);
+    sse_encode_opt_String(self.liquidGenesisHash, serializer);
     sse_encode_String(self.mempoolspaceUrl, serializer);
     sse_encode_String(self.workingDir, serializer);
     sse_encode_opt_String(self.cacheDir, serializer);
@@ -5580,6 +7122,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.zeroConfMinFeeRateMsat, serializer);
     sse_encode_opt_box_autoadd_u_64(self.zeroConfMaxAmountSat, serializer);
     sse_encode_opt_String(self.breezApiKey, serializer);
+    sse_encode_opt_list_String(self.fiatRateHistoryCurrencies, serializer);
+    sse_encode_opt_list_String(self.eventWebhookUrls, serializer);
+    sse_encode_opt_box_autoadd_scheduler_intervals(self.schedulerIntervals, serializer);
   }
 
   @protected
@@ -5601,12 +7146,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_locale_overrides(self.localeOverrides, serializer);
   }
 
+  @protected
+  void sse_encode_error_info(ErrorInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.code, serializer);
+    sse_encode_bool(self.retryable, serializer);
+  }
+
+  @protected
+  void sse_encode_event_log_entry(EventLogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.seq, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+    sse_encode_sdk_event(self.event, serializer);
+  }
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_fiat_amount(FiatAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.currency, serializer);
+    sse_encode_f_64(self.amount, serializer);
+  }
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5693,6 +7260,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_limits(self.send, serializer);
     sse_encode_limits(self.receive, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.cacheAgeSecs, serializer);
   }
 
   @protected
@@ -5729,6 +7297,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_chain_backend(List<ChainBackend> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chain_backend(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_event_log_entry(List<EventLogEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_log_entry(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5765,6 +7351,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_field(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5852,6 +7447,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_swap_state_transition(List<SwapStateTransition> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_swap_state_transition(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_sync_task(List<SyncTask> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_task(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_webhook_delivery(List<WebhookDelivery> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_webhook_delivery(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6120,6 +7742,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.line, serializer);
     sse_encode_String(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_opt_String(self.module, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_opt_String(self.swapId, serializer);
+    sse_encode_opt_String(self.paymentId, serializer);
+    sse_encode_list_log_field(self.fields, serializer);
+  }
+
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
@@ -6140,6 +7775,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_limits(self.send, serializer);
     sse_encode_limits(self.receive, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.cacheAgeSecs, serializer);
   }
 
   @protected
@@ -6172,6 +7808,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_fiat_amount(FiatAmount? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fiat_amount(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6212,6 +7858,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_payment_fiat_rate(PaymentFiatRate? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_payment_fiat_rate(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_rate(Rate? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_rate(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_scheduler_intervals(SchedulerIntervals? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_scheduler_intervals(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6243,6 +7919,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_sync_metrics(SyncMetrics? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sync_metrics(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6263,6 +7949,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_chain_backend(List<ChainBackend>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_chain_backend(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6282,6 +7988,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_64(amountSat, serializer);
       case PayAmount_Drain():
         sse_encode_i_32(1, serializer);
+      case PayAmount_Fiat(currency: final currency, amount: final amount):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(currency, serializer);
+        sse_encode_f_64(amount, serializer);
       default:
         throw UnimplementedError('');
     }
@@ -6305,6 +8015,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment_type(self.paymentType, serializer);
     sse_encode_payment_state(self.status, serializer);
     sse_encode_payment_details(self.details, serializer);
+    sse_encode_opt_box_autoadd_payment_fiat_rate(self.fiatRate, serializer);
   }
 
   @protected
@@ -6407,11 +8118,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case PaymentError_SignerError(err: final err):
         sse_encode_i_32(20, serializer);
         sse_encode_String(err, serializer);
+      case PaymentError_Swapper(err: final err):
+        sse_encode_i_32(21, serializer);
+        sse_encode_String(err, serializer);
+      case PaymentError_ChainService(err: final err):
+        sse_encode_i_32(22, serializer);
+        sse_encode_String(err, serializer);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  void sse_encode_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.currency, serializer);
+    sse_encode_f_64(self.rate, serializer);
+  }
+
   @protected
   void sse_encode_payment_method(PaymentMethod self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6475,12 +8199,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.receiverAmountSat, serializer);
     sse_encode_u_64(self.claimFeesSat, serializer);
     sse_encode_u_64(self.totalFeesSat, serializer);
+    sse_encode_opt_box_autoadd_payment_fiat_rate(self.fiatRate, serializer);
   }
 
   @protected
   void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
+    sse_encode_opt_box_autoadd_fiat_amount(self.payerAmountFiat, serializer);
     sse_encode_payment_method(self.paymentMethod, serializer);
   }
 
@@ -6490,6 +8216,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment_method(self.paymentMethod, serializer);
     sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_payment_fiat_rate(self.fiatRate, serializer);
   }
 
   @protected
@@ -6506,6 +8233,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.txVsize, serializer);
     sse_encode_u_64(self.txFeeSat, serializer);
     sse_encode_opt_String(self.refundTxId, serializer);
+    sse_encode_bool(self.isCooperative, serializer);
+    sse_encode_u_64(self.feeSavedSat, serializer);
   }
 
   @protected
@@ -6520,6 +8249,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_send_destination(self.destination, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_payment_fiat_rate(self.fiatRate, serializer);
   }
 
   @protected
@@ -6573,6 +8303,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.swapAddress, serializer);
     sse_encode_u_32(self.timestamp, serializer);
     sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timeToRefundable, serializer);
   }
 
   @protected
@@ -6599,6 +8330,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.htlcMaximumMsat, serializer);
   }
 
+  @protected
+  void sse_encode_scheduler_intervals(SchedulerIntervals self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.syncSecs, serializer);
+    sse_encode_u_64(self.refundsSecs, serializer);
+    sse_encode_u_64(self.liquidRescanSecs, serializer);
+    sse_encode_u_64(self.bitcoinRescanSecs, serializer);
+    sse_encode_u_64(self.newBlocksSecs, serializer);
+  }
+
   @protected
   void sse_encode_sdk_error(SdkError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6613,6 +8354,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case SdkError_ServiceConnectivity(err: final err):
         sse_encode_i_32(3, serializer);
         sse_encode_String(err, serializer);
+      case SdkError_Swapper(err: final err):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(err, serializer);
+      case SdkError_ChainService(err: final err):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(err, serializer);
+      case SdkError_Persistence(err: final err):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(err, serializer);
+      case SdkError_Signer(err: final err):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(err, serializer);
+      case SdkError_Validation(err: final err):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(err, serializer);
       default:
         throw UnimplementedError('');
     }
@@ -6628,20 +8384,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case SdkEvent_PaymentPending(details: final details):
         sse_encode_i_32(1, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_PaymentRefunded(details: final details):
+      case SdkEvent_PaymentQueued(details: final details):
         sse_encode_i_32(2, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_PaymentRefundPending(details: final details):
+      case SdkEvent_PaymentRefunded(details: final details):
         sse_encode_i_32(3, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_PaymentSucceeded(details: final details):
+      case SdkEvent_PaymentRefundPending(details: final details):
         sse_encode_i_32(4, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_PaymentWaitingConfirmation(details: final details):
+      case SdkEvent_PaymentSucceeded(details: final details):
         sse_encode_i_32(5, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_Synced():
+      case SdkEvent_PaymentWaitingConfirmation(details: final details):
         sse_encode_i_32(6, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case SdkEvent_SwapNearingTimeout(swapId: final swapId, blocksLeft: final blocksLeft):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(swapId, serializer);
+        sse_encode_u_32(blocksLeft, serializer);
+      case SdkEvent_SwapRefundable(swapId: final swapId):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(swapId, serializer);
+      case SdkEvent_SwapStateChanged(
+          swapId: final swapId,
+          fromState: final fromState,
+          toState: final toState
+        ):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(swapId, serializer);
+        sse_encode_payment_state(fromState, serializer);
+        sse_encode_payment_state(toState, serializer);
+      case SdkEvent_BalanceChanged(oldBalanceSat: final oldBalanceSat, newBalanceSat: final newBalanceSat):
+        sse_encode_i_32(10, serializer);
+        sse_encode_u_64(oldBalanceSat, serializer);
+        sse_encode_u_64(newBalanceSat, serializer);
+      case SdkEvent_SyncProgress(step: final step, total: final total):
+        sse_encode_i_32(11, serializer);
+        sse_encode_u_32(step, serializer);
+        sse_encode_u_32(total, serializer);
+      case SdkEvent_ConnectivityChanged(isConnected: final isConnected):
+        sse_encode_i_32(12, serializer);
+        sse_encode_bool(isConnected, serializer);
+      case SdkEvent_NewBlock(chain: final chain, height: final height):
+        sse_encode_i_32(13, serializer);
+        sse_encode_blockchain(chain, serializer);
+        sse_encode_u_32(height, serializer);
+      case SdkEvent_Synced():
+        sse_encode_i_32(14, serializer);
       default:
         throw UnimplementedError('');
     }
@@ -6726,6 +8516,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_swap_state_transition(SwapStateTransition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_payment_state(self.fromState, serializer);
+    sse_encode_payment_state(self.toState, serializer);
+    sse_encode_opt_String(self.swapperStatus, serializer);
+    sse_encode_list_String(self.txIds, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+  }
+
   @protected
   void sse_encode_symbol(Symbol self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6735,6 +8536,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_32(self.position, serializer);
   }
 
+  @protected
+  void sse_encode_sync_metrics(SyncMetrics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.startedAt, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_u_64(self.scanDurationMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.liquidTip, serializer);
+    sse_encode_u_32(self.totalTxCount, serializer);
+    sse_encode_u_32(self.processedTxCount, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_once_request(SyncOnceRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeoutSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_once_response(SyncOnceResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_sync_task(self.pendingTasks, serializer);
+    sse_encode_u_32(self.pendingSwapCount, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_task(SyncTask self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6777,6 +8608,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_webhook_delivery(WebhookDelivery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_u_64(self.eventSeq, serializer);
+    sse_encode_String(self.payload, serializer);
+    sse_encode_webhook_delivery_state(self.state, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_u_32(self.nextAttemptAt, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_webhook_delivery_state(WebhookDeliveryState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 }
 
 @sealed
@@ -6795,6 +8646,10 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_BindingLiquidSdkPtr,
   );
 
+  void ackEvents({required String listenerId, required BigInt seq}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkAckEvents(
+          that: this, listenerId: listenerId, seq: seq);
+
   Stream<SdkEvent> addEventListener() => RustLib.instance.api.crateBindingsBindingLiquidSdkAddEventListener(
         that: this,
       );
@@ -6816,6 +8671,12 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  List<EventLogEntry> eventsSince({required BigInt seq}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkEventsSince(that: this, seq: seq);
+
+  String exportRescueFile({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkExportRescueFile(that: this, swapId: swapId);
+
   Future<List<Rate>> fetchFiatRates() => RustLib.instance.api.crateBindingsBindingLiquidSdkFetchFiatRates(
         that: this,
       );
@@ -6830,13 +8691,52 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<void> forceClaimSwap({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkForceClaimSwap(that: this, swapId: swapId);
+
+  Future<String> forceRefundSendSwap({required String swapId, required bool isCooperative}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkForceRefundSendSwap(
+          that: this, swapId: swapId, isCooperative: isCooperative);
+
+  Future<String> generateDiagnostics() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGenerateDiagnostics(
+        that: this,
+      );
+
+  BigInt? getEventCursor({required String listenerId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetEventCursor(that: this, listenerId: listenerId);
+
+  Future<Rate?> getHistoricalFiatRate({required String currency, required int timestamp}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetHistoricalFiatRate(
+          that: this, currency: currency, timestamp: timestamp);
+
   Future<GetInfoResponse> getInfo() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetInfo(
         that: this,
       );
 
+  SyncMetrics? getLastSyncMetrics() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetLastSyncMetrics(
+        that: this,
+      );
+
   Future<Payment?> getPayment({required GetPaymentRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkGetPayment(that: this, req: req);
 
+  List<SwapStateTransition> getSwapHistory({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetSwapHistory(that: this, swapId: swapId);
+
+  String getWebhookSigningSecret() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetWebhookSigningSecret(
+        that: this,
+      );
+
+  Future<void> importSwap({required String rescueJson}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkImportSwap(that: this, rescueJson: rescueJson);
+
+  List<WebhookDelivery> listFailedWebhookDeliveries() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListFailedWebhookDeliveries(
+        that: this,
+      );
+
   Future<List<FiatCurrency>> listFiatCurrencies() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListFiatCurrencies(
         that: this,
@@ -6859,6 +8759,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkLnurlWithdraw(that: this, req: req);
 
+  Future<void> markSwapFailed({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkMarkSwapFailed(that: this, swapId: swapId);
+
   Future<SendPaymentResponse> payOnchain({required PayOnchainRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPayOnchain(that: this, req: req);
 
@@ -6888,6 +8791,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<void> refetchSwapStatus({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRefetchSwapStatus(that: this, swapId: swapId);
+
   Future<RefundResponse> refund({required RefundRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRefund(that: this, req: req);
 
@@ -6901,6 +8807,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   void restore({required RestoreRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRestore(that: this, req: req);
 
+  void retryWebhookDelivery({required BigInt id}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRetryWebhookDelivery(that: this, id: id);
+
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkSendPayment(that: this, req: req);
 
@@ -6911,6 +8820,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<SyncOnceResponse> syncOnce({required SyncOnceRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSyncOnce(that: this, req: req);
+
   Future<void> unregisterWebhook() => RustLib.instance.api.crateBindingsBindingLiquidSdkUnregisterWebhook(
         that: this,
       );
@@ -71,6 +71,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BitcoinAddressData dco_decode_bitcoin_address_data(dynamic raw);
 
+  @protected
+  Blockchain dco_decode_blockchain(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -107,6 +110,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw);
 
+  @protected
+  FiatAmount dco_decode_box_autoadd_fiat_amount(dynamic raw);
+
   @protected
   GetPaymentRequest dco_decode_box_autoadd_get_payment_request(dynamic raw);
 
@@ -167,6 +173,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment dco_decode_box_autoadd_payment(dynamic raw);
 
+  @protected
+  PaymentError dco_decode_box_autoadd_payment_error(dynamic raw);
+
+  @protected
+  PaymentFiatRate dco_decode_box_autoadd_payment_fiat_rate(dynamic raw);
+
   @protected
   PrepareBuyBitcoinRequest dco_decode_box_autoadd_prepare_buy_bitcoin_request(dynamic raw);
 
@@ -185,6 +197,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw);
 
+  @protected
+  Rate dco_decode_box_autoadd_rate(dynamic raw);
+
   @protected
   ReceivePaymentRequest dco_decode_box_autoadd_receive_payment_request(dynamic raw);
 
@@ -194,6 +209,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw);
 
+  @protected
+  SchedulerIntervals dco_decode_box_autoadd_scheduler_intervals(dynamic raw);
+
+  @protected
+  SdkError dco_decode_box_autoadd_sdk_error(dynamic raw);
+
   @protected
   SdkEvent dco_decode_box_autoadd_sdk_event(dynamic raw);
 
@@ -212,6 +233,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Symbol dco_decode_box_autoadd_symbol(dynamic raw);
 
+  @protected
+  SyncMetrics dco_decode_box_autoadd_sync_metrics(dynamic raw);
+
+  @protected
+  SyncOnceRequest dco_decode_box_autoadd_sync_once_request(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
@@ -227,6 +254,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BuyBitcoinRequest dco_decode_buy_bitcoin_request(dynamic raw);
 
+  @protected
+  ChainBackend dco_decode_chain_backend(dynamic raw);
+
   @protected
   CheckMessageRequest dco_decode_check_message_request(dynamic raw);
 
@@ -242,9 +272,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CurrencyInfo dco_decode_currency_info(dynamic raw);
 
+  @protected
+  ErrorInfo dco_decode_error_info(dynamic raw);
+
+  @protected
+  EventLogEntry dco_decode_event_log_entry(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FiatAmount dco_decode_fiat_amount(dynamic raw);
+
   @protected
   FiatCurrency dco_decode_fiat_currency(dynamic raw);
 
@@ -278,6 +317,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<ChainBackend> dco_decode_list_chain_backend(dynamic raw);
+
+  @protected
+  List<EventLogEntry> dco_decode_list_event_log_entry(dynamic raw);
+
   @protected
   List<FiatCurrency> dco_decode_list_fiat_currency(dynamic raw);
 
@@ -290,6 +335,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<LocalizedName> dco_decode_list_localized_name(dynamic raw);
 
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw);
+
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw);
 
@@ -317,6 +365,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<RouteHintHop> dco_decode_list_route_hint_hop(dynamic raw);
 
+  @protected
+  List<SwapStateTransition> dco_decode_list_swap_state_transition(dynamic raw);
+
+  @protected
+  List<SyncTask> dco_decode_list_sync_task(dynamic raw);
+
+  @protected
+  List<WebhookDelivery> dco_decode_list_webhook_delivery(dynamic raw);
+
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw);
 
@@ -380,6 +437,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LogEntry dco_decode_log_entry(dynamic raw);
 
+  @protected
+  LogField dco_decode_log_field(dynamic raw);
+
   @protected
   MessageSuccessActionData dco_decode_message_success_action_data(dynamic raw);
 
@@ -398,6 +458,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  FiatAmount? dco_decode_opt_box_autoadd_fiat_amount(dynamic raw);
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
@@ -410,6 +473,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
 
+  @protected
+  PaymentFiatRate? dco_decode_opt_box_autoadd_payment_fiat_rate(dynamic raw);
+
+  @protected
+  Rate? dco_decode_opt_box_autoadd_rate(dynamic raw);
+
+  @protected
+  SchedulerIntervals? dco_decode_opt_box_autoadd_scheduler_intervals(dynamic raw);
+
   @protected
   SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw);
 
@@ -419,12 +491,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Symbol? dco_decode_opt_box_autoadd_symbol(dynamic raw);
 
+  @protected
+  SyncMetrics? dco_decode_opt_box_autoadd_sync_metrics(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<ChainBackend>? dco_decode_opt_list_chain_backend(dynamic raw);
+
   @protected
   List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw);
 
@@ -443,6 +524,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentError dco_decode_payment_error(dynamic raw);
 
+  @protected
+  PaymentFiatRate dco_decode_payment_fiat_rate(dynamic raw);
+
   @protected
   PaymentMethod dco_decode_payment_method(dynamic raw);
 
@@ -518,6 +602,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RouteHintHop dco_decode_route_hint_hop(dynamic raw);
 
+  @protected
+  SchedulerIntervals dco_decode_scheduler_intervals(dynamic raw);
+
   @protected
   SdkError dco_decode_sdk_error(dynamic raw);
 
@@ -545,9 +632,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SuccessActionProcessed dco_decode_success_action_processed(dynamic raw);
 
+  @protected
+  SwapStateTransition dco_decode_swap_state_transition(dynamic raw);
+
   @protected
   Symbol dco_decode_symbol(dynamic raw);
 
+  @protected
+  SyncMetrics dco_decode_sync_metrics(dynamic raw);
+
+  @protected
+  SyncOnceRequest dco_decode_sync_once_request(dynamic raw);
+
+  @protected
+  SyncOnceResponse dco_decode_sync_once_response(dynamic raw);
+
+  @protected
+  SyncTask dco_decode_sync_task(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -569,6 +671,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  WebhookDelivery dco_decode_webhook_delivery(dynamic raw);
+
+  @protected
+  WebhookDeliveryState dco_decode_webhook_delivery_state(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -616,6 +724,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BitcoinAddressData sse_decode_bitcoin_address_data(SseDeserializer deserializer);
 
+  @protected
+  Blockchain sse_decode_blockchain(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -654,6 +765,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer);
 
+  @protected
+  FiatAmount sse_decode_box_autoadd_fiat_amount(SseDeserializer deserializer);
+
   @protected
   GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer);
 
@@ -714,6 +828,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer);
 
+  @protected
+  PaymentError sse_decode_box_autoadd_payment_error(SseDeserializer deserializer);
+
+  @protected
+  PaymentFiatRate sse_decode_box_autoadd_payment_fiat_rate(SseDeserializer deserializer);
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_box_autoadd_prepare_buy_bitcoin_request(SseDeserializer deserializer);
 
@@ -732,6 +852,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer);
 
+  @protected
+  Rate sse_decode_box_autoadd_rate(SseDeserializer deserializer);
+
   @protected
   ReceivePaymentRequest sse_decode_box_autoadd_receive_payment_request(SseDeserializer deserializer);
 
@@ -741,6 +864,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer);
 
+  @protected
+  SchedulerIntervals sse_decode_box_autoadd_scheduler_intervals(SseDeserializer deserializer);
+
+  @protected
+  SdkError sse_decode_box_autoadd_sdk_error(SseDeserializer deserializer);
+
   @protected
   SdkEvent sse_decode_box_autoadd_sdk_event(SseDeserializer deserializer);
 
@@ -759,6 +888,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Symbol sse_decode_box_autoadd_symbol(SseDeserializer deserializer);
 
+  @protected
+  SyncMetrics sse_decode_box_autoadd_sync_metrics(SseDeserializer deserializer);
+
+  @protected
+  SyncOnceRequest sse_decode_box_autoadd_sync_once_request(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -774,6 +909,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BuyBitcoinRequest sse_decode_buy_bitcoin_request(SseDeserializer deserializer);
 
+  @protected
+  ChainBackend sse_decode_chain_backend(SseDeserializer deserializer);
+
   @protected
   CheckMessageRequest sse_decode_check_message_request(SseDeserializer deserializer);
 
@@ -789,9 +927,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CurrencyInfo sse_decode_currency_info(SseDeserializer deserializer);
 
+  @protected
+  ErrorInfo sse_decode_error_info(SseDeserializer deserializer);
+
+  @protected
+  EventLogEntry sse_decode_event_log_entry(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FiatAmount sse_decode_fiat_amount(SseDeserializer deserializer);
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer);
 
@@ -825,6 +972,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<ChainBackend> sse_decode_list_chain_backend(SseDeserializer deserializer);
+
+  @protected
+  List<EventLogEntry> sse_decode_list_event_log_entry(SseDeserializer deserializer);
+
   @protected
   List<FiatCurrency> sse_decode_list_fiat_currency(SseDeserializer deserializer);
 
@@ -837,6 +990,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<LocalizedName> sse_decode_list_localized_name(SseDeserializer deserializer);
 
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer);
+
   @protected
   List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
 
@@ -864,6 +1020,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer);
 
+  @protected
+  List<SwapStateTransition> sse_decode_list_swap_state_transition(SseDeserializer deserializer);
+
+  @protected
+  List<SyncTask> sse_decode_list_sync_task(SseDeserializer deserializer);
+
+  @protected
+  List<WebhookDelivery> sse_decode_list_webhook_delivery(SseDeserializer deserializer);
+
   @protected
   LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer);
 
@@ -927,6 +1092,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LogEntry sse_decode_log_entry(SseDeserializer deserializer);
 
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer);
+
   @protected
   MessageSuccessActionData sse_decode_message_success_action_data(SseDeserializer deserializer);
 
@@ -945,6 +1113,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  FiatAmount? sse_decode_opt_box_autoadd_fiat_amount(SseDeserializer deserializer);
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
@@ -957,6 +1128,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer);
 
+  @protected
+  PaymentFiatRate? sse_decode_opt_box_autoadd_payment_fiat_rate(SseDeserializer deserializer);
+
+  @protected
+  Rate? sse_decode_opt_box_autoadd_rate(SseDeserializer deserializer);
+
+  @protected
+  SchedulerIntervals? sse_decode_opt_box_autoadd_scheduler_intervals(SseDeserializer deserializer);
+
   @protected
   SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer);
 
@@ -966,12 +1146,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Symbol? sse_decode_opt_box_autoadd_symbol(SseDeserializer deserializer);
 
+  @protected
+  SyncMetrics? sse_decode_opt_box_autoadd_sync_metrics(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ChainBackend>? sse_decode_opt_list_chain_backend(SseDeserializer deserializer);
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer);
 
@@ -990,6 +1179,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentError sse_decode_payment_error(SseDeserializer deserializer);
 
+  @protected
+  PaymentFiatRate sse_decode_payment_fiat_rate(SseDeserializer deserializer);
+
   @protected
   PaymentMethod sse_decode_payment_method(SseDeserializer deserializer);
 
@@ -1065,6 +1257,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer);
 
+  @protected
+  SchedulerIntervals sse_decode_scheduler_intervals(SseDeserializer deserializer);
+
   @protected
   SdkError sse_decode_sdk_error(SseDeserializer deserializer);
 
@@ -1092,9 +1287,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer);
 
+  @protected
+  SwapStateTransition sse_decode_swap_state_transition(SseDeserializer deserializer);
+
   @protected
   Symbol sse_decode_symbol(SseDeserializer deserializer);
 
+  @protected
+  SyncMetrics sse_decode_sync_metrics(SseDeserializer deserializer);
+
+  @protected
+  SyncOnceRequest sse_decode_sync_once_request(SseDeserializer deserializer);
+
+  @protected
+  SyncOnceResponse sse_decode_sync_once_response(SseDeserializer deserializer);
+
+  @protected
+  SyncTask sse_decode_sync_task(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -1116,6 +1326,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  WebhookDelivery sse_decode_webhook_delivery(SseDeserializer deserializer);
+
+  @protected
+  WebhookDeliveryState sse_decode_webhook_delivery_state(SseDeserializer deserializer);
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1243,6 +1459,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_fiat_amount> cst_encode_box_autoadd_fiat_amount(FiatAmount raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_fiat_amount();
+    cst_api_fill_to_wire_fiat_amount(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_get_payment_request> cst_encode_box_autoadd_get_payment_request(
       GetPaymentRequest raw) {
@@ -1414,6 +1638,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_payment_error> cst_encode_box_autoadd_payment_error(PaymentError raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_payment_error();
+    cst_api_fill_to_wire_payment_error(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_payment_fiat_rate> cst_encode_box_autoadd_payment_fiat_rate(PaymentFiatRate raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_payment_fiat_rate();
+    cst_api_fill_to_wire_payment_fiat_rate(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> cst_encode_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest raw) {
@@ -1468,6 +1708,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_rate> cst_encode_box_autoadd_rate(Rate raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_rate();
+    cst_api_fill_to_wire_rate(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_receive_payment_request> cst_encode_box_autoadd_receive_payment_request(
       ReceivePaymentRequest raw) {
@@ -1493,6 +1741,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_scheduler_intervals> cst_encode_box_autoadd_scheduler_intervals(
+      SchedulerIntervals raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_scheduler_intervals();
+    cst_api_fill_to_wire_scheduler_intervals(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_sdk_error> cst_encode_box_autoadd_sdk_error(SdkError raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sdk_error();
+    cst_api_fill_to_wire_sdk_error(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_sdk_event> cst_encode_box_autoadd_sdk_event(SdkEvent raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1544,6 +1809,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_sync_metrics> cst_encode_box_autoadd_sync_metrics(SyncMetrics raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sync_metrics();
+    cst_api_fill_to_wire_sync_metrics(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_sync_once_request> cst_encode_box_autoadd_sync_once_request(SyncOnceRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sync_once_request();
+    cst_api_fill_to_wire_sync_once_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1581,6 +1862,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_chain_backend> cst_encode_list_chain_backend(List<ChainBackend> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_chain_backend(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_chain_backend(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_event_log_entry> cst_encode_list_event_log_entry(List<EventLogEntry> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_event_log_entry(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_event_log_entry(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_fiat_currency> cst_encode_list_fiat_currency(List<FiatCurrency> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1622,6 +1923,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_log_field> cst_encode_list_log_field(List<LogField> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_log_field(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_log_field(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment> cst_encode_list_payment(List<Payment> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1690,6 +2001,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_swap_state_transition> cst_encode_list_swap_state_transition(
+      List<SwapStateTransition> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_swap_state_transition(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_swap_state_transition(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_sync_task> cst_encode_list_sync_task(List<SyncTask> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_sync_task(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_sync_task(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_webhook_delivery> cst_encode_list_webhook_delivery(List<WebhookDelivery> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_webhook_delivery(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_webhook_delivery(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1708,6 +2050,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bool(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_fiat_amount> cst_encode_opt_box_autoadd_fiat_amount(FiatAmount? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fiat_amount(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1733,6 +2081,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_payment(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_payment_fiat_rate> cst_encode_opt_box_autoadd_payment_fiat_rate(PaymentFiatRate? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_payment_fiat_rate(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_rate> cst_encode_opt_box_autoadd_rate(Rate? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_rate(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_scheduler_intervals> cst_encode_opt_box_autoadd_scheduler_intervals(
+      SchedulerIntervals? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_scheduler_intervals(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_success_action> cst_encode_opt_box_autoadd_success_action(SuccessAction? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1752,6 +2119,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_symbol(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_sync_metrics> cst_encode_opt_box_autoadd_sync_metrics(SyncMetrics? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_sync_metrics(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1764,6 +2137,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(List<String>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_chain_backend> cst_encode_opt_list_chain_backend(List<ChainBackend>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_chain_backend(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment_type> cst_encode_opt_list_payment_type(List<PaymentType>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1913,8 +2298,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_get_payment_request(
-      GetPaymentRequest apiObj, ffi.Pointer<wire_cst_get_payment_request> wireObj) {
+  void cst_api_fill_to_wire_box_autoadd_fiat_amount(
+      FiatAmount apiObj, ffi.Pointer<wire_cst_fiat_amount> wireObj) {
+    cst_api_fill_to_wire_fiat_amount(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_get_payment_request(
+      GetPaymentRequest apiObj, ffi.Pointer<wire_cst_get_payment_request> wireObj) {
     cst_api_fill_to_wire_get_payment_request(apiObj, wireObj.ref);
   }
 
@@ -2024,6 +2415,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_payment(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_payment_error(
+      PaymentError apiObj, ffi.Pointer<wire_cst_payment_error> wireObj) {
+    cst_api_fill_to_wire_payment_error(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_payment_fiat_rate(
+      PaymentFiatRate apiObj, ffi.Pointer<wire_cst_payment_fiat_rate> wireObj) {
+    cst_api_fill_to_wire_payment_fiat_rate(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest apiObj, ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> wireObj) {
@@ -2060,6 +2463,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_send_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_rate(Rate apiObj, ffi.Pointer<wire_cst_rate> wireObj) {
+    cst_api_fill_to_wire_rate(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_receive_payment_request(
       ReceivePaymentRequest apiObj, ffi.Pointer<wire_cst_receive_payment_request> wireObj) {
@@ -2078,6 +2486,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_restore_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_scheduler_intervals(
+      SchedulerIntervals apiObj, ffi.Pointer<wire_cst_scheduler_intervals> wireObj) {
+    cst_api_fill_to_wire_scheduler_intervals(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sdk_error(SdkError apiObj, ffi.Pointer<wire_cst_sdk_error> wireObj) {
+    cst_api_fill_to_wire_sdk_error(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_sdk_event(SdkEvent apiObj, ffi.Pointer<wire_cst_sdk_event> wireObj) {
     cst_api_fill_to_wire_sdk_event(apiObj, wireObj.ref);
@@ -2112,6 +2531,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_symbol(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sync_metrics(
+      SyncMetrics apiObj, ffi.Pointer<wire_cst_sync_metrics> wireObj) {
+    cst_api_fill_to_wire_sync_metrics(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sync_once_request(
+      SyncOnceRequest apiObj, ffi.Pointer<wire_cst_sync_once_request> wireObj) {
+    cst_api_fill_to_wire_sync_once_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_url_success_action_data(
       UrlSuccessActionData apiObj, ffi.Pointer<wire_cst_url_success_action_data> wireObj) {
@@ -2125,6 +2556,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.redirect_url = cst_encode_opt_String(apiObj.redirectUrl);
   }
 
+  @protected
+  void cst_api_fill_to_wire_chain_backend(ChainBackend apiObj, wire_cst_chain_backend wireObj) {
+    if (apiObj is ChainBackend_Electrum) {
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 0;
+      wireObj.kind.Electrum.url = pre_url;
+      return;
+    }
+    if (apiObj is ChainBackend_Esplora) {
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 1;
+      wireObj.kind.Esplora.url = pre_url;
+      return;
+    }
+    if (apiObj is ChainBackend_Hybrid) {
+      var pre_electrum_url = cst_encode_String(apiObj.electrumUrl);
+      var pre_esplora_url = cst_encode_String(apiObj.esploraUrl);
+      wireObj.tag = 2;
+      wireObj.kind.Hybrid.electrum_url = pre_electrum_url;
+      wireObj.kind.Hybrid.esplora_url = pre_esplora_url;
+      return;
+    }
+    if (apiObj is ChainBackend_Rpc) {
+      var pre_url = cst_encode_String(apiObj.url);
+      var pre_username = cst_encode_opt_String(apiObj.username);
+      var pre_password = cst_encode_opt_String(apiObj.password);
+      wireObj.tag = 3;
+      wireObj.kind.Rpc.url = pre_url;
+      wireObj.kind.Rpc.username = pre_username;
+      wireObj.kind.Rpc.password = pre_password;
+      return;
+    }
+  }
+
   @protected
   void cst_api_fill_to_wire_check_message_request(
       CheckMessageRequest apiObj, wire_cst_check_message_request wireObj) {
@@ -2141,8 +2606,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void cst_api_fill_to_wire_config(Config apiObj, wire_cst_config wireObj) {
-    wireObj.liquid_electrum_url = cst_encode_String(apiObj.liquidElectrumUrl);
-    wireObj.bitcoin_electrum_url = cst_encode_String(apiObj.bitcoinElectrumUrl);
+    cst_api_fill_to_wire_chain_backend(apiObj.liquidChainBackend, wireObj.liquid_chain_backend);
+    cst_api_fill_to_wire_chain_backend(apiObj.bitcoinChainBackend, wireObj.bitcoin_chain_backend);
+    wireObj.liquid_fallback_chain_backends =
+        cst_encode_opt_list_chain_backend(apiObj.liquidFallbackChainBackends);
+    wireObj.bitcoin_fallback_chain_backends =
+        cst_encode_opt_list_chain_backend(apiObj.bitcoinFallbackChainBackends);
+    wireObj.chain_quorum_min_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.chainQuorumMinAmountSat);
+    wireObj.swapper_url = cst_encode_opt_String(apiObj.swapperUrl);
+    wireObj.swapper_ws_url = cst_encode_opt_String(apiObj.swapperWsUrl);
+    wireObj.swapper_referral_id = cst_encode_opt_String(apiObj.swapperReferralId);
+    wireObj.lbtc_asset_id = cst_encode_opt_String(apiObj.lbtcAssetId);
+    wireObj.liquid_genesis_hash = cst_encode_opt_String(apiObj.liquidGenesisHash);
     wireObj.mempoolspace_url = cst_encode_String(apiObj.mempoolspaceUrl);
     wireObj.working_dir = cst_encode_String(apiObj.workingDir);
     wireObj.cache_dir = cst_encode_opt_String(apiObj.cacheDir);
@@ -2151,6 +2626,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.zero_conf_min_fee_rate_msat = cst_encode_u_32(apiObj.zeroConfMinFeeRateMsat);
     wireObj.zero_conf_max_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.zeroConfMaxAmountSat);
     wireObj.breez_api_key = cst_encode_opt_String(apiObj.breezApiKey);
+    wireObj.fiat_rate_history_currencies = cst_encode_opt_list_String(apiObj.fiatRateHistoryCurrencies);
+    wireObj.event_webhook_urls = cst_encode_opt_list_String(apiObj.eventWebhookUrls);
+    wireObj.scheduler_intervals = cst_encode_opt_box_autoadd_scheduler_intervals(apiObj.schedulerIntervals);
   }
 
   @protected
@@ -2170,6 +2648,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.locale_overrides = cst_encode_list_locale_overrides(apiObj.localeOverrides);
   }
 
+  @protected
+  void cst_api_fill_to_wire_error_info(ErrorInfo apiObj, wire_cst_error_info wireObj) {
+    wireObj.code = cst_encode_u_32(apiObj.code);
+    wireObj.retryable = cst_encode_bool(apiObj.retryable);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_event_log_entry(EventLogEntry apiObj, wire_cst_event_log_entry wireObj) {
+    wireObj.seq = cst_encode_u_64(apiObj.seq);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+    cst_api_fill_to_wire_sdk_event(apiObj.event, wireObj.event);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_fiat_amount(FiatAmount apiObj, wire_cst_fiat_amount wireObj) {
+    wireObj.currency = cst_encode_String(apiObj.currency);
+    wireObj.amount = cst_encode_f_64(apiObj.amount);
+  }
+
   @protected
   void cst_api_fill_to_wire_fiat_currency(FiatCurrency apiObj, wire_cst_fiat_currency wireObj) {
     wireObj.id = cst_encode_String(apiObj.id);
@@ -2265,6 +2762,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       LightningPaymentLimitsResponse apiObj, wire_cst_lightning_payment_limits_response wireObj) {
     cst_api_fill_to_wire_limits(apiObj.send, wireObj.send);
     cst_api_fill_to_wire_limits(apiObj.receive, wireObj.receive);
+    wireObj.cache_age_secs = cst_encode_opt_box_autoadd_u_64(apiObj.cacheAgeSecs);
   }
 
   @protected
@@ -2632,6 +3130,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void cst_api_fill_to_wire_log_entry(LogEntry apiObj, wire_cst_log_entry wireObj) {
     wireObj.line = cst_encode_String(apiObj.line);
     wireObj.level = cst_encode_String(apiObj.level);
+    wireObj.target = cst_encode_String(apiObj.target);
+    wireObj.module = cst_encode_opt_String(apiObj.module);
+    wireObj.timestamp = cst_encode_u_64(apiObj.timestamp);
+    wireObj.swap_id = cst_encode_opt_String(apiObj.swapId);
+    wireObj.payment_id = cst_encode_opt_String(apiObj.paymentId);
+    wireObj.fields = cst_encode_list_log_field(apiObj.fields);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_log_field(LogField apiObj, wire_cst_log_field wireObj) {
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_String(apiObj.value);
   }
 
   @protected
@@ -2645,6 +3155,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       OnchainPaymentLimitsResponse apiObj, wire_cst_onchain_payment_limits_response wireObj) {
     cst_api_fill_to_wire_limits(apiObj.send, wireObj.send);
     cst_api_fill_to_wire_limits(apiObj.receive, wireObj.receive);
+    wireObj.cache_age_secs = cst_encode_opt_box_autoadd_u_64(apiObj.cacheAgeSecs);
   }
 
   @protected
@@ -2659,6 +3170,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.tag = 1;
       return;
     }
+    if (apiObj is PayAmount_Fiat) {
+      var pre_currency = cst_encode_String(apiObj.currency);
+      var pre_amount = cst_encode_f_64(apiObj.amount);
+      wireObj.tag = 2;
+      wireObj.kind.Fiat.currency = pre_currency;
+      wireObj.kind.Fiat.amount = pre_amount;
+      return;
+    }
   }
 
   @protected
@@ -2678,6 +3197,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
     wireObj.status = cst_encode_payment_state(apiObj.status);
     cst_api_fill_to_wire_payment_details(apiObj.details, wireObj.details);
+    wireObj.fiat_rate = cst_encode_opt_box_autoadd_payment_fiat_rate(apiObj.fiatRate);
   }
 
   @protected
@@ -2832,6 +3352,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.SignerError.err = pre_err;
       return;
     }
+    if (apiObj is PaymentError_Swapper) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 21;
+      wireObj.kind.Swapper.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_ChainService) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 22;
+      wireObj.kind.ChainService.err = pre_err;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_fiat_rate(PaymentFiatRate apiObj, wire_cst_payment_fiat_rate wireObj) {
+    wireObj.currency = cst_encode_String(apiObj.currency);
+    wireObj.rate = cst_encode_f_64(apiObj.rate);
   }
 
   @protected
@@ -2879,12 +3417,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
     wireObj.claim_fees_sat = cst_encode_u_64(apiObj.claimFeesSat);
     wireObj.total_fees_sat = cst_encode_u_64(apiObj.totalFeesSat);
+    wireObj.fiat_rate = cst_encode_opt_box_autoadd_payment_fiat_rate(apiObj.fiatRate);
   }
 
   @protected
   void cst_api_fill_to_wire_prepare_receive_request(
       PrepareReceiveRequest apiObj, wire_cst_prepare_receive_request wireObj) {
     wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
+    wireObj.payer_amount_fiat = cst_encode_opt_box_autoadd_fiat_amount(apiObj.payerAmountFiat);
     wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
   }
 
@@ -2894,6 +3434,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
     wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.fiat_rate = cst_encode_opt_box_autoadd_payment_fiat_rate(apiObj.fiatRate);
   }
 
   @protected
@@ -2910,6 +3451,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.tx_vsize = cst_encode_u_32(apiObj.txVsize);
     wireObj.tx_fee_sat = cst_encode_u_64(apiObj.txFeeSat);
     wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
+    wireObj.is_cooperative = cst_encode_bool(apiObj.isCooperative);
+    wireObj.fee_saved_sat = cst_encode_u_64(apiObj.feeSavedSat);
   }
 
   @protected
@@ -2924,6 +3467,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       PrepareSendResponse apiObj, wire_cst_prepare_send_response wireObj) {
     cst_api_fill_to_wire_send_destination(apiObj.destination, wireObj.destination);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.fiat_rate = cst_encode_opt_box_autoadd_payment_fiat_rate(apiObj.fiatRate);
   }
 
   @protected
@@ -2972,6 +3516,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
     wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
     wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.time_to_refundable = cst_encode_opt_box_autoadd_u_64(apiObj.timeToRefundable);
   }
 
   @protected
@@ -2995,6 +3540,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.htlc_maximum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMaximumMsat);
   }
 
+  @protected
+  void cst_api_fill_to_wire_scheduler_intervals(
+      SchedulerIntervals apiObj, wire_cst_scheduler_intervals wireObj) {
+    wireObj.sync_secs = cst_encode_u_64(apiObj.syncSecs);
+    wireObj.refunds_secs = cst_encode_u_64(apiObj.refundsSecs);
+    wireObj.liquid_rescan_secs = cst_encode_u_64(apiObj.liquidRescanSecs);
+    wireObj.bitcoin_rescan_secs = cst_encode_u_64(apiObj.bitcoinRescanSecs);
+    wireObj.new_blocks_secs = cst_encode_u_64(apiObj.newBlocksSecs);
+  }
+
   @protected
   void cst_api_fill_to_wire_sdk_error(SdkError apiObj, wire_cst_sdk_error wireObj) {
     if (apiObj is SdkError_AlreadyStarted) {
@@ -3017,6 +3572,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.ServiceConnectivity.err = pre_err;
       return;
     }
+    if (apiObj is SdkError_Swapper) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 4;
+      wireObj.kind.Swapper.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkError_ChainService) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 5;
+      wireObj.kind.ChainService.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkError_Persistence) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 6;
+      wireObj.kind.Persistence.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkError_Signer) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 7;
+      wireObj.kind.Signer.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkError_Validation) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 8;
+      wireObj.kind.Validation.err = pre_err;
+      return;
+    }
   }
 
   @protected
@@ -3033,32 +3618,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.PaymentPending.details = pre_details;
       return;
     }
-    if (apiObj is SdkEvent_PaymentRefunded) {
+    if (apiObj is SdkEvent_PaymentQueued) {
       var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
       wireObj.tag = 2;
+      wireObj.kind.PaymentQueued.details = pre_details;
+      return;
+    }
+    if (apiObj is SdkEvent_PaymentRefunded) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 3;
       wireObj.kind.PaymentRefunded.details = pre_details;
       return;
     }
     if (apiObj is SdkEvent_PaymentRefundPending) {
       var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 3;
+      wireObj.tag = 4;
       wireObj.kind.PaymentRefundPending.details = pre_details;
       return;
     }
     if (apiObj is SdkEvent_PaymentSucceeded) {
       var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 4;
+      wireObj.tag = 5;
       wireObj.kind.PaymentSucceeded.details = pre_details;
       return;
     }
     if (apiObj is SdkEvent_PaymentWaitingConfirmation) {
       var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 5;
+      wireObj.tag = 6;
       wireObj.kind.PaymentWaitingConfirmation.details = pre_details;
       return;
     }
+    if (apiObj is SdkEvent_SwapNearingTimeout) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      var pre_blocks_left = cst_encode_u_32(apiObj.blocksLeft);
+      wireObj.tag = 7;
+      wireObj.kind.SwapNearingTimeout.swap_id = pre_swap_id;
+      wireObj.kind.SwapNearingTimeout.blocks_left = pre_blocks_left;
+      return;
+    }
+    if (apiObj is SdkEvent_SwapRefundable) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      wireObj.tag = 8;
+      wireObj.kind.SwapRefundable.swap_id = pre_swap_id;
+      return;
+    }
+    if (apiObj is SdkEvent_SwapStateChanged) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      var pre_from_state = cst_encode_payment_state(apiObj.fromState);
+      var pre_to_state = cst_encode_payment_state(apiObj.toState);
+      wireObj.tag = 9;
+      wireObj.kind.SwapStateChanged.swap_id = pre_swap_id;
+      wireObj.kind.SwapStateChanged.from_state = pre_from_state;
+      wireObj.kind.SwapStateChanged.to_state = pre_to_state;
+      return;
+    }
+    if (apiObj is SdkEvent_BalanceChanged) {
+      var pre_old_balance_sat = cst_encode_u_64(apiObj.oldBalanceSat);
+      var pre_new_balance_sat = cst_encode_u_64(apiObj.newBalanceSat);
+      wireObj.tag = 10;
+      wireObj.kind.BalanceChanged.old_balance_sat = pre_old_balance_sat;
+      wireObj.kind.BalanceChanged.new_balance_sat = pre_new_balance_sat;
+      return;
+    }
+    if (apiObj is SdkEvent_SyncProgress) {
+      var pre_step = cst_encode_u_32(apiObj.step);
+      var pre_total = cst_encode_u_32(apiObj.total);
+      wireObj.tag = 11;
+      wireObj.kind.SyncProgress.step = pre_step;
+      wireObj.kind.SyncProgress.total = pre_total;
+      return;
+    }
+    if (apiObj is SdkEvent_ConnectivityChanged) {
+      var pre_is_connected = cst_encode_bool(apiObj.isConnected);
+      wireObj.tag = 12;
+      wireObj.kind.ConnectivityChanged.is_connected = pre_is_connected;
+      return;
+    }
+    if (apiObj is SdkEvent_NewBlock) {
+      var pre_chain = cst_encode_blockchain(apiObj.chain);
+      var pre_height = cst_encode_u_32(apiObj.height);
+      wireObj.tag = 13;
+      wireObj.kind.NewBlock.chain = pre_chain;
+      wireObj.kind.NewBlock.height = pre_height;
+      return;
+    }
     if (apiObj is SdkEvent_Synced) {
-      wireObj.tag = 6;
+      wireObj.tag = 14;
       return;
     }
   }
@@ -3156,6 +3801,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_swap_state_transition(
+      SwapStateTransition apiObj, wire_cst_swap_state_transition wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.from_state = cst_encode_payment_state(apiObj.fromState);
+    wireObj.to_state = cst_encode_payment_state(apiObj.toState);
+    wireObj.swapper_status = cst_encode_opt_String(apiObj.swapperStatus);
+    wireObj.tx_ids = cst_encode_list_String(apiObj.txIds);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+  }
+
   @protected
   void cst_api_fill_to_wire_symbol(Symbol apiObj, wire_cst_symbol wireObj) {
     wireObj.grapheme = cst_encode_opt_String(apiObj.grapheme);
@@ -3164,6 +3820,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.position = cst_encode_opt_box_autoadd_u_32(apiObj.position);
   }
 
+  @protected
+  void cst_api_fill_to_wire_sync_metrics(SyncMetrics apiObj, wire_cst_sync_metrics wireObj) {
+    wireObj.started_at = cst_encode_u_32(apiObj.startedAt);
+    wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs);
+    wireObj.scan_duration_ms = cst_encode_u_64(apiObj.scanDurationMs);
+    wireObj.liquid_tip = cst_encode_opt_box_autoadd_u_32(apiObj.liquidTip);
+    wireObj.total_tx_count = cst_encode_u_32(apiObj.totalTxCount);
+    wireObj.processed_tx_count = cst_encode_u_32(apiObj.processedTxCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sync_once_request(SyncOnceRequest apiObj, wire_cst_sync_once_request wireObj) {
+    wireObj.timeout_secs = cst_encode_u_64(apiObj.timeoutSecs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sync_once_response(SyncOnceResponse apiObj, wire_cst_sync_once_response wireObj) {
+    wireObj.pending_tasks = cst_encode_list_sync_task(apiObj.pendingTasks);
+    wireObj.pending_swap_count = cst_encode_u_32(apiObj.pendingSwapCount);
+  }
+
   @protected
   void cst_api_fill_to_wire_url_success_action_data(
       UrlSuccessActionData apiObj, wire_cst_url_success_action_data wireObj) {
@@ -3172,6 +3849,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.matches_callback_domain = cst_encode_bool(apiObj.matchesCallbackDomain);
   }
 
+  @protected
+  void cst_api_fill_to_wire_webhook_delivery(WebhookDelivery apiObj, wire_cst_webhook_delivery wireObj) {
+    wireObj.id = cst_encode_u_64(apiObj.id);
+    wireObj.url = cst_encode_String(apiObj.url);
+    wireObj.event_seq = cst_encode_u_64(apiObj.eventSeq);
+    wireObj.payload = cst_encode_String(apiObj.payload);
+    wireObj.state = cst_encode_webhook_delivery_state(apiObj.state);
+    wireObj.attempts = cst_encode_u_32(apiObj.attempts);
+    wireObj.next_attempt_at = cst_encode_u_32(apiObj.nextAttemptAt);
+    wireObj.last_error = cst_encode_opt_String(apiObj.lastError);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+  }
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
       BindingLiquidSdk raw);
@@ -3184,6 +3874,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
       BindingLiquidSdk raw);
 
+  @protected
+  int cst_encode_blockchain(Blockchain raw);
+
   @protected
   bool cst_encode_bool(bool raw);
 
@@ -3211,6 +3904,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int cst_encode_payment_type(PaymentType raw);
 
+  @protected
+  int cst_encode_sync_task(SyncTask raw);
+
   @protected
   int cst_encode_u_16(int raw);
 
@@ -3223,6 +3919,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void cst_encode_unit(void raw);
 
+  @protected
+  int cst_encode_webhook_delivery_state(WebhookDeliveryState raw);
+
   @protected
   void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
 
@@ -3269,6 +3968,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_blockchain(Blockchain self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -3307,6 +4009,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_fiat_amount(FiatAmount self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer);
 
@@ -3370,6 +4075,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_payment_error(PaymentError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest self, SseSerializer serializer);
@@ -3391,6 +4102,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_rate(Rate self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_receive_payment_request(ReceivePaymentRequest self, SseSerializer serializer);
 
@@ -3400,6 +4114,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_scheduler_intervals(SchedulerIntervals self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sdk_error(SdkError self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_sdk_event(SdkEvent self, SseSerializer serializer);
 
@@ -3418,6 +4138,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_symbol(Symbol self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_sync_metrics(SyncMetrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sync_once_request(SyncOnceRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -3433,6 +4159,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_chain_backend(ChainBackend self, SseSerializer serializer);
+
   @protected
   void sse_encode_check_message_request(CheckMessageRequest self, SseSerializer serializer);
 
@@ -3448,9 +4177,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_currency_info(CurrencyInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_error_info(ErrorInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_log_entry(EventLogEntry self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_fiat_amount(FiatAmount self, SseSerializer serializer);
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer);
 
@@ -3485,6 +4223,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_chain_backend(List<ChainBackend> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_event_log_entry(List<EventLogEntry> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer);
 
@@ -3497,6 +4241,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_localized_name(List<LocalizedName> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
 
@@ -3524,6 +4271,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_swap_state_transition(List<SwapStateTransition> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_sync_task(List<SyncTask> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_webhook_delivery(List<WebhookDelivery> self, SseSerializer serializer);
+
   @protected
   void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer);
 
@@ -3587,6 +4343,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer);
+
   @protected
   void sse_encode_message_success_action_data(MessageSuccessActionData self, SseSerializer serializer);
 
@@ -3606,6 +4365,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_fiat_amount(FiatAmount? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
@@ -3618,6 +4380,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_payment_fiat_rate(PaymentFiatRate? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_rate(Rate? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_scheduler_intervals(SchedulerIntervals? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer);
 
@@ -3628,12 +4399,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_symbol(Symbol? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_sync_metrics(SyncMetrics? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_chain_backend(List<ChainBackend>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer);
 
@@ -3652,6 +4432,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_payment_error(PaymentError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer);
+
   @protected
   void sse_encode_payment_method(PaymentMethod self, SseSerializer serializer);
 
@@ -3727,6 +4510,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_route_hint_hop(RouteHintHop self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_scheduler_intervals(SchedulerIntervals self, SseSerializer serializer);
+
   @protected
   void sse_encode_sdk_error(SdkError self, SseSerializer serializer);
 
@@ -3754,9 +4540,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_swap_state_transition(SwapStateTransition self, SseSerializer serializer);
+
   @protected
   void sse_encode_symbol(Symbol self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_sync_metrics(SyncMetrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_once_request(SyncOnceRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_once_response(SyncOnceResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_task(SyncTask self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -3777,6 +4578,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_webhook_delivery(WebhookDelivery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_webhook_delivery_state(WebhookDeliveryState self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -3814,6 +4621,27 @@ class RustLibWire implements BaseWire {
   late final _store_dart_post_cobject =
       _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_ack_events(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> listener_id,
+    int seq,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_ack_events(
+      that,
+      listener_id,
+      seq,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_ack_eventsPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events');
+  late final _wire__crate__bindings__BindingLiquidSdk_ack_events =
+      _wire__crate__bindings__BindingLiquidSdk_ack_eventsPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_add_event_listener(
     int port_,
     int that,
@@ -3921,6 +4749,41 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_events_since(
+    int that,
+    int seq,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_events_since(
+      that,
+      seq,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_events_sincePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Uint64)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since');
+  late final _wire__crate__bindings__BindingLiquidSdk_events_since =
+      _wire__crate__bindings__BindingLiquidSdk_events_sincePtr
+          .asFunction<WireSyncRust2DartDco Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_export_rescue_file(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_export_rescue_file(
+      that,
+      swap_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_export_rescue_filePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file');
+  late final _wire__crate__bindings__BindingLiquidSdk_export_rescue_file =
+      _wire__crate__bindings__BindingLiquidSdk_export_rescue_filePtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
     int port_,
     int that,
@@ -3970,23 +4833,138 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits =
       _wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limitsPtr.asFunction<void Function(int, int)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_get_info(
+  void wire__crate__bindings__BindingLiquidSdk_force_claim_swap(
     int port_,
     int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_get_info(
+    return _wire__crate__bindings__BindingLiquidSdk_force_claim_swap(
       port_,
       that,
+      swap_id,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_get_infoPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info');
-  late final _wire__crate__bindings__BindingLiquidSdk_get_info =
-      _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
-
-  void wire__crate__bindings__BindingLiquidSdk_get_payment(
+  late final _wire__crate__bindings__BindingLiquidSdk_force_claim_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap');
+  late final _wire__crate__bindings__BindingLiquidSdk_force_claim_swap =
+      _wire__crate__bindings__BindingLiquidSdk_force_claim_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+    bool is_cooperative,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(
+      port_,
+      that,
+      swap_id,
+      is_cooperative,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_force_refund_send_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap');
+  late final _wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap =
+      _wire__crate__bindings__BindingLiquidSdk_force_refund_send_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_generate_diagnosticsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics');
+  late final _wire__crate__bindings__BindingLiquidSdk_generate_diagnostics =
+      _wire__crate__bindings__BindingLiquidSdk_generate_diagnosticsPtr.asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_event_cursor(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> listener_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_event_cursor(
+      that,
+      listener_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_event_cursorPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_event_cursor =
+      _wire__crate__bindings__BindingLiquidSdk_get_event_cursorPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> currency,
+    int timestamp,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(
+      port_,
+      that,
+      currency,
+      timestamp,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_ratePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate =
+      _wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_ratePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_info(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_info(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_info =
+      _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_last_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics =
+      _wire__crate__bindings__BindingLiquidSdk_get_last_sync_metricsPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_payment(
     int port_,
     int that,
     ffi.Pointer<wire_cst_get_payment_request> req,
@@ -4006,6 +4984,74 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_payment_request>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_swap_history(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_swap_history(
+      that,
+      swap_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_swap_historyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_swap_history =
+      _wire__crate__bindings__BindingLiquidSdk_get_swap_historyPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secretPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret =
+      _wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secretPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_import_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> rescue_json,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_import_swap(
+      port_,
+      that,
+      rescue_json,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_import_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap');
+  late final _wire__crate__bindings__BindingLiquidSdk_import_swap =
+      _wire__crate__bindings__BindingLiquidSdk_import_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveriesPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries =
+      _wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveriesPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
     int port_,
     int that,
@@ -4118,6 +5164,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(
+      port_,
+      that,
+      swap_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_mark_swap_failedPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed');
+  late final _wire__crate__bindings__BindingLiquidSdk_mark_swap_failed =
+      _wire__crate__bindings__BindingLiquidSdk_mark_swap_failedPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_pay_onchain(
     int port_,
     int that,
@@ -4294,6 +5360,26 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_recommended_fees =
       _wire__crate__bindings__BindingLiquidSdk_recommended_feesPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(
+      port_,
+      that,
+      swap_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_refetch_swap_statusPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status');
+  late final _wire__crate__bindings__BindingLiquidSdk_refetch_swap_status =
+      _wire__crate__bindings__BindingLiquidSdk_refetch_swap_statusPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_refund(
     int port_,
     int that,
@@ -4368,6 +5454,23 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(
+    int that,
+    int id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(
+      that,
+      id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_retry_webhook_deliveryPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Uint64)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery');
+  late final _wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery =
+      _wire__crate__bindings__BindingLiquidSdk_retry_webhook_deliveryPtr
+          .asFunction<WireSyncRust2DartDco Function(int, int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -4422,6 +5525,26 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_sync =
       _wire__crate__bindings__BindingLiquidSdk_syncPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_sync_once(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_sync_once_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_sync_once(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_sync_oncePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_sync_once_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once');
+  late final _wire__crate__bindings__BindingLiquidSdk_sync_once =
+      _wire__crate__bindings__BindingLiquidSdk_sync_oncePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_sync_once_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_unregister_webhook(
     int port_,
     int that,
@@ -4539,6 +5662,34 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__parse_invoice = _wire__crate__bindings__parse_invoicePtr
       .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__payment_error_info(
+    ffi.Pointer<wire_cst_payment_error> err,
+  ) {
+    return _wire__crate__bindings__payment_error_info(
+      err,
+    );
+  }
+
+  late final _wire__crate__bindings__payment_error_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_payment_error>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__payment_error_info');
+  late final _wire__crate__bindings__payment_error_info = _wire__crate__bindings__payment_error_infoPtr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_payment_error>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__sdk_error_info(
+    ffi.Pointer<wire_cst_sdk_error> err,
+  ) {
+    return _wire__crate__bindings__sdk_error_info(
+      err,
+    );
+  }
+
+  late final _wire__crate__bindings__sdk_error_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_sdk_error>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__sdk_error_info');
+  late final _wire__crate__bindings__sdk_error_info = _wire__crate__bindings__sdk_error_infoPtr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_sdk_error>)>();
+
   void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ffi.Pointer<ffi.Void> ptr,
@@ -4688,6 +5839,16 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_connect_request =
       _cst_new_box_autoadd_connect_requestPtr.asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_fiat_amount> cst_new_box_autoadd_fiat_amount() {
+    return _cst_new_box_autoadd_fiat_amount();
+  }
+
+  late final _cst_new_box_autoadd_fiat_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount');
+  late final _cst_new_box_autoadd_fiat_amount =
+      _cst_new_box_autoadd_fiat_amountPtr.asFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>();
+
   ffi.Pointer<wire_cst_get_payment_request> cst_new_box_autoadd_get_payment_request() {
     return _cst_new_box_autoadd_get_payment_request();
   }
@@ -4895,6 +6056,26 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_payment =
       _cst_new_box_autoadd_paymentPtr.asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_error> cst_new_box_autoadd_payment_error() {
+    return _cst_new_box_autoadd_payment_error();
+  }
+
+  late final _cst_new_box_autoadd_payment_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_error> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_error');
+  late final _cst_new_box_autoadd_payment_error =
+      _cst_new_box_autoadd_payment_errorPtr.asFunction<ffi.Pointer<wire_cst_payment_error> Function()>();
+
+  ffi.Pointer<wire_cst_payment_fiat_rate> cst_new_box_autoadd_payment_fiat_rate() {
+    return _cst_new_box_autoadd_payment_fiat_rate();
+  }
+
+  late final _cst_new_box_autoadd_payment_fiat_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_fiat_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate');
+  late final _cst_new_box_autoadd_payment_fiat_rate = _cst_new_box_autoadd_payment_fiat_ratePtr
+      .asFunction<ffi.Pointer<wire_cst_payment_fiat_rate> Function()>();
+
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> cst_new_box_autoadd_prepare_buy_bitcoin_request() {
     return _cst_new_box_autoadd_prepare_buy_bitcoin_request();
   }
@@ -4958,6 +6139,16 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_prepare_send_request = _cst_new_box_autoadd_prepare_send_requestPtr
       .asFunction<ffi.Pointer<wire_cst_prepare_send_request> Function()>();
 
+  ffi.Pointer<wire_cst_rate> cst_new_box_autoadd_rate() {
+    return _cst_new_box_autoadd_rate();
+  }
+
+  late final _cst_new_box_autoadd_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_rate');
+  late final _cst_new_box_autoadd_rate =
+      _cst_new_box_autoadd_ratePtr.asFunction<ffi.Pointer<wire_cst_rate> Function()>();
+
   ffi.Pointer<wire_cst_receive_payment_request> cst_new_box_autoadd_receive_payment_request() {
     return _cst_new_box_autoadd_receive_payment_request();
   }
@@ -4988,6 +6179,26 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_restore_request =
       _cst_new_box_autoadd_restore_requestPtr.asFunction<ffi.Pointer<wire_cst_restore_request> Function()>();
 
+  ffi.Pointer<wire_cst_scheduler_intervals> cst_new_box_autoadd_scheduler_intervals() {
+    return _cst_new_box_autoadd_scheduler_intervals();
+  }
+
+  late final _cst_new_box_autoadd_scheduler_intervalsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_scheduler_intervals> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals');
+  late final _cst_new_box_autoadd_scheduler_intervals = _cst_new_box_autoadd_scheduler_intervalsPtr
+      .asFunction<ffi.Pointer<wire_cst_scheduler_intervals> Function()>();
+
+  ffi.Pointer<wire_cst_sdk_error> cst_new_box_autoadd_sdk_error() {
+    return _cst_new_box_autoadd_sdk_error();
+  }
+
+  late final _cst_new_box_autoadd_sdk_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sdk_error> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sdk_error');
+  late final _cst_new_box_autoadd_sdk_error =
+      _cst_new_box_autoadd_sdk_errorPtr.asFunction<ffi.Pointer<wire_cst_sdk_error> Function()>();
+
   ffi.Pointer<wire_cst_sdk_event> cst_new_box_autoadd_sdk_event() {
     return _cst_new_box_autoadd_sdk_event();
   }
@@ -5048,6 +6259,26 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_symbol =
       _cst_new_box_autoadd_symbolPtr.asFunction<ffi.Pointer<wire_cst_symbol> Function()>();
 
+  ffi.Pointer<wire_cst_sync_metrics> cst_new_box_autoadd_sync_metrics() {
+    return _cst_new_box_autoadd_sync_metrics();
+  }
+
+  late final _cst_new_box_autoadd_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sync_metrics> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics');
+  late final _cst_new_box_autoadd_sync_metrics =
+      _cst_new_box_autoadd_sync_metricsPtr.asFunction<ffi.Pointer<wire_cst_sync_metrics> Function()>();
+
+  ffi.Pointer<wire_cst_sync_once_request> cst_new_box_autoadd_sync_once_request() {
+    return _cst_new_box_autoadd_sync_once_request();
+  }
+
+  late final _cst_new_box_autoadd_sync_once_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sync_once_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request');
+  late final _cst_new_box_autoadd_sync_once_request = _cst_new_box_autoadd_sync_once_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_sync_once_request> Function()>();
+
   ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(
     int value,
   ) {
@@ -5100,6 +6331,34 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_String =
       _cst_new_list_StringPtr.asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_chain_backend> cst_new_list_chain_backend(
+    int len,
+  ) {
+    return _cst_new_list_chain_backend(
+      len,
+    );
+  }
+
+  late final _cst_new_list_chain_backendPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_chain_backend> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_chain_backend');
+  late final _cst_new_list_chain_backend =
+      _cst_new_list_chain_backendPtr.asFunction<ffi.Pointer<wire_cst_list_chain_backend> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_event_log_entry> cst_new_list_event_log_entry(
+    int len,
+  ) {
+    return _cst_new_list_event_log_entry(
+      len,
+    );
+  }
+
+  late final _cst_new_list_event_log_entryPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_event_log_entry> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_event_log_entry');
+  late final _cst_new_list_event_log_entry =
+      _cst_new_list_event_log_entryPtr.asFunction<ffi.Pointer<wire_cst_list_event_log_entry> Function(int)>();
+
   ffi.Pointer<wire_cst_list_fiat_currency> cst_new_list_fiat_currency(
     int len,
   ) {
@@ -5156,6 +6415,20 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_localized_name =
       _cst_new_list_localized_namePtr.asFunction<ffi.Pointer<wire_cst_list_localized_name> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_log_field> cst_new_list_log_field(
+    int len,
+  ) {
+    return _cst_new_list_log_field(
+      len,
+    );
+  }
+
+  late final _cst_new_list_log_fieldPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_log_field> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_log_field');
+  late final _cst_new_list_log_field =
+      _cst_new_list_log_fieldPtr.asFunction<ffi.Pointer<wire_cst_list_log_field> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment> cst_new_list_payment(
     int len,
   ) {
@@ -5254,6 +6527,48 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_route_hint_hop =
       _cst_new_list_route_hint_hopPtr.asFunction<ffi.Pointer<wire_cst_list_route_hint_hop> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_swap_state_transition> cst_new_list_swap_state_transition(
+    int len,
+  ) {
+    return _cst_new_list_swap_state_transition(
+      len,
+    );
+  }
+
+  late final _cst_new_list_swap_state_transitionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_swap_state_transition> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_swap_state_transition');
+  late final _cst_new_list_swap_state_transition = _cst_new_list_swap_state_transitionPtr
+      .asFunction<ffi.Pointer<wire_cst_list_swap_state_transition> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_sync_task> cst_new_list_sync_task(
+    int len,
+  ) {
+    return _cst_new_list_sync_task(
+      len,
+    );
+  }
+
+  late final _cst_new_list_sync_taskPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_sync_task> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_sync_task');
+  late final _cst_new_list_sync_task =
+      _cst_new_list_sync_taskPtr.asFunction<ffi.Pointer<wire_cst_list_sync_task> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_webhook_delivery> cst_new_list_webhook_delivery(
+    int len,
+  ) {
+    return _cst_new_list_webhook_delivery(
+      len,
+    );
+  }
+
+  late final _cst_new_list_webhook_deliveryPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_webhook_delivery> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_webhook_delivery');
+  late final _cst_new_list_webhook_delivery = _cst_new_list_webhook_deliveryPtr
+      .asFunction<ffi.Pointer<wire_cst_list_webhook_delivery> Function(int)>();
+
   int dummy_method_to_enforce_bundling() {
     return _dummy_method_to_enforce_bundling();
   }
@@ -5631,6 +6946,13 @@ final class wire_cst_ln_url_withdraw_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 }
 
+final class wire_cst_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double rate;
+}
+
 final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
   @ffi.Uint64()
   external int receiver_amount_sat;
@@ -5640,6 +6962,8 @@ final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int total_fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_pay_onchain_request extends ffi.Struct {
@@ -5696,8 +7020,17 @@ final class wire_cst_PayAmount_Receiver extends ffi.Struct {
   external int amount_sat;
 }
 
+final class wire_cst_PayAmount_Fiat extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double amount;
+}
+
 final class PayAmountKind extends ffi.Union {
   external wire_cst_PayAmount_Receiver Receiver;
+
+  external wire_cst_PayAmount_Fiat Fiat;
 }
 
 final class wire_cst_pay_amount extends ffi.Struct {
@@ -5713,9 +7046,18 @@ final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> fee_rate_sat_per_vbyte;
 }
 
+final class wire_cst_fiat_amount extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double amount;
+}
+
 final class wire_cst_prepare_receive_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 
+  external ffi.Pointer<wire_cst_fiat_amount> payer_amount_fiat;
+
   @ffi.Int32()
   external int payment_method;
 }
@@ -5743,6 +7085,8 @@ final class wire_cst_prepare_receive_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_receive_payment_request extends ffi.Struct {
@@ -5771,6 +7115,8 @@ final class wire_cst_prepare_send_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_send_payment_request extends ffi.Struct {
@@ -5781,6 +7127,11 @@ final class wire_cst_sign_message_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
 }
 
+final class wire_cst_sync_once_request extends ffi.Struct {
+  @ffi.Uint64()
+  external int timeout_secs;
+}
+
 final class wire_cst_binding_event_listener extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
 }
@@ -5855,6 +7206,8 @@ final class wire_cst_payment extends ffi.Struct {
   external int status;
 
   external wire_cst_payment_details details;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_SdkEvent_PaymentFailed extends ffi.Struct {
@@ -5865,6 +7218,10 @@ final class wire_cst_SdkEvent_PaymentPending extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_SdkEvent_PaymentQueued extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
 final class wire_cst_SdkEvent_PaymentRefunded extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
@@ -5881,10 +7238,62 @@ final class wire_cst_SdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
-final class SdkEventKind extends ffi.Union {
-  external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
+final class wire_cst_SdkEvent_SwapNearingTimeout extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-  external wire_cst_SdkEvent_PaymentPending PaymentPending;
+  @ffi.Uint32()
+  external int blocks_left;
+}
+
+final class wire_cst_SdkEvent_SwapRefundable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_SdkEvent_SwapStateChanged extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Int32()
+  external int from_state;
+
+  @ffi.Int32()
+  external int to_state;
+}
+
+final class wire_cst_SdkEvent_BalanceChanged extends ffi.Struct {
+  @ffi.Uint64()
+  external int old_balance_sat;
+
+  @ffi.Uint64()
+  external int new_balance_sat;
+}
+
+final class wire_cst_SdkEvent_SyncProgress extends ffi.Struct {
+  @ffi.Uint32()
+  external int step;
+
+  @ffi.Uint32()
+  external int total;
+}
+
+final class wire_cst_SdkEvent_ConnectivityChanged extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_connected;
+}
+
+final class wire_cst_SdkEvent_NewBlock extends ffi.Struct {
+  @ffi.Int32()
+  external int chain;
+
+  @ffi.Uint32()
+  external int height;
+}
+
+final class SdkEventKind extends ffi.Union {
+  external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
+
+  external wire_cst_SdkEvent_PaymentPending PaymentPending;
+
+  external wire_cst_SdkEvent_PaymentQueued PaymentQueued;
 
   external wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
 
@@ -5893,6 +7302,20 @@ final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
 
   external wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+
+  external wire_cst_SdkEvent_SwapNearingTimeout SwapNearingTimeout;
+
+  external wire_cst_SdkEvent_SwapRefundable SwapRefundable;
+
+  external wire_cst_SdkEvent_SwapStateChanged SwapStateChanged;
+
+  external wire_cst_SdkEvent_BalanceChanged BalanceChanged;
+
+  external wire_cst_SdkEvent_SyncProgress SyncProgress;
+
+  external wire_cst_SdkEvent_ConnectivityChanged ConnectivityChanged;
+
+  external wire_cst_SdkEvent_NewBlock NewBlock;
 }
 
 final class wire_cst_sdk_event extends ffi.Struct {
@@ -5902,10 +7325,89 @@ final class wire_cst_sdk_event extends ffi.Struct {
   external SdkEventKind kind;
 }
 
+final class wire_cst_ChainBackend_Electrum extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_ChainBackend_Esplora extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_ChainBackend_Hybrid extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> electrum_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> esplora_url;
+}
+
+final class wire_cst_ChainBackend_Rpc extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> username;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> password;
+}
+
+final class ChainBackendKind extends ffi.Union {
+  external wire_cst_ChainBackend_Electrum Electrum;
+
+  external wire_cst_ChainBackend_Esplora Esplora;
+
+  external wire_cst_ChainBackend_Hybrid Hybrid;
+
+  external wire_cst_ChainBackend_Rpc Rpc;
+}
+
+final class wire_cst_chain_backend extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ChainBackendKind kind;
+}
+
+final class wire_cst_list_chain_backend extends ffi.Struct {
+  external ffi.Pointer<wire_cst_chain_backend> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_scheduler_intervals extends ffi.Struct {
+  @ffi.Uint64()
+  external int sync_secs;
+
+  @ffi.Uint64()
+  external int refunds_secs;
+
+  @ffi.Uint64()
+  external int liquid_rescan_secs;
+
+  @ffi.Uint64()
+  external int bitcoin_rescan_secs;
+
+  @ffi.Uint64()
+  external int new_blocks_secs;
+}
+
 final class wire_cst_config extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_electrum_url;
+  external wire_cst_chain_backend liquid_chain_backend;
+
+  external wire_cst_chain_backend bitcoin_chain_backend;
+
+  external ffi.Pointer<wire_cst_list_chain_backend> liquid_fallback_chain_backends;
+
+  external ffi.Pointer<wire_cst_list_chain_backend> bitcoin_fallback_chain_backends;
+
+  external ffi.Pointer<ffi.Uint64> chain_quorum_min_amount_sat;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_electrum_url;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_ws_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_referral_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lbtc_asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_genesis_hash;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mempoolspace_url;
 
@@ -5925,6 +7427,12 @@ final class wire_cst_config extends ffi.Struct {
   external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key;
+
+  external ffi.Pointer<wire_cst_list_String> fiat_rate_history_currencies;
+
+  external ffi.Pointer<wire_cst_list_String> event_webhook_urls;
+
+  external ffi.Pointer<wire_cst_scheduler_intervals> scheduler_intervals;
 }
 
 final class wire_cst_connect_request extends ffi.Struct {
@@ -5933,6 +7441,140 @@ final class wire_cst_connect_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
 }
 
+final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidNetwork extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Generic extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidDescription extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_LwkError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_ReceiveError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Refunded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+}
+
+final class wire_cst_PaymentError_SendError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_SignerError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Swapper extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_ChainService extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_AmountMissing AmountMissing;
+
+  external wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
+
+  external wire_cst_PaymentError_Generic Generic;
+
+  external wire_cst_PaymentError_InvalidDescription InvalidDescription;
+
+  external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
+
+  external wire_cst_PaymentError_LwkError LwkError;
+
+  external wire_cst_PaymentError_ReceiveError ReceiveError;
+
+  external wire_cst_PaymentError_Refunded Refunded;
+
+  external wire_cst_PaymentError_SendError SendError;
+
+  external wire_cst_PaymentError_SignerError SignerError;
+
+  external wire_cst_PaymentError_Swapper Swapper;
+
+  external wire_cst_PaymentError_ChainService ChainService;
+}
+
+final class wire_cst_payment_error extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external PaymentErrorKind kind;
+}
+
+final class wire_cst_SdkError_Generic extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_ServiceConnectivity extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Swapper extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_ChainService extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Persistence extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Signer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Validation extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class SdkErrorKind extends ffi.Union {
+  external wire_cst_SdkError_Generic Generic;
+
+  external wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
+
+  external wire_cst_SdkError_Swapper Swapper;
+
+  external wire_cst_SdkError_ChainService ChainService;
+
+  external wire_cst_SdkError_Persistence Persistence;
+
+  external wire_cst_SdkError_Signer Signer;
+
+  external wire_cst_SdkError_Validation Validation;
+}
+
+final class wire_cst_sdk_error extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SdkErrorKind kind;
+}
+
 final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
@@ -6020,6 +7662,13 @@ final class wire_cst_ln_url_withdraw_success_data extends ffi.Struct {
   external wire_cst_ln_invoice invoice;
 }
 
+final class wire_cst_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+
+  @ffi.Double()
+  external double value;
+}
+
 final class wire_cst_symbol extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> grapheme;
 
@@ -6030,6 +7679,42 @@ final class wire_cst_symbol extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> position;
 }
 
+final class wire_cst_sync_metrics extends ffi.Struct {
+  @ffi.Uint32()
+  external int started_at;
+
+  @ffi.Uint64()
+  external int duration_ms;
+
+  @ffi.Uint64()
+  external int scan_duration_ms;
+
+  external ffi.Pointer<ffi.Uint32> liquid_tip;
+
+  @ffi.Uint32()
+  external int total_tx_count;
+
+  @ffi.Uint32()
+  external int processed_tx_count;
+}
+
+final class wire_cst_event_log_entry extends ffi.Struct {
+  @ffi.Uint64()
+  external int seq;
+
+  @ffi.Uint32()
+  external int created_at;
+
+  external wire_cst_sdk_event event;
+}
+
+final class wire_cst_list_event_log_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_event_log_entry> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_localized_name extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
 
@@ -6088,18 +7773,24 @@ final class wire_cst_list_fiat_currency extends ffi.Struct {
   external int len;
 }
 
-final class wire_cst_list_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> ptr;
+final class wire_cst_log_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_log_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_log_field> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_rate extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+final class wire_cst_list_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> ptr;
 
-  @ffi.Double()
-  external double value;
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_list_rate extends ffi.Struct {
@@ -6117,6 +7808,8 @@ final class wire_cst_refundable_swap extends ffi.Struct {
 
   @ffi.Uint64()
   external int amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> time_to_refundable;
 }
 
 final class wire_cst_list_refundable_swap extends ffi.Struct {
@@ -6126,11 +7819,83 @@ final class wire_cst_list_refundable_swap extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_swap_state_transition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Int32()
+  external int from_state;
+
+  @ffi.Int32()
+  external int to_state;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_status;
+
+  external ffi.Pointer<wire_cst_list_String> tx_ids;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_swap_state_transition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_swap_state_transition> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_sync_task extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_webhook_delivery extends ffi.Struct {
+  @ffi.Uint64()
+  external int id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Uint64()
+  external int event_seq;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payload;
+
+  @ffi.Int32()
+  external int state;
+
+  @ffi.Uint32()
+  external int attempts;
+
+  @ffi.Uint32()
+  external int next_attempt_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_webhook_delivery extends ffi.Struct {
+  external ffi.Pointer<wire_cst_webhook_delivery> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_check_message_response extends ffi.Struct {
   @ffi.Bool()
   external bool is_valid;
 }
 
+final class wire_cst_error_info extends ffi.Struct {
+  @ffi.Uint32()
+  external int code;
+
+  @ffi.Bool()
+  external bool retryable;
+}
+
 final class wire_cst_get_info_response extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -6230,6 +7995,8 @@ final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
   external wire_cst_limits send;
 
   external wire_cst_limits receive;
+
+  external ffi.Pointer<ffi.Uint64> cache_age_secs;
 }
 
 final class wire_cst_LnUrlAuthError_Generic extends ffi.Struct {
@@ -6452,83 +8219,27 @@ final class wire_cst_log_entry extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
-}
 
-final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
-  external wire_cst_limits send;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
 
-  external wire_cst_limits receive;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> module;
 
-final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_InvalidNetwork extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_Generic extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_InvalidDescription extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_LwkError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_ReceiveError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_Refunded extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+  @ffi.Uint64()
+  external int timestamp;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-final class wire_cst_PaymentError_SendError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
 
-final class wire_cst_PaymentError_SignerError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+  external ffi.Pointer<wire_cst_list_log_field> fields;
 }
 
-final class PaymentErrorKind extends ffi.Union {
-  external wire_cst_PaymentError_AmountMissing AmountMissing;
-
-  external wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
-
-  external wire_cst_PaymentError_Generic Generic;
-
-  external wire_cst_PaymentError_InvalidDescription InvalidDescription;
-
-  external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
-
-  external wire_cst_PaymentError_LwkError LwkError;
-
-  external wire_cst_PaymentError_ReceiveError ReceiveError;
-
-  external wire_cst_PaymentError_Refunded Refunded;
-
-  external wire_cst_PaymentError_SendError SendError;
-
-  external wire_cst_PaymentError_SignerError SignerError;
-}
+final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
+  external wire_cst_limits send;
 
-final class wire_cst_payment_error extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+  external wire_cst_limits receive;
 
-  external PaymentErrorKind kind;
+  external ffi.Pointer<ffi.Uint64> cache_age_secs;
 }
 
 final class wire_cst_prepare_refund_response extends ffi.Struct {
@@ -6539,6 +8250,12 @@ final class wire_cst_prepare_refund_response extends ffi.Struct {
   external int tx_fee_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  @ffi.Bool()
+  external bool is_cooperative;
+
+  @ffi.Uint64()
+  external int fee_saved_sat;
 }
 
 final class wire_cst_receive_payment_response extends ffi.Struct {
@@ -6566,27 +8283,6 @@ final class wire_cst_refund_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
-final class wire_cst_SdkError_Generic extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_SdkError_ServiceConnectivity extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class SdkErrorKind extends ffi.Union {
-  external wire_cst_SdkError_Generic Generic;
-
-  external wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
-}
-
-final class wire_cst_sdk_error extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
-
-  external SdkErrorKind kind;
-}
-
 final class wire_cst_send_payment_response extends ffi.Struct {
   external wire_cst_payment payment;
 }
@@ -6595,12 +8291,21 @@ final class wire_cst_sign_message_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature;
 }
 
+final class wire_cst_sync_once_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_sync_task> pending_tasks;
+
+  @ffi.Uint32()
+  external int pending_swap_count;
+}
+
 const int ESTIMATED_BTC_CLAIM_TX_VSIZE = 111;
 
 const double STANDARD_FEE_RATE_SAT_PER_VBYTE = 0.1;
 
 const double LOWBALL_FEE_RATE_SAT_PER_VBYTE = 0.01;
 
+const int BITCOIN_BLOCK_INTERVAL_SECS = 600;
+
 const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET = 100;
 
 const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET = 10;
@@ -6608,3 +8313,15 @@ const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET = 10;
 const int DEFAULT_ZERO_CONF_MAX_SAT = 100000;
 
 const int CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS = 4320;
+
+const int SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS = 6;
+
+const int SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS = 60;
+
+const int EVENT_LOG_RETENTION_SECS = 2592000;
+
+const int EVENT_LOG_PAGE_SIZE = 500;
+
+const int FIAT_RATE_HISTORY_RETENTION_SECS = 31536000;
+
+const int FIAT_RATE_HISTORY_MAX_DISTANCE_SECS = 86400;
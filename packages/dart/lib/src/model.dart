@@ -30,6 +30,13 @@ class BackupRequest {
       other is BackupRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
 }
 
+/// The chains the SDK interacts with
+enum Blockchain {
+  liquid,
+  bitcoin,
+  ;
+}
+
 /// An argument of [PrepareBuyBitcoinRequest] when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
 enum BuyBitcoinProvider {
   moonpay,
@@ -62,6 +69,47 @@ class BuyBitcoinRequest {
           redirectUrl == other.redirectUrl;
 }
 
+@freezed
+sealed class ChainBackend with _$ChainBackend {
+  const ChainBackend._();
+
+  /// An Electrum server, in the format `host:port`. TLS is used unless the URL is
+  /// prefixed with `tcp://`, as is common for local regtest servers.
+  const factory ChainBackend.electrum({
+    required String url,
+  }) = ChainBackend_Electrum;
+
+  /// An Esplora REST API, in the format `https://blockstream.info/liquid/api`
+  ///
+  /// Boltz swap claim and refund transactions are built with Electrum data. If no backend of
+  /// the chain has an Electrum server, a public Electrum server of the network is used for them.
+  const factory ChainBackend.esplora({
+    required String url,
+  }) = ChainBackend_Esplora;
+
+  /// An Electrum server for the tip and batched queries, with single script histories,
+  /// transaction lookups and broadcasts going through an Esplora REST API.
+  /// This lets the SDK see lowball transactions which Electrum servers may not relay.
+  const factory ChainBackend.hybrid({
+    required String electrumUrl,
+    required String esploraUrl,
+  }) = ChainBackend_Hybrid;
+
+  /// The JSON-RPC interface of a bitcoind or elementsd node, e.g. `http://127.0.0.1:18884`.
+  ///
+  /// The node needs `txindex=1`. Script histories can't be fetched over RPC, as scanning the
+  /// UTXO set doesn't show spent outputs, and neither can the Liquid wallet and Boltz swap
+  /// transactions be synced. These go through the other backends of the chain, of which at
+  /// least one has to be an Electrum, Esplora or Hybrid backend. RPC-only configurations
+  /// aren't supported, so a regtest setup needs a local Electrum or Esplora server, such as
+  /// electrs, next to the daemons.
+  const factory ChainBackend.rpc({
+    required String url,
+    String? username,
+    String? password,
+  }) = ChainBackend_Rpc;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::check_message].
 class CheckMessageRequest {
   /// The message that was signed.
@@ -113,8 +161,40 @@ class CheckMessageResponse {
 
 /// Configuration for the Liquid SDK
 class Config {
-  final String liquidElectrumUrl;
-  final String bitcoinElectrumUrl;
+  final ChainBackend liquidChainBackend;
+  final ChainBackend bitcoinChainBackend;
+
+  /// Backends the SDK fails over to, in order, when the Liquid chain backend is unreachable
+  final List<ChainBackend>? liquidFallbackChainBackends;
+
+  /// Backends the SDK fails over to, in order, when the Bitcoin chain backend is unreachable
+  final List<ChainBackend>? bitcoinFallbackChainBackends;
+
+  /// Swaps of at least this amount have the server lockup transaction verified by two
+  /// different chain backends before being claimed. Requires fallback backends to be set, so
+  /// that each chain has at least two Electrum, Esplora or Hybrid backends.
+  final BigInt? chainQuorumMinAmountSat;
+
+  /// The Boltz API URL, in the format `https://api.boltz.exchange/v2`. Overrides the default
+  /// Boltz instance of the network. Required on [LiquidNetwork::Regtest], where there is
+  /// no public Boltz instance.
+  final String? swapperUrl;
+
+  /// The Boltz websocket URL, in the format `wss://api.boltz.exchange/v2/ws`.
+  /// Defaults to the `/ws` endpoint of the swapper API URL.
+  final String? swapperWsUrl;
+
+  /// The referral id sent to Boltz when creating swaps. If not set, the Breez referral id is
+  /// used when swapping through the default mainnet Boltz instance.
+  final String? swapperReferralId;
+
+  /// The hex encoded L-BTC asset id. Only used on [LiquidNetwork::Regtest], where it defaults
+  /// to the policy asset of a default elementsd regtest chain.
+  final String? lbtcAssetId;
+
+  /// The hex encoded genesis block hash of the Liquid chain. Only used on
+  /// [LiquidNetwork::Regtest], where it's fetched from the Liquid chain backend if not set.
+  final String? liquidGenesisHash;
 
   /// The mempool.space API URL, has to be in the format: `https://mempool.space/api`
   final String mempoolspaceUrl;
@@ -141,9 +221,29 @@ class Config {
   /// The Breez API key used for making requests to their mempool service
   final String? breezApiKey;
 
+  /// Currencies whose rates are persisted hourly, so that the fiat value of past payments is
+  /// available offline. Defaults to [DEFAULT_FIAT_RATE_HISTORY_CURRENCIES]
+  final List<String>? fiatRateHistoryCurrencies;
+
+  /// Endpoints to which every persisted [SdkEvent] is POSTed as a signed JSON payload,
+  /// starting with the events emitted after they are first configured.
+  /// See [crate::sdk::LiquidSdk::get_webhook_signing_secret]
+  final List<String>? eventWebhookUrls;
+
+  /// The intervals of the background tasks. Defaults to [SchedulerIntervals::default]
+  final SchedulerIntervals? schedulerIntervals;
+
   const Config({
-    required this.liquidElectrumUrl,
-    required this.bitcoinElectrumUrl,
+    required this.liquidChainBackend,
+    required this.bitcoinChainBackend,
+    this.liquidFallbackChainBackends,
+    this.bitcoinFallbackChainBackends,
+    this.chainQuorumMinAmountSat,
+    this.swapperUrl,
+    this.swapperWsUrl,
+    this.swapperReferralId,
+    this.lbtcAssetId,
+    this.liquidGenesisHash,
     required this.mempoolspaceUrl,
     required this.workingDir,
     this.cacheDir,
@@ -152,12 +252,23 @@ class Config {
     required this.zeroConfMinFeeRateMsat,
     this.zeroConfMaxAmountSat,
     this.breezApiKey,
+    this.fiatRateHistoryCurrencies,
+    this.eventWebhookUrls,
+    this.schedulerIntervals,
   });
 
   @override
   int get hashCode =>
-      liquidElectrumUrl.hashCode ^
-      bitcoinElectrumUrl.hashCode ^
+      liquidChainBackend.hashCode ^
+      bitcoinChainBackend.hashCode ^
+      liquidFallbackChainBackends.hashCode ^
+      bitcoinFallbackChainBackends.hashCode ^
+      chainQuorumMinAmountSat.hashCode ^
+      swapperUrl.hashCode ^
+      swapperWsUrl.hashCode ^
+      swapperReferralId.hashCode ^
+      lbtcAssetId.hashCode ^
+      liquidGenesisHash.hashCode ^
       mempoolspaceUrl.hashCode ^
       workingDir.hashCode ^
       cacheDir.hashCode ^
@@ -165,15 +276,26 @@ class Config {
       paymentTimeoutSec.hashCode ^
       zeroConfMinFeeRateMsat.hashCode ^
       zeroConfMaxAmountSat.hashCode ^
-      breezApiKey.hashCode;
+      breezApiKey.hashCode ^
+      fiatRateHistoryCurrencies.hashCode ^
+      eventWebhookUrls.hashCode ^
+      schedulerIntervals.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is Config &&
           runtimeType == other.runtimeType &&
-          liquidElectrumUrl == other.liquidElectrumUrl &&
-          bitcoinElectrumUrl == other.bitcoinElectrumUrl &&
+          liquidChainBackend == other.liquidChainBackend &&
+          bitcoinChainBackend == other.bitcoinChainBackend &&
+          liquidFallbackChainBackends == other.liquidFallbackChainBackends &&
+          bitcoinFallbackChainBackends == other.bitcoinFallbackChainBackends &&
+          chainQuorumMinAmountSat == other.chainQuorumMinAmountSat &&
+          swapperUrl == other.swapperUrl &&
+          swapperWsUrl == other.swapperWsUrl &&
+          swapperReferralId == other.swapperReferralId &&
+          lbtcAssetId == other.lbtcAssetId &&
+          liquidGenesisHash == other.liquidGenesisHash &&
           mempoolspaceUrl == other.mempoolspaceUrl &&
           workingDir == other.workingDir &&
           cacheDir == other.cacheDir &&
@@ -181,7 +303,10 @@ class Config {
           paymentTimeoutSec == other.paymentTimeoutSec &&
           zeroConfMinFeeRateMsat == other.zeroConfMinFeeRateMsat &&
           zeroConfMaxAmountSat == other.zeroConfMaxAmountSat &&
-          breezApiKey == other.breezApiKey;
+          breezApiKey == other.breezApiKey &&
+          fiatRateHistoryCurrencies == other.fiatRateHistoryCurrencies &&
+          eventWebhookUrls == other.eventWebhookUrls &&
+          schedulerIntervals == other.schedulerIntervals;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::connect].
@@ -206,6 +331,57 @@ class ConnectRequest {
           mnemonic == other.mnemonic;
 }
 
+/// An [SdkEvent] stored in the persisted event log, see [crate::sdk::LiquidSdk::events_since]
+class EventLogEntry {
+  /// Monotonically increasing sequence number of the event
+  final BigInt seq;
+
+  /// The time the event was emitted
+  final int createdAt;
+  final SdkEvent event;
+
+  const EventLogEntry({
+    required this.seq,
+    required this.createdAt,
+    required this.event,
+  });
+
+  @override
+  int get hashCode => seq.hashCode ^ createdAt.hashCode ^ event.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventLogEntry &&
+          runtimeType == other.runtimeType &&
+          seq == other.seq &&
+          createdAt == other.createdAt &&
+          event == other.event;
+}
+
+/// An amount denominated in a fiat currency
+class FiatAmount {
+  /// The currency id, e.g. `USD`
+  final String currency;
+  final double amount;
+
+  const FiatAmount({
+    required this.currency,
+    required this.amount,
+  });
+
+  @override
+  int get hashCode => currency.hashCode ^ amount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FiatAmount &&
+          runtimeType == other.runtimeType &&
+          currency == other.currency &&
+          amount == other.amount;
+}
+
 /// Returned when calling [crate::sdk::LiquidSdk::get_info].
 class GetInfoResponse {
   /// Usable balance. This is the confirmed onchain balance minus `pending_send_sat`.
@@ -269,13 +445,19 @@ class LightningPaymentLimitsResponse {
   /// Amount limits for a Receive Payment to be valid
   final Limits receive;
 
+  /// Seconds since the limits were fetched from the swapper. They are cached for a short
+  /// while, and served from the cache while the swapper is unreachable.
+  /// `None` if they haven't been cached yet.
+  final BigInt? cacheAgeSecs;
+
   const LightningPaymentLimitsResponse({
     required this.send,
     required this.receive,
+    this.cacheAgeSecs,
   });
 
   @override
-  int get hashCode => send.hashCode ^ receive.hashCode;
+  int get hashCode => send.hashCode ^ receive.hashCode ^ cacheAgeSecs.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -283,7 +465,8 @@ class LightningPaymentLimitsResponse {
       other is LightningPaymentLimitsResponse &&
           runtimeType == other.runtimeType &&
           send == other.send &&
-          receive == other.receive;
+          receive == other.receive &&
+          cacheAgeSecs == other.cacheAgeSecs;
 }
 
 /// The minimum and maximum in satoshis of a Lightning or onchain payment.
@@ -319,6 +502,9 @@ enum LiquidNetwork {
 
   /// Testnet Bitcoin and Liquid chains
   testnet,
+
+  /// Local regtest Bitcoin and Liquid chains, e.g. for integration tests
+  regtest,
   ;
 }
 
@@ -437,23 +623,83 @@ class LnUrlPaySuccessData {
           successAction == other.successAction;
 }
 
-/// Internal SDK log entry used in the Uniffi and Dart bindings
+/// Internal SDK log entry used in the Uniffi and Dart bindings.
+///
+/// Preimages, private keys and other secrets are redacted from the line and the fields.
 class LogEntry {
   final String line;
   final String level;
 
+  /// The target of the log record, by default the module path of the caller
+  final String target;
+  final String? module;
+
+  /// The time of the log record, in milliseconds since the Unix epoch
+  final BigInt timestamp;
+
+  /// The id of the swap being handled, when logged within a swap span
+  final String? swapId;
+
+  /// The tx id of the swap payment, when logged within a swap span and already known
+  final String? paymentId;
+
+  /// The key/value pairs attached to the log record
+  final List<LogField> fields;
+
   const LogEntry({
     required this.line,
     required this.level,
+    required this.target,
+    this.module,
+    required this.timestamp,
+    this.swapId,
+    this.paymentId,
+    required this.fields,
   });
 
   @override
-  int get hashCode => line.hashCode ^ level.hashCode;
+  int get hashCode =>
+      line.hashCode ^
+      level.hashCode ^
+      target.hashCode ^
+      module.hashCode ^
+      timestamp.hashCode ^
+      swapId.hashCode ^
+      paymentId.hashCode ^
+      fields.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LogEntry && runtimeType == other.runtimeType && line == other.line && level == other.level;
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          line == other.line &&
+          level == other.level &&
+          target == other.target &&
+          module == other.module &&
+          timestamp == other.timestamp &&
+          swapId == other.swapId &&
+          paymentId == other.paymentId &&
+          fields == other.fields;
+}
+
+/// A key/value pair of a [LogEntry]
+class LogField {
+  final String key;
+  final String value;
+
+  const LogField({
+    required this.key,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogField && runtimeType == other.runtimeType && key == other.key && value == other.value;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::fetch_onchain_limits].
@@ -464,13 +710,19 @@ class OnchainPaymentLimitsResponse {
   /// Amount limits for a Receive Onchain Payment to be valid
   final Limits receive;
 
+  /// Seconds since the limits were fetched from the swapper. They are cached for a short
+  /// while, and served from the cache while the swapper is unreachable.
+  /// `None` if they haven't been cached yet.
+  final BigInt? cacheAgeSecs;
+
   const OnchainPaymentLimitsResponse({
     required this.send,
     required this.receive,
+    this.cacheAgeSecs,
   });
 
   @override
-  int get hashCode => send.hashCode ^ receive.hashCode;
+  int get hashCode => send.hashCode ^ receive.hashCode ^ cacheAgeSecs.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -478,7 +730,8 @@ class OnchainPaymentLimitsResponse {
       other is OnchainPaymentLimitsResponse &&
           runtimeType == other.runtimeType &&
           send == other.send &&
-          receive == other.receive;
+          receive == other.receive &&
+          cacheAgeSecs == other.cacheAgeSecs;
 }
 
 @freezed
@@ -492,6 +745,13 @@ sealed class PayAmount with _$PayAmount {
 
   /// Indicates that all available funds should be sent
   const factory PayAmount.drain() = PayAmount_Drain;
+
+  /// The amount in a fiat currency that will be received, converted to satoshi at the
+  /// current rate when preparing the payment
+  const factory PayAmount.fiat({
+    required String currency,
+    required double amount,
+  }) = PayAmount_Fiat;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
@@ -566,6 +826,12 @@ class Payment {
   /// [type](Payment::payment_type)
   final PaymentDetails details;
 
+  /// The fiat rate used when the payment was prepared with a fiat amount.
+  ///
+  /// Not recorded for direct Liquid receives, whose tx isn't known in advance.
+  /// See [crate::sdk::LiquidSdk::get_historical_fiat_rate] for the rate at any point in time.
+  final PaymentFiatRate? fiatRate;
+
   const Payment({
     this.destination,
     this.txId,
@@ -575,6 +841,7 @@ class Payment {
     required this.paymentType,
     required this.status,
     required this.details,
+    this.fiatRate,
   });
 
   @override
@@ -586,7 +853,8 @@ class Payment {
       feesSat.hashCode ^
       paymentType.hashCode ^
       status.hashCode ^
-      details.hashCode;
+      details.hashCode ^
+      fiatRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -600,7 +868,8 @@ class Payment {
           feesSat == other.feesSat &&
           paymentType == other.paymentType &&
           status == other.status &&
-          details == other.details;
+          details == other.details &&
+          fiatRate == other.fiatRate;
 }
 
 @freezed
@@ -657,6 +926,31 @@ sealed class PaymentDetails with _$PaymentDetails {
   }) = PaymentDetails_Bitcoin;
 }
 
+/// The fiat rate used to convert a fiat amount when preparing a payment
+class PaymentFiatRate {
+  /// The currency id, e.g. `USD`
+  final String currency;
+
+  /// The value of one bitcoin in the currency
+  final double rate;
+
+  const PaymentFiatRate({
+    required this.currency,
+    required this.rate,
+  });
+
+  @override
+  int get hashCode => currency.hashCode ^ rate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentFiatRate &&
+          runtimeType == other.runtimeType &&
+          currency == other.currency &&
+          rate == other.rate;
+}
+
 /// The send/receive methods supported by the SDK
 enum PaymentMethod {
   lightning,
@@ -884,14 +1178,19 @@ class PreparePayOnchainResponse {
   final BigInt claimFeesSat;
   final BigInt totalFeesSat;
 
+  /// The rate used to convert the fiat amount, if one was requested
+  final PaymentFiatRate? fiatRate;
+
   const PreparePayOnchainResponse({
     required this.receiverAmountSat,
     required this.claimFeesSat,
     required this.totalFeesSat,
+    this.fiatRate,
   });
 
   @override
-  int get hashCode => receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode;
+  int get hashCode =>
+      receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode ^ fiatRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -900,21 +1199,27 @@ class PreparePayOnchainResponse {
           runtimeType == other.runtimeType &&
           receiverAmountSat == other.receiverAmountSat &&
           claimFeesSat == other.claimFeesSat &&
-          totalFeesSat == other.totalFeesSat;
+          totalFeesSat == other.totalFeesSat &&
+          fiatRate == other.fiatRate;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
 class PrepareReceiveRequest {
   final BigInt? payerAmountSat;
+
+  /// The amount to be paid by the payer in a fiat currency, converted at the current rate.
+  /// Cannot be set together with `payer_amount_sat`.
+  final FiatAmount? payerAmountFiat;
   final PaymentMethod paymentMethod;
 
   const PrepareReceiveRequest({
     this.payerAmountSat,
+    this.payerAmountFiat,
     required this.paymentMethod,
   });
 
   @override
-  int get hashCode => payerAmountSat.hashCode ^ paymentMethod.hashCode;
+  int get hashCode => payerAmountSat.hashCode ^ payerAmountFiat.hashCode ^ paymentMethod.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -922,6 +1227,7 @@ class PrepareReceiveRequest {
       other is PrepareReceiveRequest &&
           runtimeType == other.runtimeType &&
           payerAmountSat == other.payerAmountSat &&
+          payerAmountFiat == other.payerAmountFiat &&
           paymentMethod == other.paymentMethod;
 }
 
@@ -931,14 +1237,18 @@ class PrepareReceiveResponse {
   final BigInt? payerAmountSat;
   final BigInt feesSat;
 
+  /// The rate used to convert the fiat amount, if one was requested
+  final PaymentFiatRate? fiatRate;
+
   const PrepareReceiveResponse({
     required this.paymentMethod,
     this.payerAmountSat,
     required this.feesSat,
+    this.fiatRate,
   });
 
   @override
-  int get hashCode => paymentMethod.hashCode ^ payerAmountSat.hashCode ^ feesSat.hashCode;
+  int get hashCode => paymentMethod.hashCode ^ payerAmountSat.hashCode ^ feesSat.hashCode ^ fiatRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -947,7 +1257,8 @@ class PrepareReceiveResponse {
           runtimeType == other.runtimeType &&
           paymentMethod == other.paymentMethod &&
           payerAmountSat == other.payerAmountSat &&
-          feesSat == other.feesSat;
+          feesSat == other.feesSat &&
+          fiatRate == other.fiatRate;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_refund].
@@ -986,14 +1297,29 @@ class PrepareRefundResponse {
   final BigInt txFeeSat;
   final String? refundTxId;
 
+  /// Whether the refund will be co-signed by the swapper (key path) instead of using the
+  /// refund script path. The cooperative path is used until the swap timeout, after which
+  /// the refund falls back to the script path.
+  final bool isCooperative;
+
+  /// The fee saved in sats by refunding cooperatively, compared to the script path
+  final BigInt feeSavedSat;
+
   const PrepareRefundResponse({
     required this.txVsize,
     required this.txFeeSat,
     this.refundTxId,
+    required this.isCooperative,
+    required this.feeSavedSat,
   });
 
   @override
-  int get hashCode => txVsize.hashCode ^ txFeeSat.hashCode ^ refundTxId.hashCode;
+  int get hashCode =>
+      txVsize.hashCode ^
+      txFeeSat.hashCode ^
+      refundTxId.hashCode ^
+      isCooperative.hashCode ^
+      feeSavedSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -1002,7 +1328,9 @@ class PrepareRefundResponse {
           runtimeType == other.runtimeType &&
           txVsize == other.txVsize &&
           txFeeSat == other.txFeeSat &&
-          refundTxId == other.refundTxId;
+          refundTxId == other.refundTxId &&
+          isCooperative == other.isCooperative &&
+          feeSavedSat == other.feeSavedSat;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
@@ -1037,13 +1365,17 @@ class PrepareSendResponse {
   final SendDestination destination;
   final BigInt feesSat;
 
+  /// The rate used to convert the fiat amount, if one was requested
+  final PaymentFiatRate? fiatRate;
+
   const PrepareSendResponse({
     required this.destination,
     required this.feesSat,
+    this.fiatRate,
   });
 
   @override
-  int get hashCode => destination.hashCode ^ feesSat.hashCode;
+  int get hashCode => destination.hashCode ^ feesSat.hashCode ^ fiatRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -1051,7 +1383,8 @@ class PrepareSendResponse {
       other is PrepareSendResponse &&
           runtimeType == other.runtimeType &&
           destination == other.destination &&
-          feesSat == other.feesSat;
+          feesSat == other.feesSat &&
+          fiatRate == other.fiatRate;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::receive_payment].
@@ -1193,14 +1526,21 @@ class RefundableSwap {
   /// Amount that is refundable, from all UTXOs
   final BigInt amountSat;
 
+  /// Estimated time in seconds until the swap timeout, after which the funds can be refunded
+  /// without the swapper's cooperation. `None` if the timeout has already elapsed, or if the
+  /// Bitcoin tip could not be fetched.
+  final BigInt? timeToRefundable;
+
   const RefundableSwap({
     required this.swapAddress,
     required this.timestamp,
     required this.amountSat,
+    this.timeToRefundable,
   });
 
   @override
-  int get hashCode => swapAddress.hashCode ^ timestamp.hashCode ^ amountSat.hashCode;
+  int get hashCode =>
+      swapAddress.hashCode ^ timestamp.hashCode ^ amountSat.hashCode ^ timeToRefundable.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -1209,7 +1549,8 @@ class RefundableSwap {
           runtimeType == other.runtimeType &&
           swapAddress == other.swapAddress &&
           timestamp == other.timestamp &&
-          amountSat == other.amountSat;
+          amountSat == other.amountSat &&
+          timeToRefundable == other.timeToRefundable;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::restore].
@@ -1229,6 +1570,52 @@ class RestoreRequest {
       other is RestoreRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
 }
 
+/// The intervals, in seconds, at which the SDK background tasks run while it's connected.
+/// See [Config::scheduler_intervals]
+class SchedulerIntervals {
+  /// How often the wallet and payments are synced, see [crate::sdk::LiquidSdk::sync]
+  final BigInt syncSecs;
+
+  /// How often expired swaps are checked and refunded
+  final BigInt refundsSecs;
+
+  /// How often the Liquid lockups of incoming chain swaps are rescanned
+  final BigInt liquidRescanSecs;
+
+  /// How often the Bitcoin lockups and claims of chain swaps are rescanned
+  final BigInt bitcoinRescanSecs;
+
+  /// How often the chain tips are polled for new blocks, see [SdkEvent::NewBlock]
+  final BigInt newBlocksSecs;
+
+  const SchedulerIntervals({
+    required this.syncSecs,
+    required this.refundsSecs,
+    required this.liquidRescanSecs,
+    required this.bitcoinRescanSecs,
+    required this.newBlocksSecs,
+  });
+
+  @override
+  int get hashCode =>
+      syncSecs.hashCode ^
+      refundsSecs.hashCode ^
+      liquidRescanSecs.hashCode ^
+      bitcoinRescanSecs.hashCode ^
+      newBlocksSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchedulerIntervals &&
+          runtimeType == other.runtimeType &&
+          syncSecs == other.syncSecs &&
+          refundsSecs == other.refundsSecs &&
+          liquidRescanSecs == other.liquidRescanSecs &&
+          bitcoinRescanSecs == other.bitcoinRescanSecs &&
+          newBlocksSecs == other.newBlocksSecs;
+}
+
 @freezed
 sealed class SdkEvent with _$SdkEvent {
   const SdkEvent._();
@@ -1239,6 +1626,13 @@ sealed class SdkEvent with _$SdkEvent {
   const factory SdkEvent.paymentPending({
     required Payment details,
   }) = SdkEvent_PaymentPending;
+
+  /// A direct Liquid payment was signed while offline and is queued until it can be broadcast.
+  /// A [SdkEvent::PaymentWaitingConfirmation] follows once it's broadcast, or a
+  /// [SdkEvent::PaymentFailed] if it's rejected.
+  const factory SdkEvent.paymentQueued({
+    required Payment details,
+  }) = SdkEvent_PaymentQueued;
   const factory SdkEvent.paymentRefunded({
     required Payment details,
   }) = SdkEvent_PaymentRefunded;
@@ -1251,6 +1645,47 @@ sealed class SdkEvent with _$SdkEvent {
   const factory SdkEvent.paymentWaitingConfirmation({
     required Payment details,
   }) = SdkEvent_PaymentWaitingConfirmation;
+
+  /// An ongoing swap is getting close to its timeout block height
+  const factory SdkEvent.swapNearingTimeout({
+    required String swapId,
+    required int blocksLeft,
+  }) = SdkEvent_SwapNearingTimeout;
+
+  /// An ongoing swap has reached its timeout block height and its funds can be refunded
+  const factory SdkEvent.swapRefundable({
+    required String swapId,
+  }) = SdkEvent_SwapRefundable;
+
+  /// A swap transitioned to a new [PaymentState]
+  const factory SdkEvent.swapStateChanged({
+    required String swapId,
+    required PaymentState fromState,
+    required PaymentState toState,
+  }) = SdkEvent_SwapStateChanged;
+
+  /// The usable balance changed. See [GetInfoResponse::balance_sat].
+  const factory SdkEvent.balanceChanged({
+    required BigInt oldBalanceSat,
+    required BigInt newBalanceSat,
+  }) = SdkEvent_BalanceChanged;
+
+  /// Emitted while synchronizing the wallet transactions with the local state
+  const factory SdkEvent.syncProgress({
+    required int step,
+    required int total,
+  }) = SdkEvent_SyncProgress;
+
+  /// The connection to the swapper status stream was lost or (re)established
+  const factory SdkEvent.connectivityChanged({
+    required bool isConnected,
+  }) = SdkEvent_ConnectivityChanged;
+
+  /// A new block was found on the given chain
+  const factory SdkEvent.newBlock({
+    required Blockchain chain,
+    required int height,
+  }) = SdkEvent_NewBlock;
   const factory SdkEvent.synced() = SdkEvent_Synced;
 }
 
@@ -1339,3 +1774,226 @@ class SignMessageResponse {
       identical(this, other) ||
       other is SignMessageResponse && runtimeType == other.runtimeType && signature == other.signature;
 }
+
+/// A recorded transition of a swap, see [crate::sdk::LiquidSdk::get_swap_history]
+class SwapStateTransition {
+  final String swapId;
+  final PaymentState fromState;
+  final PaymentState toState;
+
+  /// The swapper status update which triggered the transition, if any
+  final String? swapperStatus;
+
+  /// The ids of the txs involved in the transition
+  final List<String> txIds;
+  final int createdAt;
+
+  const SwapStateTransition({
+    required this.swapId,
+    required this.fromState,
+    required this.toState,
+    this.swapperStatus,
+    required this.txIds,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      swapId.hashCode ^
+      fromState.hashCode ^
+      toState.hashCode ^
+      swapperStatus.hashCode ^
+      txIds.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SwapStateTransition &&
+          runtimeType == other.runtimeType &&
+          swapId == other.swapId &&
+          fromState == other.fromState &&
+          toState == other.toState &&
+          swapperStatus == other.swapperStatus &&
+          txIds == other.txIds &&
+          createdAt == other.createdAt;
+}
+
+/// Metrics of the last [crate::sdk::LiquidSdk::sync]
+class SyncMetrics {
+  final int startedAt;
+
+  /// The total duration of the sync
+  final BigInt durationMs;
+
+  /// The time spent scanning the wallet with the Liquid chain backend
+  final BigInt scanDurationMs;
+
+  /// The Liquid tip height when the sync completed, if it could be fetched
+  final int? liquidTip;
+
+  /// The number of wallet txs
+  final int totalTxCount;
+
+  /// The number of new or changed wallet txs which were processed
+  final int processedTxCount;
+
+  const SyncMetrics({
+    required this.startedAt,
+    required this.durationMs,
+    required this.scanDurationMs,
+    this.liquidTip,
+    required this.totalTxCount,
+    required this.processedTxCount,
+  });
+
+  @override
+  int get hashCode =>
+      startedAt.hashCode ^
+      durationMs.hashCode ^
+      scanDurationMs.hashCode ^
+      liquidTip.hashCode ^
+      totalTxCount.hashCode ^
+      processedTxCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncMetrics &&
+          runtimeType == other.runtimeType &&
+          startedAt == other.startedAt &&
+          durationMs == other.durationMs &&
+          scanDurationMs == other.scanDurationMs &&
+          liquidTip == other.liquidTip &&
+          totalTxCount == other.totalTxCount &&
+          processedTxCount == other.processedTxCount;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::sync_once]
+class SyncOnceRequest {
+  /// The time budget. No new work is started once it has elapsed.
+  final BigInt timeoutSecs;
+
+  const SyncOnceRequest({
+    required this.timeoutSecs,
+  });
+
+  @override
+  int get hashCode => timeoutSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncOnceRequest && runtimeType == other.runtimeType && timeoutSecs == other.timeoutSecs;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sync_once]
+class SyncOnceResponse {
+  /// The tasks which didn't complete within the time budget
+  final List<SyncTask> pendingTasks;
+
+  /// The number of ongoing swaps which weren't checked within the time budget
+  final int pendingSwapCount;
+
+  const SyncOnceResponse({
+    required this.pendingTasks,
+    required this.pendingSwapCount,
+  });
+
+  @override
+  int get hashCode => pendingTasks.hashCode ^ pendingSwapCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncOnceResponse &&
+          runtimeType == other.runtimeType &&
+          pendingTasks == other.pendingTasks &&
+          pendingSwapCount == other.pendingSwapCount;
+}
+
+/// The work done by [crate::sdk::LiquidSdk::sync_once], from highest to lowest priority
+enum SyncTask {
+  /// Checking the ongoing swaps with the swapper and claiming their funds
+  claims,
+
+  /// Refunding the expired swaps
+  refunds,
+
+  /// Syncing the wallet txs and payments, see [crate::sdk::LiquidSdk::sync]
+  history,
+  ;
+}
+
+/// An [EventLogEntry] queued for delivery to one of the [Config::event_webhook_urls].
+/// Successfully delivered entries are removed from the outbox.
+class WebhookDelivery {
+  final BigInt id;
+  final String url;
+
+  /// The sequence number of the delivered [EventLogEntry]
+  final BigInt eventSeq;
+
+  /// The JSON body that is POSTed to the url
+  final String payload;
+  final WebhookDeliveryState state;
+
+  /// The number of failed delivery attempts
+  final int attempts;
+
+  /// The time after which the next delivery attempt is made
+  final int nextAttemptAt;
+
+  /// The error of the last failed delivery attempt
+  final String? lastError;
+  final int createdAt;
+
+  const WebhookDelivery({
+    required this.id,
+    required this.url,
+    required this.eventSeq,
+    required this.payload,
+    required this.state,
+    required this.attempts,
+    required this.nextAttemptAt,
+    this.lastError,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      url.hashCode ^
+      eventSeq.hashCode ^
+      payload.hashCode ^
+      state.hashCode ^
+      attempts.hashCode ^
+      nextAttemptAt.hashCode ^
+      lastError.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebhookDelivery &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          url == other.url &&
+          eventSeq == other.eventSeq &&
+          payload == other.payload &&
+          state == other.state &&
+          attempts == other.attempts &&
+          nextAttemptAt == other.nextAttemptAt &&
+          lastError == other.lastError &&
+          createdAt == other.createdAt;
+}
+
+/// The delivery state of a [WebhookDelivery]
+enum WebhookDeliveryState {
+  /// The delivery is waiting for its next attempt
+  pending,
+
+  /// All delivery attempts failed. See [crate::sdk::LiquidSdk::retry_webhook_delivery]
+  failed,
+  ;
+}
@@ -14,6 +14,372 @@ T _$identity<T>(T value) => value;
 final _privateConstructorUsedError = UnsupportedError(
     'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
 
+/// @nodoc
+mixin _$ChainBackend {}
+
+/// @nodoc
+abstract class $ChainBackendCopyWith<$Res> {
+  factory $ChainBackendCopyWith(ChainBackend value, $Res Function(ChainBackend) then) =
+      _$ChainBackendCopyWithImpl<$Res, ChainBackend>;
+}
+
+/// @nodoc
+class _$ChainBackendCopyWithImpl<$Res, $Val extends ChainBackend> implements $ChainBackendCopyWith<$Res> {
+  _$ChainBackendCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$ChainBackend_ElectrumImplCopyWith<$Res> {
+  factory _$$ChainBackend_ElectrumImplCopyWith(
+          _$ChainBackend_ElectrumImpl value, $Res Function(_$ChainBackend_ElectrumImpl) then) =
+      __$$ChainBackend_ElectrumImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$ChainBackend_ElectrumImplCopyWithImpl<$Res>
+    extends _$ChainBackendCopyWithImpl<$Res, _$ChainBackend_ElectrumImpl>
+    implements _$$ChainBackend_ElectrumImplCopyWith<$Res> {
+  __$$ChainBackend_ElectrumImplCopyWithImpl(
+      _$ChainBackend_ElectrumImpl _value, $Res Function(_$ChainBackend_ElectrumImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$ChainBackend_ElectrumImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainBackend_ElectrumImpl extends ChainBackend_Electrum {
+  const _$ChainBackend_ElectrumImpl({required this.url}) : super._();
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'ChainBackend.electrum(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainBackend_ElectrumImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainBackend_ElectrumImplCopyWith<_$ChainBackend_ElectrumImpl> get copyWith =>
+      __$$ChainBackend_ElectrumImplCopyWithImpl<_$ChainBackend_ElectrumImpl>(this, _$identity);
+}
+
+abstract class ChainBackend_Electrum extends ChainBackend {
+  const factory ChainBackend_Electrum({required final String url}) = _$ChainBackend_ElectrumImpl;
+  const ChainBackend_Electrum._() : super._();
+
+  String get url;
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ChainBackend_ElectrumImplCopyWith<_$ChainBackend_ElectrumImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ChainBackend_EsploraImplCopyWith<$Res> {
+  factory _$$ChainBackend_EsploraImplCopyWith(
+          _$ChainBackend_EsploraImpl value, $Res Function(_$ChainBackend_EsploraImpl) then) =
+      __$$ChainBackend_EsploraImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$ChainBackend_EsploraImplCopyWithImpl<$Res>
+    extends _$ChainBackendCopyWithImpl<$Res, _$ChainBackend_EsploraImpl>
+    implements _$$ChainBackend_EsploraImplCopyWith<$Res> {
+  __$$ChainBackend_EsploraImplCopyWithImpl(
+      _$ChainBackend_EsploraImpl _value, $Res Function(_$ChainBackend_EsploraImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$ChainBackend_EsploraImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainBackend_EsploraImpl extends ChainBackend_Esplora {
+  const _$ChainBackend_EsploraImpl({required this.url}) : super._();
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'ChainBackend.esplora(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainBackend_EsploraImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainBackend_EsploraImplCopyWith<_$ChainBackend_EsploraImpl> get copyWith =>
+      __$$ChainBackend_EsploraImplCopyWithImpl<_$ChainBackend_EsploraImpl>(this, _$identity);
+}
+
+abstract class ChainBackend_Esplora extends ChainBackend {
+  const factory ChainBackend_Esplora({required final String url}) = _$ChainBackend_EsploraImpl;
+  const ChainBackend_Esplora._() : super._();
+
+  String get url;
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ChainBackend_EsploraImplCopyWith<_$ChainBackend_EsploraImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ChainBackend_HybridImplCopyWith<$Res> {
+  factory _$$ChainBackend_HybridImplCopyWith(
+          _$ChainBackend_HybridImpl value, $Res Function(_$ChainBackend_HybridImpl) then) =
+      __$$ChainBackend_HybridImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String electrumUrl, String esploraUrl});
+}
+
+/// @nodoc
+class __$$ChainBackend_HybridImplCopyWithImpl<$Res>
+    extends _$ChainBackendCopyWithImpl<$Res, _$ChainBackend_HybridImpl>
+    implements _$$ChainBackend_HybridImplCopyWith<$Res> {
+  __$$ChainBackend_HybridImplCopyWithImpl(
+      _$ChainBackend_HybridImpl _value, $Res Function(_$ChainBackend_HybridImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? electrumUrl = null,
+    Object? esploraUrl = null,
+  }) {
+    return _then(_$ChainBackend_HybridImpl(
+      electrumUrl: null == electrumUrl
+          ? _value.electrumUrl
+          : electrumUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      esploraUrl: null == esploraUrl
+          ? _value.esploraUrl
+          : esploraUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainBackend_HybridImpl extends ChainBackend_Hybrid {
+  const _$ChainBackend_HybridImpl({required this.electrumUrl, required this.esploraUrl}) : super._();
+
+  @override
+  final String electrumUrl;
+  @override
+  final String esploraUrl;
+
+  @override
+  String toString() {
+    return 'ChainBackend.hybrid(electrumUrl: $electrumUrl, esploraUrl: $esploraUrl)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainBackend_HybridImpl &&
+            (identical(other.electrumUrl, electrumUrl) || other.electrumUrl == electrumUrl) &&
+            (identical(other.esploraUrl, esploraUrl) || other.esploraUrl == esploraUrl));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, electrumUrl, esploraUrl);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainBackend_HybridImplCopyWith<_$ChainBackend_HybridImpl> get copyWith =>
+      __$$ChainBackend_HybridImplCopyWithImpl<_$ChainBackend_HybridImpl>(this, _$identity);
+}
+
+abstract class ChainBackend_Hybrid extends ChainBackend {
+  const factory ChainBackend_Hybrid({required final String electrumUrl, required final String esploraUrl}) =
+      _$ChainBackend_HybridImpl;
+  const ChainBackend_Hybrid._() : super._();
+
+  String get electrumUrl;
+  String get esploraUrl;
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ChainBackend_HybridImplCopyWith<_$ChainBackend_HybridImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ChainBackend_RpcImplCopyWith<$Res> {
+  factory _$$ChainBackend_RpcImplCopyWith(
+          _$ChainBackend_RpcImpl value, $Res Function(_$ChainBackend_RpcImpl) then) =
+      __$$ChainBackend_RpcImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url, String? username, String? password});
+}
+
+/// @nodoc
+class __$$ChainBackend_RpcImplCopyWithImpl<$Res>
+    extends _$ChainBackendCopyWithImpl<$Res, _$ChainBackend_RpcImpl>
+    implements _$$ChainBackend_RpcImplCopyWith<$Res> {
+  __$$ChainBackend_RpcImplCopyWithImpl(
+      _$ChainBackend_RpcImpl _value, $Res Function(_$ChainBackend_RpcImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+    Object? username = freezed,
+    Object? password = freezed,
+  }) {
+    return _then(_$ChainBackend_RpcImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+      username: freezed == username
+          ? _value.username
+          : username // ignore: cast_nullable_to_non_nullable
+              as String?,
+      password: freezed == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainBackend_RpcImpl extends ChainBackend_Rpc {
+  const _$ChainBackend_RpcImpl({required this.url, this.username, this.password}) : super._();
+
+  @override
+  final String url;
+  @override
+  final String? username;
+  @override
+  final String? password;
+
+  @override
+  String toString() {
+    return 'ChainBackend.rpc(url: $url, username: $username, password: $password)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainBackend_RpcImpl &&
+            (identical(other.url, url) || other.url == url) &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.password, password) || other.password == password));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url, username, password);
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainBackend_RpcImplCopyWith<_$ChainBackend_RpcImpl> get copyWith =>
+      __$$ChainBackend_RpcImplCopyWithImpl<_$ChainBackend_RpcImpl>(this, _$identity);
+}
+
+abstract class ChainBackend_Rpc extends ChainBackend {
+  const factory ChainBackend_Rpc(
+      {required final String url, final String? username, final String? password}) = _$ChainBackend_RpcImpl;
+  const ChainBackend_Rpc._() : super._();
+
+  String get url;
+  String? get username;
+  String? get password;
+
+  /// Create a copy of ChainBackend
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ChainBackend_RpcImplCopyWith<_$ChainBackend_RpcImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$GetPaymentRequest {
   String get paymentHash => throw _privateConstructorUsedError;
@@ -737,65 +1103,151 @@ abstract class PayAmount_Drain extends PayAmount {
 }
 
 /// @nodoc
-mixin _$PaymentDetails {
-  /// Represents the invoice description
-  String get description => throw _privateConstructorUsedError;
-
-  /// Create a copy of PaymentDetails
-  /// with the given fields replaced by the non-null parameter values.
-  @JsonKey(includeFromJson: false, includeToJson: false)
-  $PaymentDetailsCopyWith<PaymentDetails> get copyWith => throw _privateConstructorUsedError;
-}
-
-/// @nodoc
-abstract class $PaymentDetailsCopyWith<$Res> {
-  factory $PaymentDetailsCopyWith(PaymentDetails value, $Res Function(PaymentDetails) then) =
-      _$PaymentDetailsCopyWithImpl<$Res, PaymentDetails>;
+abstract class _$$PayAmount_FiatImplCopyWith<$Res> {
+  factory _$$PayAmount_FiatImplCopyWith(
+          _$PayAmount_FiatImpl value, $Res Function(_$PayAmount_FiatImpl) then) =
+      __$$PayAmount_FiatImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({String description});
+  $Res call({String currency, double amount});
 }
 
 /// @nodoc
-class _$PaymentDetailsCopyWithImpl<$Res, $Val extends PaymentDetails>
-    implements $PaymentDetailsCopyWith<$Res> {
-  _$PaymentDetailsCopyWithImpl(this._value, this._then);
-
-  // ignore: unused_field
-  final $Val _value;
-  // ignore: unused_field
-  final $Res Function($Val) _then;
+class __$$PayAmount_FiatImplCopyWithImpl<$Res> extends _$PayAmountCopyWithImpl<$Res, _$PayAmount_FiatImpl>
+    implements _$$PayAmount_FiatImplCopyWith<$Res> {
+  __$$PayAmount_FiatImplCopyWithImpl(_$PayAmount_FiatImpl _value, $Res Function(_$PayAmount_FiatImpl) _then)
+      : super(_value, _then);
 
-  /// Create a copy of PaymentDetails
+  /// Create a copy of PayAmount
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? description = null,
+    Object? currency = null,
+    Object? amount = null,
   }) {
-    return _then(_value.copyWith(
-      description: null == description
-          ? _value.description
-          : description // ignore: cast_nullable_to_non_nullable
+    return _then(_$PayAmount_FiatImpl(
+      currency: null == currency
+          ? _value.currency
+          : currency // ignore: cast_nullable_to_non_nullable
               as String,
-    ) as $Val);
+      amount: null == amount
+          ? _value.amount
+          : amount // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
   }
 }
 
 /// @nodoc
-abstract class _$$PaymentDetails_LightningImplCopyWith<$Res> implements $PaymentDetailsCopyWith<$Res> {
-  factory _$$PaymentDetails_LightningImplCopyWith(
-          _$PaymentDetails_LightningImpl value, $Res Function(_$PaymentDetails_LightningImpl) then) =
-      __$$PaymentDetails_LightningImplCopyWithImpl<$Res>;
+
+class _$PayAmount_FiatImpl extends PayAmount_Fiat {
+  const _$PayAmount_FiatImpl({required this.currency, required this.amount}) : super._();
+
   @override
-  @useResult
-  $Res call(
-      {String swapId,
-      String description,
-      String? preimage,
-      String? bolt11,
-      String? bolt12Offer,
-      String? paymentHash,
-      String? refundTxId,
+  final String currency;
+  @override
+  final double amount;
+
+  @override
+  String toString() {
+    return 'PayAmount.fiat(currency: $currency, amount: $amount)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PayAmount_FiatImpl &&
+            (identical(other.currency, currency) || other.currency == currency) &&
+            (identical(other.amount, amount) || other.amount == amount));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, currency, amount);
+
+  /// Create a copy of PayAmount
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PayAmount_FiatImplCopyWith<_$PayAmount_FiatImpl> get copyWith =>
+      __$$PayAmount_FiatImplCopyWithImpl<_$PayAmount_FiatImpl>(this, _$identity);
+}
+
+abstract class PayAmount_Fiat extends PayAmount {
+  const factory PayAmount_Fiat({required final String currency, required final double amount}) =
+      _$PayAmount_FiatImpl;
+  const PayAmount_Fiat._() : super._();
+
+  String get currency;
+  double get amount;
+
+  /// Create a copy of PayAmount
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PayAmount_FiatImplCopyWith<_$PayAmount_FiatImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PaymentDetails {
+  /// Represents the invoice description
+  String get description => throw _privateConstructorUsedError;
+
+  /// Create a copy of PaymentDetails
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PaymentDetailsCopyWith<PaymentDetails> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PaymentDetailsCopyWith<$Res> {
+  factory $PaymentDetailsCopyWith(PaymentDetails value, $Res Function(PaymentDetails) then) =
+      _$PaymentDetailsCopyWithImpl<$Res, PaymentDetails>;
+  @useResult
+  $Res call({String description});
+}
+
+/// @nodoc
+class _$PaymentDetailsCopyWithImpl<$Res, $Val extends PaymentDetails>
+    implements $PaymentDetailsCopyWith<$Res> {
+  _$PaymentDetailsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PaymentDetails
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? description = null,
+  }) {
+    return _then(_value.copyWith(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PaymentDetails_LightningImplCopyWith<$Res> implements $PaymentDetailsCopyWith<$Res> {
+  factory _$$PaymentDetails_LightningImplCopyWith(
+          _$PaymentDetails_LightningImpl value, $Res Function(_$PaymentDetails_LightningImpl) then) =
+      __$$PaymentDetails_LightningImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String swapId,
+      String description,
+      String? preimage,
+      String? bolt11,
+      String? bolt12Offer,
+      String? paymentHash,
+      String? refundTxId,
       BigInt? refundTxAmountSat});
 }
 
@@ -1387,6 +1839,85 @@ abstract class SdkEvent_PaymentPending extends SdkEvent {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$SdkEvent_PaymentQueuedImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentQueuedImplCopyWith(
+          _$SdkEvent_PaymentQueuedImpl value, $Res Function(_$SdkEvent_PaymentQueuedImpl) then) =
+      __$$SdkEvent_PaymentQueuedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentQueuedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentQueuedImpl>
+    implements _$$SdkEvent_PaymentQueuedImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentQueuedImplCopyWithImpl(
+      _$SdkEvent_PaymentQueuedImpl _value, $Res Function(_$SdkEvent_PaymentQueuedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentQueuedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentQueuedImpl extends SdkEvent_PaymentQueued {
+  const _$SdkEvent_PaymentQueuedImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentQueued(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentQueuedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentQueuedImplCopyWith<_$SdkEvent_PaymentQueuedImpl> get copyWith =>
+      __$$SdkEvent_PaymentQueuedImplCopyWithImpl<_$SdkEvent_PaymentQueuedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_PaymentQueued extends SdkEvent {
+  const factory SdkEvent_PaymentQueued({required final Payment details}) = _$SdkEvent_PaymentQueuedImpl;
+  const SdkEvent_PaymentQueued._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentQueuedImplCopyWith<_$SdkEvent_PaymentQueuedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$SdkEvent_PaymentRefundedImplCopyWith<$Res> {
   factory _$$SdkEvent_PaymentRefundedImplCopyWith(
@@ -1708,6 +2239,624 @@ abstract class SdkEvent_PaymentWaitingConfirmation extends SdkEvent {
       get copyWith => throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$SdkEvent_SwapNearingTimeoutImplCopyWith<$Res> {
+  factory _$$SdkEvent_SwapNearingTimeoutImplCopyWith(
+          _$SdkEvent_SwapNearingTimeoutImpl value, $Res Function(_$SdkEvent_SwapNearingTimeoutImpl) then) =
+      __$$SdkEvent_SwapNearingTimeoutImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String swapId, int blocksLeft});
+}
+
+/// @nodoc
+class __$$SdkEvent_SwapNearingTimeoutImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SwapNearingTimeoutImpl>
+    implements _$$SdkEvent_SwapNearingTimeoutImplCopyWith<$Res> {
+  __$$SdkEvent_SwapNearingTimeoutImplCopyWithImpl(
+      _$SdkEvent_SwapNearingTimeoutImpl _value, $Res Function(_$SdkEvent_SwapNearingTimeoutImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? swapId = null,
+    Object? blocksLeft = null,
+  }) {
+    return _then(_$SdkEvent_SwapNearingTimeoutImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
+      blocksLeft: null == blocksLeft
+          ? _value.blocksLeft
+          : blocksLeft // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SwapNearingTimeoutImpl extends SdkEvent_SwapNearingTimeout {
+  const _$SdkEvent_SwapNearingTimeoutImpl({required this.swapId, required this.blocksLeft}) : super._();
+
+  @override
+  final String swapId;
+  @override
+  final int blocksLeft;
+
+  @override
+  String toString() {
+    return 'SdkEvent.swapNearingTimeout(swapId: $swapId, blocksLeft: $blocksLeft)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SwapNearingTimeoutImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId) &&
+            (identical(other.blocksLeft, blocksLeft) || other.blocksLeft == blocksLeft));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, swapId, blocksLeft);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SwapNearingTimeoutImplCopyWith<_$SdkEvent_SwapNearingTimeoutImpl> get copyWith =>
+      __$$SdkEvent_SwapNearingTimeoutImplCopyWithImpl<_$SdkEvent_SwapNearingTimeoutImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_SwapNearingTimeout extends SdkEvent {
+  const factory SdkEvent_SwapNearingTimeout({required final String swapId, required final int blocksLeft}) =
+      _$SdkEvent_SwapNearingTimeoutImpl;
+  const SdkEvent_SwapNearingTimeout._() : super._();
+
+  String get swapId;
+  int get blocksLeft;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SwapNearingTimeoutImplCopyWith<_$SdkEvent_SwapNearingTimeoutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SwapRefundableImplCopyWith<$Res> {
+  factory _$$SdkEvent_SwapRefundableImplCopyWith(
+          _$SdkEvent_SwapRefundableImpl value, $Res Function(_$SdkEvent_SwapRefundableImpl) then) =
+      __$$SdkEvent_SwapRefundableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String swapId});
+}
+
+/// @nodoc
+class __$$SdkEvent_SwapRefundableImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SwapRefundableImpl>
+    implements _$$SdkEvent_SwapRefundableImplCopyWith<$Res> {
+  __$$SdkEvent_SwapRefundableImplCopyWithImpl(
+      _$SdkEvent_SwapRefundableImpl _value, $Res Function(_$SdkEvent_SwapRefundableImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? swapId = null,
+  }) {
+    return _then(_$SdkEvent_SwapRefundableImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SwapRefundableImpl extends SdkEvent_SwapRefundable {
+  const _$SdkEvent_SwapRefundableImpl({required this.swapId}) : super._();
+
+  @override
+  final String swapId;
+
+  @override
+  String toString() {
+    return 'SdkEvent.swapRefundable(swapId: $swapId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SwapRefundableImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, swapId);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SwapRefundableImplCopyWith<_$SdkEvent_SwapRefundableImpl> get copyWith =>
+      __$$SdkEvent_SwapRefundableImplCopyWithImpl<_$SdkEvent_SwapRefundableImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_SwapRefundable extends SdkEvent {
+  const factory SdkEvent_SwapRefundable({required final String swapId}) = _$SdkEvent_SwapRefundableImpl;
+  const SdkEvent_SwapRefundable._() : super._();
+
+  String get swapId;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SwapRefundableImplCopyWith<_$SdkEvent_SwapRefundableImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SwapStateChangedImplCopyWith<$Res> {
+  factory _$$SdkEvent_SwapStateChangedImplCopyWith(
+          _$SdkEvent_SwapStateChangedImpl value, $Res Function(_$SdkEvent_SwapStateChangedImpl) then) =
+      __$$SdkEvent_SwapStateChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String swapId, PaymentState fromState, PaymentState toState});
+}
+
+/// @nodoc
+class __$$SdkEvent_SwapStateChangedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SwapStateChangedImpl>
+    implements _$$SdkEvent_SwapStateChangedImplCopyWith<$Res> {
+  __$$SdkEvent_SwapStateChangedImplCopyWithImpl(
+      _$SdkEvent_SwapStateChangedImpl _value, $Res Function(_$SdkEvent_SwapStateChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? swapId = null,
+    Object? fromState = null,
+    Object? toState = null,
+  }) {
+    return _then(_$SdkEvent_SwapStateChangedImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
+      fromState: null == fromState
+          ? _value.fromState
+          : fromState // ignore: cast_nullable_to_non_nullable
+              as PaymentState,
+      toState: null == toState
+          ? _value.toState
+          : toState // ignore: cast_nullable_to_non_nullable
+              as PaymentState,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SwapStateChangedImpl extends SdkEvent_SwapStateChanged {
+  const _$SdkEvent_SwapStateChangedImpl(
+      {required this.swapId, required this.fromState, required this.toState})
+      : super._();
+
+  @override
+  final String swapId;
+  @override
+  final PaymentState fromState;
+  @override
+  final PaymentState toState;
+
+  @override
+  String toString() {
+    return 'SdkEvent.swapStateChanged(swapId: $swapId, fromState: $fromState, toState: $toState)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SwapStateChangedImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId) &&
+            (identical(other.fromState, fromState) || other.fromState == fromState) &&
+            (identical(other.toState, toState) || other.toState == toState));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, swapId, fromState, toState);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SwapStateChangedImplCopyWith<_$SdkEvent_SwapStateChangedImpl> get copyWith =>
+      __$$SdkEvent_SwapStateChangedImplCopyWithImpl<_$SdkEvent_SwapStateChangedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_SwapStateChanged extends SdkEvent {
+  const factory SdkEvent_SwapStateChanged(
+      {required final String swapId,
+      required final PaymentState fromState,
+      required final PaymentState toState}) = _$SdkEvent_SwapStateChangedImpl;
+  const SdkEvent_SwapStateChanged._() : super._();
+
+  String get swapId;
+  PaymentState get fromState;
+  PaymentState get toState;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SwapStateChangedImplCopyWith<_$SdkEvent_SwapStateChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_BalanceChangedImplCopyWith<$Res> {
+  factory _$$SdkEvent_BalanceChangedImplCopyWith(
+          _$SdkEvent_BalanceChangedImpl value, $Res Function(_$SdkEvent_BalanceChangedImpl) then) =
+      __$$SdkEvent_BalanceChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt oldBalanceSat, BigInt newBalanceSat});
+}
+
+/// @nodoc
+class __$$SdkEvent_BalanceChangedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_BalanceChangedImpl>
+    implements _$$SdkEvent_BalanceChangedImplCopyWith<$Res> {
+  __$$SdkEvent_BalanceChangedImplCopyWithImpl(
+      _$SdkEvent_BalanceChangedImpl _value, $Res Function(_$SdkEvent_BalanceChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? oldBalanceSat = null,
+    Object? newBalanceSat = null,
+  }) {
+    return _then(_$SdkEvent_BalanceChangedImpl(
+      oldBalanceSat: null == oldBalanceSat
+          ? _value.oldBalanceSat
+          : oldBalanceSat // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      newBalanceSat: null == newBalanceSat
+          ? _value.newBalanceSat
+          : newBalanceSat // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_BalanceChangedImpl extends SdkEvent_BalanceChanged {
+  const _$SdkEvent_BalanceChangedImpl({required this.oldBalanceSat, required this.newBalanceSat}) : super._();
+
+  @override
+  final BigInt oldBalanceSat;
+  @override
+  final BigInt newBalanceSat;
+
+  @override
+  String toString() {
+    return 'SdkEvent.balanceChanged(oldBalanceSat: $oldBalanceSat, newBalanceSat: $newBalanceSat)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_BalanceChangedImpl &&
+            (identical(other.oldBalanceSat, oldBalanceSat) || other.oldBalanceSat == oldBalanceSat) &&
+            (identical(other.newBalanceSat, newBalanceSat) || other.newBalanceSat == newBalanceSat));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, oldBalanceSat, newBalanceSat);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_BalanceChangedImplCopyWith<_$SdkEvent_BalanceChangedImpl> get copyWith =>
+      __$$SdkEvent_BalanceChangedImplCopyWithImpl<_$SdkEvent_BalanceChangedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_BalanceChanged extends SdkEvent {
+  const factory SdkEvent_BalanceChanged(
+      {required final BigInt oldBalanceSat, required final BigInt newBalanceSat}) =
+      _$SdkEvent_BalanceChangedImpl;
+  const SdkEvent_BalanceChanged._() : super._();
+
+  BigInt get oldBalanceSat;
+  BigInt get newBalanceSat;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_BalanceChangedImplCopyWith<_$SdkEvent_BalanceChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SyncProgressImplCopyWith<$Res> {
+  factory _$$SdkEvent_SyncProgressImplCopyWith(
+          _$SdkEvent_SyncProgressImpl value, $Res Function(_$SdkEvent_SyncProgressImpl) then) =
+      __$$SdkEvent_SyncProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int step, int total});
+}
+
+/// @nodoc
+class __$$SdkEvent_SyncProgressImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SyncProgressImpl>
+    implements _$$SdkEvent_SyncProgressImplCopyWith<$Res> {
+  __$$SdkEvent_SyncProgressImplCopyWithImpl(
+      _$SdkEvent_SyncProgressImpl _value, $Res Function(_$SdkEvent_SyncProgressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? step = null,
+    Object? total = null,
+  }) {
+    return _then(_$SdkEvent_SyncProgressImpl(
+      step: null == step
+          ? _value.step
+          : step // ignore: cast_nullable_to_non_nullable
+              as int,
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SyncProgressImpl extends SdkEvent_SyncProgress {
+  const _$SdkEvent_SyncProgressImpl({required this.step, required this.total}) : super._();
+
+  @override
+  final int step;
+  @override
+  final int total;
+
+  @override
+  String toString() {
+    return 'SdkEvent.syncProgress(step: $step, total: $total)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SyncProgressImpl &&
+            (identical(other.step, step) || other.step == step) &&
+            (identical(other.total, total) || other.total == total));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, step, total);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SyncProgressImplCopyWith<_$SdkEvent_SyncProgressImpl> get copyWith =>
+      __$$SdkEvent_SyncProgressImplCopyWithImpl<_$SdkEvent_SyncProgressImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_SyncProgress extends SdkEvent {
+  const factory SdkEvent_SyncProgress({required final int step, required final int total}) =
+      _$SdkEvent_SyncProgressImpl;
+  const SdkEvent_SyncProgress._() : super._();
+
+  int get step;
+  int get total;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SyncProgressImplCopyWith<_$SdkEvent_SyncProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_ConnectivityChangedImplCopyWith<$Res> {
+  factory _$$SdkEvent_ConnectivityChangedImplCopyWith(
+          _$SdkEvent_ConnectivityChangedImpl value, $Res Function(_$SdkEvent_ConnectivityChangedImpl) then) =
+      __$$SdkEvent_ConnectivityChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool isConnected});
+}
+
+/// @nodoc
+class __$$SdkEvent_ConnectivityChangedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_ConnectivityChangedImpl>
+    implements _$$SdkEvent_ConnectivityChangedImplCopyWith<$Res> {
+  __$$SdkEvent_ConnectivityChangedImplCopyWithImpl(
+      _$SdkEvent_ConnectivityChangedImpl _value, $Res Function(_$SdkEvent_ConnectivityChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? isConnected = null,
+  }) {
+    return _then(_$SdkEvent_ConnectivityChangedImpl(
+      isConnected: null == isConnected
+          ? _value.isConnected
+          : isConnected // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_ConnectivityChangedImpl extends SdkEvent_ConnectivityChanged {
+  const _$SdkEvent_ConnectivityChangedImpl({required this.isConnected}) : super._();
+
+  @override
+  final bool isConnected;
+
+  @override
+  String toString() {
+    return 'SdkEvent.connectivityChanged(isConnected: $isConnected)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_ConnectivityChangedImpl &&
+            (identical(other.isConnected, isConnected) || other.isConnected == isConnected));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, isConnected);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_ConnectivityChangedImplCopyWith<_$SdkEvent_ConnectivityChangedImpl> get copyWith =>
+      __$$SdkEvent_ConnectivityChangedImplCopyWithImpl<_$SdkEvent_ConnectivityChangedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_ConnectivityChanged extends SdkEvent {
+  const factory SdkEvent_ConnectivityChanged({required final bool isConnected}) =
+      _$SdkEvent_ConnectivityChangedImpl;
+  const SdkEvent_ConnectivityChanged._() : super._();
+
+  bool get isConnected;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_ConnectivityChangedImplCopyWith<_$SdkEvent_ConnectivityChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_NewBlockImplCopyWith<$Res> {
+  factory _$$SdkEvent_NewBlockImplCopyWith(
+          _$SdkEvent_NewBlockImpl value, $Res Function(_$SdkEvent_NewBlockImpl) then) =
+      __$$SdkEvent_NewBlockImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Blockchain chain, int height});
+}
+
+/// @nodoc
+class __$$SdkEvent_NewBlockImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_NewBlockImpl>
+    implements _$$SdkEvent_NewBlockImplCopyWith<$Res> {
+  __$$SdkEvent_NewBlockImplCopyWithImpl(
+      _$SdkEvent_NewBlockImpl _value, $Res Function(_$SdkEvent_NewBlockImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? chain = null,
+    Object? height = null,
+  }) {
+    return _then(_$SdkEvent_NewBlockImpl(
+      chain: null == chain
+          ? _value.chain
+          : chain // ignore: cast_nullable_to_non_nullable
+              as Blockchain,
+      height: null == height
+          ? _value.height
+          : height // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_NewBlockImpl extends SdkEvent_NewBlock {
+  const _$SdkEvent_NewBlockImpl({required this.chain, required this.height}) : super._();
+
+  @override
+  final Blockchain chain;
+  @override
+  final int height;
+
+  @override
+  String toString() {
+    return 'SdkEvent.newBlock(chain: $chain, height: $height)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_NewBlockImpl &&
+            (identical(other.chain, chain) || other.chain == chain) &&
+            (identical(other.height, height) || other.height == height));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, chain, height);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_NewBlockImplCopyWith<_$SdkEvent_NewBlockImpl> get copyWith =>
+      __$$SdkEvent_NewBlockImplCopyWithImpl<_$SdkEvent_NewBlockImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_NewBlock extends SdkEvent {
+  const factory SdkEvent_NewBlock({required final Blockchain chain, required final int height}) =
+      _$SdkEvent_NewBlockImpl;
+  const SdkEvent_NewBlock._() : super._();
+
+  Blockchain get chain;
+  int get height;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_NewBlockImplCopyWith<_$SdkEvent_NewBlockImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$SdkEvent_SyncedImplCopyWith<$Res> {
   factory _$$SdkEvent_SyncedImplCopyWith(
@@ -37,6 +37,27 @@ class FlutterBreezLiquidBindings {
   late final _store_dart_post_cobject =
       _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> listener_id,
+    int seq,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events(
+      that,
+      listener_id,
+      seq,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_eventsPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_events =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ack_eventsPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(
     int port_,
     int that,
@@ -145,6 +166,41 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since(
+    int that,
+    int seq,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since(
+      that,
+      seq,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_sincePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Uint64)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_since =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_events_sincePtr
+          .asFunction<WireSyncRust2DartDco Function(int, int)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file(
+      that,
+      swap_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_filePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_file =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_rescue_filePtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
     int port_,
     int that,
@@ -196,6 +252,107 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limitsPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap(
+      port_,
+      that,
+      swap_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swap =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_claim_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+    bool is_cooperative,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap(
+      port_,
+      that,
+      swap_id,
+      is_cooperative,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swap =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_force_refund_send_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(
+    int port_,
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics(
+      port_,
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnosticsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnostics =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_generate_diagnosticsPtr
+          .asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> listener_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor(
+      that,
+      listener_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursorPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursor =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_event_cursorPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> currency,
+    int timestamp,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate(
+      port_,
+      that,
+      currency,
+      timestamp,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_ratePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_rate =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_historical_fiat_ratePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
     int port_,
     int that,
@@ -213,6 +370,21 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_infoPtr
           .asFunction<void Function(int, int)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics(
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metrics =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_last_sync_metricsPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
     int port_,
     int that,
@@ -233,6 +405,75 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_payment_request>)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history(
+      that,
+      swap_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_historyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_history =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_swap_historyPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret(
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secretPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secret =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_webhook_signing_secretPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> rescue_json,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap(
+      port_,
+      that,
+      rescue_json,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swapPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swap =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco
+      frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries(
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveriesPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveries =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_failed_webhook_deliveriesPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
     int port_,
     int that,
@@ -347,6 +588,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed(
+      port_,
+      that,
+      swap_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failedPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failed =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_mark_swap_failedPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(
     int port_,
     int that,
@@ -525,6 +786,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_feesPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status(
+      port_,
+      that,
+      swap_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_statusPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_status =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refetch_swap_statusPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(
     int port_,
     int that,
@@ -600,6 +881,23 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(
+    int that,
+    int id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery(
+      that,
+      id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_deliveryPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Uint64)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_delivery =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_retry_webhook_deliveryPtr
+          .asFunction<WireSyncRust2DartDco Function(int, int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -655,6 +953,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_syncPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_sync_once_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_oncePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_sync_once_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_once =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync_oncePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_sync_once_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook(
     int port_,
     int that,
@@ -778,6 +1096,36 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__parse_invoicePtr
           .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__payment_error_info(
+    ffi.Pointer<wire_cst_payment_error> err,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__payment_error_info(
+      err,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__payment_error_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_payment_error>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__payment_error_info');
+  late final _frbgen_breez_liquid_wire__crate__bindings__payment_error_info =
+      _frbgen_breez_liquid_wire__crate__bindings__payment_error_infoPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_payment_error>)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__sdk_error_info(
+    ffi.Pointer<wire_cst_sdk_error> err,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__sdk_error_info(
+      err,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__sdk_error_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_sdk_error>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__sdk_error_info');
+  late final _frbgen_breez_liquid_wire__crate__bindings__sdk_error_info =
+      _frbgen_breez_liquid_wire__crate__bindings__sdk_error_infoPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_sdk_error>)>();
+
   void
       frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ffi.Pointer<ffi.Void> ptr,
@@ -939,6 +1287,17 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_connect_requestPtr
           .asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_fiat_amount> frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fiat_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount =
+      _frbgen_breez_liquid_cst_new_box_autoadd_fiat_amountPtr
+          .asFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>();
+
   ffi.Pointer<wire_cst_get_payment_request> frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request();
   }
@@ -1171,6 +1530,28 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_paymentPtr
           .asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_error> frbgen_breez_liquid_cst_new_box_autoadd_payment_error() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_payment_error();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_error> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_error');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_error =
+      _frbgen_breez_liquid_cst_new_box_autoadd_payment_errorPtr
+          .asFunction<ffi.Pointer<wire_cst_payment_error> Function()>();
+
+  ffi.Pointer<wire_cst_payment_fiat_rate> frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_fiat_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_rate =
+      _frbgen_breez_liquid_cst_new_box_autoadd_payment_fiat_ratePtr
+          .asFunction<ffi.Pointer<wire_cst_payment_fiat_rate> Function()>();
+
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request>
       frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request();
@@ -1242,6 +1623,16 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_requestPtr
           .asFunction<ffi.Pointer<wire_cst_prepare_send_request> Function()>();
 
+  ffi.Pointer<wire_cst_rate> frbgen_breez_liquid_cst_new_box_autoadd_rate() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_rate();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_rate');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_rate =
+      _frbgen_breez_liquid_cst_new_box_autoadd_ratePtr.asFunction<ffi.Pointer<wire_cst_rate> Function()>();
+
   ffi.Pointer<wire_cst_receive_payment_request>
       frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request();
@@ -1276,6 +1667,28 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_restore_requestPtr
           .asFunction<ffi.Pointer<wire_cst_restore_request> Function()>();
 
+  ffi.Pointer<wire_cst_scheduler_intervals> frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervalsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_scheduler_intervals> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervals =
+      _frbgen_breez_liquid_cst_new_box_autoadd_scheduler_intervalsPtr
+          .asFunction<ffi.Pointer<wire_cst_scheduler_intervals> Function()>();
+
+  ffi.Pointer<wire_cst_sdk_error> frbgen_breez_liquid_cst_new_box_autoadd_sdk_error() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_sdk_error();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sdk_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sdk_error> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sdk_error');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sdk_error =
+      _frbgen_breez_liquid_cst_new_box_autoadd_sdk_errorPtr
+          .asFunction<ffi.Pointer<wire_cst_sdk_error> Function()>();
+
   ffi.Pointer<wire_cst_sdk_event> frbgen_breez_liquid_cst_new_box_autoadd_sdk_event() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_sdk_event();
   }
@@ -1343,6 +1756,28 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_symbolPtr
           .asFunction<ffi.Pointer<wire_cst_symbol> Function()>();
 
+  ffi.Pointer<wire_cst_sync_metrics> frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sync_metrics> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sync_metrics =
+      _frbgen_breez_liquid_cst_new_box_autoadd_sync_metricsPtr
+          .asFunction<ffi.Pointer<wire_cst_sync_metrics> Function()>();
+
+  ffi.Pointer<wire_cst_sync_once_request> frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sync_once_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sync_once_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_sync_once_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_sync_once_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_sync_once_request> Function()>();
+
   ffi.Pointer<ffi.Uint32> frbgen_breez_liquid_cst_new_box_autoadd_u_32(
     int value,
   ) {
@@ -1397,6 +1832,36 @@ class FlutterBreezLiquidBindings {
   late final _frbgen_breez_liquid_cst_new_list_String = _frbgen_breez_liquid_cst_new_list_StringPtr
       .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_chain_backend> frbgen_breez_liquid_cst_new_list_chain_backend(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_chain_backend(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_chain_backendPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_chain_backend> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_chain_backend');
+  late final _frbgen_breez_liquid_cst_new_list_chain_backend =
+      _frbgen_breez_liquid_cst_new_list_chain_backendPtr
+          .asFunction<ffi.Pointer<wire_cst_list_chain_backend> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_event_log_entry> frbgen_breez_liquid_cst_new_list_event_log_entry(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_event_log_entry(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_event_log_entryPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_event_log_entry> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_event_log_entry');
+  late final _frbgen_breez_liquid_cst_new_list_event_log_entry =
+      _frbgen_breez_liquid_cst_new_list_event_log_entryPtr
+          .asFunction<ffi.Pointer<wire_cst_list_event_log_entry> Function(int)>();
+
   ffi.Pointer<wire_cst_list_fiat_currency> frbgen_breez_liquid_cst_new_list_fiat_currency(
     int len,
   ) {
@@ -1457,6 +1922,20 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_list_localized_namePtr
           .asFunction<ffi.Pointer<wire_cst_list_localized_name> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_log_field> frbgen_breez_liquid_cst_new_list_log_field(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_log_field(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_log_fieldPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_log_field> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_log_field');
+  late final _frbgen_breez_liquid_cst_new_list_log_field = _frbgen_breez_liquid_cst_new_list_log_fieldPtr
+      .asFunction<ffi.Pointer<wire_cst_list_log_field> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment> frbgen_breez_liquid_cst_new_list_payment(
     int len,
   ) {
@@ -1559,6 +2038,50 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_list_route_hint_hopPtr
           .asFunction<ffi.Pointer<wire_cst_list_route_hint_hop> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_swap_state_transition> frbgen_breez_liquid_cst_new_list_swap_state_transition(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_swap_state_transition(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_swap_state_transitionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_swap_state_transition> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_swap_state_transition');
+  late final _frbgen_breez_liquid_cst_new_list_swap_state_transition =
+      _frbgen_breez_liquid_cst_new_list_swap_state_transitionPtr
+          .asFunction<ffi.Pointer<wire_cst_list_swap_state_transition> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_sync_task> frbgen_breez_liquid_cst_new_list_sync_task(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_sync_task(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_sync_taskPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_sync_task> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_sync_task');
+  late final _frbgen_breez_liquid_cst_new_list_sync_task = _frbgen_breez_liquid_cst_new_list_sync_taskPtr
+      .asFunction<ffi.Pointer<wire_cst_list_sync_task> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_webhook_delivery> frbgen_breez_liquid_cst_new_list_webhook_delivery(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_webhook_delivery(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_webhook_deliveryPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_webhook_delivery> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_webhook_delivery');
+  late final _frbgen_breez_liquid_cst_new_list_webhook_delivery =
+      _frbgen_breez_liquid_cst_new_list_webhook_deliveryPtr
+          .asFunction<ffi.Pointer<wire_cst_list_webhook_delivery> Function(int)>();
+
   int dummy_method_to_enforce_bundling() {
     return _dummy_method_to_enforce_bundling();
   }
@@ -1586,6 +2109,28 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_free_bindingliquidsdkPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_events(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer listener_id,
+    int seq,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_events(
+      ptr,
+      listener_id,
+      seq,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_eventsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Uint64, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_events');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_events =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_ack_eventsPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, int, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_add_event_listener(
     ffi.Pointer<ffi.Void> ptr,
     int listener,
@@ -1683,7 +2228,47 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_disconnectPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
-  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_fetch_fiat_rates(
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_since(
+    ffi.Pointer<ffi.Void> ptr,
+    int seq,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_since(
+      ptr,
+      seq,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_sincePtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Uint64, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_since');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_since =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_events_sincePtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, int, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_file(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_file(
+      ptr,
+      swap_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_filePtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_file');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_file =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_export_rescue_filePtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_fetch_fiat_rates(
     ffi.Pointer<ffi.Void> ptr,
     ffi.Pointer<RustCallStatus> out_status,
   ) {
@@ -1734,6 +2319,110 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_fetch_onchain_limitsPtr
           .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swap(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swap(
+      ptr,
+      swap_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swapPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swap =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_claim_swapPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swap(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    int is_cooperative,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swap(
+      ptr,
+      swap_id,
+      is_cooperative,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swapPtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Int8, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swap =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_force_refund_send_swapPtr.asFunction<
+          RustBuffer Function(
+              ffi.Pointer<ffi.Void>, RustBuffer, int, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnostics(
+    ffi.Pointer<ffi.Void> ptr,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnostics(
+      ptr,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnosticsPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnostics');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnostics =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_generate_diagnosticsPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursor(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer listener_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursor(
+      ptr,
+      listener_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursorPtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursor');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursor =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_event_cursorPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_rate(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer currency,
+    int timestamp,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_rate(
+      ptr,
+      currency,
+      timestamp,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_ratePtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(
+                  ffi.Pointer<ffi.Void>, RustBuffer, ffi.Uint32, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_rate');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_rate =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_historical_fiat_ratePtr.asFunction<
+          RustBuffer Function(
+              ffi.Pointer<ffi.Void>, RustBuffer, int, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_info(
     ffi.Pointer<ffi.Void> ptr,
     ffi.Pointer<RustCallStatus> out_status,
@@ -1751,6 +2440,23 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_infoPtr
           .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metrics(
+    ffi.Pointer<ffi.Void> ptr,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metrics(
+      ptr,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metrics');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metrics =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_last_sync_metricsPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_payment(
     ffi.Pointer<ffi.Void> ptr,
     RustBuffer req,
@@ -1771,6 +2477,80 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_paymentPtr
           .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
 
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_history(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_history(
+      ptr,
+      swap_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_historyPtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_history');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_history =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_swap_historyPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secret(
+    ffi.Pointer<ffi.Void> ptr,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secret(
+      ptr,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secretPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secret');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secret =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_get_webhook_signing_secretPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
+
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swap(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer rescue_json,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swap(
+      ptr,
+      rescue_json,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swapPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swap =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_import_swapPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveries(
+    ffi.Pointer<ffi.Void> ptr,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveries(
+      ptr,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveriesPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveries');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveries =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_failed_webhook_deliveriesPtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_list_fiat_currencies(
     ffi.Pointer<ffi.Void> ptr,
     ffi.Pointer<RustCallStatus> out_status,
@@ -1885,6 +2665,26 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_lnurl_withdrawPtr
           .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
 
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failed(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failed(
+      ptr,
+      swap_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failedPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failed');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failed =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_mark_swap_failedPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_pay_onchain(
     ffi.Pointer<ffi.Void> ptr,
     RustBuffer req,
@@ -2063,6 +2863,26 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_recommended_feesPtr
           .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_status(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer swap_id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_status(
+      ptr,
+      swap_id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_statusPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_status');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_status =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refetch_swap_statusPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_refund(
     ffi.Pointer<ffi.Void> ptr,
     RustBuffer req,
@@ -2160,6 +2980,26 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_restorePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
 
+  void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_delivery(
+    ffi.Pointer<ffi.Void> ptr,
+    int id,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_delivery(
+      ptr,
+      id,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_deliveryPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>, ffi.Uint64, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_delivery');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_delivery =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_retry_webhook_deliveryPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>, int, ffi.Pointer<RustCallStatus>)>();
+
   RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_send_payment(
     ffi.Pointer<ffi.Void> ptr,
     RustBuffer req,
@@ -2217,6 +3057,26 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_syncPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>, ffi.Pointer<RustCallStatus>)>();
 
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_once(
+    ffi.Pointer<ffi.Void> ptr,
+    RustBuffer req,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_once(
+      ptr,
+      req,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_oncePtr = _lookup<
+          ffi.NativeFunction<
+              RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+      'uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_once');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_once =
+      _uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_sync_oncePtr
+          .asFunction<RustBuffer Function(ffi.Pointer<ffi.Void>, RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
   void uniffi_breez_sdk_liquid_bindings_fn_method_bindingliquidsdk_unregister_webhook(
     ffi.Pointer<ffi.Void> ptr,
     ffi.Pointer<RustCallStatus> out_status,
@@ -2375,6 +3235,40 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_fn_func_parse_invoicePtr
           .asFunction<RustBuffer Function(RustBuffer, ffi.Pointer<RustCallStatus>)>();
 
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_info(
+    RustBuffer err,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_info(
+      err,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_infoPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_info');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_info =
+      _uniffi_breez_sdk_liquid_bindings_fn_func_payment_error_infoPtr
+          .asFunction<RustBuffer Function(RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
+  RustBuffer uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_info(
+    RustBuffer err,
+    ffi.Pointer<RustCallStatus> out_status,
+  ) {
+    return _uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_info(
+      err,
+      out_status,
+    );
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_infoPtr =
+      _lookup<ffi.NativeFunction<RustBuffer Function(RustBuffer, ffi.Pointer<RustCallStatus>)>>(
+          'uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_info');
+  late final _uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_info =
+      _uniffi_breez_sdk_liquid_bindings_fn_func_sdk_error_infoPtr
+          .asFunction<RustBuffer Function(RustBuffer, ffi.Pointer<RustCallStatus>)>();
+
   void uniffi_breez_sdk_liquid_bindings_fn_func_set_logger(
     int logger,
     ffi.Pointer<RustCallStatus> out_status,
@@ -3360,6 +4254,26 @@ class FlutterBreezLiquidBindings {
   late final _uniffi_breez_sdk_liquid_bindings_checksum_func_parse_invoice =
       _uniffi_breez_sdk_liquid_bindings_checksum_func_parse_invoicePtr.asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_info() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_info();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_info');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_info =
+      _uniffi_breez_sdk_liquid_bindings_checksum_func_payment_error_infoPtr.asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_info() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_info();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_info');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_info =
+      _uniffi_breez_sdk_liquid_bindings_checksum_func_sdk_error_infoPtr.asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_func_set_logger() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_func_set_logger();
   }
@@ -3370,6 +4284,17 @@ class FlutterBreezLiquidBindings {
   late final _uniffi_breez_sdk_liquid_bindings_checksum_func_set_logger =
       _uniffi_breez_sdk_liquid_bindings_checksum_func_set_loggerPtr.asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_events() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_events();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_eventsPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_events');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_events =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_ack_eventsPtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_add_event_listener() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_add_event_listener();
   }
@@ -3425,6 +4350,28 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_disconnectPtr
           .asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_since() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_since();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_sincePtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_since');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_since =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_events_sincePtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_file() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_file();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_filePtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_file');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_file =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_export_rescue_filePtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_fetch_fiat_rates() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_fetch_fiat_rates();
   }
@@ -3458,59 +4405,169 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_fetch_onchain_limitsPtr
           .asFunction<int Function()>();
 
-  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info() {
-    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info();
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swap() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swap();
   }
 
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_infoPtr =
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swapPtr =
       _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
-          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info');
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info =
-      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_infoPtr
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swap =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_claim_swapPtr
           .asFunction<int Function()>();
 
-  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment() {
-    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment();
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swap() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swap();
   }
 
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_paymentPtr =
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swapPtr =
       _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
-          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment');
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment =
-      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_paymentPtr
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swap =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_force_refund_send_swapPtr
           .asFunction<int Function()>();
 
-  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies() {
-    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies();
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnostics() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnostics();
   }
 
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currenciesPtr =
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnosticsPtr =
       _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
-          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies');
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies =
-      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currenciesPtr
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnostics');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnostics =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_generate_diagnosticsPtr
           .asFunction<int Function()>();
 
-  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments() {
-    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments();
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursor() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursor();
   }
 
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_paymentsPtr =
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursorPtr =
       _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
-          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments');
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments =
-      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_paymentsPtr
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursor');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursor =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_event_cursorPtr
           .asFunction<int Function()>();
 
-  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables() {
-    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables();
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_rate() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_rate();
   }
 
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundablesPtr =
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_ratePtr =
       _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
-          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables');
-  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables =
-      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundablesPtr
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_rate');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_rate =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_historical_fiat_ratePtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_info =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_infoPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metrics() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metrics();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metricsPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metrics');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metrics =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_last_sync_metricsPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_payment =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_paymentPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_history() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_history();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_historyPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_history');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_history =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_swap_historyPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secret() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secret();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secretPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secret');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secret =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_get_webhook_signing_secretPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swap() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swap();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swapPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swap');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swap =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_import_swapPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveries() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveries();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveriesPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveries');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveries =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_failed_webhook_deliveriesPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currenciesPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currencies =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_fiat_currenciesPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_payments =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_paymentsPtr
+          .asFunction<int Function()>();
+
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundablesPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundables =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_list_refundablesPtr
           .asFunction<int Function()>();
 
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_lnurl_auth() {
@@ -3546,6 +4603,17 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_lnurl_withdrawPtr
           .asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failed() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failed();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failedPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failed');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failed =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_mark_swap_failedPtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_pay_onchain() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_pay_onchain();
   }
@@ -3645,6 +4713,17 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_recommended_feesPtr
           .asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_status() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_status();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_status');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_status =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refetch_swap_statusPtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refund() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_refund();
   }
@@ -3700,6 +4779,17 @@ class FlutterBreezLiquidBindings {
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_restorePtr
           .asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_delivery() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_delivery();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_deliveryPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_delivery');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_delivery =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_retry_webhook_deliveryPtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_send_payment() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_send_payment();
   }
@@ -3732,6 +4822,17 @@ class FlutterBreezLiquidBindings {
   late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync =
       _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_syncPtr.asFunction<int Function()>();
 
+  int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_once() {
+    return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_once();
+  }
+
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_oncePtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function()>>(
+          'uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_once');
+  late final _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_once =
+      _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_sync_oncePtr
+          .asFunction<int Function()>();
+
   int uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_unregister_webhook() {
     return _uniffi_breez_sdk_liquid_bindings_checksum_method_bindingliquidsdk_unregister_webhook();
   }
@@ -4215,6 +5316,13 @@ final class wire_cst_ln_url_withdraw_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 }
 
+final class wire_cst_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double rate;
+}
+
 final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
   @ffi.Uint64()
   external int receiver_amount_sat;
@@ -4224,6 +5332,8 @@ final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int total_fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_pay_onchain_request extends ffi.Struct {
@@ -4280,8 +5390,17 @@ final class wire_cst_PayAmount_Receiver extends ffi.Struct {
   external int amount_sat;
 }
 
+final class wire_cst_PayAmount_Fiat extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double amount;
+}
+
 final class PayAmountKind extends ffi.Union {
   external wire_cst_PayAmount_Receiver Receiver;
+
+  external wire_cst_PayAmount_Fiat Fiat;
 }
 
 final class wire_cst_pay_amount extends ffi.Struct {
@@ -4297,9 +5416,18 @@ final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> fee_rate_sat_per_vbyte;
 }
 
+final class wire_cst_fiat_amount extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+
+  @ffi.Double()
+  external double amount;
+}
+
 final class wire_cst_prepare_receive_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 
+  external ffi.Pointer<wire_cst_fiat_amount> payer_amount_fiat;
+
   @ffi.Int32()
   external int payment_method;
 }
@@ -4327,6 +5455,8 @@ final class wire_cst_prepare_receive_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_receive_payment_request extends ffi.Struct {
@@ -4355,6 +5485,8 @@ final class wire_cst_prepare_send_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_send_payment_request extends ffi.Struct {
@@ -4365,6 +5497,11 @@ final class wire_cst_sign_message_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
 }
 
+final class wire_cst_sync_once_request extends ffi.Struct {
+  @ffi.Uint64()
+  external int timeout_secs;
+}
+
 final class wire_cst_binding_event_listener extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
 }
@@ -4439,6 +5576,8 @@ final class wire_cst_payment extends ffi.Struct {
   external int status;
 
   external wire_cst_payment_details details;
+
+  external ffi.Pointer<wire_cst_payment_fiat_rate> fiat_rate;
 }
 
 final class wire_cst_SdkEvent_PaymentFailed extends ffi.Struct {
@@ -4449,6 +5588,10 @@ final class wire_cst_SdkEvent_PaymentPending extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_SdkEvent_PaymentQueued extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
 final class wire_cst_SdkEvent_PaymentRefunded extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
@@ -4465,166 +5608,494 @@ final class wire_cst_SdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
-final class SdkEventKind extends ffi.Union {
-  external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
+final class wire_cst_SdkEvent_SwapNearingTimeout extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-  external wire_cst_SdkEvent_PaymentPending PaymentPending;
+  @ffi.Uint32()
+  external int blocks_left;
+}
 
-  external wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
+final class wire_cst_SdkEvent_SwapRefundable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
 
-  external wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
+final class wire_cst_SdkEvent_SwapStateChanged extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-  external wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
+  @ffi.Int32()
+  external int from_state;
 
-  external wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+  @ffi.Int32()
+  external int to_state;
 }
 
-final class wire_cst_sdk_event extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+final class wire_cst_SdkEvent_BalanceChanged extends ffi.Struct {
+  @ffi.Uint64()
+  external int old_balance_sat;
 
-  external SdkEventKind kind;
+  @ffi.Uint64()
+  external int new_balance_sat;
 }
 
-final class wire_cst_config extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_electrum_url;
+final class wire_cst_SdkEvent_SyncProgress extends ffi.Struct {
+  @ffi.Uint32()
+  external int step;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_electrum_url;
+  @ffi.Uint32()
+  external int total;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mempoolspace_url;
+final class wire_cst_SdkEvent_ConnectivityChanged extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_connected;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
+final class wire_cst_SdkEvent_NewBlock extends ffi.Struct {
+  @ffi.Int32()
+  external int chain;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cache_dir;
+  @ffi.Uint32()
+  external int height;
+}
 
-  @ffi.Int32()
-  external int network;
+final class SdkEventKind extends ffi.Union {
+  external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
 
-  @ffi.Uint64()
-  external int payment_timeout_sec;
+  external wire_cst_SdkEvent_PaymentPending PaymentPending;
 
-  @ffi.Uint32()
-  external int zero_conf_min_fee_rate_msat;
+  external wire_cst_SdkEvent_PaymentQueued PaymentQueued;
 
-  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+  external wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key;
-}
+  external wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
 
-final class wire_cst_connect_request extends ffi.Struct {
-  external wire_cst_config config;
+  external wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
-}
+  external wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
 
-final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+  external wire_cst_SdkEvent_SwapNearingTimeout SwapNearingTimeout;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
-}
+  external wire_cst_SdkEvent_SwapRefundable SwapRefundable;
 
-final class wire_cst_AesSuccessActionDataResult_Decrypted extends ffi.Struct {
-  external ffi.Pointer<wire_cst_aes_success_action_data_decrypted> data;
-}
+  external wire_cst_SdkEvent_SwapStateChanged SwapStateChanged;
 
-final class wire_cst_AesSuccessActionDataResult_ErrorStatus extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
-}
+  external wire_cst_SdkEvent_BalanceChanged BalanceChanged;
 
-final class AesSuccessActionDataResultKind extends ffi.Union {
-  external wire_cst_AesSuccessActionDataResult_Decrypted Decrypted;
+  external wire_cst_SdkEvent_SyncProgress SyncProgress;
 
-  external wire_cst_AesSuccessActionDataResult_ErrorStatus ErrorStatus;
+  external wire_cst_SdkEvent_ConnectivityChanged ConnectivityChanged;
+
+  external wire_cst_SdkEvent_NewBlock NewBlock;
 }
 
-final class wire_cst_aes_success_action_data_result extends ffi.Struct {
+final class wire_cst_sdk_event extends ffi.Struct {
   @ffi.Int32()
   external int tag;
 
-  external AesSuccessActionDataResultKind kind;
+  external SdkEventKind kind;
 }
 
-final class wire_cst_bitcoin_address_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
-
-  @ffi.Int32()
-  external int network;
-
-  external ffi.Pointer<ffi.Uint64> amount_sat;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+final class wire_cst_ChainBackend_Electrum extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 }
 
-final class wire_cst_ln_url_error_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+final class wire_cst_ChainBackend_Esplora extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 }
 
-final class wire_cst_ln_url_pay_error_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+final class wire_cst_ChainBackend_Hybrid extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> electrum_url;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> esplora_url;
 }
 
-final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
-  external ffi.Pointer<wire_cst_aes_success_action_data_result> result;
-}
+final class wire_cst_ChainBackend_Rpc extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 
-final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
-  external ffi.Pointer<wire_cst_message_success_action_data> data;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> username;
 
-final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
-  external ffi.Pointer<wire_cst_url_success_action_data> data;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> password;
 }
 
-final class SuccessActionProcessedKind extends ffi.Union {
-  external wire_cst_SuccessActionProcessed_Aes Aes;
+final class ChainBackendKind extends ffi.Union {
+  external wire_cst_ChainBackend_Electrum Electrum;
 
-  external wire_cst_SuccessActionProcessed_Message Message;
+  external wire_cst_ChainBackend_Esplora Esplora;
 
-  external wire_cst_SuccessActionProcessed_Url Url;
+  external wire_cst_ChainBackend_Hybrid Hybrid;
+
+  external wire_cst_ChainBackend_Rpc Rpc;
 }
 
-final class wire_cst_success_action_processed extends ffi.Struct {
+final class wire_cst_chain_backend extends ffi.Struct {
   @ffi.Int32()
   external int tag;
 
-  external SuccessActionProcessedKind kind;
+  external ChainBackendKind kind;
 }
 
-final class wire_cst_ln_url_pay_success_data extends ffi.Struct {
-  external wire_cst_payment payment;
+final class wire_cst_list_chain_backend extends ffi.Struct {
+  external ffi.Pointer<wire_cst_chain_backend> ptr;
 
-  external ffi.Pointer<wire_cst_success_action_processed> success_action;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_ln_url_withdraw_success_data extends ffi.Struct {
-  external wire_cst_ln_invoice invoice;
-}
+final class wire_cst_scheduler_intervals extends ffi.Struct {
+  @ffi.Uint64()
+  external int sync_secs;
 
-final class wire_cst_symbol extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> grapheme;
+  @ffi.Uint64()
+  external int refunds_secs;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> template_;
+  @ffi.Uint64()
+  external int liquid_rescan_secs;
 
-  external ffi.Pointer<ffi.Bool> rtl;
+  @ffi.Uint64()
+  external int bitcoin_rescan_secs;
 
-  external ffi.Pointer<ffi.Uint32> position;
+  @ffi.Uint64()
+  external int new_blocks_secs;
 }
 
-final class wire_cst_localized_name extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+final class wire_cst_config extends ffi.Struct {
+  external wire_cst_chain_backend liquid_chain_backend;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
-}
+  external wire_cst_chain_backend bitcoin_chain_backend;
 
-final class wire_cst_list_localized_name extends ffi.Struct {
-  external ffi.Pointer<wire_cst_localized_name> ptr;
+  external ffi.Pointer<wire_cst_list_chain_backend> liquid_fallback_chain_backends;
 
-  @ffi.Int32()
-  external int len;
+  external ffi.Pointer<wire_cst_list_chain_backend> bitcoin_fallback_chain_backends;
+
+  external ffi.Pointer<ffi.Uint64> chain_quorum_min_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_ws_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_referral_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lbtc_asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_genesis_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mempoolspace_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cache_dir;
+
+  @ffi.Int32()
+  external int network;
+
+  @ffi.Uint64()
+  external int payment_timeout_sec;
+
+  @ffi.Uint32()
+  external int zero_conf_min_fee_rate_msat;
+
+  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key;
+
+  external ffi.Pointer<wire_cst_list_String> fiat_rate_history_currencies;
+
+  external ffi.Pointer<wire_cst_list_String> event_webhook_urls;
+
+  external ffi.Pointer<wire_cst_scheduler_intervals> scheduler_intervals;
+}
+
+final class wire_cst_connect_request extends ffi.Struct {
+  external wire_cst_config config;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
+}
+
+final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidNetwork extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Generic extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidDescription extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_LwkError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_ReceiveError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Refunded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+}
+
+final class wire_cst_PaymentError_SendError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_SignerError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_Swapper extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_ChainService extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_AmountMissing AmountMissing;
+
+  external wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
+
+  external wire_cst_PaymentError_Generic Generic;
+
+  external wire_cst_PaymentError_InvalidDescription InvalidDescription;
+
+  external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
+
+  external wire_cst_PaymentError_LwkError LwkError;
+
+  external wire_cst_PaymentError_ReceiveError ReceiveError;
+
+  external wire_cst_PaymentError_Refunded Refunded;
+
+  external wire_cst_PaymentError_SendError SendError;
+
+  external wire_cst_PaymentError_SignerError SignerError;
+
+  external wire_cst_PaymentError_Swapper Swapper;
+
+  external wire_cst_PaymentError_ChainService ChainService;
+}
+
+final class wire_cst_payment_error extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external PaymentErrorKind kind;
+}
+
+final class wire_cst_SdkError_Generic extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_ServiceConnectivity extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Swapper extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_ChainService extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Persistence extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Signer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkError_Validation extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class SdkErrorKind extends ffi.Union {
+  external wire_cst_SdkError_Generic Generic;
+
+  external wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
+
+  external wire_cst_SdkError_Swapper Swapper;
+
+  external wire_cst_SdkError_ChainService ChainService;
+
+  external wire_cst_SdkError_Persistence Persistence;
+
+  external wire_cst_SdkError_Signer Signer;
+
+  external wire_cst_SdkError_Validation Validation;
+}
+
+final class wire_cst_sdk_error extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SdkErrorKind kind;
+}
+
+final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+}
+
+final class wire_cst_AesSuccessActionDataResult_Decrypted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_aes_success_action_data_decrypted> data;
+}
+
+final class wire_cst_AesSuccessActionDataResult_ErrorStatus extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class AesSuccessActionDataResultKind extends ffi.Union {
+  external wire_cst_AesSuccessActionDataResult_Decrypted Decrypted;
+
+  external wire_cst_AesSuccessActionDataResult_ErrorStatus ErrorStatus;
+}
+
+final class wire_cst_aes_success_action_data_result extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external AesSuccessActionDataResultKind kind;
+}
+
+final class wire_cst_bitcoin_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Int32()
+  external int network;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_ln_url_error_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_ln_url_pay_error_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_aes_success_action_data_result> result;
+}
+
+final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_message_success_action_data> data;
+}
+
+final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_url_success_action_data> data;
+}
+
+final class SuccessActionProcessedKind extends ffi.Union {
+  external wire_cst_SuccessActionProcessed_Aes Aes;
+
+  external wire_cst_SuccessActionProcessed_Message Message;
+
+  external wire_cst_SuccessActionProcessed_Url Url;
+}
+
+final class wire_cst_success_action_processed extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionProcessedKind kind;
+}
+
+final class wire_cst_ln_url_pay_success_data extends ffi.Struct {
+  external wire_cst_payment payment;
+
+  external ffi.Pointer<wire_cst_success_action_processed> success_action;
+}
+
+final class wire_cst_ln_url_withdraw_success_data extends ffi.Struct {
+  external wire_cst_ln_invoice invoice;
+}
+
+final class wire_cst_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+
+  @ffi.Double()
+  external double value;
+}
+
+final class wire_cst_symbol extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> grapheme;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> template_;
+
+  external ffi.Pointer<ffi.Bool> rtl;
+
+  external ffi.Pointer<ffi.Uint32> position;
+}
+
+final class wire_cst_sync_metrics extends ffi.Struct {
+  @ffi.Uint32()
+  external int started_at;
+
+  @ffi.Uint64()
+  external int duration_ms;
+
+  @ffi.Uint64()
+  external int scan_duration_ms;
+
+  external ffi.Pointer<ffi.Uint32> liquid_tip;
+
+  @ffi.Uint32()
+  external int total_tx_count;
+
+  @ffi.Uint32()
+  external int processed_tx_count;
+}
+
+final class wire_cst_event_log_entry extends ffi.Struct {
+  @ffi.Uint64()
+  external int seq;
+
+  @ffi.Uint32()
+  external int created_at;
+
+  external wire_cst_sdk_event event;
+}
+
+final class wire_cst_list_event_log_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_event_log_entry> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_localized_name extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+}
+
+final class wire_cst_list_localized_name extends ffi.Struct {
+  external ffi.Pointer<wire_cst_localized_name> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_locale_overrides extends ffi.Struct {
@@ -4672,18 +6143,24 @@ final class wire_cst_list_fiat_currency extends ffi.Struct {
   external int len;
 }
 
-final class wire_cst_list_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> ptr;
+final class wire_cst_log_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_log_field extends ffi.Struct {
+  external ffi.Pointer<wire_cst_log_field> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_rate extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+final class wire_cst_list_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> ptr;
 
-  @ffi.Double()
-  external double value;
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_list_rate extends ffi.Struct {
@@ -4701,6 +6178,8 @@ final class wire_cst_refundable_swap extends ffi.Struct {
 
   @ffi.Uint64()
   external int amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> time_to_refundable;
 }
 
 final class wire_cst_list_refundable_swap extends ffi.Struct {
@@ -4710,11 +6189,83 @@ final class wire_cst_list_refundable_swap extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_swap_state_transition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Int32()
+  external int from_state;
+
+  @ffi.Int32()
+  external int to_state;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swapper_status;
+
+  external ffi.Pointer<wire_cst_list_String> tx_ids;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_swap_state_transition extends ffi.Struct {
+  external ffi.Pointer<wire_cst_swap_state_transition> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_sync_task extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_webhook_delivery extends ffi.Struct {
+  @ffi.Uint64()
+  external int id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Uint64()
+  external int event_seq;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payload;
+
+  @ffi.Int32()
+  external int state;
+
+  @ffi.Uint32()
+  external int attempts;
+
+  @ffi.Uint32()
+  external int next_attempt_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_webhook_delivery extends ffi.Struct {
+  external ffi.Pointer<wire_cst_webhook_delivery> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_check_message_response extends ffi.Struct {
   @ffi.Bool()
   external bool is_valid;
 }
 
+final class wire_cst_error_info extends ffi.Struct {
+  @ffi.Uint32()
+  external int code;
+
+  @ffi.Bool()
+  external bool retryable;
+}
+
 final class wire_cst_get_info_response extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -4814,6 +6365,8 @@ final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
   external wire_cst_limits send;
 
   external wire_cst_limits receive;
+
+  external ffi.Pointer<ffi.Uint64> cache_age_secs;
 }
 
 final class wire_cst_LnUrlAuthError_Generic extends ffi.Struct {
@@ -5036,83 +6589,27 @@ final class wire_cst_log_entry extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
-}
-
-final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
-  external wire_cst_limits send;
-
-  external wire_cst_limits receive;
-}
 
-final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
 
-final class wire_cst_PaymentError_InvalidNetwork extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> module;
 
-final class wire_cst_PaymentError_Generic extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_InvalidDescription extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_LwkError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_ReceiveError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_PaymentError_Refunded extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+  @ffi.Uint64()
+  external int timestamp;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-final class wire_cst_PaymentError_SendError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
 
-final class wire_cst_PaymentError_SignerError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+  external ffi.Pointer<wire_cst_list_log_field> fields;
 }
 
-final class PaymentErrorKind extends ffi.Union {
-  external wire_cst_PaymentError_AmountMissing AmountMissing;
-
-  external wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
-
-  external wire_cst_PaymentError_Generic Generic;
-
-  external wire_cst_PaymentError_InvalidDescription InvalidDescription;
-
-  external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
-
-  external wire_cst_PaymentError_LwkError LwkError;
-
-  external wire_cst_PaymentError_ReceiveError ReceiveError;
-
-  external wire_cst_PaymentError_Refunded Refunded;
-
-  external wire_cst_PaymentError_SendError SendError;
-
-  external wire_cst_PaymentError_SignerError SignerError;
-}
+final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
+  external wire_cst_limits send;
 
-final class wire_cst_payment_error extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+  external wire_cst_limits receive;
 
-  external PaymentErrorKind kind;
+  external ffi.Pointer<ffi.Uint64> cache_age_secs;
 }
 
 final class wire_cst_prepare_refund_response extends ffi.Struct {
@@ -5123,6 +6620,12 @@ final class wire_cst_prepare_refund_response extends ffi.Struct {
   external int tx_fee_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  @ffi.Bool()
+  external bool is_cooperative;
+
+  @ffi.Uint64()
+  external int fee_saved_sat;
 }
 
 final class wire_cst_receive_payment_response extends ffi.Struct {
@@ -5150,27 +6653,6 @@ final class wire_cst_refund_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
-final class wire_cst_SdkError_Generic extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class wire_cst_SdkError_ServiceConnectivity extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
-}
-
-final class SdkErrorKind extends ffi.Union {
-  external wire_cst_SdkError_Generic Generic;
-
-  external wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
-}
-
-final class wire_cst_sdk_error extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
-
-  external SdkErrorKind kind;
-}
-
 final class wire_cst_send_payment_response extends ffi.Struct {
   external wire_cst_payment payment;
 }
@@ -5179,6 +6661,13 @@ final class wire_cst_sign_message_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature;
 }
 
+final class wire_cst_sync_once_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_sync_task> pending_tasks;
+
+  @ffi.Uint32()
+  external int pending_swap_count;
+}
+
 /// EXTRA BEGIN
 typedef WireSyncRust2DartDco = ffi.Pointer<DartCObject>;
 
@@ -5226,6 +6715,8 @@ const double STANDARD_FEE_RATE_SAT_PER_VBYTE = 0.1;
 
 const double LOWBALL_FEE_RATE_SAT_PER_VBYTE = 0.01;
 
+const int BITCOIN_BLOCK_INTERVAL_SECS = 600;
+
 const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET = 100;
 
 const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET = 10;
@@ -5233,3 +6724,15 @@ const int DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET = 10;
 const int DEFAULT_ZERO_CONF_MAX_SAT = 100000;
 
 const int CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS = 4320;
+
+const int SWAP_NEARING_TIMEOUT_BITCOIN_BLOCKS = 6;
+
+const int SWAP_NEARING_TIMEOUT_LIQUID_BLOCKS = 60;
+
+const int EVENT_LOG_RETENTION_SECS = 2592000;
+
+const int EVENT_LOG_PAGE_SIZE = 500;
+
+const int FIAT_RATE_HISTORY_RETENTION_SECS = 31536000;
+
+const int FIAT_RATE_HISTORY_MAX_DISTANCE_SECS = 86400;
@@ -33,7 +33,7 @@ fun asAesSuccessActionDataList(arr: ReadableArray): List<AesSuccessActionData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asAesSuccessActionData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -66,7 +66,7 @@ fun asAesSuccessActionDataDecryptedList(arr: ReadableArray): List<AesSuccessActi
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asAesSuccessActionDataDecrypted(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -94,7 +94,7 @@ fun asBackupRequestList(arr: ReadableArray): List<BackupRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asBackupRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -133,7 +133,7 @@ fun asBitcoinAddressDataList(arr: ReadableArray): List<BitcoinAddressData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asBitcoinAddressData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -165,7 +165,7 @@ fun asBuyBitcoinRequestList(arr: ReadableArray): List<BuyBitcoinRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asBuyBitcoinRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -201,7 +201,7 @@ fun asCheckMessageRequestList(arr: ReadableArray): List<CheckMessageRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asCheckMessageRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -231,7 +231,7 @@ fun asCheckMessageResponseList(arr: ReadableArray): List<CheckMessageResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asCheckMessageResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -241,8 +241,8 @@ fun asConfig(config: ReadableMap): Config? {
     if (!validateMandatoryFields(
             config,
             arrayOf(
-                "liquidElectrumUrl",
-                "bitcoinElectrumUrl",
+                "liquidChainBackend",
+                "bitcoinChainBackend",
                 "mempoolspaceUrl",
                 "workingDir",
                 "network",
@@ -253,8 +253,8 @@ fun asConfig(config: ReadableMap): Config? {
     ) {
         return null
     }
-    val liquidElectrumUrl = config.getString("liquidElectrumUrl")!!
-    val bitcoinElectrumUrl = config.getString("bitcoinElectrumUrl")!!
+    val liquidChainBackend = config.getMap("liquidChainBackend")?.let { asChainBackend(it) }!!
+    val bitcoinChainBackend = config.getMap("bitcoinChainBackend")?.let { asChainBackend(it) }!!
     val mempoolspaceUrl = config.getString("mempoolspaceUrl")!!
     val workingDir = config.getString("workingDir")!!
     val network = config.getString("network")?.let { asLiquidNetwork(it) }!!
@@ -272,9 +272,64 @@ fun asConfig(config: ReadableMap): Config? {
         } else {
             null
         }
+    val fiatRateHistoryCurrencies =
+        if (hasNonNullKey(config, "fiatRateHistoryCurrencies")) {
+            config.getArray("fiatRateHistoryCurrencies")?.let {
+                asStringList(it)
+            }
+        } else {
+            null
+        }
+    val eventWebhookUrls =
+        if (hasNonNullKey(config, "eventWebhookUrls")) {
+            config.getArray("eventWebhookUrls")?.let {
+                asStringList(it)
+            }
+        } else {
+            null
+        }
+    val liquidFallbackChainBackends =
+        if (hasNonNullKey(config, "liquidFallbackChainBackends")) {
+            config.getArray("liquidFallbackChainBackends")?.let {
+                asChainBackendList(it)
+            }
+        } else {
+            null
+        }
+    val bitcoinFallbackChainBackends =
+        if (hasNonNullKey(config, "bitcoinFallbackChainBackends")) {
+            config.getArray("bitcoinFallbackChainBackends")?.let {
+                asChainBackendList(it)
+            }
+        } else {
+            null
+        }
+    val chainQuorumMinAmountSat =
+        if (hasNonNullKey(
+                config,
+                "chainQuorumMinAmountSat",
+            )
+        ) {
+            config.getDouble("chainQuorumMinAmountSat").toULong()
+        } else {
+            null
+        }
+    val swapperUrl = if (hasNonNullKey(config, "swapperUrl")) config.getString("swapperUrl") else null
+    val swapperWsUrl = if (hasNonNullKey(config, "swapperWsUrl")) config.getString("swapperWsUrl") else null
+    val swapperReferralId = if (hasNonNullKey(config, "swapperReferralId")) config.getString("swapperReferralId") else null
+    val lbtcAssetId = if (hasNonNullKey(config, "lbtcAssetId")) config.getString("lbtcAssetId") else null
+    val liquidGenesisHash = if (hasNonNullKey(config, "liquidGenesisHash")) config.getString("liquidGenesisHash") else null
+    val schedulerIntervals =
+        if (hasNonNullKey(config, "schedulerIntervals")) {
+            config.getMap("schedulerIntervals")?.let {
+                asSchedulerIntervals(it)
+            }
+        } else {
+            null
+        }
     return Config(
-        liquidElectrumUrl,
-        bitcoinElectrumUrl,
+        liquidChainBackend,
+        bitcoinChainBackend,
         mempoolspaceUrl,
         workingDir,
         network,
@@ -283,13 +338,24 @@ fun asConfig(config: ReadableMap): Config? {
         breezApiKey,
         cacheDir,
         zeroConfMaxAmountSat,
+        fiatRateHistoryCurrencies,
+        eventWebhookUrls,
+        liquidFallbackChainBackends,
+        bitcoinFallbackChainBackends,
+        chainQuorumMinAmountSat,
+        swapperUrl,
+        swapperWsUrl,
+        swapperReferralId,
+        lbtcAssetId,
+        liquidGenesisHash,
+        schedulerIntervals,
     )
 }
 
 fun readableMapOf(config: Config): ReadableMap =
     readableMapOf(
-        "liquidElectrumUrl" to config.liquidElectrumUrl,
-        "bitcoinElectrumUrl" to config.bitcoinElectrumUrl,
+        "liquidChainBackend" to readableMapOf(config.liquidChainBackend),
+        "bitcoinChainBackend" to readableMapOf(config.bitcoinChainBackend),
         "mempoolspaceUrl" to config.mempoolspaceUrl,
         "workingDir" to config.workingDir,
         "network" to config.network.name.lowercase(),
@@ -298,6 +364,17 @@ fun readableMapOf(config: Config): ReadableMap =
         "breezApiKey" to config.breezApiKey,
         "cacheDir" to config.cacheDir,
         "zeroConfMaxAmountSat" to config.zeroConfMaxAmountSat,
+        "fiatRateHistoryCurrencies" to config.fiatRateHistoryCurrencies?.let { readableArrayOf(it) },
+        "eventWebhookUrls" to config.eventWebhookUrls?.let { readableArrayOf(it) },
+        "liquidFallbackChainBackends" to config.liquidFallbackChainBackends?.let { readableArrayOf(it) },
+        "bitcoinFallbackChainBackends" to config.bitcoinFallbackChainBackends?.let { readableArrayOf(it) },
+        "chainQuorumMinAmountSat" to config.chainQuorumMinAmountSat,
+        "swapperUrl" to config.swapperUrl,
+        "swapperWsUrl" to config.swapperWsUrl,
+        "swapperReferralId" to config.swapperReferralId,
+        "lbtcAssetId" to config.lbtcAssetId,
+        "liquidGenesisHash" to config.liquidGenesisHash,
+        "schedulerIntervals" to config.schedulerIntervals?.let { readableMapOf(it) },
     )
 
 fun asConfigList(arr: ReadableArray): List<Config> {
@@ -305,7 +382,7 @@ fun asConfigList(arr: ReadableArray): List<Config> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asConfig(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -338,7 +415,7 @@ fun asConnectRequestList(arr: ReadableArray): List<ConnectRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asConnectRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -368,7 +445,7 @@ fun asConnectWithSignerRequestList(arr: ReadableArray): List<ConnectWithSignerRe
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asConnectWithSignerRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -413,7 +490,109 @@ fun asCurrencyInfoList(arr: ReadableArray): List<CurrencyInfo> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asCurrencyInfo(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asErrorInfo(errorInfo: ReadableMap): ErrorInfo? {
+    if (!validateMandatoryFields(
+            errorInfo,
+            arrayOf(
+                "code",
+                "retryable",
+            ),
+        )
+    ) {
+        return null
+    }
+    val code = errorInfo.getInt("code").toUInt()
+    val retryable = errorInfo.getBoolean("retryable")
+    return ErrorInfo(code, retryable)
+}
+
+fun readableMapOf(errorInfo: ErrorInfo): ReadableMap =
+    readableMapOf(
+        "code" to errorInfo.code,
+        "retryable" to errorInfo.retryable,
+    )
+
+fun asErrorInfoList(arr: ReadableArray): List<ErrorInfo> {
+    val list = ArrayList<ErrorInfo>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asErrorInfo(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asEventLogEntry(eventLogEntry: ReadableMap): EventLogEntry? {
+    if (!validateMandatoryFields(
+            eventLogEntry,
+            arrayOf(
+                "seq",
+                "createdAt",
+                "event",
+            ),
+        )
+    ) {
+        return null
+    }
+    val seq = eventLogEntry.getDouble("seq").toULong()
+    val createdAt = eventLogEntry.getInt("createdAt").toUInt()
+    val event = eventLogEntry.getMap("event")?.let { asSdkEvent(it) }!!
+    return EventLogEntry(seq, createdAt, event)
+}
+
+fun readableMapOf(eventLogEntry: EventLogEntry): ReadableMap =
+    readableMapOf(
+        "seq" to eventLogEntry.seq,
+        "createdAt" to eventLogEntry.createdAt,
+        "event" to readableMapOf(eventLogEntry.event),
+    )
+
+fun asEventLogEntryList(arr: ReadableArray): List<EventLogEntry> {
+    val list = ArrayList<EventLogEntry>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asEventLogEntry(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asFiatAmount(fiatAmount: ReadableMap): FiatAmount? {
+    if (!validateMandatoryFields(
+            fiatAmount,
+            arrayOf(
+                "currency",
+                "amount",
+            ),
+        )
+    ) {
+        return null
+    }
+    val currency = fiatAmount.getString("currency")!!
+    val amount = fiatAmount.getDouble("amount")
+    return FiatAmount(currency, amount)
+}
+
+fun readableMapOf(fiatAmount: FiatAmount): ReadableMap =
+    readableMapOf(
+        "currency" to fiatAmount.currency,
+        "amount" to fiatAmount.amount,
+    )
+
+fun asFiatAmountList(arr: ReadableArray): List<FiatAmount> {
+    val list = ArrayList<FiatAmount>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asFiatAmount(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -446,7 +625,7 @@ fun asFiatCurrencyList(arr: ReadableArray): List<FiatCurrency> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asFiatCurrency(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -488,7 +667,7 @@ fun asGetInfoResponseList(arr: ReadableArray): List<GetInfoResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asGetInfoResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -561,7 +740,7 @@ fun asLnInvoiceList(arr: ReadableArray): List<LnInvoice> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnInvoice(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -607,7 +786,7 @@ fun asLnOfferList(arr: ReadableArray): List<LnOffer> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnOffer(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -626,13 +805,24 @@ fun asLightningPaymentLimitsResponse(lightningPaymentLimitsResponse: ReadableMap
     }
     val send = lightningPaymentLimitsResponse.getMap("send")?.let { asLimits(it) }!!
     val receive = lightningPaymentLimitsResponse.getMap("receive")?.let { asLimits(it) }!!
-    return LightningPaymentLimitsResponse(send, receive)
+    val cacheAgeSecs =
+        if (hasNonNullKey(
+                lightningPaymentLimitsResponse,
+                "cacheAgeSecs",
+            )
+        ) {
+            lightningPaymentLimitsResponse.getDouble("cacheAgeSecs").toULong()
+        } else {
+            null
+        }
+    return LightningPaymentLimitsResponse(send, receive, cacheAgeSecs)
 }
 
 fun readableMapOf(lightningPaymentLimitsResponse: LightningPaymentLimitsResponse): ReadableMap =
     readableMapOf(
         "send" to readableMapOf(lightningPaymentLimitsResponse.send),
         "receive" to readableMapOf(lightningPaymentLimitsResponse.receive),
+        "cacheAgeSecs" to lightningPaymentLimitsResponse.cacheAgeSecs,
     )
 
 fun asLightningPaymentLimitsResponseList(arr: ReadableArray): List<LightningPaymentLimitsResponse> {
@@ -640,7 +830,7 @@ fun asLightningPaymentLimitsResponseList(arr: ReadableArray): List<LightningPaym
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLightningPaymentLimitsResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -676,7 +866,7 @@ fun asLimitsList(arr: ReadableArray): List<Limits> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLimits(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -717,7 +907,7 @@ fun asLiquidAddressDataList(arr: ReadableArray): List<LiquidAddressData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLiquidAddressData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -778,7 +968,7 @@ fun asListPaymentsRequestList(arr: ReadableArray): List<ListPaymentsRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asListPaymentsRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -808,7 +998,7 @@ fun asLnOfferBlindedPathList(arr: ReadableArray): List<LnOfferBlindedPath> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnOfferBlindedPath(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -846,7 +1036,7 @@ fun asLnUrlAuthRequestDataList(arr: ReadableArray): List<LnUrlAuthRequestData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlAuthRequestData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -876,7 +1066,7 @@ fun asLnUrlErrorDataList(arr: ReadableArray): List<LnUrlErrorData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlErrorData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -909,7 +1099,7 @@ fun asLnUrlPayErrorDataList(arr: ReadableArray): List<LnUrlPayErrorData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlPayErrorData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -939,7 +1129,7 @@ fun asLnUrlPayRequestList(arr: ReadableArray): List<LnUrlPayRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlPayRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1001,7 +1191,7 @@ fun asLnUrlPayRequestDataList(arr: ReadableArray): List<LnUrlPayRequestData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlPayRequestData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1040,7 +1230,7 @@ fun asLnUrlPaySuccessDataList(arr: ReadableArray): List<LnUrlPaySuccessData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlPaySuccessData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1075,7 +1265,7 @@ fun asLnUrlWithdrawRequestList(arr: ReadableArray): List<LnUrlWithdrawRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlWithdrawRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1117,7 +1307,7 @@ fun asLnUrlWithdrawRequestDataList(arr: ReadableArray): List<LnUrlWithdrawReques
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlWithdrawRequestData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1147,7 +1337,7 @@ fun asLnUrlWithdrawSuccessDataList(arr: ReadableArray): List<LnUrlWithdrawSucces
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlWithdrawSuccessData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1182,7 +1372,7 @@ fun asLocaleOverridesList(arr: ReadableArray): List<LocaleOverrides> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLocaleOverrides(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1215,7 +1405,7 @@ fun asLocalizedNameList(arr: ReadableArray): List<LocalizedName> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLocalizedName(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1227,6 +1417,9 @@ fun asLogEntry(logEntry: ReadableMap): LogEntry? {
             arrayOf(
                 "line",
                 "level",
+                "target",
+                "timestamp",
+                "fields",
             ),
         )
     ) {
@@ -1234,13 +1427,25 @@ fun asLogEntry(logEntry: ReadableMap): LogEntry? {
     }
     val line = logEntry.getString("line")!!
     val level = logEntry.getString("level")!!
-    return LogEntry(line, level)
+    val target = logEntry.getString("target")!!
+    val module = if (hasNonNullKey(logEntry, "module")) logEntry.getString("module") else null
+    val timestamp = logEntry.getDouble("timestamp").toULong()
+    val swapId = if (hasNonNullKey(logEntry, "swapId")) logEntry.getString("swapId") else null
+    val paymentId = if (hasNonNullKey(logEntry, "paymentId")) logEntry.getString("paymentId") else null
+    val fields = logEntry.getArray("fields")?.let { asLogFieldList(it) }!!
+    return LogEntry(line, level, target, module, timestamp, swapId, paymentId, fields)
 }
 
 fun readableMapOf(logEntry: LogEntry): ReadableMap =
     readableMapOf(
         "line" to logEntry.line,
         "level" to logEntry.level,
+        "target" to logEntry.target,
+        "module" to logEntry.module,
+        "timestamp" to logEntry.timestamp,
+        "swapId" to logEntry.swapId,
+        "paymentId" to logEntry.paymentId,
+        "fields" to readableArrayOf(logEntry.fields),
     )
 
 fun asLogEntryList(arr: ReadableArray): List<LogEntry> {
@@ -1248,7 +1453,40 @@ fun asLogEntryList(arr: ReadableArray): List<LogEntry> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLogEntry(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asLogField(logField: ReadableMap): LogField? {
+    if (!validateMandatoryFields(
+            logField,
+            arrayOf(
+                "key",
+                "value",
+            ),
+        )
+    ) {
+        return null
+    }
+    val key = logField.getString("key")!!
+    val value = logField.getString("value")!!
+    return LogField(key, value)
+}
+
+fun readableMapOf(logField: LogField): ReadableMap =
+    readableMapOf(
+        "key" to logField.key,
+        "value" to logField.value,
+    )
+
+fun asLogFieldList(arr: ReadableArray): List<LogField> {
+    val list = ArrayList<LogField>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLogField(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1278,7 +1516,7 @@ fun asMessageSuccessActionDataList(arr: ReadableArray): List<MessageSuccessActio
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asMessageSuccessActionData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1297,13 +1535,24 @@ fun asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: ReadableMap): O
     }
     val send = onchainPaymentLimitsResponse.getMap("send")?.let { asLimits(it) }!!
     val receive = onchainPaymentLimitsResponse.getMap("receive")?.let { asLimits(it) }!!
-    return OnchainPaymentLimitsResponse(send, receive)
+    val cacheAgeSecs =
+        if (hasNonNullKey(
+                onchainPaymentLimitsResponse,
+                "cacheAgeSecs",
+            )
+        ) {
+            onchainPaymentLimitsResponse.getDouble("cacheAgeSecs").toULong()
+        } else {
+            null
+        }
+    return OnchainPaymentLimitsResponse(send, receive, cacheAgeSecs)
 }
 
 fun readableMapOf(onchainPaymentLimitsResponse: OnchainPaymentLimitsResponse): ReadableMap =
     readableMapOf(
         "send" to readableMapOf(onchainPaymentLimitsResponse.send),
         "receive" to readableMapOf(onchainPaymentLimitsResponse.receive),
+        "cacheAgeSecs" to onchainPaymentLimitsResponse.cacheAgeSecs,
     )
 
 fun asOnchainPaymentLimitsResponseList(arr: ReadableArray): List<OnchainPaymentLimitsResponse> {
@@ -1311,7 +1560,7 @@ fun asOnchainPaymentLimitsResponseList(arr: ReadableArray): List<OnchainPaymentL
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asOnchainPaymentLimitsResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1344,7 +1593,7 @@ fun asPayOnchainRequestList(arr: ReadableArray): List<PayOnchainRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPayOnchainRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1373,7 +1622,8 @@ fun asPayment(payment: ReadableMap): Payment? {
     val details = payment.getMap("details")?.let { asPaymentDetails(it) }!!
     val destination = if (hasNonNullKey(payment, "destination")) payment.getString("destination") else null
     val txId = if (hasNonNullKey(payment, "txId")) payment.getString("txId") else null
-    return Payment(timestamp, amountSat, feesSat, paymentType, status, details, destination, txId)
+    val fiatRate = if (hasNonNullKey(payment, "fiatRate")) payment.getMap("fiatRate")?.let { asPaymentFiatRate(it) } else null
+    return Payment(timestamp, amountSat, feesSat, paymentType, status, details, destination, txId, fiatRate)
 }
 
 fun readableMapOf(payment: Payment): ReadableMap =
@@ -1386,6 +1636,7 @@ fun readableMapOf(payment: Payment): ReadableMap =
         "details" to readableMapOf(payment.details),
         "destination" to payment.destination,
         "txId" to payment.txId,
+        "fiatRate" to payment.fiatRate?.let { readableMapOf(it) },
     )
 
 fun asPaymentList(arr: ReadableArray): List<Payment> {
@@ -1393,7 +1644,40 @@ fun asPaymentList(arr: ReadableArray): List<Payment> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPayment(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asPaymentFiatRate(paymentFiatRate: ReadableMap): PaymentFiatRate? {
+    if (!validateMandatoryFields(
+            paymentFiatRate,
+            arrayOf(
+                "currency",
+                "rate",
+            ),
+        )
+    ) {
+        return null
+    }
+    val currency = paymentFiatRate.getString("currency")!!
+    val rate = paymentFiatRate.getDouble("rate")
+    return PaymentFiatRate(currency, rate)
+}
+
+fun readableMapOf(paymentFiatRate: PaymentFiatRate): ReadableMap =
+    readableMapOf(
+        "currency" to paymentFiatRate.currency,
+        "rate" to paymentFiatRate.rate,
+    )
+
+fun asPaymentFiatRateList(arr: ReadableArray): List<PaymentFiatRate> {
+    val list = ArrayList<PaymentFiatRate>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPaymentFiatRate(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1426,7 +1710,7 @@ fun asPrepareBuyBitcoinRequestList(arr: ReadableArray): List<PrepareBuyBitcoinRe
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareBuyBitcoinRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1462,7 +1746,7 @@ fun asPrepareBuyBitcoinResponseList(arr: ReadableArray): List<PrepareBuyBitcoinR
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareBuyBitcoinResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1508,7 +1792,7 @@ fun asPrepareLnUrlPayRequestList(arr: ReadableArray): List<PrepareLnUrlPayReques
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareLnUrlPayRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1550,7 +1834,7 @@ fun asPrepareLnUrlPayResponseList(arr: ReadableArray): List<PrepareLnUrlPayRespo
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareLnUrlPayResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1591,7 +1875,7 @@ fun asPreparePayOnchainRequestList(arr: ReadableArray): List<PreparePayOnchainRe
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPreparePayOnchainRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1612,7 +1896,15 @@ fun asPreparePayOnchainResponse(preparePayOnchainResponse: ReadableMap): Prepare
     val receiverAmountSat = preparePayOnchainResponse.getDouble("receiverAmountSat").toULong()
     val claimFeesSat = preparePayOnchainResponse.getDouble("claimFeesSat").toULong()
     val totalFeesSat = preparePayOnchainResponse.getDouble("totalFeesSat").toULong()
-    return PreparePayOnchainResponse(receiverAmountSat, claimFeesSat, totalFeesSat)
+    val fiatRate =
+        if (hasNonNullKey(preparePayOnchainResponse, "fiatRate")) {
+            preparePayOnchainResponse.getMap("fiatRate")?.let {
+                asPaymentFiatRate(it)
+            }
+        } else {
+            null
+        }
+    return PreparePayOnchainResponse(receiverAmountSat, claimFeesSat, totalFeesSat, fiatRate)
 }
 
 fun readableMapOf(preparePayOnchainResponse: PreparePayOnchainResponse): ReadableMap =
@@ -1620,6 +1912,7 @@ fun readableMapOf(preparePayOnchainResponse: PreparePayOnchainResponse): Readabl
         "receiverAmountSat" to preparePayOnchainResponse.receiverAmountSat,
         "claimFeesSat" to preparePayOnchainResponse.claimFeesSat,
         "totalFeesSat" to preparePayOnchainResponse.totalFeesSat,
+        "fiatRate" to preparePayOnchainResponse.fiatRate?.let { readableMapOf(it) },
     )
 
 fun asPreparePayOnchainResponseList(arr: ReadableArray): List<PreparePayOnchainResponse> {
@@ -1627,7 +1920,7 @@ fun asPreparePayOnchainResponseList(arr: ReadableArray): List<PreparePayOnchainR
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPreparePayOnchainResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1654,13 +1947,22 @@ fun asPrepareReceiveRequest(prepareReceiveRequest: ReadableMap): PrepareReceiveR
         } else {
             null
         }
-    return PrepareReceiveRequest(paymentMethod, payerAmountSat)
+    val payerAmountFiat =
+        if (hasNonNullKey(prepareReceiveRequest, "payerAmountFiat")) {
+            prepareReceiveRequest.getMap("payerAmountFiat")?.let {
+                asFiatAmount(it)
+            }
+        } else {
+            null
+        }
+    return PrepareReceiveRequest(paymentMethod, payerAmountSat, payerAmountFiat)
 }
 
 fun readableMapOf(prepareReceiveRequest: PrepareReceiveRequest): ReadableMap =
     readableMapOf(
         "paymentMethod" to prepareReceiveRequest.paymentMethod.name.lowercase(),
         "payerAmountSat" to prepareReceiveRequest.payerAmountSat,
+        "payerAmountFiat" to prepareReceiveRequest.payerAmountFiat?.let { readableMapOf(it) },
     )
 
 fun asPrepareReceiveRequestList(arr: ReadableArray): List<PrepareReceiveRequest> {
@@ -1668,7 +1970,7 @@ fun asPrepareReceiveRequestList(arr: ReadableArray): List<PrepareReceiveRequest>
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareReceiveRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1697,7 +1999,15 @@ fun asPrepareReceiveResponse(prepareReceiveResponse: ReadableMap): PrepareReceiv
         }
     val paymentMethod = prepareReceiveResponse.getString("paymentMethod")?.let { asPaymentMethod(it) }!!
     val feesSat = prepareReceiveResponse.getDouble("feesSat").toULong()
-    return PrepareReceiveResponse(payerAmountSat, paymentMethod, feesSat)
+    val fiatRate =
+        if (hasNonNullKey(prepareReceiveResponse, "fiatRate")) {
+            prepareReceiveResponse.getMap("fiatRate")?.let {
+                asPaymentFiatRate(it)
+            }
+        } else {
+            null
+        }
+    return PrepareReceiveResponse(payerAmountSat, paymentMethod, feesSat, fiatRate)
 }
 
 fun readableMapOf(prepareReceiveResponse: PrepareReceiveResponse): ReadableMap =
@@ -1705,6 +2015,7 @@ fun readableMapOf(prepareReceiveResponse: PrepareReceiveResponse): ReadableMap =
         "payerAmountSat" to prepareReceiveResponse.payerAmountSat,
         "paymentMethod" to prepareReceiveResponse.paymentMethod.name.lowercase(),
         "feesSat" to prepareReceiveResponse.feesSat,
+        "fiatRate" to prepareReceiveResponse.fiatRate?.let { readableMapOf(it) },
     )
 
 fun asPrepareReceiveResponseList(arr: ReadableArray): List<PrepareReceiveResponse> {
@@ -1712,7 +2023,7 @@ fun asPrepareReceiveResponseList(arr: ReadableArray): List<PrepareReceiveRespons
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareReceiveResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1748,7 +2059,7 @@ fun asPrepareRefundRequestList(arr: ReadableArray): List<PrepareRefundRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareRefundRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1760,6 +2071,8 @@ fun asPrepareRefundResponse(prepareRefundResponse: ReadableMap): PrepareRefundRe
             arrayOf(
                 "txVsize",
                 "txFeeSat",
+                "isCooperative",
+                "feeSavedSat",
             ),
         )
     ) {
@@ -1767,14 +2080,18 @@ fun asPrepareRefundResponse(prepareRefundResponse: ReadableMap): PrepareRefundRe
     }
     val txVsize = prepareRefundResponse.getInt("txVsize").toUInt()
     val txFeeSat = prepareRefundResponse.getDouble("txFeeSat").toULong()
+    val isCooperative = prepareRefundResponse.getBoolean("isCooperative")
+    val feeSavedSat = prepareRefundResponse.getDouble("feeSavedSat").toULong()
     val refundTxId = if (hasNonNullKey(prepareRefundResponse, "refundTxId")) prepareRefundResponse.getString("refundTxId") else null
-    return PrepareRefundResponse(txVsize, txFeeSat, refundTxId)
+    return PrepareRefundResponse(txVsize, txFeeSat, isCooperative, feeSavedSat, refundTxId)
 }
 
 fun readableMapOf(prepareRefundResponse: PrepareRefundResponse): ReadableMap =
     readableMapOf(
         "txVsize" to prepareRefundResponse.txVsize,
         "txFeeSat" to prepareRefundResponse.txFeeSat,
+        "isCooperative" to prepareRefundResponse.isCooperative,
+        "feeSavedSat" to prepareRefundResponse.feeSavedSat,
         "refundTxId" to prepareRefundResponse.refundTxId,
     )
 
@@ -1783,7 +2100,7 @@ fun asPrepareRefundResponseList(arr: ReadableArray): List<PrepareRefundResponse>
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareRefundResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1815,7 +2132,7 @@ fun asPrepareSendRequestList(arr: ReadableArray): List<PrepareSendRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareSendRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1834,13 +2151,22 @@ fun asPrepareSendResponse(prepareSendResponse: ReadableMap): PrepareSendResponse
     }
     val destination = prepareSendResponse.getMap("destination")?.let { asSendDestination(it) }!!
     val feesSat = prepareSendResponse.getDouble("feesSat").toULong()
-    return PrepareSendResponse(destination, feesSat)
+    val fiatRate =
+        if (hasNonNullKey(prepareSendResponse, "fiatRate")) {
+            prepareSendResponse.getMap("fiatRate")?.let {
+                asPaymentFiatRate(it)
+            }
+        } else {
+            null
+        }
+    return PrepareSendResponse(destination, feesSat, fiatRate)
 }
 
 fun readableMapOf(prepareSendResponse: PrepareSendResponse): ReadableMap =
     readableMapOf(
         "destination" to readableMapOf(prepareSendResponse.destination),
         "feesSat" to prepareSendResponse.feesSat,
+        "fiatRate" to prepareSendResponse.fiatRate?.let { readableMapOf(it) },
     )
 
 fun asPrepareSendResponseList(arr: ReadableArray): List<PrepareSendResponse> {
@@ -1848,7 +2174,7 @@ fun asPrepareSendResponseList(arr: ReadableArray): List<PrepareSendResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPrepareSendResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1881,7 +2207,7 @@ fun asRateList(arr: ReadableArray): List<Rate> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRate(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1924,7 +2250,7 @@ fun asReceivePaymentRequestList(arr: ReadableArray): List<ReceivePaymentRequest>
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asReceivePaymentRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1954,7 +2280,7 @@ fun asReceivePaymentResponseList(arr: ReadableArray): List<ReceivePaymentRespons
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asReceivePaymentResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -1996,7 +2322,7 @@ fun asRecommendedFeesList(arr: ReadableArray): List<RecommendedFees> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRecommendedFees(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2032,7 +2358,7 @@ fun asRefundRequestList(arr: ReadableArray): List<RefundRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRefundRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2062,7 +2388,7 @@ fun asRefundResponseList(arr: ReadableArray): List<RefundResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRefundResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2083,7 +2409,17 @@ fun asRefundableSwap(refundableSwap: ReadableMap): RefundableSwap? {
     val swapAddress = refundableSwap.getString("swapAddress")!!
     val timestamp = refundableSwap.getInt("timestamp").toUInt()
     val amountSat = refundableSwap.getDouble("amountSat").toULong()
-    return RefundableSwap(swapAddress, timestamp, amountSat)
+    val timeToRefundable =
+        if (hasNonNullKey(
+                refundableSwap,
+                "timeToRefundable",
+            )
+        ) {
+            refundableSwap.getDouble("timeToRefundable").toULong()
+        } else {
+            null
+        }
+    return RefundableSwap(swapAddress, timestamp, amountSat, timeToRefundable)
 }
 
 fun readableMapOf(refundableSwap: RefundableSwap): ReadableMap =
@@ -2091,6 +2427,7 @@ fun readableMapOf(refundableSwap: RefundableSwap): ReadableMap =
         "swapAddress" to refundableSwap.swapAddress,
         "timestamp" to refundableSwap.timestamp,
         "amountSat" to refundableSwap.amountSat,
+        "timeToRefundable" to refundableSwap.timeToRefundable,
     )
 
 fun asRefundableSwapList(arr: ReadableArray): List<RefundableSwap> {
@@ -2098,7 +2435,7 @@ fun asRefundableSwapList(arr: ReadableArray): List<RefundableSwap> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRefundableSwap(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2126,7 +2463,7 @@ fun asRestoreRequestList(arr: ReadableArray): List<RestoreRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRestoreRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2156,7 +2493,7 @@ fun asRouteHintList(arr: ReadableArray): List<RouteHint> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRouteHint(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2210,7 +2547,49 @@ fun asRouteHintHopList(arr: ReadableArray): List<RouteHintHop> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asRouteHintHop(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSchedulerIntervals(schedulerIntervals: ReadableMap): SchedulerIntervals? {
+    if (!validateMandatoryFields(
+            schedulerIntervals,
+            arrayOf(
+                "syncSecs",
+                "refundsSecs",
+                "liquidRescanSecs",
+                "bitcoinRescanSecs",
+                "newBlocksSecs",
+            ),
+        )
+    ) {
+        return null
+    }
+    val syncSecs = schedulerIntervals.getDouble("syncSecs").toULong()
+    val refundsSecs = schedulerIntervals.getDouble("refundsSecs").toULong()
+    val liquidRescanSecs = schedulerIntervals.getDouble("liquidRescanSecs").toULong()
+    val bitcoinRescanSecs = schedulerIntervals.getDouble("bitcoinRescanSecs").toULong()
+    val newBlocksSecs = schedulerIntervals.getDouble("newBlocksSecs").toULong()
+    return SchedulerIntervals(syncSecs, refundsSecs, liquidRescanSecs, bitcoinRescanSecs, newBlocksSecs)
+}
+
+fun readableMapOf(schedulerIntervals: SchedulerIntervals): ReadableMap =
+    readableMapOf(
+        "syncSecs" to schedulerIntervals.syncSecs,
+        "refundsSecs" to schedulerIntervals.refundsSecs,
+        "liquidRescanSecs" to schedulerIntervals.liquidRescanSecs,
+        "bitcoinRescanSecs" to schedulerIntervals.bitcoinRescanSecs,
+        "newBlocksSecs" to schedulerIntervals.newBlocksSecs,
+    )
+
+fun asSchedulerIntervalsList(arr: ReadableArray): List<SchedulerIntervals> {
+    val list = ArrayList<SchedulerIntervals>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSchedulerIntervals(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2240,7 +2619,7 @@ fun asSendPaymentRequestList(arr: ReadableArray): List<SendPaymentRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSendPaymentRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2270,7 +2649,7 @@ fun asSendPaymentResponseList(arr: ReadableArray): List<SendPaymentResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSendPaymentResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2300,7 +2679,7 @@ fun asSignMessageRequestList(arr: ReadableArray): List<SignMessageRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSignMessageRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2330,7 +2709,51 @@ fun asSignMessageResponseList(arr: ReadableArray): List<SignMessageResponse> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSignMessageResponse(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSwapStateTransition(swapStateTransition: ReadableMap): SwapStateTransition? {
+    if (!validateMandatoryFields(
+            swapStateTransition,
+            arrayOf(
+                "swapId",
+                "fromState",
+                "toState",
+                "txIds",
+                "createdAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val swapId = swapStateTransition.getString("swapId")!!
+    val fromState = swapStateTransition.getString("fromState")?.let { asPaymentState(it) }!!
+    val toState = swapStateTransition.getString("toState")?.let { asPaymentState(it) }!!
+    val swapperStatus = if (hasNonNullKey(swapStateTransition, "swapperStatus")) swapStateTransition.getString("swapperStatus") else null
+    val txIds = swapStateTransition.getArray("txIds")?.let { asStringList(it) }!!
+    val createdAt = swapStateTransition.getInt("createdAt").toUInt()
+    return SwapStateTransition(swapId, fromState, toState, swapperStatus, txIds, createdAt)
+}
+
+fun readableMapOf(swapStateTransition: SwapStateTransition): ReadableMap =
+    readableMapOf(
+        "swapId" to swapStateTransition.swapId,
+        "fromState" to swapStateTransition.fromState.name.lowercase(),
+        "toState" to swapStateTransition.toState.name.lowercase(),
+        "swapperStatus" to swapStateTransition.swapperStatus,
+        "txIds" to readableArrayOf(swapStateTransition.txIds),
+        "createdAt" to swapStateTransition.createdAt,
+    )
+
+fun asSwapStateTransitionList(arr: ReadableArray): List<SwapStateTransition> {
+    val list = ArrayList<SwapStateTransition>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSwapStateTransition(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2364,7 +2787,114 @@ fun asSymbolList(arr: ReadableArray): List<Symbol> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSymbol(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSyncMetrics(syncMetrics: ReadableMap): SyncMetrics? {
+    if (!validateMandatoryFields(
+            syncMetrics,
+            arrayOf(
+                "startedAt",
+                "durationMs",
+                "scanDurationMs",
+                "totalTxCount",
+                "processedTxCount",
+            ),
+        )
+    ) {
+        return null
+    }
+    val startedAt = syncMetrics.getInt("startedAt").toUInt()
+    val durationMs = syncMetrics.getDouble("durationMs").toULong()
+    val scanDurationMs = syncMetrics.getDouble("scanDurationMs").toULong()
+    val liquidTip = if (hasNonNullKey(syncMetrics, "liquidTip")) syncMetrics.getInt("liquidTip").toUInt() else null
+    val totalTxCount = syncMetrics.getInt("totalTxCount").toUInt()
+    val processedTxCount = syncMetrics.getInt("processedTxCount").toUInt()
+    return SyncMetrics(startedAt, durationMs, scanDurationMs, liquidTip, totalTxCount, processedTxCount)
+}
+
+fun readableMapOf(syncMetrics: SyncMetrics): ReadableMap =
+    readableMapOf(
+        "startedAt" to syncMetrics.startedAt,
+        "durationMs" to syncMetrics.durationMs,
+        "scanDurationMs" to syncMetrics.scanDurationMs,
+        "liquidTip" to syncMetrics.liquidTip,
+        "totalTxCount" to syncMetrics.totalTxCount,
+        "processedTxCount" to syncMetrics.processedTxCount,
+    )
+
+fun asSyncMetricsList(arr: ReadableArray): List<SyncMetrics> {
+    val list = ArrayList<SyncMetrics>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSyncMetrics(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSyncOnceRequest(syncOnceRequest: ReadableMap): SyncOnceRequest? {
+    if (!validateMandatoryFields(
+            syncOnceRequest,
+            arrayOf(
+                "timeoutSecs",
+            ),
+        )
+    ) {
+        return null
+    }
+    val timeoutSecs = syncOnceRequest.getDouble("timeoutSecs").toULong()
+    return SyncOnceRequest(timeoutSecs)
+}
+
+fun readableMapOf(syncOnceRequest: SyncOnceRequest): ReadableMap =
+    readableMapOf(
+        "timeoutSecs" to syncOnceRequest.timeoutSecs,
+    )
+
+fun asSyncOnceRequestList(arr: ReadableArray): List<SyncOnceRequest> {
+    val list = ArrayList<SyncOnceRequest>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSyncOnceRequest(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSyncOnceResponse(syncOnceResponse: ReadableMap): SyncOnceResponse? {
+    if (!validateMandatoryFields(
+            syncOnceResponse,
+            arrayOf(
+                "pendingTasks",
+                "pendingSwapCount",
+            ),
+        )
+    ) {
+        return null
+    }
+    val pendingTasks = syncOnceResponse.getArray("pendingTasks")?.let { asSyncTaskList(it) }!!
+    val pendingSwapCount = syncOnceResponse.getInt("pendingSwapCount").toUInt()
+    return SyncOnceResponse(pendingTasks, pendingSwapCount)
+}
+
+fun readableMapOf(syncOnceResponse: SyncOnceResponse): ReadableMap =
+    readableMapOf(
+        "pendingTasks" to readableArrayOf(syncOnceResponse.pendingTasks),
+        "pendingSwapCount" to syncOnceResponse.pendingSwapCount,
+    )
+
+fun asSyncOnceResponseList(arr: ReadableArray): List<SyncOnceResponse> {
+    val list = ArrayList<SyncOnceResponse>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSyncOnceResponse(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2400,7 +2930,60 @@ fun asUrlSuccessActionDataList(arr: ReadableArray): List<UrlSuccessActionData> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asUrlSuccessActionData(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asWebhookDelivery(webhookDelivery: ReadableMap): WebhookDelivery? {
+    if (!validateMandatoryFields(
+            webhookDelivery,
+            arrayOf(
+                "id",
+                "url",
+                "eventSeq",
+                "payload",
+                "state",
+                "attempts",
+                "nextAttemptAt",
+                "createdAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = webhookDelivery.getDouble("id").toULong()
+    val url = webhookDelivery.getString("url")!!
+    val eventSeq = webhookDelivery.getDouble("eventSeq").toULong()
+    val payload = webhookDelivery.getString("payload")!!
+    val state = webhookDelivery.getString("state")?.let { asWebhookDeliveryState(it) }!!
+    val attempts = webhookDelivery.getInt("attempts").toUInt()
+    val nextAttemptAt = webhookDelivery.getInt("nextAttemptAt").toUInt()
+    val lastError = if (hasNonNullKey(webhookDelivery, "lastError")) webhookDelivery.getString("lastError") else null
+    val createdAt = webhookDelivery.getInt("createdAt").toUInt()
+    return WebhookDelivery(id, url, eventSeq, payload, state, attempts, nextAttemptAt, lastError, createdAt)
+}
+
+fun readableMapOf(webhookDelivery: WebhookDelivery): ReadableMap =
+    readableMapOf(
+        "id" to webhookDelivery.id,
+        "url" to webhookDelivery.url,
+        "eventSeq" to webhookDelivery.eventSeq,
+        "payload" to webhookDelivery.payload,
+        "state" to webhookDelivery.state.name.lowercase(),
+        "attempts" to webhookDelivery.attempts,
+        "nextAttemptAt" to webhookDelivery.nextAttemptAt,
+        "lastError" to webhookDelivery.lastError,
+        "createdAt" to webhookDelivery.createdAt,
+    )
+
+fun asWebhookDeliveryList(arr: ReadableArray): List<WebhookDelivery> {
+    val list = ArrayList<WebhookDelivery>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asWebhookDelivery(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2440,7 +3023,7 @@ fun asAesSuccessActionDataResultList(arr: ReadableArray): List<AesSuccessActionD
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asAesSuccessActionDataResult(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2482,7 +3065,20 @@ fun asAmountList(arr: ReadableArray): List<Amount> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asAmount(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asBlockchain(type: String): Blockchain = Blockchain.valueOf(camelToUpperSnakeCase(type))
+
+fun asBlockchainList(arr: ReadableArray): List<Blockchain> {
+    val list = ArrayList<Blockchain>()
+    for (value in arr.toList()) {
+        when (value) {
+            is String -> list.add(asBlockchain(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2495,7 +3091,69 @@ fun asBuyBitcoinProviderList(arr: ReadableArray): List<BuyBitcoinProvider> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asBuyBitcoinProvider(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asChainBackend(chainBackend: ReadableMap): ChainBackend? {
+    val type = chainBackend.getString("type")
+
+    if (type == "electrum") {
+        val url = chainBackend.getString("url")!!
+        return ChainBackend.Electrum(url)
+    }
+    if (type == "esplora") {
+        val url = chainBackend.getString("url")!!
+        return ChainBackend.Esplora(url)
+    }
+    if (type == "hybrid") {
+        val electrumUrl = chainBackend.getString("electrumUrl")!!
+        val esploraUrl = chainBackend.getString("esploraUrl")!!
+        return ChainBackend.Hybrid(electrumUrl, esploraUrl)
+    }
+    if (type == "rpc") {
+        val url = chainBackend.getString("url")!!
+        val username = if (hasNonNullKey(chainBackend, "username")) chainBackend.getString("username") else null
+        val password = if (hasNonNullKey(chainBackend, "password")) chainBackend.getString("password") else null
+        return ChainBackend.Rpc(url, username, password)
+    }
+    return null
+}
+
+fun readableMapOf(chainBackend: ChainBackend): ReadableMap? {
+    val map = Arguments.createMap()
+    when (chainBackend) {
+        is ChainBackend.Electrum -> {
+            pushToMap(map, "type", "electrum")
+            pushToMap(map, "url", chainBackend.url)
+        }
+        is ChainBackend.Esplora -> {
+            pushToMap(map, "type", "esplora")
+            pushToMap(map, "url", chainBackend.url)
+        }
+        is ChainBackend.Hybrid -> {
+            pushToMap(map, "type", "hybrid")
+            pushToMap(map, "electrumUrl", chainBackend.electrumUrl)
+            pushToMap(map, "esploraUrl", chainBackend.esploraUrl)
+        }
+        is ChainBackend.Rpc -> {
+            pushToMap(map, "type", "rpc")
+            pushToMap(map, "url", chainBackend.url)
+            pushToMap(map, "username", chainBackend.username)
+            pushToMap(map, "password", chainBackend.password)
+        }
+    }
+    return map
+}
+
+fun asChainBackendList(arr: ReadableArray): List<ChainBackend> {
+    val list = ArrayList<ChainBackend>()
+    for (value in arr.toList()) {
+        when (value) {
+            is ReadableMap -> list.add(asChainBackend(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2527,7 +3185,7 @@ fun asGetPaymentRequestList(arr: ReadableArray): List<GetPaymentRequest> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asGetPaymentRequest(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2631,7 +3289,7 @@ fun asInputTypeList(arr: ReadableArray): List<InputType> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asInputType(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2644,7 +3302,7 @@ fun asLiquidNetworkList(arr: ReadableArray): List<LiquidNetwork> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asLiquidNetwork(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2684,7 +3342,7 @@ fun asListPaymentDetailsList(arr: ReadableArray): List<ListPaymentDetails> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asListPaymentDetails(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2722,7 +3380,7 @@ fun asLnUrlCallbackStatusList(arr: ReadableArray): List<LnUrlCallbackStatus> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlCallbackStatus(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2770,7 +3428,7 @@ fun asLnUrlPayResultList(arr: ReadableArray): List<LnUrlPayResult> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlPayResult(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2818,7 +3476,7 @@ fun asLnUrlWithdrawResultList(arr: ReadableArray): List<LnUrlWithdrawResult> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asLnUrlWithdrawResult(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2831,7 +3489,7 @@ fun asNetworkList(arr: ReadableArray): List<Network> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asNetwork(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2847,6 +3505,11 @@ fun asPayAmount(payAmount: ReadableMap): PayAmount? {
     if (type == "drain") {
         return PayAmount.Drain
     }
+    if (type == "fiat") {
+        val currency = payAmount.getString("currency")!!
+        val amount = payAmount.getDouble("amount")
+        return PayAmount.Fiat(currency, amount)
+    }
     return null
 }
 
@@ -2860,6 +3523,11 @@ fun readableMapOf(payAmount: PayAmount): ReadableMap? {
         is PayAmount.Drain -> {
             pushToMap(map, "type", "drain")
         }
+        is PayAmount.Fiat -> {
+            pushToMap(map, "type", "fiat")
+            pushToMap(map, "currency", payAmount.currency)
+            pushToMap(map, "amount", payAmount.amount)
+        }
     }
     return map
 }
@@ -2869,7 +3537,7 @@ fun asPayAmountList(arr: ReadableArray): List<PayAmount> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPayAmount(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2957,7 +3625,7 @@ fun asPaymentDetailsList(arr: ReadableArray): List<PaymentDetails> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asPaymentDetails(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2970,7 +3638,7 @@ fun asPaymentMethodList(arr: ReadableArray): List<PaymentMethod> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asPaymentMethod(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2983,7 +3651,7 @@ fun asPaymentStateList(arr: ReadableArray): List<PaymentState> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asPaymentState(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -2996,7 +3664,7 @@ fun asPaymentTypeList(arr: ReadableArray): List<PaymentType> {
     for (value in arr.toList()) {
         when (value) {
             is String -> list.add(asPaymentType(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3013,6 +3681,10 @@ fun asSdkEvent(sdkEvent: ReadableMap): SdkEvent? {
         val details = sdkEvent.getMap("details")?.let { asPayment(it) }!!
         return SdkEvent.PaymentPending(details)
     }
+    if (type == "paymentQueued") {
+        val details = sdkEvent.getMap("details")?.let { asPayment(it) }!!
+        return SdkEvent.PaymentQueued(details)
+    }
     if (type == "paymentRefunded") {
         val details = sdkEvent.getMap("details")?.let { asPayment(it) }!!
         return SdkEvent.PaymentRefunded(details)
@@ -3029,6 +3701,40 @@ fun asSdkEvent(sdkEvent: ReadableMap): SdkEvent? {
         val details = sdkEvent.getMap("details")?.let { asPayment(it) }!!
         return SdkEvent.PaymentWaitingConfirmation(details)
     }
+    if (type == "swapNearingTimeout") {
+        val swapId = sdkEvent.getString("swapId")!!
+        val blocksLeft = sdkEvent.getInt("blocksLeft").toUInt()
+        return SdkEvent.SwapNearingTimeout(swapId, blocksLeft)
+    }
+    if (type == "swapRefundable") {
+        val swapId = sdkEvent.getString("swapId")!!
+        return SdkEvent.SwapRefundable(swapId)
+    }
+    if (type == "swapStateChanged") {
+        val swapId = sdkEvent.getString("swapId")!!
+        val fromState = sdkEvent.getString("fromState")?.let { asPaymentState(it) }!!
+        val toState = sdkEvent.getString("toState")?.let { asPaymentState(it) }!!
+        return SdkEvent.SwapStateChanged(swapId, fromState, toState)
+    }
+    if (type == "balanceChanged") {
+        val oldBalanceSat = sdkEvent.getDouble("oldBalanceSat").toULong()
+        val newBalanceSat = sdkEvent.getDouble("newBalanceSat").toULong()
+        return SdkEvent.BalanceChanged(oldBalanceSat, newBalanceSat)
+    }
+    if (type == "syncProgress") {
+        val step = sdkEvent.getInt("step").toUInt()
+        val total = sdkEvent.getInt("total").toUInt()
+        return SdkEvent.SyncProgress(step, total)
+    }
+    if (type == "connectivityChanged") {
+        val isConnected = sdkEvent.getBoolean("isConnected")
+        return SdkEvent.ConnectivityChanged(isConnected)
+    }
+    if (type == "newBlock") {
+        val chain = sdkEvent.getString("chain")?.let { asBlockchain(it) }!!
+        val height = sdkEvent.getInt("height").toUInt()
+        return SdkEvent.NewBlock(chain, height)
+    }
     if (type == "synced") {
         return SdkEvent.Synced
     }
@@ -3046,6 +3752,10 @@ fun readableMapOf(sdkEvent: SdkEvent): ReadableMap? {
             pushToMap(map, "type", "paymentPending")
             pushToMap(map, "details", readableMapOf(sdkEvent.details))
         }
+        is SdkEvent.PaymentQueued -> {
+            pushToMap(map, "type", "paymentQueued")
+            pushToMap(map, "details", readableMapOf(sdkEvent.details))
+        }
         is SdkEvent.PaymentRefunded -> {
             pushToMap(map, "type", "paymentRefunded")
             pushToMap(map, "details", readableMapOf(sdkEvent.details))
@@ -3062,6 +3772,40 @@ fun readableMapOf(sdkEvent: SdkEvent): ReadableMap? {
             pushToMap(map, "type", "paymentWaitingConfirmation")
             pushToMap(map, "details", readableMapOf(sdkEvent.details))
         }
+        is SdkEvent.SwapNearingTimeout -> {
+            pushToMap(map, "type", "swapNearingTimeout")
+            pushToMap(map, "swapId", sdkEvent.swapId)
+            pushToMap(map, "blocksLeft", sdkEvent.blocksLeft)
+        }
+        is SdkEvent.SwapRefundable -> {
+            pushToMap(map, "type", "swapRefundable")
+            pushToMap(map, "swapId", sdkEvent.swapId)
+        }
+        is SdkEvent.SwapStateChanged -> {
+            pushToMap(map, "type", "swapStateChanged")
+            pushToMap(map, "swapId", sdkEvent.swapId)
+            pushToMap(map, "fromState", sdkEvent.fromState.name.lowercase())
+            pushToMap(map, "toState", sdkEvent.toState.name.lowercase())
+        }
+        is SdkEvent.BalanceChanged -> {
+            pushToMap(map, "type", "balanceChanged")
+            pushToMap(map, "oldBalanceSat", sdkEvent.oldBalanceSat)
+            pushToMap(map, "newBalanceSat", sdkEvent.newBalanceSat)
+        }
+        is SdkEvent.SyncProgress -> {
+            pushToMap(map, "type", "syncProgress")
+            pushToMap(map, "step", sdkEvent.step)
+            pushToMap(map, "total", sdkEvent.total)
+        }
+        is SdkEvent.ConnectivityChanged -> {
+            pushToMap(map, "type", "connectivityChanged")
+            pushToMap(map, "isConnected", sdkEvent.isConnected)
+        }
+        is SdkEvent.NewBlock -> {
+            pushToMap(map, "type", "newBlock")
+            pushToMap(map, "chain", sdkEvent.chain.name.lowercase())
+            pushToMap(map, "height", sdkEvent.height)
+        }
         is SdkEvent.Synced -> {
             pushToMap(map, "type", "synced")
         }
@@ -3074,7 +3818,7 @@ fun asSdkEventList(arr: ReadableArray): List<SdkEvent> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSdkEvent(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3124,7 +3868,7 @@ fun asSendDestinationList(arr: ReadableArray): List<SendDestination> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSendDestination(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3172,7 +3916,7 @@ fun asSuccessActionList(arr: ReadableArray): List<SuccessAction> {
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSuccessAction(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3220,7 +3964,33 @@ fun asSuccessActionProcessedList(arr: ReadableArray): List<SuccessActionProcesse
     for (value in arr.toList()) {
         when (value) {
             is ReadableMap -> list.add(asSuccessActionProcessed(value)!!)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asSyncTask(type: String): SyncTask = SyncTask.valueOf(camelToUpperSnakeCase(type))
+
+fun asSyncTaskList(arr: ReadableArray): List<SyncTask> {
+    val list = ArrayList<SyncTask>()
+    for (value in arr.toList()) {
+        when (value) {
+            is String -> list.add(asSyncTask(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
+        }
+    }
+    return list
+}
+
+fun asWebhookDeliveryState(type: String): WebhookDeliveryState = WebhookDeliveryState.valueOf(camelToUpperSnakeCase(type))
+
+fun asWebhookDeliveryStateList(arr: ReadableArray): List<WebhookDeliveryState> {
+    val list = ArrayList<WebhookDeliveryState>()
+    for (value in arr.toList()) {
+        when (value) {
+            is String -> list.add(asWebhookDeliveryState(value)!!)
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3256,10 +4026,13 @@ fun pushToArray(
 ) {
     when (value) {
         null -> array.pushNull()
+        is ChainBackend -> array.pushMap(readableMapOf(value))
+        is EventLogEntry -> array.pushMap(readableMapOf(value))
         is FiatCurrency -> array.pushMap(readableMapOf(value))
         is LnOfferBlindedPath -> array.pushMap(readableMapOf(value))
         is LocaleOverrides -> array.pushMap(readableMapOf(value))
         is LocalizedName -> array.pushMap(readableMapOf(value))
+        is LogField -> array.pushMap(readableMapOf(value))
         is Payment -> array.pushMap(readableMapOf(value))
         is PaymentType -> array.pushString(value.name.lowercase())
         is Rate -> array.pushMap(readableMapOf(value))
@@ -3267,10 +4040,13 @@ fun pushToArray(
         is RouteHint -> array.pushMap(readableMapOf(value))
         is RouteHintHop -> array.pushMap(readableMapOf(value))
         is String -> array.pushString(value)
+        is SwapStateTransition -> array.pushMap(readableMapOf(value))
+        is SyncTask -> array.pushString(value.name.lowercase())
         is UByte -> array.pushInt(value.toInt())
+        is WebhookDelivery -> array.pushMap(readableMapOf(value))
         is Array<*> -> array.pushArray(readableArrayOf(value.asIterable()))
         is List<*> -> array.pushArray(readableArrayOf(value))
-        else -> throw SdkException.Generic(errUnexpectedType(value))
+        else -> throw SdkException.Validation(errUnexpectedType(value))
     }
 }
 
@@ -3296,7 +4072,7 @@ fun pushToMap(
         is ULong -> map.putDouble(key, value.toDouble())
         is Array<*> -> map.putArray(key, readableArrayOf(value.asIterable()))
         is List<*> -> map.putArray(key, readableArrayOf(value))
-        else -> throw SdkException.Generic("Unexpected type ${value::class.java.name} for key [$key]")
+        else -> throw SdkException.Validation("Unexpected type ${value::class.java.name} for key [$key]")
     }
 }
 
@@ -3318,7 +4094,7 @@ fun asUByteList(arr: ReadableArray): List<UByte> {
             is Double -> list.add(value.toInt().toUByte())
             is Int -> list.add(value.toUByte())
             is UByte -> list.add(value)
-            else -> throw SdkException.Generic(errUnexpectedType(value))
+            else -> throw SdkException.Validation(errUnexpectedType(value))
         }
     }
     return list
@@ -3359,7 +4135,7 @@ internal fun ReadableArray.toList(): List<*> {
             ReadableType.String -> arrayList.add(getString(i))
             ReadableType.Map -> arrayList.add(getMap(i))
             ReadableType.Array -> arrayList.add(getArray(i))
-            else -> throw SdkException.Generic("Could not convert object at index: $i")
+            else -> throw SdkException.Validation("Could not convert object at index: $i")
         }
     }
     return arrayList
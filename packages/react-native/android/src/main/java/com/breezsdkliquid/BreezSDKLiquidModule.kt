@@ -32,7 +32,7 @@ class BreezSDKLiquidModule(
             return bindingLiquidSdk!!
         }
 
-        throw SdkException.Generic("Not initialized")
+        throw SdkException.NotStarted()
     }
 
     @Throws(SdkException::class)
@@ -41,13 +41,31 @@ class BreezSDKLiquidModule(
             val workingDirFile = File(workingDir)
 
             if (!workingDirFile.exists() && !workingDirFile.mkdirs()) {
-                throw SdkException.Generic("Mandatory field workingDir must contain a writable directory")
+                throw SdkException.Validation("Mandatory field workingDir must contain a writable directory")
             }
         } catch (e: SecurityException) {
-            throw SdkException.Generic("Mandatory field workingDir must contain a writable directory")
+            throw SdkException.Validation("Mandatory field workingDir must contain a writable directory")
         }
     }
 
+    private fun rejectErr(
+        e: Exception,
+        promise: Promise,
+    ) {
+        val info =
+            when (e) {
+                is SdkException -> sdkErrorInfo(e)
+                is PaymentException -> paymentErrorInfo(e)
+                else -> null
+            }
+        val userInfo = Arguments.createMap()
+        info?.let {
+            userInfo.putInt("code", it.code.toInt())
+            userInfo.putBoolean("retryable", it.retryable)
+        }
+        promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e, userInfo)
+    }
+
     @ReactMethod
     fun addListener(eventName: String) {}
 
@@ -70,7 +88,7 @@ class BreezSDKLiquidModule(
                 res.workingDir = workingDir.absolutePath
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -85,7 +103,7 @@ class BreezSDKLiquidModule(
                 val res = parse(input)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -100,7 +118,7 @@ class BreezSDKLiquidModule(
                 val res = parseInvoice(input)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -115,7 +133,7 @@ class BreezSDKLiquidModule(
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 e.printStackTrace()
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -126,21 +144,21 @@ class BreezSDKLiquidModule(
         promise: Promise,
     ) {
         if (bindingLiquidSdk != null) {
-            promise.reject("Generic", "Already initialized")
+            rejectErr(SdkException.AlreadyStarted(), promise)
             return
         }
 
         executor.execute {
             try {
                 var connectRequest =
-                    asConnectRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ConnectRequest")) }
+                    asConnectRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "ConnectRequest")) }
 
                 ensureWorkingDir(connectRequest.config.workingDir)
 
                 bindingLiquidSdk = connect(connectRequest)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -156,7 +174,7 @@ class BreezSDKLiquidModule(
                 eventListener.setId(res)
                 promise.resolve(res)
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -171,7 +189,92 @@ class BreezSDKLiquidModule(
                 getBindingLiquidSdk().removeEventListener(id)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun eventsSince(
+        seq: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().eventsSince(seq.toULong())
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun ackEvents(
+        listenerId: String,
+        seq: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().ackEvents(listenerId, seq.toULong())
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getEventCursor(
+        listenerId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().getEventCursor(listenerId)
+                promise.resolve(res?.let { res })
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getWebhookSigningSecret(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().getWebhookSigningSecret()
+                promise.resolve(res)
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listFailedWebhookDeliveries(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().listFailedWebhookDeliveries()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun retryWebhookDelivery(
+        id: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().retryWebhookDelivery(id.toULong())
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
             }
         }
     }
@@ -183,7 +286,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().getInfo()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -196,11 +299,12 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val signMessageRequest =
-                    asSignMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "SignMessageRequest")) }
+                    asSignMessageRequest(req)
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "SignMessageRequest")) }
                 val res = getBindingLiquidSdk().signMessage(signMessageRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -213,11 +317,12 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val checkMessageRequest =
-                    asCheckMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CheckMessageRequest")) }
+                    asCheckMessageRequest(req)
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "CheckMessageRequest")) }
                 val res = getBindingLiquidSdk().checkMessage(checkMessageRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -230,11 +335,12 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val prepareSendRequest =
-                    asPrepareSendRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareSendRequest")) }
+                    asPrepareSendRequest(req)
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PrepareSendRequest")) }
                 val res = getBindingLiquidSdk().prepareSendPayment(prepareSendRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -247,11 +353,12 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val sendPaymentRequest =
-                    asSendPaymentRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "SendPaymentRequest")) }
+                    asSendPaymentRequest(req)
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "SendPaymentRequest")) }
                 val res = getBindingLiquidSdk().sendPayment(sendPaymentRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -265,11 +372,11 @@ class BreezSDKLiquidModule(
             try {
                 val prepareReceiveRequest =
                     asPrepareReceiveRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareReceiveRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PrepareReceiveRequest")) }
                 val res = getBindingLiquidSdk().prepareReceivePayment(prepareReceiveRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -283,11 +390,11 @@ class BreezSDKLiquidModule(
             try {
                 val receivePaymentRequest =
                     asReceivePaymentRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ReceivePaymentRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "ReceivePaymentRequest")) }
                 val res = getBindingLiquidSdk().receivePayment(receivePaymentRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -299,7 +406,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().fetchLightningLimits()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -311,7 +418,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().fetchOnchainLimits()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -325,11 +432,11 @@ class BreezSDKLiquidModule(
             try {
                 val preparePayOnchainRequest =
                     asPreparePayOnchainRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PreparePayOnchainRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PreparePayOnchainRequest")) }
                 val res = getBindingLiquidSdk().preparePayOnchain(preparePayOnchainRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -342,11 +449,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val payOnchainRequest =
-                    asPayOnchainRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PayOnchainRequest")) }
+                    asPayOnchainRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PayOnchainRequest")) }
                 val res = getBindingLiquidSdk().payOnchain(payOnchainRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -360,11 +467,11 @@ class BreezSDKLiquidModule(
             try {
                 val prepareBuyBitcoinRequest =
                     asPrepareBuyBitcoinRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareBuyBitcoinRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PrepareBuyBitcoinRequest")) }
                 val res = getBindingLiquidSdk().prepareBuyBitcoin(prepareBuyBitcoinRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -377,11 +484,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val buyBitcoinRequest =
-                    asBuyBitcoinRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "BuyBitcoinRequest")) }
+                    asBuyBitcoinRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "BuyBitcoinRequest")) }
                 val res = getBindingLiquidSdk().buyBitcoin(buyBitcoinRequest)
                 promise.resolve(res)
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -394,11 +501,12 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val listPaymentsRequest =
-                    asListPaymentsRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ListPaymentsRequest")) }
+                    asListPaymentsRequest(req)
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "ListPaymentsRequest")) }
                 val res = getBindingLiquidSdk().listPayments(listPaymentsRequest)
                 promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -411,11 +519,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val reqTmp =
-                    asGetPaymentRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "GetPaymentRequest")) }
+                    asGetPaymentRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "GetPaymentRequest")) }
                 val res = getBindingLiquidSdk().getPayment(reqTmp)
                 promise.resolve(res?.let { readableMapOf(res) })
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -427,7 +535,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().listRefundables()
                 promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -441,11 +549,11 @@ class BreezSDKLiquidModule(
             try {
                 val prepareRefundRequest =
                     asPrepareRefundRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareRefundRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PrepareRefundRequest")) }
                 val res = getBindingLiquidSdk().prepareRefund(prepareRefundRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -458,11 +566,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val refundRequest =
-                    asRefundRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "RefundRequest")) }
+                    asRefundRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "RefundRequest")) }
                 val res = getBindingLiquidSdk().refund(refundRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -474,7 +582,98 @@ class BreezSDKLiquidModule(
                 getBindingLiquidSdk().rescanOnchainSwaps()
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun forceClaimSwap(
+        swapId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().forceClaimSwap(swapId)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun forceRefundSendSwap(
+        swapId: String,
+        isCooperative: Boolean,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().forceRefundSendSwap(swapId, isCooperative)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun refetchSwapStatus(
+        swapId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().refetchSwapStatus(swapId)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun markSwapFailed(
+        swapId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().markSwapFailed(swapId)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun importSwap(
+        rescueJson: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBindingLiquidSdk().importSwap(rescueJson)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun exportRescueFile(
+        swapId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().exportRescueFile(swapId)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                rejectErr(e, promise)
             }
         }
     }
@@ -486,7 +685,63 @@ class BreezSDKLiquidModule(
                 getBindingLiquidSdk().sync()
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun syncOnce(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val syncOnceRequest =
+                    asSyncOnceRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "SyncOnceRequest")) }
+                val res = getBindingLiquidSdk().syncOnce(syncOnceRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getLastSyncMetrics(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().getLastSyncMetrics()
+                promise.resolve(res?.let { readableMapOf(res) })
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getSwapHistory(
+        swapId: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().getSwapHistory(swapId)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun generateDiagnostics(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().generateDiagnostics()
+                promise.resolve(res)
+            } catch (e: Exception) {
+                rejectErr(e, promise)
             }
         }
     }
@@ -498,7 +753,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().recommendedFees()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -511,11 +766,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val backupRequest =
-                    asBackupRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "BackupRequest")) }
+                    asBackupRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "BackupRequest")) }
                 getBindingLiquidSdk().backup(backupRequest)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -528,11 +783,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val restoreRequest =
-                    asRestoreRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "RestoreRequest")) }
+                    asRestoreRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "RestoreRequest")) }
                 getBindingLiquidSdk().restore(restoreRequest)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -545,7 +800,7 @@ class BreezSDKLiquidModule(
                 bindingLiquidSdk = null
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -559,11 +814,11 @@ class BreezSDKLiquidModule(
             try {
                 val prepareLnUrlPayRequest =
                     asPrepareLnUrlPayRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareLnUrlPayRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "PrepareLnUrlPayRequest")) }
                 val res = getBindingLiquidSdk().prepareLnurlPay(prepareLnUrlPayRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -576,11 +831,11 @@ class BreezSDKLiquidModule(
         executor.execute {
             try {
                 val lnUrlPayRequest =
-                    asLnUrlPayRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "LnUrlPayRequest")) }
+                    asLnUrlPayRequest(req) ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "LnUrlPayRequest")) }
                 val res = getBindingLiquidSdk().lnurlPay(lnUrlPayRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -594,11 +849,11 @@ class BreezSDKLiquidModule(
             try {
                 val lnUrlWithdrawRequest =
                     asLnUrlWithdrawRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "LnUrlWithdrawRequest")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("req", "LnUrlWithdrawRequest")) }
                 val res = getBindingLiquidSdk().lnurlWithdraw(lnUrlWithdrawRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -612,11 +867,11 @@ class BreezSDKLiquidModule(
             try {
                 val lnUrlAuthRequestData =
                     asLnUrlAuthRequestData(reqData)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("reqData", "LnUrlAuthRequestData")) }
+                        ?: run { throw SdkException.Validation(errMissingMandatoryField("reqData", "LnUrlAuthRequestData")) }
                 val res = getBindingLiquidSdk().lnurlAuth(lnUrlAuthRequestData)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -631,7 +886,7 @@ class BreezSDKLiquidModule(
                 getBindingLiquidSdk().registerWebhook(webhookUrl)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -643,7 +898,7 @@ class BreezSDKLiquidModule(
                 getBindingLiquidSdk().unregisterWebhook()
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -655,7 +910,23 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().fetchFiatRates()
                 promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getHistoricalFiatRate(
+        currency: String,
+        timestamp: Int,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBindingLiquidSdk().getHistoricalFiatRate(currency, timestamp.toUInt())
+                promise.resolve(res?.let { readableMapOf(res) })
+            } catch (e: Exception) {
+                rejectErr(e, promise)
             }
         }
     }
@@ -667,7 +938,7 @@ class BreezSDKLiquidModule(
                 val res = getBindingLiquidSdk().listFiatCurrencies()
                 promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
-                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+                rejectErr(e, promise)
             }
         }
     }
@@ -4,13 +4,13 @@ import Foundation
 enum BreezSDKLiquidMapper {
     static func asAesSuccessActionData(aesSuccessActionData: [String: Any?]) throws -> AesSuccessActionData {
         guard let description = aesSuccessActionData["description"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "AesSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "AesSuccessActionData"))
         }
         guard let ciphertext = aesSuccessActionData["ciphertext"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "ciphertext", typeName: "AesSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "ciphertext", typeName: "AesSuccessActionData"))
         }
         guard let iv = aesSuccessActionData["iv"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "iv", typeName: "AesSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "iv", typeName: "AesSuccessActionData"))
         }
 
         return AesSuccessActionData(description: description, ciphertext: ciphertext, iv: iv)
@@ -31,7 +31,7 @@ enum BreezSDKLiquidMapper {
                 var aesSuccessActionData = try asAesSuccessActionData(aesSuccessActionData: val)
                 list.append(aesSuccessActionData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "AesSuccessActionData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "AesSuccessActionData"))
             }
         }
         return list
@@ -43,10 +43,10 @@ enum BreezSDKLiquidMapper {
 
     static func asAesSuccessActionDataDecrypted(aesSuccessActionDataDecrypted: [String: Any?]) throws -> AesSuccessActionDataDecrypted {
         guard let description = aesSuccessActionDataDecrypted["description"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "AesSuccessActionDataDecrypted"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "AesSuccessActionDataDecrypted"))
         }
         guard let plaintext = aesSuccessActionDataDecrypted["plaintext"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "plaintext", typeName: "AesSuccessActionDataDecrypted"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "plaintext", typeName: "AesSuccessActionDataDecrypted"))
         }
 
         return AesSuccessActionDataDecrypted(description: description, plaintext: plaintext)
@@ -66,7 +66,7 @@ enum BreezSDKLiquidMapper {
                 var aesSuccessActionDataDecrypted = try asAesSuccessActionDataDecrypted(aesSuccessActionDataDecrypted: val)
                 list.append(aesSuccessActionDataDecrypted)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "AesSuccessActionDataDecrypted"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "AesSuccessActionDataDecrypted"))
             }
         }
         return list
@@ -80,7 +80,7 @@ enum BreezSDKLiquidMapper {
         var backupPath: String?
         if hasNonNilKey(data: backupRequest, key: "backupPath") {
             guard let backupPathTmp = backupRequest["backupPath"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "backupPath"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "backupPath"))
             }
             backupPath = backupPathTmp
         }
@@ -101,7 +101,7 @@ enum BreezSDKLiquidMapper {
                 var backupRequest = try asBackupRequest(backupRequest: val)
                 list.append(backupRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "BackupRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "BackupRequest"))
             }
         }
         return list
@@ -113,31 +113,31 @@ enum BreezSDKLiquidMapper {
 
     static func asBitcoinAddressData(bitcoinAddressData: [String: Any?]) throws -> BitcoinAddressData {
         guard let address = bitcoinAddressData["address"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "BitcoinAddressData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "BitcoinAddressData"))
         }
         guard let networkTmp = bitcoinAddressData["network"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "BitcoinAddressData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "network", typeName: "BitcoinAddressData"))
         }
         let network = try asNetwork(network: networkTmp)
 
         var amountSat: UInt64?
         if hasNonNilKey(data: bitcoinAddressData, key: "amountSat") {
             guard let amountSatTmp = bitcoinAddressData["amountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountSat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "amountSat"))
             }
             amountSat = amountSatTmp
         }
         var label: String?
         if hasNonNilKey(data: bitcoinAddressData, key: "label") {
             guard let labelTmp = bitcoinAddressData["label"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "label"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "label"))
             }
             label = labelTmp
         }
         var message: String?
         if hasNonNilKey(data: bitcoinAddressData, key: "message") {
             guard let messageTmp = bitcoinAddressData["message"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "message"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "message"))
             }
             message = messageTmp
         }
@@ -162,7 +162,7 @@ enum BreezSDKLiquidMapper {
                 var bitcoinAddressData = try asBitcoinAddressData(bitcoinAddressData: val)
                 list.append(bitcoinAddressData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "BitcoinAddressData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "BitcoinAddressData"))
             }
         }
         return list
@@ -174,14 +174,14 @@ enum BreezSDKLiquidMapper {
 
     static func asBuyBitcoinRequest(buyBitcoinRequest: [String: Any?]) throws -> BuyBitcoinRequest {
         guard let prepareResponseTmp = buyBitcoinRequest["prepareResponse"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "BuyBitcoinRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "BuyBitcoinRequest"))
         }
         let prepareResponse = try asPrepareBuyBitcoinResponse(prepareBuyBitcoinResponse: prepareResponseTmp)
 
         var redirectUrl: String?
         if hasNonNilKey(data: buyBitcoinRequest, key: "redirectUrl") {
             guard let redirectUrlTmp = buyBitcoinRequest["redirectUrl"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "redirectUrl"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "redirectUrl"))
             }
             redirectUrl = redirectUrlTmp
         }
@@ -203,7 +203,7 @@ enum BreezSDKLiquidMapper {
                 var buyBitcoinRequest = try asBuyBitcoinRequest(buyBitcoinRequest: val)
                 list.append(buyBitcoinRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "BuyBitcoinRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "BuyBitcoinRequest"))
             }
         }
         return list
@@ -215,13 +215,13 @@ enum BreezSDKLiquidMapper {
 
     static func asCheckMessageRequest(checkMessageRequest: [String: Any?]) throws -> CheckMessageRequest {
         guard let message = checkMessageRequest["message"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "CheckMessageRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "message", typeName: "CheckMessageRequest"))
         }
         guard let pubkey = checkMessageRequest["pubkey"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pubkey", typeName: "CheckMessageRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pubkey", typeName: "CheckMessageRequest"))
         }
         guard let signature = checkMessageRequest["signature"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "CheckMessageRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "signature", typeName: "CheckMessageRequest"))
         }
 
         return CheckMessageRequest(message: message, pubkey: pubkey, signature: signature)
@@ -242,7 +242,7 @@ enum BreezSDKLiquidMapper {
                 var checkMessageRequest = try asCheckMessageRequest(checkMessageRequest: val)
                 list.append(checkMessageRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "CheckMessageRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "CheckMessageRequest"))
             }
         }
         return list
@@ -254,7 +254,7 @@ enum BreezSDKLiquidMapper {
 
     static func asCheckMessageResponse(checkMessageResponse: [String: Any?]) throws -> CheckMessageResponse {
         guard let isValid = checkMessageResponse["isValid"] as? Bool else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "isValid", typeName: "CheckMessageResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "isValid", typeName: "CheckMessageResponse"))
         }
 
         return CheckMessageResponse(isValid: isValid)
@@ -273,7 +273,7 @@ enum BreezSDKLiquidMapper {
                 var checkMessageResponse = try asCheckMessageResponse(checkMessageResponse: val)
                 list.append(checkMessageResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "CheckMessageResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "CheckMessageResponse"))
             }
         }
         return list
@@ -284,58 +284,132 @@ enum BreezSDKLiquidMapper {
     }
 
     static func asConfig(config: [String: Any?]) throws -> Config {
-        guard let liquidElectrumUrl = config["liquidElectrumUrl"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "liquidElectrumUrl", typeName: "Config"))
+        guard let liquidChainBackendTmp = config["liquidChainBackend"] as? [String: Any?] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "liquidChainBackend", typeName: "Config"))
         }
-        guard let bitcoinElectrumUrl = config["bitcoinElectrumUrl"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bitcoinElectrumUrl", typeName: "Config"))
+        let liquidChainBackend = try asChainBackend(chainBackend: liquidChainBackendTmp)
+
+        guard let bitcoinChainBackendTmp = config["bitcoinChainBackend"] as? [String: Any?] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "bitcoinChainBackend", typeName: "Config"))
         }
+        let bitcoinChainBackend = try asChainBackend(chainBackend: bitcoinChainBackendTmp)
+
         guard let mempoolspaceUrl = config["mempoolspaceUrl"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "mempoolspaceUrl", typeName: "Config"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "mempoolspaceUrl", typeName: "Config"))
         }
         guard let workingDir = config["workingDir"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "workingDir", typeName: "Config"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "workingDir", typeName: "Config"))
         }
         guard let networkTmp = config["network"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "Config"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "network", typeName: "Config"))
         }
         let network = try asLiquidNetwork(liquidNetwork: networkTmp)
 
         guard let paymentTimeoutSec = config["paymentTimeoutSec"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentTimeoutSec", typeName: "Config"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentTimeoutSec", typeName: "Config"))
         }
         guard let zeroConfMinFeeRateMsat = config["zeroConfMinFeeRateMsat"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "zeroConfMinFeeRateMsat", typeName: "Config"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "zeroConfMinFeeRateMsat", typeName: "Config"))
         }
         var breezApiKey: String?
         if hasNonNilKey(data: config, key: "breezApiKey") {
             guard let breezApiKeyTmp = config["breezApiKey"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "breezApiKey"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "breezApiKey"))
             }
             breezApiKey = breezApiKeyTmp
         }
         var cacheDir: String?
         if hasNonNilKey(data: config, key: "cacheDir") {
             guard let cacheDirTmp = config["cacheDir"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "cacheDir"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "cacheDir"))
             }
             cacheDir = cacheDirTmp
         }
         var zeroConfMaxAmountSat: UInt64?
         if hasNonNilKey(data: config, key: "zeroConfMaxAmountSat") {
             guard let zeroConfMaxAmountSatTmp = config["zeroConfMaxAmountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "zeroConfMaxAmountSat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "zeroConfMaxAmountSat"))
             }
             zeroConfMaxAmountSat = zeroConfMaxAmountSatTmp
         }
+        var fiatRateHistoryCurrencies: [String]?
+        if hasNonNilKey(data: config, key: "fiatRateHistoryCurrencies") {
+            guard let fiatRateHistoryCurrenciesTmp = config["fiatRateHistoryCurrencies"] as? [String] else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "fiatRateHistoryCurrencies"))
+            }
+            fiatRateHistoryCurrencies = fiatRateHistoryCurrenciesTmp
+        }
+        var eventWebhookUrls: [String]?
+        if hasNonNilKey(data: config, key: "eventWebhookUrls") {
+            guard let eventWebhookUrlsTmp = config["eventWebhookUrls"] as? [String] else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "eventWebhookUrls"))
+            }
+            eventWebhookUrls = eventWebhookUrlsTmp
+        }
+        var liquidFallbackChainBackends: [ChainBackend]?
+        if let liquidFallbackChainBackendsTmp = config["liquidFallbackChainBackends"] as? [[String: Any?]] {
+            liquidFallbackChainBackends = try asChainBackendList(arr: liquidFallbackChainBackendsTmp)
+        }
+
+        var bitcoinFallbackChainBackends: [ChainBackend]?
+        if let bitcoinFallbackChainBackendsTmp = config["bitcoinFallbackChainBackends"] as? [[String: Any?]] {
+            bitcoinFallbackChainBackends = try asChainBackendList(arr: bitcoinFallbackChainBackendsTmp)
+        }
+
+        var chainQuorumMinAmountSat: UInt64?
+        if hasNonNilKey(data: config, key: "chainQuorumMinAmountSat") {
+            guard let chainQuorumMinAmountSatTmp = config["chainQuorumMinAmountSat"] as? UInt64 else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "chainQuorumMinAmountSat"))
+            }
+            chainQuorumMinAmountSat = chainQuorumMinAmountSatTmp
+        }
+        var swapperUrl: String?
+        if hasNonNilKey(data: config, key: "swapperUrl") {
+            guard let swapperUrlTmp = config["swapperUrl"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "swapperUrl"))
+            }
+            swapperUrl = swapperUrlTmp
+        }
+        var swapperWsUrl: String?
+        if hasNonNilKey(data: config, key: "swapperWsUrl") {
+            guard let swapperWsUrlTmp = config["swapperWsUrl"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "swapperWsUrl"))
+            }
+            swapperWsUrl = swapperWsUrlTmp
+        }
+        var swapperReferralId: String?
+        if hasNonNilKey(data: config, key: "swapperReferralId") {
+            guard let swapperReferralIdTmp = config["swapperReferralId"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "swapperReferralId"))
+            }
+            swapperReferralId = swapperReferralIdTmp
+        }
+        var lbtcAssetId: String?
+        if hasNonNilKey(data: config, key: "lbtcAssetId") {
+            guard let lbtcAssetIdTmp = config["lbtcAssetId"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "lbtcAssetId"))
+            }
+            lbtcAssetId = lbtcAssetIdTmp
+        }
+        var liquidGenesisHash: String?
+        if hasNonNilKey(data: config, key: "liquidGenesisHash") {
+            guard let liquidGenesisHashTmp = config["liquidGenesisHash"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "liquidGenesisHash"))
+            }
+            liquidGenesisHash = liquidGenesisHashTmp
+        }
+        var schedulerIntervals: SchedulerIntervals?
+        if let schedulerIntervalsTmp = config["schedulerIntervals"] as? [String: Any?] {
+            schedulerIntervals = try asSchedulerIntervals(schedulerIntervals: schedulerIntervalsTmp)
+        }
 
-        return Config(liquidElectrumUrl: liquidElectrumUrl, bitcoinElectrumUrl: bitcoinElectrumUrl, mempoolspaceUrl: mempoolspaceUrl, workingDir: workingDir, network: network, paymentTimeoutSec: paymentTimeoutSec, zeroConfMinFeeRateMsat: zeroConfMinFeeRateMsat, breezApiKey: breezApiKey, cacheDir: cacheDir, zeroConfMaxAmountSat: zeroConfMaxAmountSat)
+        return Config(liquidChainBackend: liquidChainBackend, bitcoinChainBackend: bitcoinChainBackend, mempoolspaceUrl: mempoolspaceUrl, workingDir: workingDir, network: network, paymentTimeoutSec: paymentTimeoutSec, zeroConfMinFeeRateMsat: zeroConfMinFeeRateMsat, breezApiKey: breezApiKey, cacheDir: cacheDir, zeroConfMaxAmountSat: zeroConfMaxAmountSat, fiatRateHistoryCurrencies: fiatRateHistoryCurrencies, eventWebhookUrls: eventWebhookUrls, liquidFallbackChainBackends: liquidFallbackChainBackends, bitcoinFallbackChainBackends: bitcoinFallbackChainBackends, chainQuorumMinAmountSat: chainQuorumMinAmountSat, swapperUrl: swapperUrl, swapperWsUrl: swapperWsUrl, swapperReferralId: swapperReferralId, lbtcAssetId: lbtcAssetId, liquidGenesisHash: liquidGenesisHash, schedulerIntervals: schedulerIntervals)
     }
 
     static func dictionaryOf(config: Config) -> [String: Any?] {
         return [
-            "liquidElectrumUrl": config.liquidElectrumUrl,
-            "bitcoinElectrumUrl": config.bitcoinElectrumUrl,
+            "liquidChainBackend": dictionaryOf(chainBackend: config.liquidChainBackend),
+            "bitcoinChainBackend": dictionaryOf(chainBackend: config.bitcoinChainBackend),
             "mempoolspaceUrl": config.mempoolspaceUrl,
             "workingDir": config.workingDir,
             "network": valueOf(liquidNetwork: config.network),
@@ -344,6 +418,17 @@ enum BreezSDKLiquidMapper {
             "breezApiKey": config.breezApiKey == nil ? nil : config.breezApiKey,
             "cacheDir": config.cacheDir == nil ? nil : config.cacheDir,
             "zeroConfMaxAmountSat": config.zeroConfMaxAmountSat == nil ? nil : config.zeroConfMaxAmountSat,
+            "fiatRateHistoryCurrencies": config.fiatRateHistoryCurrencies == nil ? nil : config.fiatRateHistoryCurrencies,
+            "eventWebhookUrls": config.eventWebhookUrls == nil ? nil : config.eventWebhookUrls,
+            "liquidFallbackChainBackends": config.liquidFallbackChainBackends == nil ? nil : arrayOf(chainBackendList: config.liquidFallbackChainBackends!),
+            "bitcoinFallbackChainBackends": config.bitcoinFallbackChainBackends == nil ? nil : arrayOf(chainBackendList: config.bitcoinFallbackChainBackends!),
+            "chainQuorumMinAmountSat": config.chainQuorumMinAmountSat == nil ? nil : config.chainQuorumMinAmountSat,
+            "swapperUrl": config.swapperUrl == nil ? nil : config.swapperUrl,
+            "swapperWsUrl": config.swapperWsUrl == nil ? nil : config.swapperWsUrl,
+            "swapperReferralId": config.swapperReferralId == nil ? nil : config.swapperReferralId,
+            "lbtcAssetId": config.lbtcAssetId == nil ? nil : config.lbtcAssetId,
+            "liquidGenesisHash": config.liquidGenesisHash == nil ? nil : config.liquidGenesisHash,
+            "schedulerIntervals": config.schedulerIntervals == nil ? nil : dictionaryOf(schedulerIntervals: config.schedulerIntervals!),
         ]
     }
 
@@ -354,7 +439,7 @@ enum BreezSDKLiquidMapper {
                 var config = try asConfig(config: val)
                 list.append(config)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Config"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Config"))
             }
         }
         return list
@@ -366,12 +451,12 @@ enum BreezSDKLiquidMapper {
 
     static func asConnectRequest(connectRequest: [String: Any?]) throws -> ConnectRequest {
         guard let configTmp = connectRequest["config"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "config", typeName: "ConnectRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "config", typeName: "ConnectRequest"))
         }
         let config = try asConfig(config: configTmp)
 
         guard let mnemonic = connectRequest["mnemonic"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "mnemonic", typeName: "ConnectRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "mnemonic", typeName: "ConnectRequest"))
         }
 
         return ConnectRequest(config: config, mnemonic: mnemonic)
@@ -391,7 +476,7 @@ enum BreezSDKLiquidMapper {
                 var connectRequest = try asConnectRequest(connectRequest: val)
                 list.append(connectRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ConnectRequest"))
             }
         }
         return list
@@ -403,7 +488,7 @@ enum BreezSDKLiquidMapper {
 
     static func asConnectWithSignerRequest(connectWithSignerRequest: [String: Any?]) throws -> ConnectWithSignerRequest {
         guard let configTmp = connectWithSignerRequest["config"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "config", typeName: "ConnectWithSignerRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "config", typeName: "ConnectWithSignerRequest"))
         }
         let config = try asConfig(config: configTmp)
 
@@ -423,7 +508,7 @@ enum BreezSDKLiquidMapper {
                 var connectWithSignerRequest = try asConnectWithSignerRequest(connectWithSignerRequest: val)
                 list.append(connectWithSignerRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectWithSignerRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ConnectWithSignerRequest"))
             }
         }
         return list
@@ -435,15 +520,15 @@ enum BreezSDKLiquidMapper {
 
     static func asCurrencyInfo(currencyInfo: [String: Any?]) throws -> CurrencyInfo {
         guard let name = currencyInfo["name"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "CurrencyInfo"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "name", typeName: "CurrencyInfo"))
         }
         guard let fractionSize = currencyInfo["fractionSize"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fractionSize", typeName: "CurrencyInfo"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fractionSize", typeName: "CurrencyInfo"))
         }
         var spacing: UInt32?
         if hasNonNilKey(data: currencyInfo, key: "spacing") {
             guard let spacingTmp = currencyInfo["spacing"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "spacing"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "spacing"))
             }
             spacing = spacingTmp
         }
@@ -458,12 +543,12 @@ enum BreezSDKLiquidMapper {
         }
 
         guard let localizedNameTmp = currencyInfo["localizedName"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localizedName", typeName: "CurrencyInfo"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "localizedName", typeName: "CurrencyInfo"))
         }
         let localizedName = try asLocalizedNameList(arr: localizedNameTmp)
 
         guard let localeOverridesTmp = currencyInfo["localeOverrides"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localeOverrides", typeName: "CurrencyInfo"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "localeOverrides", typeName: "CurrencyInfo"))
         }
         let localeOverrides = try asLocaleOverridesList(arr: localeOverridesTmp)
 
@@ -489,7 +574,7 @@ enum BreezSDKLiquidMapper {
                 var currencyInfo = try asCurrencyInfo(currencyInfo: val)
                 list.append(currencyInfo)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "CurrencyInfo"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "CurrencyInfo"))
             }
         }
         return list
@@ -499,12 +584,122 @@ enum BreezSDKLiquidMapper {
         return currencyInfoList.map { v -> [String: Any?] in return dictionaryOf(currencyInfo: v) }
     }
 
+    static func asErrorInfo(errorInfo: [String: Any?]) throws -> ErrorInfo {
+        guard let code = errorInfo["code"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "code", typeName: "ErrorInfo"))
+        }
+        guard let retryable = errorInfo["retryable"] as? Bool else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "retryable", typeName: "ErrorInfo"))
+        }
+
+        return ErrorInfo(code: code, retryable: retryable)
+    }
+
+    static func dictionaryOf(errorInfo: ErrorInfo) -> [String: Any?] {
+        return [
+            "code": errorInfo.code,
+            "retryable": errorInfo.retryable,
+        ]
+    }
+
+    static func asErrorInfoList(arr: [Any]) throws -> [ErrorInfo] {
+        var list = [ErrorInfo]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var errorInfo = try asErrorInfo(errorInfo: val)
+                list.append(errorInfo)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ErrorInfo"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(errorInfoList: [ErrorInfo]) -> [Any] {
+        return errorInfoList.map { v -> [String: Any?] in return dictionaryOf(errorInfo: v) }
+    }
+
+    static func asEventLogEntry(eventLogEntry: [String: Any?]) throws -> EventLogEntry {
+        guard let seq = eventLogEntry["seq"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "seq", typeName: "EventLogEntry"))
+        }
+        guard let createdAt = eventLogEntry["createdAt"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "createdAt", typeName: "EventLogEntry"))
+        }
+        guard let eventTmp = eventLogEntry["event"] as? [String: Any?] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "event", typeName: "EventLogEntry"))
+        }
+        let event = try asSdkEvent(sdkEvent: eventTmp)
+
+        return EventLogEntry(seq: seq, createdAt: createdAt, event: event)
+    }
+
+    static func dictionaryOf(eventLogEntry: EventLogEntry) -> [String: Any?] {
+        return [
+            "seq": eventLogEntry.seq,
+            "createdAt": eventLogEntry.createdAt,
+            "event": dictionaryOf(sdkEvent: eventLogEntry.event),
+        ]
+    }
+
+    static func asEventLogEntryList(arr: [Any]) throws -> [EventLogEntry] {
+        var list = [EventLogEntry]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var eventLogEntry = try asEventLogEntry(eventLogEntry: val)
+                list.append(eventLogEntry)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "EventLogEntry"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(eventLogEntryList: [EventLogEntry]) -> [Any] {
+        return eventLogEntryList.map { v -> [String: Any?] in return dictionaryOf(eventLogEntry: v) }
+    }
+
+    static func asFiatAmount(fiatAmount: [String: Any?]) throws -> FiatAmount {
+        guard let currency = fiatAmount["currency"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "currency", typeName: "FiatAmount"))
+        }
+        guard let amount = fiatAmount["amount"] as? Double else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amount", typeName: "FiatAmount"))
+        }
+
+        return FiatAmount(currency: currency, amount: amount)
+    }
+
+    static func dictionaryOf(fiatAmount: FiatAmount) -> [String: Any?] {
+        return [
+            "currency": fiatAmount.currency,
+            "amount": fiatAmount.amount,
+        ]
+    }
+
+    static func asFiatAmountList(arr: [Any]) throws -> [FiatAmount] {
+        var list = [FiatAmount]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var fiatAmount = try asFiatAmount(fiatAmount: val)
+                list.append(fiatAmount)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "FiatAmount"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(fiatAmountList: [FiatAmount]) -> [Any] {
+        return fiatAmountList.map { v -> [String: Any?] in return dictionaryOf(fiatAmount: v) }
+    }
+
     static func asFiatCurrency(fiatCurrency: [String: Any?]) throws -> FiatCurrency {
         guard let id = fiatCurrency["id"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "FiatCurrency"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "id", typeName: "FiatCurrency"))
         }
         guard let infoTmp = fiatCurrency["info"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "info", typeName: "FiatCurrency"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "info", typeName: "FiatCurrency"))
         }
         let info = try asCurrencyInfo(currencyInfo: infoTmp)
 
@@ -525,7 +720,7 @@ enum BreezSDKLiquidMapper {
                 var fiatCurrency = try asFiatCurrency(fiatCurrency: val)
                 list.append(fiatCurrency)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "FiatCurrency"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "FiatCurrency"))
             }
         }
         return list
@@ -537,19 +732,19 @@ enum BreezSDKLiquidMapper {
 
     static func asGetInfoResponse(getInfoResponse: [String: Any?]) throws -> GetInfoResponse {
         guard let balanceSat = getInfoResponse["balanceSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "balanceSat", typeName: "GetInfoResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "balanceSat", typeName: "GetInfoResponse"))
         }
         guard let pendingSendSat = getInfoResponse["pendingSendSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pendingSendSat", typeName: "GetInfoResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pendingSendSat", typeName: "GetInfoResponse"))
         }
         guard let pendingReceiveSat = getInfoResponse["pendingReceiveSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pendingReceiveSat", typeName: "GetInfoResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pendingReceiveSat", typeName: "GetInfoResponse"))
         }
         guard let fingerprint = getInfoResponse["fingerprint"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fingerprint", typeName: "GetInfoResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fingerprint", typeName: "GetInfoResponse"))
         }
         guard let pubkey = getInfoResponse["pubkey"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pubkey", typeName: "GetInfoResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pubkey", typeName: "GetInfoResponse"))
         }
 
         return GetInfoResponse(balanceSat: balanceSat, pendingSendSat: pendingSendSat, pendingReceiveSat: pendingReceiveSat, fingerprint: fingerprint, pubkey: pubkey)
@@ -572,7 +767,7 @@ enum BreezSDKLiquidMapper {
                 var getInfoResponse = try asGetInfoResponse(getInfoResponse: val)
                 list.append(getInfoResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "GetInfoResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "GetInfoResponse"))
             }
         }
         return list
@@ -584,56 +779,56 @@ enum BreezSDKLiquidMapper {
 
     static func asLnInvoice(lnInvoice: [String: Any?]) throws -> LnInvoice {
         guard let bolt11 = lnInvoice["bolt11"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "bolt11", typeName: "LnInvoice"))
         }
         guard let networkTmp = lnInvoice["network"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "network", typeName: "LnInvoice"))
         }
         let network = try asNetwork(network: networkTmp)
 
         guard let payeePubkey = lnInvoice["payeePubkey"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payeePubkey", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "payeePubkey", typeName: "LnInvoice"))
         }
         guard let paymentHash = lnInvoice["paymentHash"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnInvoice"))
         }
         var description: String?
         if hasNonNilKey(data: lnInvoice, key: "description") {
             guard let descriptionTmp = lnInvoice["description"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "description"))
             }
             description = descriptionTmp
         }
         var descriptionHash: String?
         if hasNonNilKey(data: lnInvoice, key: "descriptionHash") {
             guard let descriptionHashTmp = lnInvoice["descriptionHash"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "descriptionHash"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "descriptionHash"))
             }
             descriptionHash = descriptionHashTmp
         }
         var amountMsat: UInt64?
         if hasNonNilKey(data: lnInvoice, key: "amountMsat") {
             guard let amountMsatTmp = lnInvoice["amountMsat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "amountMsat"))
             }
             amountMsat = amountMsatTmp
         }
         guard let timestamp = lnInvoice["timestamp"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "timestamp", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "timestamp", typeName: "LnInvoice"))
         }
         guard let expiry = lnInvoice["expiry"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiry", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "expiry", typeName: "LnInvoice"))
         }
         guard let routingHintsTmp = lnInvoice["routingHints"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "routingHints", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "routingHints", typeName: "LnInvoice"))
         }
         let routingHints = try asRouteHintList(arr: routingHintsTmp)
 
         guard let paymentSecret = lnInvoice["paymentSecret"] as? [UInt8] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentSecret", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentSecret", typeName: "LnInvoice"))
         }
         guard let minFinalCltvExpiryDelta = lnInvoice["minFinalCltvExpiryDelta"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minFinalCltvExpiryDelta", typeName: "LnInvoice"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "minFinalCltvExpiryDelta", typeName: "LnInvoice"))
         }
 
         return LnInvoice(bolt11: bolt11, network: network, payeePubkey: payeePubkey, paymentHash: paymentHash, description: description, descriptionHash: descriptionHash, amountMsat: amountMsat, timestamp: timestamp, expiry: expiry, routingHints: routingHints, paymentSecret: paymentSecret, minFinalCltvExpiryDelta: minFinalCltvExpiryDelta)
@@ -663,7 +858,7 @@ enum BreezSDKLiquidMapper {
                 var lnInvoice = try asLnInvoice(lnInvoice: val)
                 list.append(lnInvoice)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnInvoice"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnInvoice"))
             }
         }
         return list
@@ -675,27 +870,27 @@ enum BreezSDKLiquidMapper {
 
     static func asLnOffer(lnOffer: [String: Any?]) throws -> LnOffer {
         guard let offer = lnOffer["offer"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "LnOffer"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "offer", typeName: "LnOffer"))
         }
         guard let chains = lnOffer["chains"] as? [String] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "chains", typeName: "LnOffer"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "chains", typeName: "LnOffer"))
         }
         guard let pathsTmp = lnOffer["paths"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paths", typeName: "LnOffer"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paths", typeName: "LnOffer"))
         }
         let paths = try asLnOfferBlindedPathList(arr: pathsTmp)
 
         var description: String?
         if hasNonNilKey(data: lnOffer, key: "description") {
             guard let descriptionTmp = lnOffer["description"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "description"))
             }
             description = descriptionTmp
         }
         var signingPubkey: String?
         if hasNonNilKey(data: lnOffer, key: "signingPubkey") {
             guard let signingPubkeyTmp = lnOffer["signingPubkey"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "signingPubkey"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "signingPubkey"))
             }
             signingPubkey = signingPubkeyTmp
         }
@@ -707,14 +902,14 @@ enum BreezSDKLiquidMapper {
         var absoluteExpiry: UInt64?
         if hasNonNilKey(data: lnOffer, key: "absoluteExpiry") {
             guard let absoluteExpiryTmp = lnOffer["absoluteExpiry"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "absoluteExpiry"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "absoluteExpiry"))
             }
             absoluteExpiry = absoluteExpiryTmp
         }
         var issuer: String?
         if hasNonNilKey(data: lnOffer, key: "issuer") {
             guard let issuerTmp = lnOffer["issuer"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "issuer"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "issuer"))
             }
             issuer = issuerTmp
         }
@@ -742,7 +937,7 @@ enum BreezSDKLiquidMapper {
                 var lnOffer = try asLnOffer(lnOffer: val)
                 list.append(lnOffer)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnOffer"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnOffer"))
             }
         }
         return list
@@ -754,22 +949,31 @@ enum BreezSDKLiquidMapper {
 
     static func asLightningPaymentLimitsResponse(lightningPaymentLimitsResponse: [String: Any?]) throws -> LightningPaymentLimitsResponse {
         guard let sendTmp = lightningPaymentLimitsResponse["send"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "send", typeName: "LightningPaymentLimitsResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "send", typeName: "LightningPaymentLimitsResponse"))
         }
         let send = try asLimits(limits: sendTmp)
 
         guard let receiveTmp = lightningPaymentLimitsResponse["receive"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receive", typeName: "LightningPaymentLimitsResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "receive", typeName: "LightningPaymentLimitsResponse"))
         }
         let receive = try asLimits(limits: receiveTmp)
 
-        return LightningPaymentLimitsResponse(send: send, receive: receive)
+        var cacheAgeSecs: UInt64?
+        if hasNonNilKey(data: lightningPaymentLimitsResponse, key: "cacheAgeSecs") {
+            guard let cacheAgeSecsTmp = lightningPaymentLimitsResponse["cacheAgeSecs"] as? UInt64 else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "cacheAgeSecs"))
+            }
+            cacheAgeSecs = cacheAgeSecsTmp
+        }
+
+        return LightningPaymentLimitsResponse(send: send, receive: receive, cacheAgeSecs: cacheAgeSecs)
     }
 
     static func dictionaryOf(lightningPaymentLimitsResponse: LightningPaymentLimitsResponse) -> [String: Any?] {
         return [
             "send": dictionaryOf(limits: lightningPaymentLimitsResponse.send),
             "receive": dictionaryOf(limits: lightningPaymentLimitsResponse.receive),
+            "cacheAgeSecs": lightningPaymentLimitsResponse.cacheAgeSecs == nil ? nil : lightningPaymentLimitsResponse.cacheAgeSecs,
         ]
     }
 
@@ -780,7 +984,7 @@ enum BreezSDKLiquidMapper {
                 var lightningPaymentLimitsResponse = try asLightningPaymentLimitsResponse(lightningPaymentLimitsResponse: val)
                 list.append(lightningPaymentLimitsResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LightningPaymentLimitsResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LightningPaymentLimitsResponse"))
             }
         }
         return list
@@ -792,13 +996,13 @@ enum BreezSDKLiquidMapper {
 
     static func asLimits(limits: [String: Any?]) throws -> Limits {
         guard let minSat = limits["minSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minSat", typeName: "Limits"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "minSat", typeName: "Limits"))
         }
         guard let maxSat = limits["maxSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxSat", typeName: "Limits"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "maxSat", typeName: "Limits"))
         }
         guard let maxZeroConfSat = limits["maxZeroConfSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxZeroConfSat", typeName: "Limits"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "maxZeroConfSat", typeName: "Limits"))
         }
 
         return Limits(minSat: minSat, maxSat: maxSat, maxZeroConfSat: maxZeroConfSat)
@@ -819,7 +1023,7 @@ enum BreezSDKLiquidMapper {
                 var limits = try asLimits(limits: val)
                 list.append(limits)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Limits"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Limits"))
             }
         }
         return list
@@ -831,38 +1035,38 @@ enum BreezSDKLiquidMapper {
 
     static func asLiquidAddressData(liquidAddressData: [String: Any?]) throws -> LiquidAddressData {
         guard let address = liquidAddressData["address"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "LiquidAddressData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "LiquidAddressData"))
         }
         guard let networkTmp = liquidAddressData["network"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "LiquidAddressData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "network", typeName: "LiquidAddressData"))
         }
         let network = try asNetwork(network: networkTmp)
 
         var assetId: String?
         if hasNonNilKey(data: liquidAddressData, key: "assetId") {
             guard let assetIdTmp = liquidAddressData["assetId"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "assetId"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "assetId"))
             }
             assetId = assetIdTmp
         }
         var amountSat: UInt64?
         if hasNonNilKey(data: liquidAddressData, key: "amountSat") {
             guard let amountSatTmp = liquidAddressData["amountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountSat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "amountSat"))
             }
             amountSat = amountSatTmp
         }
         var label: String?
         if hasNonNilKey(data: liquidAddressData, key: "label") {
             guard let labelTmp = liquidAddressData["label"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "label"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "label"))
             }
             label = labelTmp
         }
         var message: String?
         if hasNonNilKey(data: liquidAddressData, key: "message") {
             guard let messageTmp = liquidAddressData["message"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "message"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "message"))
             }
             message = messageTmp
         }
@@ -888,7 +1092,7 @@ enum BreezSDKLiquidMapper {
                 var liquidAddressData = try asLiquidAddressData(liquidAddressData: val)
                 list.append(liquidAddressData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LiquidAddressData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LiquidAddressData"))
             }
         }
         return list
@@ -907,28 +1111,28 @@ enum BreezSDKLiquidMapper {
         var fromTimestamp: Int64?
         if hasNonNilKey(data: listPaymentsRequest, key: "fromTimestamp") {
             guard let fromTimestampTmp = listPaymentsRequest["fromTimestamp"] as? Int64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "fromTimestamp"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "fromTimestamp"))
             }
             fromTimestamp = fromTimestampTmp
         }
         var toTimestamp: Int64?
         if hasNonNilKey(data: listPaymentsRequest, key: "toTimestamp") {
             guard let toTimestampTmp = listPaymentsRequest["toTimestamp"] as? Int64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "toTimestamp"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "toTimestamp"))
             }
             toTimestamp = toTimestampTmp
         }
         var offset: UInt32?
         if hasNonNilKey(data: listPaymentsRequest, key: "offset") {
             guard let offsetTmp = listPaymentsRequest["offset"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "offset"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "offset"))
             }
             offset = offsetTmp
         }
         var limit: UInt32?
         if hasNonNilKey(data: listPaymentsRequest, key: "limit") {
             guard let limitTmp = listPaymentsRequest["limit"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "limit"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "limit"))
             }
             limit = limitTmp
         }
@@ -958,7 +1162,7 @@ enum BreezSDKLiquidMapper {
                 var listPaymentsRequest = try asListPaymentsRequest(listPaymentsRequest: val)
                 list.append(listPaymentsRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ListPaymentsRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ListPaymentsRequest"))
             }
         }
         return list
@@ -970,7 +1174,7 @@ enum BreezSDKLiquidMapper {
 
     static func asLnOfferBlindedPath(lnOfferBlindedPath: [String: Any?]) throws -> LnOfferBlindedPath {
         guard let blindedHops = lnOfferBlindedPath["blindedHops"] as? [String] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "blindedHops", typeName: "LnOfferBlindedPath"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "blindedHops", typeName: "LnOfferBlindedPath"))
         }
 
         return LnOfferBlindedPath(blindedHops: blindedHops)
@@ -989,7 +1193,7 @@ enum BreezSDKLiquidMapper {
                 var lnOfferBlindedPath = try asLnOfferBlindedPath(lnOfferBlindedPath: val)
                 list.append(lnOfferBlindedPath)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnOfferBlindedPath"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnOfferBlindedPath"))
             }
         }
         return list
@@ -1001,18 +1205,18 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlAuthRequestData(lnUrlAuthRequestData: [String: Any?]) throws -> LnUrlAuthRequestData {
         guard let k1 = lnUrlAuthRequestData["k1"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "k1", typeName: "LnUrlAuthRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "k1", typeName: "LnUrlAuthRequestData"))
         }
         guard let domain = lnUrlAuthRequestData["domain"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "domain", typeName: "LnUrlAuthRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "domain", typeName: "LnUrlAuthRequestData"))
         }
         guard let url = lnUrlAuthRequestData["url"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "LnUrlAuthRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "LnUrlAuthRequestData"))
         }
         var action: String?
         if hasNonNilKey(data: lnUrlAuthRequestData, key: "action") {
             guard let actionTmp = lnUrlAuthRequestData["action"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "action"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "action"))
             }
             action = actionTmp
         }
@@ -1036,7 +1240,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlAuthRequestData = try asLnUrlAuthRequestData(lnUrlAuthRequestData: val)
                 list.append(lnUrlAuthRequestData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlAuthRequestData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlAuthRequestData"))
             }
         }
         return list
@@ -1048,7 +1252,7 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlErrorData(lnUrlErrorData: [String: Any?]) throws -> LnUrlErrorData {
         guard let reason = lnUrlErrorData["reason"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reason", typeName: "LnUrlErrorData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "reason", typeName: "LnUrlErrorData"))
         }
 
         return LnUrlErrorData(reason: reason)
@@ -1067,7 +1271,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlErrorData = try asLnUrlErrorData(lnUrlErrorData: val)
                 list.append(lnUrlErrorData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlErrorData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlErrorData"))
             }
         }
         return list
@@ -1079,10 +1283,10 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlPayErrorData(lnUrlPayErrorData: [String: Any?]) throws -> LnUrlPayErrorData {
         guard let paymentHash = lnUrlPayErrorData["paymentHash"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnUrlPayErrorData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnUrlPayErrorData"))
         }
         guard let reason = lnUrlPayErrorData["reason"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reason", typeName: "LnUrlPayErrorData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "reason", typeName: "LnUrlPayErrorData"))
         }
 
         return LnUrlPayErrorData(paymentHash: paymentHash, reason: reason)
@@ -1102,7 +1306,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlPayErrorData = try asLnUrlPayErrorData(lnUrlPayErrorData: val)
                 list.append(lnUrlPayErrorData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayErrorData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlPayErrorData"))
             }
         }
         return list
@@ -1114,7 +1318,7 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlPayRequest(lnUrlPayRequest: [String: Any?]) throws -> LnUrlPayRequest {
         guard let prepareResponseTmp = lnUrlPayRequest["prepareResponse"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "LnUrlPayRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "LnUrlPayRequest"))
         }
         let prepareResponse = try asPrepareLnUrlPayResponse(prepareLnUrlPayResponse: prepareResponseTmp)
 
@@ -1134,7 +1338,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlPayRequest = try asLnUrlPayRequest(lnUrlPayRequest: val)
                 list.append(lnUrlPayRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlPayRequest"))
             }
         }
         return list
@@ -1146,37 +1350,37 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlPayRequestData(lnUrlPayRequestData: [String: Any?]) throws -> LnUrlPayRequestData {
         guard let callback = lnUrlPayRequestData["callback"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "callback", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "callback", typeName: "LnUrlPayRequestData"))
         }
         guard let minSendable = lnUrlPayRequestData["minSendable"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minSendable", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "minSendable", typeName: "LnUrlPayRequestData"))
         }
         guard let maxSendable = lnUrlPayRequestData["maxSendable"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxSendable", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "maxSendable", typeName: "LnUrlPayRequestData"))
         }
         guard let metadataStr = lnUrlPayRequestData["metadataStr"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "metadataStr", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "metadataStr", typeName: "LnUrlPayRequestData"))
         }
         guard let commentAllowed = lnUrlPayRequestData["commentAllowed"] as? UInt16 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "commentAllowed", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "commentAllowed", typeName: "LnUrlPayRequestData"))
         }
         guard let domain = lnUrlPayRequestData["domain"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "domain", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "domain", typeName: "LnUrlPayRequestData"))
         }
         guard let allowsNostr = lnUrlPayRequestData["allowsNostr"] as? Bool else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "allowsNostr", typeName: "LnUrlPayRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "allowsNostr", typeName: "LnUrlPayRequestData"))
         }
         var nostrPubkey: String?
         if hasNonNilKey(data: lnUrlPayRequestData, key: "nostrPubkey") {
             guard let nostrPubkeyTmp = lnUrlPayRequestData["nostrPubkey"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "nostrPubkey"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "nostrPubkey"))
             }
             nostrPubkey = nostrPubkeyTmp
         }
         var lnAddress: String?
         if hasNonNilKey(data: lnUrlPayRequestData, key: "lnAddress") {
             guard let lnAddressTmp = lnUrlPayRequestData["lnAddress"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lnAddress"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "lnAddress"))
             }
             lnAddress = lnAddressTmp
         }
@@ -1205,7 +1409,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlPayRequestData = try asLnUrlPayRequestData(lnUrlPayRequestData: val)
                 list.append(lnUrlPayRequestData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayRequestData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlPayRequestData"))
             }
         }
         return list
@@ -1222,7 +1426,7 @@ enum BreezSDKLiquidMapper {
         }
 
         guard let paymentTmp = lnUrlPaySuccessData["payment"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payment", typeName: "LnUrlPaySuccessData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "payment", typeName: "LnUrlPaySuccessData"))
         }
         let payment = try asPayment(payment: paymentTmp)
 
@@ -1243,7 +1447,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlPaySuccessData = try asLnUrlPaySuccessData(lnUrlPaySuccessData: val)
                 list.append(lnUrlPaySuccessData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPaySuccessData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlPaySuccessData"))
             }
         }
         return list
@@ -1255,17 +1459,17 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlWithdrawRequest(lnUrlWithdrawRequest: [String: Any?]) throws -> LnUrlWithdrawRequest {
         guard let dataTmp = lnUrlWithdrawRequest["data"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawRequest"))
         }
         let data = try asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: dataTmp)
 
         guard let amountMsat = lnUrlWithdrawRequest["amountMsat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "LnUrlWithdrawRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountMsat", typeName: "LnUrlWithdrawRequest"))
         }
         var description: String?
         if hasNonNilKey(data: lnUrlWithdrawRequest, key: "description") {
             guard let descriptionTmp = lnUrlWithdrawRequest["description"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "description"))
             }
             description = descriptionTmp
         }
@@ -1288,7 +1492,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlWithdrawRequest = try asLnUrlWithdrawRequest(lnUrlWithdrawRequest: val)
                 list.append(lnUrlWithdrawRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlWithdrawRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlWithdrawRequest"))
             }
         }
         return list
@@ -1300,19 +1504,19 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: [String: Any?]) throws -> LnUrlWithdrawRequestData {
         guard let callback = lnUrlWithdrawRequestData["callback"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "callback", typeName: "LnUrlWithdrawRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "callback", typeName: "LnUrlWithdrawRequestData"))
         }
         guard let k1 = lnUrlWithdrawRequestData["k1"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "k1", typeName: "LnUrlWithdrawRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "k1", typeName: "LnUrlWithdrawRequestData"))
         }
         guard let defaultDescription = lnUrlWithdrawRequestData["defaultDescription"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "defaultDescription", typeName: "LnUrlWithdrawRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "defaultDescription", typeName: "LnUrlWithdrawRequestData"))
         }
         guard let minWithdrawable = lnUrlWithdrawRequestData["minWithdrawable"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minWithdrawable", typeName: "LnUrlWithdrawRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "minWithdrawable", typeName: "LnUrlWithdrawRequestData"))
         }
         guard let maxWithdrawable = lnUrlWithdrawRequestData["maxWithdrawable"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxWithdrawable", typeName: "LnUrlWithdrawRequestData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "maxWithdrawable", typeName: "LnUrlWithdrawRequestData"))
         }
 
         return LnUrlWithdrawRequestData(callback: callback, k1: k1, defaultDescription: defaultDescription, minWithdrawable: minWithdrawable, maxWithdrawable: maxWithdrawable)
@@ -1335,7 +1539,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlWithdrawRequestData = try asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: val)
                 list.append(lnUrlWithdrawRequestData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlWithdrawRequestData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlWithdrawRequestData"))
             }
         }
         return list
@@ -1347,7 +1551,7 @@ enum BreezSDKLiquidMapper {
 
     static func asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: [String: Any?]) throws -> LnUrlWithdrawSuccessData {
         guard let invoiceTmp = lnUrlWithdrawSuccessData["invoice"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "invoice", typeName: "LnUrlWithdrawSuccessData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "invoice", typeName: "LnUrlWithdrawSuccessData"))
         }
         let invoice = try asLnInvoice(lnInvoice: invoiceTmp)
 
@@ -1367,7 +1571,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlWithdrawSuccessData = try asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: val)
                 list.append(lnUrlWithdrawSuccessData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlWithdrawSuccessData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlWithdrawSuccessData"))
             }
         }
         return list
@@ -1379,17 +1583,17 @@ enum BreezSDKLiquidMapper {
 
     static func asLocaleOverrides(localeOverrides: [String: Any?]) throws -> LocaleOverrides {
         guard let locale = localeOverrides["locale"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "locale", typeName: "LocaleOverrides"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "locale", typeName: "LocaleOverrides"))
         }
         var spacing: UInt32?
         if hasNonNilKey(data: localeOverrides, key: "spacing") {
             guard let spacingTmp = localeOverrides["spacing"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "spacing"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "spacing"))
             }
             spacing = spacingTmp
         }
         guard let symbolTmp = localeOverrides["symbol"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "symbol", typeName: "LocaleOverrides"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "symbol", typeName: "LocaleOverrides"))
         }
         let symbol = try asSymbol(symbol: symbolTmp)
 
@@ -1411,7 +1615,7 @@ enum BreezSDKLiquidMapper {
                 var localeOverrides = try asLocaleOverrides(localeOverrides: val)
                 list.append(localeOverrides)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LocaleOverrides"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LocaleOverrides"))
             }
         }
         return list
@@ -1423,10 +1627,10 @@ enum BreezSDKLiquidMapper {
 
     static func asLocalizedName(localizedName: [String: Any?]) throws -> LocalizedName {
         guard let locale = localizedName["locale"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "locale", typeName: "LocalizedName"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "locale", typeName: "LocalizedName"))
         }
         guard let name = localizedName["name"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "LocalizedName"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "name", typeName: "LocalizedName"))
         }
 
         return LocalizedName(locale: locale, name: name)
@@ -1446,7 +1650,7 @@ enum BreezSDKLiquidMapper {
                 var localizedName = try asLocalizedName(localizedName: val)
                 list.append(localizedName)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LocalizedName"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LocalizedName"))
             }
         }
         return list
@@ -1458,19 +1662,56 @@ enum BreezSDKLiquidMapper {
 
     static func asLogEntry(logEntry: [String: Any?]) throws -> LogEntry {
         guard let line = logEntry["line"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "line", typeName: "LogEntry"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "line", typeName: "LogEntry"))
         }
         guard let level = logEntry["level"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "level", typeName: "LogEntry"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "level", typeName: "LogEntry"))
+        }
+        guard let target = logEntry["target"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "target", typeName: "LogEntry"))
+        }
+        var module: String?
+        if hasNonNilKey(data: logEntry, key: "module") {
+            guard let moduleTmp = logEntry["module"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "module"))
+            }
+            module = moduleTmp
+        }
+        guard let timestamp = logEntry["timestamp"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "timestamp", typeName: "LogEntry"))
+        }
+        var swapId: String?
+        if hasNonNilKey(data: logEntry, key: "swapId") {
+            guard let swapIdTmp = logEntry["swapId"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "swapId"))
+            }
+            swapId = swapIdTmp
         }
+        var paymentId: String?
+        if hasNonNilKey(data: logEntry, key: "paymentId") {
+            guard let paymentIdTmp = logEntry["paymentId"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "paymentId"))
+            }
+            paymentId = paymentIdTmp
+        }
+        guard let fieldsTmp = logEntry["fields"] as? [[String: Any?]] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fields", typeName: "LogEntry"))
+        }
+        let fields = try asLogFieldList(arr: fieldsTmp)
 
-        return LogEntry(line: line, level: level)
+        return LogEntry(line: line, level: level, target: target, module: module, timestamp: timestamp, swapId: swapId, paymentId: paymentId, fields: fields)
     }
 
     static func dictionaryOf(logEntry: LogEntry) -> [String: Any?] {
         return [
             "line": logEntry.line,
             "level": logEntry.level,
+            "target": logEntry.target,
+            "module": logEntry.module == nil ? nil : logEntry.module,
+            "timestamp": logEntry.timestamp,
+            "swapId": logEntry.swapId == nil ? nil : logEntry.swapId,
+            "paymentId": logEntry.paymentId == nil ? nil : logEntry.paymentId,
+            "fields": arrayOf(logFieldList: logEntry.fields),
         ]
     }
 
@@ -1481,7 +1722,7 @@ enum BreezSDKLiquidMapper {
                 var logEntry = try asLogEntry(logEntry: val)
                 list.append(logEntry)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LogEntry"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LogEntry"))
             }
         }
         return list
@@ -1491,9 +1732,44 @@ enum BreezSDKLiquidMapper {
         return logEntryList.map { v -> [String: Any?] in return dictionaryOf(logEntry: v) }
     }
 
+    static func asLogField(logField: [String: Any?]) throws -> LogField {
+        guard let key = logField["key"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "key", typeName: "LogField"))
+        }
+        guard let value = logField["value"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "value", typeName: "LogField"))
+        }
+
+        return LogField(key: key, value: value)
+    }
+
+    static func dictionaryOf(logField: LogField) -> [String: Any?] {
+        return [
+            "key": logField.key,
+            "value": logField.value,
+        ]
+    }
+
+    static func asLogFieldList(arr: [Any]) throws -> [LogField] {
+        var list = [LogField]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var logField = try asLogField(logField: val)
+                list.append(logField)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LogField"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(logFieldList: [LogField]) -> [Any] {
+        return logFieldList.map { v -> [String: Any?] in return dictionaryOf(logField: v) }
+    }
+
     static func asMessageSuccessActionData(messageSuccessActionData: [String: Any?]) throws -> MessageSuccessActionData {
         guard let message = messageSuccessActionData["message"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "MessageSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "message", typeName: "MessageSuccessActionData"))
         }
 
         return MessageSuccessActionData(message: message)
@@ -1512,7 +1788,7 @@ enum BreezSDKLiquidMapper {
                 var messageSuccessActionData = try asMessageSuccessActionData(messageSuccessActionData: val)
                 list.append(messageSuccessActionData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "MessageSuccessActionData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "MessageSuccessActionData"))
             }
         }
         return list
@@ -1524,22 +1800,31 @@ enum BreezSDKLiquidMapper {
 
     static func asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: [String: Any?]) throws -> OnchainPaymentLimitsResponse {
         guard let sendTmp = onchainPaymentLimitsResponse["send"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "send", typeName: "OnchainPaymentLimitsResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "send", typeName: "OnchainPaymentLimitsResponse"))
         }
         let send = try asLimits(limits: sendTmp)
 
         guard let receiveTmp = onchainPaymentLimitsResponse["receive"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receive", typeName: "OnchainPaymentLimitsResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "receive", typeName: "OnchainPaymentLimitsResponse"))
         }
         let receive = try asLimits(limits: receiveTmp)
 
-        return OnchainPaymentLimitsResponse(send: send, receive: receive)
+        var cacheAgeSecs: UInt64?
+        if hasNonNilKey(data: onchainPaymentLimitsResponse, key: "cacheAgeSecs") {
+            guard let cacheAgeSecsTmp = onchainPaymentLimitsResponse["cacheAgeSecs"] as? UInt64 else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "cacheAgeSecs"))
+            }
+            cacheAgeSecs = cacheAgeSecsTmp
+        }
+
+        return OnchainPaymentLimitsResponse(send: send, receive: receive, cacheAgeSecs: cacheAgeSecs)
     }
 
     static func dictionaryOf(onchainPaymentLimitsResponse: OnchainPaymentLimitsResponse) -> [String: Any?] {
         return [
             "send": dictionaryOf(limits: onchainPaymentLimitsResponse.send),
             "receive": dictionaryOf(limits: onchainPaymentLimitsResponse.receive),
+            "cacheAgeSecs": onchainPaymentLimitsResponse.cacheAgeSecs == nil ? nil : onchainPaymentLimitsResponse.cacheAgeSecs,
         ]
     }
 
@@ -1550,7 +1835,7 @@ enum BreezSDKLiquidMapper {
                 var onchainPaymentLimitsResponse = try asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: val)
                 list.append(onchainPaymentLimitsResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "OnchainPaymentLimitsResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "OnchainPaymentLimitsResponse"))
             }
         }
         return list
@@ -1562,10 +1847,10 @@ enum BreezSDKLiquidMapper {
 
     static func asPayOnchainRequest(payOnchainRequest: [String: Any?]) throws -> PayOnchainRequest {
         guard let address = payOnchainRequest["address"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "PayOnchainRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "PayOnchainRequest"))
         }
         guard let prepareResponseTmp = payOnchainRequest["prepareResponse"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "PayOnchainRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "PayOnchainRequest"))
         }
         let prepareResponse = try asPreparePayOnchainResponse(preparePayOnchainResponse: prepareResponseTmp)
 
@@ -1586,7 +1871,7 @@ enum BreezSDKLiquidMapper {
                 var payOnchainRequest = try asPayOnchainRequest(payOnchainRequest: val)
                 list.append(payOnchainRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PayOnchainRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PayOnchainRequest"))
             }
         }
         return list
@@ -1598,45 +1883,49 @@ enum BreezSDKLiquidMapper {
 
     static func asPayment(payment: [String: Any?]) throws -> Payment {
         guard let timestamp = payment["timestamp"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "timestamp", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "timestamp", typeName: "Payment"))
         }
         guard let amountSat = payment["amountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountSat", typeName: "Payment"))
         }
         guard let feesSat = payment["feesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesSat", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesSat", typeName: "Payment"))
         }
         guard let paymentTypeTmp = payment["paymentType"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentType", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentType", typeName: "Payment"))
         }
         let paymentType = try asPaymentType(paymentType: paymentTypeTmp)
 
         guard let statusTmp = payment["status"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "status", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "status", typeName: "Payment"))
         }
         let status = try asPaymentState(paymentState: statusTmp)
 
         guard let detailsTmp = payment["details"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "Payment"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "Payment"))
         }
         let details = try asPaymentDetails(paymentDetails: detailsTmp)
 
         var destination: String?
         if hasNonNilKey(data: payment, key: "destination") {
             guard let destinationTmp = payment["destination"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "destination"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "destination"))
             }
             destination = destinationTmp
         }
         var txId: String?
         if hasNonNilKey(data: payment, key: "txId") {
             guard let txIdTmp = payment["txId"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "txId"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "txId"))
             }
             txId = txIdTmp
         }
+        var fiatRate: PaymentFiatRate?
+        if let fiatRateTmp = payment["fiatRate"] as? [String: Any?] {
+            fiatRate = try asPaymentFiatRate(paymentFiatRate: fiatRateTmp)
+        }
 
-        return Payment(timestamp: timestamp, amountSat: amountSat, feesSat: feesSat, paymentType: paymentType, status: status, details: details, destination: destination, txId: txId)
+        return Payment(timestamp: timestamp, amountSat: amountSat, feesSat: feesSat, paymentType: paymentType, status: status, details: details, destination: destination, txId: txId, fiatRate: fiatRate)
     }
 
     static func dictionaryOf(payment: Payment) -> [String: Any?] {
@@ -1649,6 +1938,7 @@ enum BreezSDKLiquidMapper {
             "details": dictionaryOf(paymentDetails: payment.details),
             "destination": payment.destination == nil ? nil : payment.destination,
             "txId": payment.txId == nil ? nil : payment.txId,
+            "fiatRate": payment.fiatRate == nil ? nil : dictionaryOf(paymentFiatRate: payment.fiatRate!),
         ]
     }
 
@@ -1659,7 +1949,7 @@ enum BreezSDKLiquidMapper {
                 var payment = try asPayment(payment: val)
                 list.append(payment)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Payment"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Payment"))
             }
         }
         return list
@@ -1669,14 +1959,49 @@ enum BreezSDKLiquidMapper {
         return paymentList.map { v -> [String: Any?] in return dictionaryOf(payment: v) }
     }
 
+    static func asPaymentFiatRate(paymentFiatRate: [String: Any?]) throws -> PaymentFiatRate {
+        guard let currency = paymentFiatRate["currency"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "currency", typeName: "PaymentFiatRate"))
+        }
+        guard let rate = paymentFiatRate["rate"] as? Double else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "rate", typeName: "PaymentFiatRate"))
+        }
+
+        return PaymentFiatRate(currency: currency, rate: rate)
+    }
+
+    static func dictionaryOf(paymentFiatRate: PaymentFiatRate) -> [String: Any?] {
+        return [
+            "currency": paymentFiatRate.currency,
+            "rate": paymentFiatRate.rate,
+        ]
+    }
+
+    static func asPaymentFiatRateList(arr: [Any]) throws -> [PaymentFiatRate] {
+        var list = [PaymentFiatRate]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var paymentFiatRate = try asPaymentFiatRate(paymentFiatRate: val)
+                list.append(paymentFiatRate)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PaymentFiatRate"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(paymentFiatRateList: [PaymentFiatRate]) -> [Any] {
+        return paymentFiatRateList.map { v -> [String: Any?] in return dictionaryOf(paymentFiatRate: v) }
+    }
+
     static func asPrepareBuyBitcoinRequest(prepareBuyBitcoinRequest: [String: Any?]) throws -> PrepareBuyBitcoinRequest {
         guard let providerTmp = prepareBuyBitcoinRequest["provider"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "provider", typeName: "PrepareBuyBitcoinRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "provider", typeName: "PrepareBuyBitcoinRequest"))
         }
         let provider = try asBuyBitcoinProvider(buyBitcoinProvider: providerTmp)
 
         guard let amountSat = prepareBuyBitcoinRequest["amountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareBuyBitcoinRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareBuyBitcoinRequest"))
         }
 
         return PrepareBuyBitcoinRequest(provider: provider, amountSat: amountSat)
@@ -1696,7 +2021,7 @@ enum BreezSDKLiquidMapper {
                 var prepareBuyBitcoinRequest = try asPrepareBuyBitcoinRequest(prepareBuyBitcoinRequest: val)
                 list.append(prepareBuyBitcoinRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareBuyBitcoinRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareBuyBitcoinRequest"))
             }
         }
         return list
@@ -1708,15 +2033,15 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareBuyBitcoinResponse(prepareBuyBitcoinResponse: [String: Any?]) throws -> PrepareBuyBitcoinResponse {
         guard let providerTmp = prepareBuyBitcoinResponse["provider"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "provider", typeName: "PrepareBuyBitcoinResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "provider", typeName: "PrepareBuyBitcoinResponse"))
         }
         let provider = try asBuyBitcoinProvider(buyBitcoinProvider: providerTmp)
 
         guard let amountSat = prepareBuyBitcoinResponse["amountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareBuyBitcoinResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareBuyBitcoinResponse"))
         }
         guard let feesSat = prepareBuyBitcoinResponse["feesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareBuyBitcoinResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareBuyBitcoinResponse"))
         }
 
         return PrepareBuyBitcoinResponse(provider: provider, amountSat: amountSat, feesSat: feesSat)
@@ -1737,7 +2062,7 @@ enum BreezSDKLiquidMapper {
                 var prepareBuyBitcoinResponse = try asPrepareBuyBitcoinResponse(prepareBuyBitcoinResponse: val)
                 list.append(prepareBuyBitcoinResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareBuyBitcoinResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareBuyBitcoinResponse"))
             }
         }
         return list
@@ -1749,24 +2074,24 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareLnUrlPayRequest(prepareLnUrlPayRequest: [String: Any?]) throws -> PrepareLnUrlPayRequest {
         guard let dataTmp = prepareLnUrlPayRequest["data"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "PrepareLnUrlPayRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "PrepareLnUrlPayRequest"))
         }
         let data = try asLnUrlPayRequestData(lnUrlPayRequestData: dataTmp)
 
         guard let amountMsat = prepareLnUrlPayRequest["amountMsat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PrepareLnUrlPayRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PrepareLnUrlPayRequest"))
         }
         var comment: String?
         if hasNonNilKey(data: prepareLnUrlPayRequest, key: "comment") {
             guard let commentTmp = prepareLnUrlPayRequest["comment"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "comment"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "comment"))
             }
             comment = commentTmp
         }
         var validateSuccessActionUrl: Bool?
         if hasNonNilKey(data: prepareLnUrlPayRequest, key: "validateSuccessActionUrl") {
             guard let validateSuccessActionUrlTmp = prepareLnUrlPayRequest["validateSuccessActionUrl"] as? Bool else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "validateSuccessActionUrl"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "validateSuccessActionUrl"))
             }
             validateSuccessActionUrl = validateSuccessActionUrlTmp
         }
@@ -1790,7 +2115,7 @@ enum BreezSDKLiquidMapper {
                 var prepareLnUrlPayRequest = try asPrepareLnUrlPayRequest(prepareLnUrlPayRequest: val)
                 list.append(prepareLnUrlPayRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareLnUrlPayRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareLnUrlPayRequest"))
             }
         }
         return list
@@ -1802,12 +2127,12 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareLnUrlPayResponse(prepareLnUrlPayResponse: [String: Any?]) throws -> PrepareLnUrlPayResponse {
         guard let destinationTmp = prepareLnUrlPayResponse["destination"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareLnUrlPayResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareLnUrlPayResponse"))
         }
         let destination = try asSendDestination(sendDestination: destinationTmp)
 
         guard let feesSat = prepareLnUrlPayResponse["feesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareLnUrlPayResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareLnUrlPayResponse"))
         }
         var successAction: SuccessAction?
         if let successActionTmp = prepareLnUrlPayResponse["successAction"] as? [String: Any?] {
@@ -1832,7 +2157,7 @@ enum BreezSDKLiquidMapper {
                 var prepareLnUrlPayResponse = try asPrepareLnUrlPayResponse(prepareLnUrlPayResponse: val)
                 list.append(prepareLnUrlPayResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareLnUrlPayResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareLnUrlPayResponse"))
             }
         }
         return list
@@ -1844,14 +2169,14 @@ enum BreezSDKLiquidMapper {
 
     static func asPreparePayOnchainRequest(preparePayOnchainRequest: [String: Any?]) throws -> PreparePayOnchainRequest {
         guard let amountTmp = preparePayOnchainRequest["amount"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amount", typeName: "PreparePayOnchainRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amount", typeName: "PreparePayOnchainRequest"))
         }
         let amount = try asPayAmount(payAmount: amountTmp)
 
         var feeRateSatPerVbyte: UInt32?
         if hasNonNilKey(data: preparePayOnchainRequest, key: "feeRateSatPerVbyte") {
             guard let feeRateSatPerVbyteTmp = preparePayOnchainRequest["feeRateSatPerVbyte"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "feeRateSatPerVbyte"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "feeRateSatPerVbyte"))
             }
             feeRateSatPerVbyte = feeRateSatPerVbyteTmp
         }
@@ -1873,7 +2198,7 @@ enum BreezSDKLiquidMapper {
                 var preparePayOnchainRequest = try asPreparePayOnchainRequest(preparePayOnchainRequest: val)
                 list.append(preparePayOnchainRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PreparePayOnchainRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PreparePayOnchainRequest"))
             }
         }
         return list
@@ -1885,16 +2210,20 @@ enum BreezSDKLiquidMapper {
 
     static func asPreparePayOnchainResponse(preparePayOnchainResponse: [String: Any?]) throws -> PreparePayOnchainResponse {
         guard let receiverAmountSat = preparePayOnchainResponse["receiverAmountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receiverAmountSat", typeName: "PreparePayOnchainResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "receiverAmountSat", typeName: "PreparePayOnchainResponse"))
         }
         guard let claimFeesSat = preparePayOnchainResponse["claimFeesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "claimFeesSat", typeName: "PreparePayOnchainResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "claimFeesSat", typeName: "PreparePayOnchainResponse"))
         }
         guard let totalFeesSat = preparePayOnchainResponse["totalFeesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalFeesSat", typeName: "PreparePayOnchainResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "totalFeesSat", typeName: "PreparePayOnchainResponse"))
+        }
+        var fiatRate: PaymentFiatRate?
+        if let fiatRateTmp = preparePayOnchainResponse["fiatRate"] as? [String: Any?] {
+            fiatRate = try asPaymentFiatRate(paymentFiatRate: fiatRateTmp)
         }
 
-        return PreparePayOnchainResponse(receiverAmountSat: receiverAmountSat, claimFeesSat: claimFeesSat, totalFeesSat: totalFeesSat)
+        return PreparePayOnchainResponse(receiverAmountSat: receiverAmountSat, claimFeesSat: claimFeesSat, totalFeesSat: totalFeesSat, fiatRate: fiatRate)
     }
 
     static func dictionaryOf(preparePayOnchainResponse: PreparePayOnchainResponse) -> [String: Any?] {
@@ -1902,6 +2231,7 @@ enum BreezSDKLiquidMapper {
             "receiverAmountSat": preparePayOnchainResponse.receiverAmountSat,
             "claimFeesSat": preparePayOnchainResponse.claimFeesSat,
             "totalFeesSat": preparePayOnchainResponse.totalFeesSat,
+            "fiatRate": preparePayOnchainResponse.fiatRate == nil ? nil : dictionaryOf(paymentFiatRate: preparePayOnchainResponse.fiatRate!),
         ]
     }
 
@@ -1912,7 +2242,7 @@ enum BreezSDKLiquidMapper {
                 var preparePayOnchainResponse = try asPreparePayOnchainResponse(preparePayOnchainResponse: val)
                 list.append(preparePayOnchainResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PreparePayOnchainResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PreparePayOnchainResponse"))
             }
         }
         return list
@@ -1924,25 +2254,30 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareReceiveRequest(prepareReceiveRequest: [String: Any?]) throws -> PrepareReceiveRequest {
         guard let paymentMethodTmp = prepareReceiveRequest["paymentMethod"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentMethod", typeName: "PrepareReceiveRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentMethod", typeName: "PrepareReceiveRequest"))
         }
         let paymentMethod = try asPaymentMethod(paymentMethod: paymentMethodTmp)
 
         var payerAmountSat: UInt64?
         if hasNonNilKey(data: prepareReceiveRequest, key: "payerAmountSat") {
             guard let payerAmountSatTmp = prepareReceiveRequest["payerAmountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "payerAmountSat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "payerAmountSat"))
             }
             payerAmountSat = payerAmountSatTmp
         }
+        var payerAmountFiat: FiatAmount?
+        if let payerAmountFiatTmp = prepareReceiveRequest["payerAmountFiat"] as? [String: Any?] {
+            payerAmountFiat = try asFiatAmount(fiatAmount: payerAmountFiatTmp)
+        }
 
-        return PrepareReceiveRequest(paymentMethod: paymentMethod, payerAmountSat: payerAmountSat)
+        return PrepareReceiveRequest(paymentMethod: paymentMethod, payerAmountSat: payerAmountSat, payerAmountFiat: payerAmountFiat)
     }
 
     static func dictionaryOf(prepareReceiveRequest: PrepareReceiveRequest) -> [String: Any?] {
         return [
             "paymentMethod": valueOf(paymentMethod: prepareReceiveRequest.paymentMethod),
             "payerAmountSat": prepareReceiveRequest.payerAmountSat == nil ? nil : prepareReceiveRequest.payerAmountSat,
+            "payerAmountFiat": prepareReceiveRequest.payerAmountFiat == nil ? nil : dictionaryOf(fiatAmount: prepareReceiveRequest.payerAmountFiat!),
         ]
     }
 
@@ -1953,7 +2288,7 @@ enum BreezSDKLiquidMapper {
                 var prepareReceiveRequest = try asPrepareReceiveRequest(prepareReceiveRequest: val)
                 list.append(prepareReceiveRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareReceiveRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareReceiveRequest"))
             }
         }
         return list
@@ -1967,20 +2302,24 @@ enum BreezSDKLiquidMapper {
         var payerAmountSat: UInt64?
         if hasNonNilKey(data: prepareReceiveResponse, key: "payerAmountSat") {
             guard let payerAmountSatTmp = prepareReceiveResponse["payerAmountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "payerAmountSat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "payerAmountSat"))
             }
             payerAmountSat = payerAmountSatTmp
         }
         guard let paymentMethodTmp = prepareReceiveResponse["paymentMethod"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentMethod", typeName: "PrepareReceiveResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentMethod", typeName: "PrepareReceiveResponse"))
         }
         let paymentMethod = try asPaymentMethod(paymentMethod: paymentMethodTmp)
 
         guard let feesSat = prepareReceiveResponse["feesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareReceiveResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareReceiveResponse"))
+        }
+        var fiatRate: PaymentFiatRate?
+        if let fiatRateTmp = prepareReceiveResponse["fiatRate"] as? [String: Any?] {
+            fiatRate = try asPaymentFiatRate(paymentFiatRate: fiatRateTmp)
         }
 
-        return PrepareReceiveResponse(payerAmountSat: payerAmountSat, paymentMethod: paymentMethod, feesSat: feesSat)
+        return PrepareReceiveResponse(payerAmountSat: payerAmountSat, paymentMethod: paymentMethod, feesSat: feesSat, fiatRate: fiatRate)
     }
 
     static func dictionaryOf(prepareReceiveResponse: PrepareReceiveResponse) -> [String: Any?] {
@@ -1988,6 +2327,7 @@ enum BreezSDKLiquidMapper {
             "payerAmountSat": prepareReceiveResponse.payerAmountSat == nil ? nil : prepareReceiveResponse.payerAmountSat,
             "paymentMethod": valueOf(paymentMethod: prepareReceiveResponse.paymentMethod),
             "feesSat": prepareReceiveResponse.feesSat,
+            "fiatRate": prepareReceiveResponse.fiatRate == nil ? nil : dictionaryOf(paymentFiatRate: prepareReceiveResponse.fiatRate!),
         ]
     }
 
@@ -1998,7 +2338,7 @@ enum BreezSDKLiquidMapper {
                 var prepareReceiveResponse = try asPrepareReceiveResponse(prepareReceiveResponse: val)
                 list.append(prepareReceiveResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareReceiveResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareReceiveResponse"))
             }
         }
         return list
@@ -2010,13 +2350,13 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareRefundRequest(prepareRefundRequest: [String: Any?]) throws -> PrepareRefundRequest {
         guard let swapAddress = prepareRefundRequest["swapAddress"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapAddress", typeName: "PrepareRefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapAddress", typeName: "PrepareRefundRequest"))
         }
         guard let refundAddress = prepareRefundRequest["refundAddress"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundAddress", typeName: "PrepareRefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "refundAddress", typeName: "PrepareRefundRequest"))
         }
         guard let feeRateSatPerVbyte = prepareRefundRequest["feeRateSatPerVbyte"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeRateSatPerVbyte", typeName: "PrepareRefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feeRateSatPerVbyte", typeName: "PrepareRefundRequest"))
         }
 
         return PrepareRefundRequest(swapAddress: swapAddress, refundAddress: refundAddress, feeRateSatPerVbyte: feeRateSatPerVbyte)
@@ -2037,7 +2377,7 @@ enum BreezSDKLiquidMapper {
                 var prepareRefundRequest = try asPrepareRefundRequest(prepareRefundRequest: val)
                 list.append(prepareRefundRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareRefundRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareRefundRequest"))
             }
         }
         return list
@@ -2049,26 +2389,34 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareRefundResponse(prepareRefundResponse: [String: Any?]) throws -> PrepareRefundResponse {
         guard let txVsize = prepareRefundResponse["txVsize"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txVsize", typeName: "PrepareRefundResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "txVsize", typeName: "PrepareRefundResponse"))
         }
         guard let txFeeSat = prepareRefundResponse["txFeeSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txFeeSat", typeName: "PrepareRefundResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "txFeeSat", typeName: "PrepareRefundResponse"))
+        }
+        guard let isCooperative = prepareRefundResponse["isCooperative"] as? Bool else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "isCooperative", typeName: "PrepareRefundResponse"))
+        }
+        guard let feeSavedSat = prepareRefundResponse["feeSavedSat"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feeSavedSat", typeName: "PrepareRefundResponse"))
         }
         var refundTxId: String?
         if hasNonNilKey(data: prepareRefundResponse, key: "refundTxId") {
             guard let refundTxIdTmp = prepareRefundResponse["refundTxId"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "refundTxId"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "refundTxId"))
             }
             refundTxId = refundTxIdTmp
         }
 
-        return PrepareRefundResponse(txVsize: txVsize, txFeeSat: txFeeSat, refundTxId: refundTxId)
+        return PrepareRefundResponse(txVsize: txVsize, txFeeSat: txFeeSat, isCooperative: isCooperative, feeSavedSat: feeSavedSat, refundTxId: refundTxId)
     }
 
     static func dictionaryOf(prepareRefundResponse: PrepareRefundResponse) -> [String: Any?] {
         return [
             "txVsize": prepareRefundResponse.txVsize,
             "txFeeSat": prepareRefundResponse.txFeeSat,
+            "isCooperative": prepareRefundResponse.isCooperative,
+            "feeSavedSat": prepareRefundResponse.feeSavedSat,
             "refundTxId": prepareRefundResponse.refundTxId == nil ? nil : prepareRefundResponse.refundTxId,
         ]
     }
@@ -2080,7 +2428,7 @@ enum BreezSDKLiquidMapper {
                 var prepareRefundResponse = try asPrepareRefundResponse(prepareRefundResponse: val)
                 list.append(prepareRefundResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareRefundResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareRefundResponse"))
             }
         }
         return list
@@ -2092,7 +2440,7 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareSendRequest(prepareSendRequest: [String: Any?]) throws -> PrepareSendRequest {
         guard let destination = prepareSendRequest["destination"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareSendRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareSendRequest"))
         }
         var amount: PayAmount?
         if let amountTmp = prepareSendRequest["amount"] as? [String: Any?] {
@@ -2116,7 +2464,7 @@ enum BreezSDKLiquidMapper {
                 var prepareSendRequest = try asPrepareSendRequest(prepareSendRequest: val)
                 list.append(prepareSendRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareSendRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareSendRequest"))
             }
         }
         return list
@@ -2128,21 +2476,26 @@ enum BreezSDKLiquidMapper {
 
     static func asPrepareSendResponse(prepareSendResponse: [String: Any?]) throws -> PrepareSendResponse {
         guard let destinationTmp = prepareSendResponse["destination"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareSendResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "PrepareSendResponse"))
         }
         let destination = try asSendDestination(sendDestination: destinationTmp)
 
         guard let feesSat = prepareSendResponse["feesSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareSendResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesSat", typeName: "PrepareSendResponse"))
+        }
+        var fiatRate: PaymentFiatRate?
+        if let fiatRateTmp = prepareSendResponse["fiatRate"] as? [String: Any?] {
+            fiatRate = try asPaymentFiatRate(paymentFiatRate: fiatRateTmp)
         }
 
-        return PrepareSendResponse(destination: destination, feesSat: feesSat)
+        return PrepareSendResponse(destination: destination, feesSat: feesSat, fiatRate: fiatRate)
     }
 
     static func dictionaryOf(prepareSendResponse: PrepareSendResponse) -> [String: Any?] {
         return [
             "destination": dictionaryOf(sendDestination: prepareSendResponse.destination),
             "feesSat": prepareSendResponse.feesSat,
+            "fiatRate": prepareSendResponse.fiatRate == nil ? nil : dictionaryOf(paymentFiatRate: prepareSendResponse.fiatRate!),
         ]
     }
 
@@ -2153,7 +2506,7 @@ enum BreezSDKLiquidMapper {
                 var prepareSendResponse = try asPrepareSendResponse(prepareSendResponse: val)
                 list.append(prepareSendResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareSendResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PrepareSendResponse"))
             }
         }
         return list
@@ -2165,10 +2518,10 @@ enum BreezSDKLiquidMapper {
 
     static func asRate(rate: [String: Any?]) throws -> Rate {
         guard let coin = rate["coin"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "coin", typeName: "Rate"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "coin", typeName: "Rate"))
         }
         guard let value = rate["value"] as? Double else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "value", typeName: "Rate"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "value", typeName: "Rate"))
         }
 
         return Rate(coin: coin, value: value)
@@ -2188,7 +2541,7 @@ enum BreezSDKLiquidMapper {
                 var rate = try asRate(rate: val)
                 list.append(rate)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Rate"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Rate"))
             }
         }
         return list
@@ -2200,21 +2553,21 @@ enum BreezSDKLiquidMapper {
 
     static func asReceivePaymentRequest(receivePaymentRequest: [String: Any?]) throws -> ReceivePaymentRequest {
         guard let prepareResponseTmp = receivePaymentRequest["prepareResponse"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "ReceivePaymentRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "ReceivePaymentRequest"))
         }
         let prepareResponse = try asPrepareReceiveResponse(prepareReceiveResponse: prepareResponseTmp)
 
         var description: String?
         if hasNonNilKey(data: receivePaymentRequest, key: "description") {
             guard let descriptionTmp = receivePaymentRequest["description"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "description"))
             }
             description = descriptionTmp
         }
         var useDescriptionHash: Bool?
         if hasNonNilKey(data: receivePaymentRequest, key: "useDescriptionHash") {
             guard let useDescriptionHashTmp = receivePaymentRequest["useDescriptionHash"] as? Bool else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "useDescriptionHash"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "useDescriptionHash"))
             }
             useDescriptionHash = useDescriptionHashTmp
         }
@@ -2237,7 +2590,7 @@ enum BreezSDKLiquidMapper {
                 var receivePaymentRequest = try asReceivePaymentRequest(receivePaymentRequest: val)
                 list.append(receivePaymentRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceivePaymentRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ReceivePaymentRequest"))
             }
         }
         return list
@@ -2249,7 +2602,7 @@ enum BreezSDKLiquidMapper {
 
     static func asReceivePaymentResponse(receivePaymentResponse: [String: Any?]) throws -> ReceivePaymentResponse {
         guard let destination = receivePaymentResponse["destination"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "ReceivePaymentResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "ReceivePaymentResponse"))
         }
 
         return ReceivePaymentResponse(destination: destination)
@@ -2268,7 +2621,7 @@ enum BreezSDKLiquidMapper {
                 var receivePaymentResponse = try asReceivePaymentResponse(receivePaymentResponse: val)
                 list.append(receivePaymentResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceivePaymentResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ReceivePaymentResponse"))
             }
         }
         return list
@@ -2280,19 +2633,19 @@ enum BreezSDKLiquidMapper {
 
     static func asRecommendedFees(recommendedFees: [String: Any?]) throws -> RecommendedFees {
         guard let fastestFee = recommendedFees["fastestFee"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fastestFee", typeName: "RecommendedFees"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fastestFee", typeName: "RecommendedFees"))
         }
         guard let halfHourFee = recommendedFees["halfHourFee"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "halfHourFee", typeName: "RecommendedFees"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "halfHourFee", typeName: "RecommendedFees"))
         }
         guard let hourFee = recommendedFees["hourFee"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "hourFee", typeName: "RecommendedFees"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "hourFee", typeName: "RecommendedFees"))
         }
         guard let economyFee = recommendedFees["economyFee"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "economyFee", typeName: "RecommendedFees"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "economyFee", typeName: "RecommendedFees"))
         }
         guard let minimumFee = recommendedFees["minimumFee"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minimumFee", typeName: "RecommendedFees"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "minimumFee", typeName: "RecommendedFees"))
         }
 
         return RecommendedFees(fastestFee: fastestFee, halfHourFee: halfHourFee, hourFee: hourFee, economyFee: economyFee, minimumFee: minimumFee)
@@ -2315,7 +2668,7 @@ enum BreezSDKLiquidMapper {
                 var recommendedFees = try asRecommendedFees(recommendedFees: val)
                 list.append(recommendedFees)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RecommendedFees"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RecommendedFees"))
             }
         }
         return list
@@ -2327,13 +2680,13 @@ enum BreezSDKLiquidMapper {
 
     static func asRefundRequest(refundRequest: [String: Any?]) throws -> RefundRequest {
         guard let swapAddress = refundRequest["swapAddress"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapAddress", typeName: "RefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapAddress", typeName: "RefundRequest"))
         }
         guard let refundAddress = refundRequest["refundAddress"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundAddress", typeName: "RefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "refundAddress", typeName: "RefundRequest"))
         }
         guard let feeRateSatPerVbyte = refundRequest["feeRateSatPerVbyte"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeRateSatPerVbyte", typeName: "RefundRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feeRateSatPerVbyte", typeName: "RefundRequest"))
         }
 
         return RefundRequest(swapAddress: swapAddress, refundAddress: refundAddress, feeRateSatPerVbyte: feeRateSatPerVbyte)
@@ -2354,7 +2707,7 @@ enum BreezSDKLiquidMapper {
                 var refundRequest = try asRefundRequest(refundRequest: val)
                 list.append(refundRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RefundRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RefundRequest"))
             }
         }
         return list
@@ -2366,7 +2719,7 @@ enum BreezSDKLiquidMapper {
 
     static func asRefundResponse(refundResponse: [String: Any?]) throws -> RefundResponse {
         guard let refundTxId = refundResponse["refundTxId"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundTxId", typeName: "RefundResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "refundTxId", typeName: "RefundResponse"))
         }
 
         return RefundResponse(refundTxId: refundTxId)
@@ -2385,7 +2738,7 @@ enum BreezSDKLiquidMapper {
                 var refundResponse = try asRefundResponse(refundResponse: val)
                 list.append(refundResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RefundResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RefundResponse"))
             }
         }
         return list
@@ -2397,16 +2750,23 @@ enum BreezSDKLiquidMapper {
 
     static func asRefundableSwap(refundableSwap: [String: Any?]) throws -> RefundableSwap {
         guard let swapAddress = refundableSwap["swapAddress"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapAddress", typeName: "RefundableSwap"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapAddress", typeName: "RefundableSwap"))
         }
         guard let timestamp = refundableSwap["timestamp"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "timestamp", typeName: "RefundableSwap"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "timestamp", typeName: "RefundableSwap"))
         }
         guard let amountSat = refundableSwap["amountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "RefundableSwap"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountSat", typeName: "RefundableSwap"))
+        }
+        var timeToRefundable: UInt64?
+        if hasNonNilKey(data: refundableSwap, key: "timeToRefundable") {
+            guard let timeToRefundableTmp = refundableSwap["timeToRefundable"] as? UInt64 else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "timeToRefundable"))
+            }
+            timeToRefundable = timeToRefundableTmp
         }
 
-        return RefundableSwap(swapAddress: swapAddress, timestamp: timestamp, amountSat: amountSat)
+        return RefundableSwap(swapAddress: swapAddress, timestamp: timestamp, amountSat: amountSat, timeToRefundable: timeToRefundable)
     }
 
     static func dictionaryOf(refundableSwap: RefundableSwap) -> [String: Any?] {
@@ -2414,6 +2774,7 @@ enum BreezSDKLiquidMapper {
             "swapAddress": refundableSwap.swapAddress,
             "timestamp": refundableSwap.timestamp,
             "amountSat": refundableSwap.amountSat,
+            "timeToRefundable": refundableSwap.timeToRefundable == nil ? nil : refundableSwap.timeToRefundable,
         ]
     }
 
@@ -2424,7 +2785,7 @@ enum BreezSDKLiquidMapper {
                 var refundableSwap = try asRefundableSwap(refundableSwap: val)
                 list.append(refundableSwap)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RefundableSwap"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RefundableSwap"))
             }
         }
         return list
@@ -2438,7 +2799,7 @@ enum BreezSDKLiquidMapper {
         var backupPath: String?
         if hasNonNilKey(data: restoreRequest, key: "backupPath") {
             guard let backupPathTmp = restoreRequest["backupPath"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "backupPath"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "backupPath"))
             }
             backupPath = backupPathTmp
         }
@@ -2459,7 +2820,7 @@ enum BreezSDKLiquidMapper {
                 var restoreRequest = try asRestoreRequest(restoreRequest: val)
                 list.append(restoreRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RestoreRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RestoreRequest"))
             }
         }
         return list
@@ -2471,7 +2832,7 @@ enum BreezSDKLiquidMapper {
 
     static func asRouteHint(routeHint: [String: Any?]) throws -> RouteHint {
         guard let hopsTmp = routeHint["hops"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "hops", typeName: "RouteHint"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "hops", typeName: "RouteHint"))
         }
         let hops = try asRouteHintHopList(arr: hopsTmp)
 
@@ -2491,7 +2852,7 @@ enum BreezSDKLiquidMapper {
                 var routeHint = try asRouteHint(routeHint: val)
                 list.append(routeHint)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RouteHint"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RouteHint"))
             }
         }
         return list
@@ -2503,31 +2864,31 @@ enum BreezSDKLiquidMapper {
 
     static func asRouteHintHop(routeHintHop: [String: Any?]) throws -> RouteHintHop {
         guard let srcNodeId = routeHintHop["srcNodeId"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "srcNodeId", typeName: "RouteHintHop"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "srcNodeId", typeName: "RouteHintHop"))
         }
         guard let shortChannelId = routeHintHop["shortChannelId"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "shortChannelId", typeName: "RouteHintHop"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "shortChannelId", typeName: "RouteHintHop"))
         }
         guard let feesBaseMsat = routeHintHop["feesBaseMsat"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesBaseMsat", typeName: "RouteHintHop"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesBaseMsat", typeName: "RouteHintHop"))
         }
         guard let feesProportionalMillionths = routeHintHop["feesProportionalMillionths"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesProportionalMillionths", typeName: "RouteHintHop"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "feesProportionalMillionths", typeName: "RouteHintHop"))
         }
         guard let cltvExpiryDelta = routeHintHop["cltvExpiryDelta"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "cltvExpiryDelta", typeName: "RouteHintHop"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "cltvExpiryDelta", typeName: "RouteHintHop"))
         }
         var htlcMinimumMsat: UInt64?
         if hasNonNilKey(data: routeHintHop, key: "htlcMinimumMsat") {
             guard let htlcMinimumMsatTmp = routeHintHop["htlcMinimumMsat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "htlcMinimumMsat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "htlcMinimumMsat"))
             }
             htlcMinimumMsat = htlcMinimumMsatTmp
         }
         var htlcMaximumMsat: UInt64?
         if hasNonNilKey(data: routeHintHop, key: "htlcMaximumMsat") {
             guard let htlcMaximumMsatTmp = routeHintHop["htlcMaximumMsat"] as? UInt64 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "htlcMaximumMsat"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "htlcMaximumMsat"))
             }
             htlcMaximumMsat = htlcMaximumMsatTmp
         }
@@ -2554,7 +2915,7 @@ enum BreezSDKLiquidMapper {
                 var routeHintHop = try asRouteHintHop(routeHintHop: val)
                 list.append(routeHintHop)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "RouteHintHop"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "RouteHintHop"))
             }
         }
         return list
@@ -2564,9 +2925,56 @@ enum BreezSDKLiquidMapper {
         return routeHintHopList.map { v -> [String: Any?] in return dictionaryOf(routeHintHop: v) }
     }
 
+    static func asSchedulerIntervals(schedulerIntervals: [String: Any?]) throws -> SchedulerIntervals {
+        guard let syncSecs = schedulerIntervals["syncSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "syncSecs", typeName: "SchedulerIntervals"))
+        }
+        guard let refundsSecs = schedulerIntervals["refundsSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "refundsSecs", typeName: "SchedulerIntervals"))
+        }
+        guard let liquidRescanSecs = schedulerIntervals["liquidRescanSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "liquidRescanSecs", typeName: "SchedulerIntervals"))
+        }
+        guard let bitcoinRescanSecs = schedulerIntervals["bitcoinRescanSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "bitcoinRescanSecs", typeName: "SchedulerIntervals"))
+        }
+        guard let newBlocksSecs = schedulerIntervals["newBlocksSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "newBlocksSecs", typeName: "SchedulerIntervals"))
+        }
+
+        return SchedulerIntervals(syncSecs: syncSecs, refundsSecs: refundsSecs, liquidRescanSecs: liquidRescanSecs, bitcoinRescanSecs: bitcoinRescanSecs, newBlocksSecs: newBlocksSecs)
+    }
+
+    static func dictionaryOf(schedulerIntervals: SchedulerIntervals) -> [String: Any?] {
+        return [
+            "syncSecs": schedulerIntervals.syncSecs,
+            "refundsSecs": schedulerIntervals.refundsSecs,
+            "liquidRescanSecs": schedulerIntervals.liquidRescanSecs,
+            "bitcoinRescanSecs": schedulerIntervals.bitcoinRescanSecs,
+            "newBlocksSecs": schedulerIntervals.newBlocksSecs,
+        ]
+    }
+
+    static func asSchedulerIntervalsList(arr: [Any]) throws -> [SchedulerIntervals] {
+        var list = [SchedulerIntervals]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var schedulerIntervals = try asSchedulerIntervals(schedulerIntervals: val)
+                list.append(schedulerIntervals)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SchedulerIntervals"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(schedulerIntervalsList: [SchedulerIntervals]) -> [Any] {
+        return schedulerIntervalsList.map { v -> [String: Any?] in return dictionaryOf(schedulerIntervals: v) }
+    }
+
     static func asSendPaymentRequest(sendPaymentRequest: [String: Any?]) throws -> SendPaymentRequest {
         guard let prepareResponseTmp = sendPaymentRequest["prepareResponse"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "SendPaymentRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "prepareResponse", typeName: "SendPaymentRequest"))
         }
         let prepareResponse = try asPrepareSendResponse(prepareSendResponse: prepareResponseTmp)
 
@@ -2586,7 +2994,7 @@ enum BreezSDKLiquidMapper {
                 var sendPaymentRequest = try asSendPaymentRequest(sendPaymentRequest: val)
                 list.append(sendPaymentRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SendPaymentRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SendPaymentRequest"))
             }
         }
         return list
@@ -2598,7 +3006,7 @@ enum BreezSDKLiquidMapper {
 
     static func asSendPaymentResponse(sendPaymentResponse: [String: Any?]) throws -> SendPaymentResponse {
         guard let paymentTmp = sendPaymentResponse["payment"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payment", typeName: "SendPaymentResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "payment", typeName: "SendPaymentResponse"))
         }
         let payment = try asPayment(payment: paymentTmp)
 
@@ -2618,7 +3026,7 @@ enum BreezSDKLiquidMapper {
                 var sendPaymentResponse = try asSendPaymentResponse(sendPaymentResponse: val)
                 list.append(sendPaymentResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SendPaymentResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SendPaymentResponse"))
             }
         }
         return list
@@ -2630,7 +3038,7 @@ enum BreezSDKLiquidMapper {
 
     static func asSignMessageRequest(signMessageRequest: [String: Any?]) throws -> SignMessageRequest {
         guard let message = signMessageRequest["message"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "SignMessageRequest"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "message", typeName: "SignMessageRequest"))
         }
 
         return SignMessageRequest(message: message)
@@ -2649,7 +3057,7 @@ enum BreezSDKLiquidMapper {
                 var signMessageRequest = try asSignMessageRequest(signMessageRequest: val)
                 list.append(signMessageRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SignMessageRequest"))
             }
         }
         return list
@@ -2661,7 +3069,7 @@ enum BreezSDKLiquidMapper {
 
     static func asSignMessageResponse(signMessageResponse: [String: Any?]) throws -> SignMessageResponse {
         guard let signature = signMessageResponse["signature"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "SignMessageResponse"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "signature", typeName: "SignMessageResponse"))
         }
 
         return SignMessageResponse(signature: signature)
@@ -2680,7 +3088,7 @@ enum BreezSDKLiquidMapper {
                 var signMessageResponse = try asSignMessageResponse(signMessageResponse: val)
                 list.append(signMessageResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageResponse"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SignMessageResponse"))
             }
         }
         return list
@@ -2690,32 +3098,91 @@ enum BreezSDKLiquidMapper {
         return signMessageResponseList.map { v -> [String: Any?] in return dictionaryOf(signMessageResponse: v) }
     }
 
+    static func asSwapStateTransition(swapStateTransition: [String: Any?]) throws -> SwapStateTransition {
+        guard let swapId = swapStateTransition["swapId"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "SwapStateTransition"))
+        }
+        guard let fromStateTmp = swapStateTransition["fromState"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fromState", typeName: "SwapStateTransition"))
+        }
+        let fromState = try asPaymentState(paymentState: fromStateTmp)
+
+        guard let toStateTmp = swapStateTransition["toState"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "toState", typeName: "SwapStateTransition"))
+        }
+        let toState = try asPaymentState(paymentState: toStateTmp)
+
+        var swapperStatus: String?
+        if hasNonNilKey(data: swapStateTransition, key: "swapperStatus") {
+            guard let swapperStatusTmp = swapStateTransition["swapperStatus"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "swapperStatus"))
+            }
+            swapperStatus = swapperStatusTmp
+        }
+        guard let txIds = swapStateTransition["txIds"] as? [String] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "txIds", typeName: "SwapStateTransition"))
+        }
+        guard let createdAt = swapStateTransition["createdAt"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "createdAt", typeName: "SwapStateTransition"))
+        }
+
+        return SwapStateTransition(swapId: swapId, fromState: fromState, toState: toState, swapperStatus: swapperStatus, txIds: txIds, createdAt: createdAt)
+    }
+
+    static func dictionaryOf(swapStateTransition: SwapStateTransition) -> [String: Any?] {
+        return [
+            "swapId": swapStateTransition.swapId,
+            "fromState": valueOf(paymentState: swapStateTransition.fromState),
+            "toState": valueOf(paymentState: swapStateTransition.toState),
+            "swapperStatus": swapStateTransition.swapperStatus == nil ? nil : swapStateTransition.swapperStatus,
+            "txIds": swapStateTransition.txIds,
+            "createdAt": swapStateTransition.createdAt,
+        ]
+    }
+
+    static func asSwapStateTransitionList(arr: [Any]) throws -> [SwapStateTransition] {
+        var list = [SwapStateTransition]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var swapStateTransition = try asSwapStateTransition(swapStateTransition: val)
+                list.append(swapStateTransition)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SwapStateTransition"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(swapStateTransitionList: [SwapStateTransition]) -> [Any] {
+        return swapStateTransitionList.map { v -> [String: Any?] in return dictionaryOf(swapStateTransition: v) }
+    }
+
     static func asSymbol(symbol: [String: Any?]) throws -> Symbol {
         var grapheme: String?
         if hasNonNilKey(data: symbol, key: "grapheme") {
             guard let graphemeTmp = symbol["grapheme"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "grapheme"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "grapheme"))
             }
             grapheme = graphemeTmp
         }
         var template: String?
         if hasNonNilKey(data: symbol, key: "template") {
             guard let templateTmp = symbol["template"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "template"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "template"))
             }
             template = templateTmp
         }
         var rtl: Bool?
         if hasNonNilKey(data: symbol, key: "rtl") {
             guard let rtlTmp = symbol["rtl"] as? Bool else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "rtl"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "rtl"))
             }
             rtl = rtlTmp
         }
         var position: UInt32?
         if hasNonNilKey(data: symbol, key: "position") {
             guard let positionTmp = symbol["position"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "position"))
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "position"))
             }
             position = positionTmp
         }
@@ -2739,7 +3206,7 @@ enum BreezSDKLiquidMapper {
                 var symbol = try asSymbol(symbol: val)
                 list.append(symbol)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Symbol"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Symbol"))
             }
         }
         return list
@@ -2749,15 +3216,138 @@ enum BreezSDKLiquidMapper {
         return symbolList.map { v -> [String: Any?] in return dictionaryOf(symbol: v) }
     }
 
+    static func asSyncMetrics(syncMetrics: [String: Any?]) throws -> SyncMetrics {
+        guard let startedAt = syncMetrics["startedAt"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "startedAt", typeName: "SyncMetrics"))
+        }
+        guard let durationMs = syncMetrics["durationMs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "durationMs", typeName: "SyncMetrics"))
+        }
+        guard let scanDurationMs = syncMetrics["scanDurationMs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "scanDurationMs", typeName: "SyncMetrics"))
+        }
+        var liquidTip: UInt32?
+        if hasNonNilKey(data: syncMetrics, key: "liquidTip") {
+            guard let liquidTipTmp = syncMetrics["liquidTip"] as? UInt32 else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "liquidTip"))
+            }
+            liquidTip = liquidTipTmp
+        }
+        guard let totalTxCount = syncMetrics["totalTxCount"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "totalTxCount", typeName: "SyncMetrics"))
+        }
+        guard let processedTxCount = syncMetrics["processedTxCount"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "processedTxCount", typeName: "SyncMetrics"))
+        }
+
+        return SyncMetrics(startedAt: startedAt, durationMs: durationMs, scanDurationMs: scanDurationMs, liquidTip: liquidTip, totalTxCount: totalTxCount, processedTxCount: processedTxCount)
+    }
+
+    static func dictionaryOf(syncMetrics: SyncMetrics) -> [String: Any?] {
+        return [
+            "startedAt": syncMetrics.startedAt,
+            "durationMs": syncMetrics.durationMs,
+            "scanDurationMs": syncMetrics.scanDurationMs,
+            "liquidTip": syncMetrics.liquidTip == nil ? nil : syncMetrics.liquidTip,
+            "totalTxCount": syncMetrics.totalTxCount,
+            "processedTxCount": syncMetrics.processedTxCount,
+        ]
+    }
+
+    static func asSyncMetricsList(arr: [Any]) throws -> [SyncMetrics] {
+        var list = [SyncMetrics]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var syncMetrics = try asSyncMetrics(syncMetrics: val)
+                list.append(syncMetrics)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SyncMetrics"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(syncMetricsList: [SyncMetrics]) -> [Any] {
+        return syncMetricsList.map { v -> [String: Any?] in return dictionaryOf(syncMetrics: v) }
+    }
+
+    static func asSyncOnceRequest(syncOnceRequest: [String: Any?]) throws -> SyncOnceRequest {
+        guard let timeoutSecs = syncOnceRequest["timeoutSecs"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "timeoutSecs", typeName: "SyncOnceRequest"))
+        }
+
+        return SyncOnceRequest(timeoutSecs: timeoutSecs)
+    }
+
+    static func dictionaryOf(syncOnceRequest: SyncOnceRequest) -> [String: Any?] {
+        return [
+            "timeoutSecs": syncOnceRequest.timeoutSecs,
+        ]
+    }
+
+    static func asSyncOnceRequestList(arr: [Any]) throws -> [SyncOnceRequest] {
+        var list = [SyncOnceRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var syncOnceRequest = try asSyncOnceRequest(syncOnceRequest: val)
+                list.append(syncOnceRequest)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SyncOnceRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(syncOnceRequestList: [SyncOnceRequest]) -> [Any] {
+        return syncOnceRequestList.map { v -> [String: Any?] in return dictionaryOf(syncOnceRequest: v) }
+    }
+
+    static func asSyncOnceResponse(syncOnceResponse: [String: Any?]) throws -> SyncOnceResponse {
+        guard let pendingTasksTmp = syncOnceResponse["pendingTasks"] as? [String] else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pendingTasks", typeName: "SyncOnceResponse"))
+        }
+        let pendingTasks = try asSyncTaskList(arr: pendingTasksTmp)
+
+        guard let pendingSwapCount = syncOnceResponse["pendingSwapCount"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "pendingSwapCount", typeName: "SyncOnceResponse"))
+        }
+
+        return SyncOnceResponse(pendingTasks: pendingTasks, pendingSwapCount: pendingSwapCount)
+    }
+
+    static func dictionaryOf(syncOnceResponse: SyncOnceResponse) -> [String: Any?] {
+        return [
+            "pendingTasks": arrayOf(syncTaskList: syncOnceResponse.pendingTasks),
+            "pendingSwapCount": syncOnceResponse.pendingSwapCount,
+        ]
+    }
+
+    static func asSyncOnceResponseList(arr: [Any]) throws -> [SyncOnceResponse] {
+        var list = [SyncOnceResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var syncOnceResponse = try asSyncOnceResponse(syncOnceResponse: val)
+                list.append(syncOnceResponse)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SyncOnceResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(syncOnceResponseList: [SyncOnceResponse]) -> [Any] {
+        return syncOnceResponseList.map { v -> [String: Any?] in return dictionaryOf(syncOnceResponse: v) }
+    }
+
     static func asUrlSuccessActionData(urlSuccessActionData: [String: Any?]) throws -> UrlSuccessActionData {
         guard let description = urlSuccessActionData["description"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "UrlSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "UrlSuccessActionData"))
         }
         guard let url = urlSuccessActionData["url"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "UrlSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "UrlSuccessActionData"))
         }
         guard let matchesCallbackDomain = urlSuccessActionData["matchesCallbackDomain"] as? Bool else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "matchesCallbackDomain", typeName: "UrlSuccessActionData"))
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "matchesCallbackDomain", typeName: "UrlSuccessActionData"))
         }
 
         return UrlSuccessActionData(description: description, url: url, matchesCallbackDomain: matchesCallbackDomain)
@@ -2778,7 +3368,7 @@ enum BreezSDKLiquidMapper {
                 var urlSuccessActionData = try asUrlSuccessActionData(urlSuccessActionData: val)
                 list.append(urlSuccessActionData)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "UrlSuccessActionData"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "UrlSuccessActionData"))
             }
         }
         return list
@@ -2788,11 +3378,80 @@ enum BreezSDKLiquidMapper {
         return urlSuccessActionDataList.map { v -> [String: Any?] in return dictionaryOf(urlSuccessActionData: v) }
     }
 
+    static func asWebhookDelivery(webhookDelivery: [String: Any?]) throws -> WebhookDelivery {
+        guard let id = webhookDelivery["id"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "id", typeName: "WebhookDelivery"))
+        }
+        guard let url = webhookDelivery["url"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "WebhookDelivery"))
+        }
+        guard let eventSeq = webhookDelivery["eventSeq"] as? UInt64 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "eventSeq", typeName: "WebhookDelivery"))
+        }
+        guard let payload = webhookDelivery["payload"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "payload", typeName: "WebhookDelivery"))
+        }
+        guard let stateTmp = webhookDelivery["state"] as? String else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "state", typeName: "WebhookDelivery"))
+        }
+        let state = try asWebhookDeliveryState(webhookDeliveryState: stateTmp)
+
+        guard let attempts = webhookDelivery["attempts"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "attempts", typeName: "WebhookDelivery"))
+        }
+        guard let nextAttemptAt = webhookDelivery["nextAttemptAt"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "nextAttemptAt", typeName: "WebhookDelivery"))
+        }
+        var lastError: String?
+        if hasNonNilKey(data: webhookDelivery, key: "lastError") {
+            guard let lastErrorTmp = webhookDelivery["lastError"] as? String else {
+                throw SdkError.Validation(err: errUnexpectedValue(fieldName: "lastError"))
+            }
+            lastError = lastErrorTmp
+        }
+        guard let createdAt = webhookDelivery["createdAt"] as? UInt32 else {
+            throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "createdAt", typeName: "WebhookDelivery"))
+        }
+
+        return WebhookDelivery(id: id, url: url, eventSeq: eventSeq, payload: payload, state: state, attempts: attempts, nextAttemptAt: nextAttemptAt, lastError: lastError, createdAt: createdAt)
+    }
+
+    static func dictionaryOf(webhookDelivery: WebhookDelivery) -> [String: Any?] {
+        return [
+            "id": webhookDelivery.id,
+            "url": webhookDelivery.url,
+            "eventSeq": webhookDelivery.eventSeq,
+            "payload": webhookDelivery.payload,
+            "state": valueOf(webhookDeliveryState: webhookDelivery.state),
+            "attempts": webhookDelivery.attempts,
+            "nextAttemptAt": webhookDelivery.nextAttemptAt,
+            "lastError": webhookDelivery.lastError == nil ? nil : webhookDelivery.lastError,
+            "createdAt": webhookDelivery.createdAt,
+        ]
+    }
+
+    static func asWebhookDeliveryList(arr: [Any]) throws -> [WebhookDelivery] {
+        var list = [WebhookDelivery]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var webhookDelivery = try asWebhookDelivery(webhookDelivery: val)
+                list.append(webhookDelivery)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "WebhookDelivery"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(webhookDeliveryList: [WebhookDelivery]) -> [Any] {
+        return webhookDeliveryList.map { v -> [String: Any?] in return dictionaryOf(webhookDelivery: v) }
+    }
+
     static func asAesSuccessActionDataResult(aesSuccessActionDataResult: [String: Any?]) throws -> AesSuccessActionDataResult {
         let type = aesSuccessActionDataResult["type"] as! String
         if type == "decrypted" {
             guard let dataTmp = aesSuccessActionDataResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "AesSuccessActionDataResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "AesSuccessActionDataResult"))
             }
             let _data = try asAesSuccessActionDataDecrypted(aesSuccessActionDataDecrypted: dataTmp)
 
@@ -2800,12 +3459,12 @@ enum BreezSDKLiquidMapper {
         }
         if type == "errorStatus" {
             guard let _reason = aesSuccessActionDataResult["reason"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reason", typeName: "AesSuccessActionDataResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "reason", typeName: "AesSuccessActionDataResult"))
             }
             return AesSuccessActionDataResult.errorStatus(reason: _reason)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum AesSuccessActionDataResult")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum AesSuccessActionDataResult")
     }
 
     static func dictionaryOf(aesSuccessActionDataResult: AesSuccessActionDataResult) -> [String: Any?] {
@@ -2839,7 +3498,7 @@ enum BreezSDKLiquidMapper {
                 var aesSuccessActionDataResult = try asAesSuccessActionDataResult(aesSuccessActionDataResult: val)
                 list.append(aesSuccessActionDataResult)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "AesSuccessActionDataResult"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "AesSuccessActionDataResult"))
             }
         }
         return list
@@ -2849,21 +3508,21 @@ enum BreezSDKLiquidMapper {
         let type = amount["type"] as! String
         if type == "bitcoin" {
             guard let _amountMsat = amount["amountMsat"] as? UInt64 else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "Amount"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountMsat", typeName: "Amount"))
             }
             return Amount.bitcoin(amountMsat: _amountMsat)
         }
         if type == "currency" {
             guard let _iso4217Code = amount["iso4217Code"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "iso4217Code", typeName: "Amount"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "iso4217Code", typeName: "Amount"))
             }
             guard let _fractionalAmount = amount["fractionalAmount"] as? UInt64 else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fractionalAmount", typeName: "Amount"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fractionalAmount", typeName: "Amount"))
             }
             return Amount.currency(iso4217Code: _iso4217Code, fractionalAmount: _fractionalAmount)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum Amount")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum Amount")
     }
 
     static func dictionaryOf(amount: Amount) -> [String: Any?] {
@@ -2898,7 +3557,46 @@ enum BreezSDKLiquidMapper {
                 var amount = try asAmount(amount: val)
                 list.append(amount)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Amount"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Amount"))
+            }
+        }
+        return list
+    }
+
+    static func asBlockchain(blockchain: String) throws -> Blockchain {
+        switch blockchain {
+        case "liquid":
+            return Blockchain.liquid
+
+        case "bitcoin":
+            return Blockchain.bitcoin
+
+        default: throw SdkError.Validation(err: "Invalid variant \(blockchain) for enum Blockchain")
+        }
+    }
+
+    static func valueOf(blockchain: Blockchain) -> String {
+        switch blockchain {
+        case .liquid:
+            return "liquid"
+
+        case .bitcoin:
+            return "bitcoin"
+        }
+    }
+
+    static func arrayOf(blockchainList: [Blockchain]) -> [String] {
+        return blockchainList.map { v -> String in return valueOf(blockchain: v) }
+    }
+
+    static func asBlockchainList(arr: [Any]) throws -> [Blockchain] {
+        var list = [Blockchain]()
+        for value in arr {
+            if let val = value as? String {
+                var blockchain = try asBlockchain(blockchain: val)
+                list.append(blockchain)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Blockchain"))
             }
         }
         return list
@@ -2909,7 +3607,7 @@ enum BreezSDKLiquidMapper {
         case "moonpay":
             return BuyBitcoinProvider.moonpay
 
-        default: throw SdkError.Generic(message: "Invalid variant \(buyBitcoinProvider) for enum BuyBitcoinProvider")
+        default: throw SdkError.Validation(err: "Invalid variant \(buyBitcoinProvider) for enum BuyBitcoinProvider")
         }
     }
 
@@ -2931,7 +3629,100 @@ enum BreezSDKLiquidMapper {
                 var buyBitcoinProvider = try asBuyBitcoinProvider(buyBitcoinProvider: val)
                 list.append(buyBitcoinProvider)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "BuyBitcoinProvider"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "BuyBitcoinProvider"))
+            }
+        }
+        return list
+    }
+
+    static func asChainBackend(chainBackend: [String: Any?]) throws -> ChainBackend {
+        let type = chainBackend["type"] as! String
+        if type == "electrum" {
+            guard let _url = chainBackend["url"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "ChainBackend"))
+            }
+            return ChainBackend.electrum(url: _url)
+        }
+        if type == "esplora" {
+            guard let _url = chainBackend["url"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "ChainBackend"))
+            }
+            return ChainBackend.esplora(url: _url)
+        }
+        if type == "hybrid" {
+            guard let _electrumUrl = chainBackend["electrumUrl"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "electrumUrl", typeName: "ChainBackend"))
+            }
+            guard let _esploraUrl = chainBackend["esploraUrl"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "esploraUrl", typeName: "ChainBackend"))
+            }
+            return ChainBackend.hybrid(electrumUrl: _electrumUrl, esploraUrl: _esploraUrl)
+        }
+        if type == "rpc" {
+            guard let _url = chainBackend["url"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "ChainBackend"))
+            }
+            let _username = chainBackend["username"] as? String
+
+            let _password = chainBackend["password"] as? String
+
+            return ChainBackend.rpc(url: _url, username: _username, password: _password)
+        }
+
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum ChainBackend")
+    }
+
+    static func dictionaryOf(chainBackend: ChainBackend) -> [String: Any?] {
+        switch chainBackend {
+        case let .electrum(
+            url
+        ):
+            return [
+                "type": "electrum",
+                "url": url,
+            ]
+
+        case let .esplora(
+            url
+        ):
+            return [
+                "type": "esplora",
+                "url": url,
+            ]
+
+        case let .hybrid(
+            electrumUrl, esploraUrl
+        ):
+            return [
+                "type": "hybrid",
+                "electrumUrl": electrumUrl,
+                "esploraUrl": esploraUrl,
+            ]
+
+        case let .rpc(
+            url, username, password
+        ):
+            return [
+                "type": "rpc",
+                "url": url,
+                "username": username == nil ? nil : username,
+                "password": password == nil ? nil : password,
+            ]
+        }
+    }
+
+    static func arrayOf(chainBackendList: [ChainBackend]) -> [Any] {
+        return chainBackendList.map { v -> [String: Any?] in return dictionaryOf(chainBackend: v) }
+    }
+
+    static func asChainBackendList(arr: [Any]) throws -> [ChainBackend] {
+        var list = [ChainBackend]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var chainBackend = try asChainBackend(chainBackend: val)
+                list.append(chainBackend)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ChainBackend"))
             }
         }
         return list
@@ -2941,12 +3732,12 @@ enum BreezSDKLiquidMapper {
         let type = getPaymentRequest["type"] as! String
         if type == "lightning" {
             guard let _paymentHash = getPaymentRequest["paymentHash"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "GetPaymentRequest"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "paymentHash", typeName: "GetPaymentRequest"))
             }
             return GetPaymentRequest.lightning(paymentHash: _paymentHash)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum GetPaymentRequest")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum GetPaymentRequest")
     }
 
     static func dictionaryOf(getPaymentRequest: GetPaymentRequest) -> [String: Any?] {
@@ -2972,7 +3763,7 @@ enum BreezSDKLiquidMapper {
                 var getPaymentRequest = try asGetPaymentRequest(getPaymentRequest: val)
                 list.append(getPaymentRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "GetPaymentRequest"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "GetPaymentRequest"))
             }
         }
         return list
@@ -2982,7 +3773,7 @@ enum BreezSDKLiquidMapper {
         let type = inputType["type"] as! String
         if type == "bitcoinAddress" {
             guard let addressTmp = inputType["address"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "InputType"))
             }
             let _address = try asBitcoinAddressData(bitcoinAddressData: addressTmp)
 
@@ -2990,7 +3781,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "liquidAddress" {
             guard let addressTmp = inputType["address"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "InputType"))
             }
             let _address = try asLiquidAddressData(liquidAddressData: addressTmp)
 
@@ -2998,7 +3789,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "bolt11" {
             guard let invoiceTmp = inputType["invoice"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "invoice", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "invoice", typeName: "InputType"))
             }
             let _invoice = try asLnInvoice(lnInvoice: invoiceTmp)
 
@@ -3006,7 +3797,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "bolt12Offer" {
             guard let offerTmp = inputType["offer"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "offer", typeName: "InputType"))
             }
             let _offer = try asLnOffer(lnOffer: offerTmp)
 
@@ -3014,19 +3805,19 @@ enum BreezSDKLiquidMapper {
         }
         if type == "nodeId" {
             guard let _nodeId = inputType["nodeId"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "nodeId", typeName: "InputType"))
             }
             return InputType.nodeId(nodeId: _nodeId)
         }
         if type == "url" {
             guard let _url = inputType["url"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "url", typeName: "InputType"))
             }
             return InputType.url(url: _url)
         }
         if type == "lnUrlPay" {
             guard let dataTmp = inputType["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
             }
             let _data = try asLnUrlPayRequestData(lnUrlPayRequestData: dataTmp)
 
@@ -3034,7 +3825,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "lnUrlWithdraw" {
             guard let dataTmp = inputType["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
             }
             let _data = try asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: dataTmp)
 
@@ -3042,7 +3833,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "lnUrlAuth" {
             guard let dataTmp = inputType["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
             }
             let _data = try asLnUrlAuthRequestData(lnUrlAuthRequestData: dataTmp)
 
@@ -3050,14 +3841,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "lnUrlError" {
             guard let dataTmp = inputType["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
             }
             let _data = try asLnUrlErrorData(lnUrlErrorData: dataTmp)
 
             return InputType.lnUrlError(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum InputType")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum InputType")
     }
 
     static func dictionaryOf(inputType: InputType) -> [String: Any?] {
@@ -3155,7 +3946,7 @@ enum BreezSDKLiquidMapper {
                 var inputType = try asInputType(inputType: val)
                 list.append(inputType)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "InputType"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "InputType"))
             }
         }
         return list
@@ -3169,7 +3960,10 @@ enum BreezSDKLiquidMapper {
         case "testnet":
             return LiquidNetwork.testnet
 
-        default: throw SdkError.Generic(message: "Invalid variant \(liquidNetwork) for enum LiquidNetwork")
+        case "regtest":
+            return LiquidNetwork.regtest
+
+        default: throw SdkError.Validation(err: "Invalid variant \(liquidNetwork) for enum LiquidNetwork")
         }
     }
 
@@ -3180,6 +3974,9 @@ enum BreezSDKLiquidMapper {
 
         case .testnet:
             return "testnet"
+
+        case .regtest:
+            return "regtest"
         }
     }
 
@@ -3194,7 +3991,7 @@ enum BreezSDKLiquidMapper {
                 var liquidNetwork = try asLiquidNetwork(liquidNetwork: val)
                 list.append(liquidNetwork)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LiquidNetwork"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LiquidNetwork"))
             }
         }
         return list
@@ -3204,18 +4001,18 @@ enum BreezSDKLiquidMapper {
         let type = listPaymentDetails["type"] as! String
         if type == "liquid" {
             guard let _destination = listPaymentDetails["destination"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "ListPaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "ListPaymentDetails"))
             }
             return ListPaymentDetails.liquid(destination: _destination)
         }
         if type == "bitcoin" {
             guard let _address = listPaymentDetails["address"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "ListPaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "address", typeName: "ListPaymentDetails"))
             }
             return ListPaymentDetails.bitcoin(address: _address)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum ListPaymentDetails")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum ListPaymentDetails")
     }
 
     static func dictionaryOf(listPaymentDetails: ListPaymentDetails) -> [String: Any?] {
@@ -3249,7 +4046,7 @@ enum BreezSDKLiquidMapper {
                 var listPaymentDetails = try asListPaymentDetails(listPaymentDetails: val)
                 list.append(listPaymentDetails)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ListPaymentDetails"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "ListPaymentDetails"))
             }
         }
         return list
@@ -3262,14 +4059,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "errorStatus" {
             guard let dataTmp = lnUrlCallbackStatus["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlCallbackStatus"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlCallbackStatus"))
             }
             let _data = try asLnUrlErrorData(lnUrlErrorData: dataTmp)
 
             return LnUrlCallbackStatus.errorStatus(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum LnUrlCallbackStatus")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum LnUrlCallbackStatus")
     }
 
     static func dictionaryOf(lnUrlCallbackStatus: LnUrlCallbackStatus) -> [String: Any?] {
@@ -3300,7 +4097,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlCallbackStatus = try asLnUrlCallbackStatus(lnUrlCallbackStatus: val)
                 list.append(lnUrlCallbackStatus)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlCallbackStatus"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlCallbackStatus"))
             }
         }
         return list
@@ -3310,7 +4107,7 @@ enum BreezSDKLiquidMapper {
         let type = lnUrlPayResult["type"] as! String
         if type == "endpointSuccess" {
             guard let dataTmp = lnUrlPayResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
             }
             let _data = try asLnUrlPaySuccessData(lnUrlPaySuccessData: dataTmp)
 
@@ -3318,7 +4115,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "endpointError" {
             guard let dataTmp = lnUrlPayResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
             }
             let _data = try asLnUrlErrorData(lnUrlErrorData: dataTmp)
 
@@ -3326,14 +4123,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "payError" {
             guard let dataTmp = lnUrlPayResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
             }
             let _data = try asLnUrlPayErrorData(lnUrlPayErrorData: dataTmp)
 
             return LnUrlPayResult.payError(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum LnUrlPayResult")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum LnUrlPayResult")
     }
 
     static func dictionaryOf(lnUrlPayResult: LnUrlPayResult) -> [String: Any?] {
@@ -3375,7 +4172,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlPayResult = try asLnUrlPayResult(lnUrlPayResult: val)
                 list.append(lnUrlPayResult)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayResult"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlPayResult"))
             }
         }
         return list
@@ -3385,7 +4182,7 @@ enum BreezSDKLiquidMapper {
         let type = lnUrlWithdrawResult["type"] as! String
         if type == "ok" {
             guard let dataTmp = lnUrlWithdrawResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
             }
             let _data = try asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: dataTmp)
 
@@ -3393,7 +4190,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "timeout" {
             guard let dataTmp = lnUrlWithdrawResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
             }
             let _data = try asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: dataTmp)
 
@@ -3401,14 +4198,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "errorStatus" {
             guard let dataTmp = lnUrlWithdrawResult["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlWithdrawResult"))
             }
             let _data = try asLnUrlErrorData(lnUrlErrorData: dataTmp)
 
             return LnUrlWithdrawResult.errorStatus(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum LnUrlWithdrawResult")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum LnUrlWithdrawResult")
     }
 
     static func dictionaryOf(lnUrlWithdrawResult: LnUrlWithdrawResult) -> [String: Any?] {
@@ -3450,7 +4247,7 @@ enum BreezSDKLiquidMapper {
                 var lnUrlWithdrawResult = try asLnUrlWithdrawResult(lnUrlWithdrawResult: val)
                 list.append(lnUrlWithdrawResult)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlWithdrawResult"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "LnUrlWithdrawResult"))
             }
         }
         return list
@@ -3470,7 +4267,7 @@ enum BreezSDKLiquidMapper {
         case "regtest":
             return Network.regtest
 
-        default: throw SdkError.Generic(message: "Invalid variant \(network) for enum Network")
+        default: throw SdkError.Validation(err: "Invalid variant \(network) for enum Network")
         }
     }
 
@@ -3501,7 +4298,7 @@ enum BreezSDKLiquidMapper {
                 var network = try asNetwork(network: val)
                 list.append(network)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "Network"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "Network"))
             }
         }
         return list
@@ -3511,15 +4308,24 @@ enum BreezSDKLiquidMapper {
         let type = payAmount["type"] as! String
         if type == "receiver" {
             guard let _amountSat = payAmount["amountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "PayAmount"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amountSat", typeName: "PayAmount"))
             }
             return PayAmount.receiver(amountSat: _amountSat)
         }
         if type == "drain" {
             return PayAmount.drain
         }
+        if type == "fiat" {
+            guard let _currency = payAmount["currency"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "currency", typeName: "PayAmount"))
+            }
+            guard let _amount = payAmount["amount"] as? Double else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "amount", typeName: "PayAmount"))
+            }
+            return PayAmount.fiat(currency: _currency, amount: _amount)
+        }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum PayAmount")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum PayAmount")
     }
 
     static func dictionaryOf(payAmount: PayAmount) -> [String: Any?] {
@@ -3536,6 +4342,15 @@ enum BreezSDKLiquidMapper {
             return [
                 "type": "drain",
             ]
+
+        case let .fiat(
+            currency, amount
+        ):
+            return [
+                "type": "fiat",
+                "currency": currency,
+                "amount": amount,
+            ]
         }
     }
 
@@ -3550,7 +4365,7 @@ enum BreezSDKLiquidMapper {
                 var payAmount = try asPayAmount(payAmount: val)
                 list.append(payAmount)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PayAmount"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PayAmount"))
             }
         }
         return list
@@ -3560,10 +4375,10 @@ enum BreezSDKLiquidMapper {
         let type = paymentDetails["type"] as! String
         if type == "lightning" {
             guard let _swapId = paymentDetails["swapId"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapId", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "PaymentDetails"))
             }
             guard let _description = paymentDetails["description"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
             }
             let _preimage = paymentDetails["preimage"] as? String
 
@@ -3581,19 +4396,19 @@ enum BreezSDKLiquidMapper {
         }
         if type == "liquid" {
             guard let _destination = paymentDetails["destination"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "destination", typeName: "PaymentDetails"))
             }
             guard let _description = paymentDetails["description"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
             }
             return PaymentDetails.liquid(destination: _destination, description: _description)
         }
         if type == "bitcoin" {
             guard let _swapId = paymentDetails["swapId"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapId", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "PaymentDetails"))
             }
             guard let _description = paymentDetails["description"] as? String else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "description", typeName: "PaymentDetails"))
             }
             let _refundTxId = paymentDetails["refundTxId"] as? String
 
@@ -3602,7 +4417,7 @@ enum BreezSDKLiquidMapper {
             return PaymentDetails.bitcoin(swapId: _swapId, description: _description, refundTxId: _refundTxId, refundTxAmountSat: _refundTxAmountSat)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum PaymentDetails")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum PaymentDetails")
     }
 
     static func dictionaryOf(paymentDetails: PaymentDetails) -> [String: Any?] {
@@ -3655,7 +4470,7 @@ enum BreezSDKLiquidMapper {
                 var paymentDetails = try asPaymentDetails(paymentDetails: val)
                 list.append(paymentDetails)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentDetails"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PaymentDetails"))
             }
         }
         return list
@@ -3672,7 +4487,7 @@ enum BreezSDKLiquidMapper {
         case "liquidAddress":
             return PaymentMethod.liquidAddress
 
-        default: throw SdkError.Generic(message: "Invalid variant \(paymentMethod) for enum PaymentMethod")
+        default: throw SdkError.Validation(err: "Invalid variant \(paymentMethod) for enum PaymentMethod")
         }
     }
 
@@ -3700,7 +4515,7 @@ enum BreezSDKLiquidMapper {
                 var paymentMethod = try asPaymentMethod(paymentMethod: val)
                 list.append(paymentMethod)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentMethod"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PaymentMethod"))
             }
         }
         return list
@@ -3729,7 +4544,7 @@ enum BreezSDKLiquidMapper {
         case "refundPending":
             return PaymentState.refundPending
 
-        default: throw SdkError.Generic(message: "Invalid variant \(paymentState) for enum PaymentState")
+        default: throw SdkError.Validation(err: "Invalid variant \(paymentState) for enum PaymentState")
         }
     }
 
@@ -3769,7 +4584,7 @@ enum BreezSDKLiquidMapper {
                 var paymentState = try asPaymentState(paymentState: val)
                 list.append(paymentState)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentState"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PaymentState"))
             }
         }
         return list
@@ -3783,7 +4598,7 @@ enum BreezSDKLiquidMapper {
         case "send":
             return PaymentType.send
 
-        default: throw SdkError.Generic(message: "Invalid variant \(paymentType) for enum PaymentType")
+        default: throw SdkError.Validation(err: "Invalid variant \(paymentType) for enum PaymentType")
         }
     }
 
@@ -3808,7 +4623,7 @@ enum BreezSDKLiquidMapper {
                 var paymentType = try asPaymentType(paymentType: val)
                 list.append(paymentType)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentType"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "PaymentType"))
             }
         }
         return list
@@ -3818,7 +4633,7 @@ enum BreezSDKLiquidMapper {
         let type = sdkEvent["type"] as! String
         if type == "paymentFailed" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
@@ -3826,15 +4641,23 @@ enum BreezSDKLiquidMapper {
         }
         if type == "paymentPending" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
             return SdkEvent.paymentPending(details: _details)
         }
+        if type == "paymentQueued" {
+            guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+            }
+            let _details = try asPayment(payment: detailsTmp)
+
+            return SdkEvent.paymentQueued(details: _details)
+        }
         if type == "paymentRefunded" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
@@ -3842,7 +4665,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "paymentRefundPending" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
@@ -3850,7 +4673,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "paymentSucceeded" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
@@ -3858,17 +4681,83 @@ enum BreezSDKLiquidMapper {
         }
         if type == "paymentWaitingConfirmation" {
             guard let detailsTmp = sdkEvent["details"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "details", typeName: "SdkEvent"))
             }
             let _details = try asPayment(payment: detailsTmp)
 
             return SdkEvent.paymentWaitingConfirmation(details: _details)
         }
+        if type == "swapNearingTimeout" {
+            guard let _swapId = sdkEvent["swapId"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "SdkEvent"))
+            }
+            guard let _blocksLeft = sdkEvent["blocksLeft"] as? UInt32 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "blocksLeft", typeName: "SdkEvent"))
+            }
+            return SdkEvent.swapNearingTimeout(swapId: _swapId, blocksLeft: _blocksLeft)
+        }
+        if type == "swapRefundable" {
+            guard let _swapId = sdkEvent["swapId"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "SdkEvent"))
+            }
+            return SdkEvent.swapRefundable(swapId: _swapId)
+        }
+        if type == "swapStateChanged" {
+            guard let _swapId = sdkEvent["swapId"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "swapId", typeName: "SdkEvent"))
+            }
+            guard let fromStateTmp = sdkEvent["fromState"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "fromState", typeName: "SdkEvent"))
+            }
+            let _fromState = try asPaymentState(paymentState: fromStateTmp)
+
+            guard let toStateTmp = sdkEvent["toState"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "toState", typeName: "SdkEvent"))
+            }
+            let _toState = try asPaymentState(paymentState: toStateTmp)
+
+            return SdkEvent.swapStateChanged(swapId: _swapId, fromState: _fromState, toState: _toState)
+        }
+        if type == "balanceChanged" {
+            guard let _oldBalanceSat = sdkEvent["oldBalanceSat"] as? UInt64 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "oldBalanceSat", typeName: "SdkEvent"))
+            }
+            guard let _newBalanceSat = sdkEvent["newBalanceSat"] as? UInt64 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "newBalanceSat", typeName: "SdkEvent"))
+            }
+            return SdkEvent.balanceChanged(oldBalanceSat: _oldBalanceSat, newBalanceSat: _newBalanceSat)
+        }
+        if type == "syncProgress" {
+            guard let _step = sdkEvent["step"] as? UInt32 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "step", typeName: "SdkEvent"))
+            }
+            guard let _total = sdkEvent["total"] as? UInt32 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "total", typeName: "SdkEvent"))
+            }
+            return SdkEvent.syncProgress(step: _step, total: _total)
+        }
+        if type == "connectivityChanged" {
+            guard let _isConnected = sdkEvent["isConnected"] as? Bool else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "isConnected", typeName: "SdkEvent"))
+            }
+            return SdkEvent.connectivityChanged(isConnected: _isConnected)
+        }
+        if type == "newBlock" {
+            guard let chainTmp = sdkEvent["chain"] as? String else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "chain", typeName: "SdkEvent"))
+            }
+            let _chain = try asBlockchain(blockchain: chainTmp)
+
+            guard let _height = sdkEvent["height"] as? UInt32 else {
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "height", typeName: "SdkEvent"))
+            }
+            return SdkEvent.newBlock(chain: _chain, height: _height)
+        }
         if type == "synced" {
             return SdkEvent.synced
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum SdkEvent")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum SdkEvent")
     }
 
     static func dictionaryOf(sdkEvent: SdkEvent) -> [String: Any?] {
@@ -3889,6 +4778,14 @@ enum BreezSDKLiquidMapper {
                 "details": dictionaryOf(payment: details),
             ]
 
+        case let .paymentQueued(
+            details
+        ):
+            return [
+                "type": "paymentQueued",
+                "details": dictionaryOf(payment: details),
+            ]
+
         case let .paymentRefunded(
             details
         ):
@@ -3921,6 +4818,68 @@ enum BreezSDKLiquidMapper {
                 "details": dictionaryOf(payment: details),
             ]
 
+        case let .swapNearingTimeout(
+            swapId, blocksLeft
+        ):
+            return [
+                "type": "swapNearingTimeout",
+                "swapId": swapId,
+                "blocksLeft": blocksLeft,
+            ]
+
+        case let .swapRefundable(
+            swapId
+        ):
+            return [
+                "type": "swapRefundable",
+                "swapId": swapId,
+            ]
+
+        case let .swapStateChanged(
+            swapId, fromState, toState
+        ):
+            return [
+                "type": "swapStateChanged",
+                "swapId": swapId,
+                "fromState": valueOf(paymentState: fromState),
+                "toState": valueOf(paymentState: toState),
+            ]
+
+        case let .balanceChanged(
+            oldBalanceSat, newBalanceSat
+        ):
+            return [
+                "type": "balanceChanged",
+                "oldBalanceSat": oldBalanceSat,
+                "newBalanceSat": newBalanceSat,
+            ]
+
+        case let .syncProgress(
+            step, total
+        ):
+            return [
+                "type": "syncProgress",
+                "step": step,
+                "total": total,
+            ]
+
+        case let .connectivityChanged(
+            isConnected
+        ):
+            return [
+                "type": "connectivityChanged",
+                "isConnected": isConnected,
+            ]
+
+        case let .newBlock(
+            chain, height
+        ):
+            return [
+                "type": "newBlock",
+                "chain": valueOf(blockchain: chain),
+                "height": height,
+            ]
+
         case .synced:
             return [
                 "type": "synced",
@@ -3939,7 +4898,7 @@ enum BreezSDKLiquidMapper {
                 var sdkEvent = try asSdkEvent(sdkEvent: val)
                 list.append(sdkEvent)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SdkEvent"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SdkEvent"))
             }
         }
         return list
@@ -3949,7 +4908,7 @@ enum BreezSDKLiquidMapper {
         let type = sendDestination["type"] as! String
         if type == "liquidAddress" {
             guard let addressDataTmp = sendDestination["addressData"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "addressData", typeName: "SendDestination"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "addressData", typeName: "SendDestination"))
             }
             let _addressData = try asLiquidAddressData(liquidAddressData: addressDataTmp)
 
@@ -3957,7 +4916,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "bolt11" {
             guard let invoiceTmp = sendDestination["invoice"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "invoice", typeName: "SendDestination"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "invoice", typeName: "SendDestination"))
             }
             let _invoice = try asLnInvoice(lnInvoice: invoiceTmp)
 
@@ -3965,17 +4924,17 @@ enum BreezSDKLiquidMapper {
         }
         if type == "bolt12" {
             guard let offerTmp = sendDestination["offer"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "SendDestination"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "offer", typeName: "SendDestination"))
             }
             let _offer = try asLnOffer(lnOffer: offerTmp)
 
             guard let _receiverAmountSat = sendDestination["receiverAmountSat"] as? UInt64 else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receiverAmountSat", typeName: "SendDestination"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "receiverAmountSat", typeName: "SendDestination"))
             }
             return SendDestination.bolt12(offer: _offer, receiverAmountSat: _receiverAmountSat)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum SendDestination")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum SendDestination")
     }
 
     static func dictionaryOf(sendDestination: SendDestination) -> [String: Any?] {
@@ -4018,7 +4977,7 @@ enum BreezSDKLiquidMapper {
                 var sendDestination = try asSendDestination(sendDestination: val)
                 list.append(sendDestination)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SendDestination"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SendDestination"))
             }
         }
         return list
@@ -4028,7 +4987,7 @@ enum BreezSDKLiquidMapper {
         let type = successAction["type"] as! String
         if type == "aes" {
             guard let dataTmp = successAction["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
             }
             let _data = try asAesSuccessActionData(aesSuccessActionData: dataTmp)
 
@@ -4036,7 +4995,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "message" {
             guard let dataTmp = successAction["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
             }
             let _data = try asMessageSuccessActionData(messageSuccessActionData: dataTmp)
 
@@ -4044,14 +5003,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "url" {
             guard let dataTmp = successAction["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "SuccessAction"))
             }
             let _data = try asUrlSuccessActionData(urlSuccessActionData: dataTmp)
 
             return SuccessAction.url(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum SuccessAction")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum SuccessAction")
     }
 
     static func dictionaryOf(successAction: SuccessAction) -> [String: Any?] {
@@ -4093,7 +5052,7 @@ enum BreezSDKLiquidMapper {
                 var successAction = try asSuccessAction(successAction: val)
                 list.append(successAction)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SuccessAction"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SuccessAction"))
             }
         }
         return list
@@ -4103,7 +5062,7 @@ enum BreezSDKLiquidMapper {
         let type = successActionProcessed["type"] as! String
         if type == "aes" {
             guard let resultTmp = successActionProcessed["result"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "result", typeName: "SuccessActionProcessed"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "result", typeName: "SuccessActionProcessed"))
             }
             let _result = try asAesSuccessActionDataResult(aesSuccessActionDataResult: resultTmp)
 
@@ -4111,7 +5070,7 @@ enum BreezSDKLiquidMapper {
         }
         if type == "message" {
             guard let dataTmp = successActionProcessed["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "SuccessActionProcessed"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "SuccessActionProcessed"))
             }
             let _data = try asMessageSuccessActionData(messageSuccessActionData: dataTmp)
 
@@ -4119,14 +5078,14 @@ enum BreezSDKLiquidMapper {
         }
         if type == "url" {
             guard let dataTmp = successActionProcessed["data"] as? [String: Any?] else {
-                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "SuccessActionProcessed"))
+                throw SdkError.Validation(err: errMissingMandatoryField(fieldName: "data", typeName: "SuccessActionProcessed"))
             }
             let _data = try asUrlSuccessActionData(urlSuccessActionData: dataTmp)
 
             return SuccessActionProcessed.url(data: _data)
         }
 
-        throw SdkError.Generic(message: "Unexpected type \(type) for enum SuccessActionProcessed")
+        throw SdkError.Validation(err: "Unexpected type \(type) for enum SuccessActionProcessed")
     }
 
     static func dictionaryOf(successActionProcessed: SuccessActionProcessed) -> [String: Any?] {
@@ -4168,7 +5127,91 @@ enum BreezSDKLiquidMapper {
                 var successActionProcessed = try asSuccessActionProcessed(successActionProcessed: val)
                 list.append(successActionProcessed)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SuccessActionProcessed"))
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SuccessActionProcessed"))
+            }
+        }
+        return list
+    }
+
+    static func asSyncTask(syncTask: String) throws -> SyncTask {
+        switch syncTask {
+        case "claims":
+            return SyncTask.claims
+
+        case "refunds":
+            return SyncTask.refunds
+
+        case "history":
+            return SyncTask.history
+
+        default: throw SdkError.Validation(err: "Invalid variant \(syncTask) for enum SyncTask")
+        }
+    }
+
+    static func valueOf(syncTask: SyncTask) -> String {
+        switch syncTask {
+        case .claims:
+            return "claims"
+
+        case .refunds:
+            return "refunds"
+
+        case .history:
+            return "history"
+        }
+    }
+
+    static func arrayOf(syncTaskList: [SyncTask]) -> [String] {
+        return syncTaskList.map { v -> String in return valueOf(syncTask: v) }
+    }
+
+    static func asSyncTaskList(arr: [Any]) throws -> [SyncTask] {
+        var list = [SyncTask]()
+        for value in arr {
+            if let val = value as? String {
+                var syncTask = try asSyncTask(syncTask: val)
+                list.append(syncTask)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "SyncTask"))
+            }
+        }
+        return list
+    }
+
+    static func asWebhookDeliveryState(webhookDeliveryState: String) throws -> WebhookDeliveryState {
+        switch webhookDeliveryState {
+        case "pending":
+            return WebhookDeliveryState.pending
+
+        case "failed":
+            return WebhookDeliveryState.failed
+
+        default: throw SdkError.Validation(err: "Invalid variant \(webhookDeliveryState) for enum WebhookDeliveryState")
+        }
+    }
+
+    static func valueOf(webhookDeliveryState: WebhookDeliveryState) -> String {
+        switch webhookDeliveryState {
+        case .pending:
+            return "pending"
+
+        case .failed:
+            return "failed"
+        }
+    }
+
+    static func arrayOf(webhookDeliveryStateList: [WebhookDeliveryState]) -> [String] {
+        return webhookDeliveryStateList.map { v -> String in return valueOf(webhookDeliveryState: v) }
+    }
+
+    static func asWebhookDeliveryStateList(arr: [Any]) throws -> [WebhookDeliveryState] {
+        var list = [WebhookDeliveryState]()
+        for value in arr {
+            if let val = value as? String {
+                var webhookDeliveryState = try asWebhookDeliveryState(webhookDeliveryState: val)
+                list.append(webhookDeliveryState)
+            } else {
+                throw SdkError.Validation(err: errUnexpectedType(typeName: "WebhookDeliveryState"))
             }
         }
         return list
@@ -58,7 +58,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
             return bindingLiquidSdk
         }
 
-        throw SdkError.Generic(message: "Not initialized")
+        throw SdkError.NotStarted
     }
 
     private func ensureWorkingDir(workingDir: String) throws {
@@ -67,7 +67,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
                 try FileManager.default.createDirectory(atPath: workingDir, withIntermediateDirectories: true)
             }
         } catch {
-            throw SdkError.Generic(message: "Mandatory field workingDir must contain a writable directory")
+            throw SdkError.Validation(err: "Mandatory field workingDir must contain a writable directory")
         }
     }
 
@@ -117,7 +117,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
     @objc(connect:resolve:reject:)
     func connect(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         if bindingLiquidSdk != nil {
-            reject("Generic", "Already initialized", nil)
+            rejectErr(err: SdkError.AlreadyStarted, reject: reject)
             return
         }
 
@@ -155,6 +155,70 @@ class RNBreezSDKLiquid: RCTEventEmitter {
         }
     }
 
+    @objc(eventsSince:resolve:reject:)
+    func eventsSince(_ seq: UInt64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().eventsSince(seq: seq)
+            resolve(BreezSDKLiquidMapper.arrayOf(eventLogEntryList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(ackEvents:seq:resolve:reject:)
+    func ackEvents(_ listenerId: String, seq: UInt64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().ackEvents(listenerId: listenerId, seq: seq)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(getEventCursor:resolve:reject:)
+    func getEventCursor(_ listenerId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().getEventCursor(listenerId: listenerId)
+            if res != nil {
+                resolve(res!)
+            } else {
+                resolve(nil)
+            }
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(getWebhookSigningSecret:reject:)
+    func getWebhookSigningSecret(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().getWebhookSigningSecret()
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listFailedWebhookDeliveries:reject:)
+    func listFailedWebhookDeliveries(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().listFailedWebhookDeliveries()
+            resolve(BreezSDKLiquidMapper.arrayOf(webhookDeliveryList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(retryWebhookDelivery:resolve:reject:)
+    func retryWebhookDelivery(_ id: UInt64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().retryWebhookDelivery(id: id)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(getInfo:reject:)
     func getInfo(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -363,6 +427,66 @@ class RNBreezSDKLiquid: RCTEventEmitter {
         }
     }
 
+    @objc(forceClaimSwap:resolve:reject:)
+    func forceClaimSwap(_ swapId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().forceClaimSwap(swapId: swapId)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(forceRefundSendSwap:isCooperative:resolve:reject:)
+    func forceRefundSendSwap(_ swapId: String, isCooperative: Bool, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().forceRefundSendSwap(swapId: swapId, isCooperative: isCooperative)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(refetchSwapStatus:resolve:reject:)
+    func refetchSwapStatus(_ swapId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().refetchSwapStatus(swapId: swapId)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(markSwapFailed:resolve:reject:)
+    func markSwapFailed(_ swapId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().markSwapFailed(swapId: swapId)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(importSwap:resolve:reject:)
+    func importSwap(_ rescueJson: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBindingLiquidSdk().importSwap(rescueJson: rescueJson)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(exportRescueFile:resolve:reject:)
+    func exportRescueFile(_ swapId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().exportRescueFile(swapId: swapId)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(sync:reject:)
     func sync(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -373,6 +497,51 @@ class RNBreezSDKLiquid: RCTEventEmitter {
         }
     }
 
+    @objc(syncOnce:resolve:reject:)
+    func syncOnce(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let syncOnceRequest = try BreezSDKLiquidMapper.asSyncOnceRequest(syncOnceRequest: req)
+            var res = try getBindingLiquidSdk().syncOnce(req: syncOnceRequest)
+            resolve(BreezSDKLiquidMapper.dictionaryOf(syncOnceResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(getLastSyncMetrics:reject:)
+    func getLastSyncMetrics(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().getLastSyncMetrics()
+            if res != nil {
+                resolve(BreezSDKLiquidMapper.dictionaryOf(syncMetrics: res!))
+            } else {
+                resolve(nil)
+            }
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(getSwapHistory:resolve:reject:)
+    func getSwapHistory(_ swapId: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().getSwapHistory(swapId: swapId)
+            resolve(BreezSDKLiquidMapper.arrayOf(swapStateTransitionList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(generateDiagnostics:reject:)
+    func generateDiagnostics(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().generateDiagnostics()
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(recommendedFees:reject:)
     func recommendedFees(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -490,6 +659,20 @@ class RNBreezSDKLiquid: RCTEventEmitter {
         }
     }
 
+    @objc(getHistoricalFiatRate:timestamp:resolve:reject:)
+    func getHistoricalFiatRate(_ currency: String, timestamp: UInt32, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBindingLiquidSdk().getHistoricalFiatRate(currency: currency, timestamp: timestamp)
+            if res != nil {
+                resolve(BreezSDKLiquidMapper.dictionaryOf(rate: res!))
+            } else {
+                resolve(nil)
+            }
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(listFiatCurrencies:reject:)
     func listFiatCurrencies(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -509,6 +692,17 @@ class RNBreezSDKLiquid: RCTEventEmitter {
                 message = associatedMessage.value as! String
             }
         }
-        reject(errorName, message, err)
+        var info: ErrorInfo?
+        if let sdkErr = err as? SdkError {
+            info = sdkErrorInfo(err: sdkErr)
+        } else if let paymentErr = err as? PaymentError {
+            info = paymentErrorInfo(err: paymentErr)
+        }
+        var userInfo: [String: Any] = [NSLocalizedDescriptionKey: message]
+        if let info = info {
+            userInfo["code"] = info.code
+            userInfo["retryable"] = info.retryable
+        }
+        reject(errorName, message, NSError(domain: RNBreezSDKLiquid.TAG, code: Int(info?.code ?? 0), userInfo: userInfo))
     }
 }
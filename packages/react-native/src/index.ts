@@ -58,8 +58,8 @@ export interface CheckMessageResponse {
 }
 
 export interface Config {
-    liquidElectrumUrl: string
-    bitcoinElectrumUrl: string
+    liquidChainBackend: ChainBackend
+    bitcoinChainBackend: ChainBackend
     mempoolspaceUrl: string
     workingDir: string
     network: LiquidNetwork
@@ -68,6 +68,17 @@ export interface Config {
     breezApiKey?: string
     cacheDir?: string
     zeroConfMaxAmountSat?: number
+    fiatRateHistoryCurrencies?: string[]
+    eventWebhookUrls?: string[]
+    liquidFallbackChainBackends?: ChainBackend[]
+    bitcoinFallbackChainBackends?: ChainBackend[]
+    chainQuorumMinAmountSat?: number
+    swapperUrl?: string
+    swapperWsUrl?: string
+    swapperReferralId?: string
+    lbtcAssetId?: string
+    liquidGenesisHash?: string
+    schedulerIntervals?: SchedulerIntervals
 }
 
 export interface ConnectRequest {
@@ -89,6 +100,22 @@ export interface CurrencyInfo {
     localeOverrides: LocaleOverrides[]
 }
 
+export interface ErrorInfo {
+    code: number
+    retryable: boolean
+}
+
+export interface EventLogEntry {
+    seq: number
+    createdAt: number
+    event: SdkEvent
+}
+
+export interface FiatAmount {
+    currency: string
+    amount: number
+}
+
 export interface FiatCurrency {
     id: string
     info: CurrencyInfo
@@ -131,6 +158,7 @@ export interface LnOffer {
 export interface LightningPaymentLimitsResponse {
     send: Limits
     receive: Limits
+    cacheAgeSecs?: number
 }
 
 export interface Limits {
@@ -230,6 +258,17 @@ export interface LocalizedName {
 export interface LogEntry {
     line: string
     level: string
+    target: string
+    module?: string
+    timestamp: number
+    swapId?: string
+    paymentId?: string
+    fields: LogField[]
+}
+
+export interface LogField {
+    key: string
+    value: string
 }
 
 export interface MessageSuccessActionData {
@@ -239,6 +278,7 @@ export interface MessageSuccessActionData {
 export interface OnchainPaymentLimitsResponse {
     send: Limits
     receive: Limits
+    cacheAgeSecs?: number
 }
 
 export interface PayOnchainRequest {
@@ -255,6 +295,12 @@ export interface Payment {
     details: PaymentDetails
     destination?: string
     txId?: string
+    fiatRate?: PaymentFiatRate
+}
+
+export interface PaymentFiatRate {
+    currency: string
+    rate: number
 }
 
 export interface PrepareBuyBitcoinRequest {
@@ -290,17 +336,20 @@ export interface PreparePayOnchainResponse {
     receiverAmountSat: number
     claimFeesSat: number
     totalFeesSat: number
+    fiatRate?: PaymentFiatRate
 }
 
 export interface PrepareReceiveRequest {
     paymentMethod: PaymentMethod
     payerAmountSat?: number
+    payerAmountFiat?: FiatAmount
 }
 
 export interface PrepareReceiveResponse {
     payerAmountSat?: number
     paymentMethod: PaymentMethod
     feesSat: number
+    fiatRate?: PaymentFiatRate
 }
 
 export interface PrepareRefundRequest {
@@ -312,6 +361,8 @@ export interface PrepareRefundRequest {
 export interface PrepareRefundResponse {
     txVsize: number
     txFeeSat: number
+    isCooperative: boolean
+    feeSavedSat: number
     refundTxId?: string
 }
 
@@ -323,6 +374,7 @@ export interface PrepareSendRequest {
 export interface PrepareSendResponse {
     destination: SendDestination
     feesSat: number
+    fiatRate?: PaymentFiatRate
 }
 
 export interface Rate {
@@ -362,6 +414,7 @@ export interface RefundableSwap {
     swapAddress: string
     timestamp: number
     amountSat: number
+    timeToRefundable?: number
 }
 
 export interface RestoreRequest {
@@ -382,6 +435,14 @@ export interface RouteHintHop {
     htlcMaximumMsat?: number
 }
 
+export interface SchedulerIntervals {
+    syncSecs: number
+    refundsSecs: number
+    liquidRescanSecs: number
+    bitcoinRescanSecs: number
+    newBlocksSecs: number
+}
+
 export interface SendPaymentRequest {
     prepareResponse: PrepareSendResponse
 }
@@ -398,6 +459,15 @@ export interface SignMessageResponse {
     signature: string
 }
 
+export interface SwapStateTransition {
+    swapId: string
+    fromState: PaymentState
+    toState: PaymentState
+    swapperStatus?: string
+    txIds: string[]
+    createdAt: number
+}
+
 export interface SymbolType {
     grapheme?: string
     template?: string
@@ -405,12 +475,42 @@ export interface SymbolType {
     position?: number
 }
 
+export interface SyncMetrics {
+    startedAt: number
+    durationMs: number
+    scanDurationMs: number
+    liquidTip?: number
+    totalTxCount: number
+    processedTxCount: number
+}
+
+export interface SyncOnceRequest {
+    timeoutSecs: number
+}
+
+export interface SyncOnceResponse {
+    pendingTasks: SyncTask[]
+    pendingSwapCount: number
+}
+
 export interface UrlSuccessActionData {
     description: string
     url: string
     matchesCallbackDomain: boolean
 }
 
+export interface WebhookDelivery {
+    id: number
+    url: string
+    eventSeq: number
+    payload: string
+    state: WebhookDeliveryState
+    attempts: number
+    nextAttemptAt: number
+    lastError?: string
+    createdAt: number
+}
+
 export enum AesSuccessActionDataResultVariant {
     DECRYPTED = "decrypted",
     ERROR_STATUS = "errorStatus"
@@ -438,10 +538,39 @@ export type Amount = {
     fractionalAmount: number
 }
 
+export enum Blockchain {
+    LIQUID = "liquid",
+    BITCOIN = "bitcoin"
+}
+
 export enum BuyBitcoinProvider {
     MOONPAY = "moonpay"
 }
 
+export enum ChainBackendVariant {
+    ELECTRUM = "electrum",
+    ESPLORA = "esplora",
+    HYBRID = "hybrid",
+    RPC = "rpc"
+}
+
+export type ChainBackend = {
+    type: ChainBackendVariant.ELECTRUM,
+    url: string
+} | {
+    type: ChainBackendVariant.ESPLORA,
+    url: string
+} | {
+    type: ChainBackendVariant.HYBRID,
+    electrumUrl: string
+    esploraUrl: string
+} | {
+    type: ChainBackendVariant.RPC,
+    url: string
+    username?: string
+    password?: string
+}
+
 export enum GetPaymentRequestVariant {
     LIGHTNING = "lightning"
 }
@@ -498,7 +627,8 @@ export type InputType = {
 
 export enum LiquidNetwork {
     MAINNET = "mainnet",
-    TESTNET = "testnet"
+    TESTNET = "testnet",
+    REGTEST = "regtest"
 }
 
 export enum ListPaymentDetailsVariant {
@@ -569,7 +699,8 @@ export enum Network {
 
 export enum PayAmountVariant {
     RECEIVER = "receiver",
-    DRAIN = "drain"
+    DRAIN = "drain",
+    FIAT = "fiat"
 }
 
 export type PayAmount = {
@@ -577,6 +708,10 @@ export type PayAmount = {
     amountSat: number
 } | {
     type: PayAmountVariant.DRAIN
+} | {
+    type: PayAmountVariant.FIAT,
+    currency: string
+    amount: number
 }
 
 export enum PaymentDetailsVariant {
@@ -631,10 +766,18 @@ export enum PaymentType {
 export enum SdkEventVariant {
     PAYMENT_FAILED = "paymentFailed",
     PAYMENT_PENDING = "paymentPending",
+    PAYMENT_QUEUED = "paymentQueued",
     PAYMENT_REFUNDED = "paymentRefunded",
     PAYMENT_REFUND_PENDING = "paymentRefundPending",
     PAYMENT_SUCCEEDED = "paymentSucceeded",
     PAYMENT_WAITING_CONFIRMATION = "paymentWaitingConfirmation",
+    SWAP_NEARING_TIMEOUT = "swapNearingTimeout",
+    SWAP_REFUNDABLE = "swapRefundable",
+    SWAP_STATE_CHANGED = "swapStateChanged",
+    BALANCE_CHANGED = "balanceChanged",
+    SYNC_PROGRESS = "syncProgress",
+    CONNECTIVITY_CHANGED = "connectivityChanged",
+    NEW_BLOCK = "newBlock",
     SYNCED = "synced"
 }
 
@@ -644,6 +787,9 @@ export type SdkEvent = {
 } | {
     type: SdkEventVariant.PAYMENT_PENDING,
     details: Payment
+} | {
+    type: SdkEventVariant.PAYMENT_QUEUED,
+    details: Payment
 } | {
     type: SdkEventVariant.PAYMENT_REFUNDED,
     details: Payment
@@ -656,6 +802,33 @@ export type SdkEvent = {
 } | {
     type: SdkEventVariant.PAYMENT_WAITING_CONFIRMATION,
     details: Payment
+} | {
+    type: SdkEventVariant.SWAP_NEARING_TIMEOUT,
+    swapId: string
+    blocksLeft: number
+} | {
+    type: SdkEventVariant.SWAP_REFUNDABLE,
+    swapId: string
+} | {
+    type: SdkEventVariant.SWAP_STATE_CHANGED,
+    swapId: string
+    fromState: PaymentState
+    toState: PaymentState
+} | {
+    type: SdkEventVariant.BALANCE_CHANGED,
+    oldBalanceSat: number
+    newBalanceSat: number
+} | {
+    type: SdkEventVariant.SYNC_PROGRESS,
+    step: number
+    total: number
+} | {
+    type: SdkEventVariant.CONNECTIVITY_CHANGED,
+    isConnected: boolean
+} | {
+    type: SdkEventVariant.NEW_BLOCK,
+    chain: Blockchain
+    height: number
 } | {
     type: SdkEventVariant.SYNCED
 }
@@ -712,6 +885,17 @@ export type SuccessActionProcessed = {
     data: UrlSuccessActionData
 }
 
+export enum SyncTask {
+    CLAIMS = "claims",
+    REFUNDS = "refunds",
+    HISTORY = "history"
+}
+
+export enum WebhookDeliveryState {
+    PENDING = "pending",
+    FAILED = "failed"
+}
+
 export type EventListener = (e: SdkEvent) => void
 
 export type Logger = (logEntry: LogEntry) => void
@@ -738,6 +922,18 @@ export const setLogger = async (logger: Logger): Promise<EmitterSubscription> =>
     return subscription
 }
 
+/**
+ * Returns the stable code and retryable flag of an error thrown by the SDK, which are
+ * attached to the rejected promise's `userInfo`.
+ */
+export const errorInfo = (err: unknown): ErrorInfo | undefined => {
+    const userInfo = (err as { userInfo?: Partial<ErrorInfo> } | undefined)?.userInfo
+    if (typeof userInfo?.code !== "number" || typeof userInfo?.retryable !== "boolean") {
+        return undefined
+    }
+    return { code: userInfo.code, retryable: userInfo.retryable }
+}
+
 export const defaultConfig = async (network: LiquidNetwork, breezApiKey: string = ""): Promise<Config> => {
     const response = await BreezSDKLiquid.defaultConfig(network, breezApiKey)
     return response
@@ -758,6 +954,34 @@ export const removeEventListener = async (id: string): Promise<void> => {
     await BreezSDKLiquid.removeEventListener(id)
 }
 
+export const eventsSince = async (seq: number): Promise<EventLogEntry[]> => {
+    const response = await BreezSDKLiquid.eventsSince(seq)
+    return response
+}
+
+export const ackEvents = async (listenerId: string, seq: number): Promise<void> => {
+    await BreezSDKLiquid.ackEvents(listenerId, seq)
+}
+
+export const getEventCursor = async (listenerId: string): Promise<number | null> => {
+    const response = await BreezSDKLiquid.getEventCursor(listenerId)
+    return response
+}
+
+export const getWebhookSigningSecret = async (): Promise<string> => {
+    const response = await BreezSDKLiquid.getWebhookSigningSecret()
+    return response
+}
+
+export const listFailedWebhookDeliveries = async (): Promise<WebhookDelivery[]> => {
+    const response = await BreezSDKLiquid.listFailedWebhookDeliveries()
+    return response
+}
+
+export const retryWebhookDelivery = async (id: number): Promise<void> => {
+    await BreezSDKLiquid.retryWebhookDelivery(id)
+}
+
 export const getInfo = async (): Promise<GetInfoResponse> => {
     const response = await BreezSDKLiquid.getInfo()
     return response
@@ -852,10 +1076,56 @@ export const rescanOnchainSwaps = async (): Promise<void> => {
     await BreezSDKLiquid.rescanOnchainSwaps()
 }
 
+export const forceClaimSwap = async (swapId: string): Promise<void> => {
+    await BreezSDKLiquid.forceClaimSwap(swapId)
+}
+
+export const forceRefundSendSwap = async (swapId: string, isCooperative: boolean): Promise<string> => {
+    const response = await BreezSDKLiquid.forceRefundSendSwap(swapId, isCooperative)
+    return response
+}
+
+export const refetchSwapStatus = async (swapId: string): Promise<void> => {
+    await BreezSDKLiquid.refetchSwapStatus(swapId)
+}
+
+export const markSwapFailed = async (swapId: string): Promise<void> => {
+    await BreezSDKLiquid.markSwapFailed(swapId)
+}
+
+export const importSwap = async (rescueJson: string): Promise<void> => {
+    await BreezSDKLiquid.importSwap(rescueJson)
+}
+
+export const exportRescueFile = async (swapId: string): Promise<string> => {
+    const response = await BreezSDKLiquid.exportRescueFile(swapId)
+    return response
+}
+
 export const sync = async (): Promise<void> => {
     await BreezSDKLiquid.sync()
 }
 
+export const syncOnce = async (req: SyncOnceRequest): Promise<SyncOnceResponse> => {
+    const response = await BreezSDKLiquid.syncOnce(req)
+    return response
+}
+
+export const getLastSyncMetrics = async (): Promise<SyncMetrics | null> => {
+    const response = await BreezSDKLiquid.getLastSyncMetrics()
+    return response
+}
+
+export const getSwapHistory = async (swapId: string): Promise<SwapStateTransition[]> => {
+    const response = await BreezSDKLiquid.getSwapHistory(swapId)
+    return response
+}
+
+export const generateDiagnostics = async (): Promise<string> => {
+    const response = await BreezSDKLiquid.generateDiagnostics()
+    return response
+}
+
 export const recommendedFees = async (): Promise<RecommendedFees> => {
     const response = await BreezSDKLiquid.recommendedFees()
     return response
@@ -906,6 +1176,11 @@ export const fetchFiatRates = async (): Promise<Rate[]> => {
     return response
 }
 
+export const getHistoricalFiatRate = async (currency: string, timestamp: number): Promise<Rate | null> => {
+    const response = await BreezSDKLiquid.getHistoricalFiatRate(currency, timestamp)
+    return response
+}
+
 export const listFiatCurrencies = async (): Promise<FiatCurrency[]> => {
     const response = await BreezSDKLiquid.listFiatCurrencies()
     return response